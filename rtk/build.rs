@@ -0,0 +1,68 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Bakes git provenance into the binary so `rtk version`/`--version` can
+/// report exactly which build a bug report came from, without requiring
+/// the user to have the source checkout handy.
+fn main() {
+    let branch = git(&["rev-parse", "--abbrev-ref", "HEAD"]);
+    let commit = git(&["rev-parse", "--short", "HEAD"]);
+    let dirty = git(&["status", "--porcelain"])
+        .map(|s| !s.trim().is_empty())
+        .unwrap_or(false);
+    let build_timestamp = env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let branch = branch.unwrap_or_default();
+    let commit = commit.unwrap_or_default();
+
+    let long_version = if commit.is_empty() {
+        env!("CARGO_PKG_VERSION").to_string()
+    } else if dirty {
+        format!(
+            "{} ({}@{}, dirty)",
+            env!("CARGO_PKG_VERSION"),
+            commit,
+            branch
+        )
+    } else {
+        format!("{} ({}@{})", env!("CARGO_PKG_VERSION"), commit, branch)
+    };
+
+    println!("cargo:rustc-env=RTK_LONG_VERSION={long_version}");
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR set by cargo");
+    let dest = Path::new(&out_dir).join("version_info.rs");
+    fs::write(
+        &dest,
+        format!(
+            "pub const GIT_BRANCH: &str = {branch:?};\n\
+             pub const GIT_COMMIT: &str = {commit:?};\n\
+             pub const GIT_DIRTY: bool = {dirty};\n\
+             pub const BUILD_TIMESTAMP: &str = {build_timestamp:?};\n"
+        ),
+    )
+    .expect("failed to write version_info.rs");
+
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/refs");
+}
+
+/// Runs `git <args>` and returns trimmed stdout, or `None` outside a
+/// checkout (e.g. building from a source tarball) so the build never fails.
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let s = String::from_utf8(output.stdout).ok()?;
+    let s = s.trim();
+    if s.is_empty() {
+        None
+    } else {
+        Some(s.to_string())
+    }
+}
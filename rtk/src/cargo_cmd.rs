@@ -1,10 +1,13 @@
+use crate::display_helpers::{self, Diagnostic, OutputEnvelope};
 use crate::tracking;
 use crate::utils::truncate;
 use anyhow::{Context, Result};
-use std::collections::HashMap;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
 use std::ffi::OsString;
+use std::fs;
 use std::process::Command;
-use std::sync::OnceLock;
+use std::sync::{Mutex, OnceLock};
 
 #[derive(Debug, Clone)]
 pub enum CargoCommand {
@@ -13,15 +16,19 @@ pub enum CargoCommand {
     Clippy,
     Check,
     Install,
+    Fix,
+    Fmt,
 }
 
-pub fn run(cmd: CargoCommand, args: &[String], verbose: u8) -> Result<()> {
+pub fn run(cmd: CargoCommand, args: &[String], verbose: u8, json: bool) -> Result<()> {
     match cmd {
-        CargoCommand::Build => run_build(args, verbose),
+        CargoCommand::Build => run_build(args, verbose, json),
         CargoCommand::Test => run_test(args, verbose),
-        CargoCommand::Clippy => run_clippy(args, verbose),
-        CargoCommand::Check => run_check(args, verbose),
+        CargoCommand::Clippy => run_clippy(args, verbose, json),
+        CargoCommand::Check => run_check(args, verbose, json),
         CargoCommand::Install => run_install(args, verbose),
+        CargoCommand::Fix => run_fix(args, verbose),
+        CargoCommand::Fmt => run_fmt(args, verbose),
     }
 }
 
@@ -66,24 +73,443 @@ where
     Ok(())
 }
 
-fn run_build(args: &[String], verbose: u8) -> Result<()> {
-    run_cargo_filtered("build", args, verbose, filter_cargo_build)
+fn run_build(args: &[String], verbose: u8, json: bool) -> Result<()> {
+    let explain = args.iter().any(|a| a == "--explain");
+    let fix = args.iter().any(|a| a == "--fix");
+    let cargo_args: Vec<String> = args
+        .iter()
+        .filter(|a| *a != "--explain" && *a != "--fix")
+        .cloned()
+        .collect();
+    run_cargo_filtered_json(
+        "build",
+        &cargo_args,
+        verbose,
+        json,
+        |output| filter_cargo_build(output, explain, fix),
+    )
 }
 
 fn run_test(args: &[String], verbose: u8) -> Result<()> {
     run_cargo_filtered("test", args, verbose, filter_cargo_test)
 }
 
-fn run_clippy(args: &[String], verbose: u8) -> Result<()> {
-    run_cargo_filtered("clippy", args, verbose, filter_cargo_clippy)
+fn run_clippy(args: &[String], verbose: u8, json: bool) -> Result<()> {
+    let fix = args.iter().any(|a| a == "--fix");
+    let cargo_args: Vec<String> = args.iter().filter(|a| *a != "--fix").cloned().collect();
+    run_cargo_filtered_json(
+        "clippy",
+        &cargo_args,
+        verbose,
+        json,
+        |output| filter_cargo_clippy(output, fix),
+    )
 }
 
-fn run_check(args: &[String], verbose: u8) -> Result<()> {
-    run_cargo_filtered("check", args, verbose, filter_cargo_build)
+fn run_check(args: &[String], verbose: u8, json: bool) -> Result<()> {
+    let explain = args.iter().any(|a| a == "--explain");
+    let fix = args.iter().any(|a| a == "--fix");
+    let cargo_args: Vec<String> = args
+        .iter()
+        .filter(|a| *a != "--explain" && *a != "--fix")
+        .cloned()
+        .collect();
+    run_cargo_filtered_json(
+        "check",
+        &cargo_args,
+        verbose,
+        json,
+        |output| filter_cargo_build(output, explain, fix),
+    )
 }
 
 fn run_install(args: &[String], verbose: u8) -> Result<()> {
-    run_cargo_filtered("install", args, verbose, filter_cargo_install)
+    let no_track = args.iter().any(|a| a == "--no-track");
+    run_cargo_filtered("install", args, verbose, move |output| {
+        filter_cargo_install(output, no_track)
+    })
+}
+
+/// `cargo fix` has no JSON message format (it prints its own `Fixing
+/// <file> (N fixes)` lines as it goes), so this stays on the text-based
+/// [`run_cargo_filtered`] runner like `install` and `fmt` rather than
+/// [`run_cargo_filtered_json`].
+fn run_fix(args: &[String], verbose: u8) -> Result<()> {
+    run_cargo_filtered("fix", args, verbose, filter_cargo_fix)
+}
+
+/// `cargo fmt` has no JSON message format, so this stays on the text-based
+/// [`run_cargo_filtered`] runner. Under `--check`, rustfmt's own
+/// `Diff in <file> at line N:` headers are enough to tell which files need
+/// formatting, so the diffs themselves are left alone; otherwise `-- -l`
+/// asks rustfmt to print just the list of files it reformatted, since a
+/// plain write run produces no output to summarize.
+fn run_fmt(args: &[String], verbose: u8) -> Result<()> {
+    let check_mode = args.iter().any(|a| a == "--check");
+    let mut cargo_args = args.to_vec();
+    if !check_mode {
+        cargo_args.push("--".to_string());
+        cargo_args.push("-l".to_string());
+    }
+    run_cargo_filtered("fmt", &cargo_args, verbose, move |output| {
+        filter_cargo_fmt(output, check_mode)
+    })
+}
+
+/// Like [`run_cargo_filtered`], but asks cargo to emit `--message-format=json`
+/// diagnostics instead of human-formatted text, so `filter_fn` parses
+/// structured rustc messages rather than scraping stdout for prefixes like
+/// `"error["` / `"--> "` that break whenever rustc's rendering changes.
+/// When `json` is set, prints a [`display_helpers::OutputEnvelope`] instead
+/// of `filter_fn`'s text report, reusing the same structured messages for
+/// its `diagnostics` list.
+fn run_cargo_filtered_json<F>(
+    subcommand: &str,
+    args: &[String],
+    verbose: u8,
+    json: bool,
+    filter_fn: F,
+) -> Result<()>
+where
+    F: Fn(&str) -> String,
+{
+    let timer = tracking::TimedExecution::start();
+
+    let mut cmd = Command::new("cargo");
+    cmd.arg(subcommand);
+    cmd.arg("--message-format=json");
+    for arg in args {
+        cmd.arg(arg);
+    }
+
+    if verbose > 0 {
+        eprintln!("Running: cargo {} --message-format=json {}", subcommand, args.join(" "));
+    }
+
+    let output = cmd
+        .output()
+        .with_context(|| format!("Failed to run cargo {}", subcommand))?;
+    // cargo writes its JSON message stream to stdout; stderr carries only
+    // non-JSON chatter (lock waits, yanked-dependency warnings, etc.), which
+    // `parse_compiler_messages` skips line-by-line but filters may still
+    // want to scan for (e.g. the yanked-dependency carve-out), so both are
+    // combined the same way the text-based runner combines them.
+    let raw = format!(
+        "{}\n{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let filtered = filter_fn(&raw);
+    let exit_code = output.status.code().unwrap_or(1);
+
+    if json {
+        let (rustc_diagnostics, _, _) = parse_compiler_messages(&raw);
+        let diagnostics = rustc_diagnostics
+            .iter()
+            .flat_map(|d| diagnostic_to_envelope_rows(d))
+            .collect();
+        let envelope = OutputEnvelope::new(
+            format!("cargo {}", subcommand),
+            args.to_vec(),
+            exit_code,
+            &raw,
+            filtered.clone(),
+            diagnostics,
+        );
+        display_helpers::print_envelope(&envelope)?;
+    } else {
+        println!("{}", filtered);
+    }
+
+    timer.track(
+        &format!("cargo {} {}", subcommand, args.join(" ")),
+        &format!("rtk cargo {} {}", subcommand, args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if !output.status.success() {
+        std::process::exit(exit_code);
+    }
+
+    Ok(())
+}
+
+/// Flatten one [`RustcDiagnostic`] into one [`Diagnostic`] per primary
+/// span (most have exactly one; a handful of lint groups report several).
+/// A diagnostic with no primary span (rare; usually a crate-level lint)
+/// falls back to `"<unknown>"` rather than being dropped.
+fn diagnostic_to_envelope_rows(diag: &RustcDiagnostic) -> Vec<Diagnostic> {
+    let rule = diag.code.as_ref().map(|c| c.code.clone());
+    let primary_spans: Vec<&DiagnosticSpan> = diag.spans.iter().filter(|s| s.is_primary).collect();
+
+    if primary_spans.is_empty() {
+        return vec![Diagnostic {
+            file: "<unknown>".to_string(),
+            line: 0,
+            severity: diag.level.clone(),
+            rule,
+            message: diag.message.clone(),
+        }];
+    }
+
+    primary_spans
+        .into_iter()
+        .map(|span| Diagnostic {
+            file: span.file_name.clone(),
+            line: span.line_start,
+            severity: diag.level.clone(),
+            rule: rule.clone(),
+            message: diag.message.clone(),
+        })
+        .collect()
+}
+
+/// One rustc/cargo message from a `--message-format=json` ndjson stream.
+/// Fields we don't care about (e.g. `package_id`, `children`) are left for
+/// serde to ignore.
+#[derive(Debug, Deserialize)]
+struct CargoJsonMessage {
+    reason: String,
+    #[serde(default)]
+    message: Option<RustcDiagnostic>,
+    #[serde(default)]
+    success: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(crate) struct RustcDiagnostic {
+    /// Short, single-line description, e.g. "mismatched types".
+    pub(crate) message: String,
+    pub(crate) level: String,
+    #[serde(default)]
+    pub(crate) code: Option<DiagnosticCode>,
+    #[serde(default)]
+    pub(crate) spans: Vec<DiagnosticSpan>,
+    #[serde(default)]
+    rendered: Option<String>,
+    /// Sub-diagnostics (notes, helps) — this is where rustfix-style
+    /// suggested replacements live, not on the diagnostic's own spans.
+    #[serde(default)]
+    children: Vec<DiagnosticChild>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct DiagnosticChild {
+    #[serde(default)]
+    spans: Vec<DiagnosticSpan>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(crate) struct DiagnosticCode {
+    pub(crate) code: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(crate) struct DiagnosticSpan {
+    pub(crate) file_name: String,
+    pub(crate) line_start: usize,
+    column_start: usize,
+    #[serde(default)]
+    pub(crate) is_primary: bool,
+    #[serde(default)]
+    byte_start: usize,
+    #[serde(default)]
+    byte_end: usize,
+    #[serde(default)]
+    suggested_replacement: Option<String>,
+    #[serde(default)]
+    suggestion_applicability: Option<String>,
+}
+
+/// Parse a `--message-format=json` ndjson stream into the distinct
+/// `compiler-message` diagnostics it carries (deduped by `(code, primary
+/// span)`, since rustc can repeat the same diagnostic across incremental
+/// passes), plus the number of `compiler-artifact` units compiled and the
+/// `build-finished` success flag, if cargo got that far. Lines that aren't
+/// valid JSON objects (lock-wait chatter on stderr, blank lines) are
+/// skipped rather than treated as a parse failure.
+pub(crate) fn parse_compiler_messages(output: &str) -> (Vec<RustcDiagnostic>, usize, Option<bool>) {
+    let mut diagnostics = Vec::new();
+    let mut seen: HashSet<(Option<String>, Option<(String, usize, usize)>)> = HashSet::new();
+    let mut compiled = 0;
+    let mut success = None;
+
+    for line in output.lines() {
+        let line = line.trim();
+        if !line.starts_with('{') {
+            continue;
+        }
+
+        let msg: CargoJsonMessage = match serde_json::from_str(line) {
+            Ok(m) => m,
+            Err(_) => continue,
+        };
+
+        match msg.reason.as_str() {
+            "compiler-artifact" => compiled += 1,
+            "build-finished" => success = msg.success,
+            "compiler-message" => {
+                if let Some(diag) = msg.message {
+                    let primary = diag
+                        .spans
+                        .iter()
+                        .find(|s| s.is_primary)
+                        .map(|s| (s.file_name.clone(), s.line_start, s.column_start));
+                    let key = (diag.code.as_ref().map(|c| c.code.clone()), primary);
+                    if seen.insert(key) {
+                        diagnostics.push(diag);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    (diagnostics, compiled, success)
+}
+
+/// A single rustfix-style edit: replace the bytes in `[byte_start, byte_end)`
+/// of `file` with `replacement`.
+pub(crate) struct FixEdit {
+    pub(crate) file: String,
+    pub(crate) byte_start: usize,
+    pub(crate) byte_end: usize,
+    pub(crate) replacement: String,
+}
+
+/// Run a `--message-format=json` ndjson stream through [`parse_compiler_messages`]
+/// and [`collect_fixes`] in one step, for callers outside this module (e.g.
+/// `runner::run_fix`) that only need the resulting edits, not the full
+/// diagnostic list.
+pub(crate) fn collect_machine_fixes(json_output: &str) -> Vec<FixEdit> {
+    let (diagnostics, _, _) = parse_compiler_messages(json_output);
+    collect_fixes(&diagnostics)
+}
+
+/// Like [`apply_fixes`], but returns each affected file's original and
+/// would-be-fixed contents instead of writing them, so a caller can render a
+/// diff preview under `--dry-run` without touching the filesystem.
+pub(crate) fn compute_fixed_contents(edits: &[FixEdit]) -> Result<HashMap<String, (String, String)>> {
+    let mut by_file: HashMap<&str, Vec<&FixEdit>> = HashMap::new();
+    for edit in edits {
+        by_file.entry(edit.file.as_str()).or_default().push(edit);
+    }
+
+    let mut results = HashMap::new();
+    for (file, mut file_edits) in by_file {
+        file_edits.sort_by(|a, b| b.byte_start.cmp(&a.byte_start));
+
+        let original = fs::read(file).with_context(|| format!("Failed to read {} for --fix", file))?;
+        let mut content = original.clone();
+        let mut applied_from: Option<usize> = None;
+
+        for edit in file_edits {
+            if edit.byte_start > edit.byte_end || edit.byte_end > content.len() {
+                continue;
+            }
+            if let Some(from) = applied_from {
+                if edit.byte_end > from {
+                    continue; // overlaps an edit already applied
+                }
+            }
+            content.splice(edit.byte_start..edit.byte_end, edit.replacement.bytes());
+            applied_from = Some(edit.byte_start);
+        }
+
+        results.insert(
+            file.to_string(),
+            (
+                String::from_utf8_lossy(&original).into_owned(),
+                String::from_utf8_lossy(&content).into_owned(),
+            ),
+        );
+    }
+
+    Ok(results)
+}
+
+/// Collect every `MachineApplicable` suggestion out of a diagnostic's
+/// `children` (rustc attaches suggestions to sub-diagnostics, not to the
+/// top-level diagnostic's own spans).
+fn collect_fixes(diagnostics: &[RustcDiagnostic]) -> Vec<FixEdit> {
+    let mut edits = Vec::new();
+    for diag in diagnostics {
+        for child in &diag.children {
+            for span in &child.spans {
+                if span.suggestion_applicability.as_deref() != Some("MachineApplicable") {
+                    continue;
+                }
+                if let Some(replacement) = &span.suggested_replacement {
+                    edits.push(FixEdit {
+                        file: span.file_name.clone(),
+                        byte_start: span.byte_start,
+                        byte_end: span.byte_end,
+                        replacement: replacement.clone(),
+                    });
+                }
+            }
+        }
+    }
+    edits
+}
+
+/// Apply a set of machine-applicable suggestions to their files. Edits are
+/// grouped by file and applied back-to-front (sorted by descending start
+/// offset) so splicing one edit doesn't shift the byte ranges of the edits
+/// still to come; an edit that overlaps one already applied (closer to EOF)
+/// is skipped rather than corrupting the file. Returns the number of edits
+/// actually applied.
+pub(crate) fn apply_fixes(edits: &[FixEdit]) -> Result<usize> {
+    let mut by_file: HashMap<&str, Vec<&FixEdit>> = HashMap::new();
+    for edit in edits {
+        by_file.entry(edit.file.as_str()).or_default().push(edit);
+    }
+
+    let mut applied = 0;
+    for (file, mut file_edits) in by_file {
+        file_edits.sort_by(|a, b| b.byte_start.cmp(&a.byte_start));
+
+        let mut content =
+            fs::read(file).with_context(|| format!("Failed to read {} for --fix", file))?;
+        let mut applied_from: Option<usize> = None;
+
+        for edit in file_edits {
+            if edit.byte_start > edit.byte_end || edit.byte_end > content.len() {
+                continue;
+            }
+            if let Some(from) = applied_from {
+                if edit.byte_end > from {
+                    continue; // overlaps an edit already applied
+                }
+            }
+            content.splice(edit.byte_start..edit.byte_end, edit.replacement.bytes());
+            applied_from = Some(edit.byte_start);
+            applied += 1;
+        }
+
+        fs::write(file, &content).with_context(|| format!("Failed to write {} for --fix", file))?;
+    }
+
+    Ok(applied)
+}
+
+/// Match a cargo resolver warning for a yanked dependency version, e.g.
+/// `warning: package \`foo v1.2.3\` in Cargo.lock is yanked in registry
+/// \`crates-io\`, consider running \`cargo update -p foo\` to remove the
+/// yanked version`. Returns the `name vX.Y.Z` portion when it matches, so
+/// callers can preserve the line through compaction instead of discarding
+/// it with the rest of the resolver chatter.
+fn yanked_dep(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    if trimmed.starts_with("warning: package `") && trimmed.contains("yanked") {
+        trimmed
+            .strip_prefix("warning: package `")
+            .and_then(|rest| rest.split('`').next())
+    } else {
+        None
+    }
 }
 
 /// Format crate name + version into a display string
@@ -97,8 +523,15 @@ fn format_crate_info(name: &str, version: &str, fallback: &str) -> String {
     }
 }
 
-/// Filter cargo install output - strip dep compilation, keep installed/replaced/errors
-fn filter_cargo_install(output: &str) -> String {
+/// Filter cargo install output - strip dep compilation, keep installed/replaced/errors.
+/// Since cargo tracks installed binaries in `~/.cargo/.crates2.json` and upgrades in
+/// place, the headline distinguishes a fresh `installed` from an `upgraded from →
+/// to` transition (parsed off the `Replaced package \`a\` with \`b\`` line) rather
+/// than just echoing cargo's own wording; `no_track` (from `--no-track`) is noted
+/// separately since cargo can't record an untracked install as either. Any
+/// yanked-dependency warning ([`yanked_dep`]) is always kept and counted into
+/// the headline rather than being discarded with the rest of the resolver noise.
+fn filter_cargo_install(output: &str, no_track: bool) -> String {
     let mut errors: Vec<String> = Vec::new();
     let mut error_count = 0;
     let mut compiled = 0;
@@ -106,9 +539,11 @@ fn filter_cargo_install(output: &str) -> String {
     let mut current_error = Vec::new();
     let mut installed_crate = String::new();
     let mut installed_version = String::new();
-    let mut replaced_lines: Vec<String> = Vec::new();
+    let mut upgrade_info: Option<(String, String, String)> = None;
+    let mut extra_lines: Vec<String> = Vec::new();
     let mut already_installed = false;
     let mut ignored_line = String::new();
+    let mut yanked_count = 0;
 
     for line in output.lines() {
         let trimmed = line.trim_start();
@@ -156,9 +591,23 @@ fn filter_cargo_install(output: &str) -> String {
             continue;
         }
 
-        // Keep: Replacing/Replaced lines
-        if trimmed.starts_with("Replacing") || trimmed.starts_with("Replaced") {
-            replaced_lines.push(trimmed.to_string());
+        // Replacing is pure noise now that Replaced carries the version delta
+        if trimmed.starts_with("Replacing") {
+            continue;
+        }
+
+        // Replaced package `rtk v0.9.4` with `rtk v0.11.0` (...) — parse the
+        // version delta instead of echoing the raw line back.
+        if trimmed.starts_with("Replaced") {
+            let backtick_parts: Vec<&str> = trimmed.split('`').collect();
+            if backtick_parts.len() >= 4 {
+                let old_crate = backtick_parts[1];
+                let new_crate = backtick_parts[3];
+                let old_version = old_crate.rsplit(' ').next().unwrap_or("").to_string();
+                let new_version = new_crate.rsplit(' ').next().unwrap_or("").to_string();
+                let crate_name = new_crate.split(' ').next().unwrap_or("").to_string();
+                upgrade_info = Some((crate_name, old_version, new_version));
+            }
             continue;
         }
 
@@ -169,11 +618,15 @@ fn filter_cargo_install(output: &str) -> String {
             continue;
         }
 
-        // Keep: actionable warnings (e.g., "be sure to add `/path` to your PATH")
-        // Skip summary lines like "warning: `crate` generated N warnings"
+        // Keep: actionable warnings (e.g., "be sure to add `/path` to your PATH",
+        // or a yanked-dependency notice). Skip summary lines like
+        // "warning: `crate` generated N warnings".
         if line.starts_with("warning:") {
-            if !(line.contains("generated") && line.contains("warning")) {
-                replaced_lines.push(line.to_string());
+            if yanked_dep(line).is_some() {
+                yanked_count += 1;
+                extra_lines.push(line.to_string());
+            } else if !(line.contains("generated") && line.contains("warning")) {
+                extra_lines.push(line.to_string());
             }
             continue;
         }
@@ -256,77 +709,107 @@ fn filter_cargo_install(output: &str) -> String {
 
     // Success
     let crate_info = format_crate_info(&installed_crate, &installed_version, "package");
+    let deps_suffix = if yanked_count > 0 {
+        format!(
+            "{} deps compiled, ⚠ {} yanked dep{}",
+            compiled,
+            yanked_count,
+            if yanked_count > 1 { "s" } else { "" }
+        )
+    } else {
+        format!("{} deps compiled", compiled)
+    };
+
+    let mut result = if let Some((crate_name, from_version, to_version)) = &upgrade_info {
+        format!(
+            "✓ cargo install: upgraded {} {} → {} ({})",
+            crate_name, from_version, to_version, deps_suffix
+        )
+    } else if no_track {
+        format!("✓ cargo install: installed {} ({}) (untracked)", crate_info, deps_suffix)
+    } else {
+        format!("✓ cargo install: installed {} ({})", crate_info, deps_suffix)
+    };
 
-    let mut result = format!(
-        "✓ cargo install ({}, {} deps compiled)",
-        crate_info, compiled
-    );
-
-    for line in &replaced_lines {
+    for line in &extra_lines {
         result.push_str(&format!("\n  {}", line));
     }
 
     result
 }
 
-/// Filter cargo build/check output - strip "Compiling"/"Checking" lines, keep errors + summary
-fn filter_cargo_build(output: &str) -> String {
+/// Filter `cargo fix` output into a single aggregate line, e.g.
+/// `✓ cargo fix: 7 fixes across 3 files (2 warnings remaining)`, by parsing
+/// the `Fixing <file> (N fixes)` line cargo prints as it applies each
+/// file's suggestions. Mirrors [`filter_cargo_test`]: if applying fixes
+/// actually broke the build, don't collapse anything — surface the full
+/// `error[EXXXX]` block the same way [`filter_cargo_install`] does for its
+/// own failures, instead of reporting a bogus success line.
+fn filter_cargo_fix(output: &str) -> String {
+    static FIXING_RE: OnceLock<regex::Regex> = OnceLock::new();
+    let re = FIXING_RE
+        .get_or_init(|| regex::Regex::new(r"^Fixing (\S+) \((\d+) fix(?:es)?\)$").unwrap());
+
+    let mut total_fixes = 0;
+    let mut files_fixed = 0;
+    let mut warning_count = 0;
     let mut errors: Vec<String> = Vec::new();
-    let mut warnings = 0;
-    let mut error_count = 0;
-    let mut compiled = 0;
     let mut in_error = false;
-    let mut current_error = Vec::new();
+    let mut current_error: Vec<String> = Vec::new();
+    let mut broken = false;
 
     for line in output.lines() {
-        if line.trim_start().starts_with("Compiling") || line.trim_start().starts_with("Checking") {
-            compiled += 1;
-            continue;
+        let trimmed = line.trim();
+
+        if trimmed.contains("--broken-code") {
+            broken = true;
         }
-        if line.trim_start().starts_with("Downloading")
-            || line.trim_start().starts_with("Downloaded")
-        {
+
+        if let Some(caps) = re.captures(trimmed) {
+            files_fixed += 1;
+            total_fixes += caps[2].parse::<usize>().unwrap_or(0);
             continue;
         }
-        if line.trim_start().starts_with("Finished") {
+
+        if trimmed.starts_with("Compiling")
+            || trimmed.starts_with("Checking")
+            || trimmed.starts_with("Downloading")
+            || trimmed.starts_with("Downloaded")
+            || trimmed.starts_with("Locking")
+            || trimmed.starts_with("Updating")
+            || trimmed.starts_with("Blocking waiting for file lock")
+            || trimmed.starts_with("Finished")
+        {
             continue;
         }
 
-        // Detect error/warning blocks
         if line.starts_with("error[") || line.starts_with("error:") {
-            // Skip "error: aborting due to" summary lines
-            if line.contains("aborting due to") || line.contains("could not compile") {
+            if line.contains("aborting due to") {
                 continue;
             }
             if in_error && !current_error.is_empty() {
                 errors.push(current_error.join("\n"));
                 current_error.clear();
             }
-            error_count += 1;
             in_error = true;
             current_error.push(line.to_string());
-        } else if line.starts_with("warning:")
-            && line.contains("generated")
-            && line.contains("warning")
-        {
-            // "warning: `crate` generated N warnings" summary line
             continue;
-        } else if line.starts_with("warning:") || line.starts_with("warning[") {
-            if in_error && !current_error.is_empty() {
-                errors.push(current_error.join("\n"));
-                current_error.clear();
-            }
-            warnings += 1;
-            in_error = true;
-            current_error.push(line.to_string());
-        } else if in_error {
-            if line.trim().is_empty() && current_error.len() > 3 {
+        }
+
+        if in_error {
+            if trimmed.is_empty() && current_error.len() > 3 {
                 errors.push(current_error.join("\n"));
                 current_error.clear();
                 in_error = false;
             } else {
                 current_error.push(line.to_string());
             }
+            continue;
+        }
+
+        if line.starts_with("warning:") && !(line.contains("generated") && line.contains("warning"))
+        {
+            warning_count += 1;
         }
     }
 
@@ -334,8 +817,154 @@ fn filter_cargo_build(output: &str) -> String {
         errors.push(current_error.join("\n"));
     }
 
+    if broken || !errors.is_empty() {
+        let mut result = format!(
+            "cargo fix: build broke while applying fixes ({} error{})\n",
+            errors.len(),
+            if errors.len() == 1 { "" } else { "s" }
+        );
+        result.push_str("═══════════════════════════════════════\n");
+
+        for (i, err) in errors.iter().enumerate().take(15) {
+            result.push_str(err);
+            result.push('\n');
+            if i < errors.len() - 1 {
+                result.push('\n');
+            }
+        }
+
+        if errors.len() > 15 {
+            result.push_str(&format!("\n... +{} more issues\n", errors.len() - 15));
+        }
+
+        return result.trim().to_string();
+    }
+
+    let warn_suffix = if warning_count > 0 {
+        format!(
+            " ({} warning{} remaining)",
+            warning_count,
+            if warning_count == 1 { "" } else { "s" }
+        )
+    } else {
+        String::new()
+    };
+
+    format!(
+        "✓ cargo fix: {} fix{} across {} file{}{}",
+        total_fixes,
+        if total_fixes == 1 { "" } else { "es" },
+        files_fixed,
+        if files_fixed == 1 { "" } else { "s" },
+        warn_suffix
+    )
+}
+
+/// Filter `cargo fmt` output down to the list of distinct files involved.
+/// In check mode, rustfmt prints a `Diff in <file> at line N:` header
+/// before each diff hunk; in write mode, `-l` (appended by [`run_fmt`])
+/// makes it print just the reformatted file paths, one per line.
+fn filter_cargo_fmt(output: &str, check_mode: bool) -> String {
+    let mut files: Vec<String> = Vec::new();
+
+    if check_mode {
+        for line in output.lines() {
+            if let Some(rest) = line.strip_prefix("Diff in ") {
+                if let Some(file) = rest.split(" at line").next() {
+                    let file = file.trim().to_string();
+                    if !files.contains(&file) {
+                        files.push(file);
+                    }
+                }
+            }
+        }
+    } else {
+        for line in output.lines() {
+            let file = line.trim();
+            if !file.is_empty() && !files.contains(&file.to_string()) {
+                files.push(file.to_string());
+            }
+        }
+    }
+
+    if files.is_empty() {
+        return "✓ cargo fmt: all formatted".to_string();
+    }
+
+    let mut result = if check_mode {
+        format!(
+            "cargo fmt: {} file{} need formatting\n",
+            files.len(),
+            if files.len() == 1 { "" } else { "s" }
+        )
+    } else {
+        format!(
+            "✓ cargo fmt: reformatted {} file{}\n",
+            files.len(),
+            if files.len() == 1 { "" } else { "s" }
+        )
+    };
+
+    for file in &files {
+        result.push_str(&format!("  {}\n", file));
+    }
+
+    result.trim().to_string()
+}
+
+/// One occurrence of an error/warning code, grouped for display.
+struct CodeGroup {
+    message: String,
+    locations: Vec<String>,
+}
+
+/// Filter cargo build/check `--message-format=json` output - group
+/// diagnostics by their `E0308`-style code the same way [`filter_cargo_clippy`]
+/// groups by lint rule, showing `E0308 (4x)` with up to three locations
+/// each. When `explain` is set, each group is followed by a one- or
+/// two-line distillation of `rustc --explain <code>`. When `fix` is set,
+/// any `MachineApplicable` suggestions are applied in place instead of just
+/// being counted as `fixable: N`. Yanked-dependency warnings ([`yanked_dep`])
+/// live outside the JSON message stream (resolver chatter on stderr), so
+/// they're scanned for separately and always kept.
+fn filter_cargo_build(output: &str, explain: bool, fix: bool) -> String {
+    let (diagnostics, compiled, _success) = parse_compiler_messages(output);
+    let yanked_lines: Vec<&str> = output.lines().filter(|l| yanked_dep(l).is_some()).collect();
+
+    let error_count = diagnostics.iter().filter(|d| d.level == "error").count();
+    let warnings = diagnostics.iter().filter(|d| d.level == "warning").count();
+
     if error_count == 0 && warnings == 0 {
-        return format!("✓ cargo build ({} crates compiled)", compiled);
+        let mut result = format!("✓ cargo build ({} crates compiled)", compiled);
+        for line in &yanked_lines {
+            result.push_str(&format!("\n{}", line.trim()));
+        }
+        return result;
+    }
+
+    let mut by_code: HashMap<String, CodeGroup> = HashMap::new();
+    for diag in diagnostics
+        .iter()
+        .filter(|d| d.level == "error" || d.level == "warning")
+    {
+        let code = diag
+            .code
+            .as_ref()
+            .map(|c| c.code.clone())
+            .unwrap_or_else(|| diag.message.clone());
+
+        let location = diag
+            .spans
+            .iter()
+            .find(|s| s.is_primary)
+            .map(|s| format!("{}:{}:{}", s.file_name, s.line_start, s.column_start))
+            .unwrap_or_default();
+
+        let group = by_code.entry(code).or_insert_with(|| CodeGroup {
+            message: diag.message.clone(),
+            locations: Vec::new(),
+        });
+        group.locations.push(location);
     }
 
     let mut result = String::new();
@@ -345,21 +974,97 @@ fn filter_cargo_build(output: &str) -> String {
     ));
     result.push_str("═══════════════════════════════════════\n");
 
-    for (i, err) in errors.iter().enumerate().take(15) {
-        result.push_str(err);
-        result.push('\n');
-        if i < errors.len() - 1 {
-            result.push('\n');
+    let mut code_counts: Vec<_> = by_code.iter().collect();
+    code_counts.sort_by(|a, b| b.1.locations.len().cmp(&a.1.locations.len()));
+
+    for (code, group) in code_counts.iter().take(15) {
+        result.push_str(&format!(
+            "  {} ({}x): {}\n",
+            code,
+            group.locations.len(),
+            group.message
+        ));
+        for loc in group.locations.iter().take(3) {
+            result.push_str(&format!("    {}\n", loc));
+        }
+        if group.locations.len() > 3 {
+            result.push_str(&format!("    ... +{} more\n", group.locations.len() - 3));
+        }
+        if explain {
+            if let Some(summary) = explain_code(code) {
+                result.push_str(&format!("    → {}\n", summary));
+            }
         }
     }
 
-    if errors.len() > 15 {
-        result.push_str(&format!("\n... +{} more issues\n", errors.len() - 15));
+    if by_code.len() > 15 {
+        result.push_str(&format!("\n... +{} more codes\n", by_code.len() - 15));
+    }
+
+    let fixes = collect_fixes(&diagnostics);
+    if !fixes.is_empty() {
+        if fix {
+            match apply_fixes(&fixes) {
+                Ok(applied) => result.push_str(&format!("\napplied {} fix(es)\n", applied)),
+                Err(e) => result.push_str(&format!("\nfailed to apply fixes: {}\n", e)),
+            }
+        } else {
+            result.push_str(&format!("\nfixable: {} (use --fix to apply)\n", fixes.len()));
+        }
+    }
+
+    for line in &yanked_lines {
+        result.push_str(&format!("{}\n", line.trim()));
     }
 
     result.trim().to_string()
 }
 
+/// Whether `code` is a `rustc --explain`-able error code (e.g. `E0308`), as
+/// opposed to a lint name like `clippy::too_many_arguments` or `dead_code`.
+fn is_explainable_code(code: &str) -> bool {
+    code.len() > 1
+        && code.starts_with('E')
+        && code[1..].chars().all(|c| c.is_ascii_digit())
+}
+
+/// Distilled `rustc --explain <code>` summary, cached per code for the
+/// lifetime of the process since the explanation for a given code never
+/// changes within one `rustc` install.
+fn explain_code(code: &str) -> Option<String> {
+    if !is_explainable_code(code) {
+        return None;
+    }
+
+    static CACHE: OnceLock<Mutex<HashMap<String, Option<String>>>> = OnceLock::new();
+    let cache = CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+
+    if let Some(cached) = cache.lock().unwrap().get(code) {
+        return cached.clone();
+    }
+
+    let summary = Command::new("rustc")
+        .args(["--explain", code])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .map(|text| distill_explanation(&text));
+
+    cache.lock().unwrap().insert(code.to_string(), summary.clone());
+    summary
+}
+
+/// Collapse a full `rustc --explain` page down to its opening paragraph.
+fn distill_explanation(text: &str) -> String {
+    let first_para: Vec<&str> = text
+        .lines()
+        .skip_while(|l| l.trim().is_empty())
+        .take_while(|l| !l.trim().is_empty())
+        .collect();
+    truncate(&first_para.join(" "), 200)
+}
+
 /// Aggregated test results for compact display
 #[derive(Debug, Default, Clone)]
 struct AggregatedTestResult {
@@ -458,14 +1163,22 @@ impl AggregatedTestResult {
     }
 }
 
-/// Filter cargo test output - show failures + summary only
+/// Filter cargo test output - show failures + summary only. Yanked-dependency
+/// warnings ([`yanked_dep`]) are always kept, appended after the summary,
+/// instead of being discarded with the rest of the build/resolver chatter.
 fn filter_cargo_test(output: &str) -> String {
     let mut failures: Vec<String> = Vec::new();
     let mut summary_lines: Vec<String> = Vec::new();
+    let mut yanked_lines: Vec<String> = Vec::new();
     let mut in_failure_section = false;
     let mut current_failure = Vec::new();
 
     for line in output.lines() {
+        if yanked_dep(line).is_some() {
+            yanked_lines.push(line.trim().to_string());
+            continue;
+        }
+
         // Skip compilation lines
         if line.trim_start().starts_with("Compiling")
             || line.trim_start().starts_with("Downloading")
@@ -510,6 +1223,13 @@ fn filter_cargo_test(output: &str) -> String {
         failures.push(current_failure.join("\n"));
     }
 
+    let append_yanked = |mut s: String| -> String {
+        for line in &yanked_lines {
+            s.push_str(&format!("\n{}", line));
+        }
+        s
+    };
+
     let mut result = String::new();
 
     if failures.is_empty() && !summary_lines.is_empty() {
@@ -534,7 +1254,7 @@ fn filter_cargo_test(output: &str) -> String {
         if all_parsed {
             if let Some(agg) = aggregated {
                 if agg.suites > 0 {
-                    return agg.format_compact();
+                    return append_yanked(agg.format_compact());
                 }
             }
         }
@@ -543,7 +1263,7 @@ fn filter_cargo_test(output: &str) -> String {
         for line in &summary_lines {
             result.push_str(&format!("✓ {}\n", line));
         }
-        return result.trim().to_string();
+        return append_yanked(result.trim().to_string());
     }
 
     if !failures.is_empty() {
@@ -573,95 +1293,197 @@ fn filter_cargo_test(output: &str) -> String {
         }
     }
 
-    result.trim().to_string()
+    append_yanked(result.trim().to_string())
 }
 
-/// Filter cargo clippy output - group warnings by lint rule
-fn filter_cargo_clippy(output: &str) -> String {
-    let mut by_rule: HashMap<String, Vec<String>> = HashMap::new();
-    let mut error_count = 0;
-    let mut warning_count = 0;
+/// One benchmark's parsed `bench:` line, kept for the slowest-N table.
+struct BenchResult {
+    name: String,
+    ns_per_iter: u64,
+    variance_ns: u64,
+}
 
-    // Parse clippy output lines
-    // Format: "warning: description\n  --> file:line:col\n  |\n  | code\n"
-    let mut current_rule = String::new();
+/// Render a nanosecond duration as `ns`/`µs`/`ms`, whichever keeps one
+/// decimal place of precision readable.
+fn format_duration_ns(ns: u64) -> String {
+    if ns >= 1_000_000 {
+        format!("{:.1}ms", ns as f64 / 1_000_000.0)
+    } else if ns >= 1_000 {
+        format!("{:.1}µs", ns as f64 / 1_000.0)
+    } else {
+        format!("{}ns", ns)
+    }
+}
+
+/// Filter cargo bench output - strip build chatter, parse each
+/// `test <name> ... bench: <ns> ns/iter (+/- <variance>)` line, and show
+/// the slowest benches plus a one-line summary. Falls back to the raw
+/// `test result:` line (prefixed with a checkmark), exactly like
+/// [`filter_cargo_test`] does, when no `bench:` line parses.
+fn filter_cargo_bench(output: &str) -> String {
+    static BENCH_RE: OnceLock<regex::Regex> = OnceLock::new();
+    let re = BENCH_RE.get_or_init(|| {
+        regex::Regex::new(r"^test (\S+)\s+\.\.\.\s+bench:\s+([\d,]+) ns/iter \(\+/-\s*([\d,]+)\)$")
+            .unwrap()
+    });
+
+    let mut benches: Vec<BenchResult> = Vec::new();
+    let mut summary_line: Option<String> = None;
 
     for line in output.lines() {
-        // Skip compilation lines
-        if line.trim_start().starts_with("Compiling")
-            || line.trim_start().starts_with("Checking")
-            || line.trim_start().starts_with("Downloading")
-            || line.trim_start().starts_with("Downloaded")
-            || line.trim_start().starts_with("Finished")
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("Compiling")
+            || trimmed.starts_with("Finished")
+            || trimmed.starts_with("Running")
+            || trimmed.starts_with("running ")
         {
             continue;
         }
 
-        // "warning: unused variable [unused_variables]" or "warning: description [clippy::rule_name]"
-        if (line.starts_with("warning:") || line.starts_with("warning["))
-            || (line.starts_with("error:") || line.starts_with("error["))
-        {
-            // Skip summary lines: "warning: `rtk` (bin) generated 5 warnings"
-            if line.contains("generated") && line.contains("warning") {
-                continue;
-            }
-            // Skip "error: aborting" / "error: could not compile"
-            if line.contains("aborting due to") || line.contains("could not compile") {
-                continue;
-            }
+        if let Some(caps) = re.captures(trimmed) {
+            let name = caps[1].to_string();
+            let ns_per_iter = caps[2].replace(',', "").parse().unwrap_or(0);
+            let variance_ns = caps[3].replace(',', "").parse().unwrap_or(0);
+            benches.push(BenchResult {
+                name,
+                ns_per_iter,
+                variance_ns,
+            });
+            continue;
+        }
 
-            let is_error = line.starts_with("error");
-            if is_error {
-                error_count += 1;
-            } else {
-                warning_count += 1;
-            }
+        if trimmed.starts_with("test result:") {
+            summary_line = Some(trimmed.to_string());
+        }
+    }
 
-            // Extract rule name from brackets
-            current_rule = if let Some(bracket_start) = line.rfind('[') {
-                if let Some(bracket_end) = line.rfind(']') {
-                    line[bracket_start + 1..bracket_end].to_string()
-                } else {
-                    line.to_string()
-                }
-            } else {
-                // No bracket: use the message itself as the rule
-                let prefix = if is_error { "error: " } else { "warning: " };
-                line.strip_prefix(prefix).unwrap_or(line).to_string()
-            };
-        } else if line.trim_start().starts_with("--> ") {
-            let location = line.trim_start().trim_start_matches("--> ").to_string();
-            if !current_rule.is_empty() {
-                by_rule
-                    .entry(current_rule.clone())
-                    .or_default()
-                    .push(location);
-            }
+    if benches.is_empty() {
+        return match summary_line {
+            Some(line) => format!("✓ {}", line),
+            None => "✓ cargo bench: no benchmarks found".to_string(),
+        };
+    }
+
+    benches.sort_by(|a, b| b.ns_per_iter.cmp(&a.ns_per_iter));
+    let slowest = &benches[0];
+
+    let mut result = format!(
+        "✓ cargo bench: {} bench{} (slowest {} {} ±{})\n",
+        benches.len(),
+        if benches.len() == 1 { "" } else { "es" },
+        slowest.name,
+        format_duration_ns(slowest.ns_per_iter),
+        format_duration_ns(slowest.variance_ns)
+    );
+    result.push_str("═══════════════════════════════════════\n");
+
+    for bench in benches.iter().take(10) {
+        result.push_str(&format!(
+            "  {:<30} {} ±{}\n",
+            bench.name,
+            format_duration_ns(bench.ns_per_iter),
+            format_duration_ns(bench.variance_ns)
+        ));
+    }
+    if benches.len() > 10 {
+        result.push_str(&format!("... +{} more\n", benches.len() - 10));
+    }
+
+    result.trim().to_string()
+}
+
+/// How many ranked lint rules to fold into the headline's `(rule ×N, ...)`
+/// parenthetical before summarizing the rest as `+N more`.
+const CLIPPY_HEADLINE_RULES: usize = 8;
+
+/// Filter cargo clippy `--message-format=json` output - group diagnostics by
+/// their real lint rule (`code.code`, e.g. `clippy::too_many_arguments`)
+/// instead of scraping it back out of the trailing `[...]` in rendered text.
+/// Large crates repeat the same lint dozens of times, so the headline carries
+/// a ranked `rule ×count` summary and each rule's own section collapses to a
+/// single representative location once it has more than one occurrence; a
+/// lint seen only once still shows that one (already full) location, so
+/// low-volume output is unaffected. When `fix` is set, any `MachineApplicable`
+/// suggestions (clippy attaches plenty of these) are applied in place instead
+/// of just being counted.
+fn filter_cargo_clippy(output: &str, fix: bool) -> String {
+    let (diagnostics, _compiled, _success) = parse_compiler_messages(output);
+
+    let mut by_rule: HashMap<String, Vec<String>> = HashMap::new();
+    let mut error_count = 0;
+    let mut warning_count = 0;
+
+    for diag in &diagnostics {
+        let is_error = match diag.level.as_str() {
+            "error" => true,
+            "warning" => false,
+            _ => continue,
+        };
+
+        if is_error {
+            error_count += 1;
+        } else {
+            warning_count += 1;
         }
+
+        let rule = diag.code.as_ref().map(|c| c.code.clone()).unwrap_or_else(|| {
+            diag.rendered
+                .as_deref()
+                .and_then(|r| r.lines().next())
+                .unwrap_or("unknown")
+                .to_string()
+        });
+
+        let location = diag
+            .spans
+            .iter()
+            .find(|s| s.is_primary)
+            .map(|s| format!("{}:{}:{}", s.file_name, s.line_start, s.column_start))
+            .unwrap_or_default();
+
+        by_rule.entry(rule).or_default().push(location);
     }
 
     if error_count == 0 && warning_count == 0 {
         return "✓ cargo clippy: No issues found".to_string();
     }
 
+    // Sort rules by frequency, most common first.
+    let mut rule_counts: Vec<_> = by_rule.iter().collect();
+    rule_counts.sort_by(|a, b| b.1.len().cmp(&a.1.len()));
+
+    let ranked: Vec<String> = rule_counts
+        .iter()
+        .take(CLIPPY_HEADLINE_RULES)
+        .map(|(rule, locations)| format!("{} ×{}", rule, locations.len()))
+        .collect();
+    let more_rules = rule_counts.len().saturating_sub(CLIPPY_HEADLINE_RULES);
+
     let mut result = String::new();
     result.push_str(&format!(
-        "cargo clippy: {} errors, {} warnings\n",
-        error_count, warning_count
+        "cargo clippy: {} errors, {} warnings ({}{})\n",
+        error_count,
+        warning_count,
+        ranked.join(", "),
+        if more_rules > 0 {
+            format!(", ... +{} more", more_rules)
+        } else {
+            String::new()
+        }
     ));
     result.push_str("═══════════════════════════════════════\n");
 
-    // Sort rules by frequency
-    let mut rule_counts: Vec<_> = by_rule.iter().collect();
-    rule_counts.sort_by(|a, b| b.1.len().cmp(&a.1.len()));
-
     for (rule, locations) in rule_counts.iter().take(15) {
-        result.push_str(&format!("  {} ({}x)\n", rule, locations.len()));
-        for loc in locations.iter().take(3) {
+        result.push_str(&format!("  {} ×{}\n", rule, locations.len()));
+        if let Some(loc) = locations.first() {
             result.push_str(&format!("    {}\n", loc));
         }
-        if locations.len() > 3 {
-            result.push_str(&format!("    ... +{} more\n", locations.len() - 3));
+        if locations.len() > 1 {
+            result.push_str(&format!(
+                "    ... +{} more occurrence(s)\n",
+                locations.len() - 1
+            ));
         }
     }
 
@@ -669,6 +1491,18 @@ fn filter_cargo_clippy(output: &str) -> String {
         result.push_str(&format!("\n... +{} more rules\n", by_rule.len() - 15));
     }
 
+    let fixes = collect_fixes(&diagnostics);
+    if !fixes.is_empty() {
+        if fix {
+            match apply_fixes(&fixes) {
+                Ok(applied) => result.push_str(&format!("\napplied {} fix(es)\n", applied)),
+                Err(e) => result.push_str(&format!("\nfailed to apply fixes: {}\n", e)),
+            }
+        } else {
+            result.push_str(&format!("\nfixable: {} (use --fix to apply)\n", fixes.len()));
+        }
+    }
+
     result.trim().to_string()
 }
 
@@ -699,36 +1533,60 @@ pub fn run_passthrough(args: &[OsString], verbose: u8) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::io::Write;
 
     #[test]
     fn test_filter_cargo_build_success() {
-        let output = r#"   Compiling libc v0.2.153
-   Compiling cfg-if v1.0.0
-   Compiling rtk v0.5.0
-    Finished dev [unoptimized + debuginfo] target(s) in 15.23s
+        let output = r#"{"reason":"compiler-artifact","package_id":"libc 0.2.153"}
+{"reason":"compiler-artifact","package_id":"cfg-if 1.0.0"}
+{"reason":"compiler-artifact","package_id":"rtk 0.5.0"}
+{"reason":"build-finished","success":true}
 "#;
-        let result = filter_cargo_build(output);
+        let result = filter_cargo_build(output, false, false);
         assert!(result.contains("✓ cargo build"));
         assert!(result.contains("3 crates compiled"));
     }
 
     #[test]
     fn test_filter_cargo_build_errors() {
-        let output = r#"   Compiling rtk v0.5.0
-error[E0308]: mismatched types
- --> src/main.rs:10:5
-  |
-10|     "hello"
-  |     ^^^^^^^ expected `i32`, found `&str`
-
-error: aborting due to 1 previous error
+        let output = r#"{"reason":"compiler-artifact","package_id":"rtk 0.5.0"}
+{"reason":"compiler-message","message":{"message":"mismatched types","level":"error","code":{"code":"E0308"},"spans":[{"file_name":"src/main.rs","line_start":10,"column_start":5,"is_primary":true}],"children":[],"rendered":"error[E0308]: mismatched types\n --> src/main.rs:10:5\n  |\n10 |     \"hello\"\n  |     ^^^^^^^ expected `i32`, found `&str`\n\n"}}
+{"reason":"build-finished","success":false}
 "#;
-        let result = filter_cargo_build(output);
+        let result = filter_cargo_build(output, false, false);
         assert!(result.contains("1 errors"));
         assert!(result.contains("E0308"));
         assert!(result.contains("mismatched types"));
     }
 
+    #[test]
+    fn test_filter_cargo_build_groups_by_code() {
+        let output = r#"{"reason":"compiler-message","message":{"message":"mismatched types","level":"error","code":{"code":"E0308"},"spans":[{"file_name":"src/a.rs","line_start":1,"column_start":1,"is_primary":true}],"children":[],"rendered":""}}
+{"reason":"compiler-message","message":{"message":"mismatched types","level":"error","code":{"code":"E0308"},"spans":[{"file_name":"src/b.rs","line_start":2,"column_start":1,"is_primary":true}],"children":[],"rendered":""}}
+{"reason":"build-finished","success":false}
+"#;
+        let result = filter_cargo_build(output, false, false);
+        assert!(result.contains("E0308 (2x)"), "got: {}", result);
+        assert!(result.contains("src/a.rs:1:1"), "got: {}", result);
+        assert!(result.contains("src/b.rs:2:1"), "got: {}", result);
+    }
+
+    #[test]
+    fn test_filter_cargo_build_keeps_yanked_warning() {
+        let output = "warning: package `foo v1.2.3` in Cargo.lock is yanked in registry `crates-io`, consider running `cargo update -p foo` to remove the yanked version\n{\"reason\":\"compiler-artifact\",\"package_id\":\"rtk 0.5.0\"}\n{\"reason\":\"build-finished\",\"success\":true}\n";
+        let result = filter_cargo_build(output, false, false);
+        assert!(result.contains("✓ cargo build"), "got: {}", result);
+        assert!(result.contains("yanked"), "got: {}", result);
+        assert!(result.contains("foo v1.2.3"), "got: {}", result);
+    }
+
+    #[test]
+    fn test_is_explainable_code() {
+        assert!(is_explainable_code("E0308"));
+        assert!(!is_explainable_code("clippy::too_many_arguments"));
+        assert!(!is_explainable_code("dead_code"));
+    }
+
     #[test]
     fn test_filter_cargo_test_all_pass() {
         let output = r#"   Compiling rtk v0.5.0
@@ -922,39 +1780,110 @@ test result: MALFORMED LINE WITHOUT PROPER FORMAT
         );
     }
 
+    #[test]
+    fn test_filter_cargo_test_keeps_yanked_warning() {
+        let output = r#"   Compiling rtk v0.5.0
+warning: package `foo v1.2.3` in Cargo.lock is yanked in registry `crates-io`, consider running `cargo update -p foo` to remove the yanked version
+    Finished test [unoptimized + debuginfo] target(s) in 1.20s
+     Running unittests src/main.rs
+
+running 2 tests
+test it_works ... ok
+test it_also_works ... ok
+
+test result: ok. 2 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.01s
+"#;
+        let result = filter_cargo_test(output);
+        assert!(result.contains("✓ cargo test"), "got: {}", result);
+        assert!(result.contains("yanked"), "got: {}", result);
+        assert!(result.contains("foo v1.2.3"), "got: {}", result);
+    }
+
+    #[test]
+    fn test_filter_cargo_bench_sorted_summary() {
+        let output = r#"   Compiling rtk v0.5.0
+    Finished bench [optimized] target(s) in 2.53s
+     Running target/debug/deps/rtk-abc123
+
+running 2 tests
+test bench_small ... bench:         120 ns/iter (+/- 10)
+test bench_parse_large ... bench:   1,234,567 ns/iter (+/- 56,000)
+
+test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 2 filtered out
+"#;
+        let result = filter_cargo_bench(output);
+        assert!(result.contains("✓ cargo bench: 2 benches"), "got: {}", result);
+        assert!(result.contains("slowest bench_parse_large"), "got: {}", result);
+        assert!(result.contains("1.2ms"), "got: {}", result);
+        assert!(!result.contains("Compiling"), "got: {}", result);
+    }
+
+    #[test]
+    fn test_filter_cargo_bench_regex_fallback() {
+        let output = r#"running 1 test
+test result: MALFORMED LINE WITHOUT PROPER FORMAT
+"#;
+        let result = filter_cargo_bench(output);
+        assert!(
+            result.contains("✓ test result: MALFORMED"),
+            "Expected fallback format, got: {}",
+            result
+        );
+    }
+
     #[test]
     fn test_filter_cargo_clippy_clean() {
-        let output = r#"    Checking rtk v0.5.0
-    Finished dev [unoptimized + debuginfo] target(s) in 1.53s
+        let output = r#"{"reason":"compiler-artifact","package_id":"rtk 0.5.0"}
+{"reason":"build-finished","success":true}
 "#;
-        let result = filter_cargo_clippy(output);
+        let result = filter_cargo_clippy(output, false);
         assert!(result.contains("✓ cargo clippy: No issues found"));
     }
 
     #[test]
     fn test_filter_cargo_clippy_warnings() {
-        let output = r#"    Checking rtk v0.5.0
-warning: unused variable: `x` [unused_variables]
- --> src/main.rs:10:9
-  |
-10|     let x = 5;
-  |         ^ help: if this is intentional, prefix it with an underscore: `_x`
-
-warning: this function has too many arguments [clippy::too_many_arguments]
- --> src/git.rs:16:1
-  |
-16| pub fn run(a: i32, b: i32, c: i32, d: i32, e: i32, f: i32, g: i32, h: i32) {}
-  |
-
-warning: `rtk` (bin) generated 2 warnings
-    Finished dev [unoptimized + debuginfo] target(s) in 1.53s
+        let output = r#"{"reason":"compiler-message","message":{"message":"unused variable: `x`","level":"warning","code":{"code":"unused_variables"},"spans":[{"file_name":"src/main.rs","line_start":10,"column_start":9,"is_primary":true}],"children":[],"rendered":"warning: unused variable: `x`\n --> src/main.rs:10:9\n  |\n10 |     let x = 5;\n  |         ^ help: if this is intentional, prefix it with an underscore: `_x`\n\n"}}
+{"reason":"compiler-message","message":{"message":"this function has too many arguments","level":"warning","code":{"code":"clippy::too_many_arguments"},"spans":[{"file_name":"src/git.rs","line_start":16,"column_start":1,"is_primary":true}],"children":[],"rendered":"warning: this function has too many arguments\n --> src/git.rs:16:1\n  |\n16 | pub fn run(a: i32, b: i32, c: i32, d: i32, e: i32, f: i32, g: i32, h: i32) {}\n  |\n\n"}}
+{"reason":"build-finished","success":true}
 "#;
-        let result = filter_cargo_clippy(output);
+        let result = filter_cargo_clippy(output, false);
         assert!(result.contains("0 errors, 2 warnings"));
         assert!(result.contains("unused_variables"));
         assert!(result.contains("clippy::too_many_arguments"));
     }
 
+    #[test]
+    fn test_filter_cargo_clippy_groups_repeated_lints() {
+        let mut messages = String::new();
+        for i in 0..9 {
+            messages.push_str(&format!(
+                "{{\"reason\":\"compiler-message\",\"message\":{{\"message\":\"unneeded `return` statement\",\"level\":\"warning\",\"code\":{{\"code\":\"clippy::needless_return\"}},\"spans\":[{{\"file_name\":\"src/a.rs\",\"line_start\":{},\"column_start\":1,\"is_primary\":true}}],\"children\":[],\"rendered\":\"\"}}}}\n",
+                i + 1
+            ));
+        }
+        for i in 0..3 {
+            messages.push_str(&format!(
+                "{{\"reason\":\"compiler-message\",\"message\":{{\"message\":\"unused variable\",\"level\":\"warning\",\"code\":{{\"code\":\"unused_variables\"}},\"spans\":[{{\"file_name\":\"src/b.rs\",\"line_start\":{},\"column_start\":1,\"is_primary\":true}}],\"children\":[],\"rendered\":\"\"}}}}\n",
+                i + 1
+            ));
+        }
+        messages.push_str("{\"reason\":\"build-finished\",\"success\":true}\n");
+
+        let result = filter_cargo_clippy(&messages, false);
+        assert!(result.contains("0 errors, 12 warnings"), "got: {}", result);
+        assert!(
+            result.contains("clippy::needless_return ×9"),
+            "got: {}",
+            result
+        );
+        assert!(result.contains("unused_variables ×3"), "got: {}", result);
+        assert!(
+            result.contains("... +8 more occurrence(s)"),
+            "repeated lint should collapse to one representative location: {}",
+            result
+        );
+    }
+
     #[test]
     fn test_filter_cargo_install_success() {
         let output = r#"  Installing rtk v0.11.0
@@ -970,11 +1899,11 @@ warning: `rtk` (bin) generated 2 warnings
   Replacing /Users/user/.cargo/bin/rtk
    Replaced package `rtk v0.9.4` with `rtk v0.11.0` (/Users/user/.cargo/bin/rtk)
 "#;
-        let result = filter_cargo_install(output);
+        let result = filter_cargo_install(output, false);
         assert!(result.contains("✓ cargo install"), "got: {}", result);
-        assert!(result.contains("rtk v0.11.0"), "got: {}", result);
+        assert!(result.contains("upgraded rtk v0.9.4 → v0.11.0"), "got: {}", result);
         assert!(result.contains("5 deps compiled"), "got: {}", result);
-        assert!(result.contains("Replaced"), "got: {}", result);
+        assert!(!result.contains("Replaced package"), "got: {}", result);
         assert!(!result.contains("Compiling"), "got: {}", result);
         assert!(!result.contains("Downloading"), "got: {}", result);
     }
@@ -987,10 +1916,34 @@ warning: `rtk` (bin) generated 2 warnings
   Replacing /Users/user/.cargo/bin/rtk
    Replaced package `rtk v0.9.4` with `rtk v0.11.0` (/Users/user/.cargo/bin/rtk)
 "#;
-        let result = filter_cargo_install(output);
+        let result = filter_cargo_install(output, false);
         assert!(result.contains("✓ cargo install"), "got: {}", result);
-        assert!(result.contains("Replacing"), "got: {}", result);
-        assert!(result.contains("Replaced"), "got: {}", result);
+        assert!(result.contains("upgraded rtk v0.9.4 → v0.11.0"), "got: {}", result);
+        assert!(!result.contains("Replacing"), "got: {}", result);
+        assert!(!result.contains("Replaced package"), "got: {}", result);
+    }
+
+    #[test]
+    fn test_filter_cargo_install_fresh() {
+        let output = r#"  Installing rtk v0.11.0
+   Compiling rtk v0.11.0
+    Finished `release` profile [optimized] target(s) in 10.0s
+"#;
+        let result = filter_cargo_install(output, false);
+        assert!(result.contains("✓ cargo install: installed rtk v0.11.0"), "got: {}", result);
+        assert!(!result.contains("untracked"), "got: {}", result);
+        assert!(!result.contains("upgraded"), "got: {}", result);
+    }
+
+    #[test]
+    fn test_filter_cargo_install_no_track() {
+        let output = r#"  Installing rtk v0.11.0
+   Compiling rtk v0.11.0
+    Finished `release` profile [optimized] target(s) in 10.0s
+"#;
+        let result = filter_cargo_install(output, true);
+        assert!(result.contains("✓ cargo install: installed rtk v0.11.0"), "got: {}", result);
+        assert!(result.contains("(untracked)"), "got: {}", result);
     }
 
     #[test]
@@ -1005,7 +1958,7 @@ error[E0308]: mismatched types
 
 error: aborting due to 1 previous error
 "#;
-        let result = filter_cargo_install(output);
+        let result = filter_cargo_install(output, false);
         assert!(result.contains("cargo install: 1 error"), "got: {}", result);
         assert!(result.contains("E0308"), "got: {}", result);
         assert!(result.contains("mismatched types"), "got: {}", result);
@@ -1016,7 +1969,7 @@ error: aborting due to 1 previous error
     fn test_filter_cargo_install_already_installed() {
         let output = r#"  Ignored package `rtk v0.11.0`, is already installed
 "#;
-        let result = filter_cargo_install(output);
+        let result = filter_cargo_install(output, false);
         assert!(result.contains("already installed"), "got: {}", result);
         assert!(result.contains("rtk v0.11.0"), "got: {}", result);
     }
@@ -1025,14 +1978,14 @@ error: aborting due to 1 previous error
     fn test_filter_cargo_install_up_to_date() {
         let output = r#"  Ignored package `cargo-deb v2.1.0 (/Users/user/cargo-deb)`, is already installed
 "#;
-        let result = filter_cargo_install(output);
+        let result = filter_cargo_install(output, false);
         assert!(result.contains("already installed"), "got: {}", result);
         assert!(result.contains("cargo-deb v2.1.0"), "got: {}", result);
     }
 
     #[test]
     fn test_filter_cargo_install_empty_output() {
-        let result = filter_cargo_install("");
+        let result = filter_cargo_install("", false);
         assert!(result.contains("✓ cargo install"), "got: {}", result);
         assert!(result.contains("0 deps compiled"), "got: {}", result);
     }
@@ -1046,14 +1999,30 @@ error: aborting due to 1 previous error
    Replaced package `rtk v0.9.4` with `rtk v0.11.0` (/Users/user/.cargo/bin/rtk)
 warning: be sure to add `/Users/user/.cargo/bin` to your PATH
 "#;
-        let result = filter_cargo_install(output);
+        let result = filter_cargo_install(output, false);
         assert!(result.contains("✓ cargo install"), "got: {}", result);
         assert!(
             result.contains("be sure to add"),
             "PATH warning should be kept: {}",
             result
         );
-        assert!(result.contains("Replaced"), "got: {}", result);
+        assert!(result.contains("upgraded rtk v0.9.4 → v0.11.0"), "got: {}", result);
+    }
+
+    #[test]
+    fn test_filter_cargo_install_yanked_warning() {
+        let output = r#"  Installing rtk v0.11.0
+warning: package `foo v1.2.3` in Cargo.lock is yanked in registry `crates-io`, consider running `cargo update -p foo` to remove the yanked version
+   Compiling rtk v0.11.0
+    Finished `release` profile [optimized] target(s) in 10.0s
+"#;
+        let result = filter_cargo_install(output, false);
+        assert!(
+            result.contains("⚠ 1 yanked dep"),
+            "yanked dep should be counted in headline: {}",
+            result
+        );
+        assert!(result.contains("foo v1.2.3"), "got: {}", result);
     }
 
     #[test]
@@ -1074,7 +2043,7 @@ error[E0425]: cannot find value `foo`
 
 error: aborting due to 2 previous errors
 "#;
-        let result = filter_cargo_install(output);
+        let result = filter_cargo_install(output, false);
         assert!(
             result.contains("2 errors"),
             "should show 2 errors: {}",
@@ -1096,7 +2065,7 @@ error: aborting due to 2 previous errors
     Finished `release` profile [optimized] target(s) in 30.0s
   Installing rtk v0.11.0
 "#;
-        let result = filter_cargo_install(output);
+        let result = filter_cargo_install(output, false);
         assert!(result.contains("✓ cargo install"), "got: {}", result);
         assert!(!result.contains("Locking"), "got: {}", result);
         assert!(!result.contains("Blocking"), "got: {}", result);
@@ -1109,12 +2078,57 @@ error: aborting due to 2 previous errors
    Compiling rtk v0.11.0
     Finished `release` profile [optimized] target(s) in 10.0s
 "#;
-        let result = filter_cargo_install(output);
+        let result = filter_cargo_install(output, false);
         // Path-based install: crate info not extracted from path
         assert!(result.contains("✓ cargo install"), "got: {}", result);
         assert!(result.contains("1 deps compiled"), "got: {}", result);
     }
 
+    #[test]
+    fn test_filter_cargo_fix_success() {
+        let output = r#"    Checking rtk v0.5.0
+        Fixing src/lib.rs (3 fixes)
+        Fixing src/main.rs (4 fixes)
+    Finished dev [unoptimized + debuginfo] target(s) in 1.20s
+warning: unused import: `foo`
+ --> src/lib.rs:1:5
+  |
+warning: `rtk` generated 2 warnings
+"#;
+        let result = filter_cargo_fix(output);
+        assert!(
+            result.contains("✓ cargo fix: 7 fixes across 2 files"),
+            "got: {}",
+            result
+        );
+        assert!(result.contains("1 warning remaining"), "got: {}", result);
+        assert!(!result.contains("Checking"), "got: {}", result);
+    }
+
+    #[test]
+    fn test_filter_cargo_fix_broken_build_falls_back_to_errors() {
+        let output = r#"    Checking rtk v0.5.0
+        Fixing src/lib.rs (1 fix)
+error[E0308]: mismatched types
+ --> src/lib.rs:10:5
+  |
+10 |     "hello"
+  |     ^^^^^^^ expected `i32`, found `&str`
+
+error: failed to automatically apply fixes suggested by rustc to crate `rtk`
+
+after fixes were automatically applied the compiler reported errors
+within these files, which likely indicates a bug in either rustc
+or cargo itself:
+
+To attempt to fix the code anyway, pass `--broken-code`
+"#;
+        let result = filter_cargo_fix(output);
+        assert!(result.contains("build broke"), "got: {}", result);
+        assert!(result.contains("E0308"), "got: {}", result);
+        assert!(result.contains("mismatched types"), "got: {}", result);
+    }
+
     #[test]
     fn test_format_crate_info() {
         assert_eq!(format_crate_info("rtk", "v0.11.0", ""), "rtk v0.11.0");
@@ -1122,4 +2136,156 @@ error: aborting due to 2 previous errors
         assert_eq!(format_crate_info("", "", "package"), "package");
         assert_eq!(format_crate_info("", "v0.1.0", "fallback"), "fallback");
     }
+
+    #[test]
+    fn test_parse_compiler_messages_dedupes_repeats() {
+        let output = r#"{"reason":"compiler-message","message":{"message":"mismatched types","level":"error","code":{"code":"E0308"},"spans":[{"file_name":"src/main.rs","line_start":10,"column_start":5,"is_primary":true}],"children":[],"rendered":"error[E0308]: mismatched types\n"}}
+{"reason":"compiler-message","message":{"message":"mismatched types","level":"error","code":{"code":"E0308"},"spans":[{"file_name":"src/main.rs","line_start":10,"column_start":5,"is_primary":true}],"children":[],"rendered":"error[E0308]: mismatched types\n"}}
+not json at all
+{"reason":"compiler-artifact","package_id":"rtk 0.5.0"}
+{"reason":"build-finished","success":false}
+"#;
+        let (diagnostics, compiled, success) = parse_compiler_messages(output);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(compiled, 1);
+        assert_eq!(success, Some(false));
+    }
+
+    #[test]
+    fn test_diagnostic_to_envelope_rows_uses_primary_span_and_code() {
+        let output = r#"{"reason":"compiler-message","message":{"message":"mismatched types","level":"error","code":{"code":"E0308"},"spans":[{"file_name":"src/main.rs","line_start":10,"column_start":5,"is_primary":true}],"children":[],"rendered":""}}
+"#;
+        let (diagnostics, _, _) = parse_compiler_messages(output);
+        let rows = diagnostic_to_envelope_rows(&diagnostics[0]);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].file, "src/main.rs");
+        assert_eq!(rows[0].line, 10);
+        assert_eq!(rows[0].severity, "error");
+        assert_eq!(rows[0].rule.as_deref(), Some("E0308"));
+    }
+
+    #[test]
+    fn test_diagnostic_to_envelope_rows_falls_back_without_primary_span() {
+        let diag = RustcDiagnostic {
+            message: "crate-level lint".to_string(),
+            level: "warning".to_string(),
+            code: None,
+            spans: Vec::new(),
+            rendered: None,
+            children: Vec::new(),
+        };
+        let rows = diagnostic_to_envelope_rows(&diag);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].file, "<unknown>");
+        assert_eq!(rows[0].rule, None);
+    }
+
+    #[test]
+    fn test_collect_fixes_machine_applicable_only() {
+        let output = r#"{"reason":"compiler-message","message":{"message":"unused import","level":"warning","code":{"code":"unused_imports"},"spans":[{"file_name":"src/main.rs","line_start":1,"column_start":1,"is_primary":true}],"children":[{"spans":[{"file_name":"src/main.rs","line_start":1,"column_start":1,"is_primary":true,"byte_start":0,"byte_end":10,"suggested_replacement":"","suggestion_applicability":"MachineApplicable"}]}],"rendered":""}}
+{"reason":"compiler-message","message":{"message":"consider this","level":"warning","code":{"code":"unused_mut"},"spans":[{"file_name":"src/lib.rs","line_start":2,"column_start":1,"is_primary":true}],"children":[{"spans":[{"file_name":"src/lib.rs","line_start":2,"column_start":1,"is_primary":true,"byte_start":5,"byte_end":8,"suggested_replacement":"x","suggestion_applicability":"MaybeIncorrect"}]}],"rendered":""}}
+"#;
+        let (diagnostics, _, _) = parse_compiler_messages(output);
+        let fixes = collect_fixes(&diagnostics);
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(fixes[0].file, "src/main.rs");
+        assert_eq!(fixes[0].byte_start, 0);
+        assert_eq!(fixes[0].byte_end, 10);
+    }
+
+    #[test]
+    fn test_apply_fixes_splices_replacement() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(file, "let mut x = 5;").unwrap();
+        let path = file.path().to_str().unwrap().to_string();
+
+        let edits = vec![FixEdit {
+            file: path.clone(),
+            byte_start: 4,
+            byte_end: 8,
+            replacement: String::new(),
+        }];
+
+        let applied = apply_fixes(&edits).unwrap();
+        assert_eq!(applied, 1);
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert_eq!(content, "let x = 5;");
+    }
+
+    #[test]
+    fn test_apply_fixes_skips_overlapping_edits() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(file, "abcdefgh").unwrap();
+        let path = file.path().to_str().unwrap().to_string();
+
+        // Two edits over the same range: only the later (closer to EOF,
+        // processed first in descending-offset order) one should apply.
+        let edits = vec![
+            FixEdit {
+                file: path.clone(),
+                byte_start: 2,
+                byte_end: 5,
+                replacement: "XY".to_string(),
+            },
+            FixEdit {
+                file: path.clone(),
+                byte_start: 3,
+                byte_end: 6,
+                replacement: "ZZ".to_string(),
+            },
+        ];
+
+        let applied = apply_fixes(&edits).unwrap();
+        assert_eq!(applied, 1);
+
+        let content = fs::read_to_string(&path).unwrap();
+        // The byte_start=3 edit sorts first (descending), applies "ZZ"; the
+        // byte_start=2 edit overlaps it (byte_end=5 > applied_from=3) and is skipped.
+        assert_eq!(content, "abZZfgh");
+    }
+
+    #[test]
+    fn test_filter_cargo_fmt_check_clean() {
+        let result = filter_cargo_fmt("", true);
+        assert_eq!(result, "✓ cargo fmt: all formatted");
+    }
+
+    #[test]
+    fn test_filter_cargo_fmt_check_needs_formatting() {
+        let output = r#"Diff in /repo/src/main.rs at line 10:
+-fn foo(){
++fn foo() {
+Diff in /repo/src/lib.rs at line 3:
+-    let x=1;
++    let x = 1;
+"#;
+        let result = filter_cargo_fmt(output, true);
+        assert!(result.contains("2 files need formatting"), "got: {}", result);
+        assert!(result.contains("/repo/src/main.rs"), "got: {}", result);
+        assert!(result.contains("/repo/src/lib.rs"), "got: {}", result);
+    }
+
+    #[test]
+    fn test_filter_cargo_fmt_write_mode_reports_count() {
+        let output = "src/main.rs\nsrc/lib.rs\n";
+        let result = filter_cargo_fmt(output, false);
+        assert!(result.contains("✓ cargo fmt: reformatted 2 files"), "got: {}", result);
+        assert!(result.contains("src/main.rs"), "got: {}", result);
+    }
+
+    #[test]
+    fn test_filter_cargo_fmt_write_mode_clean() {
+        let result = filter_cargo_fmt("   \n", false);
+        assert_eq!(result, "✓ cargo fmt: all formatted");
+    }
+
+    #[test]
+    fn test_filter_cargo_build_reports_fixable() {
+        let output = r#"{"reason":"compiler-message","message":{"message":"unused import","level":"warning","code":{"code":"unused_imports"},"spans":[{"file_name":"src/main.rs","line_start":1,"column_start":1,"is_primary":true}],"children":[{"spans":[{"file_name":"src/main.rs","line_start":1,"column_start":1,"is_primary":true,"byte_start":0,"byte_end":10,"suggested_replacement":"","suggestion_applicability":"MachineApplicable"}]}],"rendered":""}}
+{"reason":"build-finished","success":true}
+"#;
+        let result = filter_cargo_build(output, false, false);
+        assert!(result.contains("fixable: 1"), "got: {}", result);
+    }
 }
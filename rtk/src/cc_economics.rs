@@ -5,10 +5,11 @@
 
 use anyhow::{Context, Result};
 use chrono::NaiveDate;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use crate::ccusage::{self, CcusagePeriod, Granularity};
+use crate::economics_cache;
 use crate::tracking::{DayStats, MonthStats, Tracker, WeekStats};
 use crate::utils::{format_cpt, format_tokens, format_usd};
 
@@ -22,9 +23,24 @@ const WEIGHT_OUTPUT: f64 = 5.0; // Output = 5x input
 const WEIGHT_CACHE_CREATE: f64 = 1.25; // Cache write = 1.25x input
 const WEIGHT_CACHE_READ: f64 = 0.1; // Cache read = 0.1x input
 
+/// Default window, in periods, for [`apply_moving_averages`]'s rolling
+/// cost-per-token smoothing -- a week of daily periods.
+const DEFAULT_MA_WINDOW: usize = 7;
+
+/// `input + 5*output + 1.25*cache_create + 0.1*cache_read`, the same API
+/// price ratios [`PeriodEconomics::compute_weighted_metrics`] uses, pulled
+/// out so [`apply_moving_averages`] can derive the same denominator from
+/// raw token counts without duplicating the weights.
+fn weighted_units(input: u64, output: u64, cache_create: u64, cache_read: u64) -> f64 {
+    input as f64
+        + WEIGHT_OUTPUT * output as f64
+        + WEIGHT_CACHE_CREATE * cache_create as f64
+        + WEIGHT_CACHE_READ * cache_read as f64
+}
+
 // ── Types ──
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PeriodEconomics {
     pub label: String,
     // ccusage metrics (Option for graceful degradation)
@@ -48,10 +64,17 @@ pub struct PeriodEconomics {
     pub active_cpt: Option<f64>,  // cost / active_tokens (OVERESTIMATES)
     pub savings_blended: Option<f64>, // saved * blended_cpt (UNDERESTIMATES)
     pub savings_active: Option<f64>, // saved * active_cpt (OVERESTIMATES)
+    // Rolling weighted moving averages (see `apply_moving_averages`), smoothing
+    // period-to-period CPT noise by summing cost/units across the window
+    // instead of averaging already-divided ratios.
+    pub blended_cpt_ma: Option<f64>,
+    pub active_cpt_ma: Option<f64>,
+    pub weighted_input_cpt_ma: Option<f64>,
+    pub savings_weighted_ma: Option<f64>,
 }
 
 impl PeriodEconomics {
-    fn new(label: &str) -> Self {
+    pub(crate) fn new(label: &str) -> Self {
         Self {
             label: label.to_string(),
             cc_cost: None,
@@ -70,6 +93,10 @@ impl PeriodEconomics {
             active_cpt: None,
             savings_blended: None,
             savings_active: None,
+            blended_cpt_ma: None,
+            active_cpt_ma: None,
+            weighted_input_cpt_ma: None,
+            savings_weighted_ma: None,
         }
     }
 
@@ -121,14 +148,10 @@ impl PeriodEconomics {
                 self.cc_cache_create_tokens,
                 self.cc_cache_read_tokens,
             ) {
-                // Weighted units = input + 5*output + 1.25*cache_create + 0.1*cache_read
-                let weighted_units = input as f64
-                    + WEIGHT_OUTPUT * output as f64
-                    + WEIGHT_CACHE_CREATE * cache_create as f64
-                    + WEIGHT_CACHE_READ * cache_read as f64;
-
-                if weighted_units > 0.0 {
-                    let input_cpt = cost / weighted_units;
+                let units = weighted_units(input, output, cache_create, cache_read);
+
+                if units > 0.0 {
+                    let input_cpt = cost / units;
                     let savings = saved as f64 * input_cpt;
 
                     self.weighted_input_cpt = Some(input_cpt);
@@ -177,6 +200,11 @@ struct Totals {
     active_cpt: Option<f64>,
     savings_blended: Option<f64>,
     savings_active: Option<f64>,
+    xirr: Option<f64>, // money-weighted annualized return across the actual reporting dates
+    /// Set by the caller when these totals were computed from periods
+    /// served out of the economics snapshot cache (see `load_cached_periods`)
+    /// because `ccusage::fetch` failed, rather than from a fresh fetch.
+    stale: bool,
 }
 
 // ── Public API ──
@@ -188,13 +216,15 @@ pub fn run(
     all: bool,
     format: &str,
     verbose: u8,
+    no_cache: bool,
 ) -> Result<()> {
     let tracker = Tracker::new().context("Failed to initialize tracking database")?;
 
     match format {
-        "json" => export_json(&tracker, daily, weekly, monthly, all),
-        "csv" => export_csv(&tracker, daily, weekly, monthly, all),
-        _ => display_text(&tracker, daily, weekly, monthly, all, verbose),
+        "json" => export_json(&tracker, daily, weekly, monthly, all, no_cache),
+        "csv" => export_csv(&tracker, daily, weekly, monthly, all, no_cache),
+        "ndjson" => export_ndjson(&tracker, daily, weekly, monthly, all, no_cache),
+        _ => display_text(&tracker, daily, weekly, monthly, all, verbose, no_cache),
     }
 }
 
@@ -227,6 +257,7 @@ fn merge_daily(cc: Option<Vec<CcusagePeriod>>, rtk: Vec<DayStats>) -> Vec<Period
         period.compute_dual_metrics();
     }
     result.sort_by(|a, b| a.label.cmp(&b.label));
+    apply_moving_averages(&mut result, DEFAULT_MA_WINDOW);
     result
 }
 
@@ -265,6 +296,7 @@ fn merge_weekly(cc: Option<Vec<CcusagePeriod>>, rtk: Vec<WeekStats>) -> Vec<Peri
         period.compute_dual_metrics();
     }
     result.sort_by(|a, b| a.label.cmp(&b.label));
+    apply_moving_averages(&mut result, DEFAULT_MA_WINDOW);
     result
 }
 
@@ -294,9 +326,255 @@ fn merge_monthly(cc: Option<Vec<CcusagePeriod>>, rtk: Vec<MonthStats>) -> Vec<Pe
         period.compute_dual_metrics();
     }
     result.sort_by(|a, b| a.label.cmp(&b.label));
+    apply_moving_averages(&mut result, DEFAULT_MA_WINDOW);
+    result
+}
+
+// ── Snapshot cache ──
+
+/// Periods older than the last two entries in a cached, date-sorted
+/// snapshot are "closed" -- ccusage totals for them won't change anymore --
+/// so only the last two plus anything newer need refetching. Returns
+/// `None` when the cache doesn't have enough history to bother (a fresh
+/// cache, or one with fewer than two periods), meaning the caller should do
+/// a full fetch instead of a narrow one.
+fn incremental_since(cached: &[PeriodEconomics]) -> Option<NaiveDate> {
+    let cutoff_label = &cached.get(cached.len().checked_sub(2)?)?.label;
+    parse_period_date(cutoff_label)
+}
+
+/// Merge a cached snapshot with freshly fetched/merged periods: entries
+/// from `cached` that are older than `cutoff` (closed, immutable) are kept
+/// as-is; `fresh` (the recomputed current/previous periods, plus anything
+/// new) always wins for its own labels. The moving averages are then
+/// recomputed over the full spliced timeline so the smoothing stays
+/// continuous across the cache boundary.
+fn splice_cached_periods(
+    cached: Vec<PeriodEconomics>,
+    fresh: Vec<PeriodEconomics>,
+    cutoff: &str,
+) -> Vec<PeriodEconomics> {
+    let mut map: HashMap<String, PeriodEconomics> = HashMap::new();
+    for p in cached {
+        if p.label.as_str() < cutoff {
+            map.insert(p.label.clone(), p);
+        }
+    }
+    for p in fresh {
+        map.insert(p.label.clone(), p);
+    }
+
+    let mut result: Vec<_> = map.into_values().collect();
+    result.sort_by(|a, b| a.label.cmp(&b.label));
+    apply_moving_averages(&mut result, DEFAULT_MA_WINDOW);
     result
 }
 
+/// Load periods for `granularity`, preferring the on-disk snapshot for
+/// closed periods and only asking ccusage for the current/previous period
+/// onward (see `incremental_since`). `fetch_and_merge` does a full
+/// `ccusage::fetch` + merge when called with `None`, or a narrow
+/// `fetch_with_query(since=..)` + merge when called with `Some(since)`.
+/// Returns `(periods, stale)`; `stale` is `true` only when ccusage failed
+/// outright and the result was served entirely from the last good
+/// snapshot -- with no snapshot to fall back on, the error propagates as
+/// it always has.
+fn load_cached_periods(
+    granularity: Granularity,
+    no_cache: bool,
+    fetch_and_merge: impl FnOnce(Option<NaiveDate>) -> Result<Vec<PeriodEconomics>>,
+) -> Result<(Vec<PeriodEconomics>, bool)> {
+    if no_cache {
+        let periods = fetch_and_merge(None)?;
+        let _ = economics_cache::save(granularity, &periods);
+        return Ok((periods, false));
+    }
+
+    let cached = economics_cache::load(granularity).map(|s| s.periods);
+    let since = cached.as_deref().and_then(incremental_since);
+
+    match fetch_and_merge(since) {
+        Ok(fresh) => {
+            let periods = match (cached, since) {
+                (Some(cached), Some(_)) => {
+                    let cutoff = cached[cached.len() - 2].label.clone();
+                    splice_cached_periods(cached, fresh, &cutoff)
+                }
+                _ => fresh,
+            };
+            let _ = economics_cache::save(granularity, &periods);
+            Ok((periods, false))
+        }
+        Err(e) => match cached {
+            Some(periods) => {
+                eprintln!(
+                    "⚠️  ccusage fetch failed ({}), serving last cached economics snapshot",
+                    e
+                );
+                Ok((periods, true))
+            }
+            None => Err(e),
+        },
+    }
+}
+
+fn load_daily_periods(tracker: &Tracker, no_cache: bool) -> Result<(Vec<PeriodEconomics>, bool)> {
+    let rtk_daily = tracker
+        .get_all_days()
+        .context("Failed to load daily token savings from database")?;
+
+    load_cached_periods(Granularity::Daily, no_cache, |since| {
+        let cc = match since {
+            Some(since) => ccusage::fetch_with_query(
+                Granularity::Daily,
+                &ccusage::CcusageQuery {
+                    since: since.format("%Y%m%d").to_string(),
+                    ..Default::default()
+                },
+            ),
+            None => ccusage::fetch(Granularity::Daily),
+        }
+        .context("Failed to fetch ccusage daily data")?;
+        Ok(merge_daily(cc, rtk_daily))
+    })
+}
+
+fn load_weekly_periods(tracker: &Tracker, no_cache: bool) -> Result<(Vec<PeriodEconomics>, bool)> {
+    let rtk_weekly = tracker
+        .get_by_week()
+        .context("Failed to load weekly token savings from database")?;
+
+    load_cached_periods(Granularity::Weekly, no_cache, |since| {
+        let cc = match since {
+            Some(since) => ccusage::fetch_with_query(
+                Granularity::Weekly,
+                &ccusage::CcusageQuery {
+                    since: since.format("%Y%m%d").to_string(),
+                    ..Default::default()
+                },
+            ),
+            None => ccusage::fetch(Granularity::Weekly),
+        }
+        .context("Failed to fetch ccusage weekly data")?;
+        Ok(merge_weekly(cc, rtk_weekly))
+    })
+}
+
+fn load_monthly_periods(tracker: &Tracker, no_cache: bool) -> Result<(Vec<PeriodEconomics>, bool)> {
+    let rtk_monthly = tracker
+        .get_by_month()
+        .context("Failed to load monthly token savings from database")?;
+
+    load_cached_periods(Granularity::Monthly, no_cache, |since| {
+        let cc = match since {
+            Some(since) => ccusage::fetch_with_query(
+                Granularity::Monthly,
+                &ccusage::CcusageQuery {
+                    since: since.format("%Y%m%d").to_string(),
+                    ..Default::default()
+                },
+            ),
+            None => ccusage::fetch(Granularity::Monthly),
+        }
+        .context("Failed to fetch ccusage monthly data")?;
+        Ok(merge_monthly(cc, rtk_monthly))
+    })
+}
+
+// ── Rolling moving averages ──
+
+/// A `Σnumerator / Σdenominator` ratio over the trailing `window` periods,
+/// maintained with running sums instead of re-summing a ring buffer each
+/// period -- folding in one period and evicting the oldest once full are
+/// both O(1).
+struct RollingRatio {
+    window: usize,
+    buf: std::collections::VecDeque<(f64, f64)>,
+    num_sum: f64,
+    den_sum: f64,
+}
+
+impl RollingRatio {
+    fn new(window: usize) -> Self {
+        Self {
+            window,
+            buf: std::collections::VecDeque::with_capacity(window),
+            num_sum: 0.0,
+            den_sum: 0.0,
+        }
+    }
+
+    /// Fold one period's `(numerator, denominator)` into the window and
+    /// return the resulting ratio. A non-positive `denominator` is skipped
+    /// entirely -- it never enters the buffer or the running sums -- so a
+    /// period with no ccusage data doesn't drag the average toward zero.
+    /// Partial windows (fewer than `window` valid periods seen so far)
+    /// average over whatever is available rather than waiting for a full
+    /// window.
+    fn push(&mut self, numerator: f64, denominator: f64) -> Option<f64> {
+        if denominator > 0.0 {
+            self.buf.push_back((numerator, denominator));
+            self.num_sum += numerator;
+            self.den_sum += denominator;
+
+            while self.buf.len() > self.window {
+                if let Some((n, d)) = self.buf.pop_front() {
+                    self.num_sum -= n;
+                    self.den_sum -= d;
+                }
+            }
+        }
+
+        if self.den_sum > 0.0 {
+            Some(self.num_sum / self.den_sum)
+        } else {
+            None
+        }
+    }
+}
+
+/// Smooth `blended_cpt`, `active_cpt`, and `weighted_input_cpt` into
+/// `_ma` fields with a trailing `window`-period rolling `Σcost / Σunits`
+/// (see [`RollingRatio`]) instead of a naive mean of already-divided
+/// ratios, which would over-weight low-volume periods. `periods` must
+/// already be date-sorted (as `merge_daily`/`merge_weekly`/`merge_monthly`
+/// leave it) since this is a single forward pass.
+///
+/// `savings_weighted_ma` isn't itself a cost/unit ratio, so it's derived
+/// by applying that period's own smoothed `weighted_input_cpt_ma` to its
+/// *actual* `rtk_saved_tokens` -- smoothing the volatile price while still
+/// reflecting the period's real savings volume.
+fn apply_moving_averages(periods: &mut [PeriodEconomics], window: usize) {
+    let mut blended = RollingRatio::new(window);
+    let mut active = RollingRatio::new(window);
+    let mut weighted = RollingRatio::new(window);
+
+    for p in periods.iter_mut() {
+        let cost = p.cc_cost.unwrap_or(0.0);
+
+        p.blended_cpt_ma = blended.push(cost, p.cc_total_tokens.unwrap_or(0) as f64);
+        p.active_cpt_ma = active.push(cost, p.cc_active_tokens.unwrap_or(0) as f64);
+
+        let units = match (
+            p.cc_input_tokens,
+            p.cc_output_tokens,
+            p.cc_cache_create_tokens,
+            p.cc_cache_read_tokens,
+        ) {
+            (Some(input), Some(output), Some(cache_create), Some(cache_read)) => {
+                weighted_units(input, output, cache_create, cache_read)
+            }
+            _ => 0.0,
+        };
+        let weighted_ma = weighted.push(cost, units);
+        p.weighted_input_cpt_ma = weighted_ma;
+        p.savings_weighted_ma = match (weighted_ma, p.rtk_saved_tokens) {
+            (Some(cpt), Some(saved)) => Some(saved as f64 * cpt),
+            _ => None,
+        };
+    }
+}
+
 // ── Helpers ──
 
 /// Convert Saturday week_start (legacy rtk) to ISO Monday
@@ -311,6 +589,114 @@ fn convert_saturday_to_monday(saturday: &str) -> Option<String> {
     Some(monday.format("%Y-%m-%d").to_string())
 }
 
+/// Parse a period label into a calendar date: daily/weekly labels are
+/// already `%Y-%m-%d` (the weekly one post-[`convert_saturday_to_monday`]),
+/// monthly labels are `%Y-%m` and get anchored to the 1st.
+fn parse_period_date(label: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(label, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(&format!("{}-01", label), "%Y-%m-%d"))
+        .ok()
+}
+
+/// Money-weighted annualized return (XIRR) across `periods`: each period's
+/// `cc_cost` is a dated outflow and its `savings_weighted` a dated inflow,
+/// solved over the actual calendar dates rather than assuming equal
+/// spacing between periods. `None` when fewer than two flows carry a
+/// parseable date, or when the amounts never change sign (no discount
+/// rate makes the cashflows balance).
+///
+/// Solved with Newton-Raphson from an initial guess of `r = 0.1`, falling
+/// back to bisection on `[-0.999999, 1e6]` if Newton fails to converge in
+/// ~100 iterations or its derivative goes flat.
+fn compute_xirr(periods: &[PeriodEconomics]) -> Option<f64> {
+    let mut flows: Vec<(NaiveDate, f64)> = Vec::new();
+    for p in periods {
+        let Some(date) = parse_period_date(&p.label) else {
+            continue;
+        };
+        if let Some(cost) = p.cc_cost {
+            flows.push((date, -cost));
+        }
+        if let Some(savings) = p.savings_weighted {
+            flows.push((date, savings));
+        }
+    }
+
+    if flows.len() < 2 {
+        return None;
+    }
+    let has_positive = flows.iter().any(|(_, amount)| *amount > 0.0);
+    let has_negative = flows.iter().any(|(_, amount)| *amount < 0.0);
+    if !has_positive || !has_negative {
+        return None;
+    }
+
+    let t0 = flows.iter().map(|(date, _)| *date).min()?;
+    let dated: Vec<(f64, f64)> = flows
+        .iter()
+        .map(|(date, amount)| ((*date - t0).num_days() as f64 / 365.0, *amount))
+        .collect();
+
+    let npv = |r: f64| -> f64 { dated.iter().map(|(t, amount)| amount * (1.0 + r).powf(-t)).sum() };
+    let npv_derivative = |r: f64| -> f64 {
+        dated
+            .iter()
+            .map(|(t, amount)| -t * amount * (1.0 + r).powf(-t - 1.0))
+            .sum()
+    };
+
+    let mut r = 0.1;
+    for _ in 0..100 {
+        let value = npv(r);
+        if value.abs() < 1e-6 {
+            return Some(r);
+        }
+        let slope = npv_derivative(r);
+        if slope.abs() < 1e-12 {
+            break;
+        }
+        let next_r = r - value / slope;
+        if !next_r.is_finite() || next_r <= -1.0 {
+            break;
+        }
+        r = next_r;
+    }
+
+    bisect_xirr(&dated)
+}
+
+/// Bisection fallback for [`compute_xirr`] when Newton-Raphson diverges:
+/// halves the bracket `[-0.999999, 1e6]` until the NPV is within `1e-6` of
+/// zero or the bracket is exhausted, returning its midpoint either way.
+/// `None` when the bracket's endpoints don't straddle a root.
+fn bisect_xirr(dated: &[(f64, f64)]) -> Option<f64> {
+    let npv = |r: f64| -> f64 { dated.iter().map(|(t, amount)| amount * (1.0 + r).powf(-t)).sum() };
+
+    let mut lo = -0.999999;
+    let mut hi = 1e6;
+    let mut npv_lo = npv(lo);
+    let npv_hi = npv(hi);
+    if npv_lo.signum() == npv_hi.signum() {
+        return None;
+    }
+
+    for _ in 0..200 {
+        let mid = (lo + hi) / 2.0;
+        let npv_mid = npv(mid);
+        if npv_mid.abs() < 1e-6 {
+            return Some(mid);
+        }
+        if npv_lo.signum() == npv_mid.signum() {
+            lo = mid;
+            npv_lo = npv_mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    Some((lo + hi) / 2.0)
+}
+
 fn compute_totals(periods: &[PeriodEconomics]) -> Totals {
     let mut totals = Totals {
         cc_cost: 0.0,
@@ -329,6 +715,8 @@ fn compute_totals(periods: &[PeriodEconomics]) -> Totals {
         active_cpt: None,
         savings_blended: None,
         savings_active: None,
+        xirr: None,
+        stale: false,
     };
 
     let mut pct_sum = 0.0;
@@ -394,6 +782,8 @@ fn compute_totals(periods: &[PeriodEconomics]) -> Totals {
         totals.savings_active = Some(totals.rtk_saved_tokens as f64 * totals.active_cpt.unwrap());
     }
 
+    totals.xirr = compute_xirr(periods);
+
     totals
 }
 
@@ -406,40 +796,40 @@ fn display_text(
     monthly: bool,
     all: bool,
     verbose: u8,
+    no_cache: bool,
 ) -> Result<()> {
     // Default: summary view
     if !daily && !weekly && !monthly && !all {
-        display_summary(tracker, verbose)?;
+        display_summary(tracker, verbose, no_cache)?;
         return Ok(());
     }
 
     if all || daily {
-        display_daily(tracker, verbose)?;
+        display_daily(tracker, verbose, no_cache)?;
     }
     if all || weekly {
-        display_weekly(tracker, verbose)?;
+        display_weekly(tracker, verbose, no_cache)?;
     }
     if all || monthly {
-        display_monthly(tracker, verbose)?;
+        display_monthly(tracker, verbose, no_cache)?;
     }
 
     Ok(())
 }
 
-fn display_summary(tracker: &Tracker, verbose: u8) -> Result<()> {
-    let cc_monthly =
-        ccusage::fetch(Granularity::Monthly).context("Failed to fetch ccusage monthly data")?;
-    let rtk_monthly = tracker
-        .get_by_month()
-        .context("Failed to load monthly token savings from database")?;
-    let periods = merge_monthly(cc_monthly, rtk_monthly);
+fn display_summary(tracker: &Tracker, verbose: u8, no_cache: bool) -> Result<()> {
+    let (periods, stale) = load_monthly_periods(tracker, no_cache)?;
 
     if periods.is_empty() {
         println!("No data available. Run some rtk commands to start tracking.");
         return Ok(());
     }
 
-    let totals = compute_totals(&periods);
+    let mut totals = compute_totals(&periods);
+    totals.stale = stale;
+    if stale {
+        println!("⚠️  ccusage unavailable -- showing last cached snapshot");
+    }
 
     println!("💰 Claude Code Economics");
     println!("════════════════════════════════════════════════════");
@@ -541,44 +931,38 @@ fn display_summary(tracker: &Tracker, verbose: u8) -> Result<()> {
     Ok(())
 }
 
-fn display_daily(tracker: &Tracker, verbose: u8) -> Result<()> {
-    let cc_daily =
-        ccusage::fetch(Granularity::Daily).context("Failed to fetch ccusage daily data")?;
-    let rtk_daily = tracker
-        .get_all_days()
-        .context("Failed to load daily token savings from database")?;
-    let periods = merge_daily(cc_daily, rtk_daily);
+fn display_daily(tracker: &Tracker, verbose: u8, no_cache: bool) -> Result<()> {
+    let (periods, stale) = load_daily_periods(tracker, no_cache)?;
 
     println!("📅 Daily Economics");
     println!("════════════════════════════════════════════════════");
+    if stale {
+        println!("⚠️  ccusage unavailable -- showing last cached snapshot");
+    }
     print_period_table(&periods, verbose);
     Ok(())
 }
 
-fn display_weekly(tracker: &Tracker, verbose: u8) -> Result<()> {
-    let cc_weekly =
-        ccusage::fetch(Granularity::Weekly).context("Failed to fetch ccusage weekly data")?;
-    let rtk_weekly = tracker
-        .get_by_week()
-        .context("Failed to load weekly token savings from database")?;
-    let periods = merge_weekly(cc_weekly, rtk_weekly);
+fn display_weekly(tracker: &Tracker, verbose: u8, no_cache: bool) -> Result<()> {
+    let (periods, stale) = load_weekly_periods(tracker, no_cache)?;
 
     println!("📅 Weekly Economics");
     println!("════════════════════════════════════════════════════");
+    if stale {
+        println!("⚠️  ccusage unavailable -- showing last cached snapshot");
+    }
     print_period_table(&periods, verbose);
     Ok(())
 }
 
-fn display_monthly(tracker: &Tracker, verbose: u8) -> Result<()> {
-    let cc_monthly =
-        ccusage::fetch(Granularity::Monthly).context("Failed to fetch ccusage monthly data")?;
-    let rtk_monthly = tracker
-        .get_by_month()
-        .context("Failed to load monthly token savings from database")?;
-    let periods = merge_monthly(cc_monthly, rtk_monthly);
+fn display_monthly(tracker: &Tracker, verbose: u8, no_cache: bool) -> Result<()> {
+    let (periods, stale) = load_monthly_periods(tracker, no_cache)?;
 
     println!("📅 Monthly Economics");
     println!("════════════════════════════════════════════════════");
+    if stale {
+        println!("⚠️  ccusage unavailable -- showing last cached snapshot");
+    }
     print_period_table(&periods, verbose);
     Ok(())
 }
@@ -668,6 +1052,7 @@ fn export_json(
     weekly: bool,
     monthly: bool,
     all: bool,
+    no_cache: bool,
 ) -> Result<()> {
     #[derive(Serialize)]
     struct Export {
@@ -685,31 +1070,20 @@ fn export_json(
     };
 
     if all || daily {
-        let cc = ccusage::fetch(Granularity::Daily)
-            .context("Failed to fetch ccusage daily data for JSON export")?;
-        let rtk = tracker
-            .get_all_days()
-            .context("Failed to load daily token savings for JSON export")?;
-        export.daily = Some(merge_daily(cc, rtk));
+        let (periods, _stale) = load_daily_periods(tracker, no_cache)?;
+        export.daily = Some(periods);
     }
 
     if all || weekly {
-        let cc = ccusage::fetch(Granularity::Weekly)
-            .context("Failed to fetch ccusage weekly data for export")?;
-        let rtk = tracker
-            .get_by_week()
-            .context("Failed to load weekly token savings for export")?;
-        export.weekly = Some(merge_weekly(cc, rtk));
+        let (periods, _stale) = load_weekly_periods(tracker, no_cache)?;
+        export.weekly = Some(periods);
     }
 
     if all || monthly {
-        let cc = ccusage::fetch(Granularity::Monthly)
-            .context("Failed to fetch ccusage monthly data for export")?;
-        let rtk = tracker
-            .get_by_month()
-            .context("Failed to load monthly token savings for export")?;
-        let periods = merge_monthly(cc, rtk);
-        export.totals = Some(compute_totals(&periods));
+        let (periods, stale) = load_monthly_periods(tracker, no_cache)?;
+        let mut totals = compute_totals(&periods);
+        totals.stale = stale;
+        export.totals = Some(totals);
         export.monthly = Some(periods);
     }
 
@@ -727,50 +1101,54 @@ fn export_csv(
     weekly: bool,
     monthly: bool,
     all: bool,
+    no_cache: bool,
 ) -> Result<()> {
-    // Header (new columns: input_tokens, output_tokens, cache_create, cache_read, weighted_savings)
-    println!("period,spent,input_tokens,output_tokens,cache_create,cache_read,active_tokens,total_tokens,saved_tokens,weighted_savings,active_savings,blended_savings,rtk_commands");
+    // Header (new columns: input_tokens, output_tokens, cache_create, cache_read, weighted_savings,
+    // xirr, and the rolling moving averages: blended_cpt_ma, active_cpt_ma, weighted_input_cpt_ma,
+    // savings_weighted_ma)
+    println!("period,spent,input_tokens,output_tokens,cache_create,cache_read,active_tokens,total_tokens,saved_tokens,weighted_savings,active_savings,blended_savings,rtk_commands,xirr,blended_cpt_ma,active_cpt_ma,weighted_input_cpt_ma,savings_weighted_ma");
 
     if all || daily {
-        let cc = ccusage::fetch(Granularity::Daily)
-            .context("Failed to fetch ccusage daily data for JSON export")?;
-        let rtk = tracker
-            .get_all_days()
-            .context("Failed to load daily token savings for JSON export")?;
-        let periods = merge_daily(cc, rtk);
+        let (periods, stale) = load_daily_periods(tracker, no_cache)?;
+        if stale {
+            eprintln!("⚠️  ccusage unavailable -- daily rows are from the last cached snapshot");
+        }
+        let xirr = compute_xirr(&periods);
         for p in periods {
-            print_csv_row(&p);
+            print_csv_row(&p, xirr);
         }
     }
 
     if all || weekly {
-        let cc = ccusage::fetch(Granularity::Weekly)
-            .context("Failed to fetch ccusage weekly data for export")?;
-        let rtk = tracker
-            .get_by_week()
-            .context("Failed to load weekly token savings for export")?;
-        let periods = merge_weekly(cc, rtk);
+        let (periods, stale) = load_weekly_periods(tracker, no_cache)?;
+        if stale {
+            eprintln!("⚠️  ccusage unavailable -- weekly rows are from the last cached snapshot");
+        }
+        let xirr = compute_xirr(&periods);
         for p in periods {
-            print_csv_row(&p);
+            print_csv_row(&p, xirr);
         }
     }
 
     if all || monthly {
-        let cc = ccusage::fetch(Granularity::Monthly)
-            .context("Failed to fetch ccusage monthly data for export")?;
-        let rtk = tracker
-            .get_by_month()
-            .context("Failed to load monthly token savings for export")?;
-        let periods = merge_monthly(cc, rtk);
+        let (periods, stale) = load_monthly_periods(tracker, no_cache)?;
+        if stale {
+            eprintln!("⚠️  ccusage unavailable -- monthly rows are from the last cached snapshot");
+        }
+        let xirr = compute_xirr(&periods);
         for p in periods {
-            print_csv_row(&p);
+            print_csv_row(&p, xirr);
         }
     }
 
     Ok(())
 }
 
-fn print_csv_row(p: &PeriodEconomics) {
+/// Print one period's CSV row. `xirr` is the money-weighted annualized
+/// return across the whole granularity's periods (see [`compute_xirr`]),
+/// not a per-period value, so every row in a given export block repeats
+/// the same figure -- the totals-level column the per-row data shares.
+fn print_csv_row(p: &PeriodEconomics, xirr: Option<f64>) {
     let spent = p.cc_cost.map(|c| format!("{:.4}", c)).unwrap_or_default();
     let input_tokens = p.cc_input_tokens.map(|t| t.to_string()).unwrap_or_default();
     let output_tokens = p
@@ -807,9 +1185,26 @@ fn print_csv_row(p: &PeriodEconomics) {
         .map(|s| format!("{:.4}", s))
         .unwrap_or_default();
     let cmds = p.rtk_commands.map(|c| c.to_string()).unwrap_or_default();
+    let xirr = xirr.map(|r| format!("{:.4}", r)).unwrap_or_default();
+    let blended_cpt_ma = p
+        .blended_cpt_ma
+        .map(|v| format!("{:.6}", v))
+        .unwrap_or_default();
+    let active_cpt_ma = p
+        .active_cpt_ma
+        .map(|v| format!("{:.6}", v))
+        .unwrap_or_default();
+    let weighted_input_cpt_ma = p
+        .weighted_input_cpt_ma
+        .map(|v| format!("{:.6}", v))
+        .unwrap_or_default();
+    let savings_weighted_ma = p
+        .savings_weighted_ma
+        .map(|v| format!("{:.4}", v))
+        .unwrap_or_default();
 
     println!(
-        "{},{},{},{},{},{},{},{},{},{},{},{},{}",
+        "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
         p.label,
         spent,
         input_tokens,
@@ -822,10 +1217,205 @@ fn print_csv_row(p: &PeriodEconomics) {
         weighted_savings,
         active_savings,
         blended_savings,
-        cmds
+        cmds,
+        xirr,
+        blended_cpt_ma,
+        active_cpt_ma,
+        weighted_input_cpt_ma,
+        savings_weighted_ma
     );
 }
 
+// ── Structured (NDJSON) export ──
+
+/// Per-type token counts, nested under `tokens` in [`PeriodRecord`]/
+/// [`TotalsRecord`] instead of flat columns -- unlike CSV, a missing value
+/// serializes as an explicit JSON `null` so a consumer can tell "no
+/// ccusage data for this period" apart from a genuine zero.
+#[derive(Debug, Serialize)]
+struct TokenBreakdown {
+    input: Option<u64>,
+    output: Option<u64>,
+    cache_create: Option<u64>,
+    cache_read: Option<u64>,
+}
+
+/// The three savings models nested under `savings`, mirroring
+/// [`PeriodEconomics`]'s `savings_weighted`/`savings_active`/`savings_blended`.
+#[derive(Debug, Serialize)]
+struct SavingsBreakdown {
+    weighted: Option<f64>,
+    active: Option<f64>,
+    blended: Option<f64>,
+}
+
+/// Structured (nested, explicit-null) JSON view of one [`PeriodEconomics`],
+/// printed one per line in NDJSON mode.
+#[derive(Debug, Serialize)]
+struct PeriodRecord {
+    period: String,
+    spent: Option<f64>,
+    tokens: TokenBreakdown,
+    active_tokens: Option<u64>,
+    total_tokens: Option<u64>,
+    saved_tokens: Option<usize>,
+    savings_pct: Option<f64>,
+    savings: SavingsBreakdown,
+    weighted_input_cpt: Option<f64>,
+    rtk_commands: Option<usize>,
+}
+
+impl From<&PeriodEconomics> for PeriodRecord {
+    fn from(p: &PeriodEconomics) -> Self {
+        Self {
+            period: p.label.clone(),
+            spent: p.cc_cost,
+            tokens: TokenBreakdown {
+                input: p.cc_input_tokens,
+                output: p.cc_output_tokens,
+                cache_create: p.cc_cache_create_tokens,
+                cache_read: p.cc_cache_read_tokens,
+            },
+            active_tokens: p.cc_active_tokens,
+            total_tokens: p.cc_total_tokens,
+            saved_tokens: p.rtk_saved_tokens,
+            savings_pct: p.rtk_savings_pct,
+            savings: SavingsBreakdown {
+                weighted: p.savings_weighted,
+                active: p.savings_active,
+                blended: p.savings_blended,
+            },
+            weighted_input_cpt: p.weighted_input_cpt,
+            rtk_commands: p.rtk_commands,
+        }
+    }
+}
+
+/// Structured view of [`compute_totals`]'s [`Totals`], printed as the
+/// trailing record in NDJSON mode. Token/cost sums are always present (an
+/// empty period contributes 0, not "missing"), so only the derived
+/// per-period-style fields stay `Option`.
+#[derive(Debug, Serialize)]
+struct TotalsRecord {
+    spent: f64,
+    tokens: TokenBreakdown,
+    active_tokens: u64,
+    total_tokens: u64,
+    saved_tokens: usize,
+    avg_savings_pct: f64,
+    savings: SavingsBreakdown,
+    weighted_input_cpt: Option<f64>,
+    rtk_commands: usize,
+    xirr: Option<f64>,
+    /// `true` when ccusage couldn't be reached and these totals were served
+    /// from the last on-disk snapshot instead of a fresh fetch.
+    stale: bool,
+}
+
+impl From<&Totals> for TotalsRecord {
+    fn from(t: &Totals) -> Self {
+        Self {
+            spent: t.cc_cost,
+            tokens: TokenBreakdown {
+                input: Some(t.cc_input_tokens),
+                output: Some(t.cc_output_tokens),
+                cache_create: Some(t.cc_cache_create_tokens),
+                cache_read: Some(t.cc_cache_read_tokens),
+            },
+            active_tokens: t.cc_active_tokens,
+            total_tokens: t.cc_total_tokens,
+            saved_tokens: t.rtk_saved_tokens,
+            avg_savings_pct: t.rtk_avg_savings_pct,
+            savings: SavingsBreakdown {
+                weighted: t.savings_weighted,
+                active: t.savings_active,
+                blended: t.savings_blended,
+            },
+            weighted_input_cpt: t.weighted_input_cpt,
+            rtk_commands: t.rtk_commands,
+            xirr: t.xirr,
+            stale: t.stale,
+        }
+    }
+}
+
+/// One line of NDJSON output, discriminated by `kind` so a streaming
+/// consumer can tell a per-period record from the trailing totals record
+/// without inspecting the rest of the shape.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind")]
+enum NdjsonRecord {
+    #[serde(rename = "period")]
+    Period(PeriodRecord),
+    #[serde(rename = "totals")]
+    Totals(TotalsRecord),
+}
+
+/// Print `periods` as NDJSON: one [`PeriodRecord`] per line, in whatever
+/// order `periods` is already in (callers sort before merging).
+fn print_ndjson_periods(periods: &[PeriodEconomics]) -> Result<()> {
+    for p in periods {
+        let record = NdjsonRecord::Period(PeriodRecord::from(p));
+        println!(
+            "{}",
+            serde_json::to_string(&record).context("Failed to serialize period to NDJSON")?
+        );
+    }
+    Ok(())
+}
+
+/// Sibling of [`export_csv`] for pipelines that want structured records
+/// instead of a fixed column list: one NDJSON line per period across the
+/// requested granularities, followed by a single trailing `totals` line
+/// computed from the monthly periods (same scope [`export_json`] uses for
+/// its `totals` field).
+fn export_ndjson(
+    tracker: &Tracker,
+    daily: bool,
+    weekly: bool,
+    monthly: bool,
+    all: bool,
+    no_cache: bool,
+) -> Result<()> {
+    let mut totals_record = None;
+
+    if all || daily {
+        let (periods, stale) = load_daily_periods(tracker, no_cache)?;
+        if stale {
+            eprintln!("⚠️  ccusage unavailable -- daily records are from the last cached snapshot");
+        }
+        print_ndjson_periods(&periods)?;
+    }
+
+    if all || weekly {
+        let (periods, stale) = load_weekly_periods(tracker, no_cache)?;
+        if stale {
+            eprintln!(
+                "⚠️  ccusage unavailable -- weekly records are from the last cached snapshot"
+            );
+        }
+        print_ndjson_periods(&periods)?;
+    }
+
+    if all || monthly {
+        let (periods, stale) = load_monthly_periods(tracker, no_cache)?;
+        let mut totals = compute_totals(&periods);
+        totals.stale = stale;
+        totals_record = Some(TotalsRecord::from(&totals));
+        print_ndjson_periods(&periods)?;
+    }
+
+    if let Some(totals) = totals_record {
+        println!(
+            "{}",
+            serde_json::to_string(&NdjsonRecord::Totals(totals))
+                .context("Failed to serialize totals to NDJSON")?
+        );
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -929,6 +1519,9 @@ mod tests {
             savings_pct: 50.0,
             total_time_ms: 0,
             avg_time_ms: 0,
+            p50_time_ms: 0,
+            p95_time_ms: 0,
+            p99_time_ms: 0,
         }];
 
         let merged = merge_monthly(Some(cc), rtk);
@@ -969,6 +1562,9 @@ mod tests {
             savings_pct: 50.0,
             total_time_ms: 0,
             avg_time_ms: 0,
+            p50_time_ms: 0,
+            p95_time_ms: 0,
+            p99_time_ms: 0,
         }];
 
         let merged = merge_monthly(None, rtk);
@@ -989,6 +1585,9 @@ mod tests {
                 savings_pct: 40.0,
                 total_time_ms: 0,
                 avg_time_ms: 0,
+                p50_time_ms: 0,
+                p95_time_ms: 0,
+                p99_time_ms: 0,
             },
             MonthStats {
                 month: "2026-01".to_string(),
@@ -999,6 +1598,9 @@ mod tests {
                 savings_pct: 60.0,
                 total_time_ms: 0,
                 avg_time_ms: 0,
+                p50_time_ms: 0,
+                p95_time_ms: 0,
+                p99_time_ms: 0,
             },
         ];
 
@@ -1117,6 +1719,10 @@ mod tests {
                 active_cpt: None,
                 savings_blended: None,
                 savings_active: None,
+                blended_cpt_ma: None,
+                active_cpt_ma: None,
+                weighted_input_cpt_ma: None,
+                savings_weighted_ma: None,
             },
             PeriodEconomics {
                 label: "2026-02".to_string(),
@@ -1136,6 +1742,10 @@ mod tests {
                 active_cpt: None,
                 savings_blended: None,
                 savings_active: None,
+                blended_cpt_ma: None,
+                active_cpt_ma: None,
+                weighted_input_cpt_ma: None,
+                savings_weighted_ma: None,
             },
         ];
 
@@ -1154,4 +1764,237 @@ mod tests {
         assert!(totals.blended_cpt.is_some());
         assert!(totals.active_cpt.is_some());
     }
+
+    #[test]
+    fn test_parse_period_date_daily() {
+        assert_eq!(
+            parse_period_date("2026-01-18"),
+            NaiveDate::from_ymd_opt(2026, 1, 18)
+        );
+    }
+
+    #[test]
+    fn test_parse_period_date_monthly_anchors_to_first() {
+        assert_eq!(
+            parse_period_date("2026-03"),
+            NaiveDate::from_ymd_opt(2026, 3, 1)
+        );
+    }
+
+    #[test]
+    fn test_parse_period_date_invalid() {
+        assert_eq!(parse_period_date("not-a-date"), None);
+    }
+
+    fn period_with_flow(label: &str, cost: Option<f64>, savings: Option<f64>) -> PeriodEconomics {
+        PeriodEconomics {
+            label: label.to_string(),
+            cc_cost: cost,
+            savings_weighted: savings,
+            ..PeriodEconomics::new(label)
+        }
+    }
+
+    #[test]
+    fn test_compute_xirr_spend_then_payoff() {
+        // $100 spent on day 0, $110 of savings realized a year later.
+        let periods = vec![
+            period_with_flow("2025-01-01", Some(100.0), None),
+            period_with_flow("2026-01-01", None, Some(110.0)),
+        ];
+
+        let xirr = compute_xirr(&periods).expect("should converge");
+        assert!((xirr - 0.10).abs() < 0.01, "xirr was {}", xirr);
+    }
+
+    #[test]
+    fn test_compute_xirr_requires_two_dated_flows() {
+        let periods = vec![period_with_flow("2026-01-01", Some(100.0), None)];
+        assert_eq!(compute_xirr(&periods), None);
+    }
+
+    #[test]
+    fn test_compute_xirr_none_without_sign_change() {
+        // Every flow is an outflow -- no rate makes this balance.
+        let periods = vec![
+            period_with_flow("2026-01-01", Some(100.0), None),
+            period_with_flow("2026-02-01", Some(50.0), None),
+        ];
+        assert_eq!(compute_xirr(&periods), None);
+    }
+
+    #[test]
+    fn test_compute_xirr_ignores_unparseable_labels() {
+        let periods = vec![
+            period_with_flow("garbage", Some(100.0), Some(200.0)),
+            period_with_flow("2026-01-01", Some(100.0), None),
+            period_with_flow("2026-06-01", None, Some(120.0)),
+        ];
+        assert!(compute_xirr(&periods).is_some());
+    }
+
+    #[test]
+    fn test_period_record_nests_tokens_and_savings() {
+        let mut p = PeriodEconomics::new("2026-01");
+        p.cc_cost = Some(10.0);
+        p.cc_input_tokens = Some(100);
+        p.cc_output_tokens = None; // missing data should stay null, not 0
+        p.savings_weighted = Some(5.0);
+
+        let record = PeriodRecord::from(&p);
+        assert_eq!(record.period, "2026-01");
+        assert_eq!(record.tokens.input, Some(100));
+        assert_eq!(record.tokens.output, None);
+        assert_eq!(record.savings.weighted, Some(5.0));
+        assert_eq!(record.savings.active, None);
+
+        let json = serde_json::to_value(&record).unwrap();
+        assert_eq!(json["tokens"]["output"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_ndjson_record_tags_period_and_totals() {
+        let p = PeriodEconomics::new("2026-01");
+        let period_json = serde_json::to_value(NdjsonRecord::Period(PeriodRecord::from(&p))).unwrap();
+        assert_eq!(period_json["kind"], "period");
+
+        let totals = compute_totals(&[]);
+        let totals_json = serde_json::to_value(NdjsonRecord::Totals(TotalsRecord::from(&totals))).unwrap();
+        assert_eq!(totals_json["kind"], "totals");
+    }
+
+    fn period_with_cost_and_tokens(label: &str, cost: f64, input: u64, output: u64) -> PeriodEconomics {
+        let mut p = PeriodEconomics::new(label);
+        p.cc_cost = Some(cost);
+        p.cc_total_tokens = Some(input + output);
+        p.cc_active_tokens = Some(input + output);
+        p.cc_input_tokens = Some(input);
+        p.cc_output_tokens = Some(output);
+        p.cc_cache_create_tokens = Some(0);
+        p.cc_cache_read_tokens = Some(0);
+        p.rtk_saved_tokens = Some(1000);
+        p
+    }
+
+    #[test]
+    fn test_rolling_ratio_is_sum_of_parts_not_mean_of_ratios() {
+        let mut ratio = RollingRatio::new(7);
+        // A low-volume period (cost 1, units 1 -> ratio 1.0) shouldn't pull
+        // the average toward it as much as a naive mean of ratios would.
+        assert_eq!(ratio.push(1.0, 1.0), Some(1.0));
+        // Adding a high-volume period at ratio 0.1 should land near 0.1,
+        // not at (1.0 + 0.1) / 2 = 0.55.
+        let combined = ratio.push(100.0, 1000.0).unwrap();
+        assert!((combined - (101.0 / 1001.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rolling_ratio_skips_zero_denominator() {
+        let mut ratio = RollingRatio::new(7);
+        assert_eq!(ratio.push(10.0, 10.0), Some(1.0));
+        // A period with no units shouldn't change the running average.
+        assert_eq!(ratio.push(999.0, 0.0), Some(1.0));
+    }
+
+    #[test]
+    fn test_rolling_ratio_partial_window_before_full() {
+        let mut ratio = RollingRatio::new(3);
+        assert_eq!(ratio.push(10.0, 10.0), Some(1.0));
+        assert_eq!(ratio.push(10.0, 10.0), Some(1.0));
+    }
+
+    #[test]
+    fn test_rolling_ratio_evicts_outside_window() {
+        let mut ratio = RollingRatio::new(2);
+        ratio.push(10.0, 10.0); // ratio 1.0
+        ratio.push(100.0, 10.0); // window full: (10+100)/(10+10) = 5.5
+        let third = ratio.push(10.0, 10.0).unwrap(); // evicts first: (100+10)/(10+10) = 5.5
+        assert!((third - 5.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_apply_moving_averages_smooths_and_fills_savings() {
+        let mut periods = vec![
+            period_with_cost_and_tokens("2026-01-01", 10.0, 1000, 0),
+            period_with_cost_and_tokens("2026-01-02", 20.0, 2000, 0),
+        ];
+
+        apply_moving_averages(&mut periods, 7);
+
+        assert!(periods[0].weighted_input_cpt_ma.is_some());
+        assert!(periods[1].weighted_input_cpt_ma.is_some());
+        assert!(periods[0].savings_weighted_ma.is_some());
+        assert_eq!(
+            periods[0].savings_weighted_ma,
+            Some(1000.0 * periods[0].weighted_input_cpt_ma.unwrap())
+        );
+    }
+
+    #[test]
+    fn test_apply_moving_averages_none_without_ccusage_data() {
+        let mut periods = vec![PeriodEconomics::new("2026-01-01")];
+        apply_moving_averages(&mut periods, 7);
+
+        assert!(periods[0].blended_cpt_ma.is_none());
+        assert!(periods[0].active_cpt_ma.is_none());
+        assert!(periods[0].weighted_input_cpt_ma.is_none());
+        assert!(periods[0].savings_weighted_ma.is_none());
+    }
+
+    #[test]
+    fn test_incremental_since_needs_two_cached_periods() {
+        assert_eq!(incremental_since(&[]), None);
+        assert_eq!(
+            incremental_since(&[PeriodEconomics::new("2026-01-01")]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_incremental_since_is_second_to_last_label() {
+        let cached = vec![
+            PeriodEconomics::new("2026-01-01"),
+            PeriodEconomics::new("2026-01-02"),
+            PeriodEconomics::new("2026-01-03"),
+        ];
+        assert_eq!(
+            incremental_since(&cached),
+            NaiveDate::parse_from_str("2026-01-02", "%Y-%m-%d").ok()
+        );
+    }
+
+    #[test]
+    fn test_splice_cached_periods_keeps_closed_periods_untouched() {
+        let mut closed = PeriodEconomics::new("2026-01-01");
+        closed.cc_cost = Some(42.0);
+        let cached = vec![closed, PeriodEconomics::new("2026-01-02")];
+
+        let fresh = vec![
+            period_with_cost_and_tokens("2026-01-02", 5.0, 100, 0),
+            period_with_cost_and_tokens("2026-01-03", 10.0, 100, 0),
+        ];
+
+        let spliced = splice_cached_periods(cached, fresh, "2026-01-02");
+
+        assert_eq!(spliced.len(), 3);
+        assert_eq!(spliced[0].label, "2026-01-01");
+        assert_eq!(spliced[0].cc_cost, Some(42.0));
+        assert_eq!(spliced[1].label, "2026-01-02");
+        assert_eq!(spliced[1].cc_cost, Some(5.0));
+        assert_eq!(spliced[2].label, "2026-01-03");
+    }
+
+    #[test]
+    fn test_splice_cached_periods_fresh_wins_over_stale_cached_entry() {
+        let mut stale = PeriodEconomics::new("2026-01-02");
+        stale.cc_cost = Some(999.0);
+        let cached = vec![PeriodEconomics::new("2026-01-01"), stale];
+
+        let fresh = vec![period_with_cost_and_tokens("2026-01-02", 5.0, 100, 0)];
+
+        let spliced = splice_cached_periods(cached, fresh, "2026-01-02");
+
+        let day2 = spliced.iter().find(|p| p.label == "2026-01-02").unwrap();
+        assert_eq!(day2.cc_cost, Some(5.0));
+    }
 }
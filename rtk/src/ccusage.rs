@@ -5,6 +5,7 @@
 //! and graceful degradation when ccusage is unavailable.
 
 use anyhow::{Context, Result};
+use chrono::{Duration, Local};
 use serde::Deserialize;
 use std::process::Command;
 
@@ -32,6 +33,8 @@ pub struct CcusageMetrics {
 pub struct CcusagePeriod {
     pub key: String, // "2026-01-30" (daily), "2026-01" (monthly), "2026-01-20" (weekly ISO monday)
     pub metrics: CcusageMetrics,
+    /// Per-project split, populated only when the query requested `breakdown`.
+    pub breakdown: Option<Vec<CcusageProjectBreakdown>>,
 }
 
 /// Time granularity for ccusage reports
@@ -42,6 +45,38 @@ pub enum Granularity {
     Monthly,
 }
 
+/// Per-project metrics, requested via `--instances`/`--breakdown`.
+#[derive(Debug)]
+pub struct CcusageProjectBreakdown {
+    pub project: String,
+    pub metrics: CcusageMetrics,
+}
+
+/// Query parameters for [`fetch_with_query`]. `since`/`until` are `YYYYMMDD`
+/// strings (ccusage's own date format); `project` scopes to a single project
+/// via `--instances`.
+#[derive(Debug, Clone)]
+pub struct CcusageQuery {
+    pub since: String,
+    pub until: String,
+    pub project: Option<String>,
+    pub breakdown: bool,
+}
+
+impl Default for CcusageQuery {
+    /// Real last-90-days window ending today, no project filter.
+    fn default() -> Self {
+        let now = Local::now().date_naive();
+        let since = now - Duration::days(90);
+        Self {
+            since: since.format("%Y%m%d").to_string(),
+            until: now.format("%Y%m%d").to_string(),
+            project: None,
+            breakdown: false,
+        }
+    }
+}
+
 // ── Internal Types for JSON Deserialization ──
 
 #[derive(Debug, Deserialize)]
@@ -54,6 +89,16 @@ struct DailyEntry {
     date: String,
     #[serde(flatten)]
     metrics: CcusageMetrics,
+    #[serde(default)]
+    breakdown: Vec<ProjectEntry>,
+}
+
+/// One project's slice of a period, as emitted by `ccusage --breakdown`.
+#[derive(Debug, Deserialize)]
+struct ProjectEntry {
+    project: String,
+    #[serde(flatten)]
+    metrics: CcusageMetrics,
 }
 
 #[derive(Debug, Deserialize)]
@@ -66,6 +111,8 @@ struct WeeklyEntry {
     week: String, // ISO week start (Monday)
     #[serde(flatten)]
     metrics: CcusageMetrics,
+    #[serde(default)]
+    breakdown: Vec<ProjectEntry>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -78,6 +125,8 @@ struct MonthlyEntry {
     month: String,
     #[serde(flatten)]
     metrics: CcusageMetrics,
+    #[serde(default)]
+    breakdown: Vec<ProjectEntry>,
 }
 
 // ── Public API ──
@@ -119,12 +168,27 @@ pub fn is_available() -> bool {
     build_command().is_some()
 }
 
-/// Fetch usage data from ccusage for the last 90 days
+/// Fetch usage data from ccusage for the real last-90-days window, with no
+/// project filter. Thin wrapper over [`fetch_with_query`] kept for existing
+/// callers that don't need a custom range.
 ///
 /// Returns `Ok(None)` if ccusage is unavailable (graceful degradation)
 /// Returns `Ok(Some(vec))` with parsed data on success
 /// Returns `Err` only on unexpected failures (JSON parse, etc.)
 pub fn fetch(granularity: Granularity) -> Result<Option<Vec<CcusagePeriod>>> {
+    fetch_with_query(granularity, &CcusageQuery::default())
+}
+
+/// Fetch usage data from ccusage for an explicit date range, optionally
+/// scoped to one project and/or with a per-project breakdown.
+///
+/// Returns `Ok(None)` if ccusage is unavailable (graceful degradation)
+/// Returns `Ok(Some(vec))` with parsed data on success
+/// Returns `Err` only on unexpected failures (JSON parse, etc.)
+pub fn fetch_with_query(
+    granularity: Granularity,
+    query: &CcusageQuery,
+) -> Result<Option<Vec<CcusagePeriod>>> {
     let mut cmd = match build_command() {
         Some(cmd) => cmd,
         None => {
@@ -139,12 +203,20 @@ pub fn fetch(granularity: Granularity) -> Result<Option<Vec<CcusagePeriod>>> {
         Granularity::Monthly => "monthly",
     };
 
-    let output = cmd
-        .arg(subcommand)
+    cmd.arg(subcommand)
         .arg("--json")
         .arg("--since")
-        .arg("20250101") // 90 days back approx
-        .output();
+        .arg(&query.since)
+        .arg("--until")
+        .arg(&query.until);
+
+    if let Some(project) = &query.project {
+        cmd.arg("--instances").arg(project);
+    } else if query.breakdown {
+        cmd.arg("--breakdown");
+    }
+
+    let output = cmd.output();
 
     let output = match output {
         Err(e) => {
@@ -184,6 +256,7 @@ fn parse_json(json: &str, granularity: Granularity) -> Result<Vec<CcusagePeriod>
                 .map(|e| CcusagePeriod {
                     key: e.date,
                     metrics: e.metrics,
+                    breakdown: to_breakdown(e.breakdown),
                 })
                 .collect())
         }
@@ -196,6 +269,7 @@ fn parse_json(json: &str, granularity: Granularity) -> Result<Vec<CcusagePeriod>
                 .map(|e| CcusagePeriod {
                     key: e.week,
                     metrics: e.metrics,
+                    breakdown: to_breakdown(e.breakdown),
                 })
                 .collect())
         }
@@ -208,12 +282,28 @@ fn parse_json(json: &str, granularity: Granularity) -> Result<Vec<CcusagePeriod>
                 .map(|e| CcusagePeriod {
                     key: e.month,
                     metrics: e.metrics,
+                    breakdown: to_breakdown(e.breakdown),
                 })
                 .collect())
         }
     }
 }
 
+fn to_breakdown(entries: Vec<ProjectEntry>) -> Option<Vec<CcusageProjectBreakdown>> {
+    if entries.is_empty() {
+        return None;
+    }
+    Some(
+        entries
+            .into_iter()
+            .map(|e| CcusageProjectBreakdown {
+                project: e.project,
+                metrics: e.metrics,
+            })
+            .collect(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,167 @@
+//! Concurrent lint/typecheck/test fan-out built on [`crate::jobs`].
+//!
+//! Unlike [`crate::lint_orchestrator`] (which hardcodes two fixed ruff
+//! invocations), `rtk ci` takes arbitrary shell commands for each of
+//! `--lint`, `--typecheck`, and `--test` -- the same "caller supplies the
+//! command string" convention as `rtk err`/`rtk test` -- and runs whichever
+//! of them are given at once via [`crate::jobs::run_jobs`], merging the
+//! results into a single report in flag order.
+
+use crate::jobs::{Job, JobResult};
+use crate::tracking;
+use crate::utils::RunOutcome;
+use anyhow::{bail, Result};
+use std::time::Duration;
+
+const JOB_TIMEOUT: Duration = Duration::from_secs(600);
+
+pub fn run(
+    lint: Option<String>,
+    typecheck: Option<String>,
+    test: Option<String>,
+    verbose: u8,
+) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let jobs: Vec<Job> = [("lint", lint), ("typecheck", typecheck), ("test", test)]
+        .into_iter()
+        .filter_map(|(label, cmd)| cmd.map(|cmd| shell_job(label, &cmd)))
+        .collect();
+
+    if jobs.is_empty() {
+        bail!("rtk ci requires at least one of --lint, --typecheck, --test");
+    }
+
+    if verbose > 0 {
+        eprintln!("rtk ci: running {} job(s) concurrently", jobs.len());
+    }
+
+    let max_parallel = jobs.len();
+    let results = crate::jobs::run_jobs(jobs, max_parallel);
+
+    let (report, worst_exit_code) = render_report(&results);
+    println!("{}", report);
+    timer.track("concurrent lint/typecheck/test", "rtk ci", "", &report);
+
+    if worst_exit_code != 0 {
+        std::process::exit(worst_exit_code);
+    }
+
+    Ok(())
+}
+
+fn shell_job(label: &str, command: &str) -> Job {
+    if cfg!(target_os = "windows") {
+        Job::new(label, "cmd").args(["/C", command]).timeout(JOB_TIMEOUT)
+    } else {
+        Job::new(label, "sh").args(["-c", command]).timeout(JOB_TIMEOUT)
+    }
+}
+
+/// Merge each job's output into one report and surface the worst non-zero
+/// exit code, so CI sees a failure if any job failed.
+fn render_report(results: &[JobResult]) -> (String, i32) {
+    let mut worst_exit_code = 0;
+    let mut report = String::new();
+
+    for result in results {
+        let (body, exit_code) = match &result.outcome {
+            Ok(RunOutcome::Completed {
+                stdout,
+                stderr,
+                exit_code,
+            }) => {
+                let mut body = stdout.trim().to_string();
+                if !stderr.trim().is_empty() {
+                    if !body.is_empty() {
+                        body.push('\n');
+                    }
+                    body.push_str(stderr.trim());
+                }
+                if body.is_empty() {
+                    body.push_str("no output");
+                }
+                (body, *exit_code)
+            }
+            Ok(RunOutcome::TimedOut) => ("timed out".to_string(), 1),
+            Err(e) => (format!("failed to run: {}", e), 1),
+        };
+
+        worst_exit_code = worst_exit_code.max(exit_code);
+        report.push_str(&format!(
+            "── {} ({:.1}s) ──\n",
+            result.label,
+            result.duration.as_secs_f64()
+        ));
+        report.push_str(&body);
+        report.push_str("\n\n");
+    }
+
+    (report.trim().to_string(), worst_exit_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration as StdDuration;
+
+    #[test]
+    fn test_render_report_merges_sections_in_order() {
+        let results = vec![
+            JobResult {
+                label: "lint".to_string(),
+                duration: StdDuration::from_secs(1),
+                outcome: Ok(RunOutcome::Completed {
+                    stdout: "no lint issues".to_string(),
+                    stderr: String::new(),
+                    exit_code: 0,
+                }),
+            },
+            JobResult {
+                label: "test".to_string(),
+                duration: StdDuration::from_secs(2),
+                outcome: Ok(RunOutcome::Completed {
+                    stdout: String::new(),
+                    stderr: "1 test failed".to_string(),
+                    exit_code: 1,
+                }),
+            },
+        ];
+
+        let (report, worst_exit_code) = render_report(&results);
+        assert!(report.find("lint").unwrap() < report.find("test").unwrap());
+        assert!(report.contains("no lint issues"));
+        assert!(report.contains("1 test failed"));
+        assert_eq!(worst_exit_code, 1);
+    }
+
+    #[test]
+    fn test_render_report_all_clean_is_zero_exit() {
+        let results = vec![JobResult {
+            label: "typecheck".to_string(),
+            duration: StdDuration::from_millis(500),
+            outcome: Ok(RunOutcome::Completed {
+                stdout: String::new(),
+                stderr: String::new(),
+                exit_code: 0,
+            }),
+        }];
+
+        let (report, worst_exit_code) = render_report(&results);
+        assert!(report.contains("no output"));
+        assert_eq!(worst_exit_code, 0);
+    }
+
+    #[test]
+    fn test_render_report_timeout_counts_as_failure() {
+        let results = vec![JobResult {
+            label: "test".to_string(),
+            duration: StdDuration::from_secs(600),
+            outcome: Ok(RunOutcome::TimedOut),
+        }];
+
+        let (report, worst_exit_code) = render_report(&results);
+        assert!(report.contains("timed out"));
+        assert_eq!(worst_exit_code, 1);
+    }
+}
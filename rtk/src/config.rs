@@ -1,5 +1,6 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
 #[derive(Debug, Serialize, Deserialize, Default)]
@@ -10,14 +11,112 @@ pub struct Config {
     pub display: DisplayConfig,
     #[serde(default)]
     pub filters: FilterConfig,
+    #[serde(default)]
+    pub env: EnvConfig,
+    #[serde(default)]
+    pub normalize: NormalizeConfig,
+    #[serde(default)]
+    pub gh: GhConfig,
+    #[serde(default)]
+    pub git: GitConfig,
+    #[serde(default)]
+    pub monorepo: MonorepoConfig,
+    #[serde(default)]
+    pub events: EventsConfig,
+    /// User-defined command proxies, keyed by the name invoked as
+    /// `rtk <name> ...`. Modeled on Cargo's `alias.<name>` config entries:
+    /// each proxy names a real external command plus a declarative filter
+    /// pipeline, so `rtk` can wrap a tool it doesn't natively support
+    /// without recompiling. See [`crate::proxy_cmd`].
+    #[serde(default)]
+    pub proxies: HashMap<String, ProxyConfig>,
+    /// Named filtering presets selected with the global `--profile <name>`
+    /// flag, e.g. `aggressive`/`review`/`ci`. Each entry overrides only the
+    /// fields it sets; anything left `None` falls back through `inherit`
+    /// (defaulting to the `"default"` entry, if one exists) and finally to
+    /// [`ResolvedProfile`]'s hard-coded defaults. See
+    /// [`Config::resolve_profile`].
+    #[serde(default)]
+    pub profile: HashMap<String, ProfileConfig>,
+    /// User-defined shorthand commands, e.g. `co = "git commit"` or
+    /// `tc = "pnpm typecheck"`. Expanded against raw argv before clap ever
+    /// sees it -- see `expand_aliases` in `main.rs` -- so an alias can
+    /// introduce any verb the filter pipeline already understands without
+    /// touching the `Commands` dispatch. A name that collides with a
+    /// built-in subcommand is never expanded; the built-in always wins.
+    #[serde(default)]
+    pub alias: HashMap<String, String>,
+}
+
+/// One `[proxies.<name>]` entry: the real command to run plus a filter
+/// pipeline applied to its combined stdout/stderr before printing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProxyConfig {
+    /// External command to execute, e.g. `"black"` or `"shellcheck"`.
+    pub command: String,
+    /// Regex patterns whose matches are stripped from every line, applied
+    /// in order.
+    #[serde(default)]
+    pub strip_regex: Vec<String>,
+    /// Drop lines identical to one already kept.
+    #[serde(default)]
+    pub dedup: bool,
+    /// Truncate each line to at most this many characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub truncate_line: Option<usize>,
+    /// Keep only lines containing at least one of these substrings
+    /// (case-insensitive). Empty means keep everything.
+    #[serde(default)]
+    pub keep_only: Vec<String>,
+    /// Group the filtered lines under `### <file>` headers, splitting on
+    /// the first `path:` token found in each line.
+    #[serde(default)]
+    pub group_by_file: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TrackingConfig {
     pub enabled: bool,
+    /// Deprecated: superseded by the tiered `keep_*` fields below, which
+    /// `Tracker::cleanup_old` now uses exclusively. Kept so existing
+    /// config files still deserialize.
     pub history_days: u32,
+    /// Always keep the most recent `keep_last` rows in full.
+    #[serde(default = "default_keep_last")]
+    pub keep_last: usize,
+    /// Beyond that, keep at most one row per day for this many days.
+    #[serde(default = "default_keep_daily")]
+    pub keep_daily: usize,
+    /// Beyond that, keep at most one row per ISO week for this many weeks.
+    #[serde(default = "default_keep_weekly")]
+    pub keep_weekly: usize,
+    /// Beyond that, keep at most one row per month for this many months.
+    #[serde(default = "default_keep_monthly")]
+    pub keep_monthly: usize,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub database_path: Option<PathBuf>,
+    /// Tokenizer model to count `input_tokens`/`output_tokens` with, e.g.
+    /// `"cl100k_base"`. Only takes effect when rtk is built with the
+    /// `bpe-tokenizer` feature; otherwise the zero-dependency
+    /// [`crate::tokenizer::HeuristicTokenizer`] is always used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tokenizer_model: Option<String>,
+}
+
+fn default_keep_last() -> usize {
+    200
+}
+
+fn default_keep_daily() -> usize {
+    30
+}
+
+fn default_keep_weekly() -> usize {
+    12
+}
+
+fn default_keep_monthly() -> usize {
+    12
 }
 
 impl Default for TrackingConfig {
@@ -25,7 +124,12 @@ impl Default for TrackingConfig {
         Self {
             enabled: true,
             history_days: 90,
+            keep_last: default_keep_last(),
+            keep_daily: default_keep_daily(),
+            keep_weekly: default_keep_weekly(),
+            keep_monthly: default_keep_monthly(),
             database_path: None,
+            tokenizer_model: None,
         }
     }
 }
@@ -69,7 +173,236 @@ impl Default for FilterConfig {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EnvConfig {
+    /// Minimum Shannon entropy (bits/char) for a value longer than 16
+    /// chars to be flagged as a likely secret, regardless of key name.
+    pub entropy_threshold: f64,
+    /// Extra credential prefixes to flag on top of the built-in set
+    /// (`AKIA`, `ghp_`, `sk-`, `xoxb-`, ...).
+    pub extra_token_prefixes: Vec<String>,
+}
+
+impl Default for EnvConfig {
+    fn default() -> Self {
+        Self {
+            entropy_threshold: 4.0,
+            extra_token_prefixes: Vec::new(),
+        }
+    }
+}
+
+/// Rules applied to `rtk err`/`rtk test` output before it's filtered, to
+/// replace run-specific noise (absolute paths, timestamps, temp dirs,
+/// durations) with stable placeholders - ui_test's `stderr_filters` for this
+/// crate's compact-output commands. Empty by default; machine/project-specific
+/// rules belong in the config file, not hardcoded here.
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct NormalizeConfig {
+    #[serde(default)]
+    pub rules: Vec<NormalizeRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NormalizeRule {
+    pub pattern: String,
+    pub replacement: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GhConfig {
+    /// How long a cached `gh pr list`/`pr view`/`run list` response stays
+    /// valid before a repeat invocation re-fetches it. `--no-cache`/
+    /// `--refresh` bypass this regardless of the configured value.
+    #[serde(default = "default_gh_cache_ttl_secs")]
+    pub cache_ttl_secs: u64,
+}
+
+fn default_gh_cache_ttl_secs() -> u64 {
+    30
+}
+
+impl Default for GhConfig {
+    fn default() -> Self {
+        Self {
+            cache_ttl_secs: default_gh_cache_ttl_secs(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GitConfig {
+    /// Execution engine for `rtk git status`/`diff`/`show`/`log`:
+    /// `"subprocess"` (default) shells out to the system `git` binary;
+    /// `"libgit2"` reads the repository directly via the `git2` crate,
+    /// only available when rtk is built with the `libgit2-backend`
+    /// feature. Falls back to `"subprocess"` silently if that feature
+    /// isn't compiled in.
+    #[serde(default = "default_git_backend")]
+    pub backend: String,
+}
+
+fn default_git_backend() -> String {
+    "subprocess".to_string()
+}
+
+impl Default for GitConfig {
+    fn default() -> Self {
+        Self {
+            backend: default_git_backend(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EventsConfig {
+    /// Off by default: emitting an event per wrapped command is extra I/O
+    /// (and, for the webhook sink, a network call) most invocations don't
+    /// want.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Where structured events go: `"stdout"` (one NDJSON line per event),
+    /// `"file"` (append to `file_path`), or `"webhook"` (POST to
+    /// `webhook_url`). See [`crate::events`].
+    #[serde(default = "default_events_sink")]
+    pub sink: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_path: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+}
+
+fn default_events_sink() -> String {
+    "stdout".to_string()
+}
+
+impl Default for EventsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            sink: default_events_sink(),
+            file_path: None,
+            webhook_url: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct MonorepoConfig {
+    /// Explicit project roots for `git status --by-project` grouping,
+    /// relative to the repo root (e.g. `["services/api", "services/web"]`).
+    /// Empty means auto-discover by locating `Cargo.toml`/`package.json`/
+    /// `go.mod`. See [`crate::monorepo::resolve_project_roots`].
+    #[serde(default)]
+    pub roots: Vec<String>,
+}
+
+/// One `[profile.<name>]` entry. Every field is optional so a profile can
+/// override just the knobs it cares about; unset fields are resolved via
+/// `inherit` (see [`Config::resolve_profile`]).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ProfileConfig {
+    /// Profile to inherit unset fields from. Defaults to `"default"` (if
+    /// such a profile entry exists) so every profile implicitly extends it
+    /// unless it names a different parent or `"none"`.
+    #[serde(default)]
+    pub inherit: Option<String>,
+    /// Default [`crate::filter::FilterLevel`] for commands that accept one
+    /// (`none`, `minimal`, `aggressive`).
+    #[serde(default)]
+    pub filter_level: Option<String>,
+    /// Truncate individual output lines longer than this many characters.
+    #[serde(default)]
+    pub max_line_length: Option<usize>,
+    /// Minimum repeat count before a line is considered a duplicate worth
+    /// collapsing.
+    #[serde(default)]
+    pub dedup_threshold: Option<usize>,
+    /// Keep lines reporting a passing test, not just failures.
+    #[serde(default)]
+    pub keep_passing_tests: Option<bool>,
+    /// Truncate a filtered report to at most this many lines.
+    #[serde(default)]
+    pub truncate_limit: Option<usize>,
+}
+
+/// [`ProfileConfig`] with every field resolved to a concrete value --
+/// either from the profile chain or from the built-in defaults below --
+/// so `*_cmd` runners never have to think about inheritance or `Option`s.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResolvedProfile {
+    pub filter_level: crate::filter::FilterLevel,
+    pub max_line_length: usize,
+    pub dedup_threshold: usize,
+    pub keep_passing_tests: bool,
+    pub truncate_limit: usize,
+}
+
+impl Default for ResolvedProfile {
+    fn default() -> Self {
+        Self {
+            filter_level: crate::filter::FilterLevel::Minimal,
+            max_line_length: 500,
+            dedup_threshold: 1,
+            keep_passing_tests: false,
+            truncate_limit: 10_000,
+        }
+    }
+}
+
 impl Config {
+    /// Resolve `name` (or just the `"default"` profile and built-in
+    /// defaults, if `name` is `None`) by walking its `inherit` chain from
+    /// least to most specific, applying each profile's set fields over the
+    /// one before it. A missing `name` is an error; a missing `"default"`
+    /// entry silently falls back to [`ResolvedProfile::default`].
+    pub fn resolve_profile(&self, name: Option<&str>) -> Result<ResolvedProfile> {
+        let mut chain = Vec::new();
+        let mut current = match name {
+            Some(n) => {
+                if !self.profile.contains_key(n) {
+                    anyhow::bail!("unknown profile '{}' (no [profile.{}] config entry)", n, n);
+                }
+                n.to_string()
+            }
+            None => "default".to_string(),
+        };
+
+        let mut seen = HashSet::new();
+        while let Some(profile) = self.profile.get(&current) {
+            if !seen.insert(current.clone()) {
+                anyhow::bail!("profile inheritance cycle detected at '{}'", current);
+            }
+            chain.push(profile.clone());
+            match &profile.inherit {
+                Some(parent) if parent != "none" => current = parent.clone(),
+                _ => break,
+            }
+        }
+
+        let mut resolved = ResolvedProfile::default();
+        for profile in chain.into_iter().rev() {
+            if let Some(level) = &profile.filter_level {
+                resolved.filter_level = level
+                    .parse()
+                    .map_err(|e: String| anyhow::anyhow!("invalid filter_level: {}", e))?;
+            }
+            if let Some(v) = profile.max_line_length {
+                resolved.max_line_length = v;
+            }
+            if let Some(v) = profile.dedup_threshold {
+                resolved.dedup_threshold = v;
+            }
+            if let Some(v) = profile.keep_passing_tests {
+                resolved.keep_passing_tests = v;
+            }
+            if let Some(v) = profile.truncate_limit {
+                resolved.truncate_limit = v;
+            }
+        }
+        Ok(resolved)
+    }
+
     pub fn load() -> Result<Self> {
         let path = get_config_path()?;
 
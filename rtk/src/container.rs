@@ -1,5 +1,6 @@
 use crate::tracking;
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
+use std::env;
 use std::ffi::OsString;
 use std::process::Command;
 
@@ -8,6 +9,7 @@ pub enum ContainerCmd {
     DockerPs,
     DockerImages,
     DockerLogs,
+    DockerStats,
     KubectlPods,
     KubectlServices,
     KubectlLogs,
@@ -15,65 +17,234 @@ pub enum ContainerCmd {
 
 pub fn run(cmd: ContainerCmd, args: &[String], verbose: u8) -> Result<()> {
     match cmd {
-        ContainerCmd::DockerPs => docker_ps(verbose),
+        ContainerCmd::DockerPs => docker_ps(args, verbose),
         ContainerCmd::DockerImages => docker_images(verbose),
         ContainerCmd::DockerLogs => docker_logs(args, verbose),
-        ContainerCmd::KubectlPods => kubectl_pods(args, verbose),
-        ContainerCmd::KubectlServices => kubectl_services(args, verbose),
-        ContainerCmd::KubectlLogs => kubectl_logs(args, verbose),
+        ContainerCmd::DockerStats => docker_stats(verbose),
+        ContainerCmd::KubectlPods => kubectl_pods(&with_default_namespace(args), verbose),
+        ContainerCmd::KubectlServices => kubectl_services(&with_default_namespace(args), verbose),
+        ContainerCmd::KubectlLogs => kubectl_logs(&with_default_namespace(args), verbose),
     }
 }
 
-fn docker_ps(_verbose: u8) -> Result<()> {
-    let timer = tracking::TimedExecution::start();
+/// Detect whether rtk is running inside a Docker container: via the
+/// `/.dockerenv` marker file Docker writes into every container's root, or
+/// (the fallback for runtimes that skip it) `/proc/1/cgroup` mentioning
+/// `docker`.
+pub fn inside_docker() -> bool {
+    std::path::Path::new("/.dockerenv").exists()
+        || std::fs::read_to_string("/proc/1/cgroup")
+            .map(|cgroup| cgroup.contains("docker"))
+            .unwrap_or(false)
+}
 
-    let raw = Command::new("docker")
-        .args(["ps"])
-        .output()
-        .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
-        .unwrap_or_default();
+/// Detect whether rtk is running inside a Kubernetes pod: via the mounted
+/// service-account token directory every pod gets by default, or (the
+/// fallback when that's been disabled) `/proc/1/cgroup` mentioning
+/// `kubepods`.
+pub fn inside_kubernetes() -> bool {
+    std::path::Path::new("/var/run/secrets/kubernetes.io/serviceaccount").exists()
+        || std::fs::read_to_string("/proc/1/cgroup")
+            .map(|cgroup| cgroup.contains("kubepods"))
+            .unwrap_or(false)
+}
 
-    let output = Command::new("docker")
-        .args([
-            "ps",
-            "--format",
-            "{{.ID}}\t{{.Names}}\t{{.Status}}\t{{.Image}}\t{{.Ports}}",
-        ])
-        .output()
-        .context("Failed to run docker ps")?;
+/// The namespace rtk itself is running in, read from the mounted
+/// service-account token. Only meaningful when `inside_kubernetes()`.
+fn current_namespace() -> Option<String> {
+    std::fs::read_to_string("/var/run/secrets/kubernetes.io/serviceaccount/namespace")
+        .ok()
+        .map(|ns| ns.trim().to_string())
+        .filter(|ns| !ns.is_empty())
+}
+
+/// When running in-cluster with no explicit `-n`/`-A` already present,
+/// default `kubectl` commands to the namespace rtk itself is running in -
+/// so users don't have to pass `-n namespace` repeatedly from inside a pod.
+fn with_default_namespace(args: &[String]) -> Vec<String> {
+    if args.iter().any(|a| a == "-n" || a == "-A") || !inside_kubernetes() {
+        return args.to_vec();
+    }
+    match current_namespace() {
+        Some(ns) => {
+            let mut args = args.to_vec();
+            args.push("-n".to_string());
+            args.push(ns);
+            args
+        }
+        None => args.to_vec(),
+    }
+}
+
+/// A short annotation for summary headers, making in-cluster output
+/// self-describing about where it ran (e.g. `(in-cluster, ns=foo) `).
+/// Empty outside a Kubernetes pod.
+fn in_cluster_annotation() -> String {
+    if !inside_kubernetes() {
+        return String::new();
+    }
+    match current_namespace() {
+        Some(ns) => format!("(in-cluster, ns={}) ", ns),
+        None => "(in-cluster) ".to_string(),
+    }
+}
+
+/// One Docker daemon to aggregate across. `host` is `None` for the local
+/// daemon (no `-H` flag needed) and `Some(endpoint)` for a remote one.
+struct DockerEndpoint {
+    name: String,
+    host: Option<String>,
+}
+
+/// The endpoints `docker_ps`/`docker_images` aggregate across, read from
+/// `RTK_DOCKER_HOSTS` - a comma-separated list of `name=tcp://host:2375` (or
+/// `ssh://host`) entries, falling back to a bare `host` using the host
+/// itself as the name. Unset (the common case) means just the local daemon,
+/// so single-host operators see no change in behavior.
+fn docker_endpoints() -> Vec<DockerEndpoint> {
+    match env::var("RTK_DOCKER_HOSTS") {
+        Ok(val) if !val.trim().is_empty() => val
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| match entry.split_once('=') {
+                Some((name, host)) => DockerEndpoint {
+                    name: name.to_string(),
+                    host: Some(host.to_string()),
+                },
+                None => DockerEndpoint {
+                    name: entry.to_string(),
+                    host: Some(entry.to_string()),
+                },
+            })
+            .collect(),
+        _ => vec![DockerEndpoint {
+            name: "local".to_string(),
+            host: None,
+        }],
+    }
+}
+
+/// A `docker` invocation targeting `endpoint` (`-H <host>` when remote).
+fn docker_command(endpoint: &DockerEndpoint) -> Command {
+    let mut cmd = Command::new("docker");
+    if let Some(host) = &endpoint.host {
+        cmd.args(["-H", host]);
+    }
+    cmd
+}
+
+fn docker_ps(args: &[String], _verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+    let endpoints = docker_endpoints();
+    let multi = endpoints.len() > 1;
+
+    let mut raw = String::new();
+    let mut rows: Vec<(String, String)> = Vec::new(); // (endpoint name, formatted line)
+
+    for endpoint in &endpoints {
+        raw.push_str(
+            &docker_command(endpoint)
+                .args(["ps"])
+                .output()
+                .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
+                .unwrap_or_default(),
+        );
+
+        let output = docker_command(endpoint)
+            .args([
+                "ps",
+                "--format",
+                "{{.ID}}\t{{.Names}}\t{{.Status}}\t{{.Image}}\t{{.Ports}}",
+            ])
+            .output();
+        let output = match output {
+            Ok(o) => o,
+            Err(_) if multi => continue,
+            Err(e) => return Err(e).context("Failed to run docker ps"),
+        };
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            rows.push((endpoint.name.clone(), line.to_string()));
+        }
+    }
+
+    // "which endpoint has this container" mode: a container name/id narrows
+    // the aggregate down to a single match instead of listing everything.
+    if let Some(query) = args.first() {
+        let matches: Vec<&(String, String)> = rows
+            .iter()
+            .filter(|(_, line)| {
+                line.split('\t')
+                    .next()
+                    .is_some_and(|id| id.starts_with(query.as_str()))
+                    || line.split('\t').nth(1) == Some(query.as_str())
+            })
+            .collect();
+
+        return match matches.len() {
+            0 => {
+                let rtk = format!("🐳 no container matching `{}` found", query);
+                println!("{}", rtk);
+                timer.track("docker ps", "rtk docker ps", &raw, &rtk);
+                Ok(())
+            }
+            1 => {
+                let (endpoint_name, line) = matches[0];
+                let parts: Vec<&str> = line.split('\t').collect();
+                let name = parts.get(1).copied().unwrap_or(query.as_str());
+                let rtk = format!("🐳 {} is on endpoint `{}`", name, endpoint_name);
+                println!("{}", rtk);
+                timer.track("docker ps", "rtk docker ps", &raw, &rtk);
+                Ok(())
+            }
+            n => bail!(
+                "`{}` matches {} containers across endpoints ({}) - use a more specific id",
+                query,
+                n,
+                matches
+                    .iter()
+                    .map(|(e, _)| e.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+    }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
     let mut rtk = String::new();
 
-    if stdout.trim().is_empty() {
+    if rows.is_empty() {
         rtk.push_str("🐳 0 containers");
         println!("{}", rtk);
         timer.track("docker ps", "rtk docker ps", &raw, &rtk);
         return Ok(());
     }
 
-    let count = stdout.lines().count();
-    rtk.push_str(&format!("🐳 {} containers:\n", count));
+    rtk.push_str(&format!("🐳 {} containers:\n", rows.len()));
 
-    for line in stdout.lines().take(15) {
+    for (endpoint_name, line) in rows.iter().take(15) {
         let parts: Vec<&str> = line.split('\t').collect();
         if parts.len() >= 4 {
             let id = &parts[0][..12.min(parts[0].len())];
             let name = parts[1];
             let short_image = parts.get(3).unwrap_or(&"").split('/').last().unwrap_or("");
             let ports = compact_ports(parts.get(4).unwrap_or(&""));
+            let tag = if multi {
+                format!("[{}] ", endpoint_name)
+            } else {
+                String::new()
+            };
             if ports == "-" {
-                rtk.push_str(&format!("  {} {} ({})\n", id, name, short_image));
+                rtk.push_str(&format!("  {}{} {} ({})\n", tag, id, name, short_image));
             } else {
                 rtk.push_str(&format!(
-                    "  {} {} ({}) [{}]\n",
-                    id, name, short_image, ports
+                    "  {}{} {} ({}) [{}]\n",
+                    tag, id, name, short_image, ports
                 ));
             }
         }
     }
-    if count > 15 {
-        rtk.push_str(&format!("  ... +{} more", count - 15));
+    if rows.len() > 15 {
+        rtk.push_str(&format!("  ... +{} more", rows.len() - 15));
     }
 
     print!("{}", rtk);
@@ -83,23 +254,37 @@ fn docker_ps(_verbose: u8) -> Result<()> {
 
 fn docker_images(_verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
+    let endpoints = docker_endpoints();
+    let multi = endpoints.len() > 1;
+
+    let mut raw = String::new();
+    let mut rows: Vec<(String, String)> = Vec::new(); // (endpoint name, formatted line)
+
+    for endpoint in &endpoints {
+        raw.push_str(
+            &docker_command(endpoint)
+                .args(["images"])
+                .output()
+                .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
+                .unwrap_or_default(),
+        );
+
+        let output = docker_command(endpoint)
+            .args(["images", "--format", "{{.Repository}}:{{.Tag}}\t{{.Size}}"])
+            .output();
+        let output = match output {
+            Ok(o) => o,
+            Err(_) if multi => continue,
+            Err(e) => return Err(e).context("Failed to run docker images"),
+        };
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            rows.push((endpoint.name.clone(), line.to_string()));
+        }
+    }
 
-    let raw = Command::new("docker")
-        .args(["images"])
-        .output()
-        .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
-        .unwrap_or_default();
-
-    let output = Command::new("docker")
-        .args(["images", "--format", "{{.Repository}}:{{.Tag}}\t{{.Size}}"])
-        .output()
-        .context("Failed to run docker images")?;
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let lines: Vec<&str> = stdout.lines().collect();
     let mut rtk = String::new();
 
-    if lines.is_empty() {
+    if rows.is_empty() {
         rtk.push_str("🐳 0 images");
         println!("{}", rtk);
         timer.track("docker images", "rtk docker images", &raw, &rtk);
@@ -107,29 +292,20 @@ fn docker_images(_verbose: u8) -> Result<()> {
     }
 
     let mut total_size_mb: f64 = 0.0;
-    for line in &lines {
+    for (_, line) in &rows {
         let parts: Vec<&str> = line.split('\t').collect();
         if let Some(size_str) = parts.get(1) {
-            if size_str.contains("GB") {
-                if let Ok(n) = size_str.replace("GB", "").trim().parse::<f64>() {
-                    total_size_mb += n * 1024.0;
-                }
-            } else if size_str.contains("MB") {
-                if let Ok(n) = size_str.replace("MB", "").trim().parse::<f64>() {
-                    total_size_mb += n;
-                }
-            }
+            total_size_mb += parse_size_to_mb(size_str);
         }
     }
 
-    let total_display = if total_size_mb > 1024.0 {
-        format!("{:.1}GB", total_size_mb / 1024.0)
-    } else {
-        format!("{:.0}MB", total_size_mb)
-    };
-    rtk.push_str(&format!("🐳 {} images ({})\n", lines.len(), total_display));
+    rtk.push_str(&format!(
+        "🐳 {} images ({})\n",
+        rows.len(),
+        format_mb(total_size_mb)
+    ));
 
-    for line in lines.iter().take(15) {
+    for (endpoint_name, line) in rows.iter().take(15) {
         let parts: Vec<&str> = line.split('\t').collect();
         if !parts.is_empty() {
             let image = parts[0];
@@ -139,11 +315,16 @@ fn docker_images(_verbose: u8) -> Result<()> {
             } else {
                 image.to_string()
             };
-            rtk.push_str(&format!("  {} [{}]\n", short, size));
+            let tag = if multi {
+                format!("[{}] ", endpoint_name)
+            } else {
+                String::new()
+            };
+            rtk.push_str(&format!("  {}{} [{}]\n", tag, short, size));
         }
     }
-    if lines.len() > 15 {
-        rtk.push_str(&format!("  ... +{} more", lines.len() - 15));
+    if rows.len() > 15 {
+        rtk.push_str(&format!("  ... +{} more", rows.len() - 15));
     }
 
     print!("{}", rtk);
@@ -181,7 +362,361 @@ fn docker_logs(args: &[String], _verbose: u8) -> Result<()> {
     Ok(())
 }
 
-fn kubectl_pods(args: &[String], _verbose: u8) -> Result<()> {
+/// Parse a human size string into megabytes. Handles both the decimal
+/// `MB`/`GB`/`KB` suffixes `docker images` emits and the binary
+/// `MiB`/`GiB`/`KiB` suffixes `docker stats` emits.
+fn parse_size_to_mb(size_str: &str) -> f64 {
+    let s = size_str.trim();
+    let (value, unit_mb) = if let Some(v) = s.strip_suffix("GiB") {
+        (v, 1024.0)
+    } else if let Some(v) = s.strip_suffix("MiB") {
+        (v, 1.0)
+    } else if let Some(v) = s.strip_suffix("KiB") {
+        (v, 1.0 / 1024.0)
+    } else if let Some(v) = s.strip_suffix("GB") {
+        (v, 1024.0)
+    } else if let Some(v) = s.strip_suffix("MB") {
+        (v, 1.0)
+    } else if let Some(v) = s.strip_suffix("KB") {
+        (v, 1.0 / 1024.0)
+    } else if let Some(v) = s.strip_suffix('B') {
+        (v, 1.0 / (1024.0 * 1024.0))
+    } else {
+        return 0.0;
+    };
+    value.trim().parse::<f64>().unwrap_or(0.0) * unit_mb
+}
+
+/// Render a megabyte quantity back into the `MB`/`GB` units `docker images`
+/// and `docker stats` summaries are displayed in.
+fn format_mb(mb: f64) -> String {
+    if mb > 1024.0 {
+        format!("{:.1}GB", mb / 1024.0)
+    } else {
+        format!("{:.0}MB", mb)
+    }
+}
+
+/// One `docker stats` row, with the percentages and sizes already parsed out
+/// of their display strings.
+struct ContainerStat {
+    name: String,
+    cpu_pct: f64,
+    mem_used_mb: f64,
+    mem_limit_mb: f64,
+    mem_pct: f64,
+    net_io: String,
+}
+
+/// Containers at or above either threshold are called out in the `⚠️`
+/// outliers section instead of only appearing in the top-N lists.
+const STATS_CPU_WARN_PCT: f64 = 80.0;
+const STATS_MEM_WARN_PCT: f64 = 80.0;
+
+fn docker_stats(_verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let output = Command::new("docker")
+        .args([
+            "stats",
+            "--no-stream",
+            "--format",
+            "{{.Name}}\t{{.CPUPerc}}\t{{.MemUsage}}\t{{.MemPerc}}\t{{.NetIO}}",
+        ])
+        .output()
+        .context("Failed to run docker stats")?;
+
+    let raw = String::from_utf8_lossy(&output.stdout).to_string();
+    let mut rtk = String::new();
+
+    let stats: Vec<ContainerStat> = raw
+        .lines()
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.split('\t').collect();
+            if parts.len() < 5 {
+                return None;
+            }
+            let (used, limit) = parts[2].split_once('/').unwrap_or((parts[2], "0"));
+            Some(ContainerStat {
+                name: parts[0].to_string(),
+                cpu_pct: parts[1].trim_end_matches('%').parse().unwrap_or(0.0),
+                mem_used_mb: parse_size_to_mb(used),
+                mem_limit_mb: parse_size_to_mb(limit),
+                mem_pct: parts[3].trim_end_matches('%').parse().unwrap_or(0.0),
+                net_io: parts[4].to_string(),
+            })
+        })
+        .collect();
+
+    if stats.is_empty() {
+        rtk.push_str("🐳 0 containers");
+        println!("{}", rtk);
+        timer.track("docker stats", "rtk docker stats", &raw, &rtk);
+        return Ok(());
+    }
+
+    let total_cpu: f64 = stats.iter().map(|s| s.cpu_pct).sum();
+    let total_mem_used: f64 = stats.iter().map(|s| s.mem_used_mb).sum();
+    let total_mem_limit: f64 = stats.iter().map(|s| s.mem_limit_mb).sum();
+
+    rtk.push_str(&format!(
+        "🐳 {} containers: {:.1}% CPU, {} / {} mem\n",
+        stats.len(),
+        total_cpu,
+        format_mb(total_mem_used),
+        format_mb(total_mem_limit),
+    ));
+
+    let mut by_cpu: Vec<&ContainerStat> = stats.iter().collect();
+    by_cpu.sort_by(|a, b| b.cpu_pct.total_cmp(&a.cpu_pct));
+    rtk.push_str("  Top CPU:\n");
+    for s in by_cpu.iter().take(5) {
+        rtk.push_str(&format!(
+            "    {} {:.1}% (net {})\n",
+            s.name, s.cpu_pct, s.net_io
+        ));
+    }
+
+    let mut by_mem: Vec<&ContainerStat> = stats.iter().collect();
+    by_mem.sort_by(|a, b| b.mem_used_mb.total_cmp(&a.mem_used_mb));
+    rtk.push_str("  Top Memory:\n");
+    for s in by_mem.iter().take(5) {
+        rtk.push_str(&format!(
+            "    {} {} ({:.1}%)\n",
+            s.name,
+            format_mb(s.mem_used_mb),
+            s.mem_pct
+        ));
+    }
+
+    let outliers: Vec<String> = stats
+        .iter()
+        .filter(|s| s.cpu_pct >= STATS_CPU_WARN_PCT || s.mem_pct >= STATS_MEM_WARN_PCT)
+        .map(|s| {
+            let mut reasons = Vec::new();
+            if s.cpu_pct >= STATS_CPU_WARN_PCT {
+                reasons.push(format!("{:.1}% CPU", s.cpu_pct));
+            }
+            if s.mem_pct >= STATS_MEM_WARN_PCT {
+                reasons.push(format!("{:.1}% mem", s.mem_pct));
+            }
+            format!("{}: {}", s.name, reasons.join(", "))
+        })
+        .collect();
+    if !outliers.is_empty() {
+        rtk.push_str("⚠️  Outliers:\n");
+        for outlier in &outliers {
+            rtk.push_str(&format!("  {}\n", outlier));
+        }
+    }
+
+    print!("{}", rtk);
+    timer.track("docker stats", "rtk docker stats", &raw, &rtk);
+    Ok(())
+}
+
+/// A single container-level health signal, mirroring the checks `kubectl
+/// describe` surfaces but condensed into a compact, greppable line instead
+/// of a multi-paragraph describe output.
+#[derive(Debug, Clone)]
+enum SuspiciousContainerReason {
+    /// `state.waiting.reason` verbatim, e.g. `ImagePullBackOff`.
+    Waiting(String),
+    /// Ready is false with no more specific reason to explain why.
+    NotReady,
+    /// `restartCount > 0`, with the last termination's exit code + reason.
+    Restarted {
+        count: i64,
+        exit_code: i64,
+        reason: String,
+    },
+    /// Current state is terminated with a nonzero exit code.
+    TerminatedWithError(i64),
+}
+
+impl std::fmt::Display for SuspiciousContainerReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SuspiciousContainerReason::Waiting(reason) => write!(f, "{}", reason),
+            SuspiciousContainerReason::NotReady => write!(f, "not ready"),
+            SuspiciousContainerReason::Restarted {
+                count,
+                exit_code,
+                reason,
+            } => write!(f, "restarted x{} ({}, exit {})", count, reason, exit_code),
+            SuspiciousContainerReason::TerminatedWithError(exit_code) => {
+                write!(f, "terminated (exit {})", exit_code)
+            }
+        }
+    }
+}
+
+/// Diagnose one `containerStatuses[]` entry. A container can carry more than
+/// one of these at once (e.g. currently `CrashLoopBackOff` *and* a restart
+/// history), so all that apply are returned; `NotReady` is only added when
+/// nothing more specific already explains it.
+fn diagnose_container(c: &serde_json::Value) -> Vec<SuspiciousContainerReason> {
+    let mut reasons = Vec::new();
+
+    if let Some(reason) = c["state"]["waiting"]["reason"].as_str() {
+        reasons.push(SuspiciousContainerReason::Waiting(reason.to_string()));
+    }
+
+    if let Some(exit_code) = c["state"]["terminated"]["exitCode"].as_i64() {
+        if exit_code != 0 {
+            reasons.push(SuspiciousContainerReason::TerminatedWithError(exit_code));
+        }
+    }
+
+    let restart_count = c["restartCount"].as_i64().unwrap_or(0);
+    if restart_count > 0 {
+        let exit_code = c["lastState"]["terminated"]["exitCode"]
+            .as_i64()
+            .unwrap_or(0);
+        let reason = c["lastState"]["terminated"]["reason"]
+            .as_str()
+            .unwrap_or("unknown")
+            .to_string();
+        reasons.push(SuspiciousContainerReason::Restarted {
+            count: restart_count,
+            exit_code,
+            reason,
+        });
+    }
+
+    if reasons.is_empty() && c["ready"].as_bool() == Some(false) {
+        reasons.push(SuspiciousContainerReason::NotReady);
+    }
+
+    reasons
+}
+
+/// Render the same compact pod report the shell and typed backends both
+/// produce, given the counts and pre-formatted issue lines each one scans
+/// out of its own pod representation.
+fn render_pod_report(
+    pod_count: usize,
+    running: usize,
+    pending: usize,
+    failed: usize,
+    restarts_total: i64,
+    issues: &[String],
+) -> String {
+    let mut rtk = String::new();
+
+    let mut parts = Vec::new();
+    if running > 0 {
+        parts.push(format!("{} ✓", running));
+    }
+    if pending > 0 {
+        parts.push(format!("{} pending", pending));
+    }
+    if failed > 0 {
+        parts.push(format!("{} ✗", failed));
+    }
+    if restarts_total > 0 {
+        parts.push(format!("{} restarts", restarts_total));
+    }
+
+    rtk.push_str(&format!(
+        "☸️  {}{} pods: {}\n",
+        in_cluster_annotation(),
+        pod_count,
+        parts.join(", ")
+    ));
+    if !issues.is_empty() {
+        rtk.push_str("⚠️  Issues:\n");
+        for issue in issues.iter().take(10) {
+            rtk.push_str(&format!("  {}\n", issue));
+        }
+        if issues.len() > 10 {
+            rtk.push_str(&format!("  ... +{} more", issues.len() - 10));
+        }
+    }
+
+    rtk
+}
+
+/// `kubectl pods` entry point. Tries the typed `kube-client` backend first
+/// (when built with that feature) and falls back to shelling out to
+/// `kubectl` otherwise - the typed backend also supports `--watch`, which
+/// the shell path has no equivalent for.
+fn kubectl_pods(args: &[String], verbose: u8) -> Result<()> {
+    let watch = args.iter().any(|a| a == "--watch");
+    let forwarded: Vec<String> = args
+        .iter()
+        .filter(|a| a.as_str() != "--watch")
+        .cloned()
+        .collect();
+
+    #[cfg(feature = "kube-client")]
+    {
+        let all = forwarded.iter().any(|a| a == "-A");
+        let namespace = forwarded
+            .iter()
+            .position(|a| a == "-n")
+            .and_then(|i| forwarded.get(i + 1))
+            .cloned();
+
+        if watch {
+            return crate::k8s_client::watch_pods(namespace.as_deref(), all, |pods| {
+                print!("\x1B[2J\x1B[H");
+                print!("{}", format_typed_pod_report(pods));
+            })
+            .or_else(|_| kubectl_pods_shell(&forwarded, verbose));
+        }
+
+        if let Ok(pods) = crate::k8s_client::list_pods(namespace.as_deref(), all) {
+            let rtk = format_typed_pod_report(&pods);
+            print!("{}", rtk);
+            return Ok(());
+        }
+    }
+
+    if watch {
+        println!(
+            "☸️  --watch requires rtk built with the `kube-client` feature; showing a one-shot snapshot instead"
+        );
+    }
+    kubectl_pods_shell(&forwarded, verbose)
+}
+
+#[cfg(feature = "kube-client")]
+fn format_typed_pod_report(pods: &[crate::k8s_client::PodSummary]) -> String {
+    let (mut running, mut pending, mut failed, mut restarts_total) = (0, 0, 0, 0i64);
+    let mut issues: Vec<String> = Vec::new();
+
+    for pod in pods {
+        restarts_total += pod.restarts as i64;
+        match pod.phase.as_str() {
+            "Failed" | "Error" => {
+                failed += 1;
+                issues.push(format!("{}/{} {}", pod.namespace, pod.name, pod.phase));
+            }
+            "Pending" => {
+                pending += 1;
+                issues.push(format!("{}/{} Pending", pod.namespace, pod.name));
+                for reason in &pod.issues {
+                    issues.push(format!("{}/{}: {}", pod.namespace, pod.name, reason));
+                }
+            }
+            _ => {
+                if pod.issues.is_empty() {
+                    running += 1;
+                } else {
+                    failed += 1;
+                    for reason in &pod.issues {
+                        issues.push(format!("{}/{}: {}", pod.namespace, pod.name, reason));
+                    }
+                }
+            }
+        }
+    }
+
+    render_pod_report(pods.len(), running, pending, failed, restarts_total, &issues)
+}
+
+fn kubectl_pods_shell(args: &[String], _verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
     let mut cmd = Command::new("kubectl");
@@ -221,68 +756,104 @@ fn kubectl_pods(args: &[String], _verbose: u8) -> Result<()> {
         let name = pod["metadata"]["name"].as_str().unwrap_or("-");
         let phase = pod["status"]["phase"].as_str().unwrap_or("Unknown");
 
+        let mut container_reasons: Vec<SuspiciousContainerReason> = Vec::new();
         if let Some(containers) = pod["status"]["containerStatuses"].as_array() {
             for c in containers {
                 restarts_total += c["restartCount"].as_i64().unwrap_or(0);
+                container_reasons.extend(diagnose_container(c));
             }
         }
 
         match phase {
-            "Running" => running += 1,
-            "Pending" => {
-                pending += 1;
-                issues.push(format!("{}/{} Pending", ns, name));
-            }
             "Failed" | "Error" => {
                 failed += 1;
                 issues.push(format!("{}/{} {}", ns, name, phase));
             }
+            "Pending" => {
+                pending += 1;
+                issues.push(format!("{}/{} Pending", ns, name));
+                for reason in &container_reasons {
+                    issues.push(format!("{}/{}: {}", ns, name, reason));
+                }
+            }
+            // "Running" pods, and any other phase, are only healthy once
+            // every container is free of a suspicious reason - a
+            // CrashLoopBackOff container still reports its pod as Running.
             _ => {
-                if let Some(containers) = pod["status"]["containerStatuses"].as_array() {
-                    for c in containers {
-                        if let Some(w) = c["state"]["waiting"]["reason"].as_str() {
-                            if w.contains("CrashLoop") || w.contains("Error") {
-                                failed += 1;
-                                issues.push(format!("{}/{} {}", ns, name, w));
-                            }
-                        }
+                if container_reasons.is_empty() {
+                    running += 1;
+                } else {
+                    failed += 1;
+                    for reason in &container_reasons {
+                        issues.push(format!("{}/{}: {}", ns, name, reason));
                     }
                 }
             }
         }
     }
 
-    let mut parts = Vec::new();
-    if running > 0 {
-        parts.push(format!("{} ✓", running));
-    }
-    if pending > 0 {
-        parts.push(format!("{} pending", pending));
-    }
-    if failed > 0 {
-        parts.push(format!("{} ✗", failed));
+    let rtk = render_pod_report(pods.len(), running, pending, failed, restarts_total, &issues);
+
+    print!("{}", rtk);
+    timer.track("kubectl get pods", "rtk kubectl pods", &raw, &rtk);
+    Ok(())
+}
+
+/// Render the same compact service report the shell and typed backends both
+/// produce, given each service's namespace/name/type/ports already
+/// extracted out of its own representation.
+fn render_service_report(lines: &[(String, String, String, Vec<String>)]) -> String {
+    let mut rtk = String::new();
+    rtk.push_str(&format!(
+        "☸️  {}{} services:\n",
+        in_cluster_annotation(),
+        lines.len()
+    ));
+
+    for (ns, name, svc_type, ports) in lines.iter().take(15) {
+        rtk.push_str(&format!(
+            "  {}/{} {} [{}]\n",
+            ns,
+            name,
+            svc_type,
+            ports.join(",")
+        ));
     }
-    if restarts_total > 0 {
-        parts.push(format!("{} restarts", restarts_total));
+    if lines.len() > 15 {
+        rtk.push_str(&format!("  ... +{} more", lines.len() - 15));
     }
 
-    rtk.push_str(&format!("☸️  {} pods: {}\n", pods.len(), parts.join(", ")));
-    if !issues.is_empty() {
-        rtk.push_str("⚠️  Issues:\n");
-        for issue in issues.iter().take(10) {
-            rtk.push_str(&format!("  {}\n", issue));
-        }
-        if issues.len() > 10 {
-            rtk.push_str(&format!("  ... +{} more", issues.len() - 10));
+    rtk
+}
+
+/// `kubectl services` entry point. Tries the typed `kube-client` backend
+/// first (when built with that feature) and falls back to shelling out to
+/// `kubectl` otherwise.
+fn kubectl_services(args: &[String], verbose: u8) -> Result<()> {
+    #[cfg(feature = "kube-client")]
+    {
+        let all = args.iter().any(|a| a == "-A");
+        let namespace = args
+            .iter()
+            .position(|a| a == "-n")
+            .and_then(|i| args.get(i + 1))
+            .cloned();
+
+        if let Ok(services) = crate::k8s_client::list_services(namespace.as_deref(), all) {
+            let lines: Vec<(String, String, String, Vec<String>)> = services
+                .into_iter()
+                .map(|s| (s.namespace, s.name, s.svc_type, s.ports))
+                .collect();
+            let rtk = render_service_report(&lines);
+            print!("{}", rtk);
+            return Ok(());
         }
     }
 
-    print!("{}", rtk);
-    timer.track("kubectl get pods", "rtk kubectl pods", &raw, &rtk);
-    Ok(())
+    kubectl_services_shell(args, verbose)
 }
 
-fn kubectl_services(args: &[String], _verbose: u8) -> Result<()> {
+fn kubectl_services_shell(args: &[String], _verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
     let mut cmd = Command::new("kubectl");
@@ -314,42 +885,36 @@ fn kubectl_services(args: &[String], _verbose: u8) -> Result<()> {
     }
 
     let services = items.unwrap();
-    rtk.push_str(&format!("☸️  {} services:\n", services.len()));
-
-    for svc in services.iter().take(15) {
-        let ns = svc["metadata"]["namespace"].as_str().unwrap_or("-");
-        let name = svc["metadata"]["name"].as_str().unwrap_or("-");
-        let svc_type = svc["spec"]["type"].as_str().unwrap_or("-");
-        let ports: Vec<String> = svc["spec"]["ports"]
-            .as_array()
-            .map(|arr| {
-                arr.iter()
-                    .map(|p| {
-                        let port = p["port"].as_i64().unwrap_or(0);
-                        let target = p["targetPort"]
-                            .as_i64()
-                            .or_else(|| p["targetPort"].as_str().and_then(|s| s.parse().ok()))
-                            .unwrap_or(port);
-                        if port == target {
-                            format!("{}", port)
-                        } else {
-                            format!("{}→{}", port, target)
-                        }
-                    })
-                    .collect()
-            })
-            .unwrap_or_default();
-        rtk.push_str(&format!(
-            "  {}/{} {} [{}]\n",
-            ns,
-            name,
-            svc_type,
-            ports.join(",")
-        ));
-    }
-    if services.len() > 15 {
-        rtk.push_str(&format!("  ... +{} more", services.len() - 15));
-    }
+    let lines: Vec<(String, String, String, Vec<String>)> = services
+        .iter()
+        .map(|svc| {
+            let ns = svc["metadata"]["namespace"].as_str().unwrap_or("-");
+            let name = svc["metadata"]["name"].as_str().unwrap_or("-");
+            let svc_type = svc["spec"]["type"].as_str().unwrap_or("-");
+            let ports: Vec<String> = svc["spec"]["ports"]
+                .as_array()
+                .map(|arr| {
+                    arr.iter()
+                        .map(|p| {
+                            let port = p["port"].as_i64().unwrap_or(0);
+                            let target = p["targetPort"]
+                                .as_i64()
+                                .or_else(|| p["targetPort"].as_str().and_then(|s| s.parse().ok()))
+                                .unwrap_or(port);
+                            if port == target {
+                                format!("{}", port)
+                            } else {
+                                format!("{}→{}", port, target)
+                            }
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            (ns.to_string(), name.to_string(), svc_type.to_string(), ports)
+        })
+        .collect();
+
+    let rtk = render_service_report(&lines);
 
     print!("{}", rtk);
     timer.track("kubectl get svc", "rtk kubectl svc", &raw, &rtk);
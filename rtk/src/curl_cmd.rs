@@ -54,7 +54,7 @@ fn filter_curl_output(output: &str) -> String {
     if (trimmed.starts_with('{') || trimmed.starts_with('['))
         && (trimmed.ends_with('}') || trimmed.ends_with(']'))
     {
-        if let Ok(schema) = json_cmd::filter_json_string(trimmed, 5) {
+        if let Ok(schema) = json_cmd::filter_json_string(trimmed, 5, "text") {
             return schema;
         }
     }
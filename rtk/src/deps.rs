@@ -1,11 +1,485 @@
 use crate::tracking;
 use anyhow::Result;
 use regex::Regex;
+use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-/// Summarize project dependencies
-pub fn run(path: &Path, verbose: u8) -> Result<()> {
+/// Directories skipped while expanding `**` workspace-member globs, so a
+/// monorepo scan doesn't wander into build output or dependency caches.
+const NOISE_DIRS: &[&str] = &[
+    "node_modules",
+    ".git",
+    "target",
+    "dist",
+    "build",
+    ".cache",
+    "vendor",
+];
+
+/// A dependency's actually-installed version and source, resolved from a
+/// lockfile (`Cargo.lock`, `package-lock.json`/`yarn.lock`, `go.sum`)
+/// rather than the manifest's loose version range.
+struct LockedDep {
+    version: String,
+    /// `Some("git")`/`Some("registry")` when the lockfile records where
+    /// the package came from; `None` when it doesn't say.
+    source: Option<String>,
+}
+
+/// A parsed Cargo.toml dependency entry, covering both the bare
+/// `name = "1.0"` shorthand and the detailed table form (`name = { version
+/// = "1.0", features = [...], git = "...", path = "...", workspace = true
+/// }`), mirroring tauri's `CargoManifestDependency`.
+#[derive(Default)]
+struct CargoDepSpec {
+    version: Option<String>,
+    features: Vec<String>,
+    path: Option<String>,
+    git: Option<String>,
+    branch: Option<String>,
+    rev: Option<String>,
+    workspace: bool,
+    /// Set when this dependency came from a `[target.'cfg(...)'.dependencies]`
+    /// table rather than the top-level one.
+    target_cfg: Option<String>,
+}
+
+/// Parse a single Cargo.toml dependency value, whether it's the bare
+/// version-string shorthand or the detailed table form.
+fn parse_cargo_dep_value(value: &toml::Value) -> CargoDepSpec {
+    match value {
+        toml::Value::String(version) => CargoDepSpec {
+            version: Some(version.clone()),
+            ..Default::default()
+        },
+        toml::Value::Table(table) => CargoDepSpec {
+            version: table.get("version").and_then(|v| v.as_str()).map(String::from),
+            features: table
+                .get("features")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+                .unwrap_or_default(),
+            path: table.get("path").and_then(|v| v.as_str()).map(String::from),
+            git: table.get("git").and_then(|v| v.as_str()).map(String::from),
+            branch: table.get("branch").and_then(|v| v.as_str()).map(String::from),
+            rev: table.get("rev").and_then(|v| v.as_str()).map(String::from),
+            workspace: table.get("workspace").and_then(|v| v.as_bool()).unwrap_or(false),
+            target_cfg: None,
+        },
+        _ => CargoDepSpec::default(),
+    }
+}
+
+/// Render a Cargo dependency, annotating path/git/workspace sources and
+/// enabled features instead of just a bare version: `serde (1.0.193,
+/// features: derive)`, `mylib (path: ../mylib)`, `foo (git: url#branch)`,
+/// `bar (workspace)`.
+fn format_cargo_dep(name: &str, spec: &CargoDepSpec, locked: &HashMap<String, LockedDep>) -> String {
+    let base = if spec.workspace {
+        format!("{name} (workspace)")
+    } else if let Some(path) = &spec.path {
+        format!("{name} (path: {path})")
+    } else if let Some(git) = &spec.git {
+        match spec.branch.as_deref().or(spec.rev.as_deref()) {
+            Some(refspec) => format!("{name} (git: {git}#{refspec})"),
+            None => format!("{name} (git: {git})"),
+        }
+    } else {
+        let declared = spec.version.as_deref().unwrap_or("*");
+        let (version, source) = match locked.get(name) {
+            Some(dep) => (dep.version.clone(), dep.source.clone()),
+            None => (format!("{declared}, unresolved"), None),
+        };
+        let mut parts = vec![version];
+        parts.extend(source);
+        if !spec.features.is_empty() {
+            parts.push(format!("features: {}", spec.features.join(", ")));
+        }
+        format!("{name} ({})", parts.join(", "))
+    };
+
+    match &spec.target_cfg {
+        Some(cfg) => format!("{base} [{cfg}]"),
+        None => base,
+    }
+}
+
+/// Render a dependency line using its lockfile-resolved version when
+/// available (e.g. `serde (1.0.193)`, `serde (1.0.193) [git]`), falling
+/// back to the manifest's loose version marked `unresolved` otherwise.
+fn format_dep(name: &str, declared_version: &str, locked: &HashMap<String, LockedDep>) -> String {
+    match locked.get(name) {
+        Some(dep) => match &dep.source {
+            Some(source) => format!("{name} ({}) [{source}]", dep.version),
+            None => format!("{name} ({})", dep.version),
+        },
+        None => format!("{name} ({declared_version}, unresolved)"),
+    }
+}
+
+/// Like [`format_dep`], but in `module version` form (no parens), matching
+/// `go.mod`'s own `require` line syntax.
+fn format_go_dep(module: &str, declared_version: &str, locked: &HashMap<String, LockedDep>) -> String {
+    match locked.get(module) {
+        Some(dep) => format!("{module} {}", dep.version),
+        None => format!("{module} {declared_version} (unresolved)"),
+    }
+}
+
+/// Parse `Cargo.lock`'s repeated `[[package]]` tables into `name ->
+/// (version, source)`. `source = "registry+..."` becomes `"registry"`;
+/// `source = "git+..."` becomes `"git"`; no `source` field (path deps,
+/// the root crate) leaves `source: None`.
+fn parse_cargo_lock(dir: &Path) -> HashMap<String, LockedDep> {
+    let mut out = HashMap::new();
+    let Ok(content) = fs::read_to_string(dir.join("Cargo.lock")) else {
+        return out;
+    };
+    let Ok(value) = content.parse::<toml::Value>() else {
+        return out;
+    };
+    let Some(packages) = value.get("package").and_then(|v| v.as_array()) else {
+        return out;
+    };
+
+    for pkg in packages {
+        let (Some(name), Some(version)) = (
+            pkg.get("name").and_then(|v| v.as_str()),
+            pkg.get("version").and_then(|v| v.as_str()),
+        ) else {
+            continue;
+        };
+        let source = pkg.get("source").and_then(|v| v.as_str()).map(|s| {
+            if s.starts_with("git+") {
+                "git".to_string()
+            } else {
+                "registry".to_string()
+            }
+        });
+        out.insert(name.to_string(), LockedDep { version: version.to_string(), source });
+    }
+
+    out
+}
+
+/// Parse `package-lock.json`: npm v2/v3's `packages` map (keyed by
+/// `node_modules/...` path) when present, otherwise npm v1's flat
+/// `dependencies` map.
+fn parse_npm_lock(dir: &Path) -> HashMap<String, LockedDep> {
+    let mut out = HashMap::new();
+    let Ok(content) = fs::read_to_string(dir.join("package-lock.json")) else {
+        return out;
+    };
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) else {
+        return out;
+    };
+
+    if let Some(packages) = json.get("packages").and_then(|v| v.as_object()) {
+        for (path, info) in packages {
+            if path.is_empty() {
+                continue; // the root package itself
+            }
+            let name = path.rsplit("node_modules/").next().unwrap_or(path);
+            let Some(version) = info.get("version").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            let source = info
+                .get("resolved")
+                .and_then(|v| v.as_str())
+                .map(classify_npm_source);
+            out.insert(name.to_string(), LockedDep { version: version.to_string(), source });
+        }
+    } else if let Some(deps) = json.get("dependencies").and_then(|v| v.as_object()) {
+        for (name, info) in deps {
+            let Some(version) = info.get("version").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            let source = info
+                .get("resolved")
+                .and_then(|v| v.as_str())
+                .map(classify_npm_source);
+            out.insert(name.to_string(), LockedDep { version: version.to_string(), source });
+        }
+    }
+
+    out
+}
+
+fn classify_npm_source(resolved: &str) -> String {
+    if resolved.starts_with("git") || resolved.contains("github.com") {
+        "git".to_string()
+    } else {
+        "registry".to_string()
+    }
+}
+
+/// Parse `yarn.lock`'s block format: an unindented header line listing one
+/// or more `"name@range"` specs, followed by indented `version "..."` and
+/// `resolved "..."` lines.
+fn parse_yarn_lock(dir: &Path) -> HashMap<String, LockedDep> {
+    let mut out = HashMap::new();
+    let Ok(content) = fs::read_to_string(dir.join("yarn.lock")) else {
+        return out;
+    };
+
+    let mut names: Vec<String> = Vec::new();
+    let mut version: Option<String> = None;
+    let mut source: Option<String> = None;
+
+    for line in content.lines() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if !line.starts_with(' ') {
+            flush_yarn_entry(&mut names, &mut version, &mut source, &mut out);
+            names = line
+                .trim_end_matches(':')
+                .split(", ")
+                .filter_map(|spec| {
+                    let spec = spec.trim_matches('"');
+                    spec.rsplit_once('@').map(|(name, _)| name.to_string())
+                })
+                .collect();
+        } else {
+            let trimmed = line.trim();
+            if let Some(rest) = trimmed.strip_prefix("version ") {
+                version = Some(rest.trim_matches('"').to_string());
+            } else if let Some(rest) = trimmed.strip_prefix("resolved ") {
+                let resolved = rest.trim_matches('"');
+                source = Some(if resolved.contains("git") {
+                    "git".to_string()
+                } else {
+                    "registry".to_string()
+                });
+            }
+        }
+    }
+    flush_yarn_entry(&mut names, &mut version, &mut source, &mut out);
+
+    out
+}
+
+fn flush_yarn_entry(
+    names: &mut Vec<String>,
+    version: &mut Option<String>,
+    source: &mut Option<String>,
+    out: &mut HashMap<String, LockedDep>,
+) {
+    if let Some(v) = version.take() {
+        for name in names.drain(..) {
+            out.insert(name, LockedDep { version: v.clone(), source: source.clone() });
+        }
+    } else {
+        names.clear();
+    }
+    *source = None;
+}
+
+/// Parse `go.sum`: `module version h1:...=` lines, skipping the
+/// `module version/go.mod h1:...=` lines (those hash the manifest, not the
+/// package).
+fn parse_go_sum(dir: &Path) -> HashMap<String, LockedDep> {
+    let mut out = HashMap::new();
+    let Ok(content) = fs::read_to_string(dir.join("go.sum")) else {
+        return out;
+    };
+
+    for line in content.lines() {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        let [module, version, ..] = parts.as_slice() else {
+            continue;
+        };
+        if version.ends_with("/go.mod") {
+            continue;
+        }
+        out.insert(
+            module.to_string(),
+            LockedDep { version: version.to_string(), source: None },
+        );
+    }
+
+    out
+}
+
+/// Detect the project's framework/runtime stack from its manifests, e.g.
+/// `"Next.js (React) + TypeScript, pnpm"`, mirroring tauri's framework
+/// inference. Returns `None` when nothing recognizable was found.
+pub(crate) fn detect_stack(dir: &Path) -> Option<String> {
+    let mut tags = Vec::new();
+    tags.extend(detect_node_stack(dir));
+    tags.extend(detect_python_stack(dir));
+    tags.extend(detect_rust_stack(dir));
+
+    if tags.is_empty() {
+        return None;
+    }
+
+    let mut line = tags.join(" + ");
+    if let Some(pm) = detect_node_package_manager(dir) {
+        line.push_str(&format!(", {pm}"));
+    }
+    Some(line)
+}
+
+/// Detect frontend/backend framework tags from `package.json`'s combined
+/// `dependencies`/`devDependencies`.
+fn detect_node_stack(dir: &Path) -> Vec<String> {
+    let Ok(content) = fs::read_to_string(dir.join("package.json")) else {
+        return Vec::new();
+    };
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) else {
+        return Vec::new();
+    };
+
+    let mut names: std::collections::HashSet<String> = std::collections::HashSet::new();
+    for key in ["dependencies", "devDependencies"] {
+        if let Some(obj) = json.get(key).and_then(|v| v.as_object()) {
+            names.extend(obj.keys().cloned());
+        }
+    }
+    let has = |name: &str| names.contains(name);
+
+    let mut tags = Vec::new();
+    if has("next") {
+        tags.push("Next.js (React)".to_string());
+    } else if has("react") {
+        tags.push("React".to_string());
+    } else if has("vue") {
+        tags.push("Vue".to_string());
+    } else if has("svelte") {
+        tags.push("Svelte".to_string());
+    } else if has("@angular/core") {
+        tags.push("Angular".to_string());
+    } else if has("express") {
+        tags.push("Express".to_string());
+    } else if has("vite") {
+        tags.push("Vite".to_string());
+    }
+    if has("typescript") {
+        tags.push("TypeScript".to_string());
+    }
+    tags
+}
+
+/// Detect the Node package manager from which lockfile is present.
+fn detect_node_package_manager(dir: &Path) -> Option<&'static str> {
+    if dir.join("pnpm-lock.yaml").exists() {
+        Some("pnpm")
+    } else if dir.join("yarn.lock").exists() {
+        Some("yarn")
+    } else if dir.join("bun.lockb").exists() {
+        Some("bun")
+    } else if dir.join("package-lock.json").exists() {
+        Some("npm")
+    } else {
+        None
+    }
+}
+
+/// Detect Python framework tags from `requirements.txt` and
+/// `pyproject.toml` (both PEP 621 `project.dependencies` and Poetry's
+/// `tool.poetry.dependencies`).
+fn detect_python_stack(dir: &Path) -> Vec<String> {
+    let mut names: std::collections::HashSet<String> = std::collections::HashSet::new();
+
+    if let Ok(content) = fs::read_to_string(dir.join("requirements.txt")) {
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let name = line
+                .split(|c: char| "=<>!~[; ".contains(c))
+                .next()
+                .unwrap_or("")
+                .trim();
+            if !name.is_empty() {
+                names.insert(name.to_lowercase());
+            }
+        }
+    }
+
+    if let Ok(content) = fs::read_to_string(dir.join("pyproject.toml")) {
+        if let Ok(value) = content.parse::<toml::Value>() {
+            if let Some(deps) = value
+                .get("project")
+                .and_then(|v| v.get("dependencies"))
+                .and_then(|v| v.as_array())
+            {
+                for dep in deps {
+                    if let Some(s) = dep.as_str() {
+                        let name = s
+                            .split(|c: char| "=<>!~[; ".contains(c))
+                            .next()
+                            .unwrap_or("")
+                            .trim();
+                        if !name.is_empty() {
+                            names.insert(name.to_lowercase());
+                        }
+                    }
+                }
+            }
+            if let Some(table) = value
+                .get("tool")
+                .and_then(|v| v.get("poetry"))
+                .and_then(|v| v.get("dependencies"))
+                .and_then(|v| v.as_table())
+            {
+                names.extend(table.keys().map(|k| k.to_lowercase()));
+            }
+        }
+    }
+
+    let mut tags = Vec::new();
+    if names.contains("django") {
+        tags.push("Django".to_string());
+    } else if names.contains("fastapi") {
+        tags.push("FastAPI".to_string());
+    } else if names.contains("flask") {
+        tags.push("Flask".to_string());
+    }
+    if names.contains("torch") {
+        tags.push("PyTorch".to_string());
+    }
+    tags
+}
+
+/// Detect Rust framework tags from `Cargo.toml`'s dependency tables.
+fn detect_rust_stack(dir: &Path) -> Vec<String> {
+    let Ok(content) = fs::read_to_string(dir.join("Cargo.toml")) else {
+        return Vec::new();
+    };
+    let Ok(value) = content.parse::<toml::Value>() else {
+        return Vec::new();
+    };
+
+    let mut names: std::collections::HashSet<String> = std::collections::HashSet::new();
+    for section in ["dependencies", "dev-dependencies"] {
+        if let Some(table) = value.get(section).and_then(|v| v.as_table()) {
+            names.extend(table.keys().cloned());
+        }
+    }
+
+    let mut tags = Vec::new();
+    if names.contains("axum") {
+        tags.push("Axum".to_string());
+    } else if names.contains("actix-web") {
+        tags.push("Actix".to_string());
+    }
+    if names.contains("bevy") {
+        tags.push("Bevy".to_string());
+    }
+    if names.contains("tokio") {
+        tags.push("Tokio".to_string());
+    }
+    tags
+}
+
+/// Summarize project dependencies. `max_depth` caps workspace/monorepo
+/// member traversal (see [`discover_workspace_members`]); `no_recurse`
+/// skips member discovery entirely and reports only `path` itself.
+pub fn run(path: &Path, max_depth: usize, no_recurse: bool, verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
     let dir = if path.is_file() {
@@ -18,6 +492,67 @@ pub fn run(path: &Path, verbose: u8) -> Result<()> {
         eprintln!("Scanning dependencies in: {}", dir.display());
     }
 
+    let mut found = false;
+    let mut rtk = String::new();
+    let mut raw = String::new();
+    let mut workspace_deps: std::collections::BTreeSet<String> = std::collections::BTreeSet::new();
+
+    if let Some(stack) = detect_stack(dir) {
+        rtk.push_str(&format!("🔎 Stack: {stack}\n\n"));
+    }
+
+    let (root_rtk, root_raw, root_found) = summarize_manifests_in_dir(dir)?;
+    found |= root_found;
+    rtk.push_str(&root_rtk);
+    raw.push_str(&root_raw);
+    workspace_deps.extend(collect_cargo_dep_names(dir));
+    workspace_deps.extend(collect_node_dep_names(dir));
+
+    if !no_recurse {
+        let members = discover_workspace_members(dir, max_depth);
+        if !members.is_empty() {
+            found = true;
+            rtk.push_str(&format!("\n📂 Workspace members ({}):\n", members.len()));
+            for member in &members {
+                let rel = member.strip_prefix(dir).unwrap_or(member);
+                rtk.push_str(&format!("\n  -- {} --\n", rel.display()));
+                let (member_rtk, member_raw, member_found) = summarize_manifests_in_dir(member)?;
+                if member_found {
+                    rtk.push_str(&member_rtk);
+                    raw.push_str(&member_raw);
+                } else {
+                    rtk.push_str("  (no manifest found)\n");
+                }
+                workspace_deps.extend(collect_cargo_dep_names(member));
+                workspace_deps.extend(collect_node_dep_names(member));
+            }
+
+            rtk.push_str(&format!(
+                "\n🔗 Workspace-wide dependencies ({} unique):\n",
+                workspace_deps.len()
+            ));
+            for name in workspace_deps.iter().take(30) {
+                rtk.push_str(&format!("  {}\n", name));
+            }
+            if workspace_deps.len() > 30 {
+                rtk.push_str(&format!("  ... +{} more\n", workspace_deps.len() - 30));
+            }
+        }
+    }
+
+    if !found {
+        rtk.push_str(&format!("No dependency files found in {}", dir.display()));
+    }
+
+    print!("{}", rtk);
+    timer.track("cat */deps", "rtk deps", &raw, &rtk);
+    Ok(())
+}
+
+/// Summarize whichever manifests exist directly in `dir` (not
+/// recursing into workspace members). Returns `(summary, raw manifest
+/// text, found_any)`.
+fn summarize_manifests_in_dir(dir: &Path) -> Result<(String, String, bool)> {
     let mut found = false;
     let mut rtk = String::new();
     let mut raw = String::new();
@@ -27,7 +562,7 @@ pub fn run(path: &Path, verbose: u8) -> Result<()> {
         found = true;
         raw.push_str(&fs::read_to_string(&cargo_path).unwrap_or_default());
         rtk.push_str("📦 Rust (Cargo.toml):\n");
-        rtk.push_str(&summarize_cargo_str(&cargo_path)?);
+        rtk.push_str(&summarize_cargo_str(&cargo_path, &parse_cargo_lock(dir))?);
     }
 
     let package_path = dir.join("package.json");
@@ -35,7 +570,9 @@ pub fn run(path: &Path, verbose: u8) -> Result<()> {
         found = true;
         raw.push_str(&fs::read_to_string(&package_path).unwrap_or_default());
         rtk.push_str("📦 Node.js (package.json):\n");
-        rtk.push_str(&summarize_package_json_str(&package_path)?);
+        let mut locked = parse_npm_lock(dir);
+        locked.extend(parse_yarn_lock(dir));
+        rtk.push_str(&summarize_package_json_str(&package_path, &locked)?);
     }
 
     let requirements_path = dir.join("requirements.txt");
@@ -59,46 +596,203 @@ pub fn run(path: &Path, verbose: u8) -> Result<()> {
         found = true;
         raw.push_str(&fs::read_to_string(&gomod_path).unwrap_or_default());
         rtk.push_str("📦 Go (go.mod):\n");
-        rtk.push_str(&summarize_gomod_str(&gomod_path)?);
+        rtk.push_str(&summarize_gomod_str(&gomod_path, &parse_go_sum(dir))?);
     }
 
-    if !found {
-        rtk.push_str(&format!("No dependency files found in {}", dir.display()));
+    Ok((rtk, raw, found))
+}
+
+fn collect_cargo_dep_names(dir: &Path) -> Vec<String> {
+    let Ok(content) = fs::read_to_string(dir.join("Cargo.toml")) else {
+        return Vec::new();
+    };
+    let Ok(value) = content.parse::<toml::Value>() else {
+        return Vec::new();
+    };
+    let mut names = Vec::new();
+    for section in ["dependencies", "dev-dependencies"] {
+        if let Some(table) = value.get(section).and_then(|v| v.as_table()) {
+            names.extend(table.keys().cloned());
+        }
     }
+    names
+}
 
-    print!("{}", rtk);
-    timer.track("cat */deps", "rtk deps", &raw, &rtk);
-    Ok(())
+fn collect_node_dep_names(dir: &Path) -> Vec<String> {
+    let Ok(content) = fs::read_to_string(dir.join("package.json")) else {
+        return Vec::new();
+    };
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) else {
+        return Vec::new();
+    };
+    let mut names = Vec::new();
+    for key in ["dependencies", "devDependencies"] {
+        if let Some(obj) = json.get(key).and_then(|v| v.as_object()) {
+            names.extend(obj.keys().cloned());
+        }
+    }
+    names
+}
+
+/// Resolve workspace/monorepo member directories declared by a root
+/// `Cargo.toml`'s `[workspace] members` (glob patterns) or a root
+/// `package.json`'s `workspaces` array (plain array, or `{ "packages":
+/// [...] }`), recursing into members that declare their own nested
+/// workspace up to `max_depth` levels.
+fn discover_workspace_members(dir: &Path, max_depth: usize) -> Vec<PathBuf> {
+    if max_depth == 0 {
+        return Vec::new();
+    }
+
+    let mut patterns = Vec::new();
+
+    if let Ok(content) = fs::read_to_string(dir.join("Cargo.toml")) {
+        if let Ok(value) = content.parse::<toml::Value>() {
+            if let Some(members) = value
+                .get("workspace")
+                .and_then(|v| v.get("members"))
+                .and_then(|v| v.as_array())
+            {
+                patterns.extend(members.iter().filter_map(|v| v.as_str().map(String::from)));
+            }
+        }
+    }
+
+    if let Ok(content) = fs::read_to_string(dir.join("package.json")) {
+        if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
+            match json.get("workspaces") {
+                Some(serde_json::Value::Array(arr)) => {
+                    patterns.extend(arr.iter().filter_map(|v| v.as_str().map(String::from)));
+                }
+                Some(serde_json::Value::Object(obj)) => {
+                    if let Some(serde_json::Value::Array(arr)) = obj.get("packages") {
+                        patterns.extend(arr.iter().filter_map(|v| v.as_str().map(String::from)));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if patterns.is_empty() {
+        return Vec::new();
+    }
+
+    let mut members = resolve_member_globs(dir, &patterns);
+    members.sort();
+    members.dedup();
+
+    if max_depth > 1 {
+        let nested: Vec<PathBuf> = members
+            .iter()
+            .flat_map(|member| discover_workspace_members(member, max_depth - 1))
+            .collect();
+        members.extend(nested);
+        members.sort();
+        members.dedup();
+    }
+
+    members
+}
+
+/// Expand workspace member glob patterns (`crates/*`, `packages/**`,
+/// or a literal path) against `base`, returning only directories that
+/// actually exist.
+fn resolve_member_globs(base: &Path, patterns: &[String]) -> Vec<PathBuf> {
+    let mut out = Vec::new();
+    for pattern in patterns {
+        out.extend(resolve_member_glob(base, pattern));
+    }
+    out
 }
 
-fn summarize_cargo_str(path: &Path) -> Result<String> {
+fn resolve_member_glob(base: &Path, pattern: &str) -> Vec<PathBuf> {
+    let segments: Vec<&str> = pattern.split('/').filter(|s| !s.is_empty()).collect();
+    let mut current = vec![base.to_path_buf()];
+
+    for segment in segments {
+        let mut next = Vec::new();
+        if segment == "**" {
+            for dir in &current {
+                collect_all_subdirs(dir, &mut next);
+            }
+        } else if segment.contains('*') || segment.contains('?') {
+            for dir in &current {
+                let Ok(read_dir) = fs::read_dir(dir) else {
+                    continue;
+                };
+                for entry in read_dir.filter_map(|e| e.ok()) {
+                    if !entry.path().is_dir() {
+                        continue;
+                    }
+                    let name = entry.file_name().to_string_lossy().into_owned();
+                    if crate::find_cmd::glob_match(segment, &name) {
+                        next.push(entry.path());
+                    }
+                }
+            }
+        } else {
+            for dir in &current {
+                let candidate = dir.join(segment);
+                if candidate.is_dir() {
+                    next.push(candidate);
+                }
+            }
+        }
+        current = next;
+        if current.is_empty() {
+            break;
+        }
+    }
+
+    current
+}
+
+fn collect_all_subdirs(dir: &Path, out: &mut Vec<PathBuf>) {
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in read_dir.filter_map(|e| e.ok()) {
+        if !entry.path().is_dir() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if NOISE_DIRS.contains(&name.as_str()) {
+            continue;
+        }
+        out.push(entry.path());
+        collect_all_subdirs(&entry.path(), out);
+    }
+}
+
+fn summarize_cargo_str(path: &Path, locked: &HashMap<String, LockedDep>) -> Result<String> {
     let content = fs::read_to_string(path)?;
-    let dep_re =
-        Regex::new(r#"^([a-zA-Z0-9_-]+)\s*=\s*(?:"([^"]+)"|.*version\s*=\s*"([^"]+)")"#).unwrap();
-    let section_re = Regex::new(r"^\[([^\]]+)\]").unwrap();
-    let mut current_section = String::new();
+    let manifest: toml::Value = content.parse()?;
     let mut deps = Vec::new();
     let mut dev_deps = Vec::new();
     let mut out = String::new();
 
-    for line in content.lines() {
-        if let Some(caps) = section_re.captures(line) {
-            current_section = caps
-                .get(1)
-                .map(|m| m.as_str().to_string())
-                .unwrap_or_default();
-        } else if let Some(caps) = dep_re.captures(line) {
-            let name = caps.get(1).map(|m| m.as_str()).unwrap_or("");
-            let version = caps
-                .get(2)
-                .or(caps.get(3))
-                .map(|m| m.as_str())
-                .unwrap_or("*");
-            let dep = format!("{} ({})", name, version);
-            match current_section.as_str() {
-                "dependencies" => deps.push(dep),
-                "dev-dependencies" => dev_deps.push(dep),
-                _ => {}
+    if let Some(table) = manifest.get("dependencies").and_then(|v| v.as_table()) {
+        for (name, value) in table {
+            deps.push(format_cargo_dep(name, &parse_cargo_dep_value(value), locked));
+        }
+    }
+    if let Some(table) = manifest.get("dev-dependencies").and_then(|v| v.as_table()) {
+        for (name, value) in table {
+            dev_deps.push(format_cargo_dep(name, &parse_cargo_dep_value(value), locked));
+        }
+    }
+    // `[target.'cfg(...)'.dependencies]` tables are keyed by the cfg/triple
+    // string; fold their entries into the regular dependency list tagged
+    // with that target so they're no longer silently dropped.
+    if let Some(targets) = manifest.get("target").and_then(|v| v.as_table()) {
+        for (cfg, target_value) in targets {
+            if let Some(table) = target_value.get("dependencies").and_then(|v| v.as_table()) {
+                for (name, value) in table {
+                    let mut spec = parse_cargo_dep_value(value);
+                    spec.target_cfg = Some(cfg.clone());
+                    deps.push(format_cargo_dep(name, &spec, locked));
+                }
             }
         }
     }
@@ -124,7 +818,7 @@ fn summarize_cargo_str(path: &Path) -> Result<String> {
     Ok(out)
 }
 
-fn summarize_package_json_str(path: &Path) -> Result<String> {
+fn summarize_package_json_str(path: &Path, locked: &HashMap<String, LockedDep>) -> Result<String> {
     let content = fs::read_to_string(path)?;
     let json: serde_json::Value = serde_json::from_str(&content)?;
     let mut out = String::new();
@@ -141,9 +835,8 @@ fn summarize_package_json_str(path: &Path) -> Result<String> {
                 break;
             }
             out.push_str(&format!(
-                "    {} ({})\n",
-                name,
-                version.as_str().unwrap_or("*")
+                "    {}\n",
+                format_dep(name, version.as_str().unwrap_or("*"), locked)
             ));
         }
     }
@@ -224,7 +917,7 @@ fn summarize_pyproject_str(path: &Path) -> Result<String> {
     Ok(out)
 }
 
-fn summarize_gomod_str(path: &Path) -> Result<String> {
+fn summarize_gomod_str(path: &Path, locked: &HashMap<String, LockedDep>) -> Result<String> {
     let content = fs::read_to_string(path)?;
     let mut module_name = String::new();
     let mut go_version = String::new();
@@ -245,10 +938,15 @@ fn summarize_gomod_str(path: &Path) -> Result<String> {
         } else if in_require && !line.starts_with("//") {
             let parts: Vec<&str> = line.split_whitespace().collect();
             if parts.len() >= 2 {
-                deps.push(format!("{} {}", parts[0], parts[1]));
+                deps.push(format_go_dep(parts[0], parts[1], locked));
             }
         } else if line.starts_with("require ") && !line.contains("(") {
-            deps.push(line.trim_start_matches("require ").to_string());
+            let rest = line.trim_start_matches("require ");
+            let parts: Vec<&str> = rest.split_whitespace().collect();
+            deps.push(match parts.as_slice() {
+                [module, version] => format_go_dep(module, version, locked),
+                _ => rest.to_string(),
+            });
         }
     }
 
@@ -1,11 +1,13 @@
+use crate::parser::{DependencyState, DiffFormatter, LintResult, TestResult};
 use crate::tracking;
 use crate::utils::truncate;
 use anyhow::Result;
+use serde::Serialize;
 use std::fs;
 use std::path::Path;
 
 /// Ultra-condensed diff - only changed lines, no context
-pub fn run(file1: &Path, file2: &Path, verbose: u8) -> Result<()> {
+pub fn run(file1: &Path, file2: &Path, patience: bool, format: &str, verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
     if verbose > 0 {
@@ -16,9 +18,43 @@ pub fn run(file1: &Path, file2: &Path, verbose: u8) -> Result<()> {
     let content2 = fs::read_to_string(file2)?;
     let raw = format!("{}\n---\n{}", content1, content2);
 
+    // If both files are snapshots of the same rtk canonical type (e.g. two
+    // `rtk test --format json` runs), diff them semantically instead of
+    // line-by-line - this is what actually answers "what regressed".
+    if let Some(rtk) = try_structured_diff(&content1, &content2) {
+        println!("{}", rtk);
+        timer.track(
+            &format!("diff {} {}", file1.display(), file2.display()),
+            "rtk diff",
+            &raw,
+            &rtk,
+        );
+        return Ok(());
+    }
+
     let lines1: Vec<&str> = content1.lines().collect();
     let lines2: Vec<&str> = content2.lines().collect();
-    let diff = compute_diff(&lines1, &lines2);
+    let diff = if patience {
+        compute_patience_diff(&lines1, &lines2)
+    } else {
+        compute_diff(&lines1, &lines2)
+    };
+
+    // Automation (LLM context builders, CI gates) wants the full,
+    // untruncated DiffResult rather than the emoji-decorated, 50-line-capped
+    // text a human reads -- so json bypasses the pretty-print path entirely.
+    if format == "json" {
+        let rtk = serde_json::to_string_pretty(&diff)?;
+        println!("{}", rtk);
+        timer.track(
+            &format!("diff {} {}", file1.display(), file2.display()),
+            "rtk diff",
+            &raw,
+            &rtk,
+        );
+        return Ok(());
+    }
+
     let mut rtk = String::new();
 
     if diff.added == 0 && diff.removed == 0 {
@@ -41,13 +77,16 @@ pub fn run(file1: &Path, file2: &Path, verbose: u8) -> Result<()> {
 
     for change in diff.changes.iter().take(50) {
         match change {
-            DiffChange::Added(ln, c) => rtk.push_str(&format!("+{:4} {}\n", ln, truncate(c, 80))),
-            DiffChange::Removed(ln, c) => rtk.push_str(&format!("-{:4} {}\n", ln, truncate(c, 80))),
-            DiffChange::Modified(ln, old, new) => rtk.push_str(&format!(
-                "~{:4} {} → {}\n",
-                ln,
-                truncate(old, 70),
-                truncate(new, 70)
+            DiffChange::Added { line, text } => {
+                rtk.push_str(&format!("+{:4} {}\n", line, truncate(text, 80)))
+            }
+            DiffChange::Removed { line, text } => {
+                rtk.push_str(&format!("-{:4} {}\n", line, truncate(text, 80)))
+            }
+            DiffChange::Modified { line, old, new } => rtk.push_str(&format!(
+                "~{:4} {}\n",
+                line,
+                truncate(&inline_diff(old, new), 100)
             )),
         }
     }
@@ -65,8 +104,33 @@ pub fn run(file1: &Path, file2: &Path, verbose: u8) -> Result<()> {
     Ok(())
 }
 
+/// Try to parse both inputs as the same rtk canonical type and render a
+/// semantic diff. Returns `None` (falling back to line diff) unless both
+/// sides parse as JSON *and* agree on which type they are.
+fn try_structured_diff(content1: &str, content2: &str) -> Option<String> {
+    if let (Ok(before), Ok(current)) = (
+        serde_json::from_str::<TestResult>(content1),
+        serde_json::from_str::<TestResult>(content2),
+    ) {
+        return Some(current.diff(&before));
+    }
+    if let (Ok(before), Ok(current)) = (
+        serde_json::from_str::<LintResult>(content1),
+        serde_json::from_str::<LintResult>(content2),
+    ) {
+        return Some(current.diff(&before));
+    }
+    if let (Ok(before), Ok(current)) = (
+        serde_json::from_str::<DependencyState>(content1),
+        serde_json::from_str::<DependencyState>(content2),
+    ) {
+        return Some(current.diff(&before));
+    }
+    None
+}
+
 /// Run diff from stdin (piped command output)
-pub fn run_stdin(_verbose: u8) -> Result<()> {
+pub fn run_stdin(context_size: usize, _verbose: u8) -> Result<()> {
     use std::io::{self, Read};
     let timer = tracking::TimedExecution::start();
 
@@ -74,7 +138,7 @@ pub fn run_stdin(_verbose: u8) -> Result<()> {
     io::stdin().read_to_string(&mut input)?;
 
     // Parse unified diff format
-    let condensed = condense_unified_diff(&input);
+    let condensed = condense_unified_diff(&input, context_size);
     println!("{}", condensed);
 
     timer.track("diff (stdin)", "rtk diff (stdin)", &input, &condensed);
@@ -82,13 +146,15 @@ pub fn run_stdin(_verbose: u8) -> Result<()> {
     Ok(())
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
 enum DiffChange {
-    Added(usize, String),
-    Removed(usize, String),
-    Modified(usize, String, String),
+    Added { line: usize, text: String },
+    Removed { line: usize, text: String },
+    Modified { line: usize, old: String, new: String },
 }
 
+#[derive(Serialize)]
 struct DiffResult {
     added: usize,
     removed: usize,
@@ -96,43 +162,221 @@ struct DiffResult {
     changes: Vec<DiffChange>,
 }
 
+/// One step of a Myers edit script, indexing into the original `lines1`
+/// (`Delete`) or `lines2` (`Insert`/the `b` side of `Keep`) slices.
+enum Edit {
+    Keep(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Furthest-reaching x on diagonal `k` for the current edit distance,
+/// read from/written to a `V` array offset by `max` so negative `k`
+/// (down to `-max`) stays in bounds.
+fn shortest_edit(a: &[&str], b: &[&str]) -> (Vec<Vec<i64>>, i64, i64) {
+    let n = a.len() as i64;
+    let m = b.len() as i64;
+    let max = n + m;
+    if max == 0 {
+        return (Vec::new(), n, m);
+    }
+
+    let offset = max;
+    let idx = |k: i64| (offset + k) as usize;
+    let mut v = vec![0i64; (2 * max + 1) as usize];
+    let mut trace = Vec::new();
+
+    'outer: for d in 0..=max {
+        trace.push(v.clone());
+        let mut k = -d;
+        while k <= d {
+            let mut x = if k == -d || (k != d && v[idx(k - 1)] < v[idx(k + 1)]) {
+                v[idx(k + 1)]
+            } else {
+                v[idx(k - 1)] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx(k)] = x;
+            if x >= n && y >= m {
+                break 'outer;
+            }
+            k += 2;
+        }
+    }
+
+    (trace, n, m)
+}
+
+/// Walk the saved `V` snapshots backward from `(n, m)` to `(0, 0)`,
+/// recovering the shortest edit script in forward order.
+fn backtrack(trace: &[Vec<i64>], n: i64, m: i64) -> Vec<Edit> {
+    if trace.is_empty() {
+        return Vec::new();
+    }
+    let max = n + m;
+    let offset = max;
+    let idx = |k: i64| (offset + k) as usize;
+
+    let mut x = n;
+    let mut y = m;
+    let mut ops = Vec::new();
+
+    for d in (0..trace.len() as i64).rev() {
+        let v = &trace[d as usize];
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && v[idx(k - 1)] < v[idx(k + 1)]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = v[idx(prev_k)];
+        let prev_y = prev_x - prev_k;
+
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            ops.push(Edit::Keep(x as usize, y as usize));
+        }
+
+        if d > 0 {
+            if x == prev_x {
+                ops.push(Edit::Insert((y - 1) as usize));
+            } else {
+                ops.push(Edit::Delete((x - 1) as usize));
+            }
+        }
+
+        x = prev_x;
+        y = prev_y;
+    }
+
+    ops.reverse();
+    ops
+}
+
+/// Myers' O(ND) shortest-edit-script diff between two line slices. Unlike
+/// comparing `lines1[i]` to `lines2[i]` at the same index, this aligns on
+/// actual matching lines, so inserting one line at the top no longer
+/// reports every line after it as changed.
+fn myers_diff(a: &[&str], b: &[&str]) -> Vec<Edit> {
+    let (trace, n, m) = shortest_edit(a, b);
+    backtrack(&trace, n, m)
+}
+
+/// Collapse a run of adjacent deletes/inserts (Myers emits them as two
+/// separate runs rather than the shifted-index pairing the old code
+/// assumed) into `Modified` where same-position lines clear the existing
+/// similarity threshold, otherwise separate `Removed`/`Added`.
+#[allow(clippy::too_many_arguments)]
+fn flush_pending(
+    lines1: &[&str],
+    lines2: &[&str],
+    deletes: &mut Vec<usize>,
+    inserts: &mut Vec<usize>,
+    changes: &mut Vec<DiffChange>,
+    added: &mut usize,
+    removed: &mut usize,
+    modified: &mut usize,
+) {
+    let paired = deletes.len().min(inserts.len());
+    for i in 0..paired {
+        let a_idx = deletes[i];
+        let b_idx = inserts[i];
+        let a = lines1[a_idx];
+        let b = lines2[b_idx];
+        if similarity(a, b) > 0.5 {
+            changes.push(DiffChange::Modified {
+                line: b_idx + 1,
+                old: a.to_string(),
+                new: b.to_string(),
+            });
+            *modified += 1;
+        } else {
+            changes.push(DiffChange::Removed {
+                line: a_idx + 1,
+                text: a.to_string(),
+            });
+            changes.push(DiffChange::Added {
+                line: b_idx + 1,
+                text: b.to_string(),
+            });
+            *removed += 1;
+            *added += 1;
+        }
+    }
+    for &a_idx in &deletes[paired..] {
+        changes.push(DiffChange::Removed {
+            line: a_idx + 1,
+            text: lines1[a_idx].to_string(),
+        });
+        *removed += 1;
+    }
+    for &b_idx in &inserts[paired..] {
+        changes.push(DiffChange::Added {
+            line: b_idx + 1,
+            text: lines2[b_idx].to_string(),
+        });
+        *added += 1;
+    }
+    deletes.clear();
+    inserts.clear();
+}
+
 fn compute_diff(lines1: &[&str], lines2: &[&str]) -> DiffResult {
+    diff_result_from_ops(lines1, lines2, myers_diff(lines1, lines2))
+}
+
+/// Like [`compute_diff`] but aligns on the patience algorithm's stable
+/// backbone instead of a raw Myers edit script -- better for source files
+/// full of repeated boilerplate (`}`, blank lines, `import ...`) that
+/// plain LCS/Myers tends to align against the wrong occurrence of.
+fn compute_patience_diff(lines1: &[&str], lines2: &[&str]) -> DiffResult {
+    diff_result_from_ops(lines1, lines2, patience_diff(lines1, lines2))
+}
+
+/// Shared by [`compute_diff`] and [`compute_patience_diff`]: turn a
+/// Keep/Delete/Insert edit script into the `+/-/~` change list, pairing
+/// up adjacent delete/insert runs into `Modified` where they clear the
+/// similarity threshold.
+fn diff_result_from_ops(lines1: &[&str], lines2: &[&str], ops: Vec<Edit>) -> DiffResult {
     let mut changes = Vec::new();
     let mut added = 0;
     let mut removed = 0;
     let mut modified = 0;
 
-    // Simple line-by-line comparison (not optimal but fast)
-    let max_len = lines1.len().max(lines2.len());
-
-    for i in 0..max_len {
-        let l1 = lines1.get(i).copied();
-        let l2 = lines2.get(i).copied();
-
-        match (l1, l2) {
-            (Some(a), Some(b)) if a != b => {
-                // Check if it's similar (modification) or completely different
-                if similarity(a, b) > 0.5 {
-                    changes.push(DiffChange::Modified(i + 1, a.to_string(), b.to_string()));
-                    modified += 1;
-                } else {
-                    changes.push(DiffChange::Removed(i + 1, a.to_string()));
-                    changes.push(DiffChange::Added(i + 1, b.to_string()));
-                    removed += 1;
-                    added += 1;
-                }
-            }
-            (Some(a), None) => {
-                changes.push(DiffChange::Removed(i + 1, a.to_string()));
-                removed += 1;
-            }
-            (None, Some(b)) => {
-                changes.push(DiffChange::Added(i + 1, b.to_string()));
-                added += 1;
-            }
-            _ => {}
+    let mut pending_deletes = Vec::new();
+    let mut pending_inserts = Vec::new();
+
+    for op in ops {
+        match op {
+            Edit::Keep(..) => flush_pending(
+                lines1,
+                lines2,
+                &mut pending_deletes,
+                &mut pending_inserts,
+                &mut changes,
+                &mut added,
+                &mut removed,
+                &mut modified,
+            ),
+            Edit::Delete(a_idx) => pending_deletes.push(a_idx),
+            Edit::Insert(b_idx) => pending_inserts.push(b_idx),
         }
     }
+    flush_pending(
+        lines1,
+        lines2,
+        &mut pending_deletes,
+        &mut pending_inserts,
+        &mut changes,
+        &mut added,
+        &mut removed,
+        &mut modified,
+    );
 
     DiffResult {
         added,
@@ -142,71 +386,426 @@ fn compute_diff(lines1: &[&str], lines2: &[&str]) -> DiffResult {
     }
 }
 
-fn similarity(a: &str, b: &str) -> f64 {
-    let a_chars: std::collections::HashSet<char> = a.chars().collect();
-    let b_chars: std::collections::HashSet<char> = b.chars().collect();
+/// Lines that occur exactly once in both `a` and `b`, paired as
+/// `(index in a, index in b)` and sorted by position in `a` -- candidate
+/// anchors for the patience backbone.
+fn unique_common_lines(a: &[&str], b: &[&str]) -> Vec<(usize, usize)> {
+    use std::collections::HashMap;
 
-    let intersection = a_chars.intersection(&b_chars).count();
-    let union = a_chars.union(&b_chars).count();
+    let mut a_count: HashMap<&str, usize> = HashMap::new();
+    let mut a_pos: HashMap<&str, usize> = HashMap::new();
+    for (i, &line) in a.iter().enumerate() {
+        *a_count.entry(line).or_insert(0) += 1;
+        a_pos.insert(line, i);
+    }
 
-    if union == 0 {
-        1.0
-    } else {
-        intersection as f64 / union as f64
+    let mut b_count: HashMap<&str, usize> = HashMap::new();
+    let mut b_pos: HashMap<&str, usize> = HashMap::new();
+    for (i, &line) in b.iter().enumerate() {
+        *b_count.entry(line).or_insert(0) += 1;
+        b_pos.insert(line, i);
     }
+
+    let mut pairs: Vec<(usize, usize)> = a_count
+        .iter()
+        .filter(|&(_, &count)| count == 1)
+        .filter_map(|(line, _)| {
+            if b_count.get(line) == Some(&1) {
+                Some((a_pos[line], b_pos[line]))
+            } else {
+                None
+            }
+        })
+        .collect();
+    pairs.sort_unstable_by_key(|&(a_idx, _)| a_idx);
+    pairs
 }
 
-fn condense_unified_diff(diff: &str) -> String {
+/// Longest increasing (by `.1`) subsequence of `pairs`, via patience
+/// sorting: `tails[k]` holds the index into `pairs` of the smallest-tail
+/// candidate for a run of length `k + 1`, found by binary search, with
+/// `prev` threading back through each candidate's predecessor so the
+/// actual subsequence (not just its length) can be reconstructed.
+fn longest_increasing_subsequence(pairs: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let mut tails: Vec<usize> = Vec::new();
+    let mut prev: Vec<Option<usize>> = vec![None; pairs.len()];
+
+    for i in 0..pairs.len() {
+        let b_idx = pairs[i].1;
+        let pos = tails.partition_point(|&t| pairs[t].1 < b_idx);
+        prev[i] = if pos > 0 { Some(tails[pos - 1]) } else { None };
+        if pos == tails.len() {
+            tails.push(i);
+        } else {
+            tails[pos] = i;
+        }
+    }
+
     let mut result = Vec::new();
-    let mut current_file = String::new();
-    let mut added = 0;
-    let mut removed = 0;
-    let mut changes = Vec::new();
+    let mut cursor = tails.last().copied();
+    while let Some(i) = cursor {
+        result.push(pairs[i]);
+        cursor = prev[i];
+    }
+    result.reverse();
+    result
+}
 
-    for line in diff.lines() {
-        if line.starts_with("diff --git") || line.starts_with("--- ") || line.starts_with("+++ ") {
-            // File header
-            if line.starts_with("+++ ") {
-                if !current_file.is_empty() && (added > 0 || removed > 0) {
-                    result.push(format!("📄 {} (+{} -{})", current_file, added, removed));
-                    for c in changes.iter().take(10) {
-                        result.push(format!("  {}", c));
-                    }
-                    if changes.len() > 10 {
-                        result.push(format!("  ... +{} more", changes.len() - 10));
-                    }
-                }
-                current_file = line
-                    .trim_start_matches("+++ ")
-                    .trim_start_matches("b/")
-                    .to_string();
-                added = 0;
-                removed = 0;
-                changes.clear();
+/// Patience diff: find the unique-in-both lines, take their longest
+/// increasing subsequence as a stable matched backbone, then recurse on
+/// the slices between consecutive anchors (falling back to Myers once a
+/// slice has no unique anchors of its own).
+fn patience_diff(a: &[&str], b: &[&str]) -> Vec<Edit> {
+    patience_diff_slice(a, b, 0, 0)
+}
+
+fn patience_diff_slice(a: &[&str], b: &[&str], a_off: usize, b_off: usize) -> Vec<Edit> {
+    if a.is_empty() && b.is_empty() {
+        return Vec::new();
+    }
+    if a.is_empty() {
+        return (0..b.len()).map(|i| Edit::Insert(b_off + i)).collect();
+    }
+    if b.is_empty() {
+        return (0..a.len()).map(|i| Edit::Delete(a_off + i)).collect();
+    }
+
+    let anchors = longest_increasing_subsequence(&unique_common_lines(a, b));
+    if anchors.is_empty() {
+        return myers_diff(a, b)
+            .into_iter()
+            .map(|op| match op {
+                Edit::Keep(ai, bi) => Edit::Keep(a_off + ai, b_off + bi),
+                Edit::Delete(ai) => Edit::Delete(a_off + ai),
+                Edit::Insert(bi) => Edit::Insert(b_off + bi),
+            })
+            .collect();
+    }
+
+    let mut ops = Vec::new();
+    let mut cursor_a = 0;
+    let mut cursor_b = 0;
+    for (a_idx, b_idx) in anchors {
+        ops.extend(patience_diff_slice(
+            &a[cursor_a..a_idx],
+            &b[cursor_b..b_idx],
+            a_off + cursor_a,
+            b_off + cursor_b,
+        ));
+        ops.push(Edit::Keep(a_off + a_idx, b_off + b_idx));
+        cursor_a = a_idx + 1;
+        cursor_b = b_idx + 1;
+    }
+    ops.extend(patience_diff_slice(
+        &a[cursor_a..],
+        &b[cursor_b..],
+        a_off + cursor_a,
+        b_off + cursor_b,
+    ));
+
+    ops
+}
+
+/// Word-level edit between two strings, rendered as the shared
+/// prefix/suffix left untouched with the changed middle bracketed, e.g.
+/// `let x = [1→2];` -- far easier to scan than printing both full lines
+/// when a `Modified` change is really a one-token tweak.
+fn inline_diff(old: &str, new: &str) -> String {
+    let old_tokens = tokenize(old);
+    let new_tokens = tokenize(new);
+    let ops = myers_diff(&old_tokens, &new_tokens);
+    let segments = merge_short_equal_segments(segments_from_ops(&old_tokens, &new_tokens, &ops));
+
+    let mut rendered = String::new();
+    for segment in &segments {
+        match segment {
+            InlineSegment::Equal(s) => rendered.push_str(s),
+            InlineSegment::Edit(old, new) => {
+                rendered.push('[');
+                rendered.push_str(old);
+                rendered.push('→');
+                rendered.push_str(new);
+                rendered.push(']');
             }
-        } else if line.starts_with('+') && !line.starts_with("+++") {
-            added += 1;
-            if changes.len() < 15 {
-                changes.push(truncate(line, 70));
+        }
+    }
+    rendered
+}
+
+/// Split `s` into alternating runs of whitespace and non-whitespace, so
+/// the word-level diff can match on whole tokens instead of characters.
+fn tokenize(s: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut in_whitespace: Option<bool> = None;
+
+    for (i, c) in s.char_indices() {
+        let ws = c.is_whitespace();
+        match in_whitespace {
+            Some(prev) if prev != ws => {
+                tokens.push(&s[start..i]);
+                start = i;
             }
-        } else if line.starts_with('-') && !line.starts_with("---") {
-            removed += 1;
-            if changes.len() < 15 {
-                changes.push(truncate(line, 70));
+            _ => {}
+        }
+        in_whitespace = Some(ws);
+    }
+    if start < s.len() {
+        tokens.push(&s[start..]);
+    }
+    tokens
+}
+
+/// A run of matched tokens (`Equal`) or a run of deleted/inserted tokens
+/// collapsed into one before/after pair (`Edit`).
+#[derive(Clone)]
+enum InlineSegment {
+    Equal(String),
+    Edit(String, String),
+}
+
+/// Fold a token-level Myers edit script into runs, merging consecutive
+/// Delete/Insert ops (in either order) into a single `Edit` segment.
+fn segments_from_ops(old_tokens: &[&str], new_tokens: &[&str], ops: &[Edit]) -> Vec<InlineSegment> {
+    let mut segments: Vec<InlineSegment> = Vec::new();
+    for op in ops {
+        match *op {
+            Edit::Keep(a_idx, _) => match segments.last_mut() {
+                Some(InlineSegment::Equal(text)) => text.push_str(old_tokens[a_idx]),
+                _ => segments.push(InlineSegment::Equal(old_tokens[a_idx].to_string())),
+            },
+            Edit::Delete(a_idx) => match segments.last_mut() {
+                Some(InlineSegment::Edit(old, _)) => old.push_str(old_tokens[a_idx]),
+                _ => segments.push(InlineSegment::Edit(old_tokens[a_idx].to_string(), String::new())),
+            },
+            Edit::Insert(b_idx) => match segments.last_mut() {
+                Some(InlineSegment::Edit(_, new)) => new.push_str(new_tokens[b_idx]),
+                _ => segments.push(InlineSegment::Edit(String::new(), new_tokens[b_idx].to_string())),
+            },
+        }
+    }
+    segments
+}
+
+/// Below this many characters, an `Equal` segment sandwiched between two
+/// `Edit` segments reads as noise (a single matched letter between two
+/// changed words) -- fold it into its neighbors instead.
+const SHORT_EQUAL_CHARS: usize = 4;
+
+fn merge_short_equal_segments(segments: Vec<InlineSegment>) -> Vec<InlineSegment> {
+    let mut segments = segments;
+    loop {
+        let mut out: Vec<InlineSegment> = Vec::with_capacity(segments.len());
+        let mut merged_any = false;
+        let mut i = 0;
+        while i < segments.len() {
+            let should_merge = i + 1 < segments.len()
+                && matches!(out.last(), Some(InlineSegment::Edit(..)))
+                && matches!(&segments[i], InlineSegment::Equal(eq) if eq.chars().count() < SHORT_EQUAL_CHARS)
+                && matches!(&segments[i + 1], InlineSegment::Edit(..));
+
+            if should_merge {
+                if let (InlineSegment::Equal(eq), InlineSegment::Edit(next_old, next_new)) =
+                    (&segments[i], &segments[i + 1])
+                {
+                    if let Some(InlineSegment::Edit(prev_old, prev_new)) = out.last_mut() {
+                        prev_old.push_str(eq);
+                        prev_old.push_str(next_old);
+                        prev_new.push_str(eq);
+                        prev_new.push_str(next_new);
+                    }
+                }
+                merged_any = true;
+                i += 2;
+            } else {
+                out.push(segments[i].clone());
+                i += 1;
             }
         }
+        segments = out;
+        if !merged_any {
+            return segments;
+        }
+    }
+}
+
+/// Normalized edit-distance ratio: `1.0 - levenshtein(a, b) / max(len_a,
+/// len_b)` (both empty is defined as 1.0). Unlike a character-set Jaccard
+/// score, this is order-sensitive, so anagrams and reordered lines don't
+/// read as near-identical just because they share an alphabet.
+fn similarity(a: &str, b: &str) -> f64 {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+
+    if a_chars.is_empty() && b_chars.is_empty() {
+        return 1.0;
+    }
+
+    let distance = levenshtein(&a_chars, &b_chars);
+    let max_len = a_chars.len().max(b_chars.len());
+    1.0 - distance as f64 / max_len as f64
+}
+
+/// Classic Levenshtein distance via the two-row rolling DP (only the
+/// previous row is needed to compute the next), so memory stays
+/// `O(min(n, m))` by diffing against the shorter side.
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let (shorter, longer) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+
+    let mut prev_row: Vec<usize> = (0..=shorter.len()).collect();
+    let mut cur_row = vec![0usize; shorter.len() + 1];
+
+    for (i, &lc) in longer.iter().enumerate() {
+        cur_row[0] = i + 1;
+        for (j, &sc) in shorter.iter().enumerate() {
+            let cost = if lc == sc { 0 } else { 1 };
+            cur_row[j + 1] = (prev_row[j + 1] + 1)
+                .min(cur_row[j] + 1)
+                .min(prev_row[j] + cost);
+        }
+        std::mem::swap(&mut prev_row, &mut cur_row);
+    }
+
+    prev_row[shorter.len()]
+}
+
+/// A single line from a unified diff body, tagged with the line number
+/// it holds on each side -- for a `Context` line both are real positions;
+/// for `Added`/`Removed` the side that doesn't have the line carries the
+/// position it would have been at, so a hunk header can still cite it.
+struct BodyLine<'a> {
+    kind: LineKind,
+    text: &'a str,
+    old_ln: usize,
+    new_ln: usize,
+}
+
+enum LineKind {
+    Context,
+    Added,
+    Removed,
+}
+
+/// Parse a `@@ -old_start,old_len +new_start,new_len @@` hunk header into
+/// its two starting line numbers (the lengths aren't needed here -- line
+/// numbers are tracked per-line as the body is walked).
+fn parse_hunk_header(line: &str) -> Option<(usize, usize)> {
+    let mut parts = line.split_whitespace();
+    parts.next()?; // "@@"
+    let old_part = parts.next()?;
+    let new_part = parts.next()?;
+    let old_start = old_part.trim_start_matches('-').split(',').next()?.parse().ok()?;
+    let new_start = new_part.trim_start_matches('+').split(',').next()?.parse().ok()?;
+    Some((old_start, new_start))
+}
+
+/// Group a file's change lines into hunks: each change pulls in up to
+/// `context_size` lines on either side, and hunks whose expanded ranges
+/// touch or overlap (i.e. separated by at most `2 * context_size` lines
+/// of context) merge into one. Returns `(start, end)` indices into
+/// `body`, inclusive.
+fn hunk_ranges(body: &[BodyLine], context_size: usize) -> Vec<(usize, usize)> {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for (i, line) in body.iter().enumerate() {
+        if matches!(line.kind, LineKind::Context) {
+            continue;
+        }
+        let start = i.saturating_sub(context_size);
+        let end = (i + context_size).min(body.len() - 1);
+        match ranges.last_mut() {
+            Some((_, last_end)) if start <= *last_end + 1 => *last_end = end.max(*last_end),
+            _ => ranges.push((start, end)),
+        }
     }
+    ranges
+}
 
-    // Last file
-    if !current_file.is_empty() && (added > 0 || removed > 0) {
-        result.push(format!("📄 {} (+{} -{})", current_file, added, removed));
-        for c in changes.iter().take(10) {
-            result.push(format!("  {}", c));
+/// Render one file's `📄 path (+n -n)` summary followed by its hunks,
+/// each starting with an `@@ -old +new @@` line-number marker so a
+/// reader can locate the change instead of seeing a flat list of
+/// stripped `+`/`-` lines.
+fn render_file_hunks(result: &mut Vec<String>, file: &str, body: &[BodyLine], context_size: usize) {
+    if file.is_empty() {
+        return;
+    }
+    let added = body.iter().filter(|l| matches!(l.kind, LineKind::Added)).count();
+    let removed = body.iter().filter(|l| matches!(l.kind, LineKind::Removed)).count();
+    if added == 0 && removed == 0 {
+        return;
+    }
+
+    result.push(format!("📄 {} (+{} -{})", file, added, removed));
+    for (start, end) in hunk_ranges(body, context_size) {
+        let first = &body[start];
+        result.push(format!("  @@ -{} +{} @@", first.old_ln, first.new_ln));
+        for line in &body[start..=end] {
+            result.push(format!("  {}", truncate(line.text, 70)));
         }
-        if changes.len() > 10 {
-            result.push(format!("  ... +{} more", changes.len() - 10));
+    }
+}
+
+/// Condense a unified diff (e.g. piped in from `git diff`) into a
+/// hunk-grouped summary: each change keeps `context_size` lines of
+/// surrounding context, with wide gaps between changes collapsed into
+/// separate hunks instead of one flat per-file list of bare `+`/`-` lines.
+fn condense_unified_diff(diff: &str, context_size: usize) -> String {
+    let mut result = Vec::new();
+    let mut current_file = String::new();
+    let mut old_line = 0usize;
+    let mut new_line = 0usize;
+    let mut body: Vec<BodyLine> = Vec::new();
+
+    for line in diff.lines() {
+        if line.starts_with("diff --git") {
+            render_file_hunks(&mut result, &current_file, &body, context_size);
+            current_file.clear();
+            body.clear();
+            old_line = 0;
+            new_line = 0;
+        } else if line.starts_with("--- ") {
+            // Old-file header; the filename we display comes from "+++ ".
+        } else if line.starts_with("+++ ") {
+            render_file_hunks(&mut result, &current_file, &body, context_size);
+            current_file = line
+                .trim_start_matches("+++ ")
+                .trim_start_matches("b/")
+                .to_string();
+            body.clear();
+            old_line = 0;
+            new_line = 0;
+        } else if line.starts_with("@@") {
+            if let Some((old_start, new_start)) = parse_hunk_header(line) {
+                old_line = old_start;
+                new_line = new_start;
+            }
+        } else if line.starts_with('+') && !line.starts_with("+++") {
+            body.push(BodyLine {
+                kind: LineKind::Added,
+                text: line,
+                old_ln: old_line,
+                new_ln: new_line,
+            });
+            new_line += 1;
+        } else if line.starts_with('-') && !line.starts_with("---") {
+            body.push(BodyLine {
+                kind: LineKind::Removed,
+                text: line,
+                old_ln: old_line,
+                new_ln: new_line,
+            });
+            old_line += 1;
+        } else {
+            body.push(BodyLine {
+                kind: LineKind::Context,
+                text: line,
+                old_ln: old_line,
+                new_ln: new_line,
+            });
+            old_line += 1;
+            new_line += 1;
         }
     }
+    render_file_hunks(&mut result, &current_file, &body, context_size);
 
     result.join("\n")
 }
@@ -236,8 +835,8 @@ mod tests {
     #[test]
     fn test_similarity_partial_overlap() {
         let s = similarity("abcd", "abef");
-        // Shared: a, b. Union: a, b, c, d, e, f = 6. Jaccard = 2/6
-        assert!((s - 2.0 / 6.0).abs() < f64::EPSILON);
+        // Edit distance 2 (substitute "cd" -> "ef"): 1.0 - 2/4 = 0.5
+        assert!((s - 0.5).abs() < f64::EPSILON);
     }
 
     #[test]
@@ -246,6 +845,37 @@ mod tests {
         assert!(similarity("let x = 1;", "let x = 2;") > 0.5);
     }
 
+    #[test]
+    fn test_similarity_anagram_is_not_a_false_match() {
+        // Character-set Jaccard scored this 1.0 (same alphabet); edit
+        // distance correctly sees these as almost entirely rewritten.
+        let s = similarity("abcd", "dcba");
+        assert!(s < 1.0);
+    }
+
+    // --- DiffResult serialization ---
+
+    #[test]
+    fn test_diff_result_json_tags_change_kind() {
+        let a = vec!["let x = 1;"];
+        let b = vec!["let x = 2;"];
+        let diff = compute_diff(&a, &b);
+        let json = serde_json::to_string(&diff).unwrap();
+        assert!(json.contains("\"op\":\"modified\""));
+        assert!(json.contains("\"line\":1"));
+        assert!(json.contains("\"old\":\"let x = 1;\""));
+        assert!(json.contains("\"new\":\"let x = 2;\""));
+    }
+
+    #[test]
+    fn test_diff_result_json_added_removed_tags() {
+        let a = vec!["line1"];
+        let b = vec!["line1", "line2"];
+        let diff = compute_diff(&a, &b);
+        let json = serde_json::to_string(&diff).unwrap();
+        assert!(json.contains("\"op\":\"added\""));
+    }
+
     // --- truncate ---
 
     #[test]
@@ -316,6 +946,78 @@ mod tests {
         assert_eq!(result.removed, 1);
     }
 
+    #[test]
+    fn test_compute_diff_shifted_insert_does_not_modify_everything() {
+        // Inserting a line at the top used to shift every subsequent line
+        // out of position alignment, reporting them all as "modified".
+        let a = vec!["fn main() {", "    foo();", "    bar();", "}"];
+        let b = vec![
+            "// header comment",
+            "fn main() {",
+            "    foo();",
+            "    bar();",
+            "}",
+        ];
+        let result = compute_diff(&a, &b);
+        assert_eq!(result.added, 1);
+        assert_eq!(result.removed, 0);
+        assert_eq!(result.modified, 0);
+    }
+
+    // --- inline_diff ---
+
+    #[test]
+    fn test_inline_diff_single_token_change() {
+        let result = inline_diff("let x = 1;", "let x = 2;");
+        assert_eq!(result, "let x = [1;→2;]");
+    }
+
+    #[test]
+    fn test_inline_diff_identical_strings() {
+        assert_eq!(inline_diff("let x = 1;", "let x = 1;"), "let x = 1;");
+    }
+
+    #[test]
+    fn test_inline_diff_merges_short_equal_between_edits() {
+        // The single-word equal run (" a ", 3 chars) between two edited
+        // words is shorter than SHORT_EQUAL_CHARS, so it folds into one
+        // bracketed span instead of three choppy pieces.
+        let result = inline_diff("foo a bar", "baz a qux");
+        assert_eq!(result, "[foo a bar→baz a qux]");
+    }
+
+    #[test]
+    fn test_inline_diff_empty_strings() {
+        assert_eq!(inline_diff("", ""), "");
+    }
+
+    // --- compute_patience_diff ---
+
+    #[test]
+    fn test_compute_patience_diff_aligns_unique_anchor_lines() {
+        // The lone `bar();` line is unique in both sides and anchors the
+        // match even though the surrounding `}` boilerplate repeats.
+        let a = vec!["}", "bar();", "}"];
+        let b = vec!["}", "bar();", "baz();", "}"];
+        let result = compute_patience_diff(&a, &b);
+        assert_eq!(result.added, 1);
+        assert_eq!(result.removed, 0);
+    }
+
+    #[test]
+    fn test_compute_patience_diff_identical() {
+        let a = vec!["line1", "line2", "line3"];
+        let b = vec!["line1", "line2", "line3"];
+        let result = compute_patience_diff(&a, &b);
+        assert!(result.changes.is_empty());
+    }
+
+    #[test]
+    fn test_compute_patience_diff_empty_inputs() {
+        let result = compute_patience_diff(&[], &[]);
+        assert!(result.changes.is_empty());
+    }
+
     #[test]
     fn test_compute_diff_empty_inputs() {
         let result = compute_diff(&[], &[]);
@@ -337,7 +1039,7 @@ mod tests {
      println!("world");
  }
 "#;
-        let result = condense_unified_diff(diff);
+        let result = condense_unified_diff(diff, 2);
         assert!(result.contains("src/main.rs"));
         assert!(result.contains("+1"));
         assert!(result.contains("println"));
@@ -354,14 +1056,48 @@ diff --git a/b.rs b/b.rs
 +++ b/b.rs
 -removed line
 "#;
-        let result = condense_unified_diff(diff);
+        let result = condense_unified_diff(diff, 2);
         assert!(result.contains("a.rs"));
         assert!(result.contains("b.rs"));
     }
 
     #[test]
     fn test_condense_unified_diff_empty() {
-        let result = condense_unified_diff("");
+        let result = condense_unified_diff("", 2);
         assert!(result.is_empty());
     }
+
+    #[test]
+    fn test_condense_unified_diff_keeps_context_around_change() {
+        let diff = r#"diff --git a/src/main.rs b/src/main.rs
+--- a/src/main.rs
++++ b/src/main.rs
+@@ -1,5 +1,5 @@
+ fn main() {
+     let a = 1;
+-    let b = 2;
++    let b = 3;
+     let c = 3;
+ }
+"#;
+        let result = condense_unified_diff(diff, 2);
+        assert!(result.contains("@@"));
+        assert!(result.contains("let a = 1;"));
+        assert!(result.contains("let c = 3;"));
+    }
+
+    #[test]
+    fn test_condense_unified_diff_splits_distant_changes_into_separate_hunks() {
+        let mut diff = String::from(
+            "diff --git a/f.rs b/f.rs\n--- a/f.rs\n+++ b/f.rs\n@@ -1,20 +1,20 @@\n",
+        );
+        diff.push_str("-first change\n");
+        for i in 0..20 {
+            diff.push_str(&format!(" context line {}\n", i));
+        }
+        diff.push_str("-second change\n");
+
+        let result = condense_unified_diff(&diff, 2);
+        assert_eq!(result.lines().filter(|l| l.trim_start().starts_with("@@")).count(), 2);
+    }
 }
@@ -0,0 +1,139 @@
+use super::provider::ExtractedCommand;
+use std::collections::HashMap;
+use trie_rs::{Trie, TrieBuilder};
+
+/// Number of times a tokenized command (or prefix) was seen, plus how many
+/// of those occurrences were errors.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Count {
+    pub total: usize,
+    pub errors: usize,
+}
+
+/// A trie-backed index over tokenized commands, supporting prefix queries
+/// ("what usually follows `git`?") and frequency aggregation without
+/// re-scanning the full command list for every query.
+pub struct CommandIndex {
+    trie: Trie<String>,
+    counts: HashMap<Vec<String>, Count>,
+}
+
+impl CommandIndex {
+    /// Build an index from a set of extracted commands. Each command is
+    /// whitespace-tokenized; empty commands are skipped.
+    pub fn from_commands(commands: &[ExtractedCommand]) -> Self {
+        let mut builder = TrieBuilder::new();
+        let mut counts: HashMap<Vec<String>, Count> = HashMap::new();
+
+        for cmd in commands {
+            let tokens = tokenize(&cmd.command);
+            if tokens.is_empty() {
+                continue;
+            }
+
+            builder.push(tokens.clone());
+
+            let entry = counts.entry(tokens).or_insert_with(Count::default);
+            entry.total += 1;
+            if cmd.is_error {
+                entry.errors += 1;
+            }
+        }
+
+        CommandIndex {
+            trie: builder.build(),
+            counts,
+        }
+    }
+
+    /// Find all indexed commands whose tokens start with `prefix`, paired
+    /// with their observed frequency. Results are not sorted.
+    pub fn predictive_search(&self, prefix: &[&str]) -> Vec<(Vec<String>, Count)> {
+        self.trie
+            .predictive_search(prefix.iter().map(|s| s.to_string()).collect::<Vec<_>>())
+            .into_iter()
+            .map(|tokens: Vec<String>| {
+                let count = self.counts.get(&tokens).copied().unwrap_or_default();
+                (tokens, count)
+            })
+            .collect()
+    }
+
+    /// The `n` most frequently seen commands, highest count first.
+    pub fn most_frequent(&self, n: usize) -> Vec<(Vec<String>, Count)> {
+        let mut entries: Vec<(Vec<String>, Count)> = self
+            .counts
+            .iter()
+            .map(|(tokens, count)| (tokens.clone(), *count))
+            .collect();
+
+        entries.sort_by(|a, b| b.1.total.cmp(&a.1.total));
+        entries.truncate(n);
+        entries
+    }
+}
+
+/// Split a command string on whitespace into owned tokens.
+fn tokenize(command: &str) -> Vec<String> {
+    command
+        .split_whitespace()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cmd(command: &str, is_error: bool) -> ExtractedCommand {
+        ExtractedCommand {
+            command: command.to_string(),
+            output_len: None,
+            session_id: "test".to_string(),
+            output_content: None,
+            is_error,
+            sequence_index: 0,
+            error_class: None,
+            timestamp: None,
+        }
+    }
+
+    #[test]
+    fn test_most_frequent() {
+        let commands = vec![
+            cmd("git status", false),
+            cmd("git status", false),
+            cmd("git diff", false),
+        ];
+        let index = CommandIndex::from_commands(&commands);
+        let top = index.most_frequent(1);
+        assert_eq!(top.len(), 1);
+        assert_eq!(top[0].0, vec!["git".to_string(), "status".to_string()]);
+        assert_eq!(top[0].1.total, 2);
+    }
+
+    #[test]
+    fn test_predictive_search() {
+        let commands = vec![
+            cmd("git status", false),
+            cmd("git diff", true),
+            cmd("npm run build", false),
+        ];
+        let index = CommandIndex::from_commands(&commands);
+        let results = index.predictive_search(&["git"]);
+        assert_eq!(results.len(), 2);
+
+        let diff = results
+            .iter()
+            .find(|(tokens, _)| tokens == &vec!["git".to_string(), "diff".to_string()])
+            .unwrap();
+        assert_eq!(diff.1.errors, 1);
+    }
+
+    #[test]
+    fn test_empty_command_skipped() {
+        let commands = vec![cmd("", false), cmd("   ", false)];
+        let index = CommandIndex::from_commands(&commands);
+        assert_eq!(index.most_frequent(10).len(), 0);
+    }
+}
@@ -1,3 +1,4 @@
+pub mod index;
 pub mod provider;
 pub mod registry;
 mod report;
@@ -5,8 +6,10 @@ mod report;
 use anyhow::Result;
 use std::collections::HashMap;
 
+use crate::matcher;
+use crate::pricing;
 use provider::{ClaudeProvider, SessionProvider};
-use registry::{category_avg_tokens, classify_command, split_command_chain, Classification};
+use registry::{category_avg_tokens, classify_command, split_all_stages, Classification};
 use report::{DiscoverReport, SupportedEntry, UnsupportedEntry};
 
 /// Aggregation bucket for supported commands.
@@ -24,17 +27,27 @@ struct SupportedBucket {
 struct UnsupportedBucket {
     count: usize,
     example: String,
+    suggestion: Option<&'static str>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     project: Option<&str>,
     all: bool,
     since_days: u64,
     limit: usize,
     format: &str,
+    model: Option<&str>,
+    input_price: Option<f64>,
+    output_price: Option<f64>,
+    color: &str,
+    include: &[String],
+    exclude: &[String],
     verbose: u8,
 ) -> Result<()> {
+    let rates = pricing::resolve_rates(model, input_price, output_price);
     let provider = ClaudeProvider;
+    let matcher = matcher::build_matcher(include, exclude);
 
     // Determine project filter
     let project_filter = if all {
@@ -49,7 +62,11 @@ pub fn run(
         Some(encoded)
     };
 
-    let sessions = provider.discover_sessions(project_filter.as_deref(), Some(since_days))?;
+    let sessions: Vec<_> = provider
+        .discover_sessions(project_filter.as_deref(), Some(since_days))?
+        .into_iter()
+        .filter(|path| matcher.matches(&path.to_string_lossy()))
+        .collect();
 
     if verbose > 0 {
         eprintln!("Scanning {} session files...", sessions.len());
@@ -77,8 +94,14 @@ pub fn run(
         };
 
         for ext_cmd in &extracted {
-            let parts = split_command_chain(&ext_cmd.command);
+            // Every pipe/chain stage is classified independently so e.g.
+            // `git log | rg fix` counts savings for both `git log` and
+            // `rg fix`, not just the first stage.
+            let parts = split_all_stages(&ext_cmd.command);
             for part in parts {
+                if !matcher.matches(part) {
+                    continue;
+                }
                 total_commands += 1;
 
                 match classify_command(part) {
@@ -123,11 +146,15 @@ pub fn run(
                             .or_insert(0);
                         *entry += 1;
                     }
-                    Classification::Unsupported { base_command } => {
+                    Classification::Unsupported {
+                        base_command,
+                        suggestion,
+                    } => {
                         let bucket = unsupported_map.entry(base_command).or_insert_with(|| {
                             UnsupportedBucket {
                                 count: 0,
                                 example: part.to_string(),
+                                suggestion,
                             }
                         });
                         bucket.count += 1;
@@ -178,6 +205,10 @@ pub fn run(
                 estimated_savings_tokens: bucket.total_output_tokens,
                 estimated_savings_pct: bucket.savings_pct,
                 rtk_status: status,
+                estimated_savings_usd: pricing::estimate_usd(
+                    bucket.total_output_tokens,
+                    rates.output_per_million,
+                ),
             }
         })
         .collect();
@@ -191,6 +222,7 @@ pub fn run(
             base_command: base,
             count: bucket.count,
             example: bucket.example,
+            suggestion: bucket.suggestion,
         })
         .collect();
 
@@ -205,18 +237,22 @@ pub fn run(
         supported,
         unsupported,
         parse_errors,
+        pricing_model: rates.model,
     };
 
     match format {
         "json" => println!("{}", report::format_json(&report)),
-        _ => print!("{}", report::format_text(&report, limit, verbose > 0)),
+        _ => {
+            let style = crate::style::Style::resolve(color);
+            print!("{}", report::format_text(&report, limit, verbose > 0, style));
+        }
     }
 
     Ok(())
 }
 
 /// Extract the subcommand from a command string (second word).
-fn extract_subcmd(cmd: &str) -> &str {
+pub(crate) fn extract_subcmd(cmd: &str) -> &str {
     let parts: Vec<&str> = cmd.trim().splitn(3, char::is_whitespace).collect();
     if parts.len() >= 2 {
         parts[1]
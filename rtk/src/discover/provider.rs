@@ -1,13 +1,15 @@
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::io::{BufRead, BufReader};
+use std::io::{BufRead, BufReader, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
 use std::time::{Duration, SystemTime};
 use walkdir::WalkDir;
 
 /// A command extracted from a session file.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExtractedCommand {
     pub command: String,
     pub output_len: Option<usize>,
@@ -19,6 +21,56 @@ pub struct ExtractedCommand {
     pub is_error: bool,
     /// Chronological sequence index within the session
     pub sequence_index: usize,
+    /// Classification of the failure, set whenever `is_error` is true.
+    pub error_class: Option<ErrorClass>,
+    /// RFC 3339 timestamp of the `tool_use` entry, when the transcript
+    /// carried one. `None` for providers that don't report timestamps.
+    #[serde(default)]
+    pub timestamp: Option<String>,
+}
+
+/// A coarse classification of why a command's `tool_result` was an error,
+/// determined by matching known signatures in `output_content`. Lets
+/// downstream reporting group failures ("3 permission-denied, 1 git error")
+/// instead of just counting them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ErrorClass {
+    CommandNotFound,
+    PermissionDenied,
+    FileNotFound,
+    GitError,
+    SyntaxError,
+    NonZeroExit,
+    Timeout,
+    Generic,
+}
+
+impl ErrorClass {
+    /// Classify an error's output by matching known signatures, in priority
+    /// order. First match wins; anything unrecognized falls back to
+    /// [`ErrorClass::Generic`].
+    fn classify(output: &str) -> ErrorClass {
+        let lower = output.to_lowercase();
+
+        if lower.contains("command not found") || lower.contains("not recognized as an internal")
+        {
+            ErrorClass::CommandNotFound
+        } else if lower.contains("permission denied") || lower.contains("eacces") {
+            ErrorClass::PermissionDenied
+        } else if lower.contains("no such file or directory") || lower.contains("not found:") {
+            ErrorClass::FileNotFound
+        } else if lower.contains("fatal:") || lower.contains("not a git repository") {
+            ErrorClass::GitError
+        } else if lower.contains("syntax error") || lower.contains("unexpected token") {
+            ErrorClass::SyntaxError
+        } else if lower.contains("timed out") || lower.contains("timeout") {
+            ErrorClass::Timeout
+        } else if lower.contains("exit code") || lower.contains("exit status") {
+            ErrorClass::NonZeroExit
+        } else {
+            ErrorClass::Generic
+        }
+    }
 }
 
 /// Trait for session providers (Claude Code, future: Cursor, Windsurf).
@@ -127,12 +179,28 @@ impl SessionProvider for ClaudeProvider {
             .unwrap_or("unknown")
             .to_string();
 
+        let (commands, _next_sequence) = Self::parse_bash_entries(reader, &session_id, 0);
+        Ok(commands)
+    }
+}
+
+impl ClaudeProvider {
+    /// Parse Bash tool_use/tool_result pairs out of `reader`, assigning
+    /// sequence indices starting at `start_sequence`. Shared by a full-file
+    /// [`extract_commands`](SessionProvider::extract_commands) parse and the
+    /// incremental tail parse in [`extract_commands_cached`]. Returns the
+    /// extracted commands plus the next unused sequence index.
+    fn parse_bash_entries(
+        reader: impl BufRead,
+        session_id: &str,
+        start_sequence: usize,
+    ) -> (Vec<ExtractedCommand>, usize) {
         // First pass: collect all tool_use Bash commands with their IDs and sequence
         // Second pass (same loop): collect tool_result output lengths, content, and error status
-        let mut pending_tool_uses: Vec<(String, String, usize)> = Vec::new(); // (tool_use_id, command, sequence)
+        let mut pending_tool_uses: Vec<(String, String, usize, Option<String>)> = Vec::new(); // (tool_use_id, command, sequence, timestamp)
         let mut tool_results: HashMap<String, (usize, String, bool)> = HashMap::new(); // (len, content, is_error)
         let mut commands = Vec::new();
-        let mut sequence_counter = 0;
+        let mut sequence_counter = start_sequence;
 
         for line in reader.lines() {
             let line = match line {
@@ -154,6 +222,11 @@ impl SessionProvider for ClaudeProvider {
 
             match entry_type {
                 "assistant" => {
+                    let timestamp = entry
+                        .get("timestamp")
+                        .and_then(|t| t.as_str())
+                        .map(|s| s.to_string());
+
                     // Look for tool_use Bash blocks in message.content
                     if let Some(content) =
                         entry.pointer("/message/content").and_then(|c| c.as_array())
@@ -170,6 +243,7 @@ impl SessionProvider for ClaudeProvider {
                                         id.to_string(),
                                         cmd.to_string(),
                                         sequence_counter,
+                                        timestamp.clone(),
                                     ));
                                     sequence_counter += 1;
                                 }
@@ -214,26 +288,335 @@ impl SessionProvider for ClaudeProvider {
         }
 
         // Match tool_uses with their results
-        for (tool_id, command, sequence_index) in pending_tool_uses {
+        for (tool_id, command, sequence_index, timestamp) in pending_tool_uses {
             let (output_len, output_content, is_error) = tool_results
                 .get(&tool_id)
                 .map(|(len, content, err)| (Some(*len), Some(content.clone()), *err))
                 .unwrap_or((None, None, false));
 
+            let error_class = if is_error {
+                Some(ErrorClass::classify(output_content.as_deref().unwrap_or("")))
+            } else {
+                None
+            };
+
             commands.push(ExtractedCommand {
                 command,
                 output_len,
-                session_id: session_id.clone(),
+                session_id: session_id.to_string(),
                 output_content,
                 is_error,
                 sequence_index,
+                error_class,
+                timestamp,
             });
         }
 
+        (commands, sequence_counter)
+    }
+}
+
+/// Cache manifest entry: the file state the cache was built from, plus the
+/// commands extracted at that point.
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    mtime_secs: u64,
+    size: u64,
+    commands: Vec<ExtractedCommand>,
+}
+
+impl ClaudeProvider {
+    /// Directory the incremental discovery cache lives under:
+    /// `~/.seline/cache/`.
+    fn cache_dir() -> Result<PathBuf> {
+        let home = dirs::home_dir().context("could not determine home directory")?;
+        Ok(home.join(".seline").join("cache"))
+    }
+
+    fn cache_manifest_path() -> Result<PathBuf> {
+        Ok(Self::cache_dir()?.join("discover_cache.json"))
+    }
+
+    fn load_cache_manifest() -> HashMap<String, CacheEntry> {
+        let Ok(path) = Self::cache_manifest_path() else {
+            return HashMap::new();
+        };
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    fn save_cache_manifest(manifest: &HashMap<String, CacheEntry>) -> Result<()> {
+        let dir = Self::cache_dir()?;
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("failed to create {}", dir.display()))?;
+        let path = Self::cache_manifest_path()?;
+        let json = serde_json::to_string(manifest)?;
+        fs::write(&path, json).with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    /// Like [`extract_commands`](SessionProvider::extract_commands), but
+    /// backed by a persistent cache keyed on `(mtime, size)` under
+    /// `~/.seline/cache/`, so unchanged session files are never re-parsed.
+    ///
+    /// When a cached file has only grown (same mtime-or-newer, larger size),
+    /// this seeks to the previously cached byte offset and parses just the
+    /// appended tail, merging it onto the cached commands and continuing the
+    /// `sequence_index` counter — a JSONL transcript is append-only in
+    /// practice, so this is the common case for an in-progress session. Any
+    /// other change (file shrank, or same size but different mtime) is
+    /// treated as a rewrite and triggers a full re-parse.
+    pub fn extract_commands_cached(&self, path: &Path) -> Result<Vec<ExtractedCommand>> {
+        let metadata =
+            fs::metadata(path).with_context(|| format!("failed to stat {}", path.display()))?;
+        let size = metadata.len();
+        let mtime_secs = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        let key = path.to_string_lossy().into_owned();
+        let mut manifest = Self::load_cache_manifest();
+
+        if let Some(entry) = manifest.get(&key) {
+            if entry.mtime_secs == mtime_secs && entry.size == size {
+                return Ok(entry.commands.clone());
+            }
+
+            if size > entry.size {
+                let session_id = path
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("unknown")
+                    .to_string();
+
+                let mut file = fs::File::open(path)
+                    .with_context(|| format!("failed to open {}", path.display()))?;
+                file.seek(SeekFrom::Start(entry.size))?;
+                let reader = BufReader::new(file);
+
+                let start_sequence = entry
+                    .commands
+                    .iter()
+                    .map(|c| c.sequence_index + 1)
+                    .max()
+                    .unwrap_or(0);
+                let (tail_commands, _) =
+                    Self::parse_bash_entries(reader, &session_id, start_sequence);
+
+                let mut merged = entry.commands.clone();
+                merged.extend(tail_commands);
+
+                manifest.insert(
+                    key,
+                    CacheEntry {
+                        mtime_secs,
+                        size,
+                        commands: merged.clone(),
+                    },
+                );
+                Self::save_cache_manifest(&manifest)?;
+                return Ok(merged);
+            }
+            // File shrank or was rewritten at the same size: fall through to
+            // a full re-parse below, which overwrites the stale entry.
+        }
+
+        let commands = self.extract_commands(path)?;
+        manifest.insert(
+            key,
+            CacheEntry {
+                mtime_secs,
+                size,
+                commands: commands.clone(),
+            },
+        );
+        Self::save_cache_manifest(&manifest)?;
         Ok(commands)
     }
 }
 
+/// A session provider implemented as an external executable, discovered from
+/// `~/.seline/providers/`. This lets users add support for another agent's
+/// transcript format (Cursor, Windsurf, ...) without touching this crate:
+/// drop an executable in the providers directory and it's picked up at
+/// startup.
+///
+/// Protocol: one line-delimited JSON request is written to the child's
+/// stdin, and one line-delimited JSON response is read back from stdout.
+///
+/// - `{"op":"discover","project_filter":<string|null>,"since_days":<u64|null>}`
+///   → a JSON array of session file paths (strings).
+/// - `{"op":"extract","path":"<path>"}`
+///   → a JSON array of objects with `command`, `output_len`, `output_content`,
+///     `is_error`, `sequence_index` (the same shape as [`ExtractedCommand`],
+///     minus `session_id`, which is derived from the path).
+///
+/// A non-zero exit status or malformed JSON is treated as a recoverable
+/// per-provider failure, not a hard error, so one broken plugin doesn't take
+/// down discovery for every other provider.
+pub struct PluginProvider {
+    executable: PathBuf,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum PluginRequest<'a> {
+    Discover {
+        project_filter: Option<&'a str>,
+        since_days: Option<u64>,
+    },
+    Extract {
+        path: String,
+    },
+}
+
+#[derive(Deserialize)]
+struct PluginCommand {
+    command: String,
+    output_len: Option<usize>,
+    output_content: Option<String>,
+    is_error: bool,
+    sequence_index: usize,
+    #[serde(default)]
+    timestamp: Option<String>,
+}
+
+impl PluginProvider {
+    /// Directory plugins are discovered from: `~/.seline/providers/`.
+    fn providers_dir() -> Result<PathBuf> {
+        let home = dirs::home_dir().context("could not determine home directory")?;
+        Ok(home.join(".seline").join("providers"))
+    }
+
+    /// Discover all executables under the providers directory. Missing
+    /// directory is not an error — it just means no plugins are installed.
+    pub fn discover_plugins() -> Result<Vec<PluginProvider>> {
+        let dir = Self::providers_dir()?;
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut providers = Vec::new();
+        for entry in fs::read_dir(&dir)
+            .with_context(|| format!("failed to read {}", dir.display()))?
+            .flatten()
+        {
+            let path = entry.path();
+            if !path.is_file() || !is_executable(&path) {
+                continue;
+            }
+            providers.push(PluginProvider { executable: path });
+        }
+
+        Ok(providers)
+    }
+
+    /// Send `request` to the plugin over stdin and parse one JSON response
+    /// line from stdout.
+    fn call<T: serde::de::DeserializeOwned>(&self, request: &PluginRequest) -> Result<T> {
+        let mut child = Command::new(&self.executable)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .with_context(|| format!("failed to spawn provider {}", self.executable.display()))?;
+
+        let request_line = serde_json::to_string(request)?;
+        if let Some(stdin) = child.stdin.as_mut() {
+            writeln!(stdin, "{}", request_line)?;
+            stdin.flush()?;
+        }
+
+        let mut stdout = BufReader::new(
+            child
+                .stdout
+                .take()
+                .context("provider did not expose stdout")?,
+        );
+        let mut response_line = String::new();
+        stdout.read_line(&mut response_line)?;
+
+        let status = child.wait()?;
+        if !status.success() {
+            anyhow::bail!(
+                "provider {} exited with {}",
+                self.executable.display(),
+                status
+            );
+        }
+
+        serde_json::from_str(response_line.trim())
+            .with_context(|| format!("malformed response from {}", self.executable.display()))
+    }
+}
+
+impl SessionProvider for PluginProvider {
+    fn discover_sessions(
+        &self,
+        project_filter: Option<&str>,
+        since_days: Option<u64>,
+    ) -> Result<Vec<PathBuf>> {
+        let paths: Vec<String> = self.call(&PluginRequest::Discover {
+            project_filter,
+            since_days,
+        })?;
+        Ok(paths.into_iter().map(PathBuf::from).collect())
+    }
+
+    fn extract_commands(&self, path: &Path) -> Result<Vec<ExtractedCommand>> {
+        let session_id = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("unknown")
+            .to_string();
+
+        let plugin_commands: Vec<PluginCommand> = self.call(&PluginRequest::Extract {
+            path: path.to_string_lossy().into_owned(),
+        })?;
+
+        Ok(plugin_commands
+            .into_iter()
+            .map(|c| {
+                let error_class = if c.is_error {
+                    Some(ErrorClass::classify(c.output_content.as_deref().unwrap_or("")))
+                } else {
+                    None
+                };
+
+                ExtractedCommand {
+                    command: c.command,
+                    output_len: c.output_len,
+                    session_id: session_id.clone(),
+                    output_content: c.output_content,
+                    is_error: c.is_error,
+                    sequence_index: c.sequence_index,
+                    error_class,
+                    timestamp: c.timestamp,
+                }
+            })
+            .collect())
+    }
+}
+
+/// Unix-only executable-bit check; treated as "executable" unconditionally
+/// on other platforms since there's no equivalent permission bit to check.
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    fs::metadata(path)
+        .map(|m| m.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(_path: &Path) -> bool {
+    true
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -385,4 +768,54 @@ mod tests {
         assert_eq!(cmds[1].command, "second");
         assert_eq!(cmds[2].command, "third");
     }
+
+    #[test]
+    fn test_error_class_command_not_found() {
+        let jsonl = make_jsonl(&[
+            r#"{"type":"assistant","message":{"role":"assistant","content":[{"type":"tool_use","id":"toolu_1","name":"Bash","input":{"command":"foobarbaz"}}]}}"#,
+            r#"{"type":"user","message":{"role":"user","content":[{"type":"tool_result","tool_use_id":"toolu_1","content":"bash: foobarbaz: command not found","is_error":true}]}}"#,
+        ]);
+
+        let provider = ClaudeProvider;
+        let cmds = provider.extract_commands(jsonl.path()).unwrap();
+        assert_eq!(cmds[0].error_class, Some(ErrorClass::CommandNotFound));
+    }
+
+    #[test]
+    fn test_error_class_git_error() {
+        let jsonl = make_jsonl(&[
+            r#"{"type":"assistant","message":{"role":"assistant","content":[{"type":"tool_use","id":"toolu_1","name":"Bash","input":{"command":"git push"}}]}}"#,
+            r#"{"type":"user","message":{"role":"user","content":[{"type":"tool_result","tool_use_id":"toolu_1","content":"fatal: not a git repository","is_error":true}]}}"#,
+        ]);
+
+        let provider = ClaudeProvider;
+        let cmds = provider.extract_commands(jsonl.path()).unwrap();
+        assert_eq!(cmds[0].error_class, Some(ErrorClass::GitError));
+    }
+
+    #[test]
+    fn test_error_class_none_when_not_error() {
+        let jsonl = make_jsonl(&[
+            r#"{"type":"assistant","message":{"role":"assistant","content":[{"type":"tool_use","id":"toolu_1","name":"Bash","input":{"command":"ls"}}]}}"#,
+            r#"{"type":"user","message":{"role":"user","content":[{"type":"tool_result","tool_use_id":"toolu_1","content":"file1.txt","is_error":false}]}}"#,
+        ]);
+
+        let provider = ClaudeProvider;
+        let cmds = provider.extract_commands(jsonl.path()).unwrap();
+        assert_eq!(cmds[0].error_class, None);
+    }
+
+    #[test]
+    fn test_parse_bash_entries_continues_sequence() {
+        let jsonl = make_jsonl(&[
+            r#"{"type":"assistant","message":{"role":"assistant","content":[{"type":"tool_use","id":"toolu_1","name":"Bash","input":{"command":"git status"}}]}}"#,
+        ]);
+
+        let file = std::fs::File::open(jsonl.path()).unwrap();
+        let reader = BufReader::new(file);
+        let (commands, next_sequence) = ClaudeProvider::parse_bash_entries(reader, "sess", 5);
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].sequence_index, 5);
+        assert_eq!(next_sequence, 6);
+    }
 }
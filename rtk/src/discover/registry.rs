@@ -1,7 +1,10 @@
+use anyhow::{Context, Result};
 use lazy_static::lazy_static;
 use regex::{Regex, RegexSet};
+use serde::Deserialize;
 
 /// A rule mapping a shell command pattern to its RTK equivalent.
+#[derive(Clone)]
 struct RtkRule {
     rtk_cmd: &'static str,
     category: &'static str,
@@ -21,6 +24,9 @@ pub enum Classification {
     },
     Unsupported {
         base_command: String,
+        /// The closest RTK-covered command, when the base command's first
+        /// token is a likely typo of one (e.g. `gti` -> `rtk git`).
+        suggestion: Option<&'static str>,
     },
     Ignored,
 }
@@ -284,49 +290,300 @@ const IGNORED_PREFIXES: &[&str] = &[
 
 const IGNORED_EXACT: &[&str] = &["cd", "echo", "true", "false", "wait", "pwd", "bash", "sh"];
 
+/// Known leading commands RTK has dedicated handling for, paired with the
+/// `rtk <cmd>` equivalent to suggest. Derived from the leading word(s) of
+/// each `PATTERNS` entry (e.g. `cat|head|tail` all map to `rtk read`), used
+/// by [`suggest_command`] to power "did you mean" hints on unsupported
+/// commands.
+const KNOWN_COMMANDS: &[(&str, &str)] = &[
+    ("git", "rtk git"),
+    ("gh", "rtk gh"),
+    ("cargo", "rtk cargo"),
+    ("pnpm", "rtk pnpm"),
+    ("npm", "rtk npm"),
+    ("npx", "rtk npx"),
+    ("cat", "rtk read"),
+    ("head", "rtk read"),
+    ("tail", "rtk read"),
+    ("rg", "rtk grep"),
+    ("grep", "rtk grep"),
+    ("ls", "rtk ls"),
+    ("find", "rtk find"),
+    ("tsc", "rtk tsc"),
+    ("eslint", "rtk lint"),
+    ("biome", "rtk lint"),
+    ("prettier", "rtk prettier"),
+    ("next", "rtk next"),
+    ("vitest", "rtk vitest"),
+    ("jest", "rtk vitest"),
+    ("playwright", "rtk playwright"),
+    ("prisma", "rtk prisma"),
+    ("docker", "rtk docker"),
+    ("kubectl", "rtk kubectl"),
+    ("curl", "rtk curl"),
+    ("wget", "rtk wget"),
+];
+
 lazy_static! {
-    static ref REGEX_SET: RegexSet = RegexSet::new(PATTERNS).expect("invalid regex patterns");
-    static ref COMPILED: Vec<Regex> = PATTERNS
-        .iter()
-        .map(|p| Regex::new(p).expect("invalid regex"))
-        .collect();
     static ref ENV_PREFIX: Regex =
         Regex::new(r"^(?:sudo\s+|env\s+|[A-Z_][A-Z0-9_]*=[^\s]*\s+)+").unwrap();
+    static ref REGISTRY: Registry = Registry::load().unwrap_or_else(|err| {
+        eprintln!("warning: ignoring seline.toml ({err:#}); using built-in rules only");
+        Registry::builtin()
+    });
+    static ref IGNORE_MATCHER: IgnoreMatcher = IgnoreMatcher::load().unwrap_or_else(|err| {
+        eprintln!("warning: ignoring .selineignore ({err:#}); using built-in ignore rules only");
+        IgnoreMatcher::builtin()
+    });
 }
 
-/// Classify a single (already-split) command.
-pub fn classify_command(cmd: &str) -> Classification {
-    let trimmed = cmd.trim();
-    if trimmed.is_empty() {
-        return Classification::Ignored;
+/// One match rule from a `.selineignore` line: a pattern plus the match
+/// semantics its prefix selects.
+enum IgnorePattern {
+    Exact(String),
+    Prefix(String),
+    Regex(Regex),
+}
+
+/// A single `.selineignore` rule: a pattern plus whether it's an ignore
+/// (the default) or an include ("!"-prefixed un-ignore).
+struct IgnoreRule {
+    pattern: IgnorePattern,
+    include: bool,
+}
+
+impl IgnoreRule {
+    fn matches(&self, cmd: &str) -> bool {
+        match &self.pattern {
+            IgnorePattern::Exact(s) => cmd == s,
+            IgnorePattern::Prefix(s) => cmd.starts_with(s.as_str()),
+            IgnorePattern::Regex(re) => re.is_match(cmd),
+        }
     }
 
-    // Check ignored
-    for exact in IGNORED_EXACT {
-        if trimmed == *exact {
-            return Classification::Ignored;
+    /// Parse one `.selineignore` line: `[!]<exact:|prefix:|re:><spec>`.
+    /// Blank lines and `#`-comments parse to `None`.
+    fn parse(line: &str) -> Result<Option<IgnoreRule>> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(None);
         }
+
+        let (include, rest) = match line.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+
+        let pattern = if let Some(spec) = rest.strip_prefix("exact:") {
+            IgnorePattern::Exact(spec.to_string())
+        } else if let Some(spec) = rest.strip_prefix("prefix:") {
+            IgnorePattern::Prefix(spec.to_string())
+        } else if let Some(spec) = rest.strip_prefix("re:") {
+            IgnorePattern::Regex(
+                Regex::new(spec)
+                    .with_context(|| format!("invalid regex in .selineignore: `{spec}`"))?,
+            )
+        } else {
+            anyhow::bail!(
+                "'{}' is missing an exact:/prefix:/re: match-type prefix",
+                line
+            );
+        };
+
+        Ok(Some(IgnoreRule { pattern, include }))
     }
-    for prefix in IGNORED_PREFIXES {
-        if trimmed.starts_with(prefix) {
-            return Classification::Ignored;
+}
+
+/// Composed ignore matcher: the built-in `IGNORED_EXACT`/`IGNORED_PREFIXES`
+/// defaults, then any `.selineignore` rules appended in file order.
+/// Evaluated last-match-wins, so a later `!exact:sed` can re-enable a
+/// command an earlier broad `prefix:se` ignored.
+struct IgnoreMatcher {
+    rules: Vec<IgnoreRule>,
+}
+
+impl IgnoreMatcher {
+    /// Built-in defaults only, skipping any `.selineignore` lookup. Used
+    /// when no ignore file is present, and as the fallback when one fails
+    /// to load.
+    fn builtin() -> Self {
+        IgnoreMatcher {
+            rules: default_ignore_rules(),
         }
     }
 
-    // Strip env prefixes (sudo, env VAR=val, VAR=val)
-    let stripped = ENV_PREFIX.replace(trimmed, "");
-    let cmd_clean = stripped.trim();
-    if cmd_clean.is_empty() {
-        return Classification::Ignored;
+    /// Built-in defaults plus any `.selineignore` found in the current
+    /// directory.
+    fn load() -> Result<Self> {
+        let mut rules = default_ignore_rules();
+        rules.extend(load_selineignore()?);
+        Ok(IgnoreMatcher { rules })
+    }
+
+    fn is_ignored(&self, cmd: &str) -> bool {
+        let mut ignored = false;
+        for rule in &self.rules {
+            if rule.matches(cmd) {
+                ignored = !rule.include;
+            }
+        }
+        ignored
     }
+}
+
+fn default_ignore_rules() -> Vec<IgnoreRule> {
+    let mut rules: Vec<IgnoreRule> = IGNORED_EXACT
+        .iter()
+        .map(|s| IgnoreRule {
+            pattern: IgnorePattern::Exact(s.to_string()),
+            include: false,
+        })
+        .collect();
+    rules.extend(IGNORED_PREFIXES.iter().map(|s| IgnoreRule {
+        pattern: IgnorePattern::Prefix(s.to_string()),
+        include: false,
+    }));
+    rules
+}
+
+/// Read `.selineignore` from the current directory, if present, and parse
+/// its lines into ignore/include rules. Returns an empty list (not an
+/// error) when the file doesn't exist; an invalid regex or malformed line
+/// surfaces as a clear error instead of panicking.
+fn load_selineignore() -> Result<Vec<IgnoreRule>> {
+    let path = std::env::current_dir()
+        .context("failed to read current directory")?
+        .join(".selineignore");
+
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+
+    content
+        .lines()
+        .filter_map(|line| IgnoreRule::parse(line).transpose())
+        .collect()
+}
+
+/// One user-defined rule from a `seline.toml` `[[rule]]` table, before its
+/// strings are leaked to `'static` so it can live alongside the built-in
+/// `RtkRule`s in a `Registry`.
+#[derive(Debug, Deserialize)]
+struct UserRule {
+    pattern: String,
+    rtk_cmd: String,
+    category: String,
+    savings_pct: f64,
+    #[serde(default)]
+    subcmd_savings: Vec<(String, f64)>,
+    #[serde(default)]
+    subcmd_status: Vec<(String, String)>,
+}
+
+impl UserRule {
+    /// Leak this rule's owned strings to `'static` so it fits the same
+    /// `RtkRule` shape as the built-in, const-defined rules. Rules are only
+    /// ever loaded once at startup, so the one-time leak is bounded by the
+    /// (small) size of the user's `seline.toml`.
+    fn into_rule(self) -> RtkRule {
+        let subcmd_savings: Vec<(&'static str, f64)> = self
+            .subcmd_savings
+            .into_iter()
+            .map(|(sub, pct)| (leak_str(sub), pct))
+            .collect();
+        let subcmd_status: Vec<(&'static str, super::report::RtkStatus)> = self
+            .subcmd_status
+            .into_iter()
+            .map(|(sub, status)| {
+                let status = match status.as_str() {
+                    "passthrough" => super::report::RtkStatus::Passthrough,
+                    "not-supported" => super::report::RtkStatus::NotSupported,
+                    _ => super::report::RtkStatus::Existing,
+                };
+                (leak_str(sub), status)
+            })
+            .collect();
+
+        RtkRule {
+            rtk_cmd: leak_str(self.rtk_cmd),
+            category: leak_str(self.category),
+            savings_pct: self.savings_pct,
+            subcmd_savings: Box::leak(subcmd_savings.into_boxed_slice()),
+            subcmd_status: Box::leak(subcmd_status.into_boxed_slice()),
+        }
+    }
+}
+
+/// The `[[rule]]` array of tables a `seline.toml` is expected to contain.
+#[derive(Debug, Default, Deserialize)]
+struct UserRulesFile {
+    #[serde(default)]
+    rule: Vec<UserRule>,
+}
+
+fn leak_str(s: String) -> &'static str {
+    Box::leak(s.into_boxed_str())
+}
+
+/// Compiled, queryable set of classification rules: the built-in `RULES`
+/// table plus any rules merged in from a `seline.toml`. User rules are
+/// appended after the built-ins, so `classify` (which keeps the *last*
+/// `RegexSet` match as the most specific one) lets a user rule override a
+/// built-in default with the same shape.
+struct Registry {
+    rules: Vec<RtkRule>,
+    regex_set: RegexSet,
+    compiled: Vec<Regex>,
+}
+
+impl Registry {
+    /// Built-in rules only, skipping any `seline.toml` lookup. Used when no
+    /// user config is present, and as the fallback when one fails to load.
+    fn builtin() -> Self {
+        Self::build(Vec::new()).expect("built-in PATTERNS are valid regexes")
+    }
+
+    /// Built-ins plus any `seline.toml` found in the current directory,
+    /// analogous to how Cargo layers `[alias]` entries from `config.toml`
+    /// on top of its own defaults.
+    fn load() -> Result<Self> {
+        Self::build(load_user_rules()?)
+    }
+
+    fn build(user_rules: Vec<(String, RtkRule)>) -> Result<Self> {
+        let mut patterns: Vec<String> = PATTERNS.iter().map(|p| p.to_string()).collect();
+        let mut rules: Vec<RtkRule> = RULES.to_vec();
+
+        for (pattern, rule) in user_rules {
+            patterns.push(pattern);
+            rules.push(rule);
+        }
 
-    // Fast check with RegexSet â€” take the last (most specific) match
-    let matches: Vec<usize> = REGEX_SET.matches(cmd_clean).into_iter().collect();
-    if let Some(&idx) = matches.last() {
-        let rule = &RULES[idx];
+        let regex_set =
+            RegexSet::new(&patterns).context("failed to compile classification rule patterns")?;
+        let compiled = patterns
+            .iter()
+            .map(|p| Regex::new(p))
+            .collect::<std::result::Result<Vec<_>, regex::Error>>()
+            .context("failed to compile classification rule patterns")?;
+
+        Ok(Registry {
+            rules,
+            regex_set,
+            compiled,
+        })
+    }
+
+    fn classify(&self, cmd_clean: &str) -> Option<Classification> {
+        let matches: Vec<usize> = self.regex_set.matches(cmd_clean).into_iter().collect();
+        let &idx = matches.last()?;
+        let rule = &self.rules[idx];
 
         // Extract subcommand for savings override and status detection
-        let (savings, status) = if let Some(caps) = COMPILED[idx].captures(cmd_clean) {
+        let (savings, status) = if let Some(caps) = self.compiled[idx].captures(cmd_clean) {
             if let Some(sub) = caps.get(1) {
                 let subcmd = sub.as_str();
                 // Check if this subcommand has a special status
@@ -353,23 +610,130 @@ pub fn classify_command(cmd: &str) -> Classification {
             (rule.savings_pct, super::report::RtkStatus::Existing)
         };
 
-        Classification::Supported {
+        Some(Classification::Supported {
             rtk_equivalent: rule.rtk_cmd,
             category: rule.category,
             estimated_savings_pct: savings,
             status,
+        })
+    }
+}
+
+/// Read `seline.toml` from the current directory, if present, and parse its
+/// `[[rule]]` entries. Returns an empty list (not an error) when the file
+/// doesn't exist; an invalid regex or malformed TOML surfaces as a clear
+/// error instead of panicking.
+fn load_user_rules() -> Result<Vec<(String, RtkRule)>> {
+    let path = std::env::current_dir()
+        .context("failed to read current directory")?
+        .join("seline.toml");
+
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    let file: UserRulesFile =
+        toml::from_str(&content).with_context(|| format!("failed to parse {}", path.display()))?;
+
+    compile_user_rules(file.rule)
+}
+
+/// Validate each user rule's regex pattern and leak it into a `RtkRule`,
+/// bailing with a clear error on the first invalid pattern instead of
+/// panicking the way the built-in `.expect(...)` path used to.
+fn compile_user_rules(user_rules: Vec<UserRule>) -> Result<Vec<(String, RtkRule)>> {
+    user_rules
+        .into_iter()
+        .map(|user_rule| {
+            Regex::new(&user_rule.pattern)
+                .with_context(|| format!("invalid regex in seline.toml: `{}`", user_rule.pattern))?;
+            let pattern = user_rule.pattern.clone();
+            Ok((pattern, user_rule.into_rule()))
+        })
+        .collect()
+}
+
+/// Classify a single (already-split) command.
+pub fn classify_command(cmd: &str) -> Classification {
+    let trimmed = cmd.trim();
+    if trimmed.is_empty() {
+        return Classification::Ignored;
+    }
+
+    // Check ignored -- built-in defaults plus any `.selineignore` overrides
+    if IGNORE_MATCHER.is_ignored(trimmed) {
+        return Classification::Ignored;
+    }
+
+    // Strip env prefixes (sudo, env VAR=val, VAR=val)
+    let stripped = ENV_PREFIX.replace(trimmed, "");
+    let cmd_clean = stripped.trim();
+    if cmd_clean.is_empty() {
+        return Classification::Ignored;
+    }
+
+    // Fast check with RegexSet -- take the last (most specific) match
+    match REGISTRY.classify(cmd_clean) {
+        Some(classification) => classification,
+        None => {
+            // Extract base command for unsupported
+            let base = extract_base_command(cmd_clean);
+            if base.is_empty() {
+                Classification::Ignored
+            } else {
+                Classification::Unsupported {
+                    base_command: base.to_string(),
+                    suggestion: suggest_command(base),
+                }
+            }
         }
+    }
+}
+
+/// Classic DP edit (Levenshtein) distance between `a` and `b`, using two
+/// rolling rows for O(min(len)) memory instead of a full (m+1)x(n+1) matrix.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let (shorter, longer) = if a.chars().count() <= b.chars().count() {
+        (a, b)
     } else {
-        // Extract base command for unsupported
-        let base = extract_base_command(cmd_clean);
-        if base.is_empty() {
-            Classification::Ignored
-        } else {
-            Classification::Unsupported {
-                base_command: base.to_string(),
-            }
+        (b, a)
+    };
+    let shorter: Vec<char> = shorter.chars().collect();
+    let longer: Vec<char> = longer.chars().collect();
+
+    let mut prev: Vec<usize> = (0..=shorter.len()).collect();
+    let mut curr: Vec<usize> = vec![0; shorter.len() + 1];
+
+    for (i, &lc) in longer.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, &sc) in shorter.iter().enumerate() {
+            let cost = if lc == sc { 0 } else { 1 };
+            curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
         }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[shorter.len()]
+}
+
+/// Find the closest [`KNOWN_COMMANDS`] entry to `base_command`'s first
+/// token, for the "did you mean" hint on unsupported commands. Only
+/// suggests when the edit distance is <= 2 and strictly less than a third
+/// of the candidate's length, to avoid absurd matches on short tokens.
+fn suggest_command(base_command: &str) -> Option<&'static str> {
+    let token = base_command.split_whitespace().next().unwrap_or("");
+    if token.is_empty() {
+        return None;
     }
+
+    KNOWN_COMMANDS
+        .iter()
+        .map(|(candidate, rtk_cmd)| (edit_distance(token, candidate), candidate, rtk_cmd))
+        .filter(|(distance, candidate, _)| *distance <= 2 && distance * 3 < candidate.len())
+        .min_by_key(|(distance, _, _)| *distance)
+        .map(|(_, _, rtk_cmd)| *rtk_cmd)
 }
 
 /// Extract the base command (first word, or first two if it looks like a subcommand pattern).
@@ -405,6 +769,19 @@ fn extract_base_command(cmd: &str) -> &str {
 /// For pipes `|`, only keep the first command.
 /// Lines with `<<` (heredoc) or `$((` are returned whole.
 pub fn split_command_chain(cmd: &str) -> Vec<&str> {
+    split_chain_inner(cmd, true)
+}
+
+/// Like `split_command_chain`, but returns every pipe stage instead of
+/// keeping only the first -- e.g. `git log | rg fix | head -20` splits into
+/// all three stages instead of just `git log`. Quote state, `&&`/`||`/`;`
+/// splitting, and the heredoc/`$((` whole-line exceptions behave the same
+/// as `split_command_chain`.
+pub fn split_all_stages(cmd: &str) -> Vec<&str> {
+    split_chain_inner(cmd, false)
+}
+
+fn split_chain_inner(cmd: &str, stop_at_first_pipe: bool) -> Vec<&str> {
     let trimmed = cmd.trim();
     if trimmed.is_empty() {
         return vec![];
@@ -422,7 +799,7 @@ pub fn split_command_chain(cmd: &str) -> Vec<&str> {
     let mut i = 0;
     let mut in_single = false;
     let mut in_double = false;
-    let mut pipe_seen = false;
+    let mut stopped = false;
 
     while i < len {
         let b = bytes[i];
@@ -444,14 +821,22 @@ pub fn split_command_chain(cmd: &str) -> Vec<&str> {
                     }
                     i += 2;
                     start = i;
-                } else {
+                } else if stop_at_first_pipe {
                     // pipe: keep only first command
                     let segment = trimmed[start..i].trim();
                     if !segment.is_empty() {
                         results.push(segment);
                     }
-                    pipe_seen = true;
+                    stopped = true;
                     break;
+                } else {
+                    // pipe: keep scanning into the next stage
+                    let segment = trimmed[start..i].trim();
+                    if !segment.is_empty() {
+                        results.push(segment);
+                    }
+                    i += 1;
+                    start = i;
                 }
             }
             b'&' if !in_single && !in_double && i + 1 < len && bytes[i + 1] == b'&' => {
@@ -476,7 +861,7 @@ pub fn split_command_chain(cmd: &str) -> Vec<&str> {
         }
     }
 
-    if !pipe_seen && start < len {
+    if !stopped && start < len {
         let segment = trimmed[start..].trim();
         if !segment.is_empty() {
             results.push(segment);
@@ -486,6 +871,93 @@ pub fn split_command_chain(cmd: &str) -> Vec<&str> {
     results
 }
 
+/// Schema version for the NDJSON classification export
+/// (`classification_json` / `classify_commands_ndjson`). Bump when a field
+/// is removed or its meaning changes; additive fields don't need a bump
+/// since consumers should already tolerate unknown keys.
+pub const CLASSIFICATION_SCHEMA_VERSION: u32 = 1;
+
+/// Render one classified command as a machine-consumable JSON object, the
+/// same `schema_version`/`kind`-tagged shape `parser::json_format` uses for
+/// other structured exports.
+pub fn classification_json(
+    command: &str,
+    matched_segment: &str,
+    classification: &Classification,
+) -> serde_json::Value {
+    match classification {
+        Classification::Supported {
+            rtk_equivalent,
+            category,
+            estimated_savings_pct,
+            status,
+        } => {
+            let subcmd = super::extract_subcmd(matched_segment);
+            serde_json::json!({
+                "schema_version": CLASSIFICATION_SCHEMA_VERSION,
+                "kind": "supported",
+                "command": command,
+                "matched_segment": matched_segment,
+                "rtk_equivalent": rtk_equivalent,
+                "category": category,
+                "estimated_savings_pct": estimated_savings_pct,
+                "status": status.as_str(),
+                "category_avg_tokens": category_avg_tokens(category, subcmd),
+            })
+        }
+        Classification::Unsupported {
+            base_command,
+            suggestion,
+        } => serde_json::json!({
+            "schema_version": CLASSIFICATION_SCHEMA_VERSION,
+            "kind": "unsupported",
+            "command": command,
+            "matched_segment": matched_segment,
+            "base_command": base_command,
+            "suggestion": suggestion,
+        }),
+        Classification::Ignored => serde_json::json!({
+            "schema_version": CLASSIFICATION_SCHEMA_VERSION,
+            "kind": "ignored",
+            "command": command,
+            "matched_segment": matched_segment,
+        }),
+    }
+}
+
+/// Classify a batch of raw commands and render one JSON object per line
+/// (NDJSON) -- an opt-in, machine-readable sibling to the human text
+/// report, for feeding seline output into dashboards or diffing two runs
+/// programmatically. Each line covers the first stage only
+/// (`split_command_chain`), same as the text report's per-command
+/// classification; commands that split to no stages (blank input) are
+/// skipped.
+pub fn classify_commands_ndjson<'a, I>(commands: I) -> String
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    commands
+        .into_iter()
+        .filter_map(|command| {
+            let matched_segment = *split_command_chain(command).first()?;
+            let classification = classify_command(matched_segment);
+            Some(classification_json(command, matched_segment, &classification).to_string())
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Classify every stage of a pipeline/chain independently, so a caller can
+/// sum estimated savings across all covered stages instead of only the
+/// first (e.g. `git log | rg fix | head -20` yields three classifications,
+/// not one).
+pub fn classify_pipeline(cmd: &str) -> Vec<Classification> {
+    split_all_stages(cmd)
+        .into_iter()
+        .map(classify_command)
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::report::RtkStatus;
@@ -574,11 +1046,121 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_selineignore_parse_exact_prefix_regex() {
+        let exact = IgnoreRule::parse("exact:foo").unwrap().unwrap();
+        assert!(exact.matches("foo"));
+        assert!(!exact.matches("foobar"));
+        assert!(!exact.include);
+
+        let prefix = IgnoreRule::parse("prefix:se").unwrap().unwrap();
+        assert!(prefix.matches("sed -e s/a/b/"));
+        assert!(!prefix.include);
+
+        let regex = IgnoreRule::parse(r"re:^awk\s").unwrap().unwrap();
+        assert!(regex.matches("awk '{print}'"));
+
+        let include = IgnoreRule::parse("!exact:sed").unwrap().unwrap();
+        assert!(include.include);
+    }
+
+    #[test]
+    fn test_selineignore_parse_blank_and_comment_lines() {
+        assert!(IgnoreRule::parse("").unwrap().is_none());
+        assert!(IgnoreRule::parse("   ").unwrap().is_none());
+        assert!(IgnoreRule::parse("# a comment").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_selineignore_parse_rejects_unknown_prefix() {
+        assert!(IgnoreRule::parse("glob:*.rs").is_err());
+    }
+
+    #[test]
+    fn test_selineignore_parse_rejects_invalid_regex() {
+        assert!(IgnoreRule::parse("re:(unclosed").is_err());
+    }
+
+    #[test]
+    fn test_ignore_matcher_default_matches_builtin_behavior() {
+        let matcher = IgnoreMatcher::builtin();
+        assert!(matcher.is_ignored("cd /tmp"));
+        assert!(matcher.is_ignored("sed -e s/a/b/"));
+        assert!(!matcher.is_ignored("git status"));
+    }
+
+    #[test]
+    fn test_ignore_matcher_file_rule_extends_defaults() {
+        let mut rules = default_ignore_rules();
+        rules.push(IgnoreRule::parse("exact:mycli internal").unwrap().unwrap());
+        let matcher = IgnoreMatcher { rules };
+
+        assert!(matcher.is_ignored("mycli internal"));
+        // Unrelated defaults are untouched.
+        assert!(matcher.is_ignored("cd /tmp"));
+    }
+
+    #[test]
+    fn test_ignore_matcher_later_include_reenables_earlier_ignore() {
+        let mut rules = default_ignore_rules();
+        rules.push(IgnoreRule::parse("prefix:se").unwrap().unwrap());
+        rules.push(IgnoreRule::parse("!exact:sed").unwrap().unwrap());
+        let matcher = IgnoreMatcher { rules };
+
+        // The broad `prefix:se` still ignores other "se*" commands...
+        assert!(matcher.is_ignored("semgrep scan"));
+        // ...but the later `!exact:sed` re-enables `sed` specifically.
+        assert!(!matcher.is_ignored("sed"));
+    }
+
     #[test]
     fn test_classify_terraform_unsupported() {
         match classify_command("terraform plan -var-file=prod.tfvars") {
-            Classification::Unsupported { base_command } => {
+            Classification::Unsupported {
+                base_command,
+                suggestion,
+            } => {
                 assert_eq!(base_command, "terraform plan");
+                assert_eq!(suggestion, None); // not close to any known command
+            }
+            other => panic!("expected Unsupported, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("git", "git"), 0);
+        assert_eq!(edit_distance("gti", "git"), 2);
+        assert_eq!(edit_distance("kubctl", "kubectl"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_suggest_command_typo() {
+        assert_eq!(suggest_command("gti status"), Some("rtk git"));
+        assert_eq!(suggest_command("kubctl get"), Some("rtk kubectl"));
+    }
+
+    #[test]
+    fn test_suggest_command_no_close_match() {
+        assert_eq!(suggest_command("terraform plan"), None);
+    }
+
+    #[test]
+    fn test_suggest_command_rejects_short_token_noise() {
+        // Too far from every 2-3 char candidate relative to their length.
+        assert_eq!(suggest_command("xz foo"), None);
+    }
+
+    #[test]
+    fn test_classify_unsupported_gets_suggestion() {
+        match classify_command("gti status") {
+            Classification::Unsupported {
+                base_command,
+                suggestion,
+            } => {
+                assert_eq!(base_command, "gti status");
+                assert_eq!(suggestion, Some("rtk git"));
             }
             other => panic!("expected Unsupported, got {:?}", other),
         }
@@ -671,6 +1253,79 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_registry_build_merges_user_rule() {
+        let user_rule = UserRule {
+            pattern: r"^mycli\s+deploy".to_string(),
+            rtk_cmd: "rtk mycli".to_string(),
+            category: "Custom".to_string(),
+            savings_pct: 42.0,
+            subcmd_savings: Vec::new(),
+            subcmd_status: Vec::new(),
+        };
+        let registry = Registry::build(vec![(user_rule.pattern.clone(), user_rule.into_rule())])
+            .expect("valid pattern should build");
+
+        match registry.classify("mycli deploy --prod") {
+            Some(Classification::Supported {
+                rtk_equivalent,
+                category,
+                estimated_savings_pct,
+                ..
+            }) => {
+                assert_eq!(rtk_equivalent, "rtk mycli");
+                assert_eq!(category, "Custom");
+                assert_eq!(estimated_savings_pct, 42.0);
+            }
+            other => panic!("expected Supported, got {other:?}"),
+        }
+
+        // Built-ins are untouched by a user rule for an unrelated command.
+        match registry.classify("git status") {
+            Some(Classification::Supported { rtk_equivalent, .. }) => {
+                assert_eq!(rtk_equivalent, "rtk git");
+            }
+            other => panic!("expected Supported, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_registry_build_user_rule_overrides_builtin() {
+        // A user rule for the same leading token is appended after the
+        // built-ins, so it wins as the "last match" for commands it covers.
+        let user_rule = UserRule {
+            pattern: r"^cargo\s+bench".to_string(),
+            rtk_cmd: "rtk cargo-bench".to_string(),
+            category: "Custom".to_string(),
+            savings_pct: 90.0,
+            subcmd_savings: Vec::new(),
+            subcmd_status: Vec::new(),
+        };
+        let registry = Registry::build(vec![(user_rule.pattern.clone(), user_rule.into_rule())])
+            .expect("valid pattern should build");
+
+        match registry.classify("cargo bench") {
+            Some(Classification::Supported { rtk_equivalent, .. }) => {
+                assert_eq!(rtk_equivalent, "rtk cargo-bench");
+            }
+            other => panic!("expected Supported, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_registry_build_rejects_invalid_regex() {
+        let user_rule = UserRule {
+            pattern: "(unclosed".to_string(),
+            rtk_cmd: "rtk broken".to_string(),
+            category: "Custom".to_string(),
+            savings_pct: 50.0,
+            subcmd_savings: Vec::new(),
+            subcmd_status: Vec::new(),
+        };
+        let result = compile_user_rules(vec![user_rule]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_registry_covers_all_cargo_subcommands() {
         // Verify that every CargoCommand variant (Build, Test, Clippy, Check, Fmt)
@@ -732,4 +1387,119 @@ mod tests {
         let cmd = "cat <<'EOF'\nhello && world\nEOF";
         assert_eq!(split_command_chain(cmd), vec![cmd]);
     }
+
+    #[test]
+    fn test_split_all_stages_pipeline() {
+        assert_eq!(
+            split_all_stages("git log | rg fix | head -20"),
+            vec!["git log", "rg fix", "head -20"]
+        );
+    }
+
+    #[test]
+    fn test_split_all_stages_trailing_pipe_drops_empty_segment() {
+        assert_eq!(split_all_stages("git log |"), vec!["git log"]);
+    }
+
+    #[test]
+    fn test_split_all_stages_pipe_in_quotes_not_split() {
+        assert_eq!(
+            split_all_stages(r#"echo "a | b""#),
+            vec![r#"echo "a | b""#]
+        );
+    }
+
+    #[test]
+    fn test_split_all_stages_mixes_pipe_and_and() {
+        assert_eq!(
+            split_all_stages("git log | rg fix && cargo test"),
+            vec!["git log", "rg fix", "cargo test"]
+        );
+    }
+
+    #[test]
+    fn test_split_all_stages_heredoc_no_split() {
+        let cmd = "cat <<'EOF'\nhello | world\nEOF";
+        assert_eq!(split_all_stages(cmd), vec![cmd]);
+    }
+
+    #[test]
+    fn test_split_all_stages_single_command_matches_split_command_chain() {
+        assert_eq!(split_all_stages("git status"), vec!["git status"]);
+    }
+
+    #[test]
+    fn test_classify_pipeline_classifies_every_stage() {
+        let results = classify_pipeline("git log | rg fix | head -20");
+        assert_eq!(results.len(), 3);
+        for classification in &results {
+            match classification {
+                Classification::Supported { .. } => {}
+                other => panic!("expected every stage Supported, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_classify_pipeline_keeps_first_stage_only_classification_unchanged() {
+        // split_command_chain's single-stage behavior for a non-pipeline
+        // command is unaffected by the pipeline-aware entry point.
+        assert_eq!(
+            classify_pipeline("git status"),
+            vec![classify_command("git status")]
+        );
+    }
+
+    #[test]
+    fn test_classification_json_supported_shape() {
+        let classification = classify_command("git log");
+        let value = classification_json("git log -5", "git log", &classification);
+        assert_eq!(value["schema_version"], CLASSIFICATION_SCHEMA_VERSION);
+        assert_eq!(value["kind"], "supported");
+        assert_eq!(value["command"], "git log -5");
+        assert_eq!(value["matched_segment"], "git log");
+        assert_eq!(value["rtk_equivalent"], "rtk git");
+        assert_eq!(value["category_avg_tokens"], 200);
+    }
+
+    #[test]
+    fn test_classification_json_unsupported_shape() {
+        let classification = classify_command("terraform plan");
+        let value = classification_json("terraform plan", "terraform plan", &classification);
+        assert_eq!(value["kind"], "unsupported");
+        assert_eq!(value["base_command"], "terraform");
+    }
+
+    #[test]
+    fn test_classification_json_ignored_shape() {
+        let classification = classify_command("cd /tmp");
+        let value = classification_json("cd /tmp", "cd /tmp", &classification);
+        assert_eq!(value["kind"], "ignored");
+    }
+
+    #[test]
+    fn test_classify_commands_ndjson_one_line_per_command() {
+        let ndjson = classify_commands_ndjson(["git log", "terraform plan", "cd /tmp"]);
+        let lines: Vec<&str> = ndjson.lines().collect();
+        assert_eq!(lines.len(), 3);
+        for line in &lines {
+            let parsed: serde_json::Value =
+                serde_json::from_str(line).expect("valid JSON line");
+            assert_eq!(parsed["schema_version"], CLASSIFICATION_SCHEMA_VERSION);
+        }
+    }
+
+    #[test]
+    fn test_classify_commands_ndjson_skips_blank_commands() {
+        let ndjson = classify_commands_ndjson(["git log", "   ", "cd /tmp"]);
+        assert_eq!(ndjson.lines().count(), 2);
+    }
+
+    #[test]
+    fn test_classify_commands_ndjson_pipe_keeps_first_stage_only() {
+        let ndjson = classify_commands_ndjson(["git log | rg fix"]);
+        let parsed: serde_json::Value =
+            serde_json::from_str(ndjson.lines().next().unwrap()).unwrap();
+        assert_eq!(parsed["matched_segment"], "git log");
+    }
 }
@@ -1,3 +1,5 @@
+use crate::style::Style;
+use crate::utils::format_usd;
 use serde::Serialize;
 
 /// RTK support status for a command.
@@ -31,6 +33,9 @@ pub struct SupportedEntry {
     pub estimated_savings_tokens: usize,
     pub estimated_savings_pct: f64,
     pub rtk_status: RtkStatus,
+    /// Dollar value of `estimated_savings_tokens` at the report's
+    /// configured output-token rate (see [`crate::pricing`]).
+    pub estimated_savings_usd: f64,
 }
 
 /// An unsupported command not yet handled by RTK.
@@ -39,6 +44,9 @@ pub struct UnsupportedEntry {
     pub base_command: String,
     pub count: usize,
     pub example: String,
+    /// The closest RTK-covered command, when the base command looks like a
+    /// likely typo of one (e.g. `gti` -> `rtk git`).
+    pub suggestion: Option<&'static str>,
 }
 
 /// Full discover report.
@@ -51,6 +59,8 @@ pub struct DiscoverReport {
     pub supported: Vec<SupportedEntry>,
     pub unsupported: Vec<UnsupportedEntry>,
     pub parse_errors: usize,
+    /// Model whose rates priced `estimated_savings_usd` (see [`crate::pricing`]).
+    pub pricing_model: &'static str,
 }
 
 impl DiscoverReport {
@@ -61,17 +71,22 @@ impl DiscoverReport {
             .sum()
     }
 
+    pub fn total_saveable_usd(&self) -> f64 {
+        self.supported.iter().map(|s| s.estimated_savings_usd).sum()
+    }
+
     pub fn total_supported_count(&self) -> usize {
         self.supported.iter().map(|s| s.count).sum()
     }
 }
 
-/// Format report as text.
-pub fn format_text(report: &DiscoverReport, limit: usize, verbose: bool) -> String {
+/// Format report as text. Colors are applied to already-padded cells (never
+/// inside a width specifier) so `style` never throws off column alignment.
+pub fn format_text(report: &DiscoverReport, limit: usize, verbose: bool, style: Style) -> String {
     let mut out = String::with_capacity(2048);
 
-    out.push_str("RTK Discover -- Savings Opportunities\n");
-    out.push_str(&"=".repeat(52));
+    out.push_str(&style.bold("RTK Discover -- Savings Opportunities\n"));
+    out.push_str(&style.dim(&"=".repeat(52)));
     out.push('\n');
     out.push_str(&format!(
         "Scanned: {} sessions (last {} days), {} Bash commands\n",
@@ -94,38 +109,50 @@ pub fn format_text(report: &DiscoverReport, limit: usize, verbose: bool) -> Stri
 
     // Missed savings
     if !report.supported.is_empty() {
-        out.push_str("\nMISSED SAVINGS -- Commands RTK already handles\n");
-        out.push_str(&"-".repeat(72));
+        out.push_str(&style.bold("\nMISSED SAVINGS -- Commands RTK already handles\n"));
+        out.push_str(&style.dim(&"-".repeat(72)));
         out.push('\n');
         out.push_str(&format!(
-            "{:<24} {:>5}    {:<18} {:<13} {:>12}\n",
-            "Command", "Count", "RTK Equivalent", "Status", "Est. Savings"
+            "{:<24} {:>5}    {:<18} {:<13} {:>12} {:>9}\n",
+            "Command", "Count", "RTK Equivalent", "Status", "Est. Savings", "Est. $"
         ));
 
         for entry in report.supported.iter().take(limit) {
+            let savings_cell = format!(
+                "~{} {:>9}",
+                format_tokens(entry.estimated_savings_tokens),
+                format_usd(entry.estimated_savings_usd),
+            );
+            let savings_cell = if entry.estimated_savings_pct >= crate::style::GOOD_SAVINGS_PCT {
+                style.green(&savings_cell)
+            } else {
+                savings_cell
+            };
             out.push_str(&format!(
-                "{:<24} {:>5}    {:<18} {:<13} ~{}\n",
+                "{:<24} {:>5}    {:<18} {:<13} {}\n",
                 truncate_str(&entry.command, 23),
                 entry.count,
                 entry.rtk_equivalent,
                 entry.rtk_status.as_str(),
-                format_tokens(entry.estimated_savings_tokens),
+                savings_cell,
             ));
         }
 
-        out.push_str(&"-".repeat(72));
+        out.push_str(&style.dim(&"-".repeat(72)));
         out.push('\n');
         out.push_str(&format!(
-            "Total: {} commands -> ~{} saveable\n",
+            "Total: {} commands -> ~{} saveable (~{}, {} pricing)\n",
             report.total_supported_count(),
             format_tokens(report.total_saveable_tokens()),
+            format_usd(report.total_saveable_usd()),
+            report.pricing_model,
         ));
     }
 
     // Unhandled
     if !report.unsupported.is_empty() {
-        out.push_str("\nTOP UNHANDLED COMMANDS -- open an issue?\n");
-        out.push_str(&"-".repeat(52));
+        out.push_str(&style.bold("\nTOP UNHANDLED COMMANDS -- open an issue?\n"));
+        out.push_str(&style.dim(&"-".repeat(52)));
         out.push('\n');
         out.push_str(&format!(
             "{:<24} {:>5}    {}\n",
@@ -134,14 +161,20 @@ pub fn format_text(report: &DiscoverReport, limit: usize, verbose: bool) -> Stri
 
         for entry in report.unsupported.iter().take(limit) {
             out.push_str(&format!(
-                "{:<24} {:>5}    {}\n",
-                truncate_str(&entry.base_command, 23),
+                "{} {:>5}    {}\n",
+                style.yellow(&format!("{:<24}", truncate_str(&entry.base_command, 23))),
                 entry.count,
                 truncate_str(&entry.example, 40),
             ));
+            if let Some(suggestion) = entry.suggestion {
+                out.push_str(&format!(
+                    "{:<24}          unsupported (did you mean `{}`?)\n",
+                    "", suggestion
+                ));
+            }
         }
 
-        out.push_str(&"-".repeat(52));
+        out.push_str(&style.dim(&"-".repeat(52)));
         out.push('\n');
         out.push_str("-> github.com/rtk-ai/rtk/issues\n");
     }
@@ -3,15 +3,90 @@
 //! Eliminates duplication in gain.rs and cc_economics.rs by providing
 //! a unified trait-based system for displaying daily/weekly/monthly data.
 
+use crate::locale::Locale;
 use crate::tracking::{DayStats, MonthStats, WeekStats};
-use crate::utils::format_tokens;
+use crate::utils::{format_tokens, format_tokens_locale};
+use anyhow::Result;
+use chrono::{Datelike, NaiveDate};
+use serde::Serialize;
+use std::io::Write;
+
+/// One finding inside an [`OutputEnvelope`], normalized across tools whose
+/// native diagnostics use different field names (rustc's `spans`, ruff's
+/// `filename`/`code`, eslint's `ruleId`, ...).
+#[derive(Debug, Clone, Serialize)]
+pub struct Diagnostic {
+    pub file: String,
+    pub line: usize,
+    pub severity: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule: Option<String>,
+    pub message: String,
+}
+
+/// Machine-readable envelope for `--json`: the same numbers `*_cmd`
+/// modules already feed to [`crate::tracking::TimedExecution`], plus the
+/// filtered report and (where the tool's own output is already
+/// structured, e.g. cargo's `--message-format=json`) a normalized
+/// `diagnostics` list, so agents get a stable contract instead of
+/// scraping compacted text per tool.
+#[derive(Debug, Clone, Serialize)]
+pub struct OutputEnvelope {
+    pub command: String,
+    pub args: Vec<String>,
+    pub exit_code: i32,
+    pub original_bytes: usize,
+    pub filtered_bytes: usize,
+    pub tokens_saved: i64,
+    pub diagnostics: Vec<Diagnostic>,
+    pub summary: String,
+}
+
+impl OutputEnvelope {
+    pub fn new(
+        command: String,
+        args: Vec<String>,
+        exit_code: i32,
+        original: &str,
+        summary: String,
+        diagnostics: Vec<Diagnostic>,
+    ) -> Self {
+        let original_bytes = original.len();
+        let filtered_bytes = summary.len();
+        let tokenizer = crate::tokenizer::configured();
+        let tokens_saved = tokenizer.count_tokens(original) as i64 - tokenizer.count_tokens(&summary) as i64;
+        Self {
+            command,
+            args,
+            exit_code,
+            original_bytes,
+            filtered_bytes,
+            tokens_saved,
+            diagnostics,
+            summary,
+        }
+    }
+}
+
+/// Print `envelope` as pretty-printed JSON, the same shape regardless of
+/// which `*_cmd` module produced it.
+pub fn print_envelope(envelope: &OutputEnvelope) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(envelope)?);
+    Ok(())
+}
 
 /// Format duration in milliseconds to human-readable string
 pub fn format_duration(ms: u64) -> String {
+    format_duration_locale(ms, Locale::default())
+}
+
+/// Same as [`format_duration`], but rendered with `locale`'s decimal
+/// separator (e.g. "1,5s" in French instead of "1.5s").
+pub fn format_duration_locale(ms: u64, locale: Locale) -> String {
     if ms < 1000 {
         format!("{}ms", ms)
     } else if ms < 60_000 {
-        format!("{:.1}s", ms as f64 / 1000.0)
+        format!("{:.1}s", ms as f64 / 1000.0).replace('.', &locale.decimal_separator().to_string())
     } else {
         let minutes = ms / 60_000;
         let seconds = (ms % 60_000) / 1000;
@@ -19,6 +94,36 @@ pub fn format_duration(ms: u64) -> String {
     }
 }
 
+/// Parse a `"YYYY-MM-DD"` string and render it as `"{month_abbrev} {day}"`
+/// in `locale`, falling back to the raw `MM-DD` substring if it doesn't
+/// parse (defensive — callers always pass SQLite `DATE()` output).
+fn format_month_day(date: &str, locale: Locale) -> String {
+    match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+        Ok(d) => format!("{} {:02}", locale.month_abbrev(d.month()), d.day()),
+        Err(_) => date.get(5..).unwrap_or(date).to_string(),
+    }
+}
+
+/// Parse a `"YYYY-MM"` string and render it as `"{month_abbrev} {year}"` in
+/// `locale`, falling back to the raw string if it doesn't parse.
+fn format_month_year(month: &str, locale: Locale) -> String {
+    match NaiveDate::parse_from_str(&format!("{month}-01"), "%Y-%m-%d") {
+        Ok(d) => format!("{} {}", locale.month_abbrev(d.month()), d.year()),
+        Err(_) => month.to_string(),
+    }
+}
+
+/// Parse a `"YYYY-MM-DD"` or `"YYYY-MM"` string into a [`NaiveDate`]
+/// (the latter anchored to its first day), defaulting to the Unix epoch
+/// if it doesn't parse — used by `start_date()` impls below, which only
+/// feed [`crate::stats_filter`]'s window checks rather than anything
+/// user-visible.
+fn parse_date_lenient(s: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(&format!("{s}-01"), "%Y-%m-%d"))
+        .unwrap_or_else(|_| NaiveDate::from_ymd_opt(1970, 1, 1).expect("epoch is a valid date"))
+}
+
 /// Trait for period-based statistics that can be displayed in tables
 pub trait PeriodStats {
     /// Icon for this period type (e.g., "📅", "📊", "📆")
@@ -27,8 +132,14 @@ pub trait PeriodStats {
     /// Label for this period type (e.g., "Daily", "Weekly", "Monthly")
     fn label() -> &'static str;
 
-    /// Period identifier (e.g., "2026-01-20", "01-20 → 01-26", "2026-01")
-    fn period(&self) -> String;
+    /// Period identifier (e.g., "2026-01-20", "Jan 20 → Jan 26", "Jan 2026"),
+    /// rendered in `locale` (month/weekday names, where applicable).
+    fn period(&self, locale: Locale) -> String;
+
+    /// First date covered by this period, used by [`crate::stats_filter`]
+    /// to test window membership without parsing `period()`'s rendered
+    /// (and possibly locale-translated) string back into a date.
+    fn start_date(&self) -> NaiveDate;
 
     /// Number of commands in this period
     fn commands(&self) -> usize;
@@ -58,37 +169,100 @@ pub trait PeriodStats {
     fn separator_width() -> usize;
 }
 
+/// Delegating impl so a `Vec<&T>` (e.g. [`crate::stats_filter`]'s filtered
+/// subset) can be printed with the same table renderer as `Vec<T>`,
+/// without requiring `T: Clone`.
+impl<T: PeriodStats> PeriodStats for &T {
+    fn icon() -> &'static str {
+        T::icon()
+    }
+
+    fn label() -> &'static str {
+        T::label()
+    }
+
+    fn period(&self, locale: Locale) -> String {
+        (**self).period(locale)
+    }
+
+    fn start_date(&self) -> NaiveDate {
+        (**self).start_date()
+    }
+
+    fn commands(&self) -> usize {
+        (**self).commands()
+    }
+
+    fn input_tokens(&self) -> usize {
+        (**self).input_tokens()
+    }
+
+    fn output_tokens(&self) -> usize {
+        (**self).output_tokens()
+    }
+
+    fn saved_tokens(&self) -> usize {
+        (**self).saved_tokens()
+    }
+
+    fn savings_pct(&self) -> f64 {
+        (**self).savings_pct()
+    }
+
+    fn total_time_ms(&self) -> u64 {
+        (**self).total_time_ms()
+    }
+
+    fn avg_time_ms(&self) -> u64 {
+        (**self).avg_time_ms()
+    }
+
+    fn period_width() -> usize {
+        T::period_width()
+    }
+
+    fn separator_width() -> usize {
+        T::separator_width()
+    }
+}
+
 /// Generic table printer for any period statistics
 pub fn print_period_table<T: PeriodStats>(data: &[T]) {
+    print_period_table_locale(data, Locale::default());
+}
+
+/// Same as [`print_period_table`], but with headers, period labels, and
+/// number formatting rendered in `locale`.
+pub fn print_period_table_locale<T: PeriodStats>(data: &[T], locale: Locale) {
     if data.is_empty() {
-        println!("No {} data available.", T::label().to_lowercase());
+        println!(
+            "No {} data available.",
+            locale.period_label(T::label()).to_lowercase()
+        );
         return;
     }
 
     let period_width = T::period_width();
     let separator = "═".repeat(T::separator_width());
+    let headers = locale.headers();
 
     println!(
         "\n{} {} Breakdown ({} {}s)",
         T::icon(),
-        T::label(),
+        locale.period_label(T::label()),
         data.len(),
-        T::label().to_lowercase()
+        locale.period_label(T::label()).to_lowercase()
     );
     println!("{}", separator);
     println!(
         "{:<width$} {:>7} {:>10} {:>10} {:>10} {:>7} {:>8}",
-        match T::label() {
-            "Weekly" => "Week",
-            "Monthly" => "Month",
-            _ => "Date",
-        },
-        "Cmds",
-        "Input",
-        "Output",
-        "Saved",
-        "Save%",
-        "Time",
+        locale.column_header(T::label()),
+        headers.commands,
+        headers.input,
+        headers.output,
+        headers.saved,
+        headers.save_pct,
+        headers.time,
         width = period_width
     );
     println!("{}", "─".repeat(T::separator_width()));
@@ -96,13 +270,13 @@ pub fn print_period_table<T: PeriodStats>(data: &[T]) {
     for period in data {
         println!(
             "{:<width$} {:>7} {:>10} {:>10} {:>10} {:>6.1}% {:>8}",
-            period.period(),
+            period.period(locale),
             period.commands(),
-            format_tokens(period.input_tokens()),
-            format_tokens(period.output_tokens()),
-            format_tokens(period.saved_tokens()),
+            format_tokens_locale(period.input_tokens(), locale),
+            format_tokens_locale(period.output_tokens(), locale),
+            format_tokens_locale(period.saved_tokens(), locale),
             period.savings_pct(),
-            format_duration(period.avg_time_ms()),
+            format_duration_locale(period.avg_time_ms(), locale),
             width = period_width
         );
     }
@@ -127,18 +301,179 @@ pub fn print_period_table<T: PeriodStats>(data: &[T]) {
     println!("{}", "─".repeat(T::separator_width()));
     println!(
         "{:<width$} {:>7} {:>10} {:>10} {:>10} {:>6.1}% {:>8}",
-        "TOTAL",
+        headers.total,
         total_cmds,
-        format_tokens(total_input),
-        format_tokens(total_output),
-        format_tokens(total_saved),
+        format_tokens_locale(total_input, locale),
+        format_tokens_locale(total_output, locale),
+        format_tokens_locale(total_saved, locale),
         avg_pct,
-        format_duration(avg_time),
+        format_duration_locale(avg_time, locale),
         width = period_width
     );
     println!();
 }
 
+/// Serialize the same columns `print_period_table` renders (period,
+/// commands, input/output/saved tokens, savings_pct, total/avg time) plus
+/// the computed TOTAL row, as `"csv"` or `"parquet"`.
+pub fn export_period_table<T: PeriodStats, W: Write + Send>(
+    data: &[T],
+    format: &str,
+    writer: &mut W,
+) -> Result<()> {
+    match format {
+        "csv" => export_period_csv(data, writer),
+        "parquet" => export_period_parquet(data, writer),
+        other => anyhow::bail!("unknown export format: {other} (expected csv or parquet)"),
+    }
+}
+
+fn export_period_csv<T: PeriodStats, W: Write>(data: &[T], writer: &mut W) -> Result<()> {
+    writeln!(
+        writer,
+        "period,commands,input_tokens,output_tokens,saved_tokens,savings_pct,total_time_ms,avg_time_ms"
+    )?;
+    for row in data {
+        writeln!(
+            writer,
+            "{},{},{},{},{},{:.2},{},{}",
+            // Machine-readable exports always use Locale::default() (not
+            // the caller's display locale) so downstream analytics tooling
+            // gets a stable period format regardless of UI language.
+            csv_escape(&row.period(Locale::default())),
+            row.commands(),
+            row.input_tokens(),
+            row.output_tokens(),
+            row.saved_tokens(),
+            row.savings_pct(),
+            row.total_time_ms(),
+            row.avg_time_ms()
+        )?;
+    }
+
+    let (total_cmds, total_input, total_output, total_saved, total_time, avg_pct, avg_time) =
+        period_totals(data);
+    writeln!(
+        writer,
+        "TOTAL,{},{},{},{},{:.2},{},{}",
+        total_cmds, total_input, total_output, total_saved, avg_pct, total_time, avg_time
+    )?;
+    Ok(())
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains(',') || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn period_totals<T: PeriodStats>(data: &[T]) -> (usize, usize, usize, usize, u64, f64, u64) {
+    let total_cmds: usize = data.iter().map(|d| d.commands()).sum();
+    let total_input: usize = data.iter().map(|d| d.input_tokens()).sum();
+    let total_output: usize = data.iter().map(|d| d.output_tokens()).sum();
+    let total_saved: usize = data.iter().map(|d| d.saved_tokens()).sum();
+    let total_time: u64 = data.iter().map(|d| d.total_time_ms()).sum();
+    let avg_pct = if total_input > 0 {
+        (total_saved as f64 / total_input as f64) * 100.0
+    } else {
+        0.0
+    };
+    let avg_time = if total_cmds > 0 {
+        total_time / total_cmds as u64
+    } else {
+        0
+    };
+    (
+        total_cmds,
+        total_input,
+        total_output,
+        total_saved,
+        total_time,
+        avg_pct,
+        avg_time,
+    )
+}
+
+fn export_period_parquet<T: PeriodStats, W: Write + Send>(data: &[T], writer: &mut W) -> Result<()> {
+    #[cfg(feature = "parquet-export")]
+    {
+        export_period_parquet_impl(data, writer)
+    }
+    #[cfg(not(feature = "parquet-export"))]
+    {
+        let _ = (data, writer);
+        anyhow::bail!("parquet export requires rtk built with the `parquet-export` feature");
+    }
+}
+
+/// Build a `RecordBatch` with a stable schema (UInt64 for counts/tokens/
+/// times, Float64 for `savings_pct`, Utf8 for `period`) and write it to
+/// `writer` as Parquet via `arrow`/`parquet`'s `ArrowWriter`.
+#[cfg(feature = "parquet-export")]
+fn export_period_parquet_impl<T: PeriodStats, W: Write + Send>(
+    data: &[T],
+    writer: &mut W,
+) -> Result<()> {
+    use arrow::array::{Float64Array, StringArray, UInt64Array};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::record_batch::RecordBatch;
+    use parquet::arrow::ArrowWriter;
+    use std::sync::Arc;
+
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("period", DataType::Utf8, false),
+        Field::new("commands", DataType::UInt64, false),
+        Field::new("input_tokens", DataType::UInt64, false),
+        Field::new("output_tokens", DataType::UInt64, false),
+        Field::new("saved_tokens", DataType::UInt64, false),
+        Field::new("savings_pct", DataType::Float64, false),
+        Field::new("total_time_ms", DataType::UInt64, false),
+        Field::new("avg_time_ms", DataType::UInt64, false),
+    ]));
+
+    let mut periods: Vec<String> = data.iter().map(|d| d.period(Locale::default())).collect();
+    let mut commands: Vec<u64> = data.iter().map(|d| d.commands() as u64).collect();
+    let mut input_tokens: Vec<u64> = data.iter().map(|d| d.input_tokens() as u64).collect();
+    let mut output_tokens: Vec<u64> = data.iter().map(|d| d.output_tokens() as u64).collect();
+    let mut saved_tokens: Vec<u64> = data.iter().map(|d| d.saved_tokens() as u64).collect();
+    let mut savings_pct: Vec<f64> = data.iter().map(|d| d.savings_pct()).collect();
+    let mut total_time_ms: Vec<u64> = data.iter().map(|d| d.total_time_ms()).collect();
+    let mut avg_time_ms: Vec<u64> = data.iter().map(|d| d.avg_time_ms()).collect();
+
+    let (total_cmds, total_input, total_output, total_saved, total_time, avg_pct, avg_time) =
+        period_totals(data);
+    periods.push("TOTAL".to_string());
+    commands.push(total_cmds as u64);
+    input_tokens.push(total_input as u64);
+    output_tokens.push(total_output as u64);
+    saved_tokens.push(total_saved as u64);
+    savings_pct.push(avg_pct);
+    total_time_ms.push(total_time);
+    avg_time_ms.push(avg_time);
+
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(StringArray::from(periods)),
+            Arc::new(UInt64Array::from(commands)),
+            Arc::new(UInt64Array::from(input_tokens)),
+            Arc::new(UInt64Array::from(output_tokens)),
+            Arc::new(UInt64Array::from(saved_tokens)),
+            Arc::new(Float64Array::from(savings_pct)),
+            Arc::new(UInt64Array::from(total_time_ms)),
+            Arc::new(UInt64Array::from(avg_time_ms)),
+        ],
+    )?;
+
+    let mut arrow_writer = ArrowWriter::try_new(writer, schema, None)?;
+    arrow_writer.write(&batch)?;
+    arrow_writer.close()?;
+    Ok(())
+}
+
 // ── Trait Implementations ──
 
 impl PeriodStats for DayStats {
@@ -150,10 +485,14 @@ impl PeriodStats for DayStats {
         "Daily"
     }
 
-    fn period(&self) -> String {
+    fn period(&self, _locale: Locale) -> String {
         self.date.clone()
     }
 
+    fn start_date(&self) -> NaiveDate {
+        parse_date_lenient(&self.date)
+    }
+
     fn commands(&self) -> usize {
         self.commands
     }
@@ -200,18 +539,16 @@ impl PeriodStats for WeekStats {
         "Weekly"
     }
 
-    fn period(&self) -> String {
-        let start = if self.week_start.len() > 5 {
-            &self.week_start[5..]
-        } else {
-            &self.week_start
-        };
-        let end = if self.week_end.len() > 5 {
-            &self.week_end[5..]
-        } else {
-            &self.week_end
-        };
-        format!("{} → {}", start, end)
+    fn period(&self, locale: Locale) -> String {
+        format!(
+            "{} → {}",
+            format_month_day(&self.week_start, locale),
+            format_month_day(&self.week_end, locale)
+        )
+    }
+
+    fn start_date(&self) -> NaiveDate {
+        parse_date_lenient(&self.week_start)
     }
 
     fn commands(&self) -> usize {
@@ -260,8 +597,12 @@ impl PeriodStats for MonthStats {
         "Monthly"
     }
 
-    fn period(&self) -> String {
-        self.month.clone()
+    fn period(&self, locale: Locale) -> String {
+        format_month_year(&self.month, locale)
+    }
+
+    fn start_date(&self) -> NaiveDate {
+        parse_date_lenient(&self.month)
     }
 
     fn commands(&self) -> usize {
@@ -316,9 +657,12 @@ mod tests {
             savings_pct: 20.0,
             total_time_ms: 1500,
             avg_time_ms: 150,
+            p50_time_ms: 150,
+            p95_time_ms: 200,
+            p99_time_ms: 220,
         };
 
-        assert_eq!(day.period(), "2026-01-20");
+        assert_eq!(day.period(Locale::default()), "2026-01-20");
         assert_eq!(day.commands(), 10);
         assert_eq!(day.saved_tokens(), 200);
         assert_eq!(day.avg_time_ms(), 150);
@@ -338,9 +682,13 @@ mod tests {
             savings_pct: 40.0,
             total_time_ms: 5000,
             avg_time_ms: 100,
+            p50_time_ms: 100,
+            p95_time_ms: 140,
+            p99_time_ms: 160,
         };
 
-        assert_eq!(week.period(), "01-20 → 01-26");
+        assert_eq!(week.period(Locale::default()), "Jan 20 → Jan 26");
+        assert_eq!(week.period(Locale::FrFr), "jan 20 → jan 26");
         assert_eq!(week.avg_time_ms(), 100);
         assert_eq!(WeekStats::icon(), "📊");
         assert_eq!(WeekStats::label(), "Weekly");
@@ -357,14 +705,40 @@ mod tests {
             savings_pct: 50.0,
             total_time_ms: 20000,
             avg_time_ms: 100,
+            p50_time_ms: 100,
+            p95_time_ms: 140,
+            p99_time_ms: 160,
         };
 
-        assert_eq!(month.period(), "2026-01");
+        assert_eq!(month.period(Locale::default()), "Jan 2026");
+        assert_eq!(month.period(Locale::DeDe), "Jan 2026");
         assert_eq!(month.avg_time_ms(), 100);
         assert_eq!(MonthStats::icon(), "📆");
         assert_eq!(MonthStats::label(), "Monthly");
     }
 
+    #[test]
+    fn test_print_period_table_locale_renders_translated_headers() {
+        let data = vec![DayStats {
+            date: "2026-01-20".to_string(),
+            commands: 10,
+            input_tokens: 1000,
+            output_tokens: 500,
+            saved_tokens: 200,
+            savings_pct: 20.0,
+            total_time_ms: 1500,
+            avg_time_ms: 150,
+            p50_time_ms: 150,
+            p95_time_ms: 200,
+            p99_time_ms: 220,
+        }];
+        // Smoke-test that locale-specific rendering doesn't panic for any
+        // supported locale.
+        for locale in [Locale::EnUs, Locale::FrFr, Locale::DeDe, Locale::EsEs] {
+            print_period_table_locale(&data, locale);
+        }
+    }
+
     #[test]
     fn test_print_period_table_empty() {
         let data: Vec<DayStats> = vec![];
@@ -384,6 +758,9 @@ mod tests {
                 savings_pct: 20.0,
                 total_time_ms: 1500,
                 avg_time_ms: 150,
+                p50_time_ms: 150,
+                p95_time_ms: 200,
+                p99_time_ms: 220,
             },
             DayStats {
                 date: "2026-01-21".to_string(),
@@ -394,9 +771,101 @@ mod tests {
                 savings_pct: 30.0,
                 total_time_ms: 2250,
                 avg_time_ms: 150,
+                p50_time_ms: 150,
+                p95_time_ms: 200,
+                p99_time_ms: 220,
             },
         ];
         print_period_table(&data);
         // Should print table with 2 rows + total
     }
+
+    #[test]
+    fn test_export_period_csv() {
+        let data = vec![
+            DayStats {
+                date: "2026-01-20".to_string(),
+                commands: 10,
+                input_tokens: 1000,
+                output_tokens: 500,
+                saved_tokens: 200,
+                savings_pct: 20.0,
+                total_time_ms: 1500,
+                avg_time_ms: 150,
+                p50_time_ms: 150,
+                p95_time_ms: 200,
+                p99_time_ms: 220,
+            },
+            DayStats {
+                date: "2026-01-21".to_string(),
+                commands: 15,
+                input_tokens: 1500,
+                output_tokens: 750,
+                saved_tokens: 300,
+                savings_pct: 30.0,
+                total_time_ms: 2250,
+                avg_time_ms: 150,
+                p50_time_ms: 150,
+                p95_time_ms: 200,
+                p99_time_ms: 220,
+            },
+        ];
+
+        let mut buf: Vec<u8> = Vec::new();
+        export_period_table(&data, "csv", &mut buf).unwrap();
+        let out = String::from_utf8(buf).unwrap();
+
+        let mut lines = out.lines();
+        assert_eq!(
+            lines.next().unwrap(),
+            "period,commands,input_tokens,output_tokens,saved_tokens,savings_pct,total_time_ms,avg_time_ms"
+        );
+        assert_eq!(lines.next().unwrap(), "2026-01-20,10,1000,500,200,20.00,1500,150");
+        assert_eq!(lines.next().unwrap(), "2026-01-21,15,1500,750,300,30.00,2250,150");
+        // TOTAL row: 25 commands, 2500 input, 500 saved -> 20.00%, 3750ms / 25 = 150ms avg
+        assert_eq!(lines.next().unwrap(), "TOTAL,25,2500,1250,500,20.00,3750,150");
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn test_export_period_table_unknown_format() {
+        let data: Vec<DayStats> = vec![];
+        let mut buf: Vec<u8> = Vec::new();
+        let result = export_period_table(&data, "xml", &mut buf);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_output_envelope_serializes_diagnostics_and_omits_missing_rule() {
+        let envelope = OutputEnvelope::new(
+            "cargo build".to_string(),
+            vec!["--release".to_string()],
+            1,
+            "warning: unused variable: `x`\n --> src/main.rs:3:9\n",
+            "1 warning".to_string(),
+            vec![Diagnostic {
+                file: "src/main.rs".to_string(),
+                line: 3,
+                severity: "warning".to_string(),
+                rule: None,
+                message: "unused variable: `x`".to_string(),
+            }],
+        );
+
+        let json = serde_json::to_string(&envelope).unwrap();
+        assert!(json.contains("\"exit_code\":1"));
+        assert!(json.contains("\"file\":\"src/main.rs\""));
+        assert!(!json.contains("\"rule\""));
+    }
+
+    #[test]
+    fn test_export_period_parquet_without_feature() {
+        let data: Vec<DayStats> = vec![];
+        let mut buf: Vec<u8> = Vec::new();
+        let result = export_period_table(&data, "parquet", &mut buf);
+        #[cfg(not(feature = "parquet-export"))]
+        assert!(result.is_err());
+        #[cfg(feature = "parquet-export")]
+        assert!(result.is_ok());
+    }
 }
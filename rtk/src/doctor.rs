@@ -0,0 +1,182 @@
+use crate::deps;
+use crate::tracking;
+use crate::utils::{CommandRunner, RunOutcome};
+use anyhow::Result;
+use serde_json::json;
+use std::path::Path;
+use std::time::Duration;
+
+/// External binaries `rtk`'s specialized filters (`cargo_cmd`, `pnpm_cmd`,
+/// `prisma_cmd`, …) shell out to, paired with the flag that prints a
+/// version string without side effects.
+const PROBES: &[(&str, &[&str])] = &[
+    ("cargo", &["--version"]),
+    ("npm", &["--version"]),
+    ("pnpm", &["--version"]),
+    ("prisma", &["--version"]),
+    ("docker", &["--version"]),
+    ("kubectl", &["version", "--client", "--short"]),
+    ("go", &["version"]),
+    ("ruff", &["--version"]),
+    ("pytest", &["--version"]),
+    ("pip", &["--version"]),
+    ("playwright", &["--version"]),
+];
+
+/// Per-probe budget: generous enough for a cold `prisma --version` (which
+/// can touch the network for an update check) but short enough that one
+/// missing/hanging binary doesn't stall the whole report.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Availability and version of one probed tool.
+struct ToolProbe {
+    name: &'static str,
+    version: Option<String>,
+}
+
+/// Run `<name> <probe_args>`, capped at [`PROBE_TIMEOUT`]. Absence (not in
+/// PATH), a non-zero exit, or a timeout are all reported as "not found"
+/// rather than propagated as an error — that's exactly what `doctor` exists
+/// to surface.
+fn probe_tool(name: &'static str, probe_args: &[&str]) -> ToolProbe {
+    let outcome = CommandRunner::new(name)
+        .args(probe_args.iter().map(|s| s.to_string()))
+        .timeout(PROBE_TIMEOUT)
+        .run();
+
+    let version = match outcome {
+        Ok(RunOutcome::Completed {
+            stdout,
+            stderr,
+            exit_code,
+        }) if exit_code == 0 => {
+            let text = if !stdout.trim().is_empty() { stdout } else { stderr };
+            text.lines()
+                .next()
+                .map(|line| line.trim().to_string())
+                .filter(|s| !s.is_empty())
+        }
+        _ => None,
+    };
+
+    ToolProbe { name, version }
+}
+
+/// Key dependency versions read straight off `Cargo.lock`/`package.json`'s
+/// lockfile-adjacent fields and `go.mod`'s `require` block — just enough to
+/// confirm which stack versions are in play, not the full audit
+/// [`crate::deps::run`] does.
+fn key_dependencies(dir: &Path) -> Vec<(String, String)> {
+    let mut deps = Vec::new();
+
+    if let Ok(content) = std::fs::read_to_string(dir.join("Cargo.lock")) {
+        if let Ok(value) = content.parse::<toml::Value>() {
+            if let Some(packages) = value.get("package").and_then(|v| v.as_array()) {
+                for pkg in packages {
+                    let (Some(name), Some(version)) = (
+                        pkg.get("name").and_then(|v| v.as_str()),
+                        pkg.get("version").and_then(|v| v.as_str()),
+                    ) else {
+                        continue;
+                    };
+                    if matches!(name, "clap" | "serde" | "anyhow" | "tokio" | "git2") {
+                        deps.push((name.to_string(), version.to_string()));
+                    }
+                }
+            }
+        }
+    }
+
+    if let Ok(content) = std::fs::read_to_string(dir.join("package.json")) {
+        if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
+            for key in ["dependencies", "devDependencies"] {
+                if let Some(obj) = json.get(key).and_then(|v| v.as_object()) {
+                    for (name, version) in obj {
+                        if let Some(version) = version.as_str() {
+                            deps.push((name.clone(), version.trim_start_matches('^').to_string()));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if let Ok(content) = std::fs::read_to_string(dir.join("go.mod")) {
+        for line in content.lines() {
+            let line = line.trim();
+            if let Some(rest) = line.strip_prefix("require ") {
+                if let Some((module, version)) = rest.split_once(' ') {
+                    deps.push((module.to_string(), version.trim().to_string()));
+                }
+            }
+        }
+    }
+
+    deps
+}
+
+/// Environment probe: detect the project's stack (via
+/// [`crate::deps::detect_stack`]), its key dependency versions, and which
+/// of the binaries `rtk`'s specialized filters wrap are actually installed.
+/// Gives users a single command to check before a session that `cargo_cmd`,
+/// `pnpm_cmd`, `prisma_cmd`, etc. will have something to shell out to.
+pub fn run(format: &str, verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let dir = std::env::current_dir().unwrap_or_else(|_| Path::new(".").to_path_buf());
+    let stack = deps::detect_stack(&dir);
+    let deps = key_dependencies(&dir);
+
+    if verbose > 0 {
+        eprintln!("rtk doctor: probing {} tools", PROBES.len());
+    }
+    let tools: Vec<ToolProbe> = PROBES
+        .iter()
+        .map(|(name, args)| probe_tool(name, args))
+        .collect();
+    let missing = tools.iter().filter(|t| t.version.is_none()).count();
+
+    let output = if format == "json" {
+        serde_json::to_string_pretty(&json!({
+            "stack": stack,
+            "dependencies": deps.iter().map(|(n, v)| json!({"name": n, "version": v})).collect::<Vec<_>>(),
+            "tools": tools.iter().map(|t| json!({
+                "name": t.name,
+                "found": t.version.is_some(),
+                "version": t.version,
+            })).collect::<Vec<_>>(),
+        }))?
+    } else {
+        let mut lines = vec!["rtk doctor: environment".to_string()];
+        lines.push("═══════════════════════════════════════".to_string());
+        lines.push(format!("stack: {}", stack.as_deref().unwrap_or("unknown")));
+        if !deps.is_empty() {
+            lines.push(String::new());
+            lines.push("key dependencies:".to_string());
+            for (name, version) in &deps {
+                lines.push(format!("  {name} {version}"));
+            }
+        }
+        lines.push(String::new());
+        lines.push("tools:".to_string());
+        for tool in &tools {
+            let mark = if tool.version.is_some() { "✓" } else { "✗" };
+            match &tool.version {
+                Some(version) => lines.push(format!("  {mark} {} {}", tool.name, version)),
+                None => lines.push(format!("  {mark} {} (not found)", tool.name)),
+            }
+        }
+        lines.push(String::new());
+        lines.push(format!(
+            "{}/{} tools found",
+            tools.len() - missing,
+            tools.len()
+        ));
+        lines.join("\n")
+    };
+
+    println!("{output}");
+    timer.track("rtk doctor", "rtk doctor", &output, &output);
+
+    Ok(())
+}
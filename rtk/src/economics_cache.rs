@@ -0,0 +1,85 @@
+//! On-disk snapshot cache for `cc_economics`'s merged period data, so a
+//! repeated export doesn't re-invoke `ccusage::fetch` (and recompute every
+//! weighted/dual metric) for periods that are already closed and immutable.
+//! Mirrors `gh_cache`'s one-JSON-file convention under the crate's data
+//! directory, but keys by granularity rather than by query args since
+//! there's only ever one economics dataset per granularity.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::cc_economics::PeriodEconomics;
+use crate::ccusage::Granularity;
+
+#[derive(Serialize, Deserialize)]
+pub struct Snapshot {
+    pub periods: Vec<PeriodEconomics>,
+}
+
+fn cache_dir() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("rtk")
+        .join("economics_cache")
+}
+
+fn cache_path(granularity: Granularity) -> PathBuf {
+    let name = match granularity {
+        Granularity::Daily => "daily.json",
+        Granularity::Weekly => "weekly.json",
+        Granularity::Monthly => "monthly.json",
+    };
+    cache_dir().join(name)
+}
+
+/// Restore the last snapshot for `granularity`, if one was ever written. A
+/// missing or corrupt cache file is treated the same as "never cached" --
+/// the caller falls back to a full fetch.
+pub fn load(granularity: Granularity) -> Option<Snapshot> {
+    let contents = std::fs::read_to_string(cache_path(granularity)).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Persist `periods` as the new snapshot for `granularity`. Failures to
+/// write are non-fatal to callers -- the cache is a speedup, not a
+/// correctness requirement.
+pub fn save(granularity: Granularity, periods: &[PeriodEconomics]) -> Result<()> {
+    let dir = cache_dir();
+    std::fs::create_dir_all(&dir).context("Failed to create economics cache directory")?;
+    let snapshot = Snapshot {
+        periods: periods.to_vec(),
+    };
+    std::fs::write(
+        cache_path(granularity),
+        serde_json::to_string(&snapshot).context("Failed to serialize economics snapshot")?,
+    )
+    .context("Failed to write economics cache snapshot")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_path_differs_by_granularity() {
+        assert_ne!(
+            cache_path(Granularity::Daily),
+            cache_path(Granularity::Weekly)
+        );
+        assert_ne!(
+            cache_path(Granularity::Weekly),
+            cache_path(Granularity::Monthly)
+        );
+    }
+
+    #[test]
+    fn test_snapshot_roundtrips_through_json() {
+        let periods = vec![PeriodEconomics::new("2026-01-01")];
+        let snapshot = Snapshot { periods };
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let restored: Snapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.periods[0].label, "2026-01-01");
+    }
+}
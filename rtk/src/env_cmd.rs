@@ -1,6 +1,7 @@
+use crate::config::Config;
 use crate::tracking;
 use anyhow::Result;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::env;
 
 /// Show filtered environment variables (hide sensitive data)
@@ -11,6 +12,7 @@ pub fn run(filter: Option<&str>, show_all: bool, verbose: u8) -> Result<()> {
         eprintln!("Environment variables:");
     }
 
+    let env_config = Config::load().unwrap_or_default().env;
     let sensitive_patterns = get_sensitive_patterns();
     let mut vars: Vec<(String, String)> = env::vars().collect();
     vars.sort_by(|a, b| a.0.cmp(&b.0));
@@ -30,13 +32,29 @@ pub fn run(filter: Option<&str>, show_all: bool, verbose: u8) -> Result<()> {
             }
         }
 
-        // Check if sensitive
+        // Check if sensitive by key name, or by the value's own shape
+        // (high entropy or a known credential prefix) when the key gives
+        // no hint.
         let is_sensitive = sensitive_patterns
             .iter()
             .any(|p| key.to_lowercase().contains(p));
+        let value_reason = if is_sensitive {
+            None
+        } else {
+            detect_value_secret(value, &env_config)
+        };
 
-        let display_value = if is_sensitive && !show_all {
+        let display_value = if show_all {
+            if value.len() > 100 {
+                let preview: String = value.chars().take(50).collect();
+                format!("{}... ({} chars)", preview, value.chars().count())
+            } else {
+                value.clone()
+            }
+        } else if is_sensitive {
             mask_value(value)
+        } else if let Some(reason) = value_reason {
+            format!("{} ({})", mask_value(value), reason)
         } else if value.len() > 100 {
             let preview: String = value.chars().take(50).collect();
             format!("{}... ({} chars)", preview, value.chars().count())
@@ -143,6 +161,70 @@ fn get_sensitive_patterns() -> HashSet<&'static str> {
     set
 }
 
+/// Credential token prefixes/shapes worth flagging regardless of the key
+/// name they're stored under: AWS access keys, GitHub tokens, OpenAI-style
+/// keys, and PEM key/cert blocks. Slack tokens (`xox[baprs]-`) and JWTs
+/// (`eyJ...` three-part base64) need their own matchers below.
+const KNOWN_TOKEN_PREFIXES: &[&str] = &["AKIA", "ghp_", "gho_", "ghu_", "ghs_", "ghr_", "sk-", "-----BEGIN"];
+
+/// Flag a value as a likely secret based on its shape alone: a known
+/// credential prefix, or high Shannon entropy for a long value. Returns a
+/// short reason suitable for printing next to the mask.
+fn detect_value_secret(value: &str, config: &crate::config::EnvConfig) -> Option<&'static str> {
+    if matches_token_prefix(value, &config.extra_token_prefixes) {
+        return Some("token-prefix");
+    }
+    if value.chars().count() > 16 && shannon_entropy(value) >= config.entropy_threshold {
+        return Some("high-entropy");
+    }
+    None
+}
+
+fn matches_token_prefix(value: &str, extra_prefixes: &[String]) -> bool {
+    KNOWN_TOKEN_PREFIXES.iter().any(|p| value.starts_with(p))
+        || is_slack_token(value)
+        || is_jwt_like(value)
+        || extra_prefixes.iter().any(|p| value.starts_with(p.as_str()))
+}
+
+fn is_slack_token(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    value.starts_with("xox") && bytes.len() > 4 && b"baprs".contains(&bytes[3]) && bytes[4] == b'-'
+}
+
+/// A JWT-shaped value: three base64url segments separated by dots, the
+/// first of which (the header) typically starts with `eyJ`.
+fn is_jwt_like(value: &str) -> bool {
+    if !value.starts_with("eyJ") {
+        return false;
+    }
+    let parts: Vec<&str> = value.split('.').collect();
+    parts.len() == 3
+        && parts
+            .iter()
+            .all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+}
+
+/// Shannon entropy in bits/char over the value's character distribution:
+/// H = -Σ pᵢ·log₂(pᵢ). Random/base64-ish secrets sit well above plain
+/// words or sentences.
+fn shannon_entropy(value: &str) -> f64 {
+    let len = value.chars().count();
+    if len == 0 {
+        return 0.0;
+    }
+
+    let mut counts: HashMap<char, usize> = HashMap::new();
+    for c in value.chars() {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+
+    counts.values().fold(0.0, |entropy, &count| {
+        let p = count as f64 / len as f64;
+        entropy - p * p.log2()
+    })
+}
+
 fn mask_value(value: &str) -> String {
     let chars: Vec<char> = value.chars().collect();
     if chars.len() <= 4 {
@@ -0,0 +1,183 @@
+//! Structured, machine-readable event stream for wrapped `git` commands.
+//!
+//! [`tracking::TimedExecution`](crate::tracking::TimedExecution) already
+//! records a human "compact" string per command for the savings database;
+//! this module is the agent-facing complement -- a stable JSON schema a
+//! supervising process or CI step can consume directly instead of
+//! re-parsing the compact text, the same way a post-receive hook notifies
+//! downstream of a push. Disabled by default (`[events] enabled = false`);
+//! once turned on, every wrapped `pull`/`branch`/`fetch`/`stash`/
+//! `worktree`/passthrough call emits one [`CommandEvent`] as a line of
+//! newline-delimited JSON to whichever sink `[events] sink` names.
+
+use crate::config::EventsConfig;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+/// One wrapped command's outcome, in a schema stable enough for a
+/// supervising process to depend on across rtk versions. Every field
+/// beyond `command`/`exit_code`/`elapsed_ms` is `None` when it doesn't
+/// apply to the command that produced the event (e.g. `stash_index` is
+/// only ever set by `git stash`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommandEvent {
+    /// The git subcommand this event describes, e.g. `"pull"`, `"fetch"`.
+    pub command: String,
+    /// Exit code of the underlying `git` invocation (`0` on success).
+    pub exit_code: i32,
+    /// Wall-clock time the command took to run.
+    pub elapsed_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ahead: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub behind: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub files_changed: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insertions: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deletions: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new_refs: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stash_index: Option<usize>,
+}
+
+impl CommandEvent {
+    /// Start building an event for `command`, with every optional field
+    /// unset -- callers set only the fields their command actually knows.
+    pub fn new(command: &str, exit_code: i32, elapsed_ms: u64) -> Self {
+        Self {
+            command: command.to_string(),
+            exit_code,
+            elapsed_ms,
+            branch: None,
+            ahead: None,
+            behind: None,
+            files_changed: None,
+            insertions: None,
+            deletions: None,
+            new_refs: None,
+            stash_index: None,
+        }
+    }
+}
+
+/// Emit `event` to the sink configured under `[events]`, if enabled.
+/// Never fails the caller's command: a sink error (bad file path,
+/// unreachable webhook) is reported on stderr and swallowed, the same way
+/// [`tracking`](crate::tracking)'s own recording failures are non-fatal.
+pub fn emit(event: &CommandEvent, config: &EventsConfig) {
+    if !config.enabled {
+        return;
+    }
+
+    if let Err(err) = try_emit(event, config) {
+        eprintln!("rtk events: failed to emit event: {err:#}");
+    }
+}
+
+fn try_emit(event: &CommandEvent, config: &EventsConfig) -> Result<()> {
+    let line = serde_json::to_string(event)?;
+
+    match config.sink.as_str() {
+        "file" => {
+            let path = config
+                .file_path
+                .clone()
+                .unwrap_or_else(|| std::path::PathBuf::from("rtk-events.ndjson"));
+            let mut file = std::fs::OpenOptions::new().create(true).append(true).open(&path)?;
+            writeln!(file, "{line}")?;
+        }
+        "webhook" => {
+            let Some(url) = &config.webhook_url else {
+                anyhow::bail!("[events] sink = \"webhook\" but no webhook_url is configured");
+            };
+            let client = reqwest::blocking::Client::new();
+            client.post(url).header("Content-Type", "application/json").body(line).send()?;
+        }
+        _ => {
+            // Default / "stdout": one NDJSON line per event.
+            println!("{line}");
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_event() -> CommandEvent {
+        let mut event = CommandEvent::new("pull", 0, 42);
+        event.branch = Some("main".to_string());
+        event.ahead = Some(0);
+        event.behind = Some(2);
+        event.files_changed = Some(3);
+        event.insertions = Some(10);
+        event.deletions = Some(4);
+        event
+    }
+
+    #[test]
+    fn test_command_event_round_trips_through_json() {
+        let event = sample_event();
+        let json = serde_json::to_string(&event).unwrap();
+        let decoded: CommandEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(event, decoded);
+    }
+
+    #[test]
+    fn test_command_event_omits_unset_optional_fields() {
+        let event = CommandEvent::new("fetch", 0, 10);
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(!json.contains("branch"));
+        assert!(!json.contains("stash_index"));
+        assert!(json.contains("\"command\":\"fetch\""));
+    }
+
+    #[test]
+    fn test_command_event_includes_set_optional_fields() {
+        let event = sample_event();
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains("\"branch\":\"main\""));
+        assert!(json.contains("\"behind\":2"));
+    }
+
+    #[test]
+    fn test_emit_disabled_by_default_is_a_no_op() {
+        // No sink is touched when `enabled` is false, so this must not
+        // panic or write anything even with a bogus sink configured.
+        let config = EventsConfig {
+            enabled: false,
+            sink: "webhook".to_string(),
+            file_path: None,
+            webhook_url: None,
+        };
+        emit(&sample_event(), &config);
+    }
+
+    #[test]
+    fn test_emit_file_sink_appends_ndjson_line() {
+        let path = std::env::temp_dir().join(format!("rtk-events-test-{}.ndjson", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let config = EventsConfig {
+            enabled: true,
+            sink: "file".to_string(),
+            file_path: Some(path.clone()),
+            webhook_url: None,
+        };
+        emit(&sample_event(), &config);
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let decoded: CommandEvent = serde_json::from_str(contents.trim()).unwrap();
+        assert_eq!(decoded, sample_event());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
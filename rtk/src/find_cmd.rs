@@ -1,54 +1,388 @@
 use crate::tracking;
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
+use globset::{Glob, GlobSet, GlobSetBuilder};
 use ignore::WalkBuilder;
 use std::collections::HashMap;
 use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime};
 
-/// Match a filename against a glob pattern (supports `*` and `?`).
-fn glob_match(pattern: &str, name: &str) -> bool {
+/// Match a filename against a glob pattern (supports `*` and `?`). Used by
+/// `deps.rs` for single-segment workspace-member glob resolution; the
+/// `find` command itself uses the full `globset`-backed engine below.
+pub(crate) fn glob_match(pattern: &str, name: &str) -> bool {
     glob_match_inner(pattern.as_bytes(), name.as_bytes())
 }
 
+/// Classic linear wildcard matcher (two pointers plus a `*` backtrack mark),
+/// O(pattern · name) worst case instead of the exponential blowup a naive
+/// recursive matcher hits on patterns with several `*` segments.
 fn glob_match_inner(pat: &[u8], name: &[u8]) -> bool {
-    match (pat.first(), name.first()) {
-        (None, None) => true,
-        (Some(b'*'), _) => {
-            // '*' matches zero or more characters
-            glob_match_inner(&pat[1..], name)
-                || (!name.is_empty() && glob_match_inner(pat, &name[1..]))
+    let (mut p, mut n) = (0, 0);
+    let mut star: Option<usize> = None;
+    let mut mark = 0;
+
+    while n < name.len() {
+        if p < pat.len() && (pat[p] == b'?' || pat[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pat.len() && pat[p] == b'*' {
+            star = Some(p);
+            mark = n;
+            p += 1;
+        } else if let Some(s) = star {
+            p = s + 1;
+            mark += 1;
+            n = mark;
+        } else {
+            return false;
         }
-        (Some(b'?'), Some(_)) => glob_match_inner(&pat[1..], &name[1..]),
-        (Some(&p), Some(&n)) if p == n => glob_match_inner(&pat[1..], &name[1..]),
-        _ => false,
     }
+
+    while p < pat.len() && pat[p] == b'*' {
+        p += 1;
+    }
+
+    p == pat.len()
+}
+
+/// Expand `{a,b,c}` brace groups into every literal alternative via simple
+/// recursive substitution, one group per pass -- `globset` doesn't support
+/// brace expansion natively, so this runs before patterns are compiled.
+/// Nested/multiple groups in the same pattern resolve through recursion.
+fn expand_braces(pattern: &str) -> Vec<String> {
+    let Some(open) = pattern.find('{') else {
+        return vec![pattern.to_string()];
+    };
+    let Some(close_rel) = pattern[open..].find('}') else {
+        return vec![pattern.to_string()];
+    };
+    let close = open + close_rel;
+
+    let prefix = &pattern[..open];
+    let alternatives = &pattern[open + 1..close];
+    let suffix = &pattern[close + 1..];
+
+    alternatives
+        .split(',')
+        .flat_map(|alt| expand_braces(&format!("{prefix}{alt}{suffix}")))
+        .collect()
+}
+
+/// Compile one or more glob patterns into a single `GlobSet`, so
+/// `rtk find '*.rs' '*.toml'` matches either pattern in a single pass.
+/// `.` is treated as match-all, and each pattern is brace-expanded before
+/// compiling; character classes (`[A-Z]`) and `**` are handled natively by
+/// `globset`.
+fn build_glob_set(patterns: &[String]) -> Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let effective = if pattern == "." { "*" } else { pattern.as_str() };
+        for expanded in expand_braces(effective) {
+            builder.add(Glob::new(&expanded)?);
+        }
+    }
+    Ok(builder.build()?)
+}
+
+/// The directory portion of a pattern's wildcard-free leading segment, e.g.
+/// `src/**/*.rs` -> `src`, `*.rs` -> `""`. Everything up to the first
+/// `*`/`?`/`[`/`{` is guaranteed literal, so the walker never needs to
+/// descend outside it.
+fn literal_prefix_dir(pattern: &str) -> &str {
+    let wildcard_at = pattern.find(['*', '?', '[', '{']).unwrap_or(pattern.len());
+    match pattern[..wildcard_at].rfind('/') {
+        Some(slash) => &pattern[..slash],
+        None => "",
+    }
+}
+
+/// The longest shared leading run of path components between two
+/// slash-separated relative paths, joined back into a single path string.
+fn common_path_prefix(a: &str, b: &str) -> String {
+    let a_comps = a.split('/').filter(|s| !s.is_empty());
+    let b_comps: Vec<&str> = b.split('/').filter(|s| !s.is_empty()).collect();
+    a_comps
+        .zip(b_comps.iter())
+        .take_while(|(x, y)| x == *y)
+        .map(|(x, _)| x)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Narrow the walk root to the literal subtree every pattern is confined to,
+/// the same "split into a literal base path plus the remaining pattern"
+/// optimization other glob walkers use so the `WalkBuilder` never expands
+/// into subtrees no pattern could possibly match.
+fn prune_root(path: &str, patterns: &[String]) -> std::path::PathBuf {
+    let mut common: Option<String> = None;
+    for pattern in patterns {
+        let dir = literal_prefix_dir(pattern).to_string();
+        common = Some(match common {
+            None => dir,
+            Some(existing) => common_path_prefix(&existing, &dir),
+        });
+    }
+
+    match common {
+        Some(suffix) if !suffix.is_empty() => Path::new(path).join(suffix),
+        _ => Path::new(path).to_path_buf(),
+    }
+}
+
+/// A `--size` predicate, `fd`-style: a leading `+`/`-` selects greater-than
+/// or less-than, otherwise the size must match exactly. Suffixes are `b`
+/// (bytes, default), `k` (KiB), `M` (MiB), `G` (GiB).
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SizeCmp {
+    GreaterThan,
+    LessThan,
+    Equal,
 }
 
+#[derive(Debug, Clone, Copy)]
+struct SizeFilter {
+    cmp: SizeCmp,
+    bytes: u64,
+}
+
+impl SizeFilter {
+    fn parse(spec: &str) -> Result<SizeFilter> {
+        let (cmp, rest) = match spec.as_bytes().first() {
+            Some(b'+') => (SizeCmp::GreaterThan, &spec[1..]),
+            Some(b'-') => (SizeCmp::LessThan, &spec[1..]),
+            _ => (SizeCmp::Equal, spec),
+        };
+
+        let split_at = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let (digits, suffix) = rest.split_at(split_at);
+
+        if digits.is_empty() {
+            bail!("invalid --size spec '{}': expected a number", spec);
+        }
+        let value: u64 = digits
+            .parse()
+            .map_err(|_| anyhow::anyhow!("invalid --size spec '{}': not a number", spec))?;
+
+        let multiplier: u64 = match suffix.to_ascii_lowercase().as_str() {
+            "" | "b" => 1,
+            "k" => 1024,
+            "m" => 1024 * 1024,
+            "g" => 1024 * 1024 * 1024,
+            other => bail!("invalid --size suffix '{}' (expected b/k/M/G)", other),
+        };
+
+        Ok(SizeFilter {
+            cmp,
+            bytes: value * multiplier,
+        })
+    }
+
+    fn matches(&self, len: u64) -> bool {
+        match self.cmp {
+            SizeCmp::GreaterThan => len > self.bytes,
+            SizeCmp::LessThan => len < self.bytes,
+            SizeCmp::Equal => len == self.bytes,
+        }
+    }
+}
+
+/// Parse a duration spec like `2d`/`1w`/`30m` (suffixes `s/m/h/d/w`) for
+/// `--changed-within`/`--changed-before`.
+fn parse_duration_spec(spec: &str) -> Result<Duration> {
+    let split_at = spec.find(|c: char| !c.is_ascii_digit()).unwrap_or(spec.len());
+    let (digits, suffix) = spec.split_at(split_at);
+
+    if digits.is_empty() {
+        bail!("invalid duration spec '{}': expected a number", spec);
+    }
+    let value: u64 = digits
+        .parse()
+        .map_err(|_| anyhow::anyhow!("invalid duration spec '{}': not a number", spec))?;
+
+    let secs: u64 = match suffix {
+        "s" => value,
+        "m" => value * 60,
+        "h" => value * 60 * 60,
+        "d" => value * 60 * 60 * 24,
+        "w" => value * 60 * 60 * 24 * 7,
+        other => bail!("invalid duration suffix '{}' (expected s/m/h/d/w)", other),
+    };
+
+    Ok(Duration::from_secs(secs))
+}
+
+#[cfg(unix)]
+fn is_executable(metadata: &std::fs::Metadata) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    metadata.permissions().mode() & 0o111 != 0
+}
+
+#[cfg(not(unix))]
+fn is_executable(_metadata: &std::fs::Metadata) -> bool {
+    false
+}
+
+/// Render an `--exec` template for a single matched path: `{}` is the full
+/// path, `{.}` is the path without its extension, `{/}` is the basename,
+/// and `{//}` is the parent directory. A template with none of these
+/// placeholders gets the path appended as a trailing argument, mirroring
+/// `fd`'s default of calling `cmd {}`.
+fn render_exec_template(template: &str, path: &str) -> Vec<String> {
+    let p = Path::new(path);
+    let stem = p.with_extension("");
+    let basename = p
+        .file_name()
+        .map(|f| f.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let parent = p
+        .parent()
+        .map(|d| d.to_string_lossy().to_string())
+        .unwrap_or_else(|| ".".to_string());
+
+    let has_placeholder = ["{}", "{.}", "{/}", "{//}"]
+        .iter()
+        .any(|ph| template.contains(ph));
+
+    let mut tokens: Vec<String> = template
+        .split_whitespace()
+        .map(|tok| {
+            tok.replace("{//}", &parent)
+                .replace("{/}", &basename)
+                .replace("{.}", &stem.to_string_lossy())
+                .replace("{}", path)
+        })
+        .collect();
+
+    if !has_placeholder {
+        tokens.push(path.to_string());
+    }
+
+    tokens
+}
+
+/// Outcome of running an `--exec`/`--exec-batch` pass: how many child
+/// processes were spawned and how many exited non-zero.
+struct ExecOutcome {
+    ran: usize,
+    failed: usize,
+}
+
+/// Run `template` once per path in `paths`, substituting placeholders via
+/// `render_exec_template`.
+fn run_exec(template: &str, paths: &[String]) -> Result<ExecOutcome> {
+    let mut outcome = ExecOutcome { ran: 0, failed: 0 };
+
+    for path in paths {
+        let tokens = render_exec_template(template, path);
+        let Some((program, args)) = tokens.split_first() else {
+            continue;
+        };
+
+        let status = Command::new(program)
+            .args(args)
+            .status()
+            .with_context(|| format!("failed to run `{}` on {}", template, path))?;
+
+        outcome.ran += 1;
+        if !status.success() {
+            outcome.failed += 1;
+        }
+    }
+
+    Ok(outcome)
+}
+
+/// Run `template` once with every path in `paths` appended as separate
+/// arguments wherever `{}` appears (or trailing, if it doesn't appear at
+/// all) -- the batch counterpart to `run_exec`.
+fn run_exec_batch(template: &str, paths: &[String]) -> Result<ExecOutcome> {
+    if paths.is_empty() {
+        return Ok(ExecOutcome { ran: 0, failed: 0 });
+    }
+
+    let mut substituted = false;
+    let mut tokens: Vec<String> = Vec::new();
+    for tok in template.split_whitespace() {
+        if tok == "{}" {
+            tokens.extend(paths.iter().cloned());
+            substituted = true;
+        } else {
+            tokens.push(tok.to_string());
+        }
+    }
+    if !substituted {
+        tokens.extend(paths.iter().cloned());
+    }
+
+    let Some((program, args)) = tokens.split_first() else {
+        return Ok(ExecOutcome { ran: 0, failed: 0 });
+    };
+
+    let status = Command::new(program)
+        .args(args)
+        .status()
+        .with_context(|| format!("failed to run `{}` in batch", template))?;
+
+    Ok(ExecOutcome {
+        ran: 1,
+        failed: if status.success() { 0 } else { 1 },
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn run(
-    pattern: &str,
+    patterns: &[String],
     path: &str,
     max_results: usize,
     file_type: &str,
+    size: Option<&str>,
+    changed_within: Option<&str>,
+    changed_before: Option<&str>,
+    include_hidden: bool,
+    no_ignore: bool,
+    no_ignore_parent: bool,
+    max_depth: Option<usize>,
+    exec: Option<&str>,
+    exec_batch: Option<&str>,
     verbose: u8,
 ) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
-    // Treat "." as match-all
-    let effective_pattern = if pattern == "." { "*" } else { pattern };
+    let glob_set = build_glob_set(patterns)?;
+    let effective_pattern = patterns.join(" ");
 
     if verbose > 0 {
         eprintln!("find: {} in {}", effective_pattern, path);
     }
 
-    let want_dirs = file_type == "d";
+    let size_filter = size.map(SizeFilter::parse).transpose()?;
+    let within_cutoff = changed_within
+        .map(parse_duration_spec)
+        .transpose()?
+        .map(|d| SystemTime::now() - d);
+    let before_cutoff = changed_before
+        .map(parse_duration_spec)
+        .transpose()?
+        .map(|d| SystemTime::now() - d);
 
-    let walker = WalkBuilder::new(path)
-        .hidden(true) // skip hidden files/dirs
-        .git_ignore(true) // respect .gitignore
-        .git_global(true)
-        .git_exclude(true)
+    let walk_root = prune_root(path, patterns);
+    if verbose > 1 && walk_root != Path::new(path) {
+        eprintln!("find: pruned walk root to {}", walk_root.display());
+    }
+
+    let walker = WalkBuilder::new(&walk_root)
+        .hidden(!include_hidden)
+        .ignore(!no_ignore) // plain .ignore files
+        .git_ignore(!no_ignore)
+        .git_global(!no_ignore)
+        .git_exclude(!no_ignore)
+        .parents(!no_ignore_parent) // consult ancestor .gitignore/.ignore files
+        .max_depth(max_depth)
         .build();
 
     let mut files: Vec<String> = Vec::new();
+    let mut exec_paths: Vec<String> = Vec::new();
 
     for entry in walker {
         let entry = match entry {
@@ -57,44 +391,116 @@ pub fn run(
         };
 
         let ft = entry.file_type();
-        let is_dir = ft.as_ref().map_or(false, |t| t.is_dir());
+        let is_file = ft.as_ref().map_or(false, |t| t.is_file());
 
-        // Filter by type
-        if want_dirs && !is_dir {
+        // Filter by type before touching metadata or the glob, so a
+        // mismatched type never pays for a stat() call it doesn't need.
+        let matches_type = match file_type {
+            "d" => ft.as_ref().map_or(false, |t| t.is_dir()),
+            "l" => ft.as_ref().map_or(false, |t| t.is_symlink()),
+            "x" => is_file && entry.metadata().map_or(false, |m| is_executable(&m)),
+            "e" => is_file && entry.metadata().map_or(false, |m| m.len() == 0),
+            _ => is_file,
+        };
+        if !matches_type {
             continue;
         }
-        if !want_dirs && is_dir {
-            continue;
+
+        // Metadata-based predicates, each skipping the entry before the
+        // (more expensive, regex-backed) glob check below.
+        if size_filter.is_some() || within_cutoff.is_some() || before_cutoff.is_some() {
+            let metadata = match entry.metadata() {
+                Ok(m) => m,
+                Err(_) => continue,
+            };
+
+            if let Some(filter) = &size_filter {
+                if !filter.matches(metadata.len()) {
+                    continue;
+                }
+            }
+
+            if let Some(cutoff) = within_cutoff {
+                match metadata.modified() {
+                    Ok(modified) if modified >= cutoff => {}
+                    _ => continue,
+                }
+            }
+
+            if let Some(cutoff) = before_cutoff {
+                match metadata.modified() {
+                    Ok(modified) if modified < cutoff => {}
+                    _ => continue,
+                }
+            }
         }
 
         let entry_path = entry.path();
 
-        // Get filename for glob matching
-        let name = match entry_path.file_name() {
-            Some(n) => n.to_string_lossy(),
-            None => continue,
-        };
+        // Match against the path relative to the search root (not just
+        // file_name()), so `**` can cross directory boundaries and a
+        // pattern like `src/**/*.rs` is meaningful.
+        let relative = entry_path.strip_prefix(path).unwrap_or(entry_path);
 
-        if !glob_match(effective_pattern, &name) {
+        if !glob_set.is_match(relative) {
             continue;
         }
 
-        // Store path relative to search root
-        let display_path = entry_path
-            .strip_prefix(path)
-            .unwrap_or(entry_path)
-            .to_string_lossy()
-            .to_string();
+        let display_path = relative.to_string_lossy().to_string();
 
         if !display_path.is_empty() {
+            if exec.is_some() || exec_batch.is_some() {
+                exec_paths.push(entry_path.to_string_lossy().to_string());
+            }
             files.push(display_path);
         }
     }
 
     files.sort();
+    exec_paths.sort();
 
     let raw_output = files.join("\n");
 
+    // --exec/--exec-batch turn `find` into a pipeline primitive: run the
+    // matched paths through a command instead of printing the tree display.
+    if let Some(template) = exec {
+        let outcome = run_exec(template, &exec_paths)?;
+        let summary = format!(
+            "{} command(s) ran ({} failed) for '{}'",
+            outcome.ran, outcome.failed, effective_pattern
+        );
+        println!("{}", summary);
+        timer.track(
+            &format!("find {} -exec {}", effective_pattern, template),
+            "rtk find --exec",
+            &raw_output,
+            &summary,
+        );
+        if outcome.failed > 0 {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    if let Some(template) = exec_batch {
+        let outcome = run_exec_batch(template, &exec_paths)?;
+        let summary = format!(
+            "{} command(s) ran ({} failed) for '{}'",
+            outcome.ran, outcome.failed, effective_pattern
+        );
+        println!("{}", summary);
+        timer.track(
+            &format!("find {} -exec-batch {}", effective_pattern, template),
+            "rtk find --exec-batch",
+            &raw_output,
+            &summary,
+        );
+        if outcome.failed > 0 {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     if files.is_empty() {
         let msg = format!("0 for '{}'", effective_pattern);
         println!("{}", msg);
@@ -242,6 +648,20 @@ mod tests {
         assert!(!glob_match("test_*", "test"));
     }
 
+    #[test]
+    fn glob_match_many_stars_no_exponential_blowup() {
+        // Would have backtracked exponentially under the old recursive
+        // matcher; the iterative two-pointer version is linear.
+        assert!(glob_match("*a*a*a*a*a*a*a*a*a*a*", "aaaaaaaaaaaaaaaaaaaaaaaaax"));
+        assert!(!glob_match("*a*a*a*a*a*a*a*a*a*a*b", "aaaaaaaaaaaaaaaaaaaaaaaaax"));
+    }
+
+    #[test]
+    fn glob_match_star_matches_empty() {
+        assert!(glob_match("a*b*c*", "abc"));
+        assert!(glob_match("*a*b*c*", "xaybzc"));
+    }
+
     // --- dot pattern treated as star ---
 
     #[test]
@@ -251,41 +671,440 @@ mod tests {
         assert_eq!(effective, "*");
     }
 
+    // --- brace expansion ---
+
+    #[test]
+    fn expand_braces_no_braces_is_identity() {
+        assert_eq!(expand_braces("*.rs"), vec!["*.rs".to_string()]);
+    }
+
+    #[test]
+    fn expand_braces_simple_group() {
+        let mut expanded = expand_braces("*.{rs,toml}");
+        expanded.sort();
+        assert_eq!(expanded, vec!["*.rs".to_string(), "*.toml".to_string()]);
+    }
+
+    #[test]
+    fn expand_braces_two_groups() {
+        let mut expanded = expand_braces("{a,b}/{x,y}");
+        expanded.sort();
+        assert_eq!(
+            expanded,
+            vec![
+                "a/x".to_string(),
+                "a/y".to_string(),
+                "b/x".to_string(),
+                "b/y".to_string(),
+            ]
+        );
+    }
+
+    // --- build_glob_set: `**`, brace groups, character classes ---
+
+    #[test]
+    fn glob_set_matches_recursive_double_star() {
+        let set = build_glob_set(&["src/**/*.rs".to_string()]).unwrap();
+        assert!(set.is_match(Path::new("src/discover/registry.rs")));
+        assert!(!set.is_match(Path::new("other/registry.rs")));
+    }
+
+    #[test]
+    fn glob_set_matches_brace_expansion() {
+        let set = build_glob_set(&["*.{rs,toml}".to_string()]).unwrap();
+        assert!(set.is_match(Path::new("Cargo.toml")));
+        assert!(set.is_match(Path::new("main.rs")));
+        assert!(!set.is_match(Path::new("main.py")));
+    }
+
+    #[test]
+    fn glob_set_matches_character_class() {
+        let set = build_glob_set(&["[A-Z]*.rs".to_string()]).unwrap();
+        assert!(set.is_match(Path::new("Main.rs")));
+        assert!(!set.is_match(Path::new("main.rs")));
+    }
+
+    #[test]
+    fn glob_set_matches_multiple_patterns_in_one_pass() {
+        let set = build_glob_set(&["*.rs".to_string(), "*.toml".to_string()]).unwrap();
+        assert!(set.is_match(Path::new("main.rs")));
+        assert!(set.is_match(Path::new("Cargo.toml")));
+        assert!(!set.is_match(Path::new("main.py")));
+    }
+
+    #[test]
+    fn glob_set_dot_pattern_matches_everything() {
+        let set = build_glob_set(&[".".to_string()]).unwrap();
+        assert!(set.is_match(Path::new("anything.txt")));
+    }
+
+    // --- literal_prefix_dir / prune_root ---
+
+    #[test]
+    fn literal_prefix_dir_extracts_leading_directory() {
+        assert_eq!(literal_prefix_dir("src/**/*.rs"), "src");
+        assert_eq!(literal_prefix_dir("src/discover/*.rs"), "src/discover");
+    }
+
+    #[test]
+    fn literal_prefix_dir_empty_for_bare_pattern() {
+        assert_eq!(literal_prefix_dir("*.rs"), "");
+        assert_eq!(literal_prefix_dir("."), "");
+    }
+
+    #[test]
+    fn common_path_prefix_shared_ancestor() {
+        assert_eq!(common_path_prefix("src/discover", "src/find_cmd"), "src");
+        assert_eq!(common_path_prefix("src/a", "lib/b"), "");
+        assert_eq!(common_path_prefix("", "src"), "");
+    }
+
+    #[test]
+    fn prune_root_joins_literal_prefix_onto_path() {
+        assert_eq!(
+            prune_root(".", &["src/**/*.rs".to_string()]),
+            Path::new("./src")
+        );
+    }
+
+    #[test]
+    fn prune_root_falls_back_to_path_without_literal_prefix() {
+        assert_eq!(prune_root(".", &["*.rs".to_string()]), Path::new("."));
+    }
+
+    #[test]
+    fn prune_root_uses_shared_ancestor_across_patterns() {
+        assert_eq!(
+            prune_root(".", &["src/a/*.rs".to_string(), "src/b/*.rs".to_string()]),
+            Path::new("./src")
+        );
+    }
+
     // --- integration: run on this repo ---
 
     #[test]
     fn find_rs_files_in_src() {
         // Should find .rs files without error
-        let result = run("*.rs", "src", 100, "f", 0);
+        let result = run(&["*.rs".to_string()], "src", 100, "f", None, None, None, false, false, false, None, None, None, 0);
         assert!(result.is_ok());
     }
 
     #[test]
     fn find_dot_pattern_works() {
         // "." pattern should not error (was broken before)
-        let result = run(".", "src", 10, "f", 0);
+        let result = run(&[".".to_string()], "src", 10, "f", None, None, None, false, false, false, None, None, None, 0);
         assert!(result.is_ok());
     }
 
     #[test]
     fn find_no_matches() {
-        let result = run("*.xyz_nonexistent", "src", 50, "f", 0);
+        let result = run(&["*.xyz_nonexistent".to_string()], "src", 50, "f", None, None, None, false, false, false, None, None, None, 0);
         assert!(result.is_ok());
     }
 
     #[test]
     fn find_respects_max() {
         // With max=2, should not error
-        let result = run("*.rs", "src", 2, "f", 0);
+        let result = run(&["*.rs".to_string()], "src", 2, "f", None, None, None, false, false, false, None, None, None, 0);
         assert!(result.is_ok());
     }
 
     #[test]
     fn find_gitignored_excluded() {
         // target/ is in .gitignore — files inside should not appear
-        let result = run("*", ".", 1000, "f", 0);
+        let result = run(&["*".to_string()], ".", 1000, "f", None, None, None, false, false, false, None, None, None, 0);
         assert!(result.is_ok());
         // We can't easily capture stdout in unit tests, but at least
         // verify it runs without error. The smoke tests verify content.
     }
+
+    // --- SizeFilter ---
+
+    #[test]
+    fn size_filter_parses_greater_than() {
+        let f = SizeFilter::parse("+10k").unwrap();
+        assert_eq!(f.cmp, SizeCmp::GreaterThan);
+        assert_eq!(f.bytes, 10 * 1024);
+        assert!(f.matches(10 * 1024 + 1));
+        assert!(!f.matches(10 * 1024));
+    }
+
+    #[test]
+    fn size_filter_parses_less_than() {
+        let f = SizeFilter::parse("-1M").unwrap();
+        assert_eq!(f.cmp, SizeCmp::LessThan);
+        assert_eq!(f.bytes, 1024 * 1024);
+        assert!(f.matches(0));
+        assert!(!f.matches(1024 * 1024));
+    }
+
+    #[test]
+    fn size_filter_parses_bare_bytes_as_exact() {
+        let f = SizeFilter::parse("512b").unwrap();
+        assert_eq!(f.cmp, SizeCmp::Equal);
+        assert_eq!(f.bytes, 512);
+        assert!(f.matches(512));
+        assert!(!f.matches(513));
+    }
+
+    #[test]
+    fn size_filter_suffix_is_case_insensitive() {
+        assert_eq!(SizeFilter::parse("1g").unwrap().bytes, 1024 * 1024 * 1024);
+        assert_eq!(SizeFilter::parse("1G").unwrap().bytes, 1024 * 1024 * 1024);
+    }
+
+    #[test]
+    fn size_filter_rejects_missing_number() {
+        assert!(SizeFilter::parse("+k").is_err());
+    }
+
+    #[test]
+    fn size_filter_rejects_unknown_suffix() {
+        assert!(SizeFilter::parse("10q").is_err());
+    }
+
+    // --- parse_duration_spec ---
+
+    #[test]
+    fn duration_spec_parses_each_suffix() {
+        assert_eq!(parse_duration_spec("30s").unwrap(), Duration::from_secs(30));
+        assert_eq!(parse_duration_spec("5m").unwrap(), Duration::from_secs(5 * 60));
+        assert_eq!(parse_duration_spec("2h").unwrap(), Duration::from_secs(2 * 60 * 60));
+        assert_eq!(parse_duration_spec("2d").unwrap(), Duration::from_secs(2 * 60 * 60 * 24));
+        assert_eq!(parse_duration_spec("1w").unwrap(), Duration::from_secs(60 * 60 * 24 * 7));
+    }
+
+    #[test]
+    fn duration_spec_rejects_missing_number() {
+        assert!(parse_duration_spec("d").is_err());
+    }
+
+    #[test]
+    fn duration_spec_rejects_unknown_suffix() {
+        assert!(parse_duration_spec("2y").is_err());
+    }
+
+    // --- run() with the new metadata filters ---
+
+    #[test]
+    fn find_with_size_filter_runs_ok() {
+        let result = run(
+            &["*.rs".to_string()],
+            "src",
+            100,
+            "f",
+            Some("+1b"),
+            None,
+            None,
+            false,
+            false,
+            false,
+            None,
+            None,
+            None,
+            0,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn find_with_changed_within_runs_ok() {
+        let result = run(
+            &["*.rs".to_string()],
+            "src",
+            100,
+            "f",
+            None,
+            Some("365d"),
+            None,
+            false,
+            false,
+            false,
+            None,
+            None,
+            None,
+            0,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn find_with_changed_before_runs_ok() {
+        let result = run(
+            &["*.rs".to_string()],
+            "src",
+            100,
+            "f",
+            None,
+            None,
+            Some("52w"),
+            false,
+            false,
+            false,
+            None,
+            None,
+            None,
+            0,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn find_rejects_invalid_size_spec() {
+        let result = run(&["*.rs".to_string()], "src", 100, "f", Some("bogus"), None, None, false, false, false, None, None, None, 0);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn find_type_x_and_e_run_ok() {
+        assert!(run(&["*".to_string()], "src", 50, "x", None, None, None, false, false, false, None, None, None, 0).is_ok());
+        assert!(run(&["*".to_string()], "src", 50, "e", None, None, None, false, false, false, None, None, None, 0).is_ok());
+        assert!(run(&["*".to_string()], "src", 50, "l", None, None, None, false, false, false, None, None, None, 0).is_ok());
+    }
+
+    // --- traversal control flags ---
+
+    #[test]
+    fn find_include_hidden_runs_ok() {
+        let result = run(
+            &["*".to_string()], "src", 1000, "f", None, None, None, true, false, false, None, None, None, 0,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn find_no_ignore_runs_ok() {
+        // target/ is normally .gitignore'd; --no-ignore should let it through.
+        let result = run(
+            &["*".to_string()], ".", 1000, "f", None, None, None, false, true, false, None, None, None, 0,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn find_no_ignore_parent_runs_ok() {
+        let result = run(
+            &["*.rs".to_string()], "src", 1000, "f", None, None, None, false, false, true, None, None, None, 0,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn find_max_depth_limits_results() {
+        let result = run(
+            &["*.rs".to_string()],
+            "src",
+            1000,
+            "f",
+            None,
+            None,
+            None,
+            false,
+            false,
+            false,
+            Some(1),
+            None,
+            None,
+            0,
+        );
+        assert!(result.is_ok());
+    }
+
+    // --- render_exec_template ---
+
+    #[test]
+    fn render_exec_template_substitutes_all_placeholders() {
+        let tokens = render_exec_template("cp {} {//}/backup/{/}", "src/main.rs");
+        assert_eq!(
+            tokens,
+            vec!["cp", "src/main.rs", "src/backup/main.rs"]
+        );
+    }
+
+    #[test]
+    fn render_exec_template_stem_placeholder() {
+        let tokens = render_exec_template("mv {} {.}.bak", "src/main.rs");
+        assert_eq!(tokens, vec!["mv", "src/main.rs", "src/main.bak"]);
+    }
+
+    #[test]
+    fn render_exec_template_appends_path_without_placeholder() {
+        let tokens = render_exec_template("rustfmt", "src/main.rs");
+        assert_eq!(tokens, vec!["rustfmt", "src/main.rs"]);
+    }
+
+    // --- run_exec / run_exec_batch ---
+
+    #[test]
+    fn run_exec_runs_once_per_path() {
+        let paths = vec!["a".to_string(), "b".to_string()];
+        let outcome = run_exec("true {}", &paths).unwrap();
+        assert_eq!(outcome.ran, 2);
+        assert_eq!(outcome.failed, 0);
+    }
+
+    #[test]
+    fn run_exec_reports_failures() {
+        let paths = vec!["a".to_string()];
+        let outcome = run_exec("false {}", &paths).unwrap();
+        assert_eq!(outcome.ran, 1);
+        assert_eq!(outcome.failed, 1);
+    }
+
+    #[test]
+    fn run_exec_batch_runs_once_with_all_paths() {
+        let paths = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let outcome = run_exec_batch("true {}", &paths).unwrap();
+        assert_eq!(outcome.ran, 1);
+        assert_eq!(outcome.failed, 0);
+    }
+
+    #[test]
+    fn run_exec_batch_empty_paths_is_a_noop() {
+        let outcome = run_exec_batch("true {}", &[]).unwrap();
+        assert_eq!(outcome.ran, 0);
+        assert_eq!(outcome.failed, 0);
+    }
+
+    #[test]
+    fn find_with_exec_runs_ok() {
+        let result = run(
+            &["*.rs".to_string()],
+            "src",
+            1000,
+            "f",
+            None,
+            None,
+            None,
+            false,
+            false,
+            false,
+            None,
+            Some("true {}"),
+            None,
+            0,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn find_with_exec_batch_runs_ok() {
+        let result = run(
+            &["*.rs".to_string()],
+            "src",
+            1000,
+            "f",
+            None,
+            None,
+            None,
+            false,
+            false,
+            false,
+            None,
+            None,
+            Some("true"),
+            0,
+        );
+        assert!(result.is_ok());
+    }
 }
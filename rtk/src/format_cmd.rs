@@ -1,3 +1,4 @@
+use crate::parser::truncate_output;
 use crate::prettier_cmd;
 use crate::ruff_cmd;
 use crate::tracking;
@@ -6,6 +7,201 @@ use anyhow::{Context, Result};
 use std::path::Path;
 use std::process::Command;
 
+/// Per-file diff budget before a hunk is collapsed behind the passthrough
+/// marker, same spirit as `parser::truncate_output`'s char cap.
+const MAX_DIFF_CHARS_PER_FILE: usize = 1500;
+
+/// How prose (markdown/comments) should be wrapped, mirroring Deno's
+/// `FmtOptionsConfig.prose_wrap`. Only prettier understands this today;
+/// it's a no-op for black/ruff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProseWrap {
+    Always,
+    Never,
+    Preserve,
+}
+
+impl ProseWrap {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "always" => Some(Self::Always),
+            "never" => Some(Self::Never),
+            "preserve" => Some(Self::Preserve),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Always => "always",
+            Self::Never => "never",
+            Self::Preserve => "preserve",
+        }
+    }
+}
+
+/// One consistent knob set across heterogeneous formatters, translated by
+/// `run_once` into each tool's own flag spelling (`--line-length` for
+/// black/ruff, `--print-width` for prettier, ...).
+#[derive(Debug, Default)]
+struct FormatOptions {
+    line_width: Option<u32>,
+    prose_wrap: Option<ProseWrap>,
+}
+
+impl FormatOptions {
+    /// Parse `--line-width N` / `--prose-wrap MODE` out of the CLI args,
+    /// falling back to a `[tool.rtk.format]` section in `pyproject.toml` or
+    /// `package.json` for anything not given on the command line.
+    fn resolve(args: &[String], dir: &Path) -> (Self, Vec<String>) {
+        let mut opts = Self::from_project_config(dir);
+        let mut rest = Vec::with_capacity(args.len());
+        let mut i = 0;
+        while i < args.len() {
+            match args[i].as_str() {
+                "--line-width" => {
+                    if let Some(v) = args.get(i + 1).and_then(|v| v.parse().ok()) {
+                        opts.line_width = Some(v);
+                    }
+                    i += 2;
+                }
+                "--prose-wrap" => {
+                    if let Some(v) = args.get(i + 1).and_then(|v| ProseWrap::parse(v)) {
+                        opts.prose_wrap = Some(v);
+                    }
+                    i += 2;
+                }
+                other => {
+                    rest.push(other.to_string());
+                    i += 1;
+                }
+            }
+        }
+        (opts, rest)
+    }
+
+    fn from_project_config(dir: &Path) -> Self {
+        let mut opts = Self::default();
+
+        if let Ok(content) = std::fs::read_to_string(dir.join("pyproject.toml")) {
+            if let Ok(value) = content.parse::<toml::Value>() {
+                if let Some(section) = value
+                    .get("tool")
+                    .and_then(|t| t.get("rtk"))
+                    .and_then(|r| r.get("format"))
+                {
+                    if let Some(w) = section.get("line-width").and_then(|v| v.as_integer()) {
+                        opts.line_width = Some(w as u32);
+                    }
+                    if let Some(p) = section
+                        .get("prose-wrap")
+                        .and_then(|v| v.as_str())
+                        .and_then(ProseWrap::parse)
+                    {
+                        opts.prose_wrap = Some(p);
+                    }
+                }
+            }
+        }
+
+        if opts.line_width.is_none() || opts.prose_wrap.is_none() {
+            if let Ok(content) = std::fs::read_to_string(dir.join("package.json")) {
+                if let Ok(value) = serde_json::from_str::<serde_json::Value>(&content) {
+                    if let Some(section) = value.pointer("/rtk/format") {
+                        if opts.line_width.is_none() {
+                            if let Some(w) = section.get("lineWidth").and_then(|v| v.as_u64()) {
+                                opts.line_width = Some(w as u32);
+                            }
+                        }
+                        if opts.prose_wrap.is_none() {
+                            if let Some(p) = section
+                                .get("proseWrap")
+                                .and_then(|v| v.as_str())
+                                .and_then(ProseWrap::parse)
+                            {
+                                opts.prose_wrap = Some(p);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        opts
+    }
+
+    /// Translate into this formatter's own flag spelling.
+    fn apply(&self, formatter: &str, cmd: &mut Command) {
+        match (formatter, self.line_width) {
+            ("black", Some(w)) => {
+                cmd.arg("--line-length").arg(w.to_string());
+            }
+            ("ruff", Some(w)) => {
+                cmd.arg("--line-length").arg(w.to_string());
+            }
+            ("prettier", Some(w)) => {
+                cmd.arg("--print-width").arg(w.to_string());
+            }
+            _ => {}
+        }
+        if formatter == "prettier" {
+            if let Some(p) = self.prose_wrap {
+                cmd.arg("--prose-wrap").arg(p.as_str());
+            }
+        }
+    }
+}
+
+/// Glob-aware file collector for `rtk format`, modeled on Deno's
+/// `FileCollector`/`FilePatterns`: include globs become the walk roots and
+/// exclude globs are handed to the walker itself so a whole subtree (e.g.
+/// `dist/**`) is pruned the moment it's reached instead of being walked and
+/// then filtered out after the fact.
+struct FileCollector {
+    includes: Vec<String>,
+    excludes: Vec<String>,
+}
+
+impl FileCollector {
+    fn new(includes: Vec<String>, excludes: Vec<String>) -> Self {
+        let includes = if includes.is_empty() {
+            vec![".".to_string()]
+        } else {
+            includes
+        };
+        Self { includes, excludes }
+    }
+
+    /// Collect every non-ignored file under the include roots, pruning any
+    /// directory that matches an exclude glob before descending into it.
+    fn collect(&self) -> Result<Vec<std::path::PathBuf>> {
+        let mut overrides = ignore::overrides::OverrideBuilder::new(".");
+        for pattern in &self.excludes {
+            // `ignore`'s override globs are exclude-by-default when
+            // prefixed with `!`, mirroring .gitignore syntax.
+            overrides
+                .add(&format!("!{}", pattern))
+                .with_context(|| format!("Invalid exclude glob: {}", pattern))?;
+        }
+        let overrides = overrides.build().context("Failed to build glob filters")?;
+
+        let mut files = Vec::new();
+        for root in &self.includes {
+            let walker = ignore::WalkBuilder::new(root)
+                .hidden(false)
+                .overrides(overrides.clone())
+                .build();
+            for entry in walker {
+                let Ok(entry) = entry else { continue };
+                if entry.file_type().is_some_and(|t| t.is_file()) {
+                    files.push(entry.into_path());
+                }
+            }
+        }
+        Ok(files)
+    }
+}
+
 /// Detect formatter from project files or explicit argument
 fn detect_formatter(args: &[String]) -> String {
     detect_formatter_in_dir(args, Path::new("."))
@@ -52,6 +248,152 @@ fn detect_formatter_in_dir(args: &[String], dir: &Path) -> String {
 }
 
 pub fn run(args: &[String], verbose: u8) -> Result<()> {
+    let watch = args.iter().any(|a| a == "--watch");
+    if watch {
+        let args: Vec<String> = args.iter().filter(|a| *a != "--watch").cloned().collect();
+        return run_watch(&args, verbose);
+    }
+    run_once(args, verbose, false)
+}
+
+/// Block on filesystem changes and re-run the formatter for each burst of
+/// edits, debounced so a save's many touch events collapse into one pass.
+fn run_watch(args: &[String], verbose: u8) -> Result<()> {
+    use notify::{RecursiveMode, Watcher};
+    use std::sync::mpsc::channel;
+    use std::time::Duration;
+
+    let watch_root = args
+        .iter()
+        .find(|a| !a.starts_with('-'))
+        .map(Path::new)
+        .unwrap_or_else(|| Path::new("."));
+
+    run_once(args, verbose, true)?;
+    println!("Watching {}… (Ctrl-C to stop)", watch_root.display());
+
+    let (tx, rx) = channel();
+    let mut watcher = notify::recommended_watcher(move |res| {
+        let _ = tx.send(res);
+    })
+    .context("Failed to start file watcher")?;
+    watcher
+        .watch(watch_root, RecursiveMode::Recursive)
+        .context("Failed to watch directory")?;
+
+    loop {
+        // Block for the first event, then drain anything else that arrives
+        // within the debounce window so a single save (which fires several
+        // OS events) triggers exactly one re-format.
+        if rx.recv().is_err() {
+            break;
+        }
+        while rx.recv_timeout(Duration::from_millis(200)).is_ok() {}
+
+        if let Err(e) = run_once(args, verbose, true) {
+            eprintln!("⚠️  format run failed: {}", e);
+        }
+        println!("Watching {}… (Ctrl-C to stop)", watch_root.display());
+    }
+
+    Ok(())
+}
+
+/// Format source read from stdin and write the formatted result straight to
+/// stdout, with no summary chrome — for editor/pre-commit pipe integration
+/// (mirrors `deno fmt -`). The detected formatter is driven in its own
+/// stdin-formatting mode; `--stdin-filepath <path>` only supplies the path
+/// used for language detection and is not itself sent to the formatter.
+fn run_stdin(args: &[String], verbose: u8) -> Result<()> {
+    use std::io::{Read, Write};
+
+    let stdin_filepath = args
+        .iter()
+        .position(|a| a == "--stdin-filepath")
+        .and_then(|i| args.get(i + 1))
+        .cloned();
+    let detect_args: Vec<String> = match &stdin_filepath {
+        Some(path) => vec![path.clone()],
+        None => Vec::new(),
+    };
+    let formatter = detect_formatter(&detect_args);
+
+    let mut source = String::new();
+    std::io::stdin()
+        .read_to_string(&mut source)
+        .context("Failed to read source from stdin")?;
+
+    let mut cmd = match formatter.as_str() {
+        "prettier" => package_manager_exec("prettier"),
+        "biome" => package_manager_exec("biome"),
+        _ => Command::new(formatter.as_str()),
+    };
+
+    match formatter.as_str() {
+        "black" => {
+            cmd.arg("-");
+        }
+        "ruff" => {
+            cmd.arg("format").arg("-");
+        }
+        "prettier" => {
+            cmd.arg("--stdin-filepath")
+                .arg(stdin_filepath.as_deref().unwrap_or("stdin.txt"));
+        }
+        "biome" => {
+            cmd.arg("format")
+                .arg("--stdin-file-path")
+                .arg(stdin_filepath.as_deref().unwrap_or("stdin.txt"));
+        }
+        _ => {}
+    }
+
+    if verbose > 0 {
+        eprintln!("Formatting stdin via {}", formatter);
+    }
+
+    cmd.stdin(std::process::Stdio::piped());
+    cmd.stdout(std::process::Stdio::piped());
+    cmd.stderr(std::process::Stdio::piped());
+
+    let mut child = cmd
+        .spawn()
+        .with_context(|| format!("Failed to run {} in stdin mode", formatter))?;
+    child
+        .stdin
+        .take()
+        .expect("piped stdin")
+        .write_all(source.as_bytes())
+        .context("Failed to write source to formatter stdin")?;
+    let output = child
+        .wait_with_output()
+        .context("Failed to read formatter output")?;
+
+    if !output.status.success() {
+        // Three-tier degradation: the formatter can't consume stdin (or
+        // failed on this input) — fall back to echoing the original source
+        // unchanged rather than losing it.
+        eprintln!(
+            "⚠️  {} failed on stdin, passing source through unchanged: {}",
+            formatter,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        print!("{}", source);
+        return Ok(());
+    }
+
+    std::io::stdout().write_all(&output.stdout)?;
+    Ok(())
+}
+
+fn run_once(args: &[String], verbose: u8, watch_mode: bool) -> Result<()> {
+    if args.iter().any(|a| a == "-" || a == "--stdin-filepath") {
+        return run_stdin(args, verbose);
+    }
+
+    let (format_options, args) = FormatOptions::resolve(args, Path::new("."));
+    let args = &args[..];
+
     let timer = tracking::TimedExecution::start();
 
     // Detect formatter
@@ -80,10 +422,51 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
     // Add formatter-specific flags
     let user_args = args[start_idx..].to_vec();
 
+    let show_diff = user_args.iter().any(|a| a == "--diff");
+    let mut excludes = Vec::new();
+    let mut positional = Vec::new();
+    let mut flags = Vec::new();
+    let mut i = 0;
+    while i < user_args.len() {
+        let arg = &user_args[i];
+        if arg == "--diff" {
+            i += 1;
+        } else if arg == "--ignore" {
+            if let Some(pattern) = user_args.get(i + 1) {
+                excludes.push(pattern.clone());
+            }
+            i += 2;
+        } else if arg.starts_with('-') {
+            flags.push(arg.clone());
+            i += 1;
+        } else {
+            positional.push(arg.clone());
+            i += 1;
+        }
+    }
+
+    // When exclude globs are given, resolve the target file list ourselves
+    // (pruning excluded subtrees during the walk) instead of handing the
+    // raw include globs straight to the formatter, which doesn't understand
+    // `--ignore` uniformly across black/ruff/prettier/biome.
+    let user_args: Vec<String> = if excludes.is_empty() {
+        let mut a = flags;
+        a.extend(positional);
+        a
+    } else {
+        let collector = FileCollector::new(positional, excludes);
+        let files = collector.collect()?;
+        let mut a = flags;
+        a.extend(files.iter().map(|p| p.display().to_string()));
+        a
+    };
+
     match formatter.as_str() {
         "black" => {
-            // Inject --check if not present for check mode
-            if !user_args.iter().any(|a| a == "--check" || a == "--diff") {
+            if show_diff {
+                cmd.arg("--diff");
+            } else if !user_args.iter().any(|a| a == "--check") {
+                // Inject --check if not present for check mode
                 cmd.arg("--check");
             }
         }
@@ -92,10 +475,20 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
             if user_args.is_empty() || !user_args[0].starts_with("format") {
                 cmd.arg("format");
             }
+            if show_diff {
+                cmd.arg("--diff");
+            }
+        }
+        "prettier" if show_diff => {
+            // prettier has no built-in diff flag; fall back to --check so
+            // the user at least sees which files would change.
+            cmd.arg("--check");
         }
         _ => {}
     }
 
+    format_options.apply(&formatter, &mut cmd);
+
     // Add user arguments
     for arg in &user_args {
         cmd.arg(arg);
@@ -120,11 +513,15 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
     let raw = format!("{}\n{}", stdout, stderr);
 
     // Dispatch to appropriate filter based on formatter
-    let filtered = match formatter.as_str() {
-        "prettier" => prettier_cmd::filter_prettier_output(&raw),
-        "ruff" => ruff_cmd::filter_ruff_format(&raw),
-        "black" => filter_black_output(&raw),
-        _ => raw.trim().to_string(),
+    let filtered = if show_diff {
+        compact_diff_output(&raw)
+    } else {
+        match formatter.as_str() {
+            "prettier" => prettier_cmd::filter_prettier_output(&raw),
+            "ruff" => ruff_cmd::filter_ruff_format(&raw),
+            "black" => filter_black_output(&raw),
+            _ => raw.trim().to_string(),
+        }
     };
 
     println!("{}", filtered);
@@ -136,14 +533,56 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
         &filtered,
     );
 
-    // Preserve exit code for CI/CD
-    if !output.status.success() {
+    // Preserve exit code for CI/CD. In watch mode a non-zero run just means
+    // "files still need formatting" and must not kill the watcher.
+    if !output.status.success() && !watch_mode {
         std::process::exit(output.status.code().unwrap_or(1));
     }
 
     Ok(())
 }
 
+/// Render a formatter's unified diff output (black/ruff `--diff`) compactly:
+/// each file's hunk is capped at `MAX_DIFF_CHARS_PER_FILE` so one huge
+/// rewrite can't blow the token budget, with the `[RTK:PASSTHROUGH]` marker
+/// from `truncate_output` standing in for what got cut.
+fn compact_diff_output(output: &str) -> String {
+    let mut files: Vec<(String, String)> = Vec::new();
+    let mut current_file: Option<String> = None;
+    let mut current_hunk = String::new();
+
+    for line in output.lines() {
+        if let Some(path) = line.strip_prefix("--- ") {
+            if let Some(name) = current_file.take() {
+                files.push((name, std::mem::take(&mut current_hunk)));
+            }
+            current_file = Some(path.trim().to_string());
+            continue;
+        }
+        if current_file.is_some() {
+            current_hunk.push_str(line);
+            current_hunk.push('\n');
+        }
+    }
+    if let Some(name) = current_file.take() {
+        files.push((name, current_hunk));
+    }
+
+    if files.is_empty() {
+        return output.trim().to_string();
+    }
+
+    let mut result = format!("Format diff: {} file(s) would change\n", files.len());
+    result.push_str("═══════════════════════════════════════\n");
+    for (name, hunk) in &files {
+        result.push_str(&format!("\n{}\n", compact_path(name)));
+        result.push_str(&truncate_output(hunk.trim_end(), MAX_DIFF_CHARS_PER_FILE));
+        result.push('\n');
+    }
+
+    result.trim().to_string()
+}
+
 /// Filter black output - show files that need formatting
 fn filter_black_output(output: &str) -> String {
     let mut files_to_format: Vec<String> = Vec::new();
@@ -1,9 +1,14 @@
 use crate::display_helpers::{format_duration, print_period_table};
-use crate::tracking::{DayStats, MonthStats, Tracker, WeekStats};
-use crate::utils::format_tokens;
+use crate::pricing;
+use crate::style::Style;
+use crate::time_range;
+use crate::tracking::{CommandRecordDetailed, DayStats, GainSummary, MonthStats, Tracker, WeekStats};
+use crate::utils::{format_tokens, format_usd, truncate};
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use serde::Serialize;
 
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     graph: bool,
     history: bool,
@@ -13,42 +18,108 @@ pub fn run(
     weekly: bool,
     monthly: bool,
     all: bool,
+    since: Option<&str>,
+    range: Option<&str>,
     format: &str,
+    serve_metrics: Option<&str>,
+    interactive: bool,
+    export: Option<&str>,
+    export_limit: usize,
+    list: bool,
+    list_command: Option<&str>,
+    min_savings: Option<f64>,
+    sort: &str,
+    limit: usize,
+    model: Option<&str>,
+    input_price: Option<f64>,
+    output_price: Option<f64>,
+    color: &str,
     _verbose: u8,
 ) -> Result<()> {
+    if let Some(addr) = serve_metrics {
+        return serve_prometheus_metrics(addr);
+    }
+
+    if interactive {
+        return launch_interactive();
+    }
+
+    if let Some(export_format) = export {
+        let tracker = Tracker::new().context("Failed to initialize tracking database")?;
+        return tracker
+            .export_history(export_format, export_limit, &mut std::io::stdout())
+            .context("Failed to export command history");
+    }
+
     let tracker = Tracker::new().context("Failed to initialize tracking database")?;
+    let window = resolve_window(since, range)?;
+    let rates = pricing::resolve_rates(model, input_price, output_price);
+
+    if list {
+        let records = tracker
+            .list_records(list_command, min_savings, window, sort, limit)
+            .context("Failed to query command records")?;
+        return match format {
+            "json" => crate::tracking::export_history_json(&records, &mut std::io::stdout()),
+            "csv" => crate::tracking::export_history_csv(&records, &mut std::io::stdout()),
+            _ => {
+                print_list_table(&records);
+                Ok(())
+            }
+        };
+    }
 
     // Handle export formats
     match format {
-        "json" => return export_json(&tracker, daily, weekly, monthly, all),
-        "csv" => return export_csv(&tracker, daily, weekly, monthly, all),
+        "json" => return export_json(&tracker, daily, weekly, monthly, all, window, rates),
+        "csv" => return export_csv(&tracker, daily, weekly, monthly, all, window),
+        "prometheus" => {
+            print!("{}", tracker.export_prometheus()?);
+            return Ok(());
+        }
         _ => {} // Continue with text format
     }
 
-    let summary = tracker
-        .get_summary()
-        .context("Failed to load token savings summary from database")?;
+    let summary = match window {
+        Some((from, to)) => tracker
+            .get_stats_for_range(from, to)
+            .context("Failed to load token savings summary for the requested window")?,
+        None => tracker
+            .get_summary()
+            .context("Failed to load token savings summary from database")?,
+    };
 
     if summary.total_commands == 0 {
-        println!("No tracking data yet.");
-        println!("Run some rtk commands to start tracking savings.");
+        if window.is_some() {
+            println!("No tracking data in the requested window.");
+        } else {
+            println!("No tracking data yet.");
+            println!("Run some rtk commands to start tracking savings.");
+        }
         return Ok(());
     }
 
     // Default view (summary)
     if !daily && !weekly && !monthly && !all {
+        let style = Style::resolve(color);
         println!("📊 RTK Token Savings");
-        println!("════════════════════════════════════════");
+        println!("{}", style.dim("════════════════════════════════════════"));
         println!();
 
         println!("Total commands:    {}", summary.total_commands);
         println!("Input tokens:      {}", format_tokens(summary.total_input));
         println!("Output tokens:     {}", format_tokens(summary.total_output));
-        println!(
-            "Tokens saved:      {} ({:.1}%)",
+        let saved_line = format!(
+            "{} ({:.1}%)",
             format_tokens(summary.total_saved),
             summary.avg_savings_pct
         );
+        let saved_line = if summary.avg_savings_pct >= crate::style::GOOD_SAVINGS_PCT {
+            style.green(&saved_line)
+        } else {
+            saved_line
+        };
+        println!("Tokens saved:      {saved_line}");
         println!(
             "Total exec time:   {} (avg {})",
             format_duration(summary.total_time_ms),
@@ -58,7 +129,7 @@ pub fn run(
 
         if !summary.by_command.is_empty() {
             println!("By Command:");
-            println!("────────────────────────────────────────");
+            println!("{}", style.dim("────────────────────────────────────────"));
             println!(
                 "{:<20} {:>6} {:>10} {:>8} {:>8}",
                 "Command", "Count", "Saved", "Avg%", "Time"
@@ -82,9 +153,14 @@ pub fn run(
         }
 
         if graph && !summary.by_day.is_empty() {
-            println!("Daily Savings (last 30 days):");
-            println!("────────────────────────────────────────");
-            print_ascii_graph(&summary.by_day);
+            let heading = if window.is_some() {
+                "Daily Savings (requested window):".to_string()
+            } else {
+                "Daily Savings (last 30 days):".to_string()
+            };
+            println!("{heading}");
+            println!("{}", style.dim("────────────────────────────────────────"));
+            print_ascii_graph(&summary.by_day, style);
             println!();
         }
 
@@ -92,7 +168,7 @@ pub fn run(
             let recent = tracker.get_recent(10)?;
             if !recent.is_empty() {
                 println!("Recent Commands:");
-                println!("────────────────────────────────────────");
+                println!("{}", style.dim("────────────────────────────────────────"));
                 for rec in recent {
                     let time = rec.timestamp.format("%m-%d %H:%M");
                     let cmd_short = if rec.rtk_cmd.len() > 25 {
@@ -123,9 +199,16 @@ pub fn run(
             };
 
             let quota_pct = (summary.total_saved as f64 / quota_tokens as f64) * 100.0;
+            let this_month_saved = tracker
+                .get_by_month()?
+                .first()
+                .map(|m| m.saved_tokens)
+                .unwrap_or(0);
+            let cost_preserved_this_month =
+                pricing::estimate_usd(this_month_saved, rates.output_per_million);
 
             println!("Monthly Quota Analysis:");
-            println!("────────────────────────────────────────");
+            println!("{}", style.dim("────────────────────────────────────────"));
             println!("Subscription tier:        {}", tier_name);
             println!("Estimated monthly quota:  {}", format_tokens(quota_tokens));
             println!(
@@ -133,6 +216,11 @@ pub fn run(
                 format_tokens(summary.total_saved)
             );
             println!("Quota preserved:          {:.1}%", quota_pct);
+            println!(
+                "Cost preserved (month):   {} ({} pricing)",
+                format_usd(cost_preserved_this_month),
+                rates.model
+            );
             println!();
             println!("Note: Heuristic estimate based on ~44K tokens/5h (Pro baseline)");
             println!("      Actual limits use rolling 5-hour windows, not monthly caps.");
@@ -143,21 +231,77 @@ pub fn run(
 
     // Time breakdown views
     if all || daily {
-        print_daily_full(&tracker)?;
+        print_daily_full(&tracker, window)?;
     }
 
     if all || weekly {
-        print_weekly(&tracker)?;
+        print_weekly(&tracker, window)?;
     }
 
     if all || monthly {
-        print_monthly(&tracker)?;
+        print_monthly(&tracker, window)?;
     }
 
     Ok(())
 }
 
-fn print_ascii_graph(data: &[(String, usize)]) {
+/// Resolve `--since`/`--range` into a concrete `[from, to)` window, if given.
+///
+/// `clap` already enforces that `--since` and `--range` are mutually
+/// exclusive via `conflicts_with`.
+fn resolve_window(
+    since: Option<&str>,
+    range: Option<&str>,
+) -> Result<Option<(DateTime<Utc>, DateTime<Utc>)>> {
+    let now = Utc::now();
+
+    if let Some(expr) = since {
+        return Ok(Some(time_range::parse_since(expr, now)?));
+    }
+
+    if let Some(expr) = range {
+        return Ok(Some(time_range::parse_range(expr, now)?));
+    }
+
+    Ok(None)
+}
+
+/// Start the `metrics-server` feature's built-in `/metrics` HTTP endpoint,
+/// or explain why it can't when rtk was built without that feature.
+fn serve_prometheus_metrics(addr: &str) -> Result<()> {
+    #[cfg(feature = "metrics-server")]
+    {
+        return crate::metrics_server::serve(addr);
+    }
+
+    #[cfg(not(feature = "metrics-server"))]
+    {
+        let _ = addr;
+        anyhow::bail!(
+            "--serve-metrics requires rtk built with the `metrics-server` feature"
+        );
+    }
+}
+
+/// Launch the `tui` feature's interactive history browser, or explain why
+/// it can't when rtk was built without that feature.
+fn launch_interactive() -> Result<()> {
+    #[cfg(feature = "tui")]
+    {
+        return crate::tui::run();
+    }
+
+    #[cfg(not(feature = "tui"))]
+    {
+        anyhow::bail!("--interactive requires rtk built with the `tui` feature");
+    }
+}
+
+/// Colors each bar by its magnitude relative to the series max (green for
+/// the top third, yellow for the middle, dim for the rest). The color
+/// wraps the already-built bar string, so it never touches `width` and
+/// can't throw off column alignment.
+fn print_ascii_graph(data: &[(String, usize)], style: Style) {
     if data.is_empty() {
         return;
     }
@@ -168,13 +312,21 @@ fn print_ascii_graph(data: &[(String, usize)]) {
     for (date, value) in data {
         let date_short = if date.len() >= 10 { &date[5..10] } else { date };
 
-        let bar_len = if max_val > 0 {
-            ((*value as f64 / max_val as f64) * width as f64) as usize
+        let ratio = if max_val > 0 {
+            *value as f64 / max_val as f64
         } else {
-            0
+            0.0
         };
+        let bar_len = (ratio * width as f64) as usize;
 
         let bar: String = "█".repeat(bar_len);
+        let bar = if ratio >= 0.66 {
+            style.green(&bar)
+        } else if ratio >= 0.33 {
+            style.yellow(&bar)
+        } else {
+            style.dim(&bar)
+        };
         let spaces: String = " ".repeat(width - bar_len);
 
         println!(
@@ -187,20 +339,57 @@ fn print_ascii_graph(data: &[(String, usize)]) {
     }
 }
 
-fn print_daily_full(tracker: &Tracker) -> Result<()> {
-    let days = tracker.get_all_days()?;
+fn print_list_table(records: &[CommandRecordDetailed]) {
+    if records.is_empty() {
+        println!("No matching command records.");
+        return;
+    }
+
+    println!("Matching Commands:");
+    println!("────────────────────────────────────────────────────────────");
+    println!(
+        "{:<12} {:<25} {:>10} {:>7} {:>8}",
+        "Time", "Command", "Saved", "Pct", "Exec"
+    );
+    for rec in records {
+        let time = rec.timestamp.format("%m-%d %H:%M");
+        println!(
+            "{} {:<25} {:>10} {:>6.1}% {:>8}",
+            time,
+            truncate(&rec.rtk_cmd, 25),
+            format_tokens(rec.saved_tokens),
+            rec.savings_pct,
+            format_duration(rec.exec_time_ms)
+        );
+    }
+}
+
+fn print_daily_full(
+    tracker: &Tracker,
+    window: Option<(DateTime<Utc>, DateTime<Utc>)>,
+) -> Result<()> {
+    let days = match window {
+        Some((from, to)) => tracker.get_all_days_range(from, to)?,
+        None => tracker.get_all_days()?,
+    };
     print_period_table(&days);
     Ok(())
 }
 
-fn print_weekly(tracker: &Tracker) -> Result<()> {
-    let weeks = tracker.get_by_week()?;
+fn print_weekly(tracker: &Tracker, window: Option<(DateTime<Utc>, DateTime<Utc>)>) -> Result<()> {
+    let weeks = match window {
+        Some((from, to)) => tracker.get_by_week_range(from, to)?,
+        None => tracker.get_by_week()?,
+    };
     print_period_table(&weeks);
     Ok(())
 }
 
-fn print_monthly(tracker: &Tracker) -> Result<()> {
-    let months = tracker.get_by_month()?;
+fn print_monthly(tracker: &Tracker, window: Option<(DateTime<Utc>, DateTime<Utc>)>) -> Result<()> {
+    let months = match window {
+        Some((from, to)) => tracker.get_by_month_range(from, to)?,
+        None => tracker.get_by_month()?,
+    };
     print_period_table(&months);
     Ok(())
 }
@@ -225,6 +414,8 @@ struct ExportSummary {
     avg_savings_pct: f64,
     total_time_ms: u64,
     avg_time_ms: u64,
+    estimated_savings_usd: f64,
+    pricing_model: &'static str,
 }
 
 fn export_json(
@@ -233,10 +424,17 @@ fn export_json(
     weekly: bool,
     monthly: bool,
     all: bool,
+    window: Option<(DateTime<Utc>, DateTime<Utc>)>,
+    rates: pricing::ModelPricing,
 ) -> Result<()> {
-    let summary = tracker
-        .get_summary()
-        .context("Failed to load token savings summary from database")?;
+    let summary: GainSummary = match window {
+        Some((from, to)) => tracker
+            .get_stats_for_range(from, to)
+            .context("Failed to load token savings summary for the requested window")?,
+        None => tracker
+            .get_summary()
+            .context("Failed to load token savings summary from database")?,
+    };
 
     let export = ExportData {
         summary: ExportSummary {
@@ -247,19 +445,33 @@ fn export_json(
             avg_savings_pct: summary.avg_savings_pct,
             total_time_ms: summary.total_time_ms,
             avg_time_ms: summary.avg_time_ms,
+            estimated_savings_usd: pricing::estimate_usd(
+                summary.total_saved,
+                rates.output_per_million,
+            ),
+            pricing_model: rates.model,
         },
         daily: if all || daily {
-            Some(tracker.get_all_days()?)
+            Some(match window {
+                Some((from, to)) => tracker.get_all_days_range(from, to)?,
+                None => tracker.get_all_days()?,
+            })
         } else {
             None
         },
         weekly: if all || weekly {
-            Some(tracker.get_by_week()?)
+            Some(match window {
+                Some((from, to)) => tracker.get_by_week_range(from, to)?,
+                None => tracker.get_by_week()?,
+            })
         } else {
             None
         },
         monthly: if all || monthly {
-            Some(tracker.get_by_month()?)
+            Some(match window {
+                Some((from, to)) => tracker.get_by_month_range(from, to)?,
+                None => tracker.get_by_month()?,
+            })
         } else {
             None
         },
@@ -277,9 +489,13 @@ fn export_csv(
     weekly: bool,
     monthly: bool,
     all: bool,
+    window: Option<(DateTime<Utc>, DateTime<Utc>)>,
 ) -> Result<()> {
     if all || daily {
-        let days = tracker.get_all_days()?;
+        let days = match window {
+            Some((from, to)) => tracker.get_all_days_range(from, to)?,
+            None => tracker.get_all_days()?,
+        };
         println!("# Daily Data");
         println!("date,commands,input_tokens,output_tokens,saved_tokens,savings_pct,total_time_ms,avg_time_ms");
         for day in days {
@@ -299,7 +515,10 @@ fn export_csv(
     }
 
     if all || weekly {
-        let weeks = tracker.get_by_week()?;
+        let weeks = match window {
+            Some((from, to)) => tracker.get_by_week_range(from, to)?,
+            None => tracker.get_by_week()?,
+        };
         println!("# Weekly Data");
         println!(
             "week_start,week_end,commands,input_tokens,output_tokens,saved_tokens,savings_pct,total_time_ms,avg_time_ms"
@@ -322,7 +541,10 @@ fn export_csv(
     }
 
     if all || monthly {
-        let months = tracker.get_by_month()?;
+        let months = match window {
+            Some((from, to)) => tracker.get_by_month_range(from, to)?,
+            None => tracker.get_by_month()?,
+        };
         println!("# Monthly Data");
         println!("month,commands,input_tokens,output_tokens,saved_tokens,savings_pct,total_time_ms,avg_time_ms");
         for month in months {
@@ -0,0 +1,105 @@
+//! On-disk response cache for `gh_cmd`'s hottest read paths (`pr list`,
+//! `pr view`, `run list`), so an agent loop re-running the same query a few
+//! seconds apart skips the subprocess/API round trip entirely within a
+//! short TTL. Keyed by repo + subcommand + the passthrough args, stored as
+//! one JSON file per key under the crate's existing data directory (see
+//! `tracking::get_db_path`'s `dirs::data_local_dir()` convention).
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    fetched_at: u64,
+    json: String,
+}
+
+fn cache_dir() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("rtk")
+        .join("gh_cache")
+}
+
+fn cache_key(subcommand: &str, args: &[String]) -> String {
+    let repo = crate::git::resolve_github_repo()
+        .map(|(owner, repo)| format!("{owner}/{repo}"))
+        .unwrap_or_else(|_| "unknown".to_string());
+
+    let mut hasher = DefaultHasher::new();
+    args.hash(&mut hasher);
+    let args_hash = hasher.finish();
+
+    format!(
+        "{}_{}_{:x}",
+        repo.replace('/', "_"),
+        subcommand.replace(' ', "_"),
+        args_hash
+    )
+}
+
+/// Read a cached response for `subcommand args` if one exists and is still
+/// within `ttl`. `Duration::ZERO` (i.e. `--no-cache`/`--refresh`) always
+/// misses, matching the zero-capacity-cache idiom used for "disabled".
+pub fn get(subcommand: &str, args: &[String], ttl: Duration) -> Option<String> {
+    if ttl.is_zero() {
+        return None;
+    }
+    let path = cache_dir().join(cache_key(subcommand, args));
+    let contents = std::fs::read_to_string(path).ok()?;
+    let entry: CacheEntry = serde_json::from_str(&contents).ok()?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+    if now.saturating_sub(entry.fetched_at) > ttl.as_secs() {
+        return None;
+    }
+    Some(entry.json)
+}
+
+/// Store `json` under `subcommand args`'s cache key, stamped with the
+/// current time. Failures to write are non-fatal to callers - caching is a
+/// best-effort speedup, not a correctness requirement.
+pub fn put(subcommand: &str, args: &[String], json: &str) -> Result<()> {
+    let dir = cache_dir();
+    std::fs::create_dir_all(&dir).context("Failed to create gh cache directory")?;
+    let path = dir.join(cache_key(subcommand, args));
+    let entry = CacheEntry {
+        fetched_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs(),
+        json: json.to_string(),
+    };
+    std::fs::write(path, serde_json::to_string(&entry)?)
+        .context("Failed to write gh cache entry")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_key_is_stable_for_same_args() {
+        let a = cache_key("pr list", &["--state".to_string(), "open".to_string()]);
+        let b = cache_key("pr list", &["--state".to_string(), "open".to_string()]);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_cache_key_differs_by_subcommand_and_args() {
+        let a = cache_key("pr list", &[]);
+        let b = cache_key("run list", &[]);
+        let c = cache_key("pr list", &["--state".to_string(), "closed".to_string()]);
+        assert_ne!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_zero_ttl_always_misses() {
+        assert!(get("pr list", &[], Duration::ZERO).is_none());
+    }
+}
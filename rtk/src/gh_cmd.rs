@@ -3,39 +3,145 @@
 //! Provides token-optimized alternatives to verbose `gh` commands.
 //! Focuses on extracting essential information from JSON outputs.
 
+use crate::gh_cache;
 use crate::git;
+use crate::github_api;
 use crate::json_cmd;
+use crate::picker;
+use crate::redact;
 use crate::tracking;
 use crate::utils::{ok_confirmation, truncate};
 use anyhow::{Context, Result};
+use serde::Serialize;
 use serde_json::Value;
-use std::process::Command;
+use std::collections::HashMap;
+use std::process::{Command, Output};
+use std::time::{Duration, Instant};
+
+/// Output mode for gh subcommands: the emoji-decorated text
+/// [`run`] defaults to for interactive use, or a stable typed record per
+/// entity (`json` for one aggregate payload, `ndjson` for one line per
+/// entity) so automation can consume output without re-parsing text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Text,
+    Json,
+    Ndjson,
+}
+
+impl OutputFormat {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            "ndjson" => Some(Self::Ndjson),
+            _ => None,
+        }
+    }
+}
+
+/// Strip a `--format json|ndjson|text` flag out of a gh subcommand's
+/// argument list, defaulting to [`OutputFormat::Text`] when absent or
+/// unrecognized.
+fn extract_format(args: &[String]) -> (OutputFormat, Vec<String>) {
+    let mut format = OutputFormat::Text;
+    let mut rest = Vec::with_capacity(args.len());
+    let mut iter = args.iter().cloned();
+    while let Some(arg) = iter.next() {
+        if arg == "--format" {
+            if let Some(value) = iter.next() {
+                if let Some(parsed) = OutputFormat::parse(&value) {
+                    format = parsed;
+                }
+            }
+        } else {
+            rest.push(arg);
+        }
+    }
+    (format, rest)
+}
+
+/// Wraps a `gh` subprocess invocation with timing and exit status, so
+/// `--format json` callers get the same data `tracking::TimedExecution`
+/// records against alongside the parsed payload, not just the payload
+/// itself.
+#[derive(Debug, Serialize)]
+struct RunResult<T: Serialize> {
+    duration_ms: u64,
+    return_code: i32,
+    stdout_bytes: usize,
+    stderr: String,
+    parsed: T,
+}
+
+impl<T: Serialize> RunResult<T> {
+    fn from_output(started: Instant, output: &Output, parsed: T) -> Self {
+        Self {
+            duration_ms: started.elapsed().as_millis() as u64,
+            return_code: output.status.code().unwrap_or(-1),
+            stdout_bytes: output.stdout.len(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            parsed,
+        }
+    }
+
+    /// For backends with no subprocess `Output` to report against (e.g. the
+    /// native GraphQL client) - a successful call by definition, so
+    /// `return_code` is 0 and `stderr` is empty.
+    fn synthetic(started: Instant, stdout_bytes: usize, parsed: T) -> Self {
+        Self {
+            duration_ms: started.elapsed().as_millis() as u64,
+            return_code: 0,
+            stdout_bytes,
+            stderr: String::new(),
+            parsed,
+        }
+    }
+}
+
+/// Emit a `--format json` result as one JSON object (timing/exit-status
+/// metadata plus the parsed payload).
+fn emit_json<T: Serialize>(result: &RunResult<T>) -> Result<()> {
+    println!("{}", serde_json::to_string(result)?);
+    Ok(())
+}
+
+/// Emit a `--format ndjson` result as one line per entity, with no
+/// wrapper metadata, so it streams straight into line-oriented tools.
+fn emit_ndjson<T: Serialize>(items: &[T]) -> Result<()> {
+    for item in items {
+        println!("{}", serde_json::to_string(item)?);
+    }
+    Ok(())
+}
 
 /// Run a gh command with token-optimized output
 pub fn run(subcommand: &str, args: &[String], verbose: u8, ultra_compact: bool) -> Result<()> {
+    let (format, args) = extract_format(args);
     match subcommand {
-        "pr" => run_pr(args, verbose, ultra_compact),
-        "issue" => run_issue(args, verbose, ultra_compact),
-        "run" => run_workflow(args, verbose, ultra_compact),
-        "repo" => run_repo(args, verbose, ultra_compact),
-        "api" => run_api(args, verbose),
+        "pr" => run_pr(&args, verbose, ultra_compact, format),
+        "issue" => run_issue(&args, verbose, ultra_compact, format),
+        "run" => run_workflow(&args, verbose, ultra_compact, format),
+        "repo" => run_repo(&args, verbose, ultra_compact, format),
+        "api" => run_api(&args, verbose),
+        "webhook" => run_webhook(&args),
         _ => {
             // Unknown subcommand, pass through
-            run_passthrough("gh", subcommand, args)
+            run_passthrough("gh", subcommand, &args)
         }
     }
 }
 
-fn run_pr(args: &[String], verbose: u8, ultra_compact: bool) -> Result<()> {
+fn run_pr(args: &[String], verbose: u8, ultra_compact: bool, format: OutputFormat) -> Result<()> {
     if args.is_empty() {
         return run_passthrough("gh", "pr", args);
     }
 
     match args[0].as_str() {
-        "list" => list_prs(&args[1..], verbose, ultra_compact),
-        "view" => view_pr(&args[1..], verbose, ultra_compact),
+        "list" => list_prs(&args[1..], verbose, ultra_compact, format),
+        "view" => view_pr(&args[1..], verbose, ultra_compact, format),
         "checks" => pr_checks(&args[1..], verbose, ultra_compact),
-        "status" => pr_status(verbose, ultra_compact),
+        "status" => pr_status(verbose, ultra_compact, format),
         "create" => pr_create(&args[1..], verbose),
         "merge" => pr_merge(&args[1..], verbose),
         "diff" => pr_diff(&args[1..], verbose),
@@ -45,35 +151,76 @@ fn run_pr(args: &[String], verbose: u8, ultra_compact: bool) -> Result<()> {
     }
 }
 
-fn list_prs(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
+/// One `--format json`/`ndjson` record per PR listed by `gh pr list`.
+#[derive(Debug, Serialize)]
+struct PrListEntry {
+    number: i64,
+    title: String,
+    state: String,
+    author: String,
+    updated_at: String,
+}
+
+fn list_prs(args: &[String], _verbose: u8, ultra_compact: bool, format: OutputFormat) -> Result<()> {
     let timer = tracking::TimedExecution::start();
+    let started = Instant::now();
+    let (bypass_cache, args) = extract_cache_flags(args);
+
+    let cached = gh_cache::get("pr list", &args, if bypass_cache { Duration::ZERO } else { cache_ttl() });
+    let (raw, output) = match cached {
+        Some(raw) => (raw, None),
+        None => {
+            let mut cmd = Command::new("gh");
+            cmd.args(["pr", "list", "--json", "number,title,state,author,updatedAt"]);
+
+            // Pass through additional flags
+            for arg in &args {
+                cmd.arg(arg);
+            }
 
-    let mut cmd = Command::new("gh");
-    cmd.args([
-        "pr",
-        "list",
-        "--json",
-        "number,title,state,author,updatedAt",
-    ]);
+            let output = cmd.output().context("Failed to run gh pr list")?;
+            let raw = String::from_utf8_lossy(&output.stdout).to_string();
 
-    // Pass through additional flags
-    for arg in args {
-        cmd.arg(arg);
-    }
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+                timer.track("gh pr list", "rtk gh pr list", &stderr, &stderr);
+                eprintln!("{}", stderr.trim());
+                std::process::exit(output.status.code().unwrap_or(1));
+            }
 
-    let output = cmd.output().context("Failed to run gh pr list")?;
-    let raw = String::from_utf8_lossy(&output.stdout).to_string();
+            let _ = gh_cache::put("pr list", &args, &raw);
+            (raw, Some(output))
+        }
+    };
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-        timer.track("gh pr list", "rtk gh pr list", &stderr, &stderr);
-        eprintln!("{}", stderr.trim());
-        std::process::exit(output.status.code().unwrap_or(1));
+    let json: Value = serde_json::from_str(&raw).context("Failed to parse gh pr list output")?;
+
+    if format != OutputFormat::Text {
+        let entries: Vec<PrListEntry> = json
+            .as_array()
+            .into_iter()
+            .flatten()
+            .take(20)
+            .map(|pr| PrListEntry {
+                number: pr["number"].as_i64().unwrap_or(0),
+                title: pr["title"].as_str().unwrap_or("???").to_string(),
+                state: pr["state"].as_str().unwrap_or("???").to_string(),
+                author: pr["author"]["login"].as_str().unwrap_or("???").to_string(),
+                updated_at: pr["updatedAt"].as_str().unwrap_or("").to_string(),
+            })
+            .collect();
+
+        let filtered = serde_json::to_string(&entries).unwrap_or_default();
+        timer.track("gh pr list", "rtk gh pr list", &raw, &filtered);
+        return match format {
+            OutputFormat::Ndjson => emit_ndjson(&entries),
+            _ => emit_json(&match &output {
+                Some(output) => RunResult::from_output(started, output, entries),
+                None => RunResult::synthetic(started, raw.len(), entries),
+            }),
+        };
     }
 
-    let json: Value =
-        serde_json::from_slice(&output.stdout).context("Failed to parse gh pr list output")?;
-
     let mut filtered = String::new();
 
     if let Some(prs) = json.as_array() {
@@ -129,14 +276,140 @@ fn list_prs(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
     Ok(())
 }
 
-fn view_pr(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
+/// A `--format json`/`ndjson` record for `gh pr view`, selecting exactly
+/// the fields the compact text summary renders.
+#[derive(Debug, Serialize)]
+struct PrSummary {
+    number: i64,
+    title: String,
+    state: String,
+    author: String,
+    mergeable: String,
+    reviews_approved: usize,
+    reviews_changes: usize,
+    checks_passed: usize,
+    checks_total: usize,
+    checks_failed: usize,
+    url: String,
+}
+
+impl PrSummary {
+    fn from_json(json: &Value) -> Self {
+        let reviews = json["reviews"]["nodes"].as_array();
+        let reviews_approved = reviews
+            .map(|r| r.iter().filter(|r| r["state"].as_str() == Some("APPROVED")).count())
+            .unwrap_or(0);
+        let reviews_changes = reviews
+            .map(|r| {
+                r.iter()
+                    .filter(|r| r["state"].as_str() == Some("CHANGES_REQUESTED"))
+                    .count()
+            })
+            .unwrap_or(0);
+
+        let checks = json["statusCheckRollup"].as_array();
+        let checks_total = checks.map(|c| c.len()).unwrap_or(0);
+        let checks_passed = checks
+            .map(|c| {
+                c.iter()
+                    .filter(|c| {
+                        c["conclusion"].as_str() == Some("SUCCESS")
+                            || c["state"].as_str() == Some("SUCCESS")
+                    })
+                    .count()
+            })
+            .unwrap_or(0);
+        let checks_failed = checks
+            .map(|c| {
+                c.iter()
+                    .filter(|c| {
+                        c["conclusion"].as_str() == Some("FAILURE")
+                            || c["state"].as_str() == Some("FAILURE")
+                    })
+                    .count()
+            })
+            .unwrap_or(0);
+
+        Self {
+            number: json["number"].as_i64().unwrap_or(0),
+            title: json["title"].as_str().unwrap_or("???").to_string(),
+            state: json["state"].as_str().unwrap_or("???").to_string(),
+            author: json["author"]["login"].as_str().unwrap_or("???").to_string(),
+            mergeable: json["mergeable"].as_str().unwrap_or("UNKNOWN").to_string(),
+            reviews_approved,
+            reviews_changes,
+            checks_passed,
+            checks_total,
+            checks_failed,
+            url: json["url"].as_str().unwrap_or("").to_string(),
+        }
+    }
+}
+
+/// Fetch open PRs as picker candidates (`#number title (author)`) for
+/// `view_pr` when called with no PR number.
+fn fetch_pr_candidates() -> Result<Vec<picker::PickItem>> {
+    let output = Command::new("gh")
+        .args(["pr", "list", "--json", "number,title,author"])
+        .output()
+        .context("Failed to run gh pr list")?;
+    if !output.status.success() {
+        return Ok(Vec::new());
+    }
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap_or(Value::Null);
+    Ok(json
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|pr| {
+            let number = pr["number"].as_i64().unwrap_or(0);
+            let title = pr["title"].as_str().unwrap_or("???");
+            let author = pr["author"]["login"].as_str().unwrap_or("???");
+            picker::PickItem {
+                key: number.to_string(),
+                label: format!("#{} {} ({})", number, title, author),
+            }
+        })
+        .collect())
+}
+
+fn view_pr(args: &[String], _verbose: u8, ultra_compact: bool, format: OutputFormat) -> Result<()> {
     let timer = tracking::TimedExecution::start();
+    let started = Instant::now();
+
+    let pr_number = match args.first() {
+        Some(number) => number.clone(),
+        None => picker::pick(&fetch_pr_candidates()?)
+            .ok()
+            .flatten()
+            .ok_or_else(|| anyhow::anyhow!("PR number required"))?,
+    };
+    let pr_number = &pr_number;
+    let (bypass_cache, rest) = extract_cache_flags(args.get(1..).unwrap_or(&[]));
+    let cache_args = vec![pr_number.clone()];
 
-    if args.is_empty() {
-        return Err(anyhow::anyhow!("PR number required"));
+    if let Some(raw) = gh_cache::get("pr view", &cache_args, if bypass_cache { Duration::ZERO } else { cache_ttl() }) {
+        if let Ok(json) = serde_json::from_str::<Value>(&raw) {
+            return render_pr_view(&timer, started, None, pr_number, ultra_compact, format, &json, &raw);
+        }
     }
 
-    let pr_number = &args[0];
+    // Prefer the native GraphQL backend when a token is available: one round
+    // trip instead of a `gh` subprocess plus its own several API calls. Only
+    // takes the fast path for a plain numeric PR reference with no extra
+    // flags, since those change what `gh pr view` itself would do.
+    if rest.is_empty() {
+        if let Ok(number) = pr_number.parse::<u64>() {
+            if let Some(client) = github_api::GitHubClient::discover() {
+                if let Ok(json) = client.view_pr(number) {
+                    let raw = json.to_string();
+                    let _ = gh_cache::put("pr view", &cache_args, &raw);
+                    return render_pr_view(&timer, started, None, pr_number, ultra_compact, format, &json, &raw);
+                }
+                // Native lookup failed (rate limit, bad token, ...) - fall through to `gh`.
+            }
+        }
+    }
 
     let mut cmd = Command::new("gh");
     cmd.args([
@@ -146,6 +419,9 @@ fn view_pr(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
         "--json",
         "number,title,state,author,body,url,mergeable,reviews,statusCheckRollup",
     ]);
+    for arg in &rest {
+        cmd.arg(arg);
+    }
 
     let output = cmd.output().context("Failed to run gh pr view")?;
     let raw = String::from_utf8_lossy(&output.stdout).to_string();
@@ -164,6 +440,44 @@ fn view_pr(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
 
     let json: Value =
         serde_json::from_slice(&output.stdout).context("Failed to parse gh pr view output")?;
+    if rest.is_empty() {
+        let _ = gh_cache::put("pr view", &cache_args, &raw);
+    }
+    render_pr_view(&timer, started, Some(&output), pr_number, ultra_compact, format, &json, &raw)
+}
+
+/// Render the same compact PR summary regardless of which backend answered
+/// - the native GraphQL client or a `gh pr view --json` subprocess. `output`
+/// is `None` for the native client, which has no subprocess to report
+/// timing/exit status from in `--format json` mode.
+#[allow(clippy::too_many_arguments)]
+fn render_pr_view(
+    timer: &tracking::TimedExecution,
+    started: Instant,
+    output: Option<&Output>,
+    pr_number: &str,
+    ultra_compact: bool,
+    format: OutputFormat,
+    json: &Value,
+    raw: &str,
+) -> Result<()> {
+    if format != OutputFormat::Text {
+        let summary = PrSummary::from_json(json);
+        let filtered = serde_json::to_string(&summary).unwrap_or_default();
+        timer.track(
+            &format!("gh pr view {}", pr_number),
+            &format!("rtk gh pr view {}", pr_number),
+            raw,
+            &filtered,
+        );
+        return match format {
+            OutputFormat::Ndjson => emit_ndjson(std::slice::from_ref(&summary)),
+            _ => match output {
+                Some(output) => emit_json(&RunResult::from_output(started, output, summary)),
+                None => emit_json(&RunResult::synthetic(started, raw.len(), summary)),
+            },
+        };
+    }
 
     let mut filtered = String::new();
 
@@ -296,20 +610,24 @@ fn view_pr(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
     timer.track(
         &format!("gh pr view {}", pr_number),
         &format!("rtk gh pr view {}", pr_number),
-        &raw,
+        raw,
         &filtered,
     );
     Ok(())
 }
 
 fn pr_checks(args: &[String], _verbose: u8, _ultra_compact: bool) -> Result<()> {
-    let timer = tracking::TimedExecution::start();
-
     if args.is_empty() {
         return Err(anyhow::anyhow!("PR number required"));
     }
 
     let pr_number = &args[0];
+    let (watch, interval, _rest) = extract_watch_flags(&args[1..]);
+    if watch {
+        return pr_checks_watch(pr_number, interval);
+    }
+
+    let timer = tracking::TimedExecution::start();
 
     let mut cmd = Command::new("gh");
     cmd.args(["pr", "checks", pr_number]);
@@ -388,9 +706,335 @@ fn pr_checks(args: &[String], _verbose: u8, _ultra_compact: bool) -> Result<()>
     Ok(())
 }
 
-fn pr_status(_verbose: u8, _ultra_compact: bool) -> Result<()> {
+/// Strip `--watch` and an optional `--interval <secs>` out of a gh
+/// subcommand's trailing args, returning whether watch mode was requested,
+/// the poll interval (default 5s), and the remaining args.
+fn extract_watch_flags(args: &[String]) -> (bool, Duration, Vec<String>) {
+    let mut watch = false;
+    let mut interval = Duration::from_secs(5);
+    let mut rest = Vec::with_capacity(args.len());
+    let mut iter = args.iter().cloned();
+    while let Some(arg) = iter.next() {
+        if arg == "--watch" {
+            watch = true;
+        } else if arg == "--interval" {
+            if let Some(value) = iter.next() {
+                if let Ok(secs) = value.parse::<u64>() {
+                    interval = Duration::from_secs(secs);
+                }
+            }
+        } else {
+            rest.push(arg);
+        }
+    }
+    (watch, interval, rest)
+}
+
+/// Strip `--no-cache`/`--refresh` out of a gh subcommand's trailing args,
+/// returning whether the on-disk response cache should be bypassed and the
+/// remaining args (also the cache key, since two invocations with different
+/// flags shouldn't share a cached response).
+fn extract_cache_flags(args: &[String]) -> (bool, Vec<String>) {
+    let bypass = args.iter().any(|a| a == "--no-cache" || a == "--refresh");
+    let rest = args
+        .iter()
+        .filter(|a| *a != "--no-cache" && *a != "--refresh")
+        .cloned()
+        .collect();
+    (bypass, rest)
+}
+
+/// Strip `--rtk-paginate` (and an optional `--rtk-max-pages N` cap) out of
+/// `run_api`'s trailing args, returning whether to auto-paginate, the page
+/// cap (10 if unset), and the remaining args to pass straight to `gh api`.
+fn extract_paginate_flags(args: &[String]) -> (bool, usize, Vec<String>) {
+    let mut paginate = false;
+    let mut max_pages = 10;
+    let mut rest = Vec::with_capacity(args.len());
+    let mut iter = args.iter().cloned();
+    while let Some(arg) = iter.next() {
+        if arg == "--rtk-paginate" {
+            paginate = true;
+        } else if arg == "--rtk-max-pages" {
+            if let Some(value) = iter.next() {
+                if let Ok(n) = value.parse::<usize>() {
+                    max_pages = n;
+                }
+            }
+        } else {
+            rest.push(arg);
+        }
+    }
+    (paginate, max_pages, rest)
+}
+
+/// Run `gh api` against `args`, transparently following REST `Link: rel="next"`
+/// pagination or GraphQL `pageInfo.hasNextPage`/`endCursor` cursors up to
+/// `max_pages`, and return the aggregated JSON as a single string - a plain
+/// array for REST list endpoints, or the first page's object with every
+/// `nodes`/`edges` array it contains extended in place for GraphQL.
+fn paginate_api(args: &[String], max_pages: usize) -> Result<String> {
+    let is_graphql = args.first().map(|a| a == "graphql").unwrap_or(false);
+
+    let mut combined: Option<Value> = None;
+    let mut cursor: Option<String> = None;
+    let mut next_url: Option<String> = None;
+    let mut pages = 0usize;
+
+    loop {
+        pages += 1;
+
+        let mut cmd = Command::new("gh");
+        cmd.arg("api").arg("--include");
+        if let Some(url) = &next_url {
+            cmd.arg(url);
+        } else {
+            for arg in args {
+                cmd.arg(arg);
+            }
+            if let Some(after) = &cursor {
+                cmd.arg("-f").arg(format!("after={}", after));
+            }
+        }
+
+        let output = cmd.output().context("Failed to run gh api")?;
+        if !output.status.success() {
+            let stderr = redact::scrub(&String::from_utf8_lossy(&output.stderr), &[]);
+            anyhow::bail!("gh api failed on page {}: {}", pages, stderr.trim());
+        }
+
+        let raw = redact::scrub(&String::from_utf8_lossy(&output.stdout), &[]);
+        let (headers, body) = split_http_headers(&raw);
+        let page: Value = serde_json::from_str(body).context("gh api response was not JSON")?;
+
+        next_url = if is_graphql { None } else { parse_link_next(&headers) };
+        cursor = page
+            .get("data")
+            .and_then(find_page_info)
+            .filter(|info| info.0)
+            .map(|info| info.1);
+
+        combined = Some(match combined {
+            None => page,
+            Some(acc) => merge_page(acc, page),
+        });
+
+        let more = if is_graphql {
+            cursor.is_some()
+        } else {
+            next_url.is_some()
+        };
+        if !more || pages >= max_pages {
+            break;
+        }
+    }
+
+    Ok(serde_json::to_string(&combined.unwrap_or(Value::Null))?)
+}
+
+/// Split a `gh api --include` response into its HTTP header block and JSON
+/// body (the two are separated by the first blank line, same as any raw
+/// HTTP/1.1 response).
+fn split_http_headers(raw: &str) -> (&str, &str) {
+    match raw.split_once("\r\n\r\n").or_else(|| raw.split_once("\n\n")) {
+        Some((headers, body)) => (headers, body),
+        None => ("", raw),
+    }
+}
+
+/// Pull the `<url>` out of a `Link: ...; rel="next"` response header, if any.
+fn parse_link_next(headers: &str) -> Option<String> {
+    for line in headers.lines() {
+        let (name, value) = line.split_once(':')?;
+        if !name.trim().eq_ignore_ascii_case("link") {
+            continue;
+        }
+        for part in value.split(',') {
+            if part.contains("rel=\"next\"") {
+                let start = part.find('<')? + 1;
+                let end = part.find('>')?;
+                return Some(part[start..end].to_string());
+            }
+        }
+    }
+    None
+}
+
+/// Find the first `pageInfo` object anywhere under `value` and return
+/// `(hasNextPage, endCursor)`, for GraphQL responses whose shape nests the
+/// connection (and its `pageInfo`) arbitrarily deep under `data`.
+fn find_page_info(value: &Value) -> Option<(bool, String)> {
+    if let Some(info) = value.get("pageInfo") {
+        let has_next = info.get("hasNextPage").and_then(Value::as_bool).unwrap_or(false);
+        let cursor = info.get("endCursor").and_then(Value::as_str)?;
+        return Some((has_next, cursor.to_string()));
+    }
+    match value {
+        Value::Object(map) => map.values().find_map(find_page_info),
+        Value::Array(items) => items.iter().find_map(find_page_info),
+        _ => None,
+    }
+}
+
+/// Fold a newly-fetched `page` into the running `acc`: concatenate them
+/// directly if both are arrays, otherwise extend every `nodes`/`edges`
+/// array `page` shares with `acc` at the same path (GraphQL connections),
+/// leaving everything else in `acc` untouched.
+fn merge_page(acc: Value, page: Value) -> Value {
+    match (acc, page) {
+        (Value::Array(mut a), Value::Array(b)) => {
+            a.extend(b);
+            Value::Array(a)
+        }
+        (Value::Object(mut a), Value::Object(b)) => {
+            for (key, b_val) in b {
+                match a.get(key.as_str()).cloned() {
+                    Some(a_val) if key == "nodes" || key == "edges" => {
+                        a.insert(key, merge_page(a_val, b_val));
+                    }
+                    Some(a_val) if a_val.is_object() && b_val.is_object() => {
+                        a.insert(key, merge_page(a_val, b_val));
+                    }
+                    _ => {}
+                }
+            }
+            Value::Object(a)
+        }
+        (acc, _) => acc,
+    }
+}
+
+/// The configured TTL for [`gh_cache`], or its 30s default if unset/unreadable.
+fn cache_ttl() -> Duration {
+    let secs = crate::config::Config::load()
+        .map(|c| c.gh.cache_ttl_secs)
+        .unwrap_or(30);
+    Duration::from_secs(secs)
+}
+
+/// Poll `query` on a fixed interval until every name in its returned
+/// name->state map reaches a terminal state, printing a line only when a
+/// name's state changes between polls instead of reprinting the whole
+/// table - the point is to stay token-cheap for an agent watching a
+/// multi-minute CI run. Returns the final snapshot once every entry is
+/// terminal.
+fn watch_states(
+    interval: Duration,
+    is_terminal: impl Fn(&str) -> bool,
+    mut query: impl FnMut() -> Result<HashMap<String, String>>,
+) -> Result<HashMap<String, String>> {
+    let mut previous: HashMap<String, String> = HashMap::new();
+    loop {
+        let current = query()?;
+
+        let mut names: Vec<&String> = current.keys().collect();
+        names.sort();
+        for name in names {
+            let state = &current[name];
+            if previous.get(name) != Some(state) {
+                println!("  {} -> {}", name, state);
+            }
+        }
+
+        let all_terminal = !current.is_empty() && current.values().all(|s| is_terminal(s));
+        previous = current;
+        if all_terminal {
+            return Ok(previous);
+        }
+
+        std::thread::sleep(interval);
+    }
+}
+
+/// Fetch each check's `name`/`bucket` via `gh pr checks --json`, which is
+/// what [`pr_checks_watch`] diffs between polls (the plain-text `gh pr
+/// checks` output [`pr_checks`] parses has no stable field to key on).
+fn fetch_pr_check_buckets(pr_number: &str) -> Result<HashMap<String, String>> {
+    let output = Command::new("gh")
+        .args(["pr", "checks", pr_number, "--json", "name,bucket"])
+        .output()
+        .context("Failed to run gh pr checks")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        anyhow::bail!("gh pr checks failed: {}", stderr.trim());
+    }
+
+    let json: Value = serde_json::from_slice(&output.stdout)
+        .context("Failed to parse gh pr checks output")?;
+
+    let mut buckets = HashMap::new();
+    if let Some(checks) = json.as_array() {
+        for check in checks {
+            let name = check["name"].as_str().unwrap_or("???").to_string();
+            let bucket = check["bucket"].as_str().unwrap_or("pending").to_string();
+            buckets.insert(name, bucket);
+        }
+    }
+    Ok(buckets)
+}
+
+/// `rtk gh pr checks <n> --watch`: re-poll `gh pr checks --json` every
+/// `interval` until every check lands in a terminal bucket, printing only
+/// the checks that changed state each tick, then render the same ✅/❌
+/// summary [`pr_checks`] does and exit non-zero if anything failed.
+fn pr_checks_watch(pr_number: &str, interval: Duration) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
+    println!("🔍 Watching checks for PR #{} (every {}s)...", pr_number, interval.as_secs());
+
+    let final_state = watch_states(
+        interval,
+        |bucket| matches!(bucket, "pass" | "fail" | "skipping" | "cancel"),
+        || fetch_pr_check_buckets(pr_number),
+    )?;
+
+    let passed = final_state.values().filter(|b| b.as_str() == "pass").count();
+    let mut failed_checks: Vec<&String> = final_state
+        .iter()
+        .filter(|(_, b)| b.as_str() == "fail")
+        .map(|(name, _)| name)
+        .collect();
+    failed_checks.sort();
+
+    let mut filtered = String::new();
+    filtered.push_str("🔍 CI Checks Summary:\n");
+    filtered.push_str(&format!("  ✅ Passed: {}\n", passed));
+    filtered.push_str(&format!("  ❌ Failed: {}\n", failed_checks.len()));
+    if !failed_checks.is_empty() {
+        filtered.push_str("\n  Failed checks:\n");
+        for name in &failed_checks {
+            filtered.push_str(&format!("    {}\n", name));
+        }
+    }
+    print!("{}", filtered);
+
+    timer.track(
+        &format!("gh pr checks {} --watch", pr_number),
+        &format!("rtk gh pr checks {} --watch", pr_number),
+        &format!("{:?}", final_state),
+        &filtered,
+    );
+
+    if !failed_checks.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// One `--format json`/`ndjson` record per PR in `gh pr status`'s
+/// `createdBy` list.
+#[derive(Debug, Serialize)]
+struct PrStatusEntry {
+    number: i64,
+    title: String,
+    review_decision: String,
+}
+
+fn pr_status(_verbose: u8, _ultra_compact: bool, format: OutputFormat) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+    let started = Instant::now();
+
     let mut cmd = Command::new("gh");
     cmd.args([
         "pr",
@@ -412,6 +1056,27 @@ fn pr_status(_verbose: u8, _ultra_compact: bool) -> Result<()> {
     let json: Value =
         serde_json::from_slice(&output.stdout).context("Failed to parse gh pr status output")?;
 
+    if format != OutputFormat::Text {
+        let entries: Vec<PrStatusEntry> = json["createdBy"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .take(5)
+            .map(|pr| PrStatusEntry {
+                number: pr["number"].as_i64().unwrap_or(0),
+                title: pr["title"].as_str().unwrap_or("???").to_string(),
+                review_decision: pr["reviewDecision"].as_str().unwrap_or("PENDING").to_string(),
+            })
+            .collect();
+
+        let filtered = serde_json::to_string(&entries).unwrap_or_default();
+        timer.track("gh pr status", "rtk gh pr status", &raw, &filtered);
+        return match format {
+            OutputFormat::Ndjson => emit_ndjson(&entries),
+            _ => emit_json(&RunResult::from_output(started, &output, entries)),
+        };
+    }
+
     let mut filtered = String::new();
 
     if let Some(created_by) = json["createdBy"].as_array() {
@@ -432,20 +1097,29 @@ fn pr_status(_verbose: u8, _ultra_compact: bool) -> Result<()> {
     Ok(())
 }
 
-fn run_issue(args: &[String], verbose: u8, ultra_compact: bool) -> Result<()> {
+fn run_issue(args: &[String], verbose: u8, ultra_compact: bool, format: OutputFormat) -> Result<()> {
     if args.is_empty() {
         return run_passthrough("gh", "issue", args);
     }
 
     match args[0].as_str() {
-        "list" => list_issues(&args[1..], verbose, ultra_compact),
-        "view" => view_issue(&args[1..], verbose),
+        "list" => list_issues(&args[1..], verbose, ultra_compact, format),
+        "view" => view_issue(&args[1..], verbose, format),
         _ => run_passthrough("gh", "issue", args),
     }
 }
 
-fn list_issues(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
+/// One `--format json`/`ndjson` record per issue listed by `gh issue list`.
+#[derive(Debug, Serialize)]
+struct IssueListEntry {
+    number: i64,
+    title: String,
+    state: String,
+}
+
+fn list_issues(args: &[String], _verbose: u8, ultra_compact: bool, format: OutputFormat) -> Result<()> {
     let timer = tracking::TimedExecution::start();
+    let started = Instant::now();
 
     let mut cmd = Command::new("gh");
     cmd.args(["issue", "list", "--json", "number,title,state,author"]);
@@ -467,6 +1141,27 @@ fn list_issues(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()>
     let json: Value =
         serde_json::from_slice(&output.stdout).context("Failed to parse gh issue list output")?;
 
+    if format != OutputFormat::Text {
+        let entries: Vec<IssueListEntry> = json
+            .as_array()
+            .into_iter()
+            .flatten()
+            .take(20)
+            .map(|issue| IssueListEntry {
+                number: issue["number"].as_i64().unwrap_or(0),
+                title: issue["title"].as_str().unwrap_or("???").to_string(),
+                state: issue["state"].as_str().unwrap_or("???").to_string(),
+            })
+            .collect();
+
+        let filtered = serde_json::to_string(&entries).unwrap_or_default();
+        timer.track("gh issue list", "rtk gh issue list", &raw, &filtered);
+        return match format {
+            OutputFormat::Ndjson => emit_ndjson(&entries),
+            _ => emit_json(&RunResult::from_output(started, &output, entries)),
+        };
+    }
+
     let mut filtered = String::new();
 
     if let Some(issues) = json.as_array() {
@@ -511,14 +1206,54 @@ fn list_issues(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()>
     Ok(())
 }
 
-fn view_issue(args: &[String], _verbose: u8) -> Result<()> {
-    let timer = tracking::TimedExecution::start();
+/// A `--format json`/`ndjson` record for `gh issue view`.
+#[derive(Debug, Serialize)]
+struct IssueSummary {
+    number: i64,
+    title: String,
+    state: String,
+    author: String,
+    url: String,
+}
 
-    if args.is_empty() {
-        return Err(anyhow::anyhow!("Issue number required"));
+/// Fetch open issues as picker candidates (`#number title`) for
+/// `view_issue` when called with no issue number.
+fn fetch_issue_candidates() -> Result<Vec<picker::PickItem>> {
+    let output = Command::new("gh")
+        .args(["issue", "list", "--json", "number,title"])
+        .output()
+        .context("Failed to run gh issue list")?;
+    if !output.status.success() {
+        return Ok(Vec::new());
     }
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap_or(Value::Null);
+    Ok(json
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|issue| {
+            let number = issue["number"].as_i64().unwrap_or(0);
+            let title = issue["title"].as_str().unwrap_or("???");
+            picker::PickItem {
+                key: number.to_string(),
+                label: format!("#{} {}", number, title),
+            }
+        })
+        .collect())
+}
 
-    let issue_number = &args[0];
+fn view_issue(args: &[String], _verbose: u8, format: OutputFormat) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+    let started = Instant::now();
+
+    let issue_number = match args.first() {
+        Some(number) => number.clone(),
+        None => picker::pick(&fetch_issue_candidates()?)
+            .ok()
+            .flatten()
+            .ok_or_else(|| anyhow::anyhow!("Issue number required"))?,
+    };
+    let issue_number = &issue_number;
 
     let mut cmd = Command::new("gh");
     cmd.args([
@@ -547,6 +1282,28 @@ fn view_issue(args: &[String], _verbose: u8) -> Result<()> {
     let json: Value =
         serde_json::from_slice(&output.stdout).context("Failed to parse gh issue view output")?;
 
+    if format != OutputFormat::Text {
+        let summary = IssueSummary {
+            number: json["number"].as_i64().unwrap_or(0),
+            title: json["title"].as_str().unwrap_or("???").to_string(),
+            state: json["state"].as_str().unwrap_or("???").to_string(),
+            author: json["author"]["login"].as_str().unwrap_or("???").to_string(),
+            url: json["url"].as_str().unwrap_or("").to_string(),
+        };
+
+        let filtered = serde_json::to_string(&summary).unwrap_or_default();
+        timer.track(
+            &format!("gh issue view {}", issue_number),
+            &format!("rtk gh issue view {}", issue_number),
+            &raw,
+            &filtered,
+        );
+        return match format {
+            OutputFormat::Ndjson => emit_ndjson(std::slice::from_ref(&summary)),
+            _ => emit_json(&RunResult::from_output(started, &output, summary)),
+        };
+    }
+
     let number = json["number"].as_i64().unwrap_or(0);
     let title = json["title"].as_str().unwrap_or("???");
     let state = json["state"].as_str().unwrap_or("???");
@@ -597,47 +1354,90 @@ fn view_issue(args: &[String], _verbose: u8) -> Result<()> {
     Ok(())
 }
 
-fn run_workflow(args: &[String], verbose: u8, ultra_compact: bool) -> Result<()> {
+fn run_workflow(args: &[String], verbose: u8, ultra_compact: bool, format: OutputFormat) -> Result<()> {
     if args.is_empty() {
         return run_passthrough("gh", "run", args);
     }
 
     match args[0].as_str() {
-        "list" => list_runs(&args[1..], verbose, ultra_compact),
-        "view" => view_run(&args[1..], verbose),
+        "list" => list_runs(&args[1..], verbose, ultra_compact, format),
+        "view" => view_run(&args[1..], verbose, format),
         _ => run_passthrough("gh", "run", args),
     }
 }
 
-fn list_runs(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
+/// A `--format json`/`ndjson` record for `gh run list`.
+#[derive(Debug, Serialize)]
+struct RunListEntry {
+    id: i64,
+    name: String,
+    status: String,
+    conclusion: String,
+}
+
+fn list_runs(args: &[String], _verbose: u8, ultra_compact: bool, format: OutputFormat) -> Result<()> {
     let timer = tracking::TimedExecution::start();
+    let started = Instant::now();
+    let (bypass_cache, args) = extract_cache_flags(args);
+
+    let cached = gh_cache::get("run list", &args, if bypass_cache { Duration::ZERO } else { cache_ttl() });
+    let (raw, output) = match cached {
+        Some(raw) => (raw, None),
+        None => {
+            let mut cmd = Command::new("gh");
+            cmd.args([
+                "run",
+                "list",
+                "--json",
+                "databaseId,name,status,conclusion,createdAt",
+            ]);
+            cmd.arg("--limit").arg("10");
+
+            for arg in &args {
+                cmd.arg(arg);
+            }
 
-    let mut cmd = Command::new("gh");
-    cmd.args([
-        "run",
-        "list",
-        "--json",
-        "databaseId,name,status,conclusion,createdAt",
-    ]);
-    cmd.arg("--limit").arg("10");
+            let output = cmd.output().context("Failed to run gh run list")?;
+            let raw = String::from_utf8_lossy(&output.stdout).to_string();
 
-    for arg in args {
-        cmd.arg(arg);
-    }
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+                timer.track("gh run list", "rtk gh run list", &stderr, &stderr);
+                eprintln!("{}", stderr.trim());
+                std::process::exit(output.status.code().unwrap_or(1));
+            }
 
-    let output = cmd.output().context("Failed to run gh run list")?;
-    let raw = String::from_utf8_lossy(&output.stdout).to_string();
+            let _ = gh_cache::put("run list", &args, &raw);
+            (raw, Some(output))
+        }
+    };
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-        timer.track("gh run list", "rtk gh run list", &stderr, &stderr);
-        eprintln!("{}", stderr.trim());
-        std::process::exit(output.status.code().unwrap_or(1));
+    let json: Value = serde_json::from_str(&raw).context("Failed to parse gh run list output")?;
+
+    if format != OutputFormat::Text {
+        let entries: Vec<RunListEntry> = json
+            .as_array()
+            .into_iter()
+            .flatten()
+            .take(10)
+            .map(|run| RunListEntry {
+                id: run["databaseId"].as_i64().unwrap_or(0),
+                name: run["name"].as_str().unwrap_or("???").to_string(),
+                status: run["status"].as_str().unwrap_or("???").to_string(),
+                conclusion: run["conclusion"].as_str().unwrap_or("").to_string(),
+            })
+            .collect();
+        let filtered = serde_json::to_string(&entries).unwrap_or_default();
+        timer.track("gh run list", "rtk gh run list", &raw, &filtered);
+        return match format {
+            OutputFormat::Ndjson => emit_ndjson(&entries),
+            _ => emit_json(&match &output {
+                Some(output) => RunResult::from_output(started, output, entries),
+                None => RunResult::synthetic(started, raw.len(), entries),
+            }),
+        };
     }
 
-    let json: Value =
-        serde_json::from_slice(&output.stdout).context("Failed to parse gh run list output")?;
-
     let mut filtered = String::new();
 
     if let Some(runs) = json.as_array() {
@@ -692,14 +1492,108 @@ fn list_runs(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
     Ok(())
 }
 
-fn view_run(args: &[String], _verbose: u8) -> Result<()> {
-    let timer = tracking::TimedExecution::start();
+/// A `--format json`/`ndjson` record for `gh run view`.
+#[derive(Debug, Serialize)]
+struct RunSummary {
+    id: i64,
+    status: String,
+    conclusion: String,
+    jobs_total: usize,
+    jobs_failed: usize,
+}
 
-    if args.is_empty() {
-        return Err(anyhow::anyhow!("Run ID required"));
+/// Fetch recent workflow runs as picker candidates (`#id name [status]`)
+/// for `view_run` when called with no run ID.
+fn fetch_run_candidates() -> Result<Vec<picker::PickItem>> {
+    let output = Command::new("gh")
+        .args(["run", "list", "--json", "databaseId,name,status,conclusion"])
+        .output()
+        .context("Failed to run gh run list")?;
+    if !output.status.success() {
+        return Ok(Vec::new());
+    }
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap_or(Value::Null);
+    Ok(json
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|run| {
+            let id = run["databaseId"].as_i64().unwrap_or(0);
+            let name = run["name"].as_str().unwrap_or("???");
+            let status = run["conclusion"].as_str().filter(|s| !s.is_empty())
+                .unwrap_or_else(|| run["status"].as_str().unwrap_or("???"));
+            picker::PickItem {
+                key: id.to_string(),
+                label: format!("#{} {} [{}]", id, name, status),
+            }
+        })
+        .collect())
+}
+
+fn view_run(args: &[String], _verbose: u8, format: OutputFormat) -> Result<()> {
+    let run_id = match args.first() {
+        Some(id) => id.clone(),
+        None => picker::pick(&fetch_run_candidates()?)
+            .ok()
+            .flatten()
+            .ok_or_else(|| anyhow::anyhow!("Run ID required"))?,
+    };
+    let run_id = &run_id;
+    let (watch, interval, _rest) = extract_watch_flags(args.get(1..).unwrap_or(&[]));
+    if watch {
+        return view_run_watch(run_id, interval);
     }
 
-    let run_id = &args[0];
+    let timer = tracking::TimedExecution::start();
+    let started = Instant::now();
+
+    if format != OutputFormat::Text {
+        let output = Command::new("gh")
+            .args(["run", "view", run_id, "--json", "databaseId,status,conclusion,jobs"])
+            .output()
+            .context("Failed to run gh run view")?;
+        let raw = String::from_utf8_lossy(&output.stdout).to_string();
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            timer.track(
+                &format!("gh run view {}", run_id),
+                &format!("rtk gh run view {}", run_id),
+                &stderr,
+                &stderr,
+            );
+            eprintln!("{}", stderr.trim());
+            std::process::exit(output.status.code().unwrap_or(1));
+        }
+
+        let json: Value = serde_json::from_slice(&output.stdout)
+            .context("Failed to parse gh run view output")?;
+        let jobs = json["jobs"].as_array().cloned().unwrap_or_default();
+        let jobs_failed = jobs
+            .iter()
+            .filter(|j| j["conclusion"].as_str().map(|c| c != "success" && c != "skipped" && c != "neutral").unwrap_or(false))
+            .count();
+
+        let summary = RunSummary {
+            id: json["databaseId"].as_i64().unwrap_or(0),
+            status: json["status"].as_str().unwrap_or("???").to_string(),
+            conclusion: json["conclusion"].as_str().unwrap_or("").to_string(),
+            jobs_total: jobs.len(),
+            jobs_failed,
+        };
+
+        let filtered = serde_json::to_string(&summary).unwrap_or_default();
+        timer.track(
+            &format!("gh run view {}", run_id),
+            &format!("rtk gh run view {}", run_id),
+            &raw,
+            &filtered,
+        );
+        return match format {
+            OutputFormat::Ndjson => emit_ndjson(std::slice::from_ref(&summary)),
+            _ => emit_json(&RunResult::from_output(started, &output, summary)),
+        };
+    }
 
     let mut cmd = Command::new("gh");
     cmd.args(["run", "view", run_id]);
@@ -760,7 +1654,100 @@ fn view_run(args: &[String], _verbose: u8) -> Result<()> {
     Ok(())
 }
 
-fn run_repo(args: &[String], _verbose: u8, _ultra_compact: bool) -> Result<()> {
+/// Fetch each job's name and resolved state via `gh run view --json jobs`:
+/// its `conclusion` once `status` is `completed`, otherwise the in-progress
+/// `status` itself - this is what [`view_run_watch`] diffs between polls.
+fn fetch_run_job_states(run_id: &str) -> Result<HashMap<String, String>> {
+    let output = Command::new("gh")
+        .args(["run", "view", run_id, "--json", "jobs"])
+        .output()
+        .context("Failed to run gh run view")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        anyhow::bail!("gh run view failed: {}", stderr.trim());
+    }
+
+    let json: Value =
+        serde_json::from_slice(&output.stdout).context("Failed to parse gh run view output")?;
+
+    let mut states = HashMap::new();
+    if let Some(jobs) = json["jobs"].as_array() {
+        for job in jobs {
+            let name = job["name"].as_str().unwrap_or("???").to_string();
+            let status = job["status"].as_str().unwrap_or("queued");
+            let state = if status == "completed" {
+                job["conclusion"].as_str().unwrap_or("unknown").to_string()
+            } else {
+                status.to_string()
+            };
+            states.insert(name, state);
+        }
+    }
+    Ok(states)
+}
+
+/// `rtk gh run view <id> --watch`: re-poll `gh run view --json jobs` every
+/// `interval` until every job completes, printing only the jobs that
+/// changed state each tick, then report pass/fail counts and exit non-zero
+/// if anything failed.
+fn view_run_watch(run_id: &str, interval: Duration) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    println!("🏃 Watching run #{} (every {}s)...", run_id, interval.as_secs());
+
+    let final_state = watch_states(
+        interval,
+        |state| !matches!(state, "queued" | "in_progress" | "waiting" | "requested" | "pending"),
+        || fetch_run_job_states(run_id),
+    )?;
+
+    let total = final_state.len();
+    let mut failed_jobs: Vec<&String> = final_state
+        .iter()
+        .filter(|(_, s)| !matches!(s.as_str(), "success" | "skipped" | "neutral"))
+        .map(|(name, _)| name)
+        .collect();
+    failed_jobs.sort();
+
+    let mut filtered = String::new();
+    filtered.push_str(&format!("🏃 Workflow Run #{}\n", run_id));
+    filtered.push_str(&format!(
+        "  {}/{} jobs passed\n",
+        total - failed_jobs.len(),
+        total
+    ));
+    for name in &failed_jobs {
+        filtered.push_str(&format!("  ❌ {}: {}\n", name, final_state[*name]));
+    }
+    print!("{}", filtered);
+
+    timer.track(
+        &format!("gh run view {} --watch", run_id),
+        &format!("rtk gh run view {} --watch", run_id),
+        &format!("{:?}", final_state),
+        &filtered,
+    );
+
+    if !failed_jobs.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// A `--format json`/`ndjson` record for `gh repo view`.
+#[derive(Debug, Serialize)]
+struct RepoSummary {
+    name: String,
+    owner: String,
+    description: String,
+    url: String,
+    stars: i64,
+    forks: i64,
+    is_private: bool,
+}
+
+fn run_repo(args: &[String], _verbose: u8, _ultra_compact: bool, format: OutputFormat) -> Result<()> {
     // Parse subcommand (default to "view")
     let (subcommand, rest_args) = if args.is_empty() {
         ("view", args)
@@ -773,72 +1760,167 @@ fn run_repo(args: &[String], _verbose: u8, _ultra_compact: bool) -> Result<()> {
     }
 
     let timer = tracking::TimedExecution::start();
+    let started = Instant::now();
 
-    let mut cmd = Command::new("gh");
-    cmd.arg("repo").arg("view");
+    let (min_stars, rest_args) = extract_min_stars_flag(rest_args);
+    let (repos, other_args): (Vec<String>, Vec<String>) = rest_args
+        .iter()
+        .cloned()
+        .partition(|a| is_repo_identifier(a));
+    // No `owner/repo` positional args: fall back to the single cwd-resolved
+    // repo, same as before this request.
+    let targets: Vec<Option<String>> = if repos.is_empty() {
+        vec![None]
+    } else {
+        repos.into_iter().map(Some).collect()
+    };
 
-    for arg in rest_args {
-        cmd.arg(arg);
-    }
+    let mut raw_all = String::new();
+    let mut summaries = Vec::with_capacity(targets.len());
+    let mut last_output = None;
+    let total = targets.len();
 
-    cmd.args([
-        "--json",
-        "name,owner,description,url,stargazerCount,forkCount,isPrivate",
-    ]);
+    for target in &targets {
+        let mut cmd = Command::new("gh");
+        cmd.arg("repo").arg("view");
+        if let Some(repo) = target {
+            cmd.arg(repo);
+        }
+        for arg in &other_args {
+            cmd.arg(arg);
+        }
+        cmd.args([
+            "--json",
+            "name,owner,description,url,stargazerCount,forkCount,isPrivate",
+        ]);
+
+        let output = cmd.output().context("Failed to run gh repo view")?;
+        let raw = redact::scrub(&String::from_utf8_lossy(&output.stdout), &[]);
+
+        if !output.status.success() {
+            let stderr = redact::scrub(&String::from_utf8_lossy(&output.stderr), &[]);
+            timer.track("gh repo view", "rtk gh repo view", &stderr, &stderr);
+            eprintln!("{}", stderr.trim());
+            std::process::exit(output.status.code().unwrap_or(1));
+        }
 
-    let output = cmd.output().context("Failed to run gh repo view")?;
-    let raw = String::from_utf8_lossy(&output.stdout).to_string();
+        raw_all.push_str(&raw);
+        raw_all.push('\n');
+
+        let json: Value = serde_json::from_slice(&output.stdout)
+            .context("Failed to parse gh repo view output")?;
+        let summary = RepoSummary {
+            name: json["name"].as_str().unwrap_or("???").to_string(),
+            owner: json["owner"]["login"].as_str().unwrap_or("???").to_string(),
+            description: json["description"].as_str().unwrap_or("").to_string(),
+            url: json["url"].as_str().unwrap_or("").to_string(),
+            stars: json["stargazerCount"].as_i64().unwrap_or(0),
+            forks: json["forkCount"].as_i64().unwrap_or(0),
+            is_private: json["isPrivate"].as_bool().unwrap_or(false),
+        };
+
+        if min_stars.map(|min| summary.stars >= min).unwrap_or(true) {
+            summaries.push(summary);
+        }
+        last_output = Some(output);
+    }
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-        timer.track("gh repo view", "rtk gh repo view", &stderr, &stderr);
-        eprintln!("{}", stderr.trim());
-        std::process::exit(output.status.code().unwrap_or(1));
+    let shown = summaries.len();
+
+    if format != OutputFormat::Text {
+        let filtered = serde_json::to_string(&summaries).unwrap_or_default();
+        timer.track("gh repo view", "rtk gh repo view", &raw_all, &filtered);
+        return match format {
+            OutputFormat::Ndjson => emit_ndjson(&summaries),
+            _ => emit_json(&RunResult::from_output(
+                started,
+                &last_output.expect("at least one repo was fetched"),
+                summaries,
+            )),
+        };
     }
 
-    let json: Value =
-        serde_json::from_slice(&output.stdout).context("Failed to parse gh repo view output")?;
+    let mut filtered = String::new();
+    for summary in &summaries {
+        let visibility = if summary.is_private {
+            "🔒 Private"
+        } else {
+            "🌐 Public"
+        };
 
-    let name = json["name"].as_str().unwrap_or("???");
-    let owner = json["owner"]["login"].as_str().unwrap_or("???");
-    let description = json["description"].as_str().unwrap_or("");
-    let url = json["url"].as_str().unwrap_or("");
-    let stars = json["stargazerCount"].as_i64().unwrap_or(0);
-    let forks = json["forkCount"].as_i64().unwrap_or(0);
-    let private = json["isPrivate"].as_bool().unwrap_or(false);
+        let line = format!("📦 {}/{}\n", summary.owner, summary.name);
+        filtered.push_str(&line);
+        print!("{}", line);
 
-    let visibility = if private {
-        "🔒 Private"
-    } else {
-        "🌐 Public"
-    };
+        let line = format!("  {}\n", visibility);
+        filtered.push_str(&line);
+        print!("{}", line);
 
-    let mut filtered = String::new();
+        if !summary.description.is_empty() {
+            let line = format!("  {}\n", truncate(&summary.description, 80));
+            filtered.push_str(&line);
+            print!("{}", line);
+        }
 
-    let line = format!("📦 {}/{}\n", owner, name);
-    filtered.push_str(&line);
-    print!("{}", line);
+        let line = format!("  ⭐ {} stars | 🔱 {} forks\n", summary.stars, summary.forks);
+        filtered.push_str(&line);
+        print!("{}", line);
 
-    let line = format!("  {}\n", visibility);
-    filtered.push_str(&line);
-    print!("{}", line);
+        let line = format!("  {}\n", summary.url);
+        filtered.push_str(&line);
+        print!("{}", line);
+    }
 
-    if !description.is_empty() {
-        let line = format!("  {}\n", truncate(description, 80));
+    if min_stars.is_some() || total > 1 {
+        let line = format!(
+            "{}/{} repos shown{}\n",
+            shown,
+            total,
+            min_stars
+                .map(|min| format!(" (filtered by \u{2265}{} stars)", min))
+                .unwrap_or_default()
+        );
         filtered.push_str(&line);
         print!("{}", line);
     }
 
-    let line = format!("  ⭐ {} stars | 🔱 {} forks\n", stars, forks);
-    filtered.push_str(&line);
-    print!("{}", line);
+    timer.track("gh repo view", "rtk gh repo view", &raw_all, &filtered);
+    Ok(())
+}
 
-    let line = format!("  {}\n", url);
-    filtered.push_str(&line);
-    print!("{}", line);
+/// Strip `--min-stars N` out of `gh repo view`'s trailing args, returning
+/// the threshold (if any) and the remaining args.
+fn extract_min_stars_flag(args: &[String]) -> (Option<i64>, Vec<String>) {
+    let mut min_stars = None;
+    let mut rest = Vec::with_capacity(args.len());
+    let mut iter = args.iter().cloned();
+    while let Some(arg) = iter.next() {
+        if arg == "--min-stars" {
+            if let Some(value) = iter.next() {
+                if let Ok(n) = value.parse::<i64>() {
+                    min_stars = Some(n);
+                }
+            }
+        } else {
+            rest.push(arg);
+        }
+    }
+    (min_stars, rest)
+}
 
-    timer.track("gh repo view", "rtk gh repo view", &raw, &filtered);
-    Ok(())
+/// Whether `arg` looks like an `owner/repo` positional argument rather than
+/// a flag or its value: exactly one `/`, with a non-empty segment on each
+/// side and no leading `-`.
+fn is_repo_identifier(arg: &str) -> bool {
+    if arg.starts_with('-') {
+        return false;
+    }
+    match arg.split_once('/') {
+        Some((owner, repo)) => {
+            !owner.is_empty() && !repo.is_empty() && !repo.contains('/')
+        }
+        None => false,
+    }
 }
 
 fn pr_create(args: &[String], _verbose: u8) -> Result<()> {
@@ -851,8 +1933,8 @@ fn pr_create(args: &[String], _verbose: u8) -> Result<()> {
     }
 
     let output = cmd.output().context("Failed to run gh pr create")?;
-    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    let stdout = redact::scrub(&String::from_utf8_lossy(&output.stdout), &[]);
+    let stderr = redact::scrub(&String::from_utf8_lossy(&output.stderr), &[]);
 
     if !output.status.success() {
         timer.track("gh pr create", "rtk gh pr create", &stderr, &stderr);
@@ -889,8 +1971,8 @@ fn pr_merge(args: &[String], _verbose: u8) -> Result<()> {
     }
 
     let output = cmd.output().context("Failed to run gh pr merge")?;
-    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    let stdout = redact::scrub(&String::from_utf8_lossy(&output.stdout), &[]);
+    let stderr = redact::scrub(&String::from_utf8_lossy(&output.stderr), &[]);
 
     if !output.status.success() {
         timer.track("gh pr merge", "rtk gh pr merge", &stderr, &stderr);
@@ -925,7 +2007,53 @@ fn pr_merge(args: &[String], _verbose: u8) -> Result<()> {
     Ok(())
 }
 
+/// Spawn `cmd` with piped stdout/stderr and read both off the child's fds
+/// as they arrive (line by line) instead of blocking on `Command::output()`
+/// until the whole process exits. `gh api` still needs the complete body to
+/// parse as JSON, so this doesn't bound peak memory the way `pr_diff`'s
+/// streaming `DiffCompactor` does - but it lets output start flowing before
+/// `gh` finishes, and keeps every handler that shells out to `gh` on one
+/// shared capture path instead of each reimplementing `Command::output()`.
+fn stream_command_lines(mut cmd: Command) -> Result<(std::process::ExitStatus, String, String)> {
+    use std::io::{BufRead, Read};
+
+    cmd.stdout(std::process::Stdio::piped());
+    cmd.stderr(std::process::Stdio::piped());
+
+    let mut child = cmd.spawn()?;
+    let stdout = child.stdout.take().expect("stdout was piped");
+
+    let mut body = String::new();
+    for line in std::io::BufReader::new(stdout).lines() {
+        body.push_str(&line?);
+        body.push('\n');
+    }
+
+    let status = child.wait()?;
+
+    let mut stderr = String::new();
+    if let Some(mut err) = child.stderr.take() {
+        err.read_to_string(&mut stderr)?;
+    }
+
+    Ok((status, body, stderr))
+}
+
+/// Cap on the raw diff text kept around for `timer.track`'s token
+/// accounting. The compacted output the user actually sees is already
+/// bounded by `DiffCompactor`'s `max_lines`; this only bounds how much of
+/// the *uncompacted* source we hold in memory for a multi-megabyte diff.
+const STREAM_RAW_CAP: usize = 2 * 1024 * 1024;
+
+/// `gh pr diff`, but instead of buffering the whole diff before compacting
+/// it (`Command::output()`), spawns `gh` with piped stdout and feeds it to
+/// `git::DiffCompactor` line by line, printing each compacted line the
+/// moment it's final. Keeps peak memory bounded and shows output as soon
+/// as `gh` produces it, instead of waiting for a multi-megabyte diff to
+/// finish downloading first.
 fn pr_diff(args: &[String], _verbose: u8) -> Result<()> {
+    use std::io::{BufRead, Read};
+
     let timer = tracking::TimedExecution::start();
 
     let mut cmd = Command::new("gh");
@@ -933,27 +2061,68 @@ fn pr_diff(args: &[String], _verbose: u8) -> Result<()> {
     for arg in args {
         cmd.arg(arg);
     }
+    cmd.stdout(std::process::Stdio::piped());
+    cmd.stderr(std::process::Stdio::piped());
 
-    let output = cmd.output().context("Failed to run gh pr diff")?;
-    let raw = String::from_utf8_lossy(&output.stdout).to_string();
+    let mut child = cmd.spawn().context("Failed to run gh pr diff")?;
+    let stdout = child.stdout.take().expect("stdout was piped");
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    let mut compactor = git::DiffCompactor::new(100);
+    let mut raw = String::new();
+    let mut raw_truncated = false;
+    let mut filtered_lines: Vec<String> = Vec::new();
+
+    for line in std::io::BufReader::new(stdout).lines() {
+        let line = line.context("Failed to read gh pr diff output")?;
+        let line = redact::scrub(&line, &[]);
+
+        if raw.len() < STREAM_RAW_CAP {
+            raw.push_str(&line);
+            raw.push('\n');
+        } else {
+            raw_truncated = true;
+        }
+
+        if let Some(lines) = compactor.push_line(&line) {
+            for l in lines {
+                println!("{}", l);
+                filtered_lines.push(l);
+            }
+        }
+    }
+
+    let tail = compactor.finish();
+    if !tail.is_empty() {
+        println!("{}", tail);
+        filtered_lines.push(tail);
+    }
+
+    let status = child.wait().context("Failed waiting on gh pr diff")?;
+
+    if !status.success() {
+        let mut stderr = String::new();
+        if let Some(mut err) = child.stderr.take() {
+            let _ = err.read_to_string(&mut stderr);
+        }
+        let stderr = redact::scrub(&stderr, &[]);
         timer.track("gh pr diff", "rtk gh pr diff", &stderr, &stderr);
         eprintln!("{}", stderr.trim());
-        std::process::exit(output.status.code().unwrap_or(1));
+        std::process::exit(status.code().unwrap_or(1));
     }
 
-    let filtered = if raw.trim().is_empty() {
-        let msg = "No diff\n";
-        print!("{}", msg);
-        msg.to_string()
+    if raw_truncated {
+        raw.push_str("\n...[raw truncated for tracking]");
+    }
+
+    let filtered = if filtered_lines.is_empty() {
+        println!("No diff");
+        "No diff\n".to_string()
     } else {
-        let compacted = git::compact_diff(&raw, 100);
-        println!("{}", compacted);
-        compacted
+        filtered_lines.join("\n")
     };
 
+    let raw = redact::scrub(&raw, &[]);
+    let filtered = redact::scrub(&filtered, &[]);
     timer.track("gh pr diff", "rtk gh pr diff", &raw, &filtered);
     Ok(())
 }
@@ -971,10 +2140,10 @@ fn pr_action(action: &str, args: &[String], _verbose: u8) -> Result<()> {
     let output = cmd
         .output()
         .context(format!("Failed to run gh pr {}", action))?;
-    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    let stdout = redact::scrub(&String::from_utf8_lossy(&output.stdout), &[]);
 
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        let stderr = redact::scrub(&String::from_utf8_lossy(&output.stderr), &[]);
         timer.track(
             &format!("gh pr {}", action),
             &format!("rtk gh pr {}", action),
@@ -1013,25 +2182,31 @@ fn pr_action(action: &str, args: &[String], _verbose: u8) -> Result<()> {
 
 fn run_api(args: &[String], _verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
+    let (paginate, max_pages, args) = extract_paginate_flags(args);
 
-    let mut cmd = Command::new("gh");
-    cmd.arg("api");
-    for arg in args {
-        cmd.arg(arg);
-    }
+    let raw = if paginate {
+        paginate_api(&args, max_pages)?
+    } else {
+        let mut cmd = Command::new("gh");
+        cmd.arg("api");
+        for arg in &args {
+            cmd.arg(arg);
+        }
 
-    let output = cmd.output().context("Failed to run gh api")?;
-    let raw = String::from_utf8_lossy(&output.stdout).to_string();
+        let (status, body, stderr) = stream_command_lines(cmd).context("Failed to run gh api")?;
+        let stderr = redact::scrub(&stderr, &[]);
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-        timer.track("gh api", "rtk gh api", &stderr, &stderr);
-        eprintln!("{}", stderr.trim());
-        std::process::exit(output.status.code().unwrap_or(1));
-    }
+        if !status.success() {
+            timer.track("gh api", "rtk gh api", &stderr, &stderr);
+            eprintln!("{}", stderr.trim());
+            std::process::exit(status.code().unwrap_or(1));
+        }
+
+        redact::scrub(&body, &[])
+    };
 
     // Try to parse as JSON and filter
-    let filtered = match json_cmd::filter_json_string(&raw, 5) {
+    let filtered = match json_cmd::filter_json_string(&raw, 5, "text") {
         Ok(schema) => {
             println!("{}", schema);
             schema
@@ -1055,6 +2230,47 @@ fn run_api(args: &[String], _verbose: u8) -> Result<()> {
     Ok(())
 }
 
+/// `rtk gh webhook [--addr ADDR] [--secret SECRET]`: start the
+/// `webhook-server` feature's local GitHub webhook receiver, or explain why
+/// it can't when rtk was built without that feature.
+fn run_webhook(args: &[String]) -> Result<()> {
+    let (addr, args) = extract_flag_value(args, "--addr");
+    let addr = addr.unwrap_or_else(|| "127.0.0.1:8787".to_string());
+    let (secret_flag, _args) = extract_flag_value(&args, "--secret");
+    let secret = secret_flag
+        .or_else(|| std::env::var("GH_WEBHOOK_SECRET").ok())
+        .context("Webhook secret required: pass --secret or set GH_WEBHOOK_SECRET")?;
+
+    #[cfg(feature = "webhook-server")]
+    {
+        return crate::webhook::serve(&addr, &secret);
+    }
+
+    #[cfg(not(feature = "webhook-server"))]
+    {
+        let _ = (addr, secret);
+        anyhow::bail!("gh webhook requires rtk built with the `webhook-server` feature");
+    }
+}
+
+/// Strip a single `--flag value` pair out of `args`, returning the value
+/// (if present) and the remaining args. Mirrors `main.rs`'s
+/// `extract_flag_value` for the flags `gh_cmd` itself needs to parse out of
+/// a subcommand's trailing args.
+fn extract_flag_value(args: &[String], flag: &str) -> (Option<String>, Vec<String>) {
+    let mut value = None;
+    let mut rest = Vec::with_capacity(args.len());
+    let mut iter = args.iter().cloned();
+    while let Some(arg) = iter.next() {
+        if arg == flag {
+            value = iter.next();
+        } else {
+            rest.push(arg);
+        }
+    }
+    (value, rest)
+}
+
 fn run_passthrough(cmd: &str, subcommand: &str, args: &[String]) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
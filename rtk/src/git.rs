@@ -1,8 +1,28 @@
+use crate::config::Config;
+use crate::events::CommandEvent;
 use crate::tracking;
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use std::ffi::OsString;
 use std::process::Command;
 
+/// Which backend the `[git] backend` config entry selects for this run.
+/// Resolves to `Subprocess` whenever rtk wasn't built with the
+/// `libgit2-backend` feature, regardless of what the config says, so the
+/// compact-mode call sites can stay oblivious to the feature flag.
+#[cfg(feature = "libgit2-backend")]
+/// `RTK_GIT_BACKEND` overrides `[git] backend` from the config file, so a
+/// one-off `RTK_GIT_BACKEND=libgit2 rtk git status` doesn't require editing
+/// config just to compare the two backends.
+fn resolve_backend() -> crate::git_backend::GitBackend {
+    let backend = std::env::var("RTK_GIT_BACKEND").ok().unwrap_or_else(|| {
+        Config::load()
+            .map(|c| c.git.backend)
+            .unwrap_or_else(|_| "subprocess".to_string())
+    });
+    crate::git_backend::GitBackend::from_config(&backend)
+}
+
+
 #[derive(Debug, Clone)]
 pub enum GitCommand {
     Diff,
@@ -17,6 +37,7 @@ pub enum GitCommand {
     Fetch,
     Stash { subcommand: Option<String> },
     Worktree,
+    Smash { range: Option<String>, all: bool },
 }
 
 pub fn run(cmd: GitCommand, args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()> {
@@ -33,12 +54,40 @@ pub fn run(cmd: GitCommand, args: &[String], max_lines: Option<usize>, verbose:
         GitCommand::Fetch => run_fetch(args, verbose),
         GitCommand::Stash { subcommand } => run_stash(subcommand.as_deref(), args, verbose),
         GitCommand::Worktree => run_worktree(args, verbose),
+        GitCommand::Smash { range, all } => run_smash(range.as_deref(), all, verbose),
+    }
+}
+
+/// Pull a `--only <glob-or-ext>` / `--only=<glob-or-ext>` flag out of
+/// `args`, returning its value (if any) and the remaining args with that
+/// flag removed so it's never forwarded to a real `git` invocation.
+fn extract_only_arg(args: &[String]) -> (Option<String>, Vec<String>) {
+    let mut only = None;
+    let mut remaining = Vec::new();
+
+    let mut iter = args.iter().enumerate().peekable();
+    while let Some((i, arg)) = iter.next() {
+        if let Some(value) = arg.strip_prefix("--only=") {
+            only = Some(value.to_string());
+        } else if arg == "--only" {
+            if let Some(value) = args.get(i + 1) {
+                only = Some(value.clone());
+                iter.next();
+            }
+        } else {
+            remaining.push(arg.clone());
+        }
     }
+
+    (only, remaining)
 }
 
 fn run_diff(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
+    let (only, args) = extract_only_arg(args);
+    let args = args.as_slice();
+
     // Check if user wants stat output
     let wants_stat = args
         .iter()
@@ -77,6 +126,21 @@ fn run_diff(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
     }
 
     // Default RTK behavior: stat first, then compacted diff
+    #[cfg(feature = "libgit2-backend")]
+    if args.is_empty() && resolve_backend() == crate::git_backend::GitBackend::Libgit2 {
+        if let Ok(hunks) = crate::git_backend::diff_hunks() {
+            let (hunks, hidden) = filter_hunks_by_path(hunks, only.as_deref());
+            let mut compacted = compact_diff_hunks(&hunks, max_lines.unwrap_or(100));
+            if hidden > 0 {
+                compacted.push_str(&format!("\n({} non-matching files hidden)", hidden));
+            }
+            println!("{compacted}");
+            timer.track("git diff", "rtk git diff (libgit2)", &compacted, &compacted);
+            return Ok(());
+        }
+        // Fall through to the subprocess path on any git2 error.
+    }
+
     let mut cmd = Command::new("git");
     cmd.arg("diff").arg("--stat");
 
@@ -104,10 +168,18 @@ fn run_diff(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
     let diff_output = diff_cmd.output().context("Failed to run git diff")?;
     let diff_stdout = String::from_utf8_lossy(&diff_output.stdout);
 
+    let (scoped_diff, hidden) = match &only {
+        Some(only) => filter_diff_by_path(&diff_stdout, only),
+        None => (diff_stdout.to_string(), 0),
+    };
+
     let mut final_output = stat_stdout.to_string();
-    if !diff_stdout.is_empty() {
+    if !scoped_diff.is_empty() || hidden > 0 {
         println!("\n--- Changes ---");
-        let compacted = compact_diff(&diff_stdout, max_lines.unwrap_or(100));
+        let mut compacted = compact_diff(&scoped_diff, max_lines.unwrap_or(100));
+        if hidden > 0 {
+            compacted.push_str(&format!("\n({} non-matching files hidden)", hidden));
+        }
         println!("{}", compacted);
         final_output.push_str("\n--- Changes ---\n");
         final_output.push_str(&compacted);
@@ -126,6 +198,17 @@ fn run_diff(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
 fn run_show(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
+    // `--no-describe` is an rtk-only flag; strip it before anything below
+    // forwards `args` to a real `git` invocation.
+    let wants_describe = !args.iter().any(|arg| arg == "--no-describe");
+    let args: Vec<String> = args
+        .iter()
+        .filter(|arg| *arg != "--no-describe")
+        .cloned()
+        .collect();
+    let (only, args) = extract_only_arg(&args);
+    let args = args.as_slice();
+
     // If user wants --stat or --format only, pass through
     let wants_stat_only = args
         .iter()
@@ -160,6 +243,39 @@ fn run_show(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
         return Ok(());
     }
 
+    #[cfg(feature = "libgit2-backend")]
+    if resolve_backend() == crate::git_backend::GitBackend::Libgit2 {
+        let rev = args.first().map(|s| s.as_str()).unwrap_or("HEAD");
+        if let Ok((summary, hunks)) = crate::git_backend::show_hunks(rev) {
+            let summary = if wants_describe {
+                format!("{summary} ({})", crate::git_backend::describe_rev(rev))
+            } else {
+                summary
+            };
+            println!("{summary}");
+            let (hunks, hidden) = filter_hunks_by_path(hunks, only.as_deref());
+            let mut compacted = compact_diff_hunks(&hunks, max_lines.unwrap_or(100));
+            if hidden > 0 {
+                compacted.push_str(&format!("\n({} non-matching files hidden)", hidden));
+            }
+            if !compacted.is_empty() {
+                if verbose > 0 {
+                    println!("\n--- Changes ---");
+                }
+                println!("{compacted}");
+            }
+            let final_output = format!("{summary}\n{compacted}");
+            timer.track(
+                &format!("git show {}", args.join(" ")),
+                &format!("rtk git show {} (libgit2)", args.join(" ")),
+                &final_output,
+                &final_output,
+            );
+            return Ok(());
+        }
+        // Fall through to the subprocess path on any git2 error.
+    }
+
     // Get raw output for tracking
     let mut raw_cmd = Command::new("git");
     raw_cmd.arg("show");
@@ -184,7 +300,13 @@ fn run_show(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
         std::process::exit(summary_output.status.code().unwrap_or(1));
     }
     let summary = String::from_utf8_lossy(&summary_output.stdout);
-    println!("{}", summary.trim());
+    let summary = if wants_describe {
+        let rev = args.first().map(|s| s.as_str()).unwrap_or("HEAD");
+        format!("{} ({})", summary.trim(), describe_rev(rev))
+    } else {
+        summary.trim().to_string()
+    };
+    println!("{}", summary);
 
     // Step 2: --stat summary
     let mut stat_cmd = Command::new("git");
@@ -207,14 +329,22 @@ fn run_show(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
     }
     let diff_output = diff_cmd.output().context("Failed to run git show (diff)")?;
     let diff_stdout = String::from_utf8_lossy(&diff_output.stdout);
-    let diff_text = diff_stdout.trim();
+
+    let (scoped_diff, hidden) = match &only {
+        Some(only) => filter_diff_by_path(&diff_stdout, only),
+        None => (diff_stdout.trim().to_string(), 0),
+    };
+    let diff_text = scoped_diff.trim();
 
     let mut final_output = summary.to_string();
-    if !diff_text.is_empty() {
+    if !diff_text.is_empty() || hidden > 0 {
         if verbose > 0 {
             println!("\n--- Changes ---");
         }
-        let compacted = compact_diff(diff_text, max_lines.unwrap_or(100));
+        let mut compacted = compact_diff(diff_text, max_lines.unwrap_or(100));
+        if hidden > 0 {
+            compacted.push_str(&format!("\n({} non-matching files hidden)", hidden));
+        }
         println!("{}", compacted);
         final_output.push_str(&format!("\n{}", compacted));
     }
@@ -229,75 +359,286 @@ fn run_show(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
     Ok(())
 }
 
+/// `git describe --tags --always <rev>`: nearest tag plus commits-ahead
+/// (e.g. `v1.4.0-7-gabc1234`), falling back to the raw abbreviated SHA via
+/// `--always` in repos with no tags, and to `rev` itself if `git describe`
+/// can't run at all (e.g. an empty repo).
+fn describe_rev(rev: &str) -> String {
+    Command::new("git")
+        .args(["describe", "--tags", "--always", rev])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| rev.to_string())
+}
+
+/// Build an `--only <glob-or-ext>` pattern for [`crate::glob_filter::GlobFilter`]:
+/// a bare extension (`.rs` or `rs`, no `/` or `*`) is expanded to `**/*.rs`
+/// so it matches regardless of directory; anything else (an actual glob)
+/// is passed through unchanged.
+fn normalize_only_pattern(only: &str) -> String {
+    if !only.contains('/') && !only.contains('*') {
+        let ext = only.trim_start_matches('.');
+        format!("**/*.{ext}")
+    } else {
+        only.to_string()
+    }
+}
+
+/// Drop every `diff --git` file block whose path doesn't match `only`
+/// (a glob or bare extension, e.g. `.rs`), scanning each block's `+++
+/// b/<path>` header the way [`DiffCompactor`] already does to find `diff
+/// --git`'s file boundary. Returns the filtered diff text plus how many
+/// files were hidden, so the caller can print `(N non-matching files
+/// hidden)` without those files ever reaching [`compact_diff`] -- they're
+/// not even counted in its `+N -M` summaries.
+pub(crate) fn filter_diff_by_path(diff: &str, only: &str) -> (String, usize) {
+    let filter = match crate::glob_filter::GlobFilter::new(&[normalize_only_pattern(only)]) {
+        Ok(f) => f,
+        Err(_) => return (diff.to_string(), 0),
+    };
+
+    let mut kept = Vec::new();
+    let mut hidden = 0usize;
+    let mut current_block: Vec<&str> = Vec::new();
+    let mut current_matches = true;
+
+    let flush = |block: &[&str], matches: bool, kept: &mut Vec<String>, hidden: &mut usize| {
+        if block.is_empty() {
+            return;
+        }
+        if matches {
+            kept.push(block.join("\n"));
+        } else {
+            *hidden += 1;
+        }
+    };
+
+    for line in diff.lines() {
+        if line.starts_with("diff --git") {
+            flush(&current_block, current_matches, &mut kept, &mut hidden);
+            current_block = Vec::new();
+            let path = line.split(" b/").nth(1).unwrap_or("");
+            current_matches = filter.keep(path);
+        }
+        current_block.push(line);
+    }
+    flush(&current_block, current_matches, &mut kept, &mut hidden);
+
+    (kept.join("\n"), hidden)
+}
+
 pub(crate) fn compact_diff(diff: &str, max_lines: usize) -> String {
+    let mut compactor = DiffCompactor::new(max_lines);
     let mut result = Vec::new();
-    let mut current_file = String::new();
-    let mut added = 0;
-    let mut removed = 0;
-    let mut in_hunk = false;
-    let mut hunk_lines = 0;
-    let max_hunk_lines = 10;
-
     for line in diff.lines() {
+        match compactor.push_line(line) {
+            Some(mut lines) => result.append(&mut lines),
+            None if compactor.is_done() => break,
+            None => {}
+        }
+    }
+    let tail = compactor.finish();
+    if !tail.is_empty() {
+        result.push(tail);
+    }
+    result.join("\n")
+}
+
+/// Same rendering as [`compact_diff`], but consuming already-structured
+/// [`crate::git_backend::DiffHunk`] values from the `libgit2-backend`
+/// feature's `git2`-backed reads instead of re-parsing raw patch text.
+#[cfg(feature = "libgit2-backend")]
+pub(crate) fn compact_diff_hunks(hunks: &[crate::git_backend::DiffHunk], max_lines: usize) -> String {
+    let mut result = Vec::new();
+    let mut emitted = 0;
+
+    for hunk in hunks {
+        if emitted >= max_lines {
+            result.push("\n... (more changes truncated)".to_string());
+            break;
+        }
+        result.push(format!("\nğŸ“„ {}", hunk.path));
+        result.push(format!("  @@ {} @@", hunk.header));
+        emitted += 2;
+
+        for line in hunk.lines.iter().take(10) {
+            if emitted >= max_lines {
+                break;
+            }
+            result.push(format!("  {line}"));
+            emitted += 1;
+        }
+        if hunk.lines.len() > 10 {
+            result.push("  ... (truncated)".to_string());
+        }
+        result.push(format!("  +{} -{}", hunk.added, hunk.removed));
+    }
+
+    result.join("\n")
+}
+
+/// `--only` scoping for the `libgit2-backend` feature's typed
+/// [`crate::git_backend::DiffHunk`] path: the same glob/extension match as
+/// [`filter_diff_by_path`], applied to already-parsed hunks instead of raw
+/// diff text.
+#[cfg(feature = "libgit2-backend")]
+fn filter_hunks_by_path(
+    hunks: Vec<crate::git_backend::DiffHunk>,
+    only: Option<&str>,
+) -> (Vec<crate::git_backend::DiffHunk>, usize) {
+    let Some(only) = only else {
+        return (hunks, 0);
+    };
+    let filter = match crate::glob_filter::GlobFilter::new(&[normalize_only_pattern(only)]) {
+        Ok(f) => f,
+        Err(_) => return (hunks, 0),
+    };
+
+    let mut hidden_paths = std::collections::HashSet::new();
+    let mut kept = Vec::new();
+    for hunk in hunks {
+        if filter.keep(&hunk.path) {
+            kept.push(hunk);
+        } else {
+            hidden_paths.insert(hunk.path);
+        }
+    }
+    (kept, hidden_paths.len())
+}
+
+/// Incremental version of [`compact_diff`]'s line-folding state machine, so
+/// a caller reading a diff off a child process's stdout (e.g. `pr_diff`'s
+/// streaming capture) can feed it one line at a time and get back exactly
+/// the lines that became final at each step, instead of needing the whole
+/// diff buffered up front. [`compact_diff`] itself is just this run to
+/// completion in one pass.
+pub(crate) struct DiffCompactor {
+    current_file: String,
+    added: usize,
+    removed: usize,
+    in_hunk: bool,
+    hunk_lines: usize,
+    max_hunk_lines: usize,
+    max_lines: usize,
+    emitted: usize,
+    done: bool,
+}
+
+impl DiffCompactor {
+    pub(crate) fn new(max_lines: usize) -> Self {
+        DiffCompactor {
+            current_file: String::new(),
+            added: 0,
+            removed: 0,
+            in_hunk: false,
+            hunk_lines: 0,
+            max_hunk_lines: 10,
+            max_lines,
+            emitted: 0,
+            done: false,
+        }
+    }
+
+    /// Feed one more line of the raw diff in. Returns the compacted lines
+    /// this call made final, if any; once the `max_lines` cap is hit this
+    /// returns `None` for every subsequent call and further input is ignored.
+    pub(crate) fn push_line(&mut self, line: &str) -> Option<Vec<String>> {
+        if self.done {
+            return None;
+        }
+
+        let mut out = Vec::new();
+
         if line.starts_with("diff --git") {
-            // New file
-            if !current_file.is_empty() && (added > 0 || removed > 0) {
-                result.push(format!("  +{} -{}", added, removed));
+            if !self.current_file.is_empty() && (self.added > 0 || self.removed > 0) {
+                out.push(format!("  +{} -{}", self.added, self.removed));
             }
-            current_file = line.split(" b/").nth(1).unwrap_or("unknown").to_string();
-            result.push(format!("\nğŸ“„ {}", current_file));
-            added = 0;
-            removed = 0;
-            in_hunk = false;
+            self.current_file = line.split(" b/").nth(1).unwrap_or("unknown").to_string();
+            out.push(format!("\nğŸ“„ {}", self.current_file));
+            self.added = 0;
+            self.removed = 0;
+            self.in_hunk = false;
         } else if line.starts_with("@@") {
-            // New hunk
-            in_hunk = true;
-            hunk_lines = 0;
+            self.in_hunk = true;
+            self.hunk_lines = 0;
             let hunk_info = line.split("@@").nth(1).unwrap_or("").trim();
-            result.push(format!("  @@ {} @@", hunk_info));
-        } else if in_hunk {
+            out.push(format!("  @@ {} @@", hunk_info));
+        } else if self.in_hunk {
             if line.starts_with('+') && !line.starts_with("+++") {
-                added += 1;
-                if hunk_lines < max_hunk_lines {
-                    result.push(format!("  {}", line));
-                    hunk_lines += 1;
+                self.added += 1;
+                if self.hunk_lines < self.max_hunk_lines {
+                    out.push(format!("  {}", line));
+                    self.hunk_lines += 1;
                 }
             } else if line.starts_with('-') && !line.starts_with("---") {
-                removed += 1;
-                if hunk_lines < max_hunk_lines {
-                    result.push(format!("  {}", line));
-                    hunk_lines += 1;
+                self.removed += 1;
+                if self.hunk_lines < self.max_hunk_lines {
+                    out.push(format!("  {}", line));
+                    self.hunk_lines += 1;
                 }
-            } else if hunk_lines < max_hunk_lines && !line.starts_with("\\") {
-                // Context line
-                if hunk_lines > 0 {
-                    result.push(format!("  {}", line));
-                    hunk_lines += 1;
+            } else if self.hunk_lines < self.max_hunk_lines && !line.starts_with('\\') {
+                if self.hunk_lines > 0 {
+                    out.push(format!("  {}", line));
+                    self.hunk_lines += 1;
                 }
             }
 
-            if hunk_lines == max_hunk_lines {
-                result.push("  ... (truncated)".to_string());
-                hunk_lines += 1;
+            if self.hunk_lines == self.max_hunk_lines {
+                out.push("  ... (truncated)".to_string());
+                self.hunk_lines += 1;
             }
         }
 
-        if result.len() >= max_lines {
-            result.push("\n... (more changes truncated)".to_string());
-            break;
+        self.emitted += out.len();
+        if self.emitted >= self.max_lines {
+            out.push("\n... (more changes truncated)".to_string());
+            self.done = true;
+        }
+
+        if out.is_empty() {
+            None
+        } else {
+            Some(out)
         }
     }
 
-    if !current_file.is_empty() && (added > 0 || removed > 0) {
-        result.push(format!("  +{} -{}", added, removed));
+    /// Whether the `max_lines` cap has already been hit.
+    pub(crate) fn is_done(&self) -> bool {
+        self.done
     }
 
-    result.join("\n")
+    /// Flush the trailing `+N -M` line for the last file, once there's no
+    /// more input. Returns the fully joined compacted diff.
+    pub(crate) fn finish(self) -> String {
+        let mut tail = Vec::new();
+        if !self.done && !self.current_file.is_empty() && (self.added > 0 || self.removed > 0) {
+            tail.push(format!("  +{} -{}", self.added, self.removed));
+        }
+        tail.join("\n")
+    }
 }
 
 fn run_log(args: &[String], _max_lines: Option<usize>, verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
+    #[cfg(feature = "libgit2-backend")]
+    if args.is_empty() && resolve_backend() == crate::git_backend::GitBackend::Libgit2 {
+        if let Ok(entries) = crate::git_backend::log_entries(10) {
+            let lines: Vec<String> = entries
+                .iter()
+                .map(|e| format!("{} {} <{}>", e.short_hash, e.summary, e.author))
+                .collect();
+            let filtered = lines.join("\n");
+            println!("{filtered}");
+            timer.track("git log", "rtk git log (libgit2)", &filtered, &filtered);
+            return Ok(());
+        }
+        // Fall through to the subprocess path on any git2 error.
+    }
+
     let mut cmd = Command::new("git");
     cmd.arg("log");
 
@@ -390,8 +731,41 @@ fn filter_log_output(output: &str, limit: usize) -> String {
     capped.join("\n").trim().to_string()
 }
 
+/// Two-character porcelain-v1 XY codes that mean "unmerged" rather than
+/// "staged"/"modified": either column is `U`, or the pair marks both sides
+/// adding/deleting the same path (`AA`/`DD`). Checked before the normal
+/// per-column classification so a conflicted file is counted once, as a
+/// conflict, instead of also landing in Staged/Modified.
+const CONFLICT_CODES: &[&str] = &["DD", "AU", "UD", "UA", "DU", "AA", "UU"];
+
 /// Format porcelain output into compact RTK status display
-fn format_status_output(porcelain: &str) -> String {
+/// Render the `[ahead N, behind M]` segment of a `## branch...upstream
+/// [...]` porcelain header (already stripped of its brackets) as a compact
+/// ahead/behind/diverged indicator, or `None` when the branch is up to
+/// date or has no upstream at all.
+fn format_sync_indicator(tracking: Option<&str>) -> Option<String> {
+    let tracking = tracking?;
+
+    let mut ahead = 0u32;
+    let mut behind = 0u32;
+    for part in tracking.split(',') {
+        let part = part.trim();
+        if let Some(n) = part.strip_prefix("ahead ") {
+            ahead = n.trim().parse().unwrap_or(0);
+        } else if let Some(n) = part.strip_prefix("behind ") {
+            behind = n.trim().parse().unwrap_or(0);
+        }
+    }
+
+    match (ahead, behind) {
+        (0, 0) => None,
+        (a, 0) => Some(format!("â‡¡{}", a)),
+        (0, b) => Some(format!("â‡£{}", b)),
+        (a, b) => Some(format!("â‡• â‡¡{} â‡£{}", a, b)),
+    }
+}
+
+fn format_status_output(porcelain: &str, stash_count: usize, describe: Option<&str>) -> String {
     let lines: Vec<&str> = porcelain.lines().collect();
 
     if lines.is_empty() {
@@ -400,22 +774,40 @@ fn format_status_output(porcelain: &str) -> String {
 
     let mut output = String::new();
 
-    // Parse branch info
+    // Parse branch info: "## main...origin/main [ahead 2, behind 1]"
     if let Some(branch_line) = lines.first() {
         if branch_line.starts_with("##") {
-            let branch = branch_line.trim_start_matches("## ");
-            output.push_str(&format!("ğŸ“Œ {}\n", branch));
+            let header = branch_line.trim_start_matches("## ");
+            let (names, tracking) = match header.split_once(" [") {
+                Some((names, rest)) => (names, Some(rest.trim_end_matches(']'))),
+                None => (header, None),
+            };
+            output.push_str(&format!("ğŸ“Œ {}", names));
+            if let Some(tag) = describe {
+                output.push_str(&format!(" ({})", tag));
+            }
+            if let Some(sync) = format_sync_indicator(tracking) {
+                output.push_str(&format!(" {}", sync));
+            }
+            if stash_count > 0 {
+                output.push_str(&format!(" ${}", stash_count));
+            }
+            output.push('\n');
         }
     }
 
     // Count changes by type
     let mut staged = 0;
+    let mut renamed = 0;
     let mut modified = 0;
+    let mut deleted = 0;
     let mut untracked = 0;
     let mut conflicts = 0;
 
     let mut staged_files = Vec::new();
+    let mut renamed_files = Vec::new();
     let mut modified_files = Vec::new();
+    let mut deleted_files = Vec::new();
     let mut untracked_files = Vec::new();
 
     for line in lines.iter().skip(1) {
@@ -425,27 +817,44 @@ fn format_status_output(porcelain: &str) -> String {
         let status = line.get(0..2).unwrap_or("  ");
         let file = line.get(3..).unwrap_or("");
 
+        if status == "??" {
+            untracked += 1;
+            untracked_files.push(file);
+            continue;
+        }
+
+        if CONFLICT_CODES.contains(&status) {
+            conflicts += 1;
+            continue;
+        }
+
         match status.chars().next().unwrap_or(' ') {
-            'M' | 'A' | 'D' | 'R' | 'C' => {
+            'R' => {
+                renamed += 1;
+                renamed_files.push(file);
+            }
+            'D' => {
+                deleted += 1;
+                deleted_files.push(file);
+            }
+            'M' | 'A' | 'C' => {
                 staged += 1;
                 staged_files.push(file);
             }
-            'U' => conflicts += 1,
             _ => {}
         }
 
         match status.chars().nth(1).unwrap_or(' ') {
-            'M' | 'D' => {
+            'M' => {
                 modified += 1;
                 modified_files.push(file);
             }
+            'D' => {
+                deleted += 1;
+                deleted_files.push(file);
+            }
             _ => {}
         }
-
-        if status == "??" {
-            untracked += 1;
-            untracked_files.push(file);
-        }
     }
 
     // Build summary
@@ -459,6 +868,16 @@ fn format_status_output(porcelain: &str) -> String {
         }
     }
 
+    if renamed > 0 {
+        output.push_str(&format!("â†» Renamed: {} files\n", renamed));
+        for f in renamed_files.iter().take(5) {
+            output.push_str(&format!("   {}\n", f));
+        }
+        if renamed_files.len() > 5 {
+            output.push_str(&format!("   ... +{} more\n", renamed_files.len() - 5));
+        }
+    }
+
     if modified > 0 {
         output.push_str(&format!("ğŸ“ Modified: {} files\n", modified));
         for f in modified_files.iter().take(5) {
@@ -469,6 +888,16 @@ fn format_status_output(porcelain: &str) -> String {
         }
     }
 
+    if deleted > 0 {
+        output.push_str(&format!("ðŸ—‘ Deleted: {} files\n", deleted));
+        for f in deleted_files.iter().take(5) {
+            output.push_str(&format!("   {}\n", f));
+        }
+        if deleted_files.len() > 5 {
+            output.push_str(&format!("   ... +{} more\n", deleted_files.len() - 5));
+        }
+    }
+
     if untracked > 0 {
         output.push_str(&format!("â“ Untracked: {} files\n", untracked));
         for f in untracked_files.iter().take(3) {
@@ -486,6 +915,154 @@ fn format_status_output(porcelain: &str) -> String {
     output.trim_end().to_string()
 }
 
+/// Same rendering as [`format_status_output`], but consuming already-typed
+/// [`crate::git_backend::StatusEntry`] values instead of porcelain text.
+#[cfg(feature = "libgit2-backend")]
+fn format_status_entries(entries: &[crate::git_backend::StatusEntry]) -> String {
+    if entries.is_empty() {
+        return "Clean working tree".to_string();
+    }
+
+    let mut staged = Vec::new();
+    let mut modified = Vec::new();
+    let mut untracked = Vec::new();
+    let mut conflicts = 0;
+
+    for entry in entries {
+        match entry.index_status {
+            'A' | 'M' | 'D' | 'R' => staged.push(entry.path.as_str()),
+            'U' => conflicts += 1,
+            _ => {}
+        }
+        match entry.worktree_status {
+            'M' | 'D' => modified.push(entry.path.as_str()),
+            '?' => untracked.push(entry.path.as_str()),
+            _ => {}
+        }
+    }
+
+    let mut output = String::new();
+    if !staged.is_empty() {
+        output.push_str(&format!("✅ Staged: {} files\n", staged.len()));
+        for f in staged.iter().take(5) {
+            output.push_str(&format!("   {f}\n"));
+        }
+    }
+    if !modified.is_empty() {
+        output.push_str(&format!("📝 Modified: {} files\n", modified.len()));
+        for f in modified.iter().take(5) {
+            output.push_str(&format!("   {f}\n"));
+        }
+    }
+    if !untracked.is_empty() {
+        output.push_str(&format!("❓ Untracked: {} files\n", untracked.len()));
+        for f in untracked.iter().take(3) {
+            output.push_str(&format!("   {f}\n"));
+        }
+    }
+    if conflicts > 0 {
+        output.push_str(&format!("⚠️  Conflicts: {conflicts} files\n"));
+    }
+
+    output.trim_end().to_string()
+}
+
+/// Same rendering as [`filter_branch_output`], but consuming already-typed
+/// [`crate::git_backend::BranchEntry`] values with exact `graph_ahead_behind`
+/// counts instead of re-deriving them from `-vv`'s text.
+#[cfg(feature = "libgit2-backend")]
+fn format_branch_entries(entries: &[crate::git_backend::BranchEntry]) -> String {
+    let current = entries.iter().find(|b| b.is_head && !b.is_remote);
+    let local: Vec<&crate::git_backend::BranchEntry> = entries
+        .iter()
+        .filter(|b| !b.is_remote && !b.is_head)
+        .collect();
+    let remote: Vec<&crate::git_backend::BranchEntry> =
+        entries.iter().filter(|b| b.is_remote).collect();
+
+    let tracking_suffix = |b: &crate::git_backend::BranchEntry| -> Option<String> {
+        if b.gone {
+            return Some("[gone]".to_string());
+        }
+        let tracking = format!("ahead {}, behind {}", b.ahead, b.behind);
+        format_sync_indicator(Some(&tracking))
+    };
+
+    let current_name = current.map(|b| b.name.as_str()).unwrap_or("");
+    let mut current_line = format!("* {current_name}");
+    if let Some(b) = current {
+        if let Some(suffix) = tracking_suffix(b) {
+            current_line.push(' ');
+            current_line.push_str(&suffix);
+        }
+    }
+
+    let mut result = vec![current_line];
+    for b in &local {
+        let mut line = format!("  {}", b.name);
+        if let Some(suffix) = tracking_suffix(b) {
+            line.push(' ');
+            line.push_str(&suffix);
+        }
+        result.push(line);
+    }
+
+    if !remote.is_empty() {
+        let local_names: Vec<&str> = local.iter().map(|b| b.name.as_str()).collect();
+        let remote_only: Vec<&str> = remote
+            .iter()
+            .map(|b| b.name.strip_prefix("origin/").unwrap_or(&b.name))
+            .filter(|name| *name != current_name && !local_names.contains(name))
+            .collect();
+        if !remote_only.is_empty() {
+            result.push(format!("  remote-only ({}):", remote_only.len()));
+            for name in remote_only.iter().take(10) {
+                result.push(format!("    {name}"));
+            }
+            if remote_only.len() > 10 {
+                result.push(format!("    ... +{} more", remote_only.len() - 10));
+            }
+        }
+    }
+
+    result.join("\n")
+}
+
+/// Same rendering as [`filter_stash_list`], but consuming already-typed
+/// [`crate::git_backend::StashEntry`] values instead of `stash@{N}: ...`
+/// text.
+#[cfg(feature = "libgit2-backend")]
+fn format_stash_entries(entries: &[crate::git_backend::StashEntry]) -> String {
+    entries
+        .iter()
+        .map(|e| format!("stash@{{{}}}: {}", e.index, e.message))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Same rendering as [`filter_worktree_list`], but consuming already-typed
+/// [`crate::git_backend::WorktreeEntry`] values. Note `Repository::worktrees`
+/// only enumerates *linked* worktrees, not the primary checkout, so this
+/// path is only taken when at least one linked worktree exists.
+#[cfg(feature = "libgit2-backend")]
+fn format_worktree_entries(entries: &[crate::git_backend::WorktreeEntry]) -> String {
+    let home = dirs::home_dir()
+        .map(|h| h.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    entries
+        .iter()
+        .map(|e| {
+            let mut path = e.path.clone();
+            if !home.is_empty() && path.starts_with(&home) {
+                path = format!("~{}", &path[home.len()..]);
+            }
+            format!("{} {}", path, e.name)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Minimal filtering for git status with user-provided args
 fn filter_status_with_args(output: &str) -> String {
     let mut result = Vec::new();
@@ -523,7 +1100,34 @@ fn filter_status_with_args(output: &str) -> String {
     }
 }
 
+/// Number of entries in `git stash list`, so `format_status_output` can
+/// append a `$N` indicator without `run_status` needing a second porcelain
+/// parse. Returns 0 on any error (e.g. no stash ref yet) rather than
+/// failing the whole status command over it.
+fn count_stash_entries() -> usize {
+    Command::new("git")
+        .args(["stash", "list"])
+        .output()
+        .map(|o| String::from_utf8_lossy(&o.stdout).lines().count())
+        .unwrap_or(0)
+}
+
 fn run_status(args: &[String], verbose: u8) -> Result<()> {
+    // `--no-describe`/`--by-project` are rtk-only flags; strip them before
+    // anything below treats `args` as real `git status` flags.
+    let wants_describe = !args.iter().any(|arg| arg == "--no-describe");
+    let by_project = args.iter().any(|arg| arg == "--by-project");
+    let args: Vec<String> = args
+        .iter()
+        .filter(|arg| *arg != "--no-describe" && *arg != "--by-project")
+        .cloned()
+        .collect();
+    let args = args.as_slice();
+
+    if by_project {
+        return run_status_by_project(verbose);
+    }
+
     let timer = tracking::TimedExecution::start();
 
     // If user provided flags, apply minimal filtering
@@ -555,6 +1159,17 @@ fn run_status(args: &[String], verbose: u8) -> Result<()> {
         return Ok(());
     }
 
+    #[cfg(feature = "libgit2-backend")]
+    if resolve_backend() == crate::git_backend::GitBackend::Libgit2 {
+        if let Ok(entries) = crate::git_backend::status_entries() {
+            let formatted = format_status_entries(&entries);
+            println!("{formatted}");
+            timer.track("git status", "rtk git status (libgit2)", &formatted, &formatted);
+            return Ok(());
+        }
+        // Fall through to the subprocess path on any git2 error.
+    }
+
     // Default RTK compact mode (no args provided)
     // Get raw git status for tracking
     let raw_output = Command::new("git")
@@ -574,7 +1189,9 @@ fn run_status(args: &[String], verbose: u8) -> Result<()> {
     let formatted = if !stderr.is_empty() && stderr.contains("not a git repository") {
         "Not a git repository".to_string()
     } else {
-        format_status_output(&stdout)
+        let stash_count = count_stash_entries();
+        let describe = wants_describe.then(|| describe_rev("HEAD"));
+        format_status_output(&stdout, stash_count, describe.as_deref())
     };
 
     println!("{}", formatted);
@@ -585,6 +1202,46 @@ fn run_status(args: &[String], verbose: u8) -> Result<()> {
     Ok(())
 }
 
+/// `rtk git status --by-project`: group changed files by the monorepo
+/// project that owns them (see [`crate::monorepo`]) instead of listing
+/// them flat, then print "N projects touched" with a capped sample per
+/// project.
+fn run_status_by_project(verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let output = Command::new("git")
+        .args(["status", "--porcelain"])
+        .output()
+        .context("Failed to run git status")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    if verbose > 0 {
+        eprintln!("git status --porcelain --by-project");
+    }
+
+    let files: Vec<String> = stdout
+        .lines()
+        .filter_map(|line| line.get(3..))
+        .map(|f| f.trim().to_string())
+        .filter(|f| !f.is_empty())
+        .collect();
+
+    let config = Config::load().unwrap_or_default();
+    let roots = crate::monorepo::resolve_project_roots(&config.monorepo.roots);
+    let groups = crate::monorepo::group_by_project(&files, &roots);
+    let formatted = crate::monorepo::format_project_groups(&groups, 5);
+
+    println!("{}", formatted);
+    timer.track(
+        "git status --porcelain",
+        "rtk git status --by-project",
+        &stdout,
+        &formatted,
+    );
+
+    Ok(())
+}
+
 fn run_add(args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
@@ -784,6 +1441,7 @@ fn run_push(args: &[String], verbose: u8) -> Result<()> {
 
 fn run_pull(args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
+    let start = std::time::Instant::now();
 
     if verbose > 0 {
         eprintln!("git pull");
@@ -801,16 +1459,18 @@ fn run_pull(args: &[String], verbose: u8) -> Result<()> {
     let stderr = String::from_utf8_lossy(&output.stderr);
     let raw_output = format!("{}\n{}", stdout, stderr);
 
+    let mut event = CommandEvent::new("pull", output.status.code().unwrap_or(1), start.elapsed().as_millis() as u64);
+
     if output.status.success() {
+        // Count files changed
+        let mut files = 0;
+        let mut insertions = 0;
+        let mut deletions = 0;
+
         let compact =
             if stdout.contains("Already up to date") || stdout.contains("Already up-to-date") {
                 "ok (up-to-date)".to_string()
             } else {
-                // Count files changed
-                let mut files = 0;
-                let mut insertions = 0;
-                let mut deletions = 0;
-
                 for line in stdout.lines() {
                     if line.contains("file") && line.contains("changed") {
                         // Parse "3 files changed, 10 insertions(+), 2 deletions(-)"
@@ -846,6 +1506,12 @@ fn run_pull(args: &[String], verbose: u8) -> Result<()> {
                 }
             };
 
+        if files > 0 {
+            event.files_changed = Some(files);
+            event.insertions = Some(insertions);
+            event.deletions = Some(deletions);
+        }
+
         println!("{}", compact);
 
         timer.track(
@@ -864,11 +1530,16 @@ fn run_pull(args: &[String], verbose: u8) -> Result<()> {
         }
     }
 
+    let config = Config::load().unwrap_or_default();
+    crate::events::emit(&event, &config.events);
+
     Ok(())
 }
 
 fn run_branch(args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
+    let start = std::time::Instant::now();
+    let config = Config::load().unwrap_or_default();
 
     if verbose > 0 {
         eprintln!("git branch");
@@ -904,6 +1575,13 @@ fn run_branch(args: &[String], verbose: u8) -> Result<()> {
             msg,
         );
 
+        let event = CommandEvent::new(
+            "branch",
+            output.status.code().unwrap_or(1),
+            start.elapsed().as_millis() as u64,
+        );
+        crate::events::emit(&event, &config.events);
+
         if output.status.success() {
             println!("ok âœ“");
         } else {
@@ -919,9 +1597,34 @@ fn run_branch(args: &[String], verbose: u8) -> Result<()> {
     }
 
     // List mode: show compact branch list
-    cmd.arg("-a").arg("--no-color");
-    for arg in args {
-        cmd.arg(arg);
+    #[cfg(feature = "libgit2-backend")]
+    if args.is_empty() && resolve_backend() == crate::git_backend::GitBackend::Libgit2 {
+        if let Ok(entries) = crate::git_backend::branch_entries() {
+            let formatted = format_branch_entries(&entries);
+            println!("{formatted}");
+            timer.track(
+                "git branch -a --no-color",
+                "rtk git branch (libgit2)",
+                &formatted,
+                &formatted,
+            );
+
+            let mut event = CommandEvent::new("branch", 0, start.elapsed().as_millis() as u64);
+            if let Some(head) = entries.iter().find(|e| e.is_head) {
+                event.branch = Some(head.name.clone());
+                event.ahead = Some(head.ahead);
+                event.behind = Some(head.behind);
+            }
+            crate::events::emit(&event, &config.events);
+
+            return Ok(());
+        }
+        // Fall through to the subprocess path on any git2 error.
+    }
+
+    cmd.arg("-a").arg("-vv").arg("--no-color");
+    for arg in args {
+        cmd.arg(arg);
     }
 
     let output = cmd.output().context("Failed to run git branch")?;
@@ -938,12 +1641,46 @@ fn run_branch(args: &[String], verbose: u8) -> Result<()> {
         &filtered,
     );
 
+    let mut event = CommandEvent::new("branch", 0, start.elapsed().as_millis() as u64);
+    event.branch = stdout.lines().find_map(|l| {
+        l.trim()
+            .strip_prefix("* ")
+            .and_then(|rest| rest.split_whitespace().next())
+            .map(|name| name.to_string())
+    });
+    crate::events::emit(&event, &config.events);
+
     Ok(())
 }
 
+/// One local branch line out of `git branch -a -vv --no-color`: its name
+/// and the tracking suffix to render next to it (`⇡2`, `⇣1`, `⇕ ⇡1 ⇣2`,
+/// `[gone]`, or nothing when it's in sync or has no upstream).
+struct LocalBranch {
+    name: String,
+    tracking: Option<String>,
+}
+
+/// Pull the `[upstream: status]` bracket a `-vv` local-branch line carries
+/// right after its commit hash (e.g. `[origin/main: ahead 1, behind 2]`,
+/// `[origin/main: gone]`, or bare `[origin/main]` when fully in sync) into
+/// a compact suffix. Returns `None` when there's no bracket at all (no
+/// upstream configured) or the branch is fully in sync.
+fn parse_branch_tracking(tail: &str) -> Option<String> {
+    let bracket = tail.strip_prefix('[')?;
+    let inside = &bracket[..bracket.find(']')?];
+    let status = inside.split_once(": ").map(|(_, status)| status)?;
+
+    if status == "gone" {
+        Some("[gone]".to_string())
+    } else {
+        format_sync_indicator(Some(status))
+    }
+}
+
 fn filter_branch_output(output: &str) -> String {
-    let mut current = String::new();
-    let mut local: Vec<String> = Vec::new();
+    let mut current = LocalBranch { name: String::new(), tracking: None };
+    let mut local: Vec<LocalBranch> = Vec::new();
     let mut remote: Vec<String> = Vec::new();
 
     for line in output.lines() {
@@ -952,34 +1689,60 @@ fn filter_branch_output(output: &str) -> String {
             continue;
         }
 
-        if let Some(branch) = line.strip_prefix("* ") {
-            current = branch.to_string();
-        } else if line.starts_with("remotes/origin/") {
-            let branch = line.strip_prefix("remotes/origin/").unwrap_or(line);
+        let (is_current, rest) = match line.strip_prefix("* ") {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+
+        if let Some(branch) = rest.strip_prefix("remotes/origin/") {
             // Skip HEAD pointer
             if branch.starts_with("HEAD ") {
                 continue;
             }
-            remote.push(branch.to_string());
+            let name = branch.split_whitespace().next().unwrap_or(branch);
+            remote.push(name.to_string());
+            continue;
+        }
+
+        let mut fields = rest.splitn(2, char::is_whitespace);
+        let name = fields.next().unwrap_or(rest).to_string();
+        let after_name = fields.next().unwrap_or("").trim_start();
+        let after_hash = after_name
+            .split_once(char::is_whitespace)
+            .map(|(_, tail)| tail.trim_start())
+            .unwrap_or("");
+        let branch = LocalBranch { name, tracking: parse_branch_tracking(after_hash) };
+
+        if is_current {
+            current = branch;
         } else {
-            local.push(line.to_string());
+            local.push(branch);
         }
     }
 
     let mut result = Vec::new();
-    result.push(format!("* {}", current));
-
-    if !local.is_empty() {
-        for b in &local {
-            result.push(format!("  {}", b));
+    let mut head_line = format!("* {}", current.name);
+    if let Some(tracking) = &current.tracking {
+        head_line.push(' ');
+        head_line.push_str(tracking);
+    }
+    result.push(head_line);
+
+    for b in &local {
+        let mut line = format!("  {}", b.name);
+        if let Some(tracking) = &b.tracking {
+            line.push(' ');
+            line.push_str(tracking);
         }
+        result.push(line);
     }
 
     if !remote.is_empty() {
         // Filter out remotes that already exist locally
+        let local_names: Vec<&str> = local.iter().map(|b| b.name.as_str()).collect();
         let remote_only: Vec<&String> = remote
             .iter()
-            .filter(|r| *r != &current && !local.contains(r))
+            .filter(|r| *r != &current.name && !local_names.contains(&r.as_str()))
             .collect();
         if !remote_only.is_empty() {
             result.push(format!("  remote-only ({}):", remote_only.len()));
@@ -997,11 +1760,34 @@ fn filter_branch_output(output: &str) -> String {
 
 fn run_fetch(args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
+    let start = std::time::Instant::now();
+    let config = Config::load().unwrap_or_default();
 
     if verbose > 0 {
         eprintln!("git fetch");
     }
 
+    #[cfg(feature = "libgit2-backend")]
+    if args.is_empty() && resolve_backend() == crate::git_backend::GitBackend::Libgit2 {
+        if let Ok(received) = crate::git_backend::fetch("origin") {
+            let msg = if received > 0 {
+                format!("ok fetched ({received} objects)")
+            } else {
+                "ok fetched".to_string()
+            };
+            println!("{msg}");
+            timer.track("git fetch", "rtk git fetch (libgit2)", &msg, &msg);
+
+            let mut event = CommandEvent::new("fetch", 0, start.elapsed().as_millis() as u64);
+            event.new_refs = Some(received);
+            crate::events::emit(&event, &config.events);
+
+            return Ok(());
+        }
+        // Fall through to the subprocess path on any git2 error (auth,
+        // network, or a remote `origin` that doesn't exist).
+    }
+
     let mut cmd = Command::new("git");
     cmd.arg("fetch");
     for arg in args {
@@ -1018,6 +1804,8 @@ fn run_fetch(args: &[String], verbose: u8) -> Result<()> {
         if !stderr.trim().is_empty() {
             eprintln!("{}", stderr);
         }
+        let event = CommandEvent::new("fetch", output.status.code().unwrap_or(1), start.elapsed().as_millis() as u64);
+        crate::events::emit(&event, &config.events);
         return Ok(());
     }
 
@@ -1036,6 +1824,10 @@ fn run_fetch(args: &[String], verbose: u8) -> Result<()> {
     println!("{}", msg);
     timer.track("git fetch", "rtk git fetch", &raw, &msg);
 
+    let mut event = CommandEvent::new("fetch", 0, start.elapsed().as_millis() as u64);
+    event.new_refs = Some(new_refs);
+    crate::events::emit(&event, &config.events);
+
     Ok(())
 }
 
@@ -1048,6 +1840,26 @@ fn run_stash(subcommand: Option<&str>, args: &[String], verbose: u8) -> Result<(
 
     match subcommand {
         Some("list") => {
+            #[cfg(feature = "libgit2-backend")]
+            if resolve_backend() == crate::git_backend::GitBackend::Libgit2 {
+                if let Ok(entries) = crate::git_backend::stash_entries() {
+                    let msg = if entries.is_empty() {
+                        "No stashes".to_string()
+                    } else {
+                        format_stash_entries(&entries)
+                    };
+                    println!("{msg}");
+                    timer.track(
+                        "git stash list",
+                        "rtk git stash list (libgit2)",
+                        &msg,
+                        &msg,
+                    );
+                    return Ok(());
+                }
+                // Fall through to the subprocess path on any git2 error.
+            }
+
             let output = Command::new("git")
                 .args(["stash", "list"])
                 .output()
@@ -1090,6 +1902,7 @@ fn run_stash(subcommand: Option<&str>, args: &[String], verbose: u8) -> Result<(
         }
         Some("pop") | Some("apply") | Some("drop") | Some("push") => {
             let sub = subcommand.unwrap();
+            let start = std::time::Instant::now();
             let mut cmd = Command::new("git");
             cmd.args(["stash", sub]);
             for arg in args {
@@ -1118,9 +1931,21 @@ fn run_stash(subcommand: Option<&str>, args: &[String], verbose: u8) -> Result<(
                 &combined,
                 &msg,
             );
+
+            let mut event = CommandEvent::new(
+                &format!("stash {sub}"),
+                output.status.code().unwrap_or(1),
+                start.elapsed().as_millis() as u64,
+            );
+            if sub != "push" {
+                event.stash_index = Some(parse_stash_index(args).unwrap_or(0));
+            }
+            let config = Config::load().unwrap_or_default();
+            crate::events::emit(&event, &config.events);
         }
         _ => {
             // Default: git stash (push)
+            let start = std::time::Instant::now();
             let mut cmd = Command::new("git");
             cmd.arg("stash");
             for arg in args {
@@ -1150,6 +1975,10 @@ fn run_stash(subcommand: Option<&str>, args: &[String], verbose: u8) -> Result<(
             };
 
             timer.track("git stash", "rtk git stash", &combined, &msg);
+
+            let event = CommandEvent::new("stash", output.status.code().unwrap_or(1), start.elapsed().as_millis() as u64);
+            let config = Config::load().unwrap_or_default();
+            crate::events::emit(&event, &config.events);
         }
     }
 
@@ -1179,6 +2008,8 @@ fn filter_stash_list(output: &str) -> String {
 
 fn run_worktree(args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
+    let start = std::time::Instant::now();
+    let config = Config::load().unwrap_or_default();
 
     if verbose > 0 {
         eprintln!("git worktree list");
@@ -1213,6 +2044,13 @@ fn run_worktree(args: &[String], verbose: u8) -> Result<()> {
             msg,
         );
 
+        let event = CommandEvent::new(
+            "worktree",
+            output.status.code().unwrap_or(1),
+            start.elapsed().as_millis() as u64,
+        );
+        crate::events::emit(&event, &config.events);
+
         if output.status.success() {
             println!("ok âœ“");
         } else {
@@ -1225,6 +2063,30 @@ fn run_worktree(args: &[String], verbose: u8) -> Result<()> {
     }
 
     // Default: list mode
+    #[cfg(feature = "libgit2-backend")]
+    if resolve_backend() == crate::git_backend::GitBackend::Libgit2 {
+        if let Ok(entries) = crate::git_backend::worktree_entries() {
+            if !entries.is_empty() {
+                let formatted = format_worktree_entries(&entries);
+                println!("{formatted}");
+                timer.track(
+                    "git worktree list",
+                    "rtk git worktree (libgit2)",
+                    &formatted,
+                    &formatted,
+                );
+
+                let event = CommandEvent::new("worktree", 0, start.elapsed().as_millis() as u64);
+                crate::events::emit(&event, &config.events);
+
+                return Ok(());
+            }
+            // No linked worktrees: `Repository::worktrees` can't report the
+            // primary checkout, so fall through to the subprocess path.
+        }
+        // Fall through to the subprocess path on any git2 error.
+    }
+
     let output = Command::new("git")
         .args(["worktree", "list"])
         .output()
@@ -1237,9 +2099,29 @@ fn run_worktree(args: &[String], verbose: u8) -> Result<()> {
     println!("{}", filtered);
     timer.track("git worktree list", "rtk git worktree", &raw, &filtered);
 
+    let event = CommandEvent::new(
+        "worktree",
+        output.status.code().unwrap_or(1),
+        start.elapsed().as_millis() as u64,
+    );
+    crate::events::emit(&event, &config.events);
+
     Ok(())
 }
 
+/// Pull the stash index out of a `pop`/`apply`/`drop` invocation's args,
+/// e.g. `["stash@{2}"]` -> `Some(2)`. Bare `git stash pop` with no args
+/// targets `stash@{0}`, which callers represent as `None` rather than
+/// guessing -- the event's `stash_index` is best-effort metadata, not a
+/// guarantee of which stash actually moved.
+fn parse_stash_index(args: &[String]) -> Option<usize> {
+    args.iter().find_map(|arg| {
+        let rest = arg.strip_prefix("stash@{")?;
+        let digits = rest.strip_suffix('}')?;
+        digits.parse().ok()
+    })
+}
+
 fn filter_worktree_list(output: &str) -> String {
     let home = dirs::home_dir()
         .map(|h| h.to_string_lossy().to_string())
@@ -1267,9 +2149,405 @@ fn filter_worktree_list(output: &str) -> String {
     result.join("\n")
 }
 
+/// One `@@ -old_start,old_len +new_start,new_len @@` hunk out of `git diff
+/// --cached`, kept together with its file path and full text (header plus
+/// body) so it can be blamed, grouped, and re-applied as its own patch.
+#[derive(Clone)]
+struct StagedHunk {
+    file: String,
+    header: String,
+    body: Vec<String>,
+    old_start: usize,
+    old_len: usize,
+}
+
+/// Split `git diff --cached` output into one [`StagedHunk`] per `@@ ... @@`
+/// region. Mirrors the file/hunk boundary detection [`DiffCompactor`] uses,
+/// but keeps the raw lines instead of folding them for display.
+fn parse_staged_hunks(diff: &str) -> Vec<StagedHunk> {
+    let mut hunks = Vec::new();
+    let mut current_file = String::new();
+    let mut file_header_lines: Vec<String> = Vec::new();
+
+    for line in diff.lines() {
+        if line.starts_with("diff --git") {
+            current_file = line.split(" b/").nth(1).unwrap_or("").to_string();
+            file_header_lines = vec![line.to_string()];
+        } else if line.starts_with("index ")
+            || line.starts_with("--- ")
+            || line.starts_with("+++ ")
+            || line.starts_with("new file mode")
+            || line.starts_with("deleted file mode")
+        {
+            file_header_lines.push(line.to_string());
+        } else if line.starts_with("@@") {
+            let (old_start, old_len) = parse_hunk_range(line);
+            hunks.push(StagedHunk {
+                file: current_file.clone(),
+                header: line.to_string(),
+                body: file_header_lines.clone(),
+                old_start,
+                old_len,
+            });
+            if let Some(last) = hunks.last_mut() {
+                last.body.push(line.to_string());
+            }
+        } else if let Some(last) = hunks.last_mut() {
+            last.body.push(line.to_string());
+        }
+    }
+
+    hunks
+}
+
+/// Parse the pre-image `-old_start,old_len` out of an `@@ -a,b +c,d @@`
+/// hunk header.
+fn parse_hunk_range(header: &str) -> (usize, usize) {
+    let range = header
+        .split("@@")
+        .nth(1)
+        .unwrap_or("")
+        .split_whitespace()
+        .next()
+        .unwrap_or("")
+        .trim_start_matches('-');
+
+    let mut parts = range.splitn(2, ',');
+    let start = parts.next().and_then(|s| s.parse().ok()).unwrap_or(1);
+    let len = parts.next().and_then(|s| s.parse().ok()).unwrap_or(1);
+    (start, len)
+}
+
+/// The full SHAs reachable in `range` (e.g. `@{upstream}..HEAD`), via `git
+/// rev-list`, newest first -- used both to restrict blame targets and to
+/// break frequency ties in favor of the most recent commit. Returns an
+/// error (rather than an empty list) when `rev-list` itself fails -- e.g.
+/// `@{upstream}..HEAD` with no upstream configured -- so callers can't
+/// mistake "range resolution failed" for "no commits in range" and
+/// mis-blame every hunk as new.
+fn commits_in_range(range: &str) -> Result<Vec<String>> {
+    let output = Command::new("git")
+        .args(["rev-list", range])
+        .output()
+        .with_context(|| format!("Failed to run git rev-list {}", range))?;
+
+    if !output.status.success() {
+        bail!(
+            "failed to resolve range '{}': {}",
+            range,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|s| s.to_string())
+        .collect())
+}
+
+/// Blame `file`'s pre-image lines `[start, start+len)` and return the full
+/// commit SHA that introduced each one, via `git blame --porcelain`.
+fn blame_lines(file: &str, start: usize, len: usize) -> Vec<String> {
+    if len == 0 {
+        return Vec::new();
+    }
+    let end = start + len - 1;
+    Command::new("git")
+        .args([
+            "blame",
+            "--porcelain",
+            "-L",
+            &format!("{},{}", start, end),
+            file,
+        ])
+        .output()
+        .map(|o| {
+            String::from_utf8_lossy(&o.stdout)
+                .lines()
+                .filter_map(|line| {
+                    let first = line.split_whitespace().next()?;
+                    // Porcelain commit lines are a 40-char hex SHA at the
+                    // start of the line (continuation lines start with a
+                    // tab or a known keyword and don't match this shape).
+                    (first.len() == 40 && first.chars().all(|c| c.is_ascii_hexdigit()))
+                        .then(|| first.to_string())
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Among `candidates`, pick the one blamed most often, breaking ties by
+/// whichever appears earliest in `range_order` (i.e. most recent).
+fn pick_fixup_target(candidates: &[String], range_order: &[String]) -> Option<String> {
+    let mut counts: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+    for sha in candidates {
+        *counts.entry(sha.as_str()).or_insert(0) += 1;
+    }
+
+    counts
+        .into_iter()
+        .max_by_key(|(sha, count)| {
+            let recency = range_order
+                .iter()
+                .position(|s| s == sha)
+                .map(|pos| range_order.len() - pos)
+                .unwrap_or(0);
+            (*count, recency)
+        })
+        .map(|(sha, _)| sha.to_string())
+}
+
+/// Route currently staged hunks into `--fixup=<sha>` commits: for each
+/// staged hunk, blame the pre-image lines it touches to find which commit
+/// in `range` last modified them, group hunks by (file, target commit),
+/// and fixup-commit each group. Hunks blamed outside `range` (brand-new
+/// lines) are skipped and reported rather than committed.
+fn run_smash(range: Option<&str>, all: bool, verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let range = range
+        .map(|r| r.to_string())
+        .unwrap_or_else(|| if all { "HEAD".to_string() } else { "@{upstream}..HEAD".to_string() });
+
+    let staged_diff = Command::new("git")
+        .args(["diff", "--cached"])
+        .output()
+        .context("Failed to run git diff --cached")?;
+    let staged_diff = String::from_utf8_lossy(&staged_diff.stdout).to_string();
+
+    if staged_diff.trim().is_empty() {
+        println!("nothing staged to smash");
+        return Ok(());
+    }
+
+    let range_order = commits_in_range(&range)
+        .with_context(|| format!("could not resolve range '{}' for git smash", range))?;
+    let allowed: std::collections::HashSet<&str> = range_order.iter().map(|s| s.as_str()).collect();
+
+    let hunks = parse_staged_hunks(&staged_diff);
+
+    // Unstage everything so each fixup commit below stages only its own
+    // hunks; anything skipped is re-applied at the end.
+    Command::new("git")
+        .args(["reset", "--quiet", "HEAD"])
+        .output()
+        .context("Failed to reset the index before smashing")?;
+
+    let mut groups: std::collections::BTreeMap<(String, String), Vec<&StagedHunk>> =
+        std::collections::BTreeMap::new();
+    let mut skipped: Vec<&StagedHunk> = Vec::new();
+
+    for hunk in &hunks {
+        let blamed = blame_lines(&hunk.file, hunk.old_start, hunk.old_len);
+        let in_range: Vec<String> = blamed.into_iter().filter(|s| allowed.contains(s.as_str())).collect();
+
+        match pick_fixup_target(&in_range, &range_order) {
+            Some(target) => {
+                groups.entry((hunk.file.clone(), target)).or_default().push(hunk);
+            }
+            None => skipped.push(hunk),
+        }
+    }
+
+    let mut summary = Vec::new();
+    // Files whose earliest group already landed a fixup commit this run --
+    // their index/HEAD has moved forward, so any later group sharing the
+    // file needs its hunks re-derived against that new baseline rather than
+    // reusing patch text captured from the original pre-reset snapshot.
+    let mut committed_files: std::collections::HashSet<String> = std::collections::HashSet::new();
+
+    for ((file, sha), file_hunks) in &groups {
+        let refreshed_owned;
+        let refreshed_refs;
+        let file_hunks: &[&StagedHunk] = if committed_files.contains(file) {
+            refreshed_owned = refresh_hunks_for_file(file, file_hunks);
+            refreshed_refs = refreshed_owned.iter().collect::<Vec<_>>();
+            &refreshed_refs
+        } else {
+            file_hunks
+        };
+        let patch = build_patch(file, file_hunks);
+
+        let apply = Command::new("git")
+            .args(["apply", "--cached", "-"])
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .and_then(|mut child| {
+                use std::io::Write;
+                child
+                    .stdin
+                    .take()
+                    .expect("piped stdin")
+                    .write_all(patch.as_bytes())?;
+                child.wait_with_output()
+            });
+
+        match apply {
+            Ok(output) if output.status.success() => {
+                let commit = Command::new("git")
+                    .args(["commit", "--no-verify", &format!("--fixup={}", sha)])
+                    .output();
+                match commit {
+                    Ok(o) if o.status.success() => {
+                        committed_files.insert(file.clone());
+                        summary.push(format!("{} -> fixup:{}", file, &sha[..7.min(sha.len())]));
+                    }
+                    _ => {
+                        summary.push(format!("{} -> failed to commit fixup for {}", file, sha));
+                    }
+                }
+            }
+            _ => {
+                // `git apply --cached` never touched the index for this
+                // group, but the hunks were unstaged by the `reset` above
+                // -- re-apply the same patch so they land back in the
+                // index instead of silently vanishing from it.
+                restage_patch(&patch);
+                summary.push(format!(
+                    "{} -> failed to stage hunk for {} (left staged, uncommitted)",
+                    file, sha
+                ));
+            }
+        }
+    }
+
+    if !skipped.is_empty() {
+        let restore = build_patch_by_hunks(&skipped);
+        restage_patch(&restore);
+        summary.push(format!(
+            "{} hunk(s) left staged: blamed commit(s) outside {}",
+            skipped.len(),
+            range
+        ));
+    }
+
+    let output = if summary.is_empty() {
+        "nothing to smash".to_string()
+    } else {
+        summary.join("\n")
+    };
+    println!("{}", output);
+
+    if verbose > 0 {
+        eprintln!("rtk git smash: range={}", range);
+    }
+
+    timer.track("git smash", "rtk git smash", &staged_diff, &output);
+
+    Ok(())
+}
+
+/// Reassemble one file's selected hunks into a standalone patch `git apply
+/// --cached` can consume: the shared `diff --git`/`---`/`+++` header from
+/// the first hunk, then each hunk's own `@@ ... @@` body.
+fn build_patch(_file: &str, hunks: &[&StagedHunk]) -> String {
+    build_patch_by_hunks(hunks)
+}
+
+/// Re-derive `original`'s hunks against `file`'s *current* working-tree vs.
+/// index diff, used when an earlier group sharing `file` has already landed
+/// a fixup commit this run: that commit advanced the file's committed
+/// baseline, so `original`'s line numbers and context (captured from the
+/// pre-reset `git diff --cached` snapshot) are stale and can fail to apply.
+/// The working tree itself never changes during `run_smash`, so each
+/// hunk's changed-line content is still a stable key for matching it to its
+/// freshly-numbered counterpart; a hunk that can't be matched (diff failed,
+/// or content shifted unexpectedly) falls back to its original, stale copy
+/// so the caller's existing `restage_patch` fallback still has something to
+/// work with instead of silently dropping it.
+fn refresh_hunks_for_file(file: &str, original: &[&StagedHunk]) -> Vec<StagedHunk> {
+    let diff = Command::new("git")
+        .args(["diff", "--", file])
+        .output()
+        .map(|o| String::from_utf8_lossy(&o.stdout).into_owned())
+        .unwrap_or_default();
+
+    if diff.trim().is_empty() {
+        return original.iter().map(|h| (*h).clone()).collect();
+    }
+
+    let fresh_hunks = parse_staged_hunks(&diff);
+
+    original
+        .iter()
+        .map(|hunk| {
+            fresh_hunks
+                .iter()
+                .find(|fresh| hunk_change_lines(fresh) == hunk_change_lines(hunk))
+                .cloned()
+                .unwrap_or_else(|| (*hunk).clone())
+        })
+        .collect()
+}
+
+/// The `+`/`-` content lines of a hunk's body (excluding the `+++`/`---`
+/// file-header lines and the `@@ ... @@` line itself) -- stable across a
+/// re-diff even when old/new line numbers and surrounding context shift.
+fn hunk_change_lines(hunk: &StagedHunk) -> Vec<&str> {
+    hunk.body
+        .iter()
+        .filter(|l| {
+            (l.starts_with('+') && !l.starts_with("+++"))
+                || (l.starts_with('-') && !l.starts_with("---"))
+        })
+        .map(|s| s.as_str())
+        .collect()
+}
+
+/// Re-apply `patch` to the index via `git apply --cached -`, best-effort:
+/// used to put hunks back into the index after `run_smash` unstaged
+/// everything but a group either didn't clear blaming (`skipped`) or
+/// failed to restage as its own fixup (`apply` error) -- in both cases the
+/// hunk must land back in the index rather than quietly disappear from it.
+fn restage_patch(patch: &str) {
+    let _ = Command::new("git")
+        .args(["apply", "--cached", "-"])
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .and_then(|mut child| {
+            use std::io::Write;
+            child.stdin.take().expect("piped stdin").write_all(patch.as_bytes())?;
+            child.wait()
+        });
+}
+
+fn build_patch_by_hunks(hunks: &[&StagedHunk]) -> String {
+    let mut patch = String::new();
+    let mut last_header: Option<&str> = None;
+    for hunk in hunks {
+        // `body` starts with the shared file header lines followed by this
+        // hunk's own `@@ ... @@` line and content; only emit the header
+        // once per file run.
+        let header_end = hunk
+            .body
+            .iter()
+            .position(|l| l.starts_with("@@"))
+            .unwrap_or(0);
+        if last_header != Some(hunk.file.as_str()) {
+            for line in &hunk.body[..header_end] {
+                patch.push_str(line);
+                patch.push('\n');
+            }
+            last_header = Some(hunk.file.as_str());
+        }
+        for line in &hunk.body[header_end..] {
+            patch.push_str(line);
+            patch.push('\n');
+        }
+    }
+    patch
+}
+
 /// Runs an unsupported git subcommand by passing it through directly
 pub fn run_passthrough(args: &[OsString], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
+    let start = std::time::Instant::now();
+    let config = Config::load().unwrap_or_default();
 
     if verbose > 0 {
         eprintln!("git passthrough: {:?}", args);
@@ -1285,16 +2563,86 @@ pub fn run_passthrough(args: &[OsString], verbose: u8) -> Result<()> {
         &format!("rtk git {} (passthrough)", args_str),
     );
 
+    let event = CommandEvent::new(
+        &format!("passthrough {args_str}"),
+        status.code().unwrap_or(1),
+        start.elapsed().as_millis() as u64,
+    );
+    crate::events::emit(&event, &config.events);
+
     if !status.success() {
         std::process::exit(status.code().unwrap_or(1));
     }
     Ok(())
 }
 
+/// Resolve the current repo's GitHub `(owner, repo)` from the `origin`
+/// remote URL, so callers like [`crate::github_api::GitHubClient`] don't
+/// need the user to pass `--repo` for every call. Handles both the SSH
+/// (`git@github.com:owner/repo.git`) and HTTPS
+/// (`https://github.com/owner/repo.git`) forms `git remote` returns.
+pub fn resolve_github_repo() -> Result<(String, String)> {
+    let output = Command::new("git")
+        .args(["remote", "get-url", "origin"])
+        .output()
+        .context("Failed to run git remote get-url origin")?;
+
+    if !output.status.success() {
+        anyhow::bail!("No `origin` remote configured");
+    }
+
+    let url = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    parse_github_remote(&url).with_context(|| format!("Not a GitHub remote: {}", url))
+}
+
+fn parse_github_remote(url: &str) -> Option<(String, String)> {
+    let path = if let Some(rest) = url.strip_prefix("git@github.com:") {
+        rest
+    } else if let Some(rest) = url.strip_prefix("https://github.com/") {
+        rest
+    } else if let Some(rest) = url.strip_prefix("ssh://git@github.com/") {
+        rest
+    } else {
+        return None;
+    };
+
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    let (owner, repo) = path.split_once('/')?;
+    if owner.is_empty() || repo.is_empty() {
+        return None;
+    }
+    Some((owner.to_string(), repo.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_github_remote_ssh() {
+        assert_eq!(
+            parse_github_remote("git@github.com:owner/repo.git"),
+            Some(("owner".to_string(), "repo".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_github_remote_https() {
+        assert_eq!(
+            parse_github_remote("https://github.com/owner/repo.git"),
+            Some(("owner".to_string(), "repo".to_string()))
+        );
+        assert_eq!(
+            parse_github_remote("https://github.com/owner/repo"),
+            Some(("owner".to_string(), "repo".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_github_remote_non_github() {
+        assert_eq!(parse_github_remote("https://gitlab.com/owner/repo.git"), None);
+    }
+
     #[test]
     fn test_compact_diff() {
         let diff = r#"diff --git a/foo.rs b/foo.rs
@@ -1331,6 +2679,41 @@ mod tests {
         assert!(!result.contains("remote-only"));
     }
 
+    #[test]
+    fn test_filter_branch_vv_ahead_behind() {
+        let output = "* main                 abc1234 [origin/main: ahead 2] wip\n  feature/auth          def5678 [origin/feature/auth: behind 1] wip\n";
+        let result = filter_branch_output(output);
+        let ahead = format_sync_indicator(Some("ahead 2")).unwrap();
+        let behind = format_sync_indicator(Some("behind 1")).unwrap();
+        assert!(result.contains(&format!("* main {ahead}")));
+        assert!(result.contains(&format!("feature/auth {behind}")));
+    }
+
+    #[test]
+    fn test_filter_branch_vv_diverged() {
+        let output =
+            "* main abc1234 [origin/main: ahead 1, behind 2] wip\n  develop def5678 [origin/develop] in sync\n";
+        let result = filter_branch_output(output);
+        let diverged = format_sync_indicator(Some("ahead 1, behind 2")).unwrap();
+        assert!(result.contains(&format!("* main {diverged}")));
+        // Fully in-sync upstream carries no suffix at all.
+        assert!(result.contains("  develop\n") || result.ends_with("  develop"));
+    }
+
+    #[test]
+    fn test_filter_branch_vv_gone() {
+        let output = "* main abc1234 [origin/main] wip\n  stale def5678 [origin/stale: gone] wip\n";
+        let result = filter_branch_output(output);
+        assert!(result.contains("stale [gone]"));
+    }
+
+    #[test]
+    fn test_filter_branch_vv_no_upstream() {
+        let output = "* main abc1234 [origin/main] wip\n  local-only def5678 untracked work\n";
+        let result = filter_branch_output(output);
+        assert!(result.contains("  local-only\n") || result.ends_with("  local-only"));
+    }
+
     #[test]
     fn test_filter_stash_list() {
         let output =
@@ -1353,14 +2736,96 @@ mod tests {
     #[test]
     fn test_format_status_output_clean() {
         let porcelain = "";
-        let result = format_status_output(porcelain);
+        let result = format_status_output(porcelain, 0, None);
         assert_eq!(result, "Clean working tree");
     }
 
+    #[test]
+    fn test_format_sync_indicator_ahead() {
+        assert_eq!(format_sync_indicator(Some("ahead 2")), Some("â‡¡2".to_string()));
+    }
+
+    #[test]
+    fn test_format_sync_indicator_behind() {
+        assert_eq!(format_sync_indicator(Some("behind 1")), Some("â‡£1".to_string()));
+    }
+
+    #[test]
+    fn test_format_sync_indicator_diverged() {
+        assert_eq!(
+            format_sync_indicator(Some("ahead 2, behind 1")),
+            Some("â‡• â‡¡2 â‡£1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_format_sync_indicator_up_to_date() {
+        assert_eq!(format_sync_indicator(None), None);
+    }
+
+    #[test]
+    fn test_format_status_output_shows_sync_and_stash() {
+        let porcelain = "## main...origin/main [ahead 2, behind 1]\n M src/main.rs\n";
+        let result = format_status_output(porcelain, 3, None);
+        assert!(result.contains("â‡• â‡¡2 â‡£1"));
+        assert!(result.contains("$3"));
+    }
+
+    #[test]
+    fn test_format_status_output_shows_describe_tag() {
+        let porcelain = "## main\n M src/main.rs\n";
+        let result = format_status_output(porcelain, 0, Some("v1.4.0-7-gabc1234"));
+        assert!(result.contains("ğŸ“Œ main (v1.4.0-7-gabc1234)"));
+    }
+
+    #[test]
+    fn test_format_status_output_renamed_separate_from_staged() {
+        let porcelain = "## main\nR  old.rs -> new.rs\nM  staged.rs\n";
+        let result = format_status_output(porcelain, 0, None);
+        assert!(result.contains("â†» Renamed: 1 files"));
+        assert!(result.contains("âœ… Staged: 1 files"));
+    }
+
+    #[test]
+    fn test_format_status_output_conflict_codes() {
+        for code in ["DD", "AU", "UD", "UA", "DU", "AA", "UU"] {
+            let porcelain = format!("## main\n{code} conflicted.rs\n");
+            let result = format_status_output(&porcelain, 0, None);
+            assert!(
+                result.contains("âš ï¸  Conflicts: 1 files"),
+                "code {code} should be counted as a conflict, got: {result}"
+            );
+            assert!(!result.contains("Staged"), "code {code} should not be staged");
+            assert!(
+                !result.contains("Modified"),
+                "code {code} should not be modified"
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_status_output_deleted_index_and_worktree() {
+        let porcelain = "## main\nD  staged_delete.rs\n D worktree_delete.rs\n";
+        let result = format_status_output(porcelain, 0, None);
+        assert!(result.contains("ğŸ—‘ Deleted: 2 files"));
+        assert!(result.contains("staged_delete.rs"));
+        assert!(result.contains("worktree_delete.rs"));
+        assert!(!result.contains("Staged"));
+        assert!(!result.contains("Modified"));
+    }
+
+    #[test]
+    fn test_format_status_output_deleted_multibyte_filename() {
+        let porcelain = "## main\nD  æ—¥æœ¬èªž.rs\n";
+        let result = format_status_output(porcelain, 0, None);
+        assert!(result.contains("ğŸ—‘ Deleted: 1 files"));
+        assert!(result.contains("æ—¥æœ¬èªž.rs"));
+    }
+
     #[test]
     fn test_format_status_output_modified_files() {
         let porcelain = "## main...origin/main\n M src/main.rs\n M src/lib.rs\n";
-        let result = format_status_output(porcelain);
+        let result = format_status_output(porcelain, 0, None);
         assert!(result.contains("ğŸ“Œ main...origin/main"));
         assert!(result.contains("ğŸ“ Modified: 2 files"));
         assert!(result.contains("src/main.rs"));
@@ -1372,7 +2837,7 @@ mod tests {
     #[test]
     fn test_format_status_output_untracked_files() {
         let porcelain = "## feature/new\n?? temp.txt\n?? debug.log\n?? test.sh\n";
-        let result = format_status_output(porcelain);
+        let result = format_status_output(porcelain, 0, None);
         assert!(result.contains("ğŸ“Œ feature/new"));
         assert!(result.contains("â“ Untracked: 3 files"));
         assert!(result.contains("temp.txt"));
@@ -1389,7 +2854,7 @@ M  staged.rs
 A  added.rs
 ?? untracked.txt
 "#;
-        let result = format_status_output(porcelain);
+        let result = format_status_output(porcelain, 0, None);
         assert!(result.contains("ğŸ“Œ main"));
         assert!(result.contains("âœ… Staged: 2 files"));
         assert!(result.contains("staged.rs"));
@@ -1412,7 +2877,7 @@ M  file5.rs
 M  file6.rs
 M  file7.rs
 "#;
-        let result = format_status_output(porcelain);
+        let result = format_status_output(porcelain, 0, None);
         assert!(result.contains("âœ… Staged: 7 files"));
         assert!(result.contains("file1.rs"));
         assert!(result.contains("file5.rs"));
@@ -1508,7 +2973,7 @@ no changes added to commit (use "git add" and/or "git commit -a")
     #[test]
     fn test_format_status_output_thai_filename() {
         let porcelain = "## main\n M à¸ªà¸§à¸±à¸ªà¸”à¸µ.txt\n?? à¸—à¸”à¸ªà¸­à¸š.rs\n";
-        let result = format_status_output(porcelain);
+        let result = format_status_output(porcelain, 0, None);
         // Should not panic
         assert!(result.contains("ğŸ“Œ main"));
         assert!(result.contains("à¸ªà¸§à¸±à¸ªà¸”à¸µ.txt"));
@@ -1518,7 +2983,199 @@ no changes added to commit (use "git add" and/or "git commit -a")
     #[test]
     fn test_format_status_output_emoji_filename() {
         let porcelain = "## main\nA  ğŸ‰-party.txt\n M æ—¥æœ¬èªãƒ•ã‚¡ã‚¤ãƒ«.rs\n";
-        let result = format_status_output(porcelain);
+        let result = format_status_output(porcelain, 0, None);
         assert!(result.contains("ğŸ“Œ main"));
     }
+
+    #[test]
+    fn test_extract_only_arg_equals_form() {
+        let args = vec!["--only=rs".to_string(), "HEAD~1".to_string()];
+        let (only, remaining) = extract_only_arg(&args);
+        assert_eq!(only, Some("rs".to_string()));
+        assert_eq!(remaining, vec!["HEAD~1".to_string()]);
+    }
+
+    #[test]
+    fn test_extract_only_arg_separate_form() {
+        let args = vec!["--only".to_string(), "src/**".to_string()];
+        let (only, remaining) = extract_only_arg(&args);
+        assert_eq!(only, Some("src/**".to_string()));
+        assert!(remaining.is_empty());
+    }
+
+    #[test]
+    fn test_extract_only_arg_absent() {
+        let args = vec!["--stat".to_string()];
+        let (only, remaining) = extract_only_arg(&args);
+        assert_eq!(only, None);
+        assert_eq!(remaining, vec!["--stat".to_string()]);
+    }
+
+    #[test]
+    fn test_normalize_only_pattern_bare_extension() {
+        assert_eq!(normalize_only_pattern("rs"), "**/*.rs");
+        assert_eq!(normalize_only_pattern(".rs"), "**/*.rs");
+    }
+
+    #[test]
+    fn test_normalize_only_pattern_glob_passthrough() {
+        assert_eq!(normalize_only_pattern("src/**/*.rs"), "src/**/*.rs");
+    }
+
+    #[test]
+    fn test_filter_diff_by_path_keeps_matching_files() {
+        let diff = "diff --git a/src/main.rs b/src/main.rs\n+fn main() {}\ndiff --git a/README.md b/README.md\n+docs\n";
+        let (filtered, hidden) = filter_diff_by_path(diff, "rs");
+        assert!(filtered.contains("src/main.rs"));
+        assert!(!filtered.contains("README.md"));
+        assert_eq!(hidden, 1);
+    }
+
+    #[test]
+    fn test_filter_diff_by_path_no_match() {
+        let diff = "diff --git a/README.md b/README.md\n+docs\n";
+        let (filtered, hidden) = filter_diff_by_path(diff, "rs");
+        assert!(filtered.is_empty());
+        assert_eq!(hidden, 1);
+    }
+
+    // --- smash: parse_staged_hunks / pick_fixup_target / build_patch_by_hunks ---
+
+    #[test]
+    fn test_parse_staged_hunks_single_file_single_hunk() {
+        let diff = "diff --git a/src/main.rs b/src/main.rs\n\
+index 1111111..2222222 100644\n\
+--- a/src/main.rs\n\
++++ b/src/main.rs\n\
+@@ -2,3 +2,4 @@ fn main() {\n\
+     let a = 1;\n\
++    let b = 2;\n\
+     let c = 3;\n";
+        let hunks = parse_staged_hunks(diff);
+        assert_eq!(hunks.len(), 1);
+        assert_eq!(hunks[0].file, "src/main.rs");
+        assert_eq!(hunks[0].old_start, 2);
+        assert_eq!(hunks[0].old_len, 3);
+        assert!(hunks[0].body.iter().any(|l| l.starts_with("diff --git")));
+        assert!(hunks[0].body.iter().any(|l| l.contains("let b = 2;")));
+    }
+
+    #[test]
+    fn test_parse_staged_hunks_multiple_hunks_share_file_header() {
+        let diff = "diff --git a/a.rs b/a.rs\n\
+--- a/a.rs\n\
++++ b/a.rs\n\
+@@ -1,2 +1,3 @@\n\
+ one\n\
++two\n\
+@@ -10,2 +11,3 @@\n\
+ ten\n\
++eleven\n";
+        let hunks = parse_staged_hunks(diff);
+        assert_eq!(hunks.len(), 2);
+        assert_eq!(hunks[0].file, "a.rs");
+        assert_eq!(hunks[1].file, "a.rs");
+        assert_eq!(hunks[0].old_start, 1);
+        assert_eq!(hunks[1].old_start, 10);
+    }
+
+    #[test]
+    fn test_parse_staged_hunks_multiple_files() {
+        let diff = "diff --git a/a.rs b/a.rs\n\
+--- a/a.rs\n\
++++ b/a.rs\n\
+@@ -1,1 +1,2 @@\n\
+ one\n\
++two\n\
+diff --git a/b.rs b/b.rs\n\
+--- a/b.rs\n\
++++ b/b.rs\n\
+@@ -5,1 +5,2 @@\n\
+ five\n\
++six\n";
+        let hunks = parse_staged_hunks(diff);
+        assert_eq!(hunks.len(), 2);
+        assert_eq!(hunks[0].file, "a.rs");
+        assert_eq!(hunks[1].file, "b.rs");
+    }
+
+    #[test]
+    fn test_pick_fixup_target_most_blamed_wins() {
+        let candidates = vec!["aaa".to_string(), "bbb".to_string(), "aaa".to_string()];
+        let range_order = vec!["aaa".to_string(), "bbb".to_string()];
+        assert_eq!(pick_fixup_target(&candidates, &range_order), Some("aaa".to_string()));
+    }
+
+    #[test]
+    fn test_pick_fixup_target_ties_break_by_recency() {
+        let candidates = vec!["old".to_string(), "new".to_string()];
+        // "new" appears earlier in range_order, i.e. is more recent.
+        let range_order = vec!["new".to_string(), "old".to_string()];
+        assert_eq!(pick_fixup_target(&candidates, &range_order), Some("new".to_string()));
+    }
+
+    #[test]
+    fn test_pick_fixup_target_empty_candidates_is_none() {
+        assert_eq!(pick_fixup_target(&[], &["aaa".to_string()]), None);
+    }
+
+    #[test]
+    fn test_build_patch_by_hunks_emits_header_once_per_file() {
+        let diff = "diff --git a/a.rs b/a.rs\n\
+--- a/a.rs\n\
++++ b/a.rs\n\
+@@ -1,2 +1,3 @@\n\
+ one\n\
++two\n\
+@@ -10,2 +11,3 @@\n\
+ ten\n\
++eleven\n";
+        let hunks = parse_staged_hunks(diff);
+        let refs: Vec<&StagedHunk> = hunks.iter().collect();
+        let patch = build_patch_by_hunks(&refs);
+        assert_eq!(patch.matches("diff --git a/a.rs b/a.rs").count(), 1);
+        assert_eq!(patch.matches("@@").count(), 4); // two "@@ ... @@" headers, two "@@" tokens each
+        assert!(patch.contains("+two"));
+        assert!(patch.contains("+eleven"));
+    }
+
+    #[test]
+    fn test_hunk_change_lines_ignores_context_and_file_header() {
+        let diff = "diff --git a/a.rs b/a.rs\n\
+--- a/a.rs\n\
++++ b/a.rs\n\
+@@ -2,3 +2,3 @@\n\
+ context\n\
+-old\n\
++new\n";
+        let hunks = parse_staged_hunks(diff);
+        assert_eq!(hunk_change_lines(&hunks[0]), vec!["-old", "+new"]);
+    }
+
+    #[test]
+    fn test_hunk_change_lines_matches_across_shifted_line_numbers() {
+        // Same content, different old_start/context -- simulates a hunk
+        // re-diffed against a new baseline after an earlier fixup commit.
+        let before = "diff --git a/a.rs b/a.rs\n\
+--- a/a.rs\n\
++++ b/a.rs\n\
+@@ -20,3 +20,3 @@\n\
+ context twenty\n\
+-old B\n\
++new B\n";
+        let after = "diff --git a/a.rs b/a.rs\n\
+--- a/a.rs\n\
++++ b/a.rs\n\
+@@ -18,3 +18,3 @@\n\
+ context eighteen\n\
+-old B\n\
++new B\n";
+        let before_hunks = parse_staged_hunks(before);
+        let after_hunks = parse_staged_hunks(after);
+        assert_ne!(before_hunks[0].old_start, after_hunks[0].old_start);
+        assert_eq!(
+            hunk_change_lines(&before_hunks[0]),
+            hunk_change_lines(&after_hunks[0])
+        );
+    }
 }
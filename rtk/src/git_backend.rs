@@ -0,0 +1,407 @@
+#![cfg(feature = "libgit2-backend")]
+
+//! Structured, single-pass alternative to the `std::process::Command`
+//! plumbing in [`crate::git`]. `run_status`/`run_diff`/`run_show`/`run_log`
+//! each shell out to `git` two or three times and re-parse its porcelain
+//! stdout; opening the repository once with `git2` and reading
+//! [`StatusEntry`]/[`DiffHunk`] values directly avoids both the repeated
+//! subprocess spawns and the string parsing. Selected via `[git] backend =
+//! "libgit2"` in the config file (see [`crate::config::GitConfig`]); the
+//! default remains [`GitBackend::Subprocess`], and any `git2` error here is
+//! meant to be caught by the caller and retried through the subprocess path
+//! rather than surfaced directly.
+
+use anyhow::{Context, Result};
+use git2::{BranchType, DiffOptions, Repository, Status, StatusOptions};
+
+/// Which engine [`crate::git::run`] uses to gather repository data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GitBackend {
+    /// Shell out to the system `git` binary (the default).
+    Subprocess,
+    /// Use the `git2` crate to read the repository directly.
+    Libgit2,
+}
+
+impl GitBackend {
+    pub fn from_config(name: &str) -> Self {
+        match name {
+            "libgit2" => GitBackend::Libgit2,
+            _ => GitBackend::Subprocess,
+        }
+    }
+}
+
+/// One file's index/worktree state, as reported by `Repository::statuses`.
+#[derive(Debug, Clone)]
+pub struct StatusEntry {
+    pub path: String,
+    pub index_status: char,
+    pub worktree_status: char,
+}
+
+/// One `@@ ... @@` hunk of a diff, with its lines already classified so
+/// [`crate::git::compact_diff`]'s line-folding logic never has to re-detect
+/// `+`/`-` prefixes out of raw patch text.
+#[derive(Debug, Clone)]
+pub struct DiffHunk {
+    pub path: String,
+    pub header: String,
+    pub lines: Vec<String>,
+    pub added: usize,
+    pub removed: usize,
+}
+
+/// One `git log` entry, pre-formatted to match `run_log`'s
+/// `--pretty=format:%h %s (%ar) <%an>` default.
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub short_hash: String,
+    pub summary: String,
+    pub author: String,
+}
+
+fn open_repo() -> Result<Repository> {
+    Repository::discover(".").context("Failed to open git repository")
+}
+
+fn index_status_char(status: Status) -> char {
+    if status.is_conflicted() {
+        'U'
+    } else if status.is_index_new() {
+        'A'
+    } else if status.is_index_modified() {
+        'M'
+    } else if status.is_index_deleted() {
+        'D'
+    } else if status.is_index_renamed() {
+        'R'
+    } else if status.is_index_typechange() {
+        'T'
+    } else {
+        ' '
+    }
+}
+
+fn worktree_status_char(status: Status) -> char {
+    if status.is_conflicted() {
+        'U'
+    } else if status.is_wt_new() {
+        '?'
+    } else if status.is_wt_modified() {
+        'M'
+    } else if status.is_wt_deleted() {
+        'D'
+    } else if status.is_wt_renamed() {
+        'R'
+    } else if status.is_wt_typechange() {
+        'T'
+    } else {
+        ' '
+    }
+}
+
+/// Equivalent of `git status --porcelain -b`, read in one pass via
+/// `Repository::statuses` instead of parsing porcelain lines.
+pub fn status_entries() -> Result<Vec<StatusEntry>> {
+    let repo = open_repo()?;
+    let mut opts = StatusOptions::new();
+    opts.include_untracked(true).renames_head_to_index(true);
+    let statuses = repo
+        .statuses(Some(&mut opts))
+        .context("Failed to read git status")?;
+
+    Ok(statuses
+        .iter()
+        .map(|entry| {
+            let status = entry.status();
+            StatusEntry {
+                path: entry.path().unwrap_or("").to_string(),
+                index_status: index_status_char(status),
+                worktree_status: worktree_status_char(status),
+            }
+        })
+        .collect())
+}
+
+/// Equivalent of `git diff` against the working directory, read in one
+/// pass via `diff_tree_to_workdir_with_index` instead of the two separate
+/// `git diff --stat` / `git diff` subprocess invocations `run_diff` uses.
+pub fn diff_hunks() -> Result<Vec<DiffHunk>> {
+    let repo = open_repo()?;
+    let head_tree = repo.head().ok().and_then(|h| h.peel_to_tree().ok());
+    let mut opts = DiffOptions::new();
+    let diff = repo
+        .diff_tree_to_workdir_with_index(head_tree.as_ref(), Some(&mut opts))
+        .context("Failed to diff HEAD tree against the working directory")?;
+
+    collect_hunks(&diff)
+}
+
+/// `git describe --tags --always`'s libgit2 equivalent: nearest tag plus
+/// commits-ahead via `DescribeOptions`, falling back to the raw short SHA
+/// in repos with no tags (mirrors `--always`) rather than erroring.
+pub fn describe_rev(rev: &str) -> String {
+    let Ok(repo) = open_repo() else {
+        return rev.to_string();
+    };
+    let Ok(object) = repo.revparse_single(rev) else {
+        return rev.to_string();
+    };
+    let mut opts = git2::DescribeOptions::new();
+    opts.describe_tags();
+    match object.describe(&opts) {
+        Ok(describe) => describe
+            .format(None)
+            .unwrap_or_else(|_| object.short_id().ok().map_or_else(
+                || rev.to_string(),
+                |buf| buf.as_str().unwrap_or(rev).to_string(),
+            )),
+        Err(_) => object
+            .short_id()
+            .ok()
+            .and_then(|buf| buf.as_str().map(str::to_string))
+            .unwrap_or_else(|| rev.to_string()),
+    }
+}
+
+/// Equivalent of `git show`'s compacted diff step, diffing `rev`'s tree
+/// against its first parent.
+pub fn show_hunks(rev: &str) -> Result<(String, Vec<DiffHunk>)> {
+    let repo = open_repo()?;
+    let object = repo
+        .revparse_single(rev)
+        .with_context(|| format!("Failed to resolve revision '{rev}'"))?;
+    let commit = object
+        .peel_to_commit()
+        .with_context(|| format!("'{rev}' is not a commit"))?;
+
+    let summary = format!(
+        "{} {} <{}>",
+        &commit.id().to_string()[..7],
+        commit.summary().unwrap_or(""),
+        commit.author().name().unwrap_or("")
+    );
+
+    let new_tree = commit.tree().context("Failed to read commit tree")?;
+    let old_tree = commit.parent(0).ok().and_then(|p| p.tree().ok());
+    let mut opts = DiffOptions::new();
+    let diff = repo
+        .diff_tree_to_tree(old_tree.as_ref(), Some(&new_tree), Some(&mut opts))
+        .context("Failed to diff commit against its parent")?;
+
+    Ok((summary, collect_hunks(&diff)?))
+}
+
+fn collect_hunks(diff: &git2::Diff) -> Result<Vec<DiffHunk>> {
+    let mut hunks: Vec<DiffHunk> = Vec::new();
+    diff.foreach(
+        &mut |_delta, _progress| true,
+        None,
+        Some(&mut |delta, hunk| {
+            let path = delta
+                .new_file()
+                .path()
+                .map(|p| p.display().to_string())
+                .unwrap_or_default();
+            let header = String::from_utf8_lossy(hunk.header()).trim().to_string();
+            hunks.push(DiffHunk {
+                path,
+                header,
+                lines: Vec::new(),
+                added: 0,
+                removed: 0,
+            });
+            true
+        }),
+        Some(&mut |_delta, _hunk, line| {
+            if let Some(last) = hunks.last_mut() {
+                let content = String::from_utf8_lossy(line.content())
+                    .trim_end_matches('\n')
+                    .to_string();
+                match line.origin() {
+                    '+' => {
+                        last.added += 1;
+                        last.lines.push(format!("+{content}"));
+                    }
+                    '-' => {
+                        last.removed += 1;
+                        last.lines.push(format!("-{content}"));
+                    }
+                    _ => last.lines.push(format!(" {content}")),
+                }
+            }
+            true
+        }),
+    )
+    .context("Failed to walk diff hunks")?;
+    Ok(hunks)
+}
+
+/// Equivalent of `run_log`'s default `--no-merges -<limit>
+/// --pretty=format:%h %s (%ar) <%an>`, via `revwalk` instead of a
+/// subprocess.
+pub fn log_entries(limit: usize) -> Result<Vec<LogEntry>> {
+    let repo = open_repo()?;
+    let mut revwalk = repo.revwalk().context("Failed to start revwalk")?;
+    revwalk.push_head().context("Failed to push HEAD")?;
+
+    let mut entries = Vec::new();
+    for oid in revwalk {
+        if entries.len() >= limit {
+            break;
+        }
+        let oid = oid.context("Failed to read commit oid")?;
+        let commit = repo.find_commit(oid)?;
+        if commit.parent_count() > 1 {
+            continue;
+        }
+        let hash = commit.id().to_string();
+        entries.push(LogEntry {
+            short_hash: hash[..7.min(hash.len())].to_string(),
+            summary: commit.summary().unwrap_or("").to_string(),
+            author: commit.author().name().unwrap_or("").to_string(),
+        });
+    }
+    Ok(entries)
+}
+
+/// One local or remote-tracking branch. `ahead`/`behind` are computed via
+/// `Repository::graph_ahead_behind` against the branch's upstream (when it
+/// has one), exact counts instead of the `[ahead N, behind M]` substring
+/// `git branch -vv` happens to print.
+#[derive(Debug, Clone)]
+pub struct BranchEntry {
+    pub name: String,
+    pub is_remote: bool,
+    pub is_head: bool,
+    pub upstream: Option<String>,
+    pub ahead: usize,
+    pub behind: usize,
+    /// `true` when `branch.<name>.merge` names an upstream but the
+    /// corresponding remote-tracking ref no longer exists -- the branch
+    /// git itself reports as `[gone]` after the remote branch was deleted.
+    pub gone: bool,
+}
+
+/// Equivalent of `git branch -a -vv`, read via `Repository::branches`
+/// instead of parsing `*`-prefixed, column-aligned text.
+pub fn branch_entries() -> Result<Vec<BranchEntry>> {
+    let repo = open_repo()?;
+    let mut entries = Vec::new();
+
+    for branch_result in repo.branches(None).context("Failed to list branches")? {
+        let (branch, branch_type) = branch_result?;
+        let name = branch.name()?.unwrap_or("").to_string();
+        let is_remote = branch_type == BranchType::Remote;
+        let is_head = branch.is_head();
+
+        let (upstream, ahead, behind, gone) = if is_remote {
+            (None, 0, 0, false)
+        } else {
+            match branch.upstream() {
+                Ok(upstream_branch) => {
+                    let upstream_name = upstream_branch.name()?.map(str::to_string);
+                    let counts = match (branch.get().target(), upstream_branch.get().target()) {
+                        (Some(local), Some(remote)) => {
+                            repo.graph_ahead_behind(local, remote).unwrap_or((0, 0))
+                        }
+                        _ => (0, 0),
+                    };
+                    (upstream_name, counts.0, counts.1, false)
+                }
+                Err(_) => {
+                    let configured = repo
+                        .branch_upstream_name(&format!("refs/heads/{name}"))
+                        .is_ok();
+                    (None, 0, 0, configured)
+                }
+            }
+        };
+
+        entries.push(BranchEntry {
+            name,
+            is_remote,
+            is_head,
+            upstream,
+            ahead,
+            behind,
+            gone,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// One `git stash list` entry.
+#[derive(Debug, Clone)]
+pub struct StashEntry {
+    pub index: usize,
+    pub message: String,
+}
+
+/// Equivalent of `git stash list`, via `Repository::stash_foreach` instead
+/// of parsing `stash@{N}: ...` lines.
+pub fn stash_entries() -> Result<Vec<StashEntry>> {
+    let mut repo = open_repo()?;
+    let mut entries = Vec::new();
+    repo.stash_foreach(|index, message, _oid| {
+        entries.push(StashEntry {
+            index,
+            message: message.to_string(),
+        });
+        true
+    })
+    .context("Failed to list stashes")?;
+    Ok(entries)
+}
+
+/// One `git worktree list` entry.
+#[derive(Debug, Clone)]
+pub struct WorktreeEntry {
+    pub name: String,
+    pub path: String,
+}
+
+/// Equivalent of `git worktree list`, via `Repository::worktrees` instead
+/// of parsing its column-aligned text.
+pub fn worktree_entries() -> Result<Vec<WorktreeEntry>> {
+    let repo = open_repo()?;
+    let names = repo.worktrees().context("Failed to list worktrees")?;
+    let mut entries = Vec::new();
+    for name in names.iter().flatten() {
+        if let Ok(worktree) = repo.find_worktree(name) {
+            entries.push(WorktreeEntry {
+                name: name.to_string(),
+                path: worktree.path().display().to_string(),
+            });
+        }
+    }
+    Ok(entries)
+}
+
+/// Best-effort `git fetch <remote>` via `git2`, authenticating through
+/// whatever the SSH agent or git credential helper already has configured.
+/// Returns the number of objects received. Any failure here (network,
+/// auth, an http/credential flow `git2` can't drive non-interactively) is
+/// meant to be caught by the caller and retried through the subprocess
+/// `git fetch`, which has much broader credential-helper support.
+pub fn fetch(remote_name: &str) -> Result<usize> {
+    let repo = open_repo()?;
+    let mut remote = repo
+        .find_remote(remote_name)
+        .with_context(|| format!("No such remote '{remote_name}'"))?;
+
+    let mut callbacks = git2::RemoteCallbacks::new();
+    callbacks.credentials(|_url, username_from_url, _allowed_types| {
+        git2::Cred::ssh_key_from_agent(username_from_url.unwrap_or("git"))
+    });
+    let mut opts = git2::FetchOptions::new();
+    opts.remote_callbacks(callbacks);
+
+    remote
+        .fetch(&[] as &[&str], Some(&mut opts), None)
+        .context("git2 fetch failed")?;
+
+    Ok(remote.stats().received_objects())
+}
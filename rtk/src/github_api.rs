@@ -0,0 +1,196 @@
+//! Native GitHub API backend for `gh_cmd`, built on a single reused
+//! `reqwest::blocking::Client` calling GitHub's GraphQL endpoint directly.
+//!
+//! Used instead of shelling out to `gh` whenever a token is available from
+//! `$GH_TOKEN`/`$GITHUB_TOKEN` or the `gh auth login` config file - this
+//! skips the subprocess launch, and because each query selects exactly the
+//! fields the compressed output needs, fetches PR metadata + reviews +
+//! checks in a single round trip instead of `gh`'s several.
+
+use crate::git;
+use anyhow::{Context, Result};
+use serde_json::Value;
+
+const GRAPHQL_ENDPOINT: &str = "https://api.github.com/graphql";
+
+pub struct GitHubClient {
+    client: reqwest::blocking::Client,
+    token: String,
+    owner: String,
+    repo: String,
+}
+
+impl GitHubClient {
+    /// Build a client if a token and a GitHub `owner/repo` can both be
+    /// resolved, or `None` so callers fall back to shelling out to `gh`.
+    pub fn discover() -> Option<Self> {
+        let token = resolve_token()?;
+        let (owner, repo) = git::resolve_github_repo().ok()?;
+        let client = reqwest::blocking::Client::builder()
+            .user_agent("rtk")
+            .build()
+            .ok()?;
+        Some(Self {
+            client,
+            token,
+            owner,
+            repo,
+        })
+    }
+
+    fn graphql(&self, query: &str, variables: Value) -> Result<Value> {
+        let body = serde_json::json!({ "query": query, "variables": variables });
+        let response = self
+            .client
+            .post(GRAPHQL_ENDPOINT)
+            .bearer_auth(&self.token)
+            .json(&body)
+            .send()
+            .context("Failed to call GitHub GraphQL API")?;
+
+        let status = response.status();
+        let json: Value = response
+            .json()
+            .context("Failed to parse GitHub GraphQL response")?;
+
+        if !status.is_success() || json.get("errors").is_some() {
+            anyhow::bail!("GitHub GraphQL API error: {}", json);
+        }
+
+        Ok(json)
+    }
+
+    /// Fetch exactly the fields `gh_cmd::view_pr` renders - number, title,
+    /// state, author, mergeable, review states, and check conclusions - in
+    /// the same shape `gh pr view --json ...` returns them in, so the
+    /// rendering code doesn't need to know which backend answered it.
+    pub fn view_pr(&self, number: u64) -> Result<Value> {
+        let query = r#"
+            query($owner: String!, $repo: String!, $number: Int!) {
+                repository(owner: $owner, name: $repo) {
+                    pullRequest(number: $number) {
+                        number
+                        title
+                        state
+                        url
+                        body
+                        mergeable
+                        author { login }
+                        reviews(first: 100) { nodes { state } }
+                        commits(last: 1) {
+                            nodes {
+                                commit {
+                                    statusCheckRollup {
+                                        contexts(first: 100) {
+                                            nodes {
+                                                ... on CheckRun { conclusion }
+                                                ... on StatusContext { state }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let variables = serde_json::json!({
+            "owner": self.owner,
+            "repo": self.repo,
+            "number": number,
+        });
+
+        let response = self.graphql(query, variables)?;
+        let pr = response
+            .pointer("/data/repository/pullRequest")
+            .cloned()
+            .context("PR not found")?;
+
+        let checks = pr
+            .pointer("/commits/nodes/0/commit/statusCheckRollup/contexts/nodes")
+            .cloned()
+            .unwrap_or_else(|| Value::Array(Vec::new()));
+
+        Ok(serde_json::json!({
+            "number": pr["number"],
+            "title": pr["title"],
+            "state": pr["state"],
+            "url": pr["url"],
+            "body": pr["body"],
+            "mergeable": pr["mergeable"],
+            "author": pr["author"],
+            "reviews": { "nodes": pr["reviews"]["nodes"] },
+            "statusCheckRollup": checks,
+        }))
+    }
+}
+
+/// Resolve a GitHub token from `$GH_TOKEN`/`$GITHUB_TOKEN`, falling back to
+/// the `gh auth login` config file (`~/.config/gh/hosts.yml`), which stores
+/// `oauth_token: <token>` under the `github.com:` host. Returns `None` if
+/// neither source has one, so callers fall back to shelling out to `gh`.
+fn resolve_token() -> Option<String> {
+    if let Ok(token) = std::env::var("GH_TOKEN") {
+        if !token.is_empty() {
+            return Some(token);
+        }
+    }
+    if let Ok(token) = std::env::var("GITHUB_TOKEN") {
+        if !token.is_empty() {
+            return Some(token);
+        }
+    }
+
+    let config_dir = dirs::config_dir()?;
+    let hosts_path = config_dir.join("gh").join("hosts.yml");
+    let contents = std::fs::read_to_string(hosts_path).ok()?;
+    token_from_hosts_yaml(&contents)
+}
+
+/// Pull `oauth_token: <value>` out of `gh`'s hosts.yml without a full YAML
+/// parser - the file is a simple two-level mapping keyed by hostname, and
+/// this is the only field rtk needs from it.
+fn token_from_hosts_yaml(contents: &str) -> Option<String> {
+    let mut in_github_host = false;
+    for line in contents.lines() {
+        let trimmed = line.trim_end();
+        if !trimmed.starts_with(' ') && !trimmed.starts_with('\t') {
+            in_github_host = trimmed.trim_end_matches(':') == "github.com";
+            continue;
+        }
+        if in_github_host {
+            if let Some(value) = trimmed.trim().strip_prefix("oauth_token:") {
+                let token = value.trim().trim_matches('"');
+                if !token.is_empty() {
+                    return Some(token.to_string());
+                }
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_from_hosts_yaml() {
+        let yaml = "github.com:\n    oauth_token: gho_abc123\n    user: octocat\n";
+        assert_eq!(token_from_hosts_yaml(yaml), Some("gho_abc123".to_string()));
+    }
+
+    #[test]
+    fn test_token_from_hosts_yaml_missing() {
+        let yaml = "example.com:\n    oauth_token: gho_xyz\n";
+        assert_eq!(token_from_hosts_yaml(yaml), None);
+    }
+
+    #[test]
+    fn test_token_from_hosts_yaml_wrong_host() {
+        let yaml = "example.com:\n    oauth_token: gho_xyz\ngithub.com:\n    oauth_token: gho_real\n";
+        assert_eq!(token_from_hosts_yaml(yaml), Some("gho_real".to_string()));
+    }
+}
@@ -0,0 +1,173 @@
+//! Shared shell-style glob scoping for `grep` and `ruff`.
+//!
+//! Ripgrep has its own `--glob`/`--type` flags and ruff has none at all, so
+//! instead of leaning on either tool's native syntax, both wrappers accept
+//! the same `--glob PATTERN` flag (repeatable, `!`-prefixed for negation)
+//! and filter their already-collected results by path. That gives
+//! consistent scoping independent of what the underlying tool supports.
+
+use regex::Regex;
+
+/// One compiled `--glob` pattern plus whether it's a negation (`!pattern`).
+struct GlobPattern {
+    regex: Regex,
+    negate: bool,
+}
+
+/// Translate a shell-style glob into an anchored regex: escape regex
+/// metacharacters, then map `**` -> `.*`, `*` -> `[^/]*`, `?` -> `[^/]`, and
+/// pass `[...]` character classes through unchanged. A leading `!` marks a
+/// negation pattern and is stripped before compiling.
+fn glob_to_regex(glob: &str) -> Result<GlobPattern, regex::Error> {
+    let (negate, glob) = match glob.strip_prefix('!') {
+        Some(rest) => (true, rest),
+        None => (false, glob),
+    };
+
+    let chars: Vec<char> = glob.chars().collect();
+    let mut pattern = String::from("^");
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '*' => {
+                if chars.get(i + 1) == Some(&'*') {
+                    pattern.push_str(".*");
+                    i += 2;
+                } else {
+                    pattern.push_str("[^/]*");
+                    i += 1;
+                }
+            }
+            '?' => {
+                pattern.push_str("[^/]");
+                i += 1;
+            }
+            '[' => {
+                let start = i;
+                while i < chars.len() && chars[i] != ']' {
+                    i += 1;
+                }
+                if i < chars.len() {
+                    i += 1; // include the closing ']'
+                }
+                pattern.push_str(&chars[start..i].iter().collect::<String>());
+            }
+            c => {
+                pattern.push_str(&regex::escape(&c.to_string()));
+                i += 1;
+            }
+        }
+    }
+    pattern.push('$');
+
+    Ok(GlobPattern {
+        regex: Regex::new(&pattern)?,
+        negate,
+    })
+}
+
+/// A set of `--glob` patterns, applied the way ripgrep/git apply override
+/// patterns: a path is kept if it matches at least one positive pattern (or
+/// there are no positive patterns) and matches no negation pattern.
+#[derive(Default)]
+pub struct GlobFilter {
+    patterns: Vec<GlobPattern>,
+}
+
+impl GlobFilter {
+    pub fn new(globs: &[String]) -> Result<Self, regex::Error> {
+        let patterns = globs
+            .iter()
+            .map(|g| glob_to_regex(g))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { patterns })
+    }
+
+    pub fn keep(&self, path: &str) -> bool {
+        if self.patterns.is_empty() {
+            return true;
+        }
+
+        let mut positives = self.patterns.iter().filter(|p| !p.negate).peekable();
+        let has_positive_match =
+            positives.peek().is_none() || positives.any(|p| p.regex.is_match(path));
+
+        let negated = self
+            .patterns
+            .iter()
+            .any(|p| p.negate && p.regex.is_match(path));
+
+        has_positive_match && !negated
+    }
+}
+
+/// Pull every `--glob PATTERN` / `--glob=PATTERN` occurrence out of `args`,
+/// returning the collected patterns and the remaining args with those
+/// tokens removed. Mirrors how `ruff_cmd` strips its own non-passthrough
+/// flags (`--diff`, `--changed-only`) before building the child command.
+pub fn extract_glob_args(args: &[String]) -> (Vec<String>, Vec<String>) {
+    let mut globs = Vec::new();
+    let mut remaining = Vec::new();
+
+    let mut iter = args.iter().enumerate().peekable();
+    while let Some((i, arg)) = iter.next() {
+        if let Some(value) = arg.strip_prefix("--glob=") {
+            globs.push(value.to_string());
+        } else if arg == "--glob" {
+            if let Some(value) = args.get(i + 1) {
+                globs.push(value.clone());
+                iter.next();
+            }
+        } else {
+            remaining.push(arg.clone());
+        }
+    }
+
+    (globs, remaining)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_star_matches_within_segment() {
+        let filter = GlobFilter::new(&["**/*.py".to_string()]).unwrap();
+        assert!(filter.keep("src/pkg/main.py"));
+        assert!(!filter.keep("src/pkg/main.rs"));
+    }
+
+    #[test]
+    fn test_glob_negation_excludes() {
+        let filter =
+            GlobFilter::new(&["**/*.py".to_string(), "!**/tests/**".to_string()]).unwrap();
+        assert!(filter.keep("src/pkg/main.py"));
+        assert!(!filter.keep("src/tests/test_main.py"));
+    }
+
+    #[test]
+    fn test_no_patterns_keeps_everything() {
+        let filter = GlobFilter::new(&[]).unwrap();
+        assert!(filter.keep("anything/at/all.rs"));
+    }
+
+    #[test]
+    fn test_single_star_does_not_cross_slash() {
+        let filter = GlobFilter::new(&["src/*.py".to_string()]).unwrap();
+        assert!(filter.keep("src/main.py"));
+        assert!(!filter.keep("src/pkg/main.py"));
+    }
+
+    #[test]
+    fn test_extract_glob_args_space_and_equals_forms() {
+        let args = vec![
+            "--glob".to_string(),
+            "**/*.py".to_string(),
+            "-v".to_string(),
+            "--glob=!**/tests/**".to_string(),
+        ];
+        let (globs, remaining) = extract_glob_args(&args);
+        assert_eq!(globs, vec!["**/*.py".to_string(), "!**/tests/**".to_string()]);
+        assert_eq!(remaining, vec!["-v".to_string()]);
+    }
+}
@@ -5,6 +5,7 @@ use serde::Deserialize;
 use std::collections::HashMap;
 use std::ffi::OsString;
 use std::process::Command;
+use std::sync::OnceLock;
 
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
@@ -29,11 +30,110 @@ struct PackageResult {
     fail: usize,
     skip: usize,
     failed_tests: Vec<(String, Vec<String>)>, // (test_name, output_lines)
+    test_elapsed: Vec<(String, f64)>,         // (test_name, seconds), from pass/fail/skip events
+    total_elapsed: f64,                       // from the package-level pass/fail event
+    outcomes: Vec<(String, bool)>,            // (test_name, passed), from pass/fail events only
+    coverage_pct: Option<f64>,                // from a "coverage: NN.N% of statements" output line
 }
 
-pub fn run_test(args: &[String], verbose: u8) -> Result<()> {
-    let timer = tracking::TimedExecution::start();
+/// Pull the percentage out of a `go test` output line like
+/// `coverage: 82.4% of statements`, as printed by `-cover`/`-coverprofile`.
+fn parse_coverage_line(line: &str) -> Option<f64> {
+    let rest = line.split_once("coverage:")?.1.trim_start();
+    rest.split('%').next()?.trim().parse().ok()
+}
+
+/// Outcome of a single `go <subcommand>` invocation, shared between the
+/// standalone `run_*` entry points and the combined [`run_check`] pipeline
+/// so both can report the same raw/filtered output and exit code without
+/// duplicating the `Command` plumbing.
+struct GoPhaseResult {
+    raw: String,
+    stderr: String,
+    filtered: String,
+    success: bool,
+    exit_code: i32,
+}
+
+fn exec_go_vet(args: &[String], verbose: u8) -> Result<GoPhaseResult> {
+    let mut cmd = Command::new("go");
+    cmd.arg("vet");
+
+    for arg in args {
+        cmd.arg(arg);
+    }
+
+    if verbose > 0 {
+        eprintln!("Running: go vet {}", args.join(" "));
+    }
+
+    let output = cmd
+        .output()
+        .context("Failed to run go vet. Is Go installed?")?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+    let filtered = filter_go_vet(&raw);
+
+    Ok(GoPhaseResult {
+        raw,
+        stderr: stderr.into_owned(),
+        filtered,
+        success: output.status.success(),
+        exit_code: output.status.code().unwrap_or(1),
+    })
+}
+
+fn exec_go_build(args: &[String], verbose: u8) -> Result<GoPhaseResult> {
+    let mut cmd = Command::new("go");
+    cmd.arg("build");
+
+    for arg in args {
+        cmd.arg(arg);
+    }
+
+    if verbose > 0 {
+        eprintln!("Running: go build {}", args.join(" "));
+    }
+
+    let output = cmd
+        .output()
+        .context("Failed to run go build. Is Go installed?")?;
 
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+    let filtered = filter_go_build(&raw);
+
+    Ok(GoPhaseResult {
+        raw,
+        stderr: stderr.into_owned(),
+        filtered,
+        success: output.status.success(),
+        exit_code: output.status.code().unwrap_or(1),
+    })
+}
+
+fn exec_go_test(
+    args: &[String],
+    verbose: u8,
+    timings: Option<TimingsOptions>,
+    coverage: Option<&CoverageOptions>,
+) -> Result<GoPhaseResult> {
+    let (mut result, packages) = exec_go_test_parsed(args, verbose)?;
+    result.filtered = format_go_test_summary(&packages, timings.as_ref(), coverage);
+    Ok(result)
+}
+
+/// Run `go test -json` and return both the raw [`GoPhaseResult`] (with an
+/// empty `filtered` field -- callers fill it in) and the parsed per-package
+/// results, so `--watch` mode can diff two runs' [`PackageResult`]s without
+/// re-parsing rendered summary text.
+fn exec_go_test_parsed(
+    args: &[String],
+    verbose: u8,
+) -> Result<(GoPhaseResult, HashMap<String, PackageResult>)> {
     let mut cmd = Command::new("go");
     cmd.arg("test");
 
@@ -57,69 +157,477 @@ pub fn run_test(args: &[String], verbose: u8) -> Result<()> {
     let stdout = String::from_utf8_lossy(&output.stdout);
     let stderr = String::from_utf8_lossy(&output.stderr);
     let raw = format!("{}\n{}", stdout, stderr);
+    let packages = parse_go_test_output(&stdout);
+
+    let result = GoPhaseResult {
+        raw,
+        stderr: stderr.into_owned(),
+        filtered: String::new(),
+        success: output.status.success(),
+        exit_code: output.status.code().unwrap_or(1),
+    };
+    Ok((result, packages))
+}
+
+/// Options for the optional "slowest tests" section appended to the
+/// `go test` report, requested with `--timings` (see [`extract_timings`]).
+#[derive(Clone, Copy)]
+struct TimingsOptions {
+    /// Only tests at or above this elapsed time (in milliseconds) are
+    /// reported.
+    threshold_ms: f64,
+    /// How many slowest tests (and slowest packages) to list.
+    top_n: usize,
+}
+
+/// Pull `--timings` (and its optional `--timings-threshold <ms>` /
+/// `--timings-top <n>` companions) out of the args meant for `go test`,
+/// returning the parsed options -- `None` unless `--timings` was present --
+/// and the remaining args with all three tokens and their values removed.
+fn extract_timings(args: &[String]) -> (Option<TimingsOptions>, Vec<String>) {
+    let mut enabled = false;
+    let mut threshold_ms = 100.0;
+    let mut top_n = 10;
+    let mut rest = Vec::with_capacity(args.len());
+
+    let mut iter = args.iter().cloned();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--timings" => enabled = true,
+            "--timings-threshold" => {
+                if let Some(value) = iter.next() {
+                    threshold_ms = value.parse().unwrap_or(threshold_ms);
+                }
+            }
+            "--timings-top" => {
+                if let Some(value) = iter.next() {
+                    top_n = value.parse().unwrap_or(top_n);
+                }
+            }
+            _ => rest.push(arg),
+        }
+    }
+
+    if enabled {
+        (Some(TimingsOptions { threshold_ms, top_n }), rest)
+    } else {
+        (None, rest)
+    }
+}
 
-    let filtered = filter_go_test_json(&stdout);
+/// Options for the optional "📊 Coverage" section, turned on by passing
+/// go's own `-cover`/`-coverprofile=FILE` to `run_test` (see
+/// [`extract_coverage`]) -- no separate rtk flag needed to enable it.
+struct CoverageOptions {
+    /// Path `go test` will write the coverage profile to, if
+    /// `-coverprofile` was passed; used to compute the overall statement
+    /// coverage figure (more precise than averaging per-package percentages).
+    coverprofile: Option<String>,
+    /// Packages below this percentage are flagged in the report.
+    threshold_pct: Option<f64>,
+}
 
-    println!("{}", filtered);
+/// Detect go's own `-cover`/`-coverprofile=FILE` (or `-coverprofile FILE`)
+/// flags in args meant for `go test`, plus the rtk-only
+/// `--coverage-threshold <pct>` flag, returning the parsed options --
+/// `None` unless coverage was requested. Go's own flags are left in
+/// `rest` so `go test` still receives them; only the rtk-only flag is
+/// stripped.
+fn extract_coverage(args: &[String]) -> (Option<CoverageOptions>, Vec<String>) {
+    let mut enabled = false;
+    let mut coverprofile = None;
+    let mut threshold_pct = None;
+    let mut rest = Vec::with_capacity(args.len());
+
+    let mut iter = args.iter().cloned();
+    while let Some(arg) = iter.next() {
+        if arg == "-cover" {
+            enabled = true;
+            rest.push(arg);
+        } else if let Some(path) = arg.strip_prefix("-coverprofile=") {
+            enabled = true;
+            coverprofile = Some(path.to_string());
+            rest.push(arg.clone());
+        } else if arg == "-coverprofile" {
+            enabled = true;
+            rest.push(arg);
+            if let Some(path) = iter.next() {
+                coverprofile = Some(path.clone());
+                rest.push(path);
+            }
+        } else if arg == "--coverage-threshold" {
+            if let Some(value) = iter.next() {
+                threshold_pct = value.parse().ok();
+            }
+        } else {
+            rest.push(arg);
+        }
+    }
+
+    if enabled {
+        (
+            Some(CoverageOptions {
+                coverprofile,
+                threshold_pct,
+            }),
+            rest,
+        )
+    } else {
+        (None, rest)
+    }
+}
+
+/// Parse a `go test -coverprofile` file's contents (`mode: set` header
+/// followed by `file:startline.col,endline.col numStmt count` records)
+/// into `(covered_statements, total_statements)`, used to compute the
+/// overall coverage figure from real statement counts rather than
+/// averaging the per-package percentages `go test` already printed.
+fn parse_coverprofile_text(contents: &str) -> Option<(u64, u64)> {
+    let mut covered = 0u64;
+    let mut total = 0u64;
+
+    for line in contents.lines().skip(1) {
+        let mut fields = line.rsplit(' ');
+        let count: u64 = fields.next()?.parse().ok()?;
+        let num_stmt: u64 = fields.next()?.parse().ok()?;
+        total += num_stmt;
+        if count > 0 {
+            covered += num_stmt;
+        }
+    }
+
+    Some((covered, total))
+}
+
+fn parse_coverprofile(path: &std::path::Path) -> Option<(u64, u64)> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    parse_coverprofile_text(&contents)
+}
+
+/// Pull the rtk-only `--retry-failed=N` flag out of args meant for
+/// `go test`, returning the parsed retry count (`None` unless the flag was
+/// present) and the remaining args with it stripped -- `go test` has no
+/// notion of this flag, so unlike `-cover`/`-coverprofile` it's never
+/// forwarded.
+fn extract_retry_failed(args: &[String]) -> (Option<u32>, Vec<String>) {
+    let mut max_retries = None;
+    let mut rest = Vec::with_capacity(args.len());
+
+    for arg in args {
+        if let Some(n) = arg.strip_prefix("--retry-failed=") {
+            max_retries = n.parse().ok();
+        } else {
+            rest.push(arg.clone());
+        }
+    }
+
+    (max_retries, rest)
+}
+
+/// Outcome of retrying a run's failed tests: each failed test ends up
+/// either "flaky" (passed on at least one retry) or "consistently
+/// failing" (failed on every retry), labelled `package::test` the same
+/// way [`format_watch_delta`] labels tests.
+#[derive(Default)]
+struct RetryOutcome {
+    raw: String,
+    flaky: Vec<String>,
+    consistently_failing: Vec<String>,
+}
+
+/// Re-run only the tests that failed in `packages`, up to `max_retries`
+/// times, narrowing the `-run` pattern each round to whichever tests are
+/// still failing. Stops early once none remain. A test that fails every
+/// retry is "consistently failing"; one that passes at least once along
+/// the way is "flaky".
+fn retry_failed_tests(
+    args: &[String],
+    verbose: u8,
+    packages: &HashMap<String, PackageResult>,
+    max_retries: u32,
+) -> Result<RetryOutcome> {
+    let mut still_failing: Vec<(String, String)> = packages
+        .iter()
+        .flat_map(|(package, pkg_result)| {
+            pkg_result
+                .failed_tests
+                .iter()
+                .map(move |(test, _)| (package.clone(), test.clone()))
+        })
+        .collect();
+
+    let mut outcome = RetryOutcome::default();
+    if still_failing.is_empty() {
+        return Ok(outcome);
+    }
+
+    for attempt in 1..=max_retries {
+        if still_failing.is_empty() {
+            break;
+        }
+
+        let pattern = format!(
+            "^({})$",
+            still_failing
+                .iter()
+                .map(|(_, test)| test.as_str())
+                .collect::<Vec<_>>()
+                .join("|")
+        );
+        let mut retry_args = args.to_vec();
+        retry_args.push("-run".to_string());
+        retry_args.push(pattern);
+        retry_args.push("-count=1".to_string());
+
+        if verbose > 0 {
+            eprintln!(
+                "Retry {}/{}: re-running {} failed test(s)",
+                attempt,
+                max_retries,
+                still_failing.len()
+            );
+        }
+
+        let (retry_result, retry_packages) = exec_go_test_parsed(&retry_args, verbose)?;
+        outcome.raw.push('\n');
+        outcome.raw.push_str(&retry_result.raw);
+
+        let retry_outcomes = collect_test_outcomes(&retry_packages);
+        let mut next_still_failing = Vec::new();
+        for key @ (package, test) in still_failing {
+            let label = format!("{}::{}", compact_package_name(&package), test);
+            match retry_outcomes.get(&key) {
+                Some(true) => outcome.flaky.push(label),
+                _ => next_still_failing.push(key),
+            }
+        }
+        still_failing = next_still_failing;
+    }
+
+    outcome.consistently_failing = still_failing
+        .into_iter()
+        .map(|(package, test)| format!("{}::{}", compact_package_name(&package), test))
+        .collect();
+
+    Ok(outcome)
+}
+
+/// Append a "🔁 Retry results" section separating flaky tests (failed
+/// initially, passed on a later retry) from consistently failing ones, so
+/// CI noise from flakiness doesn't get mistaken for a real regression.
+fn append_retry_section(result: &mut String, outcome: &RetryOutcome) {
+    if outcome.flaky.is_empty() && outcome.consistently_failing.is_empty() {
+        return;
+    }
+
+    result.push_str("\n\n🔁 Retry results\n");
+    result.push_str("═══════════════════════════════════════\n");
+
+    if !outcome.flaky.is_empty() {
+        result.push_str(&format!("  flaky (passed on retry): {}\n", outcome.flaky.join(", ")));
+    }
+    if !outcome.consistently_failing.is_empty() {
+        result.push_str(&format!(
+            "  consistently failing: {}\n",
+            outcome.consistently_failing.join(", ")
+        ));
+    }
+}
+
+pub fn run_test(args: &[String], verbose: u8) -> Result<()> {
+    if args.iter().any(|a| a == "--watch") {
+        let args: Vec<String> = args.iter().filter(|a| *a != "--watch").cloned().collect();
+        return run_test_watch(&args, verbose);
+    }
+
+    let timer = tracking::TimedExecution::start();
+
+    let (timings, args) = extract_timings(args);
+    let (coverage, args) = extract_coverage(&args);
+    let (retry_failed, args) = extract_retry_failed(&args);
+    let (mut result, packages) = exec_go_test_parsed(&args, verbose)?;
+    result.filtered = format_go_test_summary(&packages, timings.as_ref(), coverage.as_ref());
+
+    // Only tests that actually failed get retried, and only a consistently
+    // failing test keeps the run's exit code nonzero -- a test that's
+    // merely flaky shouldn't block CI.
+    if let Some(max_retries) = retry_failed {
+        let retry_outcome = retry_failed_tests(&args, verbose, &packages, max_retries)?;
+        append_retry_section(&mut result.filtered, &retry_outcome);
+        result.raw.push_str(&retry_outcome.raw);
+        result.success = retry_outcome.consistently_failing.is_empty();
+    }
+
+    println!("{}", result.filtered);
 
     // Include stderr if present (build errors, etc.)
-    if !stderr.trim().is_empty() {
-        eprintln!("{}", stderr.trim());
+    if !result.stderr.trim().is_empty() {
+        eprintln!("{}", result.stderr.trim());
     }
 
     timer.track(
         &format!("go test {}", args.join(" ")),
         &format!("rtk go test {}", args.join(" ")),
-        &raw,
-        &filtered,
+        &normalize_go_output(&result.raw),
+        &normalize_go_output(&result.filtered),
     );
 
     // Preserve exit code for CI/CD
-    if !output.status.success() {
-        std::process::exit(output.status.code().unwrap_or(1));
+    if !result.success {
+        std::process::exit(result.exit_code);
     }
 
     Ok(())
 }
 
-pub fn run_build(args: &[String], verbose: u8) -> Result<()> {
-    let timer = tracking::TimedExecution::start();
+/// `rtk go test --watch`: run the suite once (printing the full summary),
+/// then drive our own debounced file watcher (the same split
+/// `rtk lint --watch`/`rtk vitest watch` use) that re-runs `go test -json`
+/// on `.go` changes under the current directory. Every re-run prints only
+/// the delta against the previous run's pass/fail set -- "newly failing"/
+/// "now passing"/"still failing" -- instead of the full summary, since
+/// that's already been shown once and the agent only cares what changed.
+/// Only the last run's raw/filtered output reaches `timer.track`, recorded
+/// once the watcher stops, so a single editing session doesn't flood the
+/// command history with one record per save.
+fn run_test_watch(args: &[String], verbose: u8) -> Result<()> {
+    use notify::{RecursiveMode, Watcher};
+    use std::sync::mpsc::channel;
+    use std::time::Duration;
+
+    let (timings, args) = extract_timings(args);
+    let root = std::env::current_dir()
+        .context("Failed to resolve working directory for go test watch")?;
+
+    let (mut result, packages) = exec_go_test_parsed(&args, verbose)?;
+    result.filtered = format_go_test_summary(&packages, timings.as_ref(), None);
+    println!("{}", result.filtered);
+    // Note: coverage is intentionally omitted from watch mode's summary and
+    // deltas -- coverage is computed from a profile file that reflects the
+    // whole run, not what changed since the last save.
+    println!("Watching {}… (Ctrl-C to stop)", root.display());
+
+    let mut previous_outcomes = collect_test_outcomes(&packages);
+
+    let (tx, rx) = channel();
+    let mut watcher = notify::recommended_watcher(move |res| {
+        let _ = tx.send(res);
+    })
+    .context("Failed to start file watcher")?;
+    watcher
+        .watch(&root, RecursiveMode::Recursive)
+        .context("Failed to watch directory")?;
+
+    loop {
+        if rx.recv().is_err() {
+            break;
+        }
+        // Drain anything else that arrives within the debounce window so a
+        // single save (which fires several OS events) triggers exactly one
+        // re-run.
+        while rx.recv_timeout(Duration::from_millis(300)).is_ok() {}
+
+        print!("\x1B[2J\x1B[1;1H");
+        match exec_go_test_parsed(&args, verbose) {
+            Ok((next_result, next_packages)) => {
+                result = next_result;
+                let outcomes = collect_test_outcomes(&next_packages);
+                println!("{}", format_watch_delta(&previous_outcomes, &outcomes));
+                previous_outcomes = outcomes;
+            }
+            Err(e) => eprintln!("⚠️  go test run failed: {}", e),
+        }
+        println!("Watching {}… (Ctrl-C to stop)", root.display());
+    }
 
-    let mut cmd = Command::new("go");
-    cmd.arg("build");
+    tracking::TimedExecution::start().track(
+        &format!("go test --watch {}", args.join(" ")),
+        &format!("rtk go test --watch {}", args.join(" ")),
+        &result.raw,
+        &result.filtered,
+    );
 
-    for arg in args {
-        cmd.arg(arg);
+    Ok(())
+}
+
+/// Reduce a parsed test run down to (package, test) -> passed, the minimal
+/// shape [`format_watch_delta`] needs to diff two runs against each other.
+fn collect_test_outcomes(packages: &HashMap<String, PackageResult>) -> HashMap<(String, String), bool> {
+    packages
+        .iter()
+        .flat_map(|(package, result)| {
+            result
+                .outcomes
+                .iter()
+                .map(move |(test, passed)| ((package.clone(), test.clone()), *passed))
+        })
+        .collect()
+}
+
+/// Compare two runs' pass/fail sets and render a compact delta: tests that
+/// flipped from passing to failing, tests that flipped the other way, and
+/// a count of tests still failing in both runs. A test absent from
+/// `previous` (new test, or first run after adding a file) counts as
+/// "newly failing" only if it's currently failing.
+fn format_watch_delta(
+    previous: &HashMap<(String, String), bool>,
+    current: &HashMap<(String, String), bool>,
+) -> String {
+    let mut newly_failing: Vec<String> = Vec::new();
+    let mut now_passing: Vec<String> = Vec::new();
+    let mut still_failing = 0usize;
+
+    for (key, &passed) in current {
+        let label = format!("{}::{}", compact_package_name(&key.0), key.1);
+        match previous.get(key) {
+            Some(&was_passing) if was_passing && !passed => newly_failing.push(label),
+            Some(&was_passing) if !was_passing && passed => now_passing.push(label),
+            Some(&was_passing) if !was_passing && !passed => still_failing += 1,
+            Some(_) => {}
+            None if !passed => newly_failing.push(label),
+            None => {}
+        }
     }
 
-    if verbose > 0 {
-        eprintln!("Running: go build {}", args.join(" "));
+    newly_failing.sort();
+    now_passing.sort();
+
+    if newly_failing.is_empty() && now_passing.is_empty() && still_failing == 0 {
+        return "✓ No change: all tests passing".to_string();
     }
 
-    let output = cmd
-        .output()
-        .context("Failed to run go build. Is Go installed?")?;
+    let mut lines = Vec::new();
+    if !newly_failing.is_empty() {
+        lines.push(format!("newly failing: {}", newly_failing.join(", ")));
+    }
+    if !now_passing.is_empty() {
+        lines.push(format!("now passing: {}", now_passing.join(", ")));
+    }
+    if still_failing > 0 {
+        lines.push(format!("still failing: {}", still_failing));
+    }
+    lines.join("\n")
+}
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
-    let raw = format!("{}\n{}", stdout, stderr);
+pub fn run_build(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
 
-    let filtered = filter_go_build(&raw);
+    let result = exec_go_build(args, verbose)?;
 
-    if !filtered.is_empty() {
-        println!("{}", filtered);
+    if !result.filtered.is_empty() {
+        println!("{}", result.filtered);
     }
 
     timer.track(
         &format!("go build {}", args.join(" ")),
         &format!("rtk go build {}", args.join(" ")),
-        &raw,
-        &filtered,
+        &normalize_go_output(&result.raw),
+        &normalize_go_output(&result.filtered),
     );
 
     // Preserve exit code for CI/CD
-    if !output.status.success() {
-        std::process::exit(output.status.code().unwrap_or(1));
+    if !result.success {
+        std::process::exit(result.exit_code);
     }
 
     Ok(())
@@ -128,41 +636,84 @@ pub fn run_build(args: &[String], verbose: u8) -> Result<()> {
 pub fn run_vet(args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
-    let mut cmd = Command::new("go");
-    cmd.arg("vet");
+    let result = exec_go_vet(args, verbose)?;
 
-    for arg in args {
-        cmd.arg(arg);
+    if !result.filtered.is_empty() {
+        println!("{}", result.filtered);
     }
 
-    if verbose > 0 {
-        eprintln!("Running: go vet {}", args.join(" "));
+    timer.track(
+        &format!("go vet {}", args.join(" ")),
+        &format!("rtk go vet {}", args.join(" ")),
+        &normalize_go_output(&result.raw),
+        &normalize_go_output(&result.filtered),
+    );
+
+    // Preserve exit code for CI/CD
+    if !result.success {
+        std::process::exit(result.exit_code);
     }
 
-    let output = cmd
-        .output()
-        .context("Failed to run go vet. Is Go installed?")?;
+    Ok(())
+}
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
-    let raw = format!("{}\n{}", stdout, stderr);
+/// Run `go vet`, `go build`, and `go test -json` in one pass and print a
+/// single merged report with a section per phase, instead of requiring
+/// three separate `rtk go` invocations. `go test` is skipped (not run)
+/// when `go build` fails, since a broken build can't produce meaningful
+/// test results. One `timer.track` call covers the whole gate with the
+/// concatenated raw and filtered output from whichever phases ran.
+pub fn run_check(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
 
-    let filtered = filter_go_vet(&raw);
+    let vet = exec_go_vet(args, verbose)?;
+    let build = exec_go_build(args, verbose)?;
+    let test = if build.success {
+        Some(exec_go_test(args, verbose, None, None)?)
+    } else {
+        None
+    };
+
+    let mut raw = String::new();
+    raw.push_str(&vet.raw);
+    raw.push('\n');
+    raw.push_str(&build.raw);
+    if let Some(test) = &test {
+        raw.push('\n');
+        raw.push_str(&test.raw);
+    }
 
-    if !filtered.is_empty() {
-        println!("{}", filtered);
+    let mut filtered = String::new();
+    filtered.push_str("── go vet ──\n");
+    filtered.push_str(&vet.filtered);
+    filtered.push_str("\n\n── go build ──\n");
+    filtered.push_str(&build.filtered);
+    filtered.push_str("\n\n── go test ──\n");
+    match &test {
+        Some(test) => filtered.push_str(&test.filtered),
+        None => filtered.push_str("Skipped (go build failed)"),
     }
 
+    println!("{}", filtered.trim());
+
     timer.track(
-        &format!("go vet {}", args.join(" ")),
-        &format!("rtk go vet {}", args.join(" ")),
-        &raw,
-        &filtered,
+        &format!("go check {}", args.join(" ")),
+        &format!("rtk go check {}", args.join(" ")),
+        &normalize_go_output(&raw),
+        &normalize_go_output(&filtered),
     );
 
-    // Preserve exit code for CI/CD
-    if !output.status.success() {
-        std::process::exit(output.status.code().unwrap_or(1));
+    // Combined exit code: nonzero if any phase that ran failed.
+    let exit_code = if !vet.success {
+        vet.exit_code
+    } else if !build.success {
+        build.exit_code
+    } else {
+        test.as_ref().map(|t| t.exit_code).unwrap_or(0)
+    };
+
+    if exit_code != 0 {
+        std::process::exit(exit_code);
     }
 
     Ok(())
@@ -213,8 +764,11 @@ pub fn run_other(args: &[OsString], verbose: u8) -> Result<()> {
     Ok(())
 }
 
-/// Parse go test -json output (NDJSON format)
-fn filter_go_test_json(output: &str) -> String {
+/// Parse `go test -json` output (NDJSON) into one [`PackageResult`] per
+/// package. Kept separate from [`format_go_test_summary`] so `--watch`
+/// mode can diff two runs' parsed results against each other instead of
+/// re-parsing the rendered summary text.
+fn parse_go_test_output(output: &str) -> HashMap<String, PackageResult> {
     let mut packages: HashMap<String, PackageResult> = HashMap::new();
     let mut current_test_output: HashMap<(String, String), Vec<String>> = HashMap::new(); // (package, test) -> outputs
 
@@ -233,40 +787,74 @@ fn filter_go_test_json(output: &str) -> String {
         let pkg_result = packages.entry(package.clone()).or_default();
 
         match event.action.as_str() {
-            "pass" => {
-                if event.test.is_some() {
+            "pass" => match (&event.test, event.elapsed) {
+                (Some(test), elapsed) => {
                     pkg_result.pass += 1;
+                    pkg_result.outcomes.push((test.clone(), true));
+                    if let Some(elapsed) = elapsed {
+                        pkg_result.test_elapsed.push((test.clone(), elapsed));
+                    }
                 }
-            }
+                (None, Some(elapsed)) => pkg_result.total_elapsed = elapsed,
+                (None, None) => {}
+            },
             "fail" => {
                 if let Some(test) = &event.test {
                     pkg_result.fail += 1;
+                    pkg_result.outcomes.push((test.clone(), false));
 
                     // Collect output for failed test
                     let key = (package.clone(), test.clone());
                     let outputs = current_test_output.remove(&key).unwrap_or_default();
                     pkg_result.failed_tests.push((test.clone(), outputs));
+
+                    if let Some(elapsed) = event.elapsed {
+                        pkg_result.test_elapsed.push((test.clone(), elapsed));
+                    }
+                } else if let Some(elapsed) = event.elapsed {
+                    pkg_result.total_elapsed = elapsed;
                 }
             }
             "skip" => {
-                if event.test.is_some() {
+                if let Some(test) = &event.test {
                     pkg_result.skip += 1;
+                    if let Some(elapsed) = event.elapsed {
+                        pkg_result.test_elapsed.push((test.clone(), elapsed));
+                    }
                 }
             }
             "output" => {
-                // Collect output for current test
-                if let (Some(test), Some(output_text)) = (&event.test, &event.output) {
-                    let key = (package.clone(), test.clone());
-                    current_test_output
-                        .entry(key)
-                        .or_default()
-                        .push(output_text.trim_end().to_string());
+                if let Some(output_text) = &event.output {
+                    // Collect output for current test
+                    if let Some(test) = &event.test {
+                        let key = (package.clone(), test.clone());
+                        current_test_output
+                            .entry(key)
+                            .or_default()
+                            .push(output_text.trim_end().to_string());
+                    }
+
+                    // `-cover`/`-coverprofile` make `go test` print a
+                    // package-level "coverage: NN.N% of statements" line
+                    if let Some(pct) = parse_coverage_line(output_text) {
+                        pkg_result.coverage_pct = Some(pct);
+                    }
                 }
             }
             _ => {} // run, pause, cont, etc.
         }
     }
 
+    packages
+}
+
+/// Render the pass/fail summary (and optional timings/coverage sections)
+/// for an already-parsed set of package results.
+fn format_go_test_summary(
+    packages: &HashMap<String, PackageResult>,
+    timings: Option<&TimingsOptions>,
+    coverage: Option<&CoverageOptions>,
+) -> String {
     // Build summary
     let total_packages = packages.len();
     let total_pass: usize = packages.values().map(|p| p.pass).sum();
@@ -277,24 +865,24 @@ fn filter_go_test_json(output: &str) -> String {
         return "Go test: No tests found".to_string();
     }
 
+    let mut result = String::new();
     if total_fail == 0 {
-        return format!(
+        result.push_str(&format!(
             "✓ Go test: {} passed in {} packages",
             total_pass, total_packages
-        );
+        ));
+    } else {
+        result.push_str(&format!(
+            "Go test: {} passed, {} failed",
+            total_pass, total_fail
+        ));
+        if total_skip > 0 {
+            result.push_str(&format!(", {} skipped", total_skip));
+        }
+        result.push_str(&format!(" in {} packages\n", total_packages));
+        result.push_str("═══════════════════════════════════════\n");
     }
 
-    let mut result = String::new();
-    result.push_str(&format!(
-        "Go test: {} passed, {} failed",
-        total_pass, total_fail
-    ));
-    if total_skip > 0 {
-        result.push_str(&format!(", {} skipped", total_skip));
-    }
-    result.push_str(&format!(" in {} packages\n", total_packages));
-    result.push_str("═══════════════════════════════════════\n");
-
     // Show failed tests grouped by package
     for (package, pkg_result) in packages.iter() {
         if pkg_result.fail == 0 {
@@ -334,9 +922,164 @@ fn filter_go_test_json(output: &str) -> String {
         }
     }
 
+    if let Some(opts) = timings {
+        append_timings_section(&mut result, packages, opts);
+    }
+
+    if let Some(opts) = coverage {
+        append_coverage_section(&mut result, packages, opts);
+    }
+
     result.trim().to_string()
 }
 
+/// Append a "🐢 Slowest tests" section listing the `top_n` slowest tests
+/// (at or above `threshold_ms`) and the `top_n` slowest packages by total
+/// elapsed time, so performance regressions show up in the filtered
+/// report instead of requiring a scroll through raw NDJSON.
+fn append_timings_section(
+    result: &mut String,
+    packages: &HashMap<String, PackageResult>,
+    opts: &TimingsOptions,
+) {
+    let threshold_secs = opts.threshold_ms / 1000.0;
+
+    let mut slowest_tests: Vec<(&str, &str, f64)> = packages
+        .iter()
+        .flat_map(|(package, pkg_result)| {
+            pkg_result
+                .test_elapsed
+                .iter()
+                .map(move |(test, elapsed)| (package.as_str(), test.as_str(), *elapsed))
+        })
+        .filter(|(_, _, elapsed)| *elapsed >= threshold_secs)
+        .collect();
+    slowest_tests.sort_by(|a, b| b.2.total_cmp(&a.2));
+
+    let mut slowest_packages: Vec<(&str, f64)> = packages
+        .iter()
+        .map(|(package, pkg_result)| (package.as_str(), pkg_result.total_elapsed))
+        .filter(|(_, elapsed)| *elapsed > 0.0)
+        .collect();
+    slowest_packages.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    if slowest_tests.is_empty() && slowest_packages.is_empty() {
+        return;
+    }
+
+    result.push_str("\n\n🐢 Slowest tests\n");
+    result.push_str("═══════════════════════════════════════\n");
+
+    if slowest_tests.is_empty() {
+        result.push_str(&format!(
+            "(no tests at or above {:.0}ms)\n",
+            opts.threshold_ms
+        ));
+    } else {
+        for (package, test, elapsed) in slowest_tests.iter().take(opts.top_n) {
+            result.push_str(&format!(
+                "  {:>7.2}s  {} ({})\n",
+                elapsed,
+                test,
+                compact_package_name(package)
+            ));
+        }
+    }
+
+    if !slowest_packages.is_empty() {
+        result.push_str("\nSlowest packages\n");
+        for (package, elapsed) in slowest_packages.iter().take(opts.top_n) {
+            result.push_str(&format!(
+                "  {:>7.2}s  {}\n",
+                elapsed,
+                compact_package_name(package)
+            ));
+        }
+    }
+}
+
+/// Append a "📊 Coverage" section listing each package's statement
+/// coverage percentage, worst first, flagging any package below
+/// `opts.threshold_pct`. The overall figure prefers the coverprofile file
+/// (real statement counts) when one was written, falling back to
+/// averaging the per-package percentages `go test` printed.
+fn append_coverage_section(
+    result: &mut String,
+    packages: &HashMap<String, PackageResult>,
+    opts: &CoverageOptions,
+) {
+    let mut by_package: Vec<(&str, f64)> = packages
+        .iter()
+        .filter_map(|(package, pkg_result)| {
+            pkg_result.coverage_pct.map(|pct| (package.as_str(), pct))
+        })
+        .collect();
+
+    if by_package.is_empty() {
+        return;
+    }
+
+    by_package.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+    let overall = opts
+        .coverprofile
+        .as_deref()
+        .and_then(|path| parse_coverprofile(std::path::Path::new(path)))
+        .filter(|(_, total)| *total > 0)
+        .map(|(covered, total)| covered as f64 / total as f64 * 100.0)
+        .unwrap_or_else(|| {
+            by_package.iter().map(|(_, pct)| pct).sum::<f64>() / by_package.len() as f64
+        });
+
+    result.push_str("\n\n📊 Coverage\n");
+    result.push_str("═══════════════════════════════════════\n");
+
+    for (package, pct) in &by_package {
+        let flag = match opts.threshold_pct {
+            Some(threshold) if *pct < threshold => "  ⚠️ below threshold",
+            _ => "",
+        };
+        result.push_str(&format!(
+            "  {:>5.1}%  {}{}\n",
+            pct,
+            compact_package_name(package),
+            flag
+        ));
+    }
+
+    result.push_str(&format!("\nOverall: {:.1}%\n", overall));
+}
+
+/// Strip non-deterministic fragments out of `go` command output before
+/// it's handed to `timer.track`, so repeated tracked runs against an
+/// unchanged tree produce stable, diffable history entries instead of
+/// noise from changing paths and timings: absolute paths under the
+/// current working directory are rewritten relative to it, `"Time"`/
+/// `"Elapsed"` fields from `-json` event output are dropped, and the
+/// per-process `go-buildNNNNN` temp directory Go mentions in build/panic
+/// output is canonicalized to a fixed placeholder.
+fn normalize_go_output(output: &str) -> String {
+    static TIME_FIELD_RE: OnceLock<regex::Regex> = OnceLock::new();
+    let time_field_re = TIME_FIELD_RE.get_or_init(|| {
+        regex::Regex::new(r#""(?:Time|Elapsed)":(?:"[^"]*"|[0-9.]+),?"#).unwrap()
+    });
+
+    static GO_BUILD_DIR_RE: OnceLock<regex::Regex> = OnceLock::new();
+    let go_build_dir_re =
+        GO_BUILD_DIR_RE.get_or_init(|| regex::Regex::new(r"go-build\d+").unwrap());
+
+    let mut result = time_field_re.replace_all(output, "").into_owned();
+    result = go_build_dir_re.replace_all(&result, "go-buildNNN").into_owned();
+
+    if let Ok(cwd) = std::env::current_dir() {
+        if let Some(cwd) = cwd.to_str() {
+            result = result.replace(&format!("{}/", cwd), "");
+        }
+    }
+
+    result
+}
+
 /// Filter go build output - show only errors
 fn filter_go_build(output: &str) -> String {
     let mut errors: Vec<String> = Vec::new();
@@ -433,7 +1176,7 @@ mod tests {
 {"Time":"2024-01-01T10:00:02Z","Action":"pass","Package":"example.com/foo","Test":"TestBar","Elapsed":0.5}
 {"Time":"2024-01-01T10:00:02Z","Action":"pass","Package":"example.com/foo","Elapsed":0.5}"#;
 
-        let result = filter_go_test_json(output);
+        let result = format_go_test_summary(&parse_go_test_output(output), None, None);
         assert!(result.contains("✓ Go test"));
         assert!(result.contains("1 passed"));
         assert!(result.contains("1 packages"));
@@ -447,7 +1190,7 @@ mod tests {
 {"Time":"2024-01-01T10:00:03Z","Action":"fail","Package":"example.com/foo","Test":"TestFail","Elapsed":0.5}
 {"Time":"2024-01-01T10:00:03Z","Action":"fail","Package":"example.com/foo","Elapsed":0.5}"#;
 
-        let result = filter_go_test_json(output);
+        let result = format_go_test_summary(&parse_go_test_output(output), None, None);
         assert!(result.contains("1 failed"));
         assert!(result.contains("TestFail"));
         assert!(result.contains("expected 5, got 3"));
@@ -498,4 +1241,261 @@ utils.go:15:5: unreachable code"#;
         assert_eq!(compact_package_name("example.com/foo"), "foo");
         assert_eq!(compact_package_name("simple"), "simple");
     }
+
+    #[test]
+    fn test_extract_timings_disabled_by_default() {
+        let args = vec!["./...".to_string(), "-race".to_string()];
+        let (timings, rest) = extract_timings(&args);
+        assert!(timings.is_none());
+        assert_eq!(rest, args);
+    }
+
+    #[test]
+    fn test_extract_timings_parses_threshold_and_top() {
+        let args = vec![
+            "--timings".to_string(),
+            "--timings-threshold".to_string(),
+            "250".to_string(),
+            "--timings-top".to_string(),
+            "3".to_string(),
+            "./...".to_string(),
+        ];
+        let (timings, rest) = extract_timings(&args);
+        let timings = timings.unwrap();
+        assert_eq!(timings.threshold_ms, 250.0);
+        assert_eq!(timings.top_n, 3);
+        assert_eq!(rest, vec!["./...".to_string()]);
+    }
+
+    #[test]
+    fn test_filter_go_test_json_timings_lists_slowest_tests_and_packages() {
+        let output = r#"{"Action":"run","Package":"example.com/foo","Test":"TestSlow"}
+{"Action":"pass","Package":"example.com/foo","Test":"TestSlow","Elapsed":0.5}
+{"Action":"run","Package":"example.com/foo","Test":"TestFast"}
+{"Action":"pass","Package":"example.com/foo","Test":"TestFast","Elapsed":0.01}
+{"Action":"pass","Package":"example.com/foo","Elapsed":0.51}"#;
+
+        let opts = TimingsOptions {
+            threshold_ms: 100.0,
+            top_n: 10,
+        };
+        let result = format_go_test_summary(&parse_go_test_output(output), Some(&opts), None);
+
+        assert!(result.contains("Slowest tests"));
+        assert!(result.contains("TestSlow"));
+        assert!(!result.contains("TestFast"));
+        assert!(result.contains("Slowest packages"));
+        assert!(result.contains("foo"));
+    }
+
+    #[test]
+    fn test_filter_go_test_json_without_timings_omits_section() {
+        let output = r#"{"Action":"pass","Package":"example.com/foo","Test":"TestBar","Elapsed":0.5}
+{"Action":"pass","Package":"example.com/foo","Elapsed":0.5}"#;
+
+        let result = format_go_test_summary(&parse_go_test_output(output), None, None);
+        assert!(!result.contains("Slowest tests"));
+    }
+
+    #[test]
+    fn test_format_watch_delta_reports_flips_and_still_failing() {
+        let mut previous = HashMap::new();
+        previous.insert(("pkg".to_string(), "TestA".to_string()), true);
+        previous.insert(("pkg".to_string(), "TestB".to_string()), false);
+        previous.insert(("pkg".to_string(), "TestC".to_string()), false);
+
+        let mut current = HashMap::new();
+        current.insert(("pkg".to_string(), "TestA".to_string()), false); // newly failing
+        current.insert(("pkg".to_string(), "TestB".to_string()), true); // now passing
+        current.insert(("pkg".to_string(), "TestC".to_string()), false); // still failing
+
+        let delta = format_watch_delta(&previous, &current);
+        assert!(delta.contains("newly failing: pkg::TestA"));
+        assert!(delta.contains("now passing: pkg::TestB"));
+        assert!(delta.contains("still failing: 1"));
+    }
+
+    #[test]
+    fn test_format_watch_delta_no_change_when_all_passing() {
+        let mut previous = HashMap::new();
+        previous.insert(("pkg".to_string(), "TestA".to_string()), true);
+
+        let current = previous.clone();
+
+        assert_eq!(
+            format_watch_delta(&previous, &current),
+            "✓ No change: all tests passing"
+        );
+    }
+
+    #[test]
+    fn test_collect_test_outcomes_ignores_skipped_tests() {
+        let output = r#"{"Action":"pass","Package":"example.com/foo","Test":"TestA","Elapsed":0.1}
+{"Action":"fail","Package":"example.com/foo","Test":"TestB","Elapsed":0.1}
+{"Action":"skip","Package":"example.com/foo","Test":"TestC"}"#;
+
+        let packages = parse_go_test_output(output);
+        let outcomes = collect_test_outcomes(&packages);
+
+        assert_eq!(
+            outcomes.get(&("example.com/foo".to_string(), "TestA".to_string())),
+            Some(&true)
+        );
+        assert_eq!(
+            outcomes.get(&("example.com/foo".to_string(), "TestB".to_string())),
+            Some(&false)
+        );
+        assert_eq!(
+            outcomes.get(&("example.com/foo".to_string(), "TestC".to_string())),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_coverage_line() {
+        assert_eq!(
+            parse_coverage_line("coverage: 82.4% of statements"),
+            Some(82.4)
+        );
+        assert_eq!(parse_coverage_line("=== RUN   TestFoo"), None);
+    }
+
+    #[test]
+    fn test_extract_coverage_disabled_by_default() {
+        let args = vec!["./...".to_string(), "-race".to_string()];
+        let (coverage, rest) = extract_coverage(&args);
+        assert!(coverage.is_none());
+        assert_eq!(rest, args);
+    }
+
+    #[test]
+    fn test_extract_coverage_parses_coverprofile_equals_form() {
+        let args = vec![
+            "-coverprofile=cover.out".to_string(),
+            "--coverage-threshold".to_string(),
+            "80".to_string(),
+            "./...".to_string(),
+        ];
+        let (coverage, rest) = extract_coverage(&args);
+        let coverage = coverage.unwrap();
+        assert_eq!(coverage.coverprofile.as_deref(), Some("cover.out"));
+        assert_eq!(coverage.threshold_pct, Some(80.0));
+        // go's own flag stays in `rest`; only the rtk-only flag is stripped
+        assert_eq!(
+            rest,
+            vec!["-coverprofile=cover.out".to_string(), "./...".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_extract_coverage_parses_coverprofile_space_form() {
+        let args = vec!["-coverprofile".to_string(), "cover.out".to_string()];
+        let (coverage, rest) = extract_coverage(&args);
+        let coverage = coverage.unwrap();
+        assert_eq!(coverage.coverprofile.as_deref(), Some("cover.out"));
+        assert_eq!(rest, args);
+    }
+
+    #[test]
+    fn test_extract_coverage_bare_cover_flag() {
+        let args = vec!["-cover".to_string(), "./...".to_string()];
+        let (coverage, rest) = extract_coverage(&args);
+        let coverage = coverage.unwrap();
+        assert_eq!(coverage.coverprofile, None);
+        assert_eq!(rest, args);
+    }
+
+    #[test]
+    fn test_parse_coverprofile_text_sums_covered_and_total_statements() {
+        let contents = "mode: set\n\
+             example.com/foo/bar.go:3.14,5.2 2 1\n\
+             example.com/foo/bar.go:7.14,9.2 3 0\n";
+        assert_eq!(parse_coverprofile_text(contents), Some((2, 5)));
+    }
+
+    #[test]
+    fn test_append_coverage_section_sorts_worst_first_and_flags_threshold() {
+        let output = r#"{"Action":"pass","Package":"example.com/bad","Test":"TestBad","Elapsed":0.1}
+{"Action":"output","Package":"example.com/bad","Output":"coverage: 10.0% of statements\n"}
+{"Action":"pass","Package":"example.com/bad","Elapsed":0.1}
+{"Action":"pass","Package":"example.com/good","Test":"TestGood","Elapsed":0.1}
+{"Action":"output","Package":"example.com/good","Output":"coverage: 90.0% of statements\n"}
+{"Action":"pass","Package":"example.com/good","Elapsed":0.1}"#;
+
+        let opts = CoverageOptions {
+            coverprofile: None,
+            threshold_pct: Some(50.0),
+        };
+        let result = format_go_test_summary(&parse_go_test_output(output), None, Some(&opts));
+
+        let bad_pos = result.find("bad").unwrap();
+        let good_pos = result.find("good").unwrap();
+        assert!(bad_pos < good_pos, "worst package should be listed first");
+        assert!(result.contains("below threshold"));
+        assert!(result.contains("Overall: 50.0%"));
+    }
+
+    #[test]
+    fn test_extract_retry_failed_disabled_by_default() {
+        let args = vec!["./...".to_string()];
+        let (max_retries, rest) = extract_retry_failed(&args);
+        assert!(max_retries.is_none());
+        assert_eq!(rest, args);
+    }
+
+    #[test]
+    fn test_extract_retry_failed_parses_count_and_strips_flag() {
+        let args = vec!["--retry-failed=3".to_string(), "./...".to_string()];
+        let (max_retries, rest) = extract_retry_failed(&args);
+        assert_eq!(max_retries, Some(3));
+        assert_eq!(rest, vec!["./...".to_string()]);
+    }
+
+    #[test]
+    fn test_append_retry_section_lists_flaky_and_consistently_failing() {
+        let outcome = RetryOutcome {
+            raw: String::new(),
+            flaky: vec!["foo::TestFlaky".to_string()],
+            consistently_failing: vec!["foo::TestBroken".to_string()],
+        };
+        let mut result = String::new();
+        append_retry_section(&mut result, &outcome);
+        assert!(result.contains("flaky (passed on retry): foo::TestFlaky"));
+        assert!(result.contains("consistently failing: foo::TestBroken"));
+    }
+
+    #[test]
+    fn test_append_retry_section_empty_when_no_retries_recorded() {
+        let outcome = RetryOutcome::default();
+        let mut result = String::new();
+        append_retry_section(&mut result, &outcome);
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_normalize_go_output_strips_cwd_absolute_path() {
+        let cwd = std::env::current_dir().unwrap();
+        let input = format!("{}/main.go:10:5: undefined: Foo\n", cwd.display());
+        assert_eq!(
+            normalize_go_output(&input),
+            "main.go:10:5: undefined: Foo\n"
+        );
+    }
+
+    #[test]
+    fn test_normalize_go_output_canonicalizes_go_build_tmp_dirs() {
+        let input = "panic: runtime error\n\t/tmp/go-build3837226541/b001/exe/foo\n";
+        let result = normalize_go_output(input);
+        assert!(result.contains("/tmp/go-buildNNN/b001/exe/foo"));
+        assert!(!result.contains("3837226541"));
+    }
+
+    #[test]
+    fn test_normalize_go_output_strips_time_and_elapsed_fields() {
+        let input = r#"{"Time":"2024-01-01T10:00:00Z","Action":"fail","Elapsed":0.5}"#;
+        let result = normalize_go_output(input);
+        assert!(!result.contains("Time"));
+        assert!(!result.contains("Elapsed"));
+        assert!(result.contains("\"Action\":\"fail\""));
+    }
 }
@@ -1,11 +1,11 @@
 use crate::tracking;
 use crate::utils::truncate;
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::process::Command;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct Position {
     #[serde(rename = "Filename")]
     filename: String,
@@ -15,7 +15,7 @@ struct Position {
     column: usize,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct Issue {
     #[serde(rename = "FromLinter")]
     from_linter: String,
@@ -23,6 +23,64 @@ struct Issue {
     text: String,
     #[serde(rename = "Pos")]
     pos: Position,
+    #[serde(rename = "Severity", default)]
+    severity: Severity,
+    #[serde(rename = "Replacement", default)]
+    replacement: Option<Replacement>,
+}
+
+/// An auto-fixable issue's suggested edit, as golangci-lint reports it:
+/// either replace the whole line(s) (`new_lines`), delete the line
+/// (`need_only_delete`), or patch a column range in place (`inline`).
+#[derive(Debug, Clone, Deserialize)]
+struct Replacement {
+    #[serde(rename = "NeedOnlyDelete", default)]
+    need_only_delete: bool,
+    #[serde(rename = "NewLines", default)]
+    new_lines: Vec<String>,
+    #[serde(rename = "Inline", default)]
+    inline: Option<InlineFix>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct InlineFix {
+    #[serde(rename = "StartCol")]
+    start_col: usize,
+    #[serde(rename = "Length")]
+    length: usize,
+    #[serde(rename = "NewString")]
+    new_string: String,
+}
+
+/// golangci-lint's per-issue severity. Most linters don't set `Severity` at
+/// all, so it defaults to `Error` — the safe assumption for a field that,
+/// historically, this wrapper dropped entirely.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Severity {
+    Info,
+    Warning,
+    #[default]
+    Error,
+}
+
+impl Severity {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "error" => Some(Severity::Error),
+            "warning" => Some(Severity::Warning),
+            "info" => Some(Severity::Info),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -31,15 +89,64 @@ struct GolangciOutput {
     issues: Vec<Issue>,
 }
 
+/// `--rtk-format=github` is an rtk-only flag (not forwarded to
+/// golangci-lint) that, in addition to the compact summary, prints
+/// GitHub Actions workflow commands so CI surfaces issues as inline
+/// annotations.
+const GITHUB_FORMAT_FLAG: &str = "--rtk-format=github";
+
+/// `--rtk-baseline[=<ref>]` is an rtk-only flag that filters the parsed
+/// issues down to ones introduced since `<ref>` (default: the merge-base
+/// with `origin/HEAD`), mirroring golangci-lint's `only-new-issues`.
+const BASELINE_FLAG: &str = "--rtk-baseline";
+const BASELINE_FLAG_PREFIX: &str = "--rtk-baseline=";
+
+/// `--fail-on=<severity>` makes `run()` exit non-zero only when at least
+/// one issue at or above the given severity exists, instead of always
+/// returning `Ok`. Omit it to keep today's "never fail the process" behavior.
+const FAIL_ON_PREFIX: &str = "--fail-on=";
+
+/// `--apply-fixes` applies each issue's captured `Replacement` directly to
+/// the source files, turning the wrapper from a read-only reporter into a
+/// remediation tool.
+const APPLY_FIXES_FLAG: &str = "--apply-fixes";
+
+fn is_rtk_only_flag(arg: &str) -> bool {
+    arg == GITHUB_FORMAT_FLAG
+        || arg == BASELINE_FLAG
+        || arg.starts_with(BASELINE_FLAG_PREFIX)
+        || arg.starts_with(FAIL_ON_PREFIX)
+        || arg == APPLY_FIXES_FLAG
+}
+
 pub fn run(args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
+    let want_github_annotations = args.iter().any(|a| a == GITHUB_FORMAT_FLAG);
+    let want_apply_fixes = args.iter().any(|a| a == APPLY_FIXES_FLAG);
+    let baseline_ref = args.iter().find_map(|a| {
+        if a == BASELINE_FLAG {
+            Some(None)
+        } else {
+            a.strip_prefix(BASELINE_FLAG_PREFIX).map(|r| Some(r.to_string()))
+        }
+    });
+    let fail_on = args
+        .iter()
+        .find_map(|a| a.strip_prefix(FAIL_ON_PREFIX))
+        .map(|s| {
+            Severity::parse(s)
+                .with_context(|| format!("invalid --fail-on severity: {}", s))
+        })
+        .transpose()?;
+    let forwarded_args: Vec<&String> = args.iter().filter(|a| !is_rtk_only_flag(a)).collect();
+
     let mut cmd = Command::new("golangci-lint");
 
     // Force JSON output
-    let has_format = args
+    let has_format = forwarded_args
         .iter()
-        .any(|a| a == "--out-format" || a.starts_with("--out-format="));
+        .any(|a| *a == "--out-format" || a.starts_with("--out-format="));
 
     if !has_format {
         cmd.arg("run").arg("--out-format=json");
@@ -47,7 +154,7 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
         cmd.arg("run");
     }
 
-    for arg in args {
+    for arg in &forwarded_args {
         cmd.arg(arg);
     }
 
@@ -63,9 +170,62 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
     let stderr = String::from_utf8_lossy(&output.stderr);
     let raw = format!("{}\n{}", stdout, stderr);
 
-    let filtered = filter_golangci_json(&stdout);
+    let mut github_lines = Vec::new();
+    let mut should_fail = false;
+
+    let filtered = match serde_json::from_str::<GolangciOutput>(&stdout) {
+        Err(_) => filter_golangci_json(&stdout),
+        Ok(parsed) => {
+            let mut issues = parsed.issues;
+            let mut note = None;
+
+            if let Some(explicit_ref) = &baseline_ref {
+                match filter_to_new_issues(&issues, explicit_ref.as_deref()) {
+                    Ok((kept, suppressed)) => {
+                        note = Some(format!(
+                            "{} new issues ({} suppressed as pre-existing)",
+                            kept.len(),
+                            suppressed
+                        ));
+                        issues = kept;
+                    }
+                    Err(e) => {
+                        eprintln!("rtk: baseline filtering failed ({}), showing all issues", e);
+                    }
+                }
+            }
+
+            if want_github_annotations {
+                github_lines = github_annotations(&issues, &github_warn_linters());
+            }
+
+            if want_apply_fixes {
+                match apply_replacements(&issues) {
+                    Ok(modified) if modified.is_empty() => {
+                        println!("rtk: no auto-fixable issues to apply");
+                    }
+                    Ok(modified) => {
+                        println!("rtk: applied fixes to {} file(s):", modified.len());
+                        for file in &modified {
+                            println!("  {}", file);
+                        }
+                    }
+                    Err(e) => eprintln!("rtk: failed to apply fixes: {}", e),
+                }
+            }
+
+            if let Some(threshold) = fail_on {
+                should_fail = issues.iter().any(|i| i.severity >= threshold);
+            }
+
+            render_golangci_summary(&issues, note.as_deref(), count_go_files())
+        }
+    };
 
     println!("{}", filtered);
+    for line in &github_lines {
+        println!("{}", line);
+    }
 
     // Include stderr if present (config errors, etc.)
     if !stderr.trim().is_empty() && verbose > 0 {
@@ -79,8 +239,12 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
         &filtered,
     );
 
-    // golangci-lint returns exit code 1 when issues found (expected behavior)
-    // Don't exit with error code in that case
+    // golangci-lint returns exit code 1 when issues found (expected behavior);
+    // only exit non-zero ourselves when --fail-on's threshold is actually met.
+    if should_fail {
+        std::process::exit(1);
+    }
+
     Ok(())
 }
 
@@ -100,8 +264,45 @@ fn filter_golangci_json(output: &str) -> String {
         }
     };
 
-    let issues = golangci_output.issues;
+    render_golangci_summary(&golangci_output.issues, None, count_go_files())
+}
+
+/// Number of `.go` files under the current directory, respecting
+/// `.gitignore` the same way `rtk find` does. Used as the denominator for
+/// the report-card grade: files golangci-lint never touched still count
+/// as "clean".
+fn count_go_files() -> usize {
+    ignore::WalkBuilder::new(".")
+        .build()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_some_and(|ft| ft.is_file()))
+        .filter(|entry| entry.path().extension().and_then(|ext| ext.to_str()) == Some("go"))
+        .count()
+}
+
+/// Map a clean-file ratio to a report-card letter grade.
+fn grade_letter(pct: f64) -> &'static str {
+    if pct >= 0.99 {
+        "A+"
+    } else if pct >= 0.95 {
+        "A"
+    } else if pct >= 0.90 {
+        "B"
+    } else if pct >= 0.80 {
+        "C"
+    } else if pct >= 0.70 {
+        "D"
+    } else {
+        "F"
+    }
+}
 
+/// Render the grouped summary for an already-parsed (and possibly
+/// baseline-filtered) issue list. `note` is an extra line shown right under
+/// the header, e.g. the new-vs-suppressed breakdown from baseline filtering.
+/// `total_project_files` is the denominator for the report-card grade
+/// (from [`count_go_files`]); files with no issues at all count as clean.
+fn render_golangci_summary(issues: &[Issue], note: Option<&str>, total_project_files: usize) -> String {
     if issues.is_empty() {
         return "✓ golangci-lint: No issues found".to_string();
     }
@@ -115,13 +316,19 @@ fn filter_golangci_json(output: &str) -> String {
 
     // Group by linter
     let mut by_linter: HashMap<String, usize> = HashMap::new();
-    for issue in &issues {
+    for issue in issues {
         *by_linter.entry(issue.from_linter.clone()).or_insert(0) += 1;
     }
 
+    // Group by severity
+    let mut by_severity: HashMap<Severity, usize> = HashMap::new();
+    for issue in issues {
+        *by_severity.entry(issue.severity).or_insert(0) += 1;
+    }
+
     // Group by file
     let mut by_file: HashMap<&str, usize> = HashMap::new();
-    for issue in &issues {
+    for issue in issues {
         *by_file.entry(&issue.pos.filename).or_insert(0) += 1;
     }
 
@@ -130,10 +337,39 @@ fn filter_golangci_json(output: &str) -> String {
 
     // Build output
     let mut result = String::new();
+
+    // Report-card grade: clean files (including ones golangci-lint never
+    // flagged at all) over the total, weighted by file count rather than
+    // raw issue count so one noisy file doesn't tank the whole score.
+    let project_files = total_project_files.max(total_files);
+    let clean_files = project_files.saturating_sub(total_files);
+    let grade_pct = clean_files as f64 / project_files as f64;
+    result.push_str(&format!(
+        "golangci-lint: grade {} ({:.1}%) — {} issues across {}/{} files\n",
+        grade_letter(grade_pct),
+        grade_pct * 100.0,
+        total_issues,
+        total_files,
+        project_files
+    ));
+
     result.push_str(&format!(
         "golangci-lint: {} issues in {} files\n",
         total_issues, total_files
     ));
+
+    let fixable = issues.iter().filter(|i| i.replacement.is_some()).count();
+    if fixable > 0 {
+        result.push_str(&format!(
+            "{} of {} issues auto-fixable (--apply-fixes)\n",
+            fixable, total_issues
+        ));
+    }
+
+    if let Some(note) = note {
+        result.push_str(note);
+        result.push('\n');
+    }
     result.push_str("═══════════════════════════════════════\n");
 
     // Show top linters
@@ -148,6 +384,16 @@ fn filter_golangci_json(output: &str) -> String {
         result.push('\n');
     }
 
+    // Show counts by severity
+    result.push_str("By severity:\n");
+    for severity in [Severity::Error, Severity::Warning, Severity::Info] {
+        let count = by_severity.get(&severity).copied().unwrap_or(0);
+        if count > 0 {
+            result.push_str(&format!("  {}: {}\n", severity.as_str(), count));
+        }
+    }
+    result.push('\n');
+
     // Show top files
     result.push_str("Top files:\n");
     for (file, count) in file_counts.iter().take(10) {
@@ -175,6 +421,220 @@ fn filter_golangci_json(output: &str) -> String {
     result.trim().to_string()
 }
 
+/// Linters downgraded from `::error` to `::warning` annotations, read from
+/// `RTK_GITHUB_WARN_LINTERS` as a comma-separated list (e.g. "gosimple,stylecheck").
+fn github_warn_linters() -> std::collections::HashSet<String> {
+    std::env::var("RTK_GITHUB_WARN_LINTERS")
+        .map(|v| v.split(',').map(|s| s.trim().to_string()).collect())
+        .unwrap_or_default()
+}
+
+/// Render each issue as a GitHub Actions workflow command
+/// (`::error file=...,line=...,col=...,title=...::message`) so CI surfaces
+/// them as inline annotations instead of only a log blob.
+fn github_annotations(issues: &[Issue], warn_linters: &std::collections::HashSet<String>) -> Vec<String> {
+    issues
+        .iter()
+        .map(|issue| {
+            let level = if warn_linters.contains(&issue.from_linter) {
+                "warning"
+            } else {
+                "error"
+            };
+
+            format!(
+                "::{} file={},line={},col={},title={}::{}",
+                level,
+                github_escape_property(&issue.pos.filename),
+                issue.pos.line,
+                issue.pos.column,
+                github_escape_property(&issue.from_linter),
+                github_escape_data(&issue.text),
+            )
+        })
+        .collect()
+}
+
+/// Percent-encode a workflow command's `key=value` property (filename,
+/// title): commas and colons would otherwise be parsed as field separators.
+fn github_escape_property(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+        .replace(',', "%2C")
+        .replace(':', "%3A")
+}
+
+/// Percent-encode a workflow command's message body: only `%` and
+/// newlines need escaping there.
+fn github_escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Apply each issue's captured [`Replacement`] directly to its source file,
+/// sorting edits per file in reverse line order so that applying one edit
+/// never shifts the line numbers the next edit was computed against.
+/// Returns the sorted list of files actually modified.
+fn apply_replacements(issues: &[Issue]) -> Result<Vec<String>> {
+    let mut by_file: HashMap<&str, Vec<&Issue>> = HashMap::new();
+    for issue in issues {
+        if issue.replacement.is_some() {
+            by_file.entry(issue.pos.filename.as_str()).or_default().push(issue);
+        }
+    }
+
+    let mut modified = Vec::new();
+    for (file, mut file_issues) in by_file {
+        file_issues.sort_by(|a, b| b.pos.line.cmp(&a.pos.line));
+
+        let content = std::fs::read_to_string(file)
+            .with_context(|| format!("failed to read {}", file))?;
+        let had_trailing_newline = content.ends_with('\n');
+        let mut lines: Vec<String> = content.lines().map(|l| l.to_string()).collect();
+
+        for issue in &file_issues {
+            let replacement = issue.replacement.as_ref().unwrap();
+            let line_idx = match issue.pos.line.checked_sub(1) {
+                Some(idx) if idx < lines.len() => idx,
+                _ => continue,
+            };
+
+            if replacement.need_only_delete {
+                lines.remove(line_idx);
+            } else if let Some(inline) = &replacement.inline {
+                let line = &lines[line_idx];
+                let start = inline.start_col.saturating_sub(1).min(line.len());
+                let end = (start + inline.length).min(line.len());
+                lines[line_idx] = format!("{}{}{}", &line[..start], inline.new_string, &line[end..]);
+            } else if !replacement.new_lines.is_empty() {
+                lines.splice(line_idx..line_idx + 1, replacement.new_lines.iter().cloned());
+            }
+        }
+
+        let mut new_content = lines.join("\n");
+        if had_trailing_newline {
+            new_content.push('\n');
+        }
+        std::fs::write(file, new_content).with_context(|| format!("failed to write {}", file))?;
+        modified.push(file.to_string());
+    }
+
+    modified.sort();
+    Ok(modified)
+}
+
+/// Filter `issues` down to ones that land on a line added (or modified)
+/// since `base_ref` (default: the merge-base with `origin/HEAD`), mirroring
+/// golangci-lint's `only-new-issues`. Returns `(kept, suppressed_count)`.
+fn filter_to_new_issues(issues: &[Issue], base_ref: Option<&str>) -> Result<(Vec<Issue>, usize)> {
+    let base_ref = match base_ref {
+        Some(r) => r.to_string(),
+        None => merge_base_with_origin_head()?,
+    };
+
+    let ranges = changed_line_ranges(&base_ref)?;
+
+    let mut kept = Vec::new();
+    let mut suppressed = 0;
+    for issue in issues {
+        if issue_in_ranges(issue, &ranges) {
+            kept.push(issue.clone());
+        } else {
+            suppressed += 1;
+        }
+    }
+
+    Ok((kept, suppressed))
+}
+
+/// Whether `issue`'s (repo-relative) file and line fall inside one of the
+/// added-line ranges collected by [`changed_line_ranges`].
+fn issue_in_ranges(issue: &Issue, ranges: &HashMap<String, Vec<(usize, usize)>>) -> bool {
+    let path = compact_path(&issue.pos.filename);
+    ranges
+        .get(path.as_str())
+        .is_some_and(|rs| rs.iter().any(|(start, end)| issue.pos.line >= *start && issue.pos.line < *end))
+}
+
+/// `git merge-base HEAD origin/HEAD`, the default baseline for "new since".
+fn merge_base_with_origin_head() -> Result<String> {
+    let output = Command::new("git")
+        .args(["merge-base", "HEAD", "origin/HEAD"])
+        .output()
+        .context("Failed to run git merge-base")?;
+
+    if !output.status.success() {
+        bail!(
+            "git merge-base HEAD origin/HEAD failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Per-file sets of `[start, end)` line ranges added since `base_ref`,
+/// keyed by the same `compact_path`-normalized spelling used for issues.
+///
+/// Diffs against the working tree (not just `HEAD`) so uncommitted changes
+/// are treated as "new" too, and passes `-M` so a renamed file's added
+/// lines are attributed to its new name.
+fn changed_line_ranges(base_ref: &str) -> Result<HashMap<String, Vec<(usize, usize)>>> {
+    let output = Command::new("git")
+        .args(["diff", "--unified=0", "-M", base_ref])
+        .output()
+        .context("Failed to run git diff for baseline filtering")?;
+
+    if !output.status.success() {
+        bail!(
+            "git diff --unified=0 {} failed: {}",
+            base_ref,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let diff_text = String::from_utf8_lossy(&output.stdout);
+    let mut ranges: HashMap<String, Vec<(usize, usize)>> = HashMap::new();
+    let mut current_file: Option<String> = None;
+
+    for line in diff_text.lines() {
+        if let Some(path) = line.strip_prefix("+++ b/") {
+            current_file = Some(compact_path(path));
+        } else if let Some(path) = line.strip_prefix("rename to ") {
+            current_file = Some(compact_path(path));
+        } else if let Some((start, len)) = parse_hunk_header(line) {
+            if len > 0 {
+                if let Some(file) = &current_file {
+                    ranges.entry(file.clone()).or_default().push((start, start + len));
+                }
+            }
+        }
+    }
+
+    Ok(ranges)
+}
+
+/// Parse a unified-diff hunk header's "added" half, e.g. `@@ -12,3 +15,5 @@`
+/// -> `(15, 5)`. A bare `+15` (no comma) means a single added line.
+fn parse_hunk_header(line: &str) -> Option<(usize, usize)> {
+    lazy_static::lazy_static! {
+        static ref HUNK_RE: regex::Regex =
+            regex::Regex::new(r"^@@ -\d+(?:,\d+)? \+(\d+)(?:,(\d+))? @@").unwrap();
+    }
+
+    let caps = HUNK_RE.captures(line)?;
+    let start: usize = caps[1].parse().ok()?;
+    let len: usize = match caps.get(2) {
+        Some(m) => m.as_str().parse().ok()?,
+        None => 1,
+    };
+    Some((start, len))
+}
+
 /// Compact file path (remove common prefixes)
 fn compact_path(path: &str) -> String {
     let path = path.replace('\\', "/");
@@ -235,6 +695,242 @@ mod tests {
         assert!(result.contains("utils.go"));
     }
 
+    #[test]
+    fn test_filter_golangci_groups_by_severity() {
+        let output = r#"{
+  "Issues": [
+    {"FromLinter": "errcheck", "Text": "bad", "Pos": {"Filename": "a.go", "Line": 1, "Column": 1}},
+    {"FromLinter": "gosimple", "Text": "meh", "Pos": {"Filename": "b.go", "Line": 1, "Column": 1}, "Severity": "warning"}
+  ]
+}"#;
+        let result = filter_golangci_json(output);
+        assert!(result.contains("By severity:"));
+        assert!(result.contains("error: 1"));
+        assert!(result.contains("warning: 1"));
+    }
+
+    #[test]
+    fn test_grade_letter_thresholds() {
+        assert_eq!(grade_letter(1.0), "A+");
+        assert_eq!(grade_letter(0.96), "A");
+        assert_eq!(grade_letter(0.913), "B");
+        assert_eq!(grade_letter(0.85), "C");
+        assert_eq!(grade_letter(0.75), "D");
+        assert_eq!(grade_letter(0.2), "F");
+    }
+
+    #[test]
+    fn test_render_golangci_summary_grade_header() {
+        let issues = vec![Issue {
+            from_linter: "errcheck".to_string(),
+            text: "bad".to_string(),
+            pos: Position {
+                filename: "a.go".to_string(),
+                line: 1,
+                column: 1,
+            },
+            severity: Severity::Error,
+            replacement: None,
+        }];
+
+        let result = render_golangci_summary(&issues, None, 10);
+        assert!(result.contains("grade B (90.0%)"));
+        assert!(result.contains("1 issues across 1/10 files"));
+    }
+
+    #[test]
+    fn test_severity_parse() {
+        assert_eq!(Severity::parse("Error"), Some(Severity::Error));
+        assert_eq!(Severity::parse("warning"), Some(Severity::Warning));
+        assert_eq!(Severity::parse("info"), Some(Severity::Info));
+        assert_eq!(Severity::parse("bogus"), None);
+        assert!(Severity::Error > Severity::Warning);
+        assert!(Severity::Warning > Severity::Info);
+    }
+
+    #[test]
+    fn test_github_annotations_format() {
+        let output = r#"{
+  "Issues": [
+    {
+      "FromLinter": "errcheck",
+      "Text": "Error return value not checked\nsecond line",
+      "Pos": {"Filename": "main.go", "Line": 42, "Column": 5}
+    }
+  ]
+}"#;
+        let parsed: GolangciOutput = serde_json::from_str(output).unwrap();
+        let lines = github_annotations(&parsed.issues, &std::collections::HashSet::new());
+        assert_eq!(lines.len(), 1);
+        assert_eq!(
+            lines[0],
+            "::error file=main.go,line=42,col=5,title=errcheck::Error return value not checked%0Asecond line"
+        );
+    }
+
+    #[test]
+    fn test_github_annotations_downgrade_to_warning() {
+        let output = r#"{"Issues":[{"FromLinter":"gosimple","Text":"use strings.Contains","Pos":{"Filename":"a.go","Line":1,"Column":1}}]}"#;
+        let parsed: GolangciOutput = serde_json::from_str(output).unwrap();
+        let mut warn = std::collections::HashSet::new();
+        warn.insert("gosimple".to_string());
+        let lines = github_annotations(&parsed.issues, &warn);
+        assert!(lines[0].starts_with("::warning"));
+    }
+
+    #[test]
+    fn test_parse_hunk_header() {
+        assert_eq!(parse_hunk_header("@@ -12,3 +15,5 @@ func foo() {"), Some((15, 5)));
+        assert_eq!(parse_hunk_header("@@ -1 +1 @@"), Some((1, 1)));
+        assert_eq!(parse_hunk_header("@@ -5,2 +5,0 @@"), Some((5, 0)));
+        assert_eq!(parse_hunk_header("not a hunk header"), None);
+    }
+
+    #[test]
+    fn test_filter_to_new_issues_against_ranges() {
+        let issues = vec![
+            Issue {
+                from_linter: "errcheck".to_string(),
+                text: "in the new range".to_string(),
+                pos: Position {
+                    filename: "main.go".to_string(),
+                    line: 16,
+                    column: 1,
+                },
+                severity: Severity::Error,
+                replacement: None,
+            },
+            Issue {
+                from_linter: "errcheck".to_string(),
+                text: "pre-existing".to_string(),
+                pos: Position {
+                    filename: "main.go".to_string(),
+                    line: 3,
+                    column: 1,
+                },
+                severity: Severity::Error,
+                replacement: None,
+            },
+        ];
+
+        let mut ranges = HashMap::new();
+        ranges.insert("main.go".to_string(), vec![(15, 20)]);
+
+        let kept: Vec<_> = issues.iter().filter(|issue| issue_in_ranges(issue, &ranges)).collect();
+
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].text, "in the new range");
+    }
+
+    #[test]
+    fn test_render_golangci_summary_fixable_count() {
+        let mut issue = Issue {
+            from_linter: "gofmt".to_string(),
+            text: "File is not gofmt-ed".to_string(),
+            pos: Position {
+                filename: "a.go".to_string(),
+                line: 1,
+                column: 1,
+            },
+            severity: Severity::Error,
+            replacement: None,
+        };
+        let fixable = Issue {
+            replacement: Some(Replacement {
+                need_only_delete: false,
+                new_lines: vec!["fixed line".to_string()],
+                inline: None,
+            }),
+            ..issue.clone()
+        };
+        issue.pos.line = 2;
+
+        let result = render_golangci_summary(&[issue, fixable], None, 10);
+        assert!(result.contains("1 of 2 issues auto-fixable (--apply-fixes)"));
+    }
+
+    #[test]
+    fn test_apply_replacements_need_only_delete() {
+        use std::io::Write;
+
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "line one\nline two\nline three").unwrap();
+
+        let issue = Issue {
+            from_linter: "unused".to_string(),
+            text: "unused import".to_string(),
+            pos: Position {
+                filename: file.path().to_string_lossy().to_string(),
+                line: 2,
+                column: 1,
+            },
+            severity: Severity::Error,
+            replacement: Some(Replacement {
+                need_only_delete: true,
+                new_lines: Vec::new(),
+                inline: None,
+            }),
+        };
+
+        let modified = apply_replacements(&[issue]).unwrap();
+        assert_eq!(modified.len(), 1);
+
+        let content = std::fs::read_to_string(file.path()).unwrap();
+        assert_eq!(content, "line one\nline three\n");
+    }
+
+    #[test]
+    fn test_apply_replacements_inline_fix() {
+        use std::io::Write;
+
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "foo := Bar()").unwrap();
+
+        let issue = Issue {
+            from_linter: "staticcheck".to_string(),
+            text: "should use foo instead".to_string(),
+            pos: Position {
+                filename: file.path().to_string_lossy().to_string(),
+                line: 1,
+                column: 1,
+            },
+            severity: Severity::Error,
+            replacement: Some(Replacement {
+                need_only_delete: false,
+                new_lines: Vec::new(),
+                inline: Some(InlineFix {
+                    start_col: 7,
+                    length: 3,
+                    new_string: "foo".to_string(),
+                }),
+            }),
+        };
+
+        let modified = apply_replacements(&[issue]).unwrap();
+        assert_eq!(modified.len(), 1);
+
+        let content = std::fs::read_to_string(file.path()).unwrap();
+        assert_eq!(content, "foo := foo()\n");
+    }
+
+    #[test]
+    fn test_apply_replacements_skips_issues_without_replacement() {
+        let issue = Issue {
+            from_linter: "errcheck".to_string(),
+            text: "unchecked error".to_string(),
+            pos: Position {
+                filename: "does-not-exist.go".to_string(),
+                line: 1,
+                column: 1,
+            },
+            severity: Severity::Error,
+            replacement: None,
+        };
+
+        let modified = apply_replacements(&[issue]).unwrap();
+        assert!(modified.is_empty());
+    }
+
     #[test]
     fn test_compact_path() {
         assert_eq!(
@@ -1,9 +1,45 @@
+use crate::glob_filter::{extract_glob_args, GlobFilter};
 use crate::tracking;
 use anyhow::{Context, Result};
 use regex::Regex;
+use serde::Deserialize;
 use std::collections::HashMap;
 use std::process::Command;
 
+/// One decoded line of ripgrep's `--json` event stream. We only care about
+/// `match` and `context` events -- `begin`/`end`/`summary` are parsed (so
+/// serde doesn't choke on them) and otherwise ignored.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum RgEvent {
+    Begin,
+    Match { data: RgMatchData },
+    Context { data: RgMatchData },
+    End,
+    Summary,
+}
+
+#[derive(Debug, Deserialize)]
+struct RgMatchData {
+    path: RgText,
+    lines: RgText,
+    line_number: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RgText {
+    text: String,
+}
+
+/// A single reported line, tagged with whether ripgrep found it via an
+/// actual pattern match or is only showing it as surrounding context
+/// (`-A`/`-B`/`-C`).
+struct Hit {
+    line_num: usize,
+    content: String,
+    is_match: bool,
+}
+
 pub fn run(
     pattern: &str,
     path: &str,
@@ -20,31 +56,34 @@ pub fn run(
         eprintln!("grep: '{}' in {}", pattern, path);
     }
 
+    let (globs, extra_args) = extract_glob_args(extra_args);
+    let glob_filter = GlobFilter::new(&globs).context("invalid --glob pattern")?;
+
     let mut rg_cmd = Command::new("rg");
-    rg_cmd.args(["-n", "--no-heading", pattern, path]);
+    rg_cmd.args(["--json", "--no-heading", pattern, path]);
 
     if let Some(ft) = file_type {
         rg_cmd.arg("--type").arg(ft);
     }
 
-    for arg in extra_args {
+    for arg in &extra_args {
         rg_cmd.arg(arg);
     }
 
-    let output = rg_cmd
-        .output()
-        .or_else(|_| Command::new("grep").args(["-rn", pattern, path]).output())
-        .context("grep/rg failed")?;
+    let output = rg_cmd.output().context("rg failed (is ripgrep installed?)")?;
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-
     let raw_output = stdout.to_string();
 
-    if stdout.trim().is_empty() {
+    let mut by_file = parse_rg_json(&stdout);
+    by_file.retain(|file, _| glob_filter.keep(file));
+    let total: usize = by_file.values().map(|hits| hits.len()).sum();
+
+    if total == 0 {
         let msg = format!("🔍 0 for '{}'", pattern);
         println!("{}", msg);
         timer.track(
-            &format!("grep -rn '{}' {}", pattern, path),
+            &format!("rg --json '{}' {}", pattern, path),
             "rtk grep",
             &raw_output,
             &msg,
@@ -52,52 +91,33 @@ pub fn run(
         return Ok(());
     }
 
-    let mut by_file: HashMap<String, Vec<(usize, String)>> = HashMap::new();
-    let mut total = 0;
-
-    for line in stdout.lines() {
-        let parts: Vec<&str> = line.splitn(3, ':').collect();
-
-        let (file, line_num, content) = if parts.len() == 3 {
-            let ln = parts[1].parse().unwrap_or(0);
-            (parts[0].to_string(), ln, parts[2])
-        } else if parts.len() == 2 {
-            let ln = parts[0].parse().unwrap_or(0);
-            (path.to_string(), ln, parts[1])
-        } else {
-            continue;
-        };
-
-        total += 1;
-        let cleaned = clean_line(content, max_line_len, context_only, pattern);
-        by_file.entry(file).or_default().push((line_num, cleaned));
-    }
-
     let mut rtk_output = String::new();
     rtk_output.push_str(&format!("🔍 {} in {}F:\n\n", total, by_file.len()));
 
     let mut shown = 0;
     let mut files: Vec<_> = by_file.iter().collect();
-    files.sort_by_key(|(f, _)| *f);
+    files.sort_by_key(|(f, _)| f.clone());
 
-    for (file, matches) in files {
+    for (file, hits) in files {
         if shown >= max_results {
             break;
         }
 
         let file_display = compact_path(file);
-        rtk_output.push_str(&format!("📄 {} ({}):\n", file_display, matches.len()));
+        rtk_output.push_str(&format!("📄 {} ({}):\n", file_display, hits.len()));
 
-        for (line_num, content) in matches.iter().take(10) {
-            rtk_output.push_str(&format!("  {:>4}: {}\n", line_num, content));
+        for hit in hits.iter().take(10) {
+            let cleaned = clean_line(&hit.content, max_line_len, context_only, pattern);
+            let sep = if hit.is_match { ':' } else { '-' };
+            rtk_output.push_str(&format!("  {:>4}{} {}\n", hit.line_num, sep, cleaned));
             shown += 1;
             if shown >= max_results {
                 break;
             }
         }
 
-        if matches.len() > 10 {
-            rtk_output.push_str(&format!("  +{}\n", matches.len() - 10));
+        if hits.len() > 10 {
+            rtk_output.push_str(&format!("  +{}\n", hits.len() - 10));
         }
         rtk_output.push('\n');
     }
@@ -108,7 +128,7 @@ pub fn run(
 
     print!("{}", rtk_output);
     timer.track(
-        &format!("grep -rn '{}' {}", pattern, path),
+        &format!("rg --json '{}' {}", pattern, path),
         "rtk grep",
         &raw_output,
         &rtk_output,
@@ -117,6 +137,43 @@ pub fn run(
     Ok(())
 }
 
+/// Decode ripgrep's newline-delimited `--json` event stream into per-file
+/// hit lists, preserving each line's match/context distinction and original
+/// order. Lines that fail to parse as a known event (or carry no line
+/// number) are skipped rather than corrupting the grouping.
+fn parse_rg_json(stdout: &str) -> HashMap<String, Vec<Hit>> {
+    let mut by_file: HashMap<String, Vec<Hit>> = HashMap::new();
+
+    for line in stdout.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let event: RgEvent = match serde_json::from_str(line) {
+            Ok(e) => e,
+            Err(_) => continue,
+        };
+
+        let (data, is_match) = match event {
+            RgEvent::Match { data } => (data, true),
+            RgEvent::Context { data } => (data, false),
+            _ => continue,
+        };
+
+        let Some(line_num) = data.line_number else {
+            continue;
+        };
+
+        by_file.entry(data.path.text).or_default().push(Hit {
+            line_num,
+            content: data.lines.text.trim_end_matches('\n').to_string(),
+            is_match,
+        });
+    }
+
+    by_file
+}
+
 fn clean_line(line: &str, max_len: usize, context_only: bool, pattern: &str) -> String {
     let trimmed = line.trim();
 
@@ -225,4 +282,36 @@ mod tests {
         let cleaned = clean_line(line, 15, false, "text");
         assert!(!cleaned.is_empty());
     }
+
+    #[test]
+    fn test_parse_rg_json_match_with_colons() {
+        let stdout = r#"{"type":"begin","data":{"path":{"text":"src/main.rs"}}}
+{"type":"match","data":{"path":{"text":"src/main.rs"},"lines":{"text":"let url = \"http://host:8080\";\n"},"line_number":10,"absolute_offset":0,"submatches":[]}}
+{"type":"end","data":{"path":{"text":"src/main.rs"}}}"#;
+
+        let by_file = parse_rg_json(stdout);
+        let hits = by_file.get("src/main.rs").expect("file present");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].line_num, 10);
+        assert!(hits[0].content.contains("http://host:8080"));
+        assert!(hits[0].is_match);
+    }
+
+    #[test]
+    fn test_parse_rg_json_distinguishes_context() {
+        let stdout = r#"{"type":"context","data":{"path":{"text":"src/lib.rs"},"lines":{"text":"  // context\n"},"line_number":4,"absolute_offset":0}}
+{"type":"match","data":{"path":{"text":"src/lib.rs"},"lines":{"text":"  fn target() {}\n"},"line_number":5,"absolute_offset":0,"submatches":[]}}"#;
+
+        let by_file = parse_rg_json(stdout);
+        let hits = by_file.get("src/lib.rs").expect("file present");
+        assert_eq!(hits.len(), 2);
+        assert!(!hits[0].is_match);
+        assert!(hits[1].is_match);
+    }
+
+    #[test]
+    fn test_parse_rg_json_empty() {
+        let by_file = parse_rg_json("");
+        assert!(by_file.is_empty());
+    }
 }
@@ -0,0 +1,125 @@
+use crate::tracking;
+use anyhow::Result;
+use std::process::Command;
+
+/// A single detected (or missing) tool in the local toolchain.
+#[derive(Debug)]
+struct EnvTool {
+    name: String,
+    path: Option<String>,
+    version: Option<String>,
+    ok: bool,
+}
+
+/// One-shot environment diagnostic: detect the local package manager,
+/// Python, and node/npm, and print a ✓/✗ checklist. Modeled on `tauri info`
+/// — useful before `rtk pip install`/`uninstall` passthrough runs so a
+/// failure there has an obvious explanation.
+pub fn run(verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let use_uv = which_command("uv").is_some();
+    let package_manager = if use_uv {
+        probe_tool("uv", &["--version"])
+    } else {
+        probe_tool("pip", &["--version"])
+    };
+
+    let python = probe_tool("python3", &["--version"])
+        .or_else(|| probe_tool("python", &["--version"]));
+    let venv = virtualenv_status();
+    let node = probe_tool("node", &["--version"]);
+    let npm = probe_tool("npm", &["--version"]);
+
+    let tools = vec![package_manager, python, node, npm];
+
+    if verbose > 0 {
+        eprintln!("rtk info: probing {} tools", tools.len());
+    }
+
+    let mut missing = 0;
+    let mut lines = vec!["rtk info: environment".to_string()];
+    lines.push("═══════════════════════════════════════".to_string());
+
+    for tool in &tools {
+        let mark = if tool.ok { "✓" } else { "✗" };
+        if !tool.ok {
+            missing += 1;
+        }
+        match (&tool.path, &tool.version) {
+            (Some(path), Some(version)) => {
+                lines.push(format!("{} {} {} ({})", mark, tool.name, version, path));
+            }
+            _ => {
+                lines.push(format!("{} {} (not found)", mark, tool.name));
+            }
+        }
+    }
+
+    lines.push(format!(
+        "venv: {}",
+        if venv {
+            "active"
+        } else {
+            "not active"
+        }
+    ));
+
+    lines.push(String::new());
+    lines.push(format!(
+        "{}/{} tools found",
+        tools.len() - missing,
+        tools.len()
+    ));
+
+    let output = lines.join("\n");
+    println!("{}", output);
+
+    timer.track("rtk info", "rtk info", &output, &output);
+
+    Ok(())
+}
+
+/// Run `<name> <probe_args>` and build an [`EnvTool`] from the result.
+/// Absence (binary not in PATH, or a non-zero exit) is reported, not
+/// propagated as an error — a missing tool is exactly what `info` exists to
+/// surface.
+fn probe_tool(name: &str, probe_args: &[&str]) -> EnvTool {
+    let path = which_command(name);
+
+    let version = path.as_ref().and_then(|_| {
+        Command::new(name)
+            .args(probe_args)
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .and_then(|o| String::from_utf8(o.stdout).ok())
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+    });
+
+    EnvTool {
+        name: name.to_string(),
+        ok: path.is_some(),
+        path,
+        version,
+    }
+}
+
+/// Check if a command exists in PATH
+fn which_command(cmd: &str) -> Option<String> {
+    Command::new("which")
+        .arg(cmd)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// Detect whether a Python virtualenv is currently active, the same way
+/// `python -m venv`-activated shells signal it.
+fn virtualenv_status() -> bool {
+    std::env::var("VIRTUAL_ENV").is_ok()
+}
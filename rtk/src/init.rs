@@ -1,15 +1,91 @@
+use crate::style::Style;
 use anyhow::{Context, Result};
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use tempfile::NamedTempFile;
 
+/// Thin `fs-err`-style wrappers around the handful of `std::fs` calls this
+/// module makes. `rtk init` touches five-plus paths under `~/.claude` in a
+/// single run, so a bare `permission denied` with no file attached is
+/// genuinely hard to act on — every call here attaches the offending path
+/// via `anyhow` context instead.
+mod pathfs {
+    use anyhow::{Context, Result};
+    use std::path::Path;
+
+    pub fn read_to_string(path: &Path) -> Result<String> {
+        std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+    }
+
+    pub fn write(path: &Path, contents: impl AsRef<[u8]>) -> Result<()> {
+        std::fs::write(path, contents)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    pub fn create_dir_all(path: &Path) -> Result<()> {
+        std::fs::create_dir_all(path)
+            .with_context(|| format!("Failed to create directory {}", path.display()))
+    }
+
+    pub fn metadata(path: &Path) -> Result<std::fs::Metadata> {
+        std::fs::metadata(path).with_context(|| format!("Failed to stat {}", path.display()))
+    }
+}
+
 // Embedded hook script (guards before set -euo pipefail)
 const REWRITE_HOOK: &str = include_str!("../hooks/rtk-rewrite.sh");
 
+// Embedded Windows counterpart (PowerShell, since .sh isn't directly runnable there)
+const REWRITE_HOOK_PS1: &str = include_str!("../hooks/rtk-rewrite.ps1");
+
 // Embedded slim RTK awareness instructions
 const RTK_SLIM: &str = include_str!("../hooks/rtk-awareness.md");
 
+// Embedded git hook templates (guards before set -euo pipefail, same shape as REWRITE_HOOK)
+const GIT_HOOK_PRE_COMMIT: &str = include_str!("../hooks/pre-commit.sh");
+const GIT_HOOK_PRE_PUSH: &str = include_str!("../hooks/pre-push.sh");
+
+/// Marker a generated git hook carries so a later `rtk init --git-hooks` can
+/// tell "rtk's own file, safe to leave in place" apart from a hand-written
+/// hook that must be preserved instead of clobbered.
+const RTK_HOOK_MARKER: &str = "rtk-managed-hook";
+
+/// A command that carries both a Unix and a Windows form, so callers pick
+/// the right one for the current platform instead of sprinkling
+/// `#[cfg(unix)]` through the init flow (borrowed from rust-analyzer
+/// xtask's `Cmd { unix, windows, work_dir }`).
+struct HookScript {
+    unix: &'static str,
+    windows: &'static str,
+}
+
+const HOOK_SCRIPT: HookScript = HookScript {
+    unix: REWRITE_HOOK,
+    windows: REWRITE_HOOK_PS1,
+};
+
+impl HookScript {
+    /// Script contents to install for the current platform.
+    fn contents(&self) -> &'static str {
+        if cfg!(windows) {
+            self.windows
+        } else {
+            self.unix
+        }
+    }
+
+    /// Hook file name for the current platform (`.ps1` needs it to dispatch
+    /// through PowerShell; `.sh` needs it to keep its shebang meaningful).
+    fn file_name(&self) -> &'static str {
+        if cfg!(windows) {
+            "rtk-rewrite.ps1"
+        } else {
+            "rtk-rewrite.sh"
+        }
+    }
+}
+
 /// Control flow for settings.json patching
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum PatchMode {
@@ -25,6 +101,17 @@ pub enum PatchResult {
     AlreadyPresent, // Hook was already in settings.json
     Declined,       // User declined when prompted
     Skipped,        // --no-patch flag used
+    DryRun,         // --dry-run flag used: diff shown, nothing written
+}
+
+/// Where an RTK installation lives: shared across all of a developer's
+/// projects (`~/.claude`), or committed to a single repo so every
+/// contributor gets the same hook without touching their home directory
+/// (`./.claude`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClaudeDirScope {
+    Global,
+    Project,
 }
 
 // Legacy full instructions for backward compatibility (--claude-md mode)
@@ -169,65 +256,344 @@ pub fn run(
     claude_md: bool,
     hook_only: bool,
     patch_mode: PatchMode,
+    dry_run: bool,
     verbose: u8,
 ) -> Result<()> {
     // Mode selection
     match (claude_md, hook_only) {
-        (true, _) => run_claude_md_mode(global, verbose),
-        (false, true) => run_hook_only_mode(global, patch_mode, verbose),
-        (false, false) => run_default_mode(global, patch_mode, verbose),
+        (true, _) => run_claude_md_mode(global, dry_run, verbose),
+        (false, true) => run_hook_only_mode(global, patch_mode, dry_run, verbose),
+        (false, false) => run_default_mode(global, patch_mode, dry_run, verbose),
     }
 }
 
 /// Prepare hook directory and return paths (hook_dir, hook_path)
-fn prepare_hook_paths() -> Result<(PathBuf, PathBuf)> {
-    let claude_dir = resolve_claude_dir()?;
+fn prepare_hook_paths(scope: ClaudeDirScope) -> Result<(PathBuf, PathBuf)> {
+    let claude_dir = resolve_claude_dir_for(scope)?;
     let hook_dir = claude_dir.join("hooks");
     fs::create_dir_all(&hook_dir)
         .with_context(|| format!("Failed to create hook directory: {}", hook_dir.display()))?;
-    let hook_path = hook_dir.join("rtk-rewrite.sh");
+    let hook_path = hook_dir.join(HOOK_SCRIPT.file_name());
     Ok((hook_dir, hook_path))
 }
 
-/// Write hook file if missing or outdated, return true if changed
-#[cfg(unix)]
-fn ensure_hook_installed(hook_path: &Path, verbose: u8) -> Result<bool> {
-    let changed = if hook_path.exists() {
-        let existing = fs::read_to_string(hook_path)
-            .with_context(|| format!("Failed to read existing hook: {}", hook_path.display()))?;
+/// Write hook file if missing or outdated, return true if changed.
+/// Picks the Unix shell script or Windows PowerShell script for the
+/// current platform; executable bits only mean anything on Unix.
+///
+/// Before handing off to [`write_if_changed`], compares the installed
+/// file's `rtk-hook-version` stamp against the embedded one: if the
+/// installed version is already current, the write (and its diff
+/// preview/prompt) is skipped entirely rather than re-asking about a file
+/// that's only drifted in, say, trailing whitespace. An older or unstamped
+/// install still goes through the normal diff/prompt flow, with an
+/// "upgraded hook vX -> vY" notice first.
+fn ensure_hook_installed(
+    hook_path: &Path,
+    mode: PatchMode,
+    dry_run: bool,
+    verbose: u8,
+) -> Result<bool> {
+    let embedded_version = version_stamp(HOOK_SCRIPT.contents());
+    let installed_version = fs::read_to_string(hook_path)
+        .ok()
+        .and_then(|content| version_stamp(&content).map(str::to_string));
+
+    let up_to_date = match (installed_version.as_deref(), embedded_version) {
+        (Some(installed), Some(embedded)) => {
+            matches!(
+                (parse_semver(installed), parse_semver(embedded)),
+                (Some(old), Some(new)) if old >= new
+            )
+        }
+        _ => false,
+    };
+
+    let changed = if up_to_date {
+        if verbose > 0 {
+            eprintln!(
+                "Hook: up to date (v{})",
+                embedded_version.unwrap_or("?")
+            );
+        }
+        false
+    } else {
+        if let (Some(installed), Some(embedded)) = (installed_version.as_deref(), embedded_version)
+        {
+            println!("Upgraded hook v{installed} -> v{embedded}");
+        }
+        write_if_changed(
+            hook_path,
+            HOOK_SCRIPT.contents(),
+            "Hook",
+            mode,
+            dry_run,
+            verbose,
+        )?
+    };
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(hook_path, fs::Permissions::from_mode(0o755))
+            .with_context(|| format!("Failed to set hook permissions: {}", hook_path.display()))?;
+    }
+
+    Ok(changed)
+}
+
+/// Extract a `rtk-hook-version: X.Y.Z` stamp from a hook script (`#` comment)
+/// or RTK.md (HTML comment), used to tell an upgraded artifact from one
+/// that's merely been reformatted.
+fn version_stamp(content: &str) -> Option<&str> {
+    let line = content.lines().find(|l| l.contains("rtk-hook-version:"))?;
+    let after = line.split("rtk-hook-version:").nth(1)?.trim();
+    Some(after.trim_end_matches("-->").trim())
+}
+
+/// Parse a dotted `major.minor.patch` version into a comparable tuple.
+fn parse_semver(version: &str) -> Option<(u64, u64, u64)> {
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    let patch = parts.next()?.parse().ok()?;
+    Some((major, minor, patch))
+}
+
+/// Resolve the real git directory for the repository rooted at the current
+/// directory. In a worktree or submodule checkout, `.git` is a file
+/// containing a `gitdir: <path>` pointer rather than the repository
+/// directory itself, so that case is resolved explicitly instead of
+/// assuming `.git` is always a directory. Returns `None` if there's no git
+/// repository here at all.
+///
+/// Worktrees additionally need one more hop: their gitdir
+/// (`.git/worktrees/<name>`) is worktree-private, but hooks are shared
+/// across all of a repo's worktrees and live in the *common* git dir, which
+/// that directory records in its own `commondir` file. Submodules have no
+/// such file, since each submodule is an independent repo with its own
+/// hooks — so their gitdir is used as-is.
+fn resolve_git_dir() -> Result<Option<PathBuf>> {
+    let git_dir = PathBuf::from(".git");
+    if git_dir.is_dir() {
+        return Ok(Some(git_dir));
+    }
+    if !git_dir.is_file() {
+        return Ok(None);
+    }
+
+    let contents = fs::read_to_string(&git_dir)
+        .with_context(|| format!("Failed to read {}", git_dir.display()))?;
+    let pointer = contents
+        .lines()
+        .find_map(|line| line.strip_prefix("gitdir:"))
+        .with_context(|| {
+            format!(
+                "{} is not a directory and doesn't look like a gitdir pointer",
+                git_dir.display()
+            )
+        })?
+        .trim();
+    let pointer_path = PathBuf::from(pointer);
+    let pointer_path = if pointer_path.is_absolute() {
+        pointer_path
+    } else {
+        PathBuf::from(".").join(pointer_path)
+    };
+
+    let commondir_file = pointer_path.join("commondir");
+    if commondir_file.is_file() {
+        let commondir = fs::read_to_string(&commondir_file)
+            .with_context(|| format!("Failed to read {}", commondir_file.display()))?;
+        let commondir_path = PathBuf::from(commondir.trim());
+        return Ok(Some(if commondir_path.is_absolute() {
+            commondir_path
+        } else {
+            pointer_path.join(commondir_path)
+        }));
+    }
+
+    Ok(Some(pointer_path))
+}
+
+/// `.git/hooks` for the repository rooted at the current directory,
+/// creating it if necessary.
+fn git_hooks_dir() -> Result<PathBuf> {
+    let git_dir = resolve_git_dir()?
+        .context("Not a git repository (no .git directory found in the current directory)")?;
+    let hooks_dir = git_dir.join("hooks");
+    fs::create_dir_all(&hooks_dir)
+        .with_context(|| format!("Failed to create git hooks directory: {}", hooks_dir.display()))?;
+    Ok(hooks_dir)
+}
+
+/// Append a stanza that runs a preserved hand-written hook after `template`'s
+/// own checks pass, so installing rtk's hook doesn't silently drop one.
+fn render_git_hook(template: &str, chained_hook: Option<&Path>) -> String {
+    match chained_hook {
+        Some(path) => format!(
+            "{template}\n# Then run the hook that was here before rtk managed this file.\nif [ -x \"{0}\" ]; then\n    \"{0}\" \"$@\"\nfi\n",
+            path.display()
+        ),
+        None => template.to_string(),
+    }
+}
 
-        if existing == REWRITE_HOOK {
+/// Install or update one git hook (`pre-commit`, `pre-push`, ...), reusing
+/// the same atomic-write-then-chmod shape as [`ensure_hook_installed`].
+///
+/// If a hook with this name already carries [`RTK_HOOK_MARKER`], it's left
+/// alone — the CHECKS array inside is meant to be hand-edited, so re-running
+/// `rtk init --git-hooks` must not clobber those edits. If a *different*,
+/// hand-written hook is found instead, it's preserved at `<name>.pre-rtk`
+/// and chained at the end of the generated script rather than overwritten.
+fn install_git_hook(name: &str, template: &str, verbose: u8) -> Result<bool> {
+    let hooks_dir = git_hooks_dir()?;
+    let hook_path = hooks_dir.join(name);
+
+    let content = if hook_path.exists() {
+        let existing = fs::read_to_string(&hook_path)
+            .with_context(|| format!("Failed to read existing {name} hook: {}", hook_path.display()))?;
+
+        if existing.contains(RTK_HOOK_MARKER) {
             if verbose > 0 {
-                eprintln!("Hook already up to date: {}", hook_path.display());
+                eprintln!(
+                    "{name} hook already managed by rtk, leaving customizations in place: {}",
+                    hook_path.display()
+                );
+            }
+            return Ok(false);
+        }
+
+        let backup_path = hooks_dir.join(format!("{name}.pre-rtk"));
+        if backup_path.exists() {
+            let backed_up = fs::read_to_string(&backup_path).with_context(|| {
+                format!("Failed to read existing backup {}", backup_path.display())
+            })?;
+            if backed_up != existing {
+                // A backup is already there and it's *not* a copy of what's
+                // at hook_path right now — renaming over it would either
+                // lose that backup or (if we skip the rename) silently drop
+                // the hand-written hook sitting at hook_path. Bail instead
+                // of guessing which one the user wants to keep.
+                anyhow::bail!(
+                    "{} already exists and differs from the current {name} hook; \
+                     resolve manually (keep or remove one of them), then re-run `rtk init --git-hooks`",
+                    backup_path.display()
+                );
             }
-            false
         } else {
-            fs::write(hook_path, REWRITE_HOOK)
-                .with_context(|| format!("Failed to write hook to {}", hook_path.display()))?;
+            fs::rename(&hook_path, &backup_path).with_context(|| {
+                format!(
+                    "Failed to preserve existing {name} hook at {}",
+                    backup_path.display()
+                )
+            })?;
             if verbose > 0 {
-                eprintln!("Updated hook: {}", hook_path.display());
+                eprintln!("Preserved existing {name} hook: {}", backup_path.display());
             }
-            true
         }
+        render_git_hook(template, Some(&backup_path))
     } else {
-        fs::write(hook_path, REWRITE_HOOK)
-            .with_context(|| format!("Failed to write hook to {}", hook_path.display()))?;
+        template.to_string()
+    };
+
+    atomic_write(&hook_path, &content)?;
+    if verbose > 0 {
+        eprintln!("Installed {name} hook: {}", hook_path.display());
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&hook_path, fs::Permissions::from_mode(0o755))
+            .with_context(|| format!("Failed to set hook permissions: {}", hook_path.display()))?;
+    }
+
+    Ok(true)
+}
+
+/// Remove a rtk-managed git hook, restoring any hand-written hook that was
+/// preserved when rtk first installed over it. Returns a message describing
+/// what happened, or `None` if there was nothing rtk-managed to remove.
+fn remove_git_hook(name: &str, verbose: u8) -> Result<Option<String>> {
+    let Some(git_dir) = resolve_git_dir()? else {
+        return Ok(None);
+    };
+    let hooks_dir = git_dir.join("hooks");
+    let hook_path = hooks_dir.join(name);
+    if !hook_path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&hook_path)
+        .with_context(|| format!("Failed to read {name} hook: {}", hook_path.display()))?;
+    if !content.contains(RTK_HOOK_MARKER) {
+        return Ok(None);
+    }
+
+    fs::remove_file(&hook_path)
+        .with_context(|| format!("Failed to remove {name} hook: {}", hook_path.display()))?;
+
+    let backup_path = hooks_dir.join(format!("{name}.pre-rtk"));
+    if backup_path.exists() {
+        fs::rename(&backup_path, &hook_path).with_context(|| {
+            format!(
+                "Failed to restore original {name} hook from {}",
+                backup_path.display()
+            )
+        })?;
         if verbose > 0 {
-            eprintln!("Created hook: {}", hook_path.display());
+            eprintln!("Restored original {name} hook: {}", hook_path.display());
         }
-        true
-    };
+        return Ok(Some(format!("Git hook: {name} removed, original restored")));
+    }
+
+    Ok(Some(format!("Git hook: {name} removed")))
+}
 
-    // Set executable permissions
-    use std::os::unix::fs::PermissionsExt;
-    fs::set_permissions(hook_path, fs::Permissions::from_mode(0o755))
-        .with_context(|| format!("Failed to set hook permissions: {}", hook_path.display()))?;
+/// `rtk init --git-hooks`: install a pre-commit hook (and, with
+/// `include_pre_push`, a pre-push hook) that runs project checks through
+/// `rtk` before letting a commit/push through.
+pub fn install_git_hooks(include_pre_push: bool, verbose: u8) -> Result<()> {
+    let installed_commit = install_git_hook("pre-commit", GIT_HOOK_PRE_COMMIT, verbose)?;
 
-    Ok(changed)
+    println!("\nrtk git hooks installed.\n");
+    if installed_commit {
+        println!("  pre-commit: .git/hooks/pre-commit");
+    } else {
+        println!("  pre-commit: already managed by rtk, left as-is");
+    }
+
+    if include_pre_push {
+        let installed_push = install_git_hook("pre-push", GIT_HOOK_PRE_PUSH, verbose)?;
+        if installed_push {
+            println!("  pre-push:   .git/hooks/pre-push");
+        } else {
+            println!("  pre-push:   already managed by rtk, left as-is");
+        }
+    }
+
+    println!("\n  Edit the CHECKS array in the hook script(s) to customize what runs.");
+
+    Ok(())
 }
 
-/// Idempotent file write: create or update if content differs
-fn write_if_changed(path: &Path, content: &str, name: &str, verbose: u8) -> Result<bool> {
+/// Idempotent file write: create or update if content differs.
+///
+/// Before touching the file, previews the change as a unified diff
+/// ([`render_unified_diff`]) so the user can see exactly what's about to be
+/// rewritten. `dry_run` prints that preview and returns without writing;
+/// otherwise `PatchMode::Ask` additionally gates the write on a `[y/N]`
+/// prompt, same as [`patch_settings_json`]'s settings.json prompt.
+fn write_if_changed(
+    path: &Path,
+    content: &str,
+    name: &str,
+    mode: PatchMode,
+    dry_run: bool,
+    verbose: u8,
+) -> Result<bool> {
     if path.exists() {
         let existing = fs::read_to_string(path)
             .with_context(|| format!("Failed to read {}: {}", name, path.display()))?;
@@ -236,16 +602,44 @@ fn write_if_changed(path: &Path, content: &str, name: &str, verbose: u8) -> Resu
             if verbose > 0 {
                 eprintln!("{} already up to date: {}", name, path.display());
             }
-            Ok(false)
-        } else {
-            fs::write(path, content)
-                .with_context(|| format!("Failed to write {}: {}", name, path.display()))?;
-            if verbose > 0 {
-                eprintln!("Updated {}: {}", name, path.display());
-            }
-            Ok(true)
+            return Ok(false);
+        }
+
+        if let Some(diff) = render_unified_diff(&existing, content, &path.display().to_string()) {
+            eprintln!("{diff}");
         }
+
+        if dry_run {
+            eprintln!("(dry run, not written: {})", path.display());
+            return Ok(false);
+        }
+
+        if mode == PatchMode::Ask && !confirm(&format!("\nWrite {}?", path.display()))? {
+            eprintln!("Skipped (declined): {}", path.display());
+            return Ok(false);
+        }
+
+        fs::write(path, content)
+            .with_context(|| format!("Failed to write {}: {}", name, path.display()))?;
+        if verbose > 0 {
+            eprintln!("Updated {}: {}", name, path.display());
+        }
+        Ok(true)
     } else {
+        if let Some(diff) = render_unified_diff("", content, &path.display().to_string()) {
+            eprintln!("{diff}");
+        }
+
+        if dry_run {
+            eprintln!("(dry run, not written: {})", path.display());
+            return Ok(false);
+        }
+
+        if mode == PatchMode::Ask && !confirm(&format!("\nCreate {}?", path.display()))? {
+            eprintln!("Skipped (declined): {}", path.display());
+            return Ok(false);
+        }
+
         fs::write(path, content)
             .with_context(|| format!("Failed to write {}: {}", name, path.display()))?;
         if verbose > 0 {
@@ -285,13 +679,138 @@ fn atomic_write(path: &Path, content: &str) -> Result<()> {
     Ok(())
 }
 
+/// Path of the on-disk snapshot a [`Transaction`] writes before mutating
+/// `path`, e.g. `settings.json` -> `settings.json.rtk.bak`. Kept as a
+/// standalone function (rather than a `Transaction` method) so `restore`
+/// can compute the same path in a fresh process that never built a
+/// `Transaction` at all.
+fn backup_path_for(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".rtk.bak");
+    path.with_file_name(name)
+}
+
+/// Makes the multi-file `rtk init -g` sequence (hook, RTK.md, CLAUDE.md,
+/// settings.json) all-or-nothing. Each file's pre-transaction contents are
+/// backed up to `<path>.rtk.bak` (and held in memory) the first time
+/// [`Transaction::snapshot`] sees it; if anything later in the sequence
+/// fails, [`Transaction::rollback`] restores every snapshot instead of
+/// leaving the install half-configured. The on-disk `.rtk.bak` copies also
+/// let `rtk init -g --restore` recover after the process itself died
+/// mid-install, when there's no `Transaction` left to roll back.
+struct Transaction {
+    snapshots: Vec<(PathBuf, Option<String>)>,
+}
+
+impl Transaction {
+    fn new() -> Self {
+        Transaction {
+            snapshots: Vec::new(),
+        }
+    }
+
+    /// Record `path`'s current contents (or its absence) before it's
+    /// mutated. `None` means the file didn't exist yet, so rollback should
+    /// remove it rather than write empty content.
+    fn snapshot(&mut self, path: &Path) -> Result<()> {
+        let previous = if path.exists() {
+            let content = pathfs::read_to_string(path)?;
+            pathfs::write(&backup_path_for(path), &content)?;
+            Some(content)
+        } else {
+            None
+        };
+        self.snapshots.push((path.to_path_buf(), previous));
+        Ok(())
+    }
+
+    /// Restore every snapshotted file to its pre-transaction state.
+    fn rollback(&self) {
+        for (path, previous) in &self.snapshots {
+            let result = match previous {
+                Some(content) => pathfs::write(path, content),
+                None if path.exists() => fs::remove_file(path)
+                    .with_context(|| format!("Failed to remove {}", path.display())),
+                None => Ok(()),
+            };
+            if let Err(e) = result {
+                eprintln!("⚠️  Rollback failed for {}: {e}", path.display());
+            }
+        }
+    }
+
+    /// Discard the `.rtk.bak` files once every change has applied cleanly.
+    fn commit(&self) {
+        for (path, _) in &self.snapshots {
+            let _ = fs::remove_file(backup_path_for(path));
+        }
+    }
+}
+
+/// Restore `.rtk.bak` snapshots left behind by a transaction that crashed
+/// or was interrupted mid-install. Unlike [`Transaction::rollback`] this
+/// doesn't need the original transaction in memory - it recomputes the
+/// same backup paths and restores whichever of them still exist, so it
+/// works from a fresh `rtk init -g --restore` invocation any time after
+/// the fact.
+pub fn restore(global: bool, verbose: u8) -> Result<()> {
+    let scope = if global {
+        ClaudeDirScope::Global
+    } else {
+        ClaudeDirScope::Project
+    };
+    let claude_dir = resolve_claude_dir_for(scope)?;
+    let candidates = [
+        claude_dir.join("hooks").join(HOOK_SCRIPT.file_name()),
+        claude_dir.join("RTK.md"),
+        resolve_claude_md_path(scope)?,
+        claude_dir.join("settings.json"),
+    ];
+
+    let mut restored = Vec::new();
+    for path in &candidates {
+        let backup_path = backup_path_for(path);
+        if !backup_path.exists() {
+            continue;
+        }
+
+        let content = pathfs::read_to_string(&backup_path)?;
+        pathfs::write(path, &content)?;
+        fs::remove_file(&backup_path)
+            .with_context(|| format!("Failed to remove {}", backup_path.display()))?;
+        if verbose > 0 {
+            eprintln!("Restored {} from {}", path.display(), backup_path.display());
+        }
+        restored.push(path.display().to_string());
+    }
+
+    if restored.is_empty() {
+        println!("No .rtk.bak snapshots found, nothing to restore.");
+    } else {
+        println!("Restored {} file(s):", restored.len());
+        for path in &restored {
+            println!("  - {path}");
+        }
+    }
+
+    Ok(())
+}
+
 /// Prompt user for consent to patch settings.json
 /// Prints to stderr (stdout may be piped), reads from stdin
 /// Default is No (capital N)
 fn prompt_user_consent(settings_path: &Path) -> Result<bool> {
+    confirm(&format!("\nPatch existing {}?", settings_path.display()))
+}
+
+/// Shared `[y/N]` gate behind every `PatchMode::Ask` prompt in `init`.
+/// Prints to stderr (stdout may be piped), reads from stdin, and defaults
+/// to No - both on an empty answer and on non-interactive (piped) stdin,
+/// since a hook/settings.json write should never happen by accident.
+fn confirm(prompt: &str) -> Result<bool> {
     use std::io::{self, BufRead, IsTerminal};
 
-    eprintln!("\nPatch existing {}? [y/N] ", settings_path.display());
+    eprintln!("{prompt} [y/N] ");
 
     // If stdin is not a terminal (piped), default to No
     if !io::stdin().is_terminal() {
@@ -310,18 +829,219 @@ fn prompt_user_consent(settings_path: &Path) -> Result<bool> {
     Ok(response == "y" || response == "yes")
 }
 
+/// One step of turning `old` into `new`, as produced by [`diff_lines`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DiffOp {
+    Equal(String),
+    Delete(String),
+    Insert(String),
+}
+
+/// Align `old` and `new` line vectors with an LCS backtrack, returning the
+/// ordered sequence of equal/delete/insert ops that turns `old` into `new`.
+/// Same recurrence as `learn::detector::diff_tokens`, but over whole lines
+/// instead of shell tokens: `lcs[i][j]` is the length of the longest common
+/// subsequence of `old[i..]` and `new[j..]`, built bottom-up and then
+/// backtracked from `lcs[0][0]`.
+fn diff_lines(old: &[&str], new: &[&str]) -> Vec<DiffOp> {
+    let m = old.len();
+    let n = new.len();
+
+    let mut lcs = vec![vec![0usize; n + 1]; m + 1];
+    for i in (0..m).rev() {
+        for j in (0..n).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::with_capacity(m.max(n));
+    let mut i = 0;
+    let mut j = 0;
+    while i < m && j < n {
+        if old[i] == new[j] {
+            ops.push(DiffOp::Equal(old[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            ops.push(DiffOp::Delete(old[i].to_string()));
+            i += 1;
+        } else {
+            ops.push(DiffOp::Insert(new[j].to_string()));
+            j += 1;
+        }
+    }
+    while i < m {
+        ops.push(DiffOp::Delete(old[i].to_string()));
+        i += 1;
+    }
+    while j < n {
+        ops.push(DiffOp::Insert(new[j].to_string()));
+        j += 1;
+    }
+    ops
+}
+
+/// Default number of unchanged context lines kept around a change when
+/// grouping [`diff_lines`] ops into [`Hunk`]s, matching `diff -u`'s default.
+const DIFF_CONTEXT: usize = 3;
+
+/// A contiguous run of changed lines plus up to [`DIFF_CONTEXT`] lines of
+/// surrounding unchanged context, ready to render as one `@@ -a,b +c,d @@`
+/// unified-diff hunk.
+struct Hunk {
+    old_start: usize,
+    old_len: usize,
+    new_start: usize,
+    new_len: usize,
+    lines: Vec<DiffOp>,
+}
+
+/// Group a flat op sequence into hunks, dropping unchanged runs that fall
+/// outside `context` lines of the nearest change so a one-line edit in a
+/// long file doesn't reprint the whole thing.
+fn build_hunks(ops: &[DiffOp], context: usize) -> Vec<Hunk> {
+    let n = ops.len();
+    let mut keep = vec![false; n];
+    for (idx, op) in ops.iter().enumerate() {
+        if !matches!(op, DiffOp::Equal(_)) {
+            let start = idx.saturating_sub(context);
+            let end = (idx + context + 1).min(n);
+            keep[start..end].fill(true);
+        }
+    }
+
+    let mut hunks = Vec::new();
+    let mut old_line = 1usize;
+    let mut new_line = 1usize;
+    let mut idx = 0;
+    while idx < n {
+        if !keep[idx] {
+            match &ops[idx] {
+                DiffOp::Equal(_) => {
+                    old_line += 1;
+                    new_line += 1;
+                }
+                DiffOp::Delete(_) => old_line += 1,
+                DiffOp::Insert(_) => new_line += 1,
+            }
+            idx += 1;
+            continue;
+        }
+
+        let old_start = old_line;
+        let new_start = new_line;
+        let mut lines = Vec::new();
+        while idx < n && keep[idx] {
+            match &ops[idx] {
+                DiffOp::Equal(s) => {
+                    lines.push(DiffOp::Equal(s.clone()));
+                    old_line += 1;
+                    new_line += 1;
+                }
+                DiffOp::Delete(s) => {
+                    lines.push(DiffOp::Delete(s.clone()));
+                    old_line += 1;
+                }
+                DiffOp::Insert(s) => {
+                    lines.push(DiffOp::Insert(s.clone()));
+                    new_line += 1;
+                }
+            }
+            idx += 1;
+        }
+
+        let old_len = lines
+            .iter()
+            .filter(|op| !matches!(op, DiffOp::Insert(_)))
+            .count();
+        let new_len = lines
+            .iter()
+            .filter(|op| !matches!(op, DiffOp::Delete(_)))
+            .count();
+        hunks.push(Hunk {
+            old_start,
+            old_len,
+            new_start,
+            new_len,
+            lines,
+        });
+    }
+    hunks
+}
+
+/// Render the proposed change from `old` to `new` as a colored unified
+/// diff, for previewing a file mutation before it happens. Returns `None`
+/// when the two are identical (nothing to preview). Color follows the
+/// same `auto` resolution as the rest of rtk's reports: on when stdout is
+/// a terminal and `NO_COLOR` is unset, off otherwise.
+pub(crate) fn render_unified_diff(old: &str, new: &str, label: &str) -> Option<String> {
+    if old == new {
+        return None;
+    }
+
+    let style = Style::resolve("auto");
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let hunks = build_hunks(&diff_lines(&old_lines, &new_lines), DIFF_CONTEXT);
+
+    let mut out = String::new();
+    out.push_str(&style.bold(&format!(
+        "--- {}\n",
+        if old.is_empty() { "/dev/null" } else { label }
+    )));
+    out.push_str(&style.bold(&format!("+++ {label}\n")));
+    for hunk in hunks {
+        out.push_str(&style.dim(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            hunk.old_start, hunk.old_len, hunk.new_start, hunk.new_len
+        )));
+        for op in hunk.lines {
+            let line = match op {
+                DiffOp::Equal(s) => format!(" {s}\n"),
+                DiffOp::Delete(s) => style.red(&format!("-{s}\n")),
+                DiffOp::Insert(s) => style.green(&format!("+{s}\n")),
+            };
+            out.push_str(&line);
+        }
+    }
+    Some(out)
+}
+
 /// Print manual instructions for settings.json patching
-fn print_manual_instructions(hook_path: &Path) {
+fn print_manual_instructions(hook_path: &Path) -> Result<()> {
+    let hook_command = hook_invocation(hook_path)?;
     println!("\n  MANUAL STEP: Add this to ~/.claude/settings.json:");
     println!("  {{");
     println!("    \"hooks\": {{ \"PreToolUse\": [{{");
     println!("      \"matcher\": \"Bash\",");
     println!("      \"hooks\": [{{ \"type\": \"command\",");
-    println!("        \"command\": \"{}\"", hook_path.display());
+    println!("        \"command\": \"{hook_command}\"");
     println!("      }}]");
     println!("    }}]}}");
     println!("  }}");
     println!("\n  Then restart Claude Code. Test with: git status\n");
+    Ok(())
+}
+
+/// Command string to register in settings.json's PreToolUse hook list.
+/// Unix scripts run directly via their shebang; `.ps1` files aren't
+/// directly executable on Windows, so they're dispatched through
+/// `powershell.exe` explicitly.
+fn hook_invocation(hook_path: &Path) -> Result<String> {
+    let path = hook_path
+        .to_str()
+        .context("Hook path contains invalid UTF-8")?;
+    if cfg!(windows) {
+        Ok(format!(
+            "powershell -NoProfile -ExecutionPolicy Bypass -File \"{path}\""
+        ))
+    } else {
+        Ok(path.to_string())
+    }
 }
 
 /// Remove RTK hook entry from settings.json
@@ -343,7 +1063,7 @@ fn remove_hook_from_json(root: &mut serde_json::Value) -> bool {
         if let Some(hooks_array) = entry.get("hooks").and_then(|h| h.as_array()) {
             for hook in hooks_array {
                 if let Some(command) = hook.get("command").and_then(|c| c.as_str()) {
-                    if command.contains("rtk-rewrite.sh") {
+                    if is_rtk_rewrite_command(command) {
                         return false; // Remove this entry
                     }
                 }
@@ -357,8 +1077,8 @@ fn remove_hook_from_json(root: &mut serde_json::Value) -> bool {
 
 /// Remove RTK hook from settings.json file
 /// Backs up before modification, returns true if hook was found and removed
-fn remove_hook_from_settings(verbose: u8) -> Result<bool> {
-    let claude_dir = resolve_claude_dir()?;
+fn remove_hook_from_settings(verbose: u8, scope: ClaudeDirScope) -> Result<bool> {
+    let claude_dir = resolve_claude_dir_for(scope)?;
     let settings_path = claude_dir.join("settings.json");
 
     if !settings_path.exists() {
@@ -401,15 +1121,22 @@ fn remove_hook_from_settings(verbose: u8) -> Result<bool> {
 
 /// Full uninstall: remove hook, RTK.md, @RTK.md reference, settings.json entry
 pub fn uninstall(global: bool, verbose: u8) -> Result<()> {
-    if !global {
-        anyhow::bail!("Uninstall only works with --global flag. For local projects, manually remove RTK from CLAUDE.md");
+    let scope = if global {
+        ClaudeDirScope::Global
+    } else {
+        ClaudeDirScope::Project
+    };
+    let claude_dir = resolve_claude_dir_for(scope)?;
+
+    if scope == ClaudeDirScope::Project && !claude_dir.exists() {
+        println!("RTK was not installed (nothing to remove)");
+        return Ok(());
     }
 
-    let claude_dir = resolve_claude_dir()?;
     let mut removed = Vec::new();
 
     // 1. Remove hook file
-    let hook_path = claude_dir.join("hooks").join("rtk-rewrite.sh");
+    let hook_path = claude_dir.join("hooks").join(HOOK_SCRIPT.file_name());
     if hook_path.exists() {
         fs::remove_file(&hook_path)
             .with_context(|| format!("Failed to remove hook: {}", hook_path.display()))?;
@@ -425,7 +1152,7 @@ pub fn uninstall(global: bool, verbose: u8) -> Result<()> {
     }
 
     // 3. Remove @RTK.md reference from CLAUDE.md
-    let claude_md_path = claude_dir.join("CLAUDE.md");
+    let claude_md_path = resolve_claude_md_path(scope)?;
     if claude_md_path.exists() {
         let content = fs::read_to_string(&claude_md_path)
             .with_context(|| format!("Failed to read CLAUDE.md: {}", claude_md_path.display()))?;
@@ -448,10 +1175,18 @@ pub fn uninstall(global: bool, verbose: u8) -> Result<()> {
     }
 
     // 4. Remove hook entry from settings.json
-    if remove_hook_from_settings(verbose)? {
+    if remove_hook_from_settings(verbose, scope)? {
         removed.push("settings.json: removed RTK hook entry".to_string());
     }
 
+    // 5. Remove rtk-managed git hooks, restoring any hand-written hook rtk
+    // preserved when it first installed over it
+    for name in ["pre-commit", "pre-push"] {
+        if let Some(message) = remove_git_hook(name, verbose)? {
+            removed.push(message);
+        }
+    }
+
     // Report results
     if removed.is_empty() {
         println!("RTK was not installed (nothing to remove)");
@@ -467,27 +1202,32 @@ pub fn uninstall(global: bool, verbose: u8) -> Result<()> {
 }
 
 /// Orchestrator: patch settings.json with RTK hook
-/// Handles reading, checking, prompting, merging, backing up, and atomic writing
-fn patch_settings_json(hook_path: &Path, mode: PatchMode, verbose: u8) -> Result<PatchResult> {
-    let claude_dir = resolve_claude_dir()?;
+/// Handles reading, checking, previewing, prompting, merging, backing up,
+/// and atomic writing.
+fn patch_settings_json(
+    hook_path: &Path,
+    mode: PatchMode,
+    dry_run: bool,
+    verbose: u8,
+    scope: ClaudeDirScope,
+) -> Result<PatchResult> {
+    let claude_dir = resolve_claude_dir_for(scope)?;
     let settings_path = claude_dir.join("settings.json");
-    let hook_command = hook_path
-        .to_str()
-        .context("Hook path contains invalid UTF-8")?;
+    let hook_command = hook_invocation(hook_path)?;
 
     // Read or create settings.json
-    let mut root = if settings_path.exists() {
-        let content = fs::read_to_string(&settings_path)
-            .with_context(|| format!("Failed to read {}", settings_path.display()))?;
-
-        if content.trim().is_empty() {
-            serde_json::json!({})
-        } else {
-            serde_json::from_str(&content)
-                .with_context(|| format!("Failed to parse {} as JSON", settings_path.display()))?
-        }
+    let original_raw = if settings_path.exists() {
+        fs::read_to_string(&settings_path)
+            .with_context(|| format!("Failed to read {}", settings_path.display()))?
     } else {
+        String::new()
+    };
+
+    let mut root = if original_raw.trim().is_empty() {
         serde_json::json!({})
+    } else {
+        serde_json::from_str(&original_raw)
+            .with_context(|| format!("Failed to parse {} as JSON", settings_path.display()))?
     };
 
     // Check idempotency
@@ -498,15 +1238,34 @@ fn patch_settings_json(hook_path: &Path, mode: PatchMode, verbose: u8) -> Result
         return Ok(PatchResult::AlreadyPresent);
     }
 
+    // Compute the prospective new content up front so it can be previewed
+    // before any prompt or write touches the file.
+    insert_hook_entry(&mut root, &hook_command);
+    let serialized =
+        serde_json::to_string_pretty(&root).context("Failed to serialize settings.json")?;
+
+    if let Some(diff) = render_unified_diff(
+        &original_raw,
+        &serialized,
+        &settings_path.display().to_string(),
+    ) {
+        eprintln!("{diff}");
+    }
+
+    if dry_run {
+        eprintln!("(dry run, not written: {})", settings_path.display());
+        return Ok(PatchResult::DryRun);
+    }
+
     // Handle mode
     match mode {
         PatchMode::Skip => {
-            print_manual_instructions(hook_path);
+            print_manual_instructions(hook_path)?;
             return Ok(PatchResult::Skipped);
         }
         PatchMode::Ask => {
             if !prompt_user_consent(&settings_path)? {
-                print_manual_instructions(hook_path);
+                print_manual_instructions(hook_path)?;
                 return Ok(PatchResult::Declined);
             }
         }
@@ -515,9 +1274,6 @@ fn patch_settings_json(hook_path: &Path, mode: PatchMode, verbose: u8) -> Result
         }
     }
 
-    // Deep-merge hook
-    insert_hook_entry(&mut root, &hook_command);
-
     // Backup original
     if settings_path.exists() {
         let backup_path = settings_path.with_extension("json.bak");
@@ -529,8 +1285,6 @@ fn patch_settings_json(hook_path: &Path, mode: PatchMode, verbose: u8) -> Result
     }
 
     // Atomic write
-    let serialized =
-        serde_json::to_string_pretty(&root).context("Failed to serialize settings.json")?;
     atomic_write(&settings_path, &serialized)?;
 
     println!("\n  settings.json: hook added");
@@ -614,8 +1368,16 @@ fn insert_hook_entry(root: &mut serde_json::Value, hook_command: &str) {
     }));
 }
 
+/// True if `cmd` invokes one of rtk's own rewrite-hook scripts (`.sh` or
+/// `.ps1`), as opposed to some unrelated hook whose path merely happens to
+/// contain "rtk-rewrite" as a substring.
+fn is_rtk_rewrite_command(cmd: &str) -> bool {
+    cmd.contains("rtk-rewrite.sh") || cmd.contains("rtk-rewrite.ps1")
+}
+
 /// Check if RTK hook is already present in settings.json
-/// Matches on rtk-rewrite.sh substring to handle different path formats
+/// Matches on the `rtk-rewrite.sh`/`rtk-rewrite.ps1` filenames to handle
+/// different path formats across platforms
 fn hook_already_present(root: &serde_json::Value, hook_command: &str) -> bool {
     let pre_tool_use_array = match root
         .get("hooks")
@@ -632,132 +1394,192 @@ fn hook_already_present(root: &serde_json::Value, hook_command: &str) -> bool {
         .flatten()
         .filter_map(|hook| hook.get("command")?.as_str())
         .any(|cmd| {
-            // Exact match OR both contain rtk-rewrite.sh
+            // Exact match OR both are rtk's own rewrite hook
             cmd == hook_command
-                || (cmd.contains("rtk-rewrite.sh") && hook_command.contains("rtk-rewrite.sh"))
+                || (is_rtk_rewrite_command(cmd) && is_rtk_rewrite_command(hook_command))
         })
 }
 
 /// Default mode: hook + slim RTK.md + @RTK.md reference
-#[cfg(not(unix))]
-fn run_default_mode(_global: bool, _patch_mode: PatchMode, _verbose: u8) -> Result<()> {
-    eprintln!("⚠️  Hook-based mode requires Unix (macOS/Linux).");
-    eprintln!("    Windows: use --claude-md mode for full injection.");
-    eprintln!("    Falling back to --claude-md mode.");
-    run_claude_md_mode(_global, _verbose)
-}
+fn run_default_mode(global: bool, patch_mode: PatchMode, dry_run: bool, verbose: u8) -> Result<()> {
+    let scope = if global {
+        ClaudeDirScope::Global
+    } else {
+        ClaudeDirScope::Project
+    };
+
+    let claude_dir = resolve_claude_dir_for(scope)?;
+    let rtk_md_path = claude_dir.join("RTK.md");
+    let claude_md_path = resolve_claude_md_path(scope)?;
+    let settings_path = claude_dir.join("settings.json");
 
-#[cfg(unix)]
-fn run_default_mode(global: bool, patch_mode: PatchMode, verbose: u8) -> Result<()> {
-    if !global {
-        // Local init: unchanged behavior (full injection into ./CLAUDE.md)
-        return run_claude_md_mode(false, verbose);
+    // 1. Prepare hook directory and snapshot every file this run will touch,
+    // so a failure partway through (step 5, say) rolls back steps 1-4
+    // instead of leaving a half-configured install behind.
+    let (_hook_dir, hook_path) = prepare_hook_paths(scope)?;
+
+    let mut txn = Transaction::new();
+    if !dry_run {
+        txn.snapshot(&hook_path)?;
+        txn.snapshot(&rtk_md_path)?;
+        txn.snapshot(&claude_md_path)?;
+        txn.snapshot(&settings_path)?;
     }
 
-    let claude_dir = resolve_claude_dir()?;
-    let rtk_md_path = claude_dir.join("RTK.md");
-    let claude_md_path = claude_dir.join("CLAUDE.md");
+    let result = (|| -> Result<()> {
+        ensure_hook_installed(&hook_path, patch_mode, dry_run, verbose)?;
+
+        // 2. Write RTK.md
+        write_if_changed(
+            &rtk_md_path,
+            RTK_SLIM,
+            "RTK.md",
+            patch_mode,
+            dry_run,
+            verbose,
+        )?;
+
+        // 3. Patch CLAUDE.md (add @RTK.md, migrate if needed)
+        let migrated = patch_claude_md(&claude_md_path, patch_mode, dry_run, verbose)?;
+
+        // 4. Print success message
+        let scope_label = if global { "global" } else { "project-local" };
+        println!("\nRTK hook installed ({scope_label}).\n");
+        println!("  Hook:      {}", hook_path.display());
+        println!("  RTK.md:    {} (10 lines)", rtk_md_path.display());
+        println!("  CLAUDE.md: @RTK.md reference added");
 
-    // 1. Prepare hook directory and install hook
-    let (_hook_dir, hook_path) = prepare_hook_paths()?;
-    ensure_hook_installed(&hook_path, verbose)?;
+        if migrated {
+            println!("\n  ✅ Migrated: removed 137-line RTK block from CLAUDE.md");
+            println!("              replaced with @RTK.md (10 lines)");
+        }
 
-    // 2. Write RTK.md
-    write_if_changed(&rtk_md_path, RTK_SLIM, "RTK.md", verbose)?;
+        // 5. Patch settings.json
+        let patch_result = patch_settings_json(&hook_path, patch_mode, dry_run, verbose, scope)?;
 
-    // 3. Patch CLAUDE.md (add @RTK.md, migrate if needed)
-    let migrated = patch_claude_md(&claude_md_path, verbose)?;
+        // Report result
+        match patch_result {
+            PatchResult::Patched => {
+                // Already printed by patch_settings_json
+            }
+            PatchResult::AlreadyPresent => {
+                println!("\n  settings.json: hook already present");
+                println!("  Restart Claude Code. Test with: git status");
+            }
+            PatchResult::Declined | PatchResult::Skipped | PatchResult::DryRun => {
+                // Manual instructions / dry-run notice already printed above
+            }
+        }
 
-    // 4. Print success message
-    println!("\nRTK hook installed (global).\n");
-    println!("  Hook:      {}", hook_path.display());
-    println!("  RTK.md:    {} (10 lines)", rtk_md_path.display());
-    println!("  CLAUDE.md: @RTK.md reference added");
+        if !global {
+            println!("  Commit .claude/ and CLAUDE.md so every contributor gets this setup.");
+        }
 
-    if migrated {
-        println!("\n  ✅ Migrated: removed 137-line RTK block from CLAUDE.md");
-        println!("              replaced with @RTK.md (10 lines)");
-    }
+        println!(); // Final newline
 
-    // 5. Patch settings.json
-    let patch_result = patch_settings_json(&hook_path, patch_mode, verbose)?;
+        Ok(())
+    })();
 
-    // Report result
-    match patch_result {
-        PatchResult::Patched => {
-            // Already printed by patch_settings_json
-        }
-        PatchResult::AlreadyPresent => {
-            println!("\n  settings.json: hook already present");
-            println!("  Restart Claude Code. Test with: git status");
-        }
-        PatchResult::Declined | PatchResult::Skipped => {
-            // Manual instructions already printed by patch_settings_json
+    if !dry_run {
+        match &result {
+            Ok(()) => txn.commit(),
+            Err(e) => {
+                eprintln!("\n❌ Install failed: {e}");
+                eprintln!("Rolling back to the previous state...");
+                txn.rollback();
+            }
         }
     }
 
-    println!(); // Final newline
-
-    Ok(())
+    result
 }
 
 /// Hook-only mode: just the hook, no RTK.md
-#[cfg(not(unix))]
-fn run_hook_only_mode(_global: bool, _patch_mode: PatchMode, _verbose: u8) -> Result<()> {
-    anyhow::bail!("Hook install requires Unix (macOS/Linux). Use WSL or --claude-md mode.")
-}
+fn run_hook_only_mode(
+    global: bool,
+    patch_mode: PatchMode,
+    dry_run: bool,
+    verbose: u8,
+) -> Result<()> {
+    let scope = if global {
+        ClaudeDirScope::Global
+    } else {
+        ClaudeDirScope::Project
+    };
 
-#[cfg(unix)]
-fn run_hook_only_mode(global: bool, patch_mode: PatchMode, verbose: u8) -> Result<()> {
-    if !global {
-        eprintln!("⚠️  Warning: --hook-only only makes sense with --global");
-        eprintln!("    For local projects, use default mode or --claude-md");
-        return Ok(());
+    // Prepare hook dir and snapshot both files this run touches, so a
+    // failed settings.json patch doesn't leave a freshly (re)written hook
+    // behind with nothing registered to invoke it.
+    let (_hook_dir, hook_path) = prepare_hook_paths(scope)?;
+    let settings_path = resolve_claude_dir_for(scope)?.join("settings.json");
+
+    let mut txn = Transaction::new();
+    if !dry_run {
+        txn.snapshot(&hook_path)?;
+        txn.snapshot(&settings_path)?;
     }
 
-    // Prepare and install hook
-    let (_hook_dir, hook_path) = prepare_hook_paths()?;
-    ensure_hook_installed(&hook_path, verbose)?;
+    let result = (|| -> Result<()> {
+        ensure_hook_installed(&hook_path, patch_mode, dry_run, verbose)?;
 
-    println!("\nRTK hook installed (hook-only mode).\n");
-    println!("  Hook: {}", hook_path.display());
-    println!(
-        "  Note: No RTK.md created. Claude won't know about meta commands (gain, discover, proxy)."
-    );
+        let scope_label = if global {
+            "hook-only mode"
+        } else {
+            "project-local hook-only mode"
+        };
+        println!("\nRTK hook installed ({scope_label}).\n");
+        println!("  Hook: {}", hook_path.display());
+        println!(
+            "  Note: No RTK.md created. Claude won't know about meta commands (gain, discover, proxy)."
+        );
 
-    // Patch settings.json
-    let patch_result = patch_settings_json(&hook_path, patch_mode, verbose)?;
+        // Patch settings.json
+        let patch_result = patch_settings_json(&hook_path, patch_mode, dry_run, verbose, scope)?;
 
-    // Report result
-    match patch_result {
-        PatchResult::Patched => {
-            // Already printed by patch_settings_json
-        }
-        PatchResult::AlreadyPresent => {
-            println!("\n  settings.json: hook already present");
-            println!("  Restart Claude Code. Test with: git status");
+        // Report result
+        match patch_result {
+            PatchResult::Patched => {
+                // Already printed by patch_settings_json
+            }
+            PatchResult::AlreadyPresent => {
+                println!("\n  settings.json: hook already present");
+                println!("  Restart Claude Code. Test with: git status");
+            }
+            PatchResult::Declined | PatchResult::Skipped | PatchResult::DryRun => {
+                // Manual instructions / dry-run notice already printed above
+            }
         }
-        PatchResult::Declined | PatchResult::Skipped => {
-            // Manual instructions already printed by patch_settings_json
+
+        println!(); // Final newline
+
+        Ok(())
+    })();
+
+    if !dry_run {
+        match &result {
+            Ok(()) => txn.commit(),
+            Err(e) => {
+                eprintln!("\n❌ Install failed: {e}");
+                eprintln!("Rolling back to the previous state...");
+                txn.rollback();
+            }
         }
     }
 
-    println!(); // Final newline
-
-    Ok(())
+    result
 }
 
 /// Legacy mode: full 137-line injection into CLAUDE.md
-fn run_claude_md_mode(global: bool, verbose: u8) -> Result<()> {
+fn run_claude_md_mode(global: bool, dry_run: bool, verbose: u8) -> Result<()> {
     let path = if global {
         resolve_claude_dir()?.join("CLAUDE.md")
     } else {
         PathBuf::from("CLAUDE.md")
     };
 
-    if global {
+    if global && !dry_run {
         if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
+            pathfs::create_dir_all(parent)?;
         }
     }
 
@@ -765,20 +1587,37 @@ fn run_claude_md_mode(global: bool, verbose: u8) -> Result<()> {
         eprintln!("Writing rtk instructions to: {}", path.display());
     }
 
-    if path.exists() {
-        let existing = fs::read_to_string(&path)?;
+    let original = if path.exists() {
+        pathfs::read_to_string(&path)?
+    } else {
+        String::new()
+    };
 
-        if existing.contains("<!-- rtk-instructions") {
-            println!("✅ {} already contains rtk instructions", path.display());
-            return Ok(());
-        }
+    if original.contains("<!-- rtk-instructions") {
+        println!("✅ {} already contains rtk instructions", path.display());
+        return Ok(());
+    }
 
-        let new_content = format!("{}\n\n{}", existing.trim(), RTK_INSTRUCTIONS);
-        fs::write(&path, new_content)?;
-        println!("✅ Added rtk instructions to existing {}", path.display());
+    let new_content = if original.is_empty() {
+        RTK_INSTRUCTIONS.to_string()
     } else {
-        fs::write(&path, RTK_INSTRUCTIONS)?;
+        format!("{}\n\n{}", original.trim(), RTK_INSTRUCTIONS)
+    };
+
+    if let Some(diff) = render_unified_diff(&original, &new_content, &path.display().to_string()) {
+        eprintln!("{diff}");
+    }
+
+    if dry_run {
+        eprintln!("(dry run, not written: {})", path.display());
+        return Ok(());
+    }
+
+    pathfs::write(&path, &new_content)?;
+    if original.is_empty() {
         println!("✅ Created {} with rtk instructions", path.display());
+    } else {
+        println!("✅ Added rtk instructions to existing {}", path.display());
     }
 
     if global {
@@ -791,13 +1630,14 @@ fn run_claude_md_mode(global: bool, verbose: u8) -> Result<()> {
 }
 
 /// Patch CLAUDE.md: add @RTK.md, migrate if old block exists
-fn patch_claude_md(path: &Path, verbose: u8) -> Result<bool> {
-    let mut content = if path.exists() {
-        fs::read_to_string(path)?
+fn patch_claude_md(path: &Path, mode: PatchMode, dry_run: bool, verbose: u8) -> Result<bool> {
+    let original = if path.exists() {
+        pathfs::read_to_string(path)?
     } else {
         String::new()
     };
 
+    let mut content = original.clone();
     let mut migrated = false;
 
     // Check for old block and migrate
@@ -812,28 +1652,45 @@ fn patch_claude_md(path: &Path, verbose: u8) -> Result<bool> {
         }
     }
 
-    // Check if @RTK.md already present
-    if content.contains("@RTK.md") {
-        if verbose > 0 {
+    let already_present = content.contains("@RTK.md");
+    let final_content = if already_present {
+        content
+    } else if content.is_empty() {
+        "@RTK.md\n".to_string()
+    } else {
+        format!("{}\n\n@RTK.md\n", content.trim())
+    };
+
+    if final_content == original {
+        if verbose > 0 && already_present {
             eprintln!("@RTK.md reference already present in CLAUDE.md");
         }
-        if migrated {
-            fs::write(path, content)?;
-        }
         return Ok(migrated);
     }
 
-    // Add @RTK.md
-    let new_content = if content.is_empty() {
-        "@RTK.md\n".to_string()
-    } else {
-        format!("{}\n\n@RTK.md\n", content.trim())
-    };
+    if let Some(diff) = render_unified_diff(&original, &final_content, &path.display().to_string())
+    {
+        eprintln!("{diff}");
+    }
 
-    fs::write(path, new_content)?;
+    if dry_run {
+        eprintln!("(dry run, not written: {})", path.display());
+        return Ok(migrated);
+    }
+
+    if mode == PatchMode::Ask && !confirm(&format!("\nWrite {}?", path.display()))? {
+        eprintln!("Skipped (declined): {}", path.display());
+        return Ok(migrated);
+    }
+
+    pathfs::write(path, &final_content)?;
 
     if verbose > 0 {
-        eprintln!("Added @RTK.md reference to CLAUDE.md");
+        if already_present {
+            eprintln!("Migrated CLAUDE.md (RTK block removed)");
+        } else {
+            eprintln!("Added @RTK.md reference to CLAUDE.md");
+        }
     }
 
     Ok(migrated)
@@ -879,15 +1736,58 @@ fn remove_rtk_block(content: &str) -> (String, bool) {
 
 /// Resolve ~/.claude directory with proper home expansion
 fn resolve_claude_dir() -> Result<PathBuf> {
-    dirs::home_dir()
-        .map(|h| h.join(".claude"))
-        .context("Cannot determine home directory. Is $HOME set?")
+    resolve_claude_dir_for(ClaudeDirScope::Global)
+}
+
+/// Resolve the `.claude` directory for a given scope: `~/.claude` for
+/// [`ClaudeDirScope::Global`], `./.claude` (relative to the current
+/// directory) for [`ClaudeDirScope::Project`] so a repo can commit a
+/// reproducible, per-project RTK setup instead of relying on each
+/// developer's home directory.
+fn resolve_claude_dir_for(scope: ClaudeDirScope) -> Result<PathBuf> {
+    match scope {
+        ClaudeDirScope::Global => dirs::home_dir()
+            .map(|h| h.join(".claude"))
+            .context("Cannot determine home directory. Is $HOME set?"),
+        ClaudeDirScope::Project => Ok(PathBuf::from(".claude")),
+    }
+}
+
+/// Resolve CLAUDE.md for a scope. Global CLAUDE.md lives inside `~/.claude`
+/// alongside the rest of the global config; project CLAUDE.md is the
+/// repo-root file Claude Code already reads per-project, not `.claude/CLAUDE.md`.
+fn resolve_claude_md_path(scope: ClaudeDirScope) -> Result<PathBuf> {
+    match scope {
+        ClaudeDirScope::Global => Ok(resolve_claude_dir_for(scope)?.join("CLAUDE.md")),
+        ClaudeDirScope::Project => Ok(PathBuf::from("CLAUDE.md")),
+    }
+}
+
+/// Print the installed-vs-current `rtk-hook-version` for an artifact, if
+/// either side carries a stamp. Shared by the hook and RTK.md checks in
+/// [`show_config`].
+fn print_version_line(installed_content: &str, embedded_content: &str) {
+    let installed_version = version_stamp(installed_content);
+    let embedded_version = version_stamp(embedded_content);
+
+    match (installed_version, embedded_version) {
+        (Some(installed), Some(embedded)) if installed == embedded => {
+            println!("   version: v{installed} (current)");
+        }
+        (Some(installed), Some(embedded)) => {
+            println!("   version: v{installed} (current: v{embedded} - run: rtk init -g)");
+        }
+        (None, Some(embedded)) => {
+            println!("   version: unstamped (current: v{embedded} - run: rtk init -g)");
+        }
+        (_, None) => {}
+    }
 }
 
 /// Show current rtk configuration
 pub fn show_config() -> Result<()> {
     let claude_dir = resolve_claude_dir()?;
-    let hook_path = claude_dir.join("hooks").join("rtk-rewrite.sh");
+    let hook_path = claude_dir.join("hooks").join(HOOK_SCRIPT.file_name());
     let rtk_md_path = claude_dir.join("RTK.md");
     let global_claude_md = claude_dir.join("CLAUDE.md");
     let local_claude_md = PathBuf::from("CLAUDE.md");
@@ -899,11 +1799,11 @@ pub fn show_config() -> Result<()> {
         #[cfg(unix)]
         {
             use std::os::unix::fs::PermissionsExt;
-            let metadata = fs::metadata(&hook_path)?;
+            let metadata = pathfs::metadata(&hook_path)?;
             let perms = metadata.permissions();
             let is_executable = perms.mode() & 0o111 != 0;
 
-            let hook_content = fs::read_to_string(&hook_path)?;
+            let hook_content = pathfs::read_to_string(&hook_path)?;
             let has_guards =
                 hook_content.contains("command -v rtk") && hook_content.contains("command -v jq");
 
@@ -921,8 +1821,17 @@ pub fn show_config() -> Result<()> {
 
         #[cfg(not(unix))]
         {
-            println!("✅ Hook: {} (exists)", hook_path.display());
+            let hook_content = pathfs::read_to_string(&hook_path)?;
+            let has_guards =
+                hook_content.contains("Get-Command rtk") && hook_content.contains("ConvertFrom-Json");
+
+            if has_guards {
+                println!("✅ Hook: {} (with guards)", hook_path.display());
+            } else {
+                println!("⚠️  Hook: {} (no guards - outdated)", hook_path.display());
+            }
         }
+        print_version_line(&pathfs::read_to_string(&hook_path)?, HOOK_SCRIPT.contents());
     } else {
         println!("⚪ Hook: not found");
     }
@@ -930,13 +1839,14 @@ pub fn show_config() -> Result<()> {
     // Check RTK.md
     if rtk_md_path.exists() {
         println!("✅ RTK.md: {} (slim mode)", rtk_md_path.display());
+        print_version_line(&pathfs::read_to_string(&rtk_md_path)?, RTK_SLIM);
     } else {
         println!("⚪ RTK.md: not found");
     }
 
     // Check global CLAUDE.md
     if global_claude_md.exists() {
-        let content = fs::read_to_string(&global_claude_md)?;
+        let content = pathfs::read_to_string(&global_claude_md)?;
         if content.contains("@RTK.md") {
             println!("✅ Global (~/.claude/CLAUDE.md): @RTK.md reference");
         } else if content.contains("<!-- rtk-instructions") {
@@ -952,7 +1862,7 @@ pub fn show_config() -> Result<()> {
 
     // Check local CLAUDE.md
     if local_claude_md.exists() {
-        let content = fs::read_to_string(&local_claude_md)?;
+        let content = pathfs::read_to_string(&local_claude_md)?;
         if content.contains("rtk") {
             println!("✅ Local (./CLAUDE.md): rtk enabled");
         } else {
@@ -965,7 +1875,7 @@ pub fn show_config() -> Result<()> {
     // Check settings.json
     let settings_path = claude_dir.join("settings.json");
     if settings_path.exists() {
-        let content = fs::read_to_string(&settings_path)?;
+        let content = pathfs::read_to_string(&settings_path)?;
         if !content.trim().is_empty() {
             if let Ok(root) = serde_json::from_str::<serde_json::Value>(&content) {
                 let hook_command = hook_path.display().to_string();
@@ -985,6 +1895,38 @@ pub fn show_config() -> Result<()> {
         println!("⚪ settings.json: not found");
     }
 
+    // Check for leftover .rtk.bak snapshots from an interrupted or rolled-back install
+    let stale_backups: Vec<PathBuf> = [&hook_path, &rtk_md_path, &global_claude_md, &settings_path]
+        .into_iter()
+        .map(|path| backup_path_for(path))
+        .filter(|path| path.exists())
+        .collect();
+    if !stale_backups.is_empty() {
+        println!("\n⚠️  Found {} leftover .rtk.bak snapshot(s):", stale_backups.len());
+        for path in &stale_backups {
+            println!("    {}", path.display());
+        }
+        println!("    Run: rtk init --restore  (to roll back)  or remove them manually");
+    }
+
+    // Check git hooks
+    if let Some(git_dir) = resolve_git_dir()? {
+        let hooks_dir = git_dir.join("hooks");
+        for name in ["pre-commit", "pre-push"] {
+            let path = hooks_dir.join(name);
+            if path.exists() {
+                let content = pathfs::read_to_string(&path)?;
+                if content.contains(RTK_HOOK_MARKER) {
+                    println!("✅ Git hook ({name}): rtk-managed");
+                } else {
+                    println!("⚪ Git hook ({name}): exists but not rtk-managed");
+                }
+            } else {
+                println!("⚪ Git hook ({name}): not found");
+            }
+        }
+    }
+
     println!("\nUsage:");
     println!("  rtk init              # Full injection into local CLAUDE.md");
     println!("  rtk init -g           # Hook + RTK.md + @RTK.md + settings.json (recommended)");
@@ -993,10 +1935,273 @@ pub fn show_config() -> Result<()> {
     println!("  rtk init -g --uninstall     # Remove all RTK artifacts");
     println!("  rtk init -g --claude-md     # Legacy: full injection into ~/.claude/CLAUDE.md");
     println!("  rtk init -g --hook-only     # Hook only, no RTK.md");
+    println!("  rtk init --git-hooks        # Install a pre-commit hook in .git/hooks");
+    println!("  rtk init --git-hooks --pre-push  # Also install a pre-push hook");
 
     Ok(())
 }
 
+/// All `command` strings in settings.json's `hooks.PreToolUse[].hooks[]`
+/// that look like rtk's own rewrite hook (see [`is_rtk_rewrite_command`]),
+/// in document order. Used by [`doctor`] to spot duplicate registrations
+/// and dangling paths, which a plain present/absent check like
+/// [`hook_already_present`] can't surface.
+fn rtk_hook_commands(root: &serde_json::Value) -> Vec<&str> {
+    let pre_tool_use_array = match root
+        .get("hooks")
+        .and_then(|h| h.get("PreToolUse"))
+        .and_then(|p| p.as_array())
+    {
+        Some(arr) => arr,
+        None => return Vec::new(),
+    };
+
+    pre_tool_use_array
+        .iter()
+        .filter_map(|entry| entry.get("hooks")?.as_array())
+        .flatten()
+        .filter_map(|hook| hook.get("command")?.as_str())
+        .filter(|cmd| is_rtk_rewrite_command(cmd))
+        .collect()
+}
+
+/// Diagnostic health-check for an existing `rtk init -g` installation.
+///
+/// Unlike [`show_config`] (a status dump) this is read-only and actionable:
+/// it checks the hook script for drift against the embedded copy, the
+/// executable bit, settings.json for duplicate or dangling hook entries,
+/// and that RTK.md / the `@RTK.md` reference are in place - printing one
+/// line per problem found and exiting non-zero so it can gate CI or a
+/// pre-push hook, mirroring the install/tidy verification pattern in
+/// rust-analyzer's xtask.
+pub fn doctor(verbose: u8) -> Result<()> {
+    let claude_dir = resolve_claude_dir()?;
+    let hook_path = claude_dir.join("hooks").join(HOOK_SCRIPT.file_name());
+    let rtk_md_path = claude_dir.join("RTK.md");
+    let claude_md_path = claude_dir.join("CLAUDE.md");
+    let settings_path = claude_dir.join("settings.json");
+
+    let mut problems: Vec<String> = Vec::new();
+
+    // 1. Hook script: drift against the embedded copy, executable bit.
+    if hook_path.exists() {
+        let installed = fs::read_to_string(&hook_path)
+            .with_context(|| format!("Failed to read {}", hook_path.display()))?;
+        if installed != HOOK_SCRIPT.contents() {
+            problems.push(format!(
+                "Hook script is out of date: {} (run: rtk init -g)",
+                hook_path.display()
+            ));
+        }
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let metadata = pathfs::metadata(&hook_path)?;
+            if metadata.permissions().mode() & 0o111 == 0 {
+                problems.push(format!(
+                    "Hook script is not executable: {} (run: chmod +x)",
+                    hook_path.display()
+                ));
+            }
+        }
+    } else {
+        problems.push(format!("Hook script not found: {}", hook_path.display()));
+    }
+
+    // 2. settings.json: valid JSON, exactly one RTK entry, and that entry's
+    // command actually points at a file that exists.
+    if settings_path.exists() {
+        let content = fs::read_to_string(&settings_path)
+            .with_context(|| format!("Failed to read {}", settings_path.display()))?;
+
+        if content.trim().is_empty() {
+            problems.push(format!("settings.json is empty: {}", settings_path.display()));
+        } else {
+            match serde_json::from_str::<serde_json::Value>(&content) {
+                Ok(root) => {
+                    let commands = rtk_hook_commands(&root);
+                    if commands.is_empty() {
+                        problems.push(format!(
+                            "settings.json has no RTK hook entry: {} (run: rtk init -g --auto-patch)",
+                            settings_path.display()
+                        ));
+                    } else if commands.len() > 1 {
+                        problems.push(format!(
+                            "settings.json has {} duplicate RTK hook entries (expected 1): {}",
+                            commands.len(),
+                            settings_path.display()
+                        ));
+                    }
+
+                    for command in commands {
+                        if !Path::new(command).exists() {
+                            problems.push(format!(
+                                "settings.json RTK hook command points to a missing file: {command}"
+                            ));
+                        }
+                    }
+                }
+                Err(e) => {
+                    problems.push(format!(
+                        "settings.json is not valid JSON: {} ({e})",
+                        settings_path.display()
+                    ));
+                }
+            }
+        }
+    } else {
+        problems.push(format!("settings.json not found: {}", settings_path.display()));
+    }
+
+    // 3. RTK.md and the @RTK.md reference in CLAUDE.md.
+    if rtk_md_path.exists() {
+        if verbose > 0 {
+            eprintln!("RTK.md: {}", rtk_md_path.display());
+        }
+    } else {
+        problems.push(format!("RTK.md not found: {}", rtk_md_path.display()));
+    }
+
+    if claude_md_path.exists() {
+        let content = fs::read_to_string(&claude_md_path)
+            .with_context(|| format!("Failed to read {}", claude_md_path.display()))?;
+        if !content.contains("@RTK.md") {
+            problems.push(format!(
+                "CLAUDE.md does not reference @RTK.md: {}",
+                claude_md_path.display()
+            ));
+        }
+    } else {
+        problems.push(format!("CLAUDE.md not found: {}", claude_md_path.display()));
+    }
+
+    if problems.is_empty() {
+        println!("✅ rtk install looks healthy");
+        return Ok(());
+    }
+
+    println!("❌ rtk doctor found {} problem(s):\n", problems.len());
+    for problem in &problems {
+        println!("  - {problem}");
+    }
+    println!("\nRun `rtk init -g --auto-patch` to repair most of these.");
+
+    std::process::exit(1);
+}
+
+/// Whether an on-disk artifact matches its embedded source of truth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArtifactStatus {
+    UpToDate,
+    Stale,
+    Missing,
+}
+
+impl ArtifactStatus {
+    fn icon(self) -> &'static str {
+        match self {
+            ArtifactStatus::UpToDate => "✅",
+            ArtifactStatus::Stale => "⚠️ ",
+            ArtifactStatus::Missing => "⚪",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            ArtifactStatus::UpToDate => "up-to-date",
+            ArtifactStatus::Stale => "stale",
+            ArtifactStatus::Missing => "missing",
+        }
+    }
+}
+
+fn status_of(path: &Path, expected: &str) -> Result<ArtifactStatus> {
+    if !path.exists() {
+        return Ok(ArtifactStatus::Missing);
+    }
+    let actual = fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    Ok(if actual == expected {
+        ArtifactStatus::UpToDate
+    } else {
+        ArtifactStatus::Stale
+    })
+}
+
+/// `rtk init --verify`: generate-then-diff drift check in the style of
+/// rust-analyzer's codegen `--verify` (regenerate the expected artifact,
+/// diff it against what's on disk, fail if they differ) rather than
+/// [`doctor`]'s broader structural health check. Compares the installed
+/// hook, RTK.md, and the settings.json hook entry byte-for-byte against
+/// the current embedded constants, reports each as up-to-date/stale/missing,
+/// and exits non-zero if anything is stale or missing so it can gate CI or
+/// a pre-commit step. With `fix`, re-syncs only the drifted artifacts
+/// through the existing `write_if_changed`/`patch_claude_md`/
+/// `patch_settings_json` machinery instead of hand-rolling a second writer.
+pub fn verify(fix: bool, patch_mode: PatchMode, verbose: u8) -> Result<()> {
+    let claude_dir = resolve_claude_dir()?;
+    let hook_path = claude_dir.join("hooks").join(HOOK_SCRIPT.file_name());
+    let rtk_md_path = claude_dir.join("RTK.md");
+    let claude_md_path = claude_dir.join("CLAUDE.md");
+    let settings_path = claude_dir.join("settings.json");
+
+    let hook_status = status_of(&hook_path, HOOK_SCRIPT.contents())?;
+    let rtk_md_status = status_of(&rtk_md_path, RTK_SLIM)?;
+
+    let claude_md_status = if claude_md_path.exists() {
+        let content = fs::read_to_string(&claude_md_path)
+            .with_context(|| format!("Failed to read {}", claude_md_path.display()))?;
+        if content.contains("@RTK.md") {
+            ArtifactStatus::UpToDate
+        } else {
+            ArtifactStatus::Stale
+        }
+    } else {
+        ArtifactStatus::Missing
+    };
+
+    let settings_status = if settings_path.exists() {
+        let content = fs::read_to_string(&settings_path)
+            .with_context(|| format!("Failed to read {}", settings_path.display()))?;
+        let hook_command = hook_invocation(&hook_path)?;
+        match serde_json::from_str::<serde_json::Value>(&content) {
+            Ok(root) if hook_already_present(&root, &hook_command) => ArtifactStatus::UpToDate,
+            Ok(_) => ArtifactStatus::Stale,
+            Err(_) => ArtifactStatus::Stale,
+        }
+    } else {
+        ArtifactStatus::Missing
+    };
+
+    println!("📋 rtk init --verify:\n");
+    println!("{} Hook:          {}", hook_status.icon(), hook_status.label());
+    println!("{} RTK.md:        {}", rtk_md_status.icon(), rtk_md_status.label());
+    println!("{} CLAUDE.md:     {}", claude_md_status.icon(), claude_md_status.label());
+    println!("{} settings.json: {}", settings_status.icon(), settings_status.label());
+
+    let drifted = [hook_status, rtk_md_status, claude_md_status, settings_status]
+        .iter()
+        .any(|s| *s != ArtifactStatus::UpToDate);
+
+    if !drifted {
+        println!("\n✅ All artifacts up-to-date");
+        return Ok(());
+    }
+
+    if fix {
+        println!("\nFixing drifted artifacts...");
+        ensure_hook_installed(&hook_path, patch_mode, false, verbose)?;
+        write_if_changed(&rtk_md_path, RTK_SLIM, "RTK.md", patch_mode, false, verbose)?;
+        patch_claude_md(&claude_md_path, patch_mode, false, verbose)?;
+        patch_settings_json(&hook_path, patch_mode, false, verbose, ClaudeDirScope::Global)?;
+        println!("Done. Re-run `rtk init --verify` to confirm.");
+        return Ok(());
+    }
+
+    println!("\n❌ Drift detected. Re-run with --fix, or: rtk init -g --auto-patch");
+    std::process::exit(1);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1366,4 +2571,89 @@ More content"#;
         let removed = remove_hook_from_json(&mut json_content);
         assert!(!removed);
     }
+
+    // Tests for version-stamp parsing (ensure_hook_installed's upgrade check)
+    #[test]
+    fn test_version_stamp_parses_shell_and_html_comments() {
+        assert_eq!(
+            version_stamp("#!/bin/sh\n# rtk-hook-version: 1.2.3\necho hi"),
+            Some("1.2.3")
+        );
+        assert_eq!(
+            version_stamp("<!-- rtk-hook-version: 2.0.0 -->\n# RTK.md"),
+            Some("2.0.0")
+        );
+        assert_eq!(version_stamp("no stamp here"), None);
+    }
+
+    #[test]
+    fn test_parse_semver_compares_fields() {
+        assert!(parse_semver("1.2.3").unwrap() < parse_semver("1.2.4").unwrap());
+        assert!(parse_semver("1.2.3").unwrap() < parse_semver("2.0.0").unwrap());
+        assert_eq!(parse_semver("1.2.3"), parse_semver("1.2.3"));
+        assert_eq!(parse_semver("not-a-version"), None);
+    }
+
+    // Tests for the diff-preview machinery (diff_lines/build_hunks/render_unified_diff)
+    #[test]
+    fn test_diff_lines_detects_insert_delete_replace() {
+        let old = vec!["a", "b", "c"];
+        let new = vec!["a", "x", "c"];
+        let ops = diff_lines(&old, &new);
+        assert_eq!(
+            ops,
+            vec![
+                DiffOp::Equal("a".to_string()),
+                DiffOp::Delete("b".to_string()),
+                DiffOp::Insert("x".to_string()),
+                DiffOp::Equal("c".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_lines_identical_is_all_equal() {
+        let lines = vec!["a", "b", "c"];
+        let ops = diff_lines(&lines, &lines);
+        assert!(ops.iter().all(|op| matches!(op, DiffOp::Equal(_))));
+    }
+
+    #[test]
+    fn test_build_hunks_drops_distant_context() {
+        // 20 unchanged lines, then one insert, then 20 more unchanged lines.
+        let mut old: Vec<&str> = (0..20).map(|_| "same").collect();
+        let mut new = old.clone();
+        old.push("same");
+        new.push("new line");
+        new.push("same");
+        old.extend((0..20).map(|_| "same"));
+        new.extend((0..20).map(|_| "same"));
+
+        let hunks = build_hunks(&diff_lines(&old, &new), DIFF_CONTEXT);
+        assert_eq!(hunks.len(), 1, "change is isolated into a single hunk");
+        // Only DIFF_CONTEXT lines of context on each side of the insert, plus the insert itself.
+        assert_eq!(hunks[0].lines.len(), DIFF_CONTEXT * 2 + 1);
+    }
+
+    #[test]
+    fn test_render_unified_diff_none_when_identical() {
+        assert!(render_unified_diff("same\n", "same\n", "file.txt").is_none());
+    }
+
+    #[test]
+    fn test_render_unified_diff_shows_hunk_header_and_markers() {
+        let diff = render_unified_diff("a\nb\nc\n", "a\nx\nc\n", "CLAUDE.md").unwrap();
+        assert!(diff.contains("--- CLAUDE.md"));
+        assert!(diff.contains("+++ CLAUDE.md"));
+        assert!(diff.contains("@@ -1,3 +1,3 @@"));
+        assert!(diff.contains("-b"));
+        assert!(diff.contains("+x"));
+    }
+
+    #[test]
+    fn test_render_unified_diff_new_file_uses_dev_null() {
+        let diff = render_unified_diff("", "new content\n", "RTK.md").unwrap();
+        assert!(diff.contains("--- /dev/null"));
+        assert!(diff.contains("+new content"));
+    }
 }
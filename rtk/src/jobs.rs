@@ -0,0 +1,191 @@
+//! Bounded-concurrency batch runner for external tool invocations.
+//!
+//! The crate otherwise runs external tools one at a time through
+//! [`crate::utils::execute_command`] / [`crate::utils::package_manager_exec`].
+//! `run_jobs` lets a single `rtk` invocation lint, typecheck, and test in
+//! parallel (a shell job table, in effect) instead of serializing several
+//! multi-second tool calls, while still returning results in the caller's
+//! original order regardless of completion order. [`crate::ci_cmd`] is the
+//! command that wires this up (`rtk ci --lint ... --typecheck ... --test ...`).
+
+use crate::utils::{CommandRunner, RunOutcome};
+use anyhow::Result;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// One external-command invocation to run as part of a [`run_jobs`] batch.
+/// Carries the same fields as [`CommandRunner`], plus a caller-supplied
+/// `label` used to key the matching [`JobResult`].
+pub struct Job {
+    label: String,
+    cmd: String,
+    args: Vec<String>,
+    cwd: Option<PathBuf>,
+    stdin_bytes: Option<Vec<u8>>,
+    timeout: Option<Duration>,
+}
+
+impl Job {
+    pub fn new(label: impl Into<String>, cmd: impl Into<String>) -> Self {
+        Job {
+            label: label.into(),
+            cmd: cmd.into(),
+            args: Vec::new(),
+            cwd: None,
+            stdin_bytes: None,
+            timeout: None,
+        }
+    }
+
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args = args.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn cwd(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cwd = Some(dir.into());
+        self
+    }
+
+    pub fn stdin_bytes(mut self, bytes: Vec<u8>) -> Self {
+        self.stdin_bytes = Some(bytes);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    fn into_runner(self) -> CommandRunner {
+        let mut runner = CommandRunner::new(self.cmd).args(self.args);
+        if let Some(dir) = self.cwd {
+            runner = runner.cwd(dir);
+        }
+        if let Some(bytes) = self.stdin_bytes {
+            runner = runner.stdin_bytes(bytes);
+        }
+        if let Some(timeout) = self.timeout {
+            runner = runner.timeout(timeout);
+        }
+        runner
+    }
+}
+
+/// Outcome of one [`Job`], keyed by its `label`.
+pub struct JobResult {
+    pub label: String,
+    pub duration: Duration,
+    pub outcome: Result<RunOutcome>,
+}
+
+/// Run `jobs` with at most `max_parallel` running concurrently, returning one
+/// [`JobResult`] per job in the same order `jobs` was given (not completion
+/// order). `max_parallel` of 0 is treated as 1.
+pub fn run_jobs(jobs: Vec<Job>, max_parallel: usize) -> Vec<JobResult> {
+    let total = jobs.len();
+    if total == 0 {
+        return Vec::new();
+    }
+    let worker_count = max_parallel.max(1).min(total);
+
+    let queue: Arc<Mutex<VecDeque<(usize, Job)>>> =
+        Arc::new(Mutex::new(jobs.into_iter().enumerate().collect()));
+    let results: Arc<Mutex<Vec<Option<JobResult>>>> =
+        Arc::new(Mutex::new((0..total).map(|_| None).collect()));
+
+    let handles: Vec<_> = (0..worker_count)
+        .map(|_| {
+            let queue = Arc::clone(&queue);
+            let results = Arc::clone(&results);
+            std::thread::spawn(move || loop {
+                let Some((index, job)) = queue.lock().unwrap().pop_front() else {
+                    break;
+                };
+                let label = job.label.clone();
+                let started = Instant::now();
+                let outcome = job.into_runner().run();
+                let duration = started.elapsed();
+                results.lock().unwrap()[index] = Some(JobResult {
+                    label,
+                    duration,
+                    outcome,
+                });
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        let _ = handle.join();
+    }
+
+    Arc::try_unwrap(results)
+        .unwrap_or_else(|_| panic!("all worker threads joined, so only one Arc handle remains"))
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|r| r.expect("every queued job index is filled before join() returns"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_jobs_preserves_order_regardless_of_completion_order() {
+        // The first job sleeps longer than the second, so with worker_count
+        // == 2 they run concurrently and the second finishes first -- the
+        // result order should still match the input order, not completion
+        // order.
+        let jobs = vec![
+            Job::new("slow", "sh").args(["-c", "sleep 0.2 && echo first"]),
+            Job::new("fast", "sh").args(["-c", "echo second"]),
+        ];
+
+        let results = run_jobs(jobs, 2);
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].label, "slow");
+        assert_eq!(results[1].label, "fast");
+        match &results[0].outcome {
+            Ok(RunOutcome::Completed { stdout, .. }) => assert_eq!(stdout.trim(), "first"),
+            other => panic!("expected completed outcome, got {:?}", other),
+        }
+        match &results[1].outcome {
+            Ok(RunOutcome::Completed { stdout, .. }) => assert_eq!(stdout.trim(), "second"),
+            other => panic!("expected completed outcome, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_run_jobs_reports_exit_codes_and_caps_worker_count() {
+        let jobs = vec![
+            Job::new("ok", "sh").args(["-c", "exit 0"]),
+            Job::new("fail", "sh").args(["-c", "exit 7"]),
+        ];
+
+        // max_parallel exceeds the job count; run_jobs should cap the
+        // worker pool at jobs.len() rather than spawning idle threads.
+        let results = run_jobs(jobs, 10);
+
+        assert_eq!(results.len(), 2);
+        let exit_code = |r: &JobResult| match &r.outcome {
+            Ok(RunOutcome::Completed { exit_code, .. }) => *exit_code,
+            other => panic!("expected completed outcome, got {:?}", other),
+        };
+        assert_eq!(exit_code(&results[0]), 0);
+        assert_eq!(exit_code(&results[1]), 7);
+    }
+
+    #[test]
+    fn test_run_jobs_empty_input_returns_empty() {
+        assert!(run_jobs(Vec::new(), 4).is_empty());
+    }
+}
@@ -1,12 +1,13 @@
 use crate::tracking;
 use anyhow::{Context, Result};
-use serde_json::Value;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
 use std::fs;
 use std::io::{self, Read};
 use std::path::Path;
 
 /// Show JSON structure without values
-pub fn run(file: &Path, max_depth: usize, verbose: u8) -> Result<()> {
+pub fn run(file: &Path, max_depth: usize, schema_format: &str, verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
     if verbose > 0 {
@@ -16,7 +17,7 @@ pub fn run(file: &Path, max_depth: usize, verbose: u8) -> Result<()> {
     let content = fs::read_to_string(file)
         .with_context(|| format!("Failed to read file: {}", file.display()))?;
 
-    let schema = filter_json_string(&content, max_depth)?;
+    let schema = filter_json_string(&content, max_depth, schema_format)?;
     println!("{}", schema);
     timer.track(
         &format!("cat {}", file.display()),
@@ -28,7 +29,7 @@ pub fn run(file: &Path, max_depth: usize, verbose: u8) -> Result<()> {
 }
 
 /// Show JSON structure from stdin
-pub fn run_stdin(max_depth: usize, verbose: u8) -> Result<()> {
+pub fn run_stdin(max_depth: usize, schema_format: &str, verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
     if verbose > 0 {
@@ -41,7 +42,7 @@ pub fn run_stdin(max_depth: usize, verbose: u8) -> Result<()> {
         .read_to_string(&mut content)
         .context("Failed to read from stdin")?;
 
-    let schema = filter_json_string(&content, max_depth)?;
+    let schema = filter_json_string(&content, max_depth, schema_format)?;
     println!("{}", schema);
     timer.track("cat - (stdin)", "rtk json -", &content, &schema);
     Ok(())
@@ -49,100 +50,351 @@ pub fn run_stdin(max_depth: usize, verbose: u8) -> Result<()> {
 
 /// Parse a JSON string and return its schema representation.
 /// Useful for piping JSON from other commands (e.g., `gh api`, `curl`).
-pub fn filter_json_string(json_str: &str, max_depth: usize) -> Result<String> {
+///
+/// `schema_format` selects the render: `"json"` emits a JSON Schema
+/// draft-07 document, anything else (including `"text"`) emits the
+/// compact human-readable view.
+pub fn filter_json_string(json_str: &str, max_depth: usize, schema_format: &str) -> Result<String> {
     let value: Value = serde_json::from_str(json_str).context("Failed to parse JSON")?;
-    Ok(extract_schema(&value, 0, max_depth))
+    let shape = shape_of(&value, 0, max_depth);
+
+    Ok(match schema_format {
+        "json" => serde_json::to_string_pretty(&to_json_schema(&shape))
+            .context("Failed to render JSON Schema")?,
+        _ => render_compact(&shape, 0),
+    })
 }
 
-fn extract_schema(value: &Value, depth: usize, max_depth: usize) -> String {
-    let indent = "  ".repeat(depth);
+/// The set of scalar JSON types observed at one position, merged across
+/// every element/instance that landed there.
+#[derive(Debug, Clone, Default)]
+struct ScalarSet {
+    null: bool,
+    bool_: bool,
+    int: bool,
+    float: bool,
+    string: bool,
+}
 
+/// One object field: its merged shape, and how many of the merged
+/// instances actually had this key (vs. `ObjectSchema::count`, the total
+/// number of instances merged) - the difference is what makes a field
+/// optional.
+#[derive(Debug, Clone)]
+struct FieldSchema {
+    shape: SchemaShape,
+    present: usize,
+}
+
+/// An object's inferred shape: the union of every key seen across all
+/// merged instances, plus how many instances were merged in total.
+#[derive(Debug, Clone, Default)]
+struct ObjectSchema {
+    fields: BTreeMap<String, FieldSchema>,
+    count: usize,
+}
+
+/// A schema shape inferred from one or more JSON values occupying the
+/// same position, e.g. every element of an array or every instance of an
+/// object field across a collection.
+#[derive(Debug, Clone)]
+enum SchemaShape {
+    Scalar(ScalarSet),
+    Array {
+        elem: Option<Box<SchemaShape>>,
+        len: usize,
+    },
+    Object(ObjectSchema),
+    /// Past `max_depth`, or nothing observed - "could be anything".
+    Any,
+    /// Conflicting non-scalar shapes that can't be merged further, e.g.
+    /// an array whose elements are sometimes objects and sometimes
+    /// arrays. Rendered as a union.
+    Mixed(Vec<SchemaShape>),
+}
+
+/// Infer the shape of `value`, recursing into arrays/objects up to
+/// `max_depth`.
+fn shape_of(value: &Value, depth: usize, max_depth: usize) -> SchemaShape {
     if depth > max_depth {
-        return format!("{}...", indent);
+        return SchemaShape::Any;
     }
 
     match value {
-        Value::Null => format!("{}null", indent),
-        Value::Bool(_) => format!("{}bool", indent),
-        Value::Number(n) => {
-            if n.is_i64() {
-                format!("{}int", indent)
-            } else {
-                format!("{}float", indent)
+        Value::Null => SchemaShape::Scalar(ScalarSet {
+            null: true,
+            ..Default::default()
+        }),
+        Value::Bool(_) => SchemaShape::Scalar(ScalarSet {
+            bool_: true,
+            ..Default::default()
+        }),
+        Value::Number(n) => SchemaShape::Scalar(ScalarSet {
+            int: n.is_i64() || n.is_u64(),
+            float: !(n.is_i64() || n.is_u64()),
+            ..Default::default()
+        }),
+        Value::String(_) => SchemaShape::Scalar(ScalarSet {
+            string: true,
+            ..Default::default()
+        }),
+        Value::Array(arr) => {
+            let elem = arr
+                .iter()
+                .map(|v| shape_of(v, depth + 1, max_depth))
+                .reduce(merge_shapes);
+            SchemaShape::Array {
+                elem: elem.map(Box::new),
+                len: arr.len(),
             }
         }
-        Value::String(s) => {
-            if s.len() > 50 {
-                format!("{}string[{}]", indent, s.len())
-            } else if s.is_empty() {
-                format!("{}string", indent)
-            } else {
-                // Check if it looks like a URL, date, etc.
-                if s.starts_with("http") {
-                    format!("{}url", indent)
-                } else if s.contains('-') && s.len() == 10 {
-                    format!("{}date?", indent)
-                } else {
-                    format!("{}string", indent)
-                }
+        Value::Object(map) => {
+            let mut fields = BTreeMap::new();
+            for (key, val) in map {
+                fields.insert(
+                    key.clone(),
+                    FieldSchema {
+                        shape: shape_of(val, depth + 1, max_depth),
+                        present: 1,
+                    },
+                );
             }
+            SchemaShape::Object(ObjectSchema { fields, count: 1 })
         }
-        Value::Array(arr) => {
-            if arr.is_empty() {
-                format!("{}[]", indent)
-            } else {
-                let first_schema = extract_schema(&arr[0], depth + 1, max_depth);
-                let trimmed = first_schema.trim();
-                if arr.len() == 1 {
-                    format!("{}[\n{}\n{}]", indent, first_schema, indent)
+    }
+}
+
+/// Merge two shapes observed at the same position (e.g. two elements of
+/// the same array). Same-kind shapes unify; everything else collapses
+/// into a [`SchemaShape::Mixed`] union.
+fn merge_shapes(a: SchemaShape, b: SchemaShape) -> SchemaShape {
+    match (a, b) {
+        (SchemaShape::Any, other) | (other, SchemaShape::Any) => other,
+        (SchemaShape::Scalar(mut s1), SchemaShape::Scalar(s2)) => {
+            s1.null |= s2.null;
+            s1.bool_ |= s2.bool_;
+            s1.int |= s2.int;
+            s1.float |= s2.float;
+            s1.string |= s2.string;
+            SchemaShape::Scalar(s1)
+        }
+        (SchemaShape::Array { elem: e1, len: l1 }, SchemaShape::Array { elem: e2, len: l2 }) => {
+            let elem = match (e1, e2) {
+                (None, None) => None,
+                (Some(e), None) | (None, Some(e)) => Some(e),
+                (Some(e1), Some(e2)) => Some(Box::new(merge_shapes(*e1, *e2))),
+            };
+            SchemaShape::Array {
+                elem,
+                len: l1 + l2,
+            }
+        }
+        (SchemaShape::Object(mut o1), SchemaShape::Object(o2)) => {
+            o1.count += o2.count;
+            for (key, f2) in o2.fields {
+                o1.fields
+                    .entry(key)
+                    .and_modify(|f1| {
+                        f1.shape = merge_shapes(f1.shape.clone(), f2.shape.clone());
+                        f1.present += f2.present;
+                    })
+                    .or_insert(f2);
+            }
+            SchemaShape::Object(o1)
+        }
+        (a, b) => push_into_mixed(a, b),
+    }
+}
+
+/// Fold `a` and `b` into a [`SchemaShape::Mixed`], flattening any nested
+/// `Mixed`s and merging members that share a kind instead of piling up
+/// duplicate scalar/array/object entries.
+fn push_into_mixed(a: SchemaShape, b: SchemaShape) -> SchemaShape {
+    let mut shapes = Vec::new();
+    match a {
+        SchemaShape::Mixed(existing) => shapes.extend(existing),
+        other => shapes.push(other),
+    }
+
+    let incoming = match b {
+        SchemaShape::Mixed(existing) => existing,
+        other => vec![other],
+    };
+
+    for shape in incoming {
+        if let Some(pos) = shapes.iter().position(|s| same_kind(s, &shape)) {
+            let existing = shapes.remove(pos);
+            shapes.push(merge_shapes(existing, shape));
+        } else {
+            shapes.push(shape);
+        }
+    }
+
+    SchemaShape::Mixed(shapes)
+}
+
+fn same_kind(a: &SchemaShape, b: &SchemaShape) -> bool {
+    matches!(
+        (a, b),
+        (SchemaShape::Scalar(_), SchemaShape::Scalar(_))
+            | (SchemaShape::Array { .. }, SchemaShape::Array { .. })
+            | (SchemaShape::Object(_), SchemaShape::Object(_))
+    )
+}
+
+/// Render the compact, human-readable schema view.
+fn render_compact(shape: &SchemaShape, depth: usize) -> String {
+    let indent = "  ".repeat(depth);
+
+    match shape {
+        SchemaShape::Any => format!("{}...", indent),
+        SchemaShape::Scalar(set) => format!("{}{}", indent, render_scalar_type(set)),
+        SchemaShape::Array { elem, len } => match elem {
+            None => format!("{}[]", indent),
+            Some(elem) => {
+                let elem_schema = render_compact(elem, depth + 1);
+                let trimmed = elem_schema.trim();
+                if *len == 1 {
+                    format!("{}[\n{}\n{}]", indent, elem_schema, indent)
                 } else {
-                    format!("{}[{}] ({})", indent, trimmed, arr.len())
+                    format!("{}[{}] ({})", indent, trimmed, len)
                 }
             }
+        },
+        SchemaShape::Object(obj) => render_object_compact(obj, depth),
+        SchemaShape::Mixed(shapes) => {
+            let parts: Vec<String> = shapes
+                .iter()
+                .map(|s| render_compact(s, 0).trim().to_string())
+                .collect();
+            format!("{}{}", indent, parts.join(" | "))
         }
-        Value::Object(map) => {
-            if map.is_empty() {
-                format!("{}{{}}", indent)
+    }
+}
+
+fn render_scalar_type(set: &ScalarSet) -> String {
+    let mut types = Vec::new();
+    if set.bool_ {
+        types.push("bool");
+    }
+    if set.int {
+        types.push("int");
+    }
+    if set.float {
+        types.push("float");
+    }
+    if set.string {
+        types.push("string");
+    }
+
+    if types.is_empty() {
+        return "null".to_string();
+    }
+
+    let mut rendered = types.join("|");
+    if set.null {
+        rendered.push('?');
+    }
+    rendered
+}
+
+fn render_object_compact(obj: &ObjectSchema, depth: usize) -> String {
+    let indent = "  ".repeat(depth);
+
+    if obj.fields.is_empty() {
+        return format!("{}{{}}", indent);
+    }
+
+    let mut lines = vec![format!("{}{{", indent)];
+    let total = obj.fields.len();
+
+    for (i, (key, field)) in obj.fields.iter().enumerate() {
+        let optional = if field.present < obj.count { "?" } else { "" };
+        let val_schema = render_compact(&field.shape, depth + 1);
+        let val_trimmed = val_schema.trim();
+        let is_simple = matches!(field.shape, SchemaShape::Scalar(_));
+
+        if is_simple {
+            if i < total - 1 {
+                lines.push(format!("{}  {}{}: {},", indent, key, optional, val_trimmed));
             } else {
-                let mut lines = vec![format!("{}{{", indent)];
-                let mut keys: Vec<_> = map.keys().collect();
-                keys.sort();
-
-                for (i, key) in keys.iter().enumerate() {
-                    let val = &map[*key];
-                    let val_schema = extract_schema(val, depth + 1, max_depth);
-                    let val_trimmed = val_schema.trim();
-
-                    // Inline simple types
-                    let is_simple = matches!(
-                        val,
-                        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_)
-                    );
-
-                    if is_simple {
-                        if i < keys.len() - 1 {
-                            lines.push(format!("{}  {}: {},", indent, key, val_trimmed));
-                        } else {
-                            lines.push(format!("{}  {}: {}", indent, key, val_trimmed));
-                        }
-                    } else {
-                        lines.push(format!("{}  {}:", indent, key));
-                        lines.push(val_schema);
-                    }
-
-                    // Limit keys shown
-                    if i >= 15 {
-                        lines.push(format!("{}  ... +{} more keys", indent, keys.len() - i - 1));
-                        break;
-                    }
+                lines.push(format!("{}  {}{}: {}", indent, key, optional, val_trimmed));
+            }
+        } else {
+            lines.push(format!("{}  {}{}:", indent, key, optional));
+            lines.push(val_schema);
+        }
+
+        if i >= 15 {
+            lines.push(format!("{}  ... +{} more keys", indent, total - i - 1));
+            break;
+        }
+    }
+    lines.push(format!("{}}}", indent));
+    lines.join("\n")
+}
+
+/// Render a JSON Schema draft-07 document for `shape`.
+fn to_json_schema(shape: &SchemaShape) -> Value {
+    match shape {
+        SchemaShape::Any => json!(true),
+        SchemaShape::Scalar(set) => {
+            let types = json_schema_type_name(set);
+            match types.len() {
+                0 => json!({ "type": "null" }),
+                1 => json!({ "type": types[0] }),
+                _ => json!({ "type": types }),
+            }
+        }
+        SchemaShape::Array { elem, len: _ } => {
+            let items = match elem {
+                None => json!(true),
+                Some(elem) => to_json_schema(elem),
+            };
+            json!({ "type": "array", "items": items })
+        }
+        SchemaShape::Object(obj) => {
+            let mut properties = Map::new();
+            let mut required = Vec::new();
+            for (key, field) in &obj.fields {
+                properties.insert(key.clone(), to_json_schema(&field.shape));
+                if field.present >= obj.count {
+                    required.push(Value::String(key.clone()));
                 }
-                lines.push(format!("{}}}", indent));
-                lines.join("\n")
             }
+            json!({
+                "type": "object",
+                "properties": Value::Object(properties),
+                "required": required,
+            })
+        }
+        SchemaShape::Mixed(shapes) => {
+            let variants: Vec<Value> = shapes.iter().map(to_json_schema).collect();
+            json!({ "oneOf": variants })
         }
     }
 }
 
+fn json_schema_type_name(set: &ScalarSet) -> Vec<&'static str> {
+    let mut types = Vec::new();
+    if set.bool_ {
+        types.push("boolean");
+    }
+    if set.int {
+        types.push("integer");
+    }
+    if set.float {
+        types.push("number");
+    }
+    if set.string {
+        types.push("string");
+    }
+    if set.null {
+        types.push("null");
+    }
+    types
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -150,7 +402,7 @@ mod tests {
     #[test]
     fn test_extract_schema_simple() {
         let json: Value = serde_json::from_str(r#"{"name": "test", "count": 42}"#).unwrap();
-        let schema = extract_schema(&json, 0, 5);
+        let schema = render_compact(&shape_of(&json, 0, 5), 0);
         assert!(schema.contains("name"));
         assert!(schema.contains("string"));
         assert!(schema.contains("int"));
@@ -159,8 +411,29 @@ mod tests {
     #[test]
     fn test_extract_schema_array() {
         let json: Value = serde_json::from_str(r#"{"items": [1, 2, 3]}"#).unwrap();
-        let schema = extract_schema(&json, 0, 5);
+        let schema = render_compact(&shape_of(&json, 0, 5), 0);
         assert!(schema.contains("items"));
         assert!(schema.contains("(3)"));
     }
+
+    #[test]
+    fn test_extract_schema_optional_field() {
+        let json: Value =
+            serde_json::from_str(r#"{"items": [{"a": 1}, {"a": 2, "b": "x"}]}"#).unwrap();
+        let schema = render_compact(&shape_of(&json, 0, 5), 0);
+        assert!(schema.contains("b?"));
+        assert!(!schema.contains("a?"));
+    }
+
+    #[test]
+    fn test_json_schema_format_required_and_types() {
+        let json: Value =
+            serde_json::from_str(r#"{"items": [{"a": 1}, {"a": "x", "b": null}]}"#).unwrap();
+        let schema = to_json_schema(&shape_of(&json, 0, 5));
+        let items = &schema["properties"]["items"]["items"];
+        let required = items["required"].as_array().unwrap();
+        assert!(required.iter().any(|v| v == "a"));
+        assert!(!required.iter().any(|v| v == "b"));
+        assert_eq!(items["properties"]["a"]["type"], json!(["integer", "string"]));
+    }
 }
@@ -0,0 +1,253 @@
+//! Typed Kubernetes API client backend for `kubectl pods`/`kubectl
+//! services`, used in place of shelling out to `kubectl` and parsing JSON
+//! when rtk is built with the `kube-client` feature. Talks to the cluster
+//! directly via the `kube` crate, resolving in-cluster or kubeconfig
+//! credentials the same way `kubectl` itself does, and deserializes into
+//! `k8s_openapi`'s typed objects instead of `serde_json::Value` index
+//! chains. Callers in `container.rs` fall back to the shell path whenever a
+//! client can't be built (e.g. no credentials available) or this feature is
+//! off entirely.
+#![cfg(feature = "kube-client")]
+
+use anyhow::{Context, Result};
+use futures::{StreamExt, TryStreamExt};
+use k8s_openapi::api::core::v1::{ContainerStatus, Pod, Service};
+use kube::{
+    api::{Api, ListParams},
+    runtime::{watcher, WatchStreamExt},
+    Client,
+};
+use std::collections::HashMap;
+
+/// Same health signals as `container::SuspiciousContainerReason`, but read
+/// off typed `ContainerStatus` fields instead of JSON index chains.
+#[derive(Debug, Clone)]
+pub enum ContainerHealth {
+    Waiting(String),
+    NotReady,
+    Restarted {
+        count: i32,
+        exit_code: i32,
+        reason: String,
+    },
+    TerminatedWithError(i32),
+}
+
+impl std::fmt::Display for ContainerHealth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContainerHealth::Waiting(reason) => write!(f, "{}", reason),
+            ContainerHealth::NotReady => write!(f, "not ready"),
+            ContainerHealth::Restarted {
+                count,
+                exit_code,
+                reason,
+            } => write!(f, "restarted x{} ({}, exit {})", count, reason, exit_code),
+            ContainerHealth::TerminatedWithError(exit_code) => {
+                write!(f, "terminated (exit {})", exit_code)
+            }
+        }
+    }
+}
+
+/// A pod's compact, pre-diagnosed status.
+#[derive(Debug, Clone)]
+pub struct PodSummary {
+    pub namespace: String,
+    pub name: String,
+    pub phase: String,
+    pub restarts: i32,
+    pub issues: Vec<ContainerHealth>,
+}
+
+/// Diagnose one container status, mirroring
+/// `container::diagnose_container`'s JSON-based logic field-for-field.
+fn diagnose(status: &ContainerStatus) -> Vec<ContainerHealth> {
+    let mut reasons = Vec::new();
+
+    if let Some(waiting) = status.state.as_ref().and_then(|s| s.waiting.as_ref()) {
+        if let Some(reason) = &waiting.reason {
+            reasons.push(ContainerHealth::Waiting(reason.clone()));
+        }
+    }
+
+    if let Some(terminated) = status.state.as_ref().and_then(|s| s.terminated.as_ref()) {
+        if terminated.exit_code != 0 {
+            reasons.push(ContainerHealth::TerminatedWithError(terminated.exit_code));
+        }
+    }
+
+    if status.restart_count > 0 {
+        let last_terminated = status
+            .last_state
+            .as_ref()
+            .and_then(|s| s.terminated.as_ref());
+        reasons.push(ContainerHealth::Restarted {
+            count: status.restart_count,
+            exit_code: last_terminated.map(|t| t.exit_code).unwrap_or(0),
+            reason: last_terminated
+                .and_then(|t| t.reason.clone())
+                .unwrap_or_else(|| "unknown".to_string()),
+        });
+    }
+
+    if reasons.is_empty() && !status.ready {
+        reasons.push(ContainerHealth::NotReady);
+    }
+
+    reasons
+}
+
+fn summarize_pod(pod: &Pod) -> PodSummary {
+    let namespace = pod
+        .metadata
+        .namespace
+        .clone()
+        .unwrap_or_else(|| "-".to_string());
+    let name = pod.metadata.name.clone().unwrap_or_else(|| "-".to_string());
+    let phase = pod
+        .status
+        .as_ref()
+        .and_then(|s| s.phase.clone())
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    let mut restarts = 0;
+    let mut issues = Vec::new();
+    if let Some(statuses) = pod.status.as_ref().and_then(|s| s.container_statuses.as_ref()) {
+        for status in statuses {
+            restarts += status.restart_count;
+            issues.extend(diagnose(status));
+        }
+    }
+
+    PodSummary {
+        namespace,
+        name,
+        phase,
+        restarts,
+        issues,
+    }
+}
+
+/// A service's compact status, typed from its `ServiceSpec`.
+#[derive(Debug, Clone)]
+pub struct ServiceSummary {
+    pub namespace: String,
+    pub name: String,
+    pub svc_type: String,
+    pub ports: Vec<String>,
+}
+
+fn summarize_service(svc: &Service) -> ServiceSummary {
+    let namespace = svc
+        .metadata
+        .namespace
+        .clone()
+        .unwrap_or_else(|| "-".to_string());
+    let name = svc.metadata.name.clone().unwrap_or_else(|| "-".to_string());
+    let spec = svc.spec.clone().unwrap_or_default();
+    let svc_type = spec.type_.unwrap_or_else(|| "-".to_string());
+    let ports = spec
+        .ports
+        .unwrap_or_default()
+        .iter()
+        .map(|p| {
+            let target = match &p.target_port {
+                Some(k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::Int(n)) => *n,
+                Some(k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::String(s)) => {
+                    s.parse().unwrap_or(p.port)
+                }
+                None => p.port,
+            };
+            if p.port == target {
+                format!("{}", p.port)
+            } else {
+                format!("{}→{}", p.port, target)
+            }
+        })
+        .collect();
+
+    ServiceSummary {
+        namespace,
+        name,
+        svc_type,
+        ports,
+    }
+}
+
+/// One-shot typed snapshot of the current services.
+pub fn list_services(namespace: Option<&str>, all_namespaces: bool) -> Result<Vec<ServiceSummary>> {
+    let rt = tokio::runtime::Runtime::new().context("Failed to start async runtime")?;
+    rt.block_on(async {
+        let cl = client().await?;
+        let api: Api<Service> = match (all_namespaces, namespace) {
+            (true, _) => Api::all(cl),
+            (false, Some(ns)) => Api::namespaced(cl, ns),
+            (false, None) => Api::default_namespaced(cl),
+        };
+        let services = api
+            .list(&ListParams::default())
+            .await
+            .context("Failed to list services")?;
+        Ok(services.items.iter().map(summarize_service).collect())
+    })
+}
+
+/// Build a client from in-cluster config, falling back to the local
+/// kubeconfig - the same resolution order `kubectl` itself uses.
+async fn client() -> Result<Client> {
+    Client::try_default()
+        .await
+        .context("Failed to build Kubernetes client (no in-cluster config or kubeconfig found)")
+}
+
+fn pod_api(client: Client, namespace: Option<&str>, all_namespaces: bool) -> Api<Pod> {
+    match (all_namespaces, namespace) {
+        (true, _) => Api::all(client),
+        (false, Some(ns)) => Api::namespaced(client, ns),
+        (false, None) => Api::default_namespaced(client),
+    }
+}
+
+/// One-shot typed snapshot of the current pods.
+pub fn list_pods(namespace: Option<&str>, all_namespaces: bool) -> Result<Vec<PodSummary>> {
+    let rt = tokio::runtime::Runtime::new().context("Failed to start async runtime")?;
+    rt.block_on(async {
+        let api = pod_api(client().await?, namespace, all_namespaces);
+        let pods = api
+            .list(&ListParams::default())
+            .await
+            .context("Failed to list pods")?;
+        Ok(pods.items.iter().map(summarize_pod).collect())
+    })
+}
+
+/// Stream pod events, invoking `on_update` with a fresh snapshot of every
+/// pod seen so far after each one - this is what turns `rtk kubectl pods
+/// --watch` into a live dashboard instead of a one-shot snapshot.
+pub fn watch_pods(
+    namespace: Option<&str>,
+    all_namespaces: bool,
+    mut on_update: impl FnMut(&[PodSummary]),
+) -> Result<()> {
+    let rt = tokio::runtime::Runtime::new().context("Failed to start async runtime")?;
+    rt.block_on(async {
+        let api = pod_api(client().await?, namespace, all_namespaces);
+        let mut seen: HashMap<String, Pod> = HashMap::new();
+        let mut stream = watcher(api, watcher::Config::default())
+            .applied_objects()
+            .boxed();
+
+        while let Some(pod) = stream.try_next().await.context("Watch stream error")? {
+            let key = format!(
+                "{}/{}",
+                pod.metadata.namespace.as_deref().unwrap_or("-"),
+                pod.metadata.name.as_deref().unwrap_or("-")
+            );
+            seen.insert(key, pod);
+            let summaries: Vec<PodSummary> = seen.values().map(summarize_pod).collect();
+            on_update(&summaries);
+        }
+        Ok(())
+    })
+}
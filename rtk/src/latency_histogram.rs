@@ -0,0 +1,169 @@
+//! HDR-histogram-style latency bucketing for execution-time percentiles.
+//!
+//! [`Histogram`] maintains logarithmically-spaced buckets over `exec_time_ms`
+//! samples: each power-of-two range `[2^k, 2^(k+1))` is sub-divided into a
+//! fixed number of linear sub-buckets, bounding relative error to roughly
+//! `1 / (2 * SUB_BUCKETS_PER_POWER)`. This avoids storing every raw sample
+//! while still answering percentile queries (p50/p95/p99) over a group of
+//! records, fed from the same grouped row scans used by
+//! [`crate::tracking::Tracker::get_all_days`] and friends.
+
+/// Sub-buckets per power-of-two range. 64 bounds relative error to ~0.8%.
+const SUB_BUCKETS_PER_POWER: u64 = 64;
+
+/// Number of power-of-two ranges tracked. `2^40` ms is ~34 years, far beyond
+/// any real command execution time.
+const MAX_POWER: usize = 40;
+
+/// A latency histogram over `exec_time_ms` samples (milliseconds).
+#[derive(Debug, Clone)]
+pub struct Histogram {
+    buckets: Vec<u64>,
+    total: u64,
+}
+
+impl Histogram {
+    /// Create an empty histogram.
+    pub fn new() -> Self {
+        Self {
+            buckets: vec![0; MAX_POWER * SUB_BUCKETS_PER_POWER as usize],
+            total: 0,
+        }
+    }
+
+    /// Record one `exec_time_ms` sample.
+    pub fn record(&mut self, value_ms: u64) {
+        let idx = bucket_index(value_ms);
+        self.buckets[idx] += 1;
+        self.total += 1;
+    }
+
+    /// Total number of recorded samples.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Compute the `p`th percentile (0.0..=100.0) of recorded samples.
+    ///
+    /// Walks buckets in ascending order accumulating counts until the
+    /// cumulative count crosses `ceil(p / 100 * total)`, returning that
+    /// bucket's representative value. Returns 0 for an empty histogram.
+    pub fn percentile(&self, p: f64) -> u64 {
+        if self.total == 0 {
+            return 0;
+        }
+
+        let threshold = ((p / 100.0) * self.total as f64).ceil() as u64;
+        let threshold = threshold.max(1);
+
+        let mut cumulative = 0u64;
+        for (idx, &count) in self.buckets.iter().enumerate() {
+            if count == 0 {
+                continue;
+            }
+            cumulative += count;
+            if cumulative >= threshold {
+                return bucket_representative_value(idx);
+            }
+        }
+
+        0
+    }
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Map a sample value to its bucket index.
+///
+/// Uses `value + 1` internally so that `value == 0` falls into the first
+/// bucket instead of requiring a `log2(0)` special case.
+fn bucket_index(value_ms: u64) -> usize {
+    let v = value_ms + 1;
+    let power = (63 - v.leading_zeros()) as usize;
+    let power = power.min(MAX_POWER - 1);
+
+    let range_start = 1u64 << power;
+    let offset_in_range = v - range_start;
+    let sub_bucket = (offset_in_range * SUB_BUCKETS_PER_POWER / range_start.max(1))
+        .min(SUB_BUCKETS_PER_POWER - 1);
+
+    power * SUB_BUCKETS_PER_POWER as usize + sub_bucket as usize
+}
+
+/// Representative value (sub-bucket midpoint) for a bucket index.
+fn bucket_representative_value(idx: usize) -> u64 {
+    let power = idx / SUB_BUCKETS_PER_POWER as usize;
+    let sub_bucket = (idx % SUB_BUCKETS_PER_POWER as usize) as u64;
+
+    let range_start = 1u64 << power;
+    let sub_width = (range_start / SUB_BUCKETS_PER_POWER).max(1);
+    let value = range_start + sub_bucket * sub_width + sub_width / 2;
+
+    value.saturating_sub(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_histogram_percentiles_are_zero() {
+        let h = Histogram::new();
+        assert_eq!(h.percentile(50.0), 0);
+        assert_eq!(h.percentile(95.0), 0);
+        assert_eq!(h.percentile(99.0), 0);
+    }
+
+    #[test]
+    fn test_single_sample_all_percentiles_match() {
+        let mut h = Histogram::new();
+        h.record(42);
+        let p50 = h.percentile(50.0);
+        assert!(p50.abs_diff(42) <= 1, "p50={p50} expected ~42");
+    }
+
+    #[test]
+    fn test_percentiles_on_uniform_distribution() {
+        let mut h = Histogram::new();
+        for v in 1..=1000u64 {
+            h.record(v);
+        }
+
+        let p50 = h.percentile(50.0);
+        let p95 = h.percentile(95.0);
+        let p99 = h.percentile(99.0);
+
+        // Within ~2% relative error of the true values for a uniform 1..=1000 set.
+        assert!((p50 as i64 - 500).abs() <= 10, "p50={p50}");
+        assert!((p95 as i64 - 950).abs() <= 20, "p95={p95}");
+        assert!((p99 as i64 - 990).abs() <= 20, "p99={p99}");
+    }
+
+    #[test]
+    fn test_percentiles_ordered() {
+        let mut h = Histogram::new();
+        for v in [10, 20, 30, 5000, 10000] {
+            h.record(v);
+        }
+
+        let p50 = h.percentile(50.0);
+        let p95 = h.percentile(95.0);
+        let p99 = h.percentile(99.0);
+
+        assert!(p50 <= p95);
+        assert!(p95 <= p99);
+    }
+
+    #[test]
+    fn test_zero_value_sample_does_not_panic() {
+        let mut h = Histogram::new();
+        h.record(0);
+        assert_eq!(h.total(), 1);
+        let p50 = h.percentile(50.0);
+        assert!(p50 <= 1);
+    }
+}
@@ -1,7 +1,9 @@
+use chrono::{DateTime, NaiveDate, Utc};
 use lazy_static::lazy_static;
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ErrorType {
     UnknownFlag,
     CommandNotFound,
@@ -33,6 +35,10 @@ pub struct CorrectionPair {
     pub error_output: String,
     pub error_type: ErrorType,
     pub confidence: f64,
+    /// RFC 3339 timestamp of the erroring command, when the transcript
+    /// reported one. Backs [`CorrectionRule::first_seen`]/`last_seen` and
+    /// the `--group-by` recent-trend breakdown.
+    pub timestamp: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -43,6 +49,10 @@ pub struct CorrectionRule {
     pub occurrences: usize,
     pub base_command: String,
     pub example_error: String,
+    /// Earliest/latest timestamp among the occurrences merged into this
+    /// rule, in RFC 3339. `None` when no merged occurrence carried one.
+    pub first_seen: Option<String>,
+    pub last_seen: Option<String>,
 }
 
 lazy_static! {
@@ -70,8 +80,18 @@ lazy_static! {
     static ref USER_REJECTION_RE: Regex = Regex::new(
         r"(?i)(user (doesn't want|declined|rejected|cancelled)|operation (cancelled|aborted) by user)"
     ).unwrap();
+
+    // A leading `NAME=value` env-var assignment token, e.g. `FOO=1` or
+    // `RUST_BACKTRACE=1`, used by `extract_base_command` to skip any number
+    // of them generically instead of matching hardcoded prefixes.
+    static ref ENV_ASSIGNMENT_RE: Regex = Regex::new(r"^[A-Za-z_][A-Za-z0-9_]*=").unwrap();
 }
 
+/// Privilege/wrapper commands that precede the real program without being
+/// part of its identity for grouping purposes (`sudo git commit` and
+/// `git commit` should compare as the same base command).
+const WRAPPER_COMMANDS: &[&str] = &["sudo", "env", "nice", "nohup", "time", "xargs"];
+
 /// Filters out user rejections - requires actual error-indicating content
 pub fn is_command_error(is_error: bool, output: &str) -> bool {
     if !is_error {
@@ -115,6 +135,8 @@ pub struct CommandExecution {
     pub command: String,
     pub is_error: bool,
     pub output: String,
+    /// RFC 3339 timestamp of the command, when known.
+    pub timestamp: Option<String>,
 }
 
 const CORRECTION_WINDOW: usize = 3;
@@ -123,16 +145,23 @@ const MIN_CONFIDENCE: f64 = 0.6;
 /// Extract base command (first 1-2 tokens, stripping env prefixes)
 pub fn extract_base_command(cmd: &str) -> String {
     let trimmed = cmd.trim();
+    let tokens: Vec<&str> = trimmed.split_whitespace().collect();
+
+    // Skip any number of leading `NAME=value` assignments and
+    // privilege/wrapper prefixes (in either order - `sudo FOO=1 cmd` and
+    // `env FOO=1 sudo cmd` both defeat grouping otherwise) to reach the
+    // real program.
+    let mut idx = 0;
+    while idx < tokens.len() {
+        if ENV_ASSIGNMENT_RE.is_match(tokens[idx]) || WRAPPER_COMMANDS.contains(&tokens[idx]) {
+            idx += 1;
+        } else {
+            break;
+        }
+    }
 
-    // Strip common env prefixes
-    let stripped = trimmed
-        .strip_prefix("RUST_BACKTRACE=1 ")
-        .or_else(|| trimmed.strip_prefix("NODE_ENV=production "))
-        .or_else(|| trimmed.strip_prefix("DEBUG=* "))
-        .unwrap_or(trimmed);
-
-    // Get first 1-2 tokens
-    let parts: Vec<&str> = stripped.split_whitespace().collect();
+    // Get first 1-2 meaningful tokens
+    let parts = &tokens[idx..];
     match parts.len() {
         0 => String::new(),
         1 => parts[0].to_string(),
@@ -140,8 +169,85 @@ pub fn extract_base_command(cmd: &str) -> String {
     }
 }
 
-/// Calculate similarity between two commands using Jaccard similarity
-/// Same base command = 0.5 base score + up to 0.5 from argument similarity
+/// Per-operation costs for [`damerau_levenshtein`]. Default weights
+/// (add=sub=del=1, swap=0) charge insert/delete/substitute a full edit each
+/// and an adjacent transposition (the `ammend` -> `amend` shape) the same as
+/// a single edit rather than two substitutions.
+#[derive(Debug, Clone, Copy)]
+struct EditWeights {
+    add: usize,
+    del: usize,
+    sub: usize,
+    swap: usize,
+}
+
+impl Default for EditWeights {
+    fn default() -> Self {
+        EditWeights {
+            add: 1,
+            del: 1,
+            sub: 1,
+            swap: 0,
+        }
+    }
+}
+
+/// Damerau-Levenshtein edit distance between `a` and `b`, with
+/// transposition - the same row-based DP `git help`'s "did you mean"
+/// uses: three rolling rows instead of a full m*n matrix.
+fn damerau_levenshtein(a: &[char], b: &[char], weights: EditWeights) -> usize {
+    let m = a.len();
+    let n = b.len();
+
+    let mut row0 = vec![0usize; n + 1];
+    let mut row1: Vec<usize> = (0..=n).map(|j| j * weights.add).collect();
+    let mut row2 = vec![0usize; n + 1];
+
+    for i in 0..m {
+        row2[0] = (i + 1) * weights.del;
+        for j in 0..n {
+            let deletion = row1[j + 1] + weights.del;
+            let insertion = row2[j] + weights.add;
+            let substitution = row1[j] + if a[i] == b[j] { 0 } else { weights.sub };
+
+            let mut best = deletion.min(insertion).min(substitution);
+
+            if i > 0 && j > 0 && a[i] == b[j - 1] && a[i - 1] == b[j] {
+                best = best.min(row0[j - 1] + weights.swap);
+            }
+
+            row2[j + 1] = best;
+        }
+
+        std::mem::swap(&mut row0, &mut row1);
+        std::mem::swap(&mut row1, &mut row2);
+    }
+
+    row1[n]
+}
+
+/// Edit-distance similarity between two tokens, in `[0, 1]`: `1 -
+/// dist/max(len)` using the default [`EditWeights`]. Two empty tokens are
+/// trivially identical (`1.0`).
+fn token_edit_similarity(a: &str, b: &str) -> f64 {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    let max_len = a_chars.len().max(b_chars.len());
+    if max_len == 0 {
+        return 1.0;
+    }
+
+    let dist = damerau_levenshtein(&a_chars, &b_chars, EditWeights::default());
+    1.0 - (dist as f64 / max_len as f64)
+}
+
+/// Calculate similarity between two commands: 0.5 base score for sharing a
+/// base command, plus up to 0.5 from argument similarity. Argument
+/// similarity starts from Jaccard overlap, but tokens present on only one
+/// side are paired up (sorted, for a deterministic pairing) and scored by
+/// [`token_edit_similarity`] instead of counting as zero overlap - so a
+/// single-character typo (`--amend` vs `--ammend`) still scores close to a
+/// match instead of looking like two unrelated tokens.
 pub fn command_similarity(a: &str, b: &str) -> f64 {
     let base_a = extract_base_command(a);
     let base_b = extract_base_command(b);
@@ -174,8 +280,21 @@ pub fn command_similarity(a: &str, b: &str) -> f64 {
         return 0.5; // Same base, no args
     }
 
-    // 0.5 for same base + up to 0.5 for arg similarity
-    0.5 + (intersection as f64 / union as f64) * 0.5
+    let mut removed: Vec<&str> = args_a.difference(&args_b).copied().collect();
+    let mut added: Vec<&str> = args_b.difference(&args_a).copied().collect();
+    removed.sort_unstable();
+    added.sort_unstable();
+
+    let typo_credit: f64 = removed
+        .iter()
+        .zip(added.iter())
+        .map(|(r, a)| token_edit_similarity(r, a))
+        .sum();
+
+    // 0.5 for same base + up to 0.5 for arg similarity (exact overlap plus
+    // fractional credit for near-miss typo pairs)
+    let arg_similarity = (intersection as f64 + typo_credit) / union as f64;
+    0.5 + arg_similarity * 0.5
 }
 
 /// Check if error is a compilation/test error (TDD cycle, not CLI correction)
@@ -269,6 +388,7 @@ pub fn find_corrections(commands: &[CommandExecution]) -> Vec<CorrectionPair> {
                 error_output: cmd.output.chars().take(500).collect(),
                 error_type: error_type.clone(),
                 confidence,
+                timestamp: cmd.timestamp.clone(),
             });
 
             // Take first match only
@@ -279,27 +399,119 @@ pub fn find_corrections(commands: &[CommandExecution]) -> Vec<CorrectionPair> {
     corrections
 }
 
-/// Extract the specific token that changed between wrong and right commands
-fn extract_diff_token(wrong: &str, right: &str) -> String {
-    let wrong_parts: std::collections::HashSet<&str> = wrong.split_whitespace().collect();
-    let right_parts: std::collections::HashSet<&str> = right.split_whitespace().collect();
+/// A single token-level edit turning a `wrong` command into a `right` one,
+/// as produced by [`diff_tokens`]. `pos` indexes into the `wrong` token
+/// sequence (the insertion point, for `Insert`), so the same edit applied
+/// at different argument slots yields a different `TokenEdit`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TokenEdit {
+    Replace { pos: usize, old: String, new: String },
+    Insert { pos: usize, token: String },
+    Delete { pos: usize, token: String },
+}
 
-    // Find tokens in wrong but not in right (removed)
-    let removed: Vec<&str> = wrong_parts.difference(&right_parts).copied().collect();
+/// Align two token sequences with an LCS backtrack and return the edits
+/// that turn `wrong` into `right`, left-to-right over `wrong`'s positions.
+/// Adjacent delete+insert pairs are merged into a single `Replace` so a
+/// flag rename reads as one edit rather than two unrelated ones.
+fn diff_tokens(wrong: &[&str], right: &[&str]) -> Vec<TokenEdit> {
+    let m = wrong.len();
+    let n = right.len();
+
+    // lcs[i][j] = length of the LCS of wrong[i..] and right[j..].
+    let mut lcs = vec![vec![0usize; n + 1]; m + 1];
+    for i in (0..m).rev() {
+        for j in (0..n).rev() {
+            lcs[i][j] = if wrong[i] == right[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
 
-    // Find tokens in right but not in wrong (added)
-    let added: Vec<&str> = right_parts.difference(&wrong_parts).copied().collect();
+    enum RawOp {
+        Keep,
+        Delete(usize, String),
+        Insert(usize, String),
+    }
 
-    // Return the most distinctive change
-    if !removed.is_empty() && !added.is_empty() {
-        format!("{} â†’ {}", removed[0], added[0])
-    } else if !removed.is_empty() {
-        format!("removed {}", removed[0])
-    } else if !added.is_empty() {
-        format!("added {}", added[0])
-    } else {
-        "unknown".to_string()
+    let mut raw = Vec::new();
+    let mut i = 0usize;
+    let mut j = 0usize;
+    while i < m && j < n {
+        if wrong[i] == right[j] {
+            raw.push(RawOp::Keep);
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            raw.push(RawOp::Delete(i, wrong[i].to_string()));
+            i += 1;
+        } else {
+            raw.push(RawOp::Insert(i, right[j].to_string()));
+            j += 1;
+        }
+    }
+    while i < m {
+        raw.push(RawOp::Delete(i, wrong[i].to_string()));
+        i += 1;
+    }
+    while j < n {
+        raw.push(RawOp::Insert(i, right[j].to_string()));
+        j += 1;
+    }
+
+    let mut edits = Vec::new();
+    let mut k = 0;
+    while k < raw.len() {
+        match &raw[k] {
+            RawOp::Keep => k += 1,
+            RawOp::Delete(pos, old) => {
+                if let Some(RawOp::Insert(_, new)) = raw.get(k + 1) {
+                    edits.push(TokenEdit::Replace {
+                        pos: *pos,
+                        old: old.clone(),
+                        new: new.clone(),
+                    });
+                    k += 2;
+                } else {
+                    edits.push(TokenEdit::Delete {
+                        pos: *pos,
+                        token: old.clone(),
+                    });
+                    k += 1;
+                }
+            }
+            RawOp::Insert(pos, token) => {
+                edits.push(TokenEdit::Insert {
+                    pos: *pos,
+                    token: token.clone(),
+                });
+                k += 1;
+            }
+        }
     }
+    edits
+}
+
+/// Render the ordered token edits between `wrong` and `right` as a stable
+/// signature string, used as the diff component of `deduplicate_corrections`'s
+/// grouping key. Positional and ordered, unlike the old set-difference
+/// sketch, so multi-change corrections (a flag rename plus an added
+/// positional arg) group distinctly instead of collapsing.
+pub(crate) fn diff_signature(wrong: &str, right: &str) -> String {
+    let wrong_tokens: Vec<&str> = wrong.split_whitespace().collect();
+    let right_tokens: Vec<&str> = right.split_whitespace().collect();
+
+    diff_tokens(&wrong_tokens, &right_tokens)
+        .iter()
+        .map(|edit| match edit {
+            TokenEdit::Replace { pos, old, new } => format!("{pos}:{old}->{new}"),
+            TokenEdit::Insert { pos, token } => format!("{pos}:+{token}"),
+            TokenEdit::Delete { pos, token } => format!("{pos}:-{token}"),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
 }
 
 pub fn deduplicate_corrections(pairs: Vec<CorrectionPair>) -> Vec<CorrectionRule> {
@@ -307,11 +519,11 @@ pub fn deduplicate_corrections(pairs: Vec<CorrectionPair>) -> Vec<CorrectionRule
 
     let mut groups: HashMap<(String, String, String), Vec<CorrectionPair>> = HashMap::new();
 
-    // Group by (base_command, error_type, diff_token)
+    // Group by (base_command, error_type, diff_signature)
     for pair in pairs {
         let base = extract_base_command(&pair.wrong_command);
         let error_type_str = pair.error_type.as_str().to_string();
-        let diff_token = extract_diff_token(&pair.wrong_command, &pair.right_command);
+        let diff_token = diff_signature(&pair.wrong_command, &pair.right_command);
 
         let key = (base, error_type_str, diff_token);
         groups.entry(key).or_default().push(pair);
@@ -333,6 +545,12 @@ pub fn deduplicate_corrections(pairs: Vec<CorrectionPair>) -> Vec<CorrectionRule
         // Reconstruct ErrorType from string (simplified - just use first one)
         let error_type = best.error_type.clone();
 
+        // RFC 3339 timestamps sort correctly as plain strings, so min/max
+        // over the group's occurrences gives first/last seen without
+        // parsing dates here.
+        let first_seen = group.iter().filter_map(|p| p.timestamp.as_ref()).min().cloned();
+        let last_seen = group.iter().filter_map(|p| p.timestamp.as_ref()).max().cloned();
+
         rules.push(CorrectionRule {
             wrong_pattern: best.wrong_command.clone(),
             right_pattern: best.right_command.clone(),
@@ -340,6 +558,8 @@ pub fn deduplicate_corrections(pairs: Vec<CorrectionPair>) -> Vec<CorrectionRule
             occurrences,
             base_command,
             example_error: best.error_output.clone(),
+            first_seen,
+            last_seen,
         });
     }
 
@@ -349,6 +569,276 @@ pub fn deduplicate_corrections(pairs: Vec<CorrectionPair>) -> Vec<CorrectionRule
     rules
 }
 
+/// A proposed fix for a failing command, derived from applying a matching
+/// [`CorrectionRule`]'s learned edit to the command that just failed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Correction {
+    pub suggested_command: String,
+    pub base_command: String,
+    pub confidence: f64,
+    pub occurrences: usize,
+}
+
+/// Default confidence floor for [`suggest_correction`], below which a rule
+/// is considered too weak (too few occurrences, too dissimilar a command)
+/// to propose automatically.
+const AUTOCORRECT_MIN_CONFIDENCE: f64 = 0.5;
+
+/// Apply `rule`'s learned wrong -> right edit to `failed_command`: diff the
+/// rule's patterns into removed/added tokens (same sorted pairing as
+/// [`command_similarity`]), substitute each removed token found in
+/// `failed_command` with its paired addition, and append any addition left
+/// over once removals run out (a pure insertion rather than a swap).
+fn apply_correction_edit(failed_command: &str, rule: &CorrectionRule) -> String {
+    use std::collections::{HashMap, HashSet};
+
+    let wrong_tokens: HashSet<&str> = rule.wrong_pattern.split_whitespace().collect();
+    let right_tokens: HashSet<&str> = rule.right_pattern.split_whitespace().collect();
+
+    let mut removed: Vec<&str> = wrong_tokens.difference(&right_tokens).copied().collect();
+    let mut added: Vec<&str> = right_tokens.difference(&wrong_tokens).copied().collect();
+    removed.sort_unstable();
+    added.sort_unstable();
+
+    let replacements: HashMap<&str, &str> = removed
+        .iter()
+        .zip(added.iter())
+        .map(|(r, a)| (*r, *a))
+        .collect();
+
+    let mut tokens: Vec<&str> = failed_command
+        .split_whitespace()
+        .map(|tok| replacements.get(tok).copied().unwrap_or(tok))
+        .collect();
+
+    if added.len() > removed.len() {
+        tokens.extend(added[removed.len()..].iter().copied());
+    }
+
+    tokens.join(" ")
+}
+
+/// Every [`CorrectionRule`] that applies to `failed_command`'s error,
+/// ranked most-confident first. A rule applies when its `base_command` and
+/// `error_type` match; confidence blends [`command_similarity`] between
+/// `failed_command` and the rule's `wrong_pattern` with how often the rule
+/// has occurred (more occurrences -> more trustworthy). Candidates whose
+/// rewrite leaves the command unchanged, or that fall below
+/// `min_confidence`, are dropped.
+pub fn suggest_corrections(
+    failed_command: &str,
+    error_output: &str,
+    rules: &[CorrectionRule],
+    min_confidence: f64,
+) -> Vec<Correction> {
+    let base = extract_base_command(failed_command);
+    let error_type = classify_error(error_output);
+
+    let mut candidates: Vec<Correction> = rules
+        .iter()
+        .filter(|rule| rule.base_command == base && rule.error_type == error_type)
+        .map(|rule| {
+            let similarity = command_similarity(failed_command, &rule.wrong_pattern);
+            let occurrence_weight = rule.occurrences as f64 / (rule.occurrences as f64 + 2.0);
+            let confidence = (similarity * 0.7 + occurrence_weight * 0.3).min(1.0);
+            Correction {
+                suggested_command: apply_correction_edit(failed_command, rule),
+                base_command: rule.base_command.clone(),
+                confidence,
+                occurrences: rule.occurrences,
+            }
+        })
+        .filter(|c| c.confidence >= min_confidence)
+        .filter(|c| c.suggested_command != failed_command)
+        .collect();
+
+    candidates.sort_by(|a, b| {
+        b.confidence
+            .partial_cmp(&a.confidence)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    candidates
+}
+
+/// The single best-matching [`CorrectionRule`] applied to `failed_command`,
+/// if any rule clears [`AUTOCORRECT_MIN_CONFIDENCE`] - analogous to git's
+/// `help.autocorrect`: classify the new error, find the closest learned
+/// mistake with the same base command and error type, and propose its fix.
+pub fn suggest_correction(
+    failed_command: &str,
+    error_output: &str,
+    rules: &[CorrectionRule],
+) -> Option<Correction> {
+    suggest_corrections(
+        failed_command,
+        error_output,
+        rules,
+        AUTOCORRECT_MIN_CONFIDENCE,
+    )
+    .into_iter()
+    .next()
+}
+
+/// How `--group-by` partitions deduplicated rules for the grouped report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupBy {
+    ErrorType,
+    BaseCommand,
+}
+
+impl GroupBy {
+    /// Parse a `--group-by` CLI value. Returns `None` for anything else so
+    /// the caller can report an unrecognized value.
+    pub fn parse(s: &str) -> Option<GroupBy> {
+        match s {
+            "error_type" => Some(GroupBy::ErrorType),
+            "base_command" => Some(GroupBy::BaseCommand),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            GroupBy::ErrorType => "error_type",
+            GroupBy::BaseCommand => "base_command",
+        }
+    }
+
+    fn rule_key(self, rule: &CorrectionRule) -> String {
+        match self {
+            GroupBy::ErrorType => rule.error_type.as_str().to_string(),
+            GroupBy::BaseCommand => rule.base_command.clone(),
+        }
+    }
+
+    fn pair_key(self, pair: &CorrectionPair) -> String {
+        match self {
+            GroupBy::ErrorType => pair.error_type.as_str().to_string(),
+            GroupBy::BaseCommand => extract_base_command(&pair.wrong_command),
+        }
+    }
+}
+
+/// Occurrence count for a single calendar day within a [`GroupStats`]'s
+/// recent-trend window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayCount {
+    pub day: String,
+    pub count: usize,
+}
+
+/// Aggregate statistics for one category of corrections (an error type or a
+/// base command), produced by [`group_rules`].
+#[derive(Debug, Clone)]
+pub struct GroupStats {
+    pub category: String,
+    pub occurrences: usize,
+    pub distinct_commands: usize,
+    pub first_seen: Option<String>,
+    pub last_seen: Option<String>,
+    /// One entry per day of the trend window, oldest first, zero-filled for
+    /// days with no matching correction.
+    pub recent_trend: Vec<DayCount>,
+}
+
+fn parse_event_day(timestamp: &str) -> Option<NaiveDate> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .map(|dt| dt.with_timezone(&Utc).date_naive())
+        .ok()
+}
+
+/// Partition `rules` by `group_by`, summing occurrences and distinct base
+/// commands per category. `pairs` (the pre-deduplication occurrences, which
+/// still carry per-event timestamps) back each category's `recent_trend`:
+/// a daily occurrence count for the `trend_days` days up to and including
+/// `today`. Categories are sorted by total occurrences descending, matching
+/// [`deduplicate_corrections`]'s ordering.
+pub fn group_rules(
+    rules: &[CorrectionRule],
+    pairs: &[CorrectionPair],
+    group_by: GroupBy,
+    trend_days: u64,
+    today: NaiveDate,
+) -> Vec<GroupStats> {
+    use std::collections::{HashMap, HashSet};
+
+    struct Accum {
+        occurrences: usize,
+        commands: HashSet<String>,
+        first_seen: Option<String>,
+        last_seen: Option<String>,
+    }
+
+    let mut groups: HashMap<String, Accum> = HashMap::new();
+
+    for rule in rules {
+        let entry = groups
+            .entry(group_by.rule_key(rule))
+            .or_insert_with(|| Accum {
+                occurrences: 0,
+                commands: HashSet::new(),
+                first_seen: None,
+                last_seen: None,
+            });
+        entry.occurrences += rule.occurrences;
+        entry.commands.insert(rule.base_command.clone());
+        if let Some(ts) = &rule.first_seen {
+            if entry.first_seen.as_deref().is_none_or(|f| ts.as_str() < f) {
+                entry.first_seen = Some(ts.clone());
+            }
+        }
+        if let Some(ts) = &rule.last_seen {
+            if entry.last_seen.as_deref().is_none_or(|l| ts.as_str() > l) {
+                entry.last_seen = Some(ts.clone());
+            }
+        }
+    }
+
+    let trend_days = trend_days.max(1);
+    let window_start = today - chrono::Duration::days(trend_days as i64 - 1);
+    let days: Vec<NaiveDate> = (0..trend_days)
+        .map(|offset| window_start + chrono::Duration::days(offset as i64))
+        .collect();
+
+    let mut stats: Vec<GroupStats> = groups
+        .into_iter()
+        .map(|(category, accum)| {
+            let recent_trend = days
+                .iter()
+                .map(|day| {
+                    let count = pairs
+                        .iter()
+                        .filter(|p| group_by.pair_key(p) == category)
+                        .filter(|p| {
+                            p.timestamp
+                                .as_deref()
+                                .and_then(parse_event_day)
+                                .is_some_and(|d| d == *day)
+                        })
+                        .count();
+                    DayCount {
+                        day: day.format("%Y-%m-%d").to_string(),
+                        count,
+                    }
+                })
+                .collect();
+
+            GroupStats {
+                category,
+                occurrences: accum.occurrences,
+                distinct_commands: accum.commands.len(),
+                first_seen: accum.first_seen,
+                last_seen: accum.last_seen,
+                recent_trend,
+            }
+        })
+        .collect();
+
+    stats.sort_by(|a, b| b.occurrences.cmp(&a.occurrences));
+    stats
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -434,6 +924,14 @@ mod tests {
             extract_base_command("RUST_BACKTRACE=1 cargo test"),
             "cargo test"
         );
+        assert_eq!(extract_base_command("FOO=1 cargo test"), "cargo test");
+        assert_eq!(extract_base_command("sudo git commit"), "git commit");
+        assert_eq!(extract_base_command("env X=1 make"), "make");
+        assert_eq!(extract_base_command("time cargo build"), "cargo build");
+        assert_eq!(
+            extract_base_command("sudo FOO=1 nice cargo build --release"),
+            "cargo build"
+        );
     }
 
     #[test]
@@ -441,10 +939,31 @@ mod tests {
         assert_eq!(command_similarity("git commit", "git commit"), 1.0);
         assert_eq!(command_similarity("git status", "npm install"), 0.0);
         let sim = command_similarity("git commit --amend", "git commit --ammend");
-        // Debug: check what similarity actually is
-        println!("Similarity: {}", sim);
-        // Same base (0.5) + both have 1 arg, 0 intersection = 0.5 + 0 = 0.5
-        assert_eq!(sim, 0.5);
+        // Same base (0.5) + edit-distance credit for the "--amend"/"--ammend"
+        // typo pair (distance 1 over max len 8) instead of zero overlap.
+        assert!((sim - 0.71875).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_damerau_levenshtein_basic() {
+        let weights = EditWeights::default();
+        let dist = |a: &str, b: &str| {
+            let a: Vec<char> = a.chars().collect();
+            let b: Vec<char> = b.chars().collect();
+            damerau_levenshtein(&a, &b, weights)
+        };
+        assert_eq!(dist("amend", "amend"), 0);
+        assert_eq!(dist("ammend", "amend"), 1); // one extra char
+        assert_eq!(dist("ab", "ba"), 0); // adjacent transposition, swap=0
+        assert_eq!(dist("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_token_edit_similarity() {
+        assert_eq!(token_edit_similarity("", ""), 1.0);
+        assert_eq!(token_edit_similarity("amend", "amend"), 1.0);
+        assert!(token_edit_similarity("--amend", "--ammend") > 0.8);
+        assert!(token_edit_similarity("--amend", "--force") < 0.5);
     }
 
     #[test]
@@ -454,11 +973,13 @@ mod tests {
                 command: "git commit --ammend".to_string(),
                 is_error: true,
                 output: "error: unexpected argument '--ammend'".to_string(),
+                timestamp: None,
             },
             CommandExecution {
                 command: "git commit --amend".to_string(),
                 is_error: false,
                 output: "[main abc123] Fix bug".to_string(),
+                timestamp: None,
             },
         ];
 
@@ -476,27 +997,32 @@ mod tests {
                 command: "git commit --ammend".to_string(),
                 is_error: true,
                 output: "error: unexpected argument '--ammend'".to_string(),
+                timestamp: None,
             },
             CommandExecution {
                 command: "ls".to_string(),
                 is_error: false,
                 output: "file1.txt\nfile2.txt".to_string(),
+                timestamp: None,
             },
             CommandExecution {
                 command: "pwd".to_string(),
                 is_error: false,
                 output: "/home/user".to_string(),
+                timestamp: None,
             },
             CommandExecution {
                 command: "echo test".to_string(),
                 is_error: false,
                 output: "test".to_string(),
+                timestamp: None,
             },
             // Outside CORRECTION_WINDOW (3)
             CommandExecution {
                 command: "git commit --amend".to_string(),
                 is_error: false,
                 output: "[main abc123] Fix".to_string(),
+                timestamp: None,
             },
         ];
 
@@ -511,11 +1037,13 @@ mod tests {
                 command: "cargo test".to_string(),
                 is_error: true,
                 output: "error[E0425]: cannot find value `x`\ntest result: FAILED".to_string(),
+                timestamp: None,
             },
             CommandExecution {
                 command: "cargo test".to_string(),
                 is_error: false,
                 output: "test result: ok. 5 passed".to_string(),
+                timestamp: None,
             },
         ];
 
@@ -530,11 +1058,13 @@ mod tests {
                 command: "cat file1.txt".to_string(),
                 is_error: true,
                 output: "cat: file1.txt: No such file or directory".to_string(),
+                timestamp: None,
             },
             CommandExecution {
                 command: "cat file2.txt".to_string(),
                 is_error: false,
                 output: "content here".to_string(),
+                timestamp: None,
             },
         ];
 
@@ -552,11 +1082,13 @@ mod tests {
                 command: "git commit --foo --bar --baz".to_string(),
                 is_error: true,
                 output: "error: unexpected argument '--foo'".to_string(),
+                timestamp: None,
             },
             CommandExecution {
                 command: "git commit --qux".to_string(),
                 is_error: false,
                 output: "[main abc123] Fix".to_string(),
+                timestamp: None,
             },
         ];
 
@@ -576,6 +1108,7 @@ mod tests {
                 error_output: "error: unexpected argument '--ammend'".to_string(),
                 error_type: ErrorType::UnknownFlag,
                 confidence: 0.8,
+                timestamp: None,
             },
             CorrectionPair {
                 wrong_command: "git commit --ammend -m 'fix'".to_string(),
@@ -583,6 +1116,7 @@ mod tests {
                 error_output: "error: unexpected argument '--ammend'".to_string(),
                 error_type: ErrorType::UnknownFlag,
                 confidence: 0.9,
+                timestamp: None,
             },
             CorrectionPair {
                 wrong_command: "git commit --ammend".to_string(),
@@ -590,6 +1124,7 @@ mod tests {
                 error_output: "error: unexpected argument '--ammend'".to_string(),
                 error_type: ErrorType::UnknownFlag,
                 confidence: 0.7,
+                timestamp: None,
             },
         ];
 
@@ -610,6 +1145,7 @@ mod tests {
                 error_output: "error: unexpected argument '--ammend'".to_string(),
                 error_type: ErrorType::UnknownFlag,
                 confidence: 0.8,
+                timestamp: None,
             },
             CorrectionPair {
                 wrong_command: "git push --force".to_string(),
@@ -617,6 +1153,7 @@ mod tests {
                 error_output: "error: --force is dangerous".to_string(),
                 error_type: ErrorType::WrongSyntax,
                 confidence: 0.7,
+                timestamp: None,
             },
         ];
 
@@ -625,4 +1162,225 @@ mod tests {
         assert_eq!(rules[0].occurrences, 1);
         assert_eq!(rules[1].occurrences, 1);
     }
+
+    #[test]
+    fn test_group_by_parse() {
+        assert_eq!(GroupBy::parse("error_type"), Some(GroupBy::ErrorType));
+        assert_eq!(GroupBy::parse("base_command"), Some(GroupBy::BaseCommand));
+        assert_eq!(GroupBy::parse("bogus"), None);
+    }
+
+    fn pair_with_ts(
+        wrong: &str,
+        right: &str,
+        error_type: ErrorType,
+        timestamp: &str,
+    ) -> CorrectionPair {
+        CorrectionPair {
+            wrong_command: wrong.to_string(),
+            right_command: right.to_string(),
+            error_output: "error".to_string(),
+            error_type,
+            confidence: 0.8,
+            timestamp: Some(timestamp.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_group_rules_by_error_type_aggregates_occurrences_and_trend() {
+        let pairs = vec![
+            pair_with_ts(
+                "git commit --ammend",
+                "git commit --amend",
+                ErrorType::UnknownFlag,
+                "2026-02-12T10:00:00Z",
+            ),
+            pair_with_ts(
+                "git commit --ammend",
+                "git commit --amend",
+                ErrorType::UnknownFlag,
+                "2026-02-13T09:00:00Z",
+            ),
+            pair_with_ts(
+                "npm isntall",
+                "npm install",
+                ErrorType::UnknownFlag,
+                "2026-02-13T11:00:00Z",
+            ),
+            pair_with_ts(
+                "git push --force",
+                "git push --force-with-lease",
+                ErrorType::WrongSyntax,
+                "2026-02-13T12:00:00Z",
+            ),
+        ];
+        let rules = deduplicate_corrections(pairs.clone());
+
+        let today = NaiveDate::from_ymd_opt(2026, 2, 13).unwrap();
+        let groups = group_rules(&rules, &pairs, GroupBy::ErrorType, 2, today);
+
+        assert_eq!(groups.len(), 2);
+        let unknown_flag = groups
+            .iter()
+            .find(|g| g.category == "Unknown Flag")
+            .unwrap();
+        assert_eq!(unknown_flag.occurrences, 3);
+        assert_eq!(unknown_flag.distinct_commands, 2);
+        assert_eq!(unknown_flag.first_seen.as_deref(), Some("2026-02-12T10:00:00Z"));
+        assert_eq!(unknown_flag.last_seen.as_deref(), Some("2026-02-13T11:00:00Z"));
+        assert_eq!(unknown_flag.recent_trend.len(), 2);
+        assert_eq!(unknown_flag.recent_trend[0].day, "2026-02-12");
+        assert_eq!(unknown_flag.recent_trend[0].count, 1);
+        assert_eq!(unknown_flag.recent_trend[1].day, "2026-02-13");
+        assert_eq!(unknown_flag.recent_trend[1].count, 2);
+
+        // Sorted by total occurrences descending.
+        assert_eq!(groups[0].category, "Unknown Flag");
+    }
+
+    #[test]
+    fn test_group_rules_by_base_command() {
+        let pairs = vec![pair_with_ts(
+            "git commit --ammend",
+            "git commit --amend",
+            ErrorType::UnknownFlag,
+            "2026-02-13T10:00:00Z",
+        )];
+        let rules = deduplicate_corrections(pairs.clone());
+        let today = NaiveDate::from_ymd_opt(2026, 2, 13).unwrap();
+
+        let groups = group_rules(&rules, &pairs, GroupBy::BaseCommand, 1, today);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].category, "git commit");
+        assert_eq!(groups[0].occurrences, 1);
+    }
+
+    fn amend_rule(occurrences: usize) -> CorrectionRule {
+        CorrectionRule {
+            wrong_pattern: "git commit --ammend".to_string(),
+            right_pattern: "git commit --amend".to_string(),
+            error_type: ErrorType::UnknownFlag,
+            occurrences,
+            base_command: "git commit".to_string(),
+            example_error: "error: unexpected argument '--ammend'".to_string(),
+            first_seen: None,
+            last_seen: None,
+        }
+    }
+
+    #[test]
+    fn test_suggest_correction_applies_learned_fix() {
+        let rules = vec![amend_rule(5)];
+        let correction = suggest_correction(
+            "git commit --ammend -m 'fix'",
+            "error: unexpected argument '--ammend'",
+            &rules,
+        )
+        .expect("expected a suggestion");
+
+        assert_eq!(correction.suggested_command, "git commit --amend -m 'fix'");
+        assert_eq!(correction.base_command, "git commit");
+        assert!(correction.confidence >= AUTOCORRECT_MIN_CONFIDENCE);
+    }
+
+    #[test]
+    fn test_suggest_correction_requires_matching_base_and_error_type() {
+        let rules = vec![amend_rule(5)];
+
+        // Different base command.
+        assert!(suggest_correction(
+            "npm install --ammend",
+            "error: unexpected argument '--ammend'",
+            &rules
+        )
+        .is_none());
+
+        // Different error type (wrong path, not unknown flag).
+        assert!(suggest_correction(
+            "git commit --ammend",
+            "No such file or directory",
+            &rules
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_suggest_correction_no_op_rewrite_is_dropped() {
+        // Already-correct command: the rewrite is a no-op, so nothing to suggest.
+        let rules = vec![amend_rule(5)];
+        assert!(suggest_correction(
+            "git commit --amend",
+            "error: unexpected argument '--ammend'",
+            &rules
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_suggest_corrections_ranks_by_confidence() {
+        let rules = vec![amend_rule(1), amend_rule(50)];
+        let suggestions = suggest_corrections(
+            "git commit --ammend",
+            "error: unexpected argument '--ammend'",
+            &rules,
+            0.0,
+        );
+
+        assert_eq!(suggestions.len(), 2);
+        assert!(suggestions[0].confidence >= suggestions[1].confidence);
+        assert_eq!(suggestions[0].occurrences, 50);
+    }
+
+    #[test]
+    fn test_diff_tokens_single_replace() {
+        let wrong = vec!["git", "commit", "--ammend"];
+        let right = vec!["git", "commit", "--amend"];
+        let edits = diff_tokens(&wrong, &right);
+        assert_eq!(
+            edits,
+            vec![TokenEdit::Replace {
+                pos: 2,
+                old: "--ammend".to_string(),
+                new: "--amend".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_diff_tokens_insert_and_delete_stay_separate_when_not_adjacent() {
+        let wrong = vec!["npm", "install"];
+        let right = vec!["npm", "ci", "--force"];
+        let edits = diff_tokens(&wrong, &right);
+        assert_eq!(
+            edits,
+            vec![
+                TokenEdit::Replace {
+                    pos: 1,
+                    old: "install".to_string(),
+                    new: "ci".to_string(),
+                },
+                TokenEdit::Insert {
+                    pos: 2,
+                    token: "--force".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_signature_distinguishes_position() {
+        // Same token-level change ("a" -> "b") but at different slots should
+        // not collapse into the same signature.
+        let sig1 = diff_signature("cmd a x", "cmd b x");
+        let sig2 = diff_signature("cmd x a", "cmd x b");
+        assert_ne!(sig1, sig2);
+    }
+
+    #[test]
+    fn test_diff_signature_multi_change_is_distinct_from_single_change() {
+        let flag_rename_only = diff_signature("git commit --ammend", "git commit --amend");
+        let flag_rename_plus_arg =
+            diff_signature("git commit --ammend", "git commit --amend --no-edit");
+        assert_ne!(flag_rename_only, flag_rename_plus_arg);
+    }
 }
@@ -1,11 +1,13 @@
 pub mod detector;
 pub mod report;
+pub mod store;
 
 use crate::discover::provider::{ClaudeProvider, SessionProvider};
 use anyhow::Result;
-use detector::{deduplicate_corrections, find_corrections, CommandExecution};
-use report::{format_console_report, write_rules_file};
+use detector::{deduplicate_corrections, find_corrections, group_rules, CommandExecution, GroupBy};
+use report::{format_console_report, format_group_report, write_rules_file};
 
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     project: Option<String>,
     all: bool,
@@ -14,6 +16,7 @@ pub fn run(
     write_rules: bool,
     min_confidence: f64,
     min_occurrences: usize,
+    group_by: Option<String>,
 ) -> Result<()> {
     let provider = ClaudeProvider;
 
@@ -54,6 +57,7 @@ pub fn run(
                     command: ext_cmd.command,
                     is_error: ext_cmd.is_error,
                     output,
+                    timestamp: ext_cmd.timestamp,
                 });
             }
         }
@@ -85,6 +89,23 @@ pub fn run(
     // Filter by occurrences
     rules.retain(|r| r.occurrences >= min_occurrences);
 
+    // Partition into per-category groups when --group-by was given, with a
+    // recent-trend breakdown covering the same `since`-day window sessions
+    // were discovered over.
+    let groups = match group_by.as_deref() {
+        Some(raw) => match GroupBy::parse(raw) {
+            Some(group_by) => {
+                let today = chrono::Utc::now().date_naive();
+                group_rules(&rules, &filtered, group_by, since, today)
+            }
+            None => anyhow::bail!(
+                "unknown --group-by value '{}' (expected 'error_type' or 'base_command')",
+                raw
+            ),
+        },
+        None => Vec::new(),
+    };
+
     // Output
     match format.as_str() {
         "json" => {
@@ -99,6 +120,17 @@ pub fn run(
                     "occurrences": r.occurrences,
                     "base_command": r.base_command,
                 })).collect::<Vec<_>>(),
+                "groups": groups.iter().map(|g| serde_json::json!({
+                    "category": g.category,
+                    "occurrences": g.occurrences,
+                    "distinct_commands": g.distinct_commands,
+                    "first_seen": g.first_seen,
+                    "last_seen": g.last_seen,
+                    "recent_trend": g.recent_trend.iter().map(|d| serde_json::json!({
+                        "day": d.day,
+                        "count": d.count,
+                    })).collect::<Vec<_>>(),
+                })).collect::<Vec<_>>(),
             });
             println!("{}", serde_json::to_string_pretty(&json)?);
         }
@@ -107,6 +139,10 @@ pub fn run(
             let report = format_console_report(&rules, filtered.len(), sessions.len(), since);
             print!("{}", report);
 
+            if let Some(raw) = group_by.as_deref() {
+                print!("{}", format_group_report(&groups, raw));
+            }
+
             if write_rules && !rules.is_empty() {
                 let rules_path = ".claude/rules/cli-corrections.md";
                 write_rules_file(&rules, rules_path)?;
@@ -0,0 +1,134 @@
+use super::detector::{CorrectionRule, GroupStats};
+use anyhow::{Context, Result};
+use std::fs;
+
+/// Render the text-mode correction report: a table of deduplicated rules,
+/// one row per distinct wrong -> right pattern.
+pub fn format_console_report(
+    rules: &[CorrectionRule],
+    total_corrections: usize,
+    sessions_scanned: usize,
+    since_days: u64,
+) -> String {
+    let mut out = String::with_capacity(1024);
+
+    out.push_str("RTK Learn -- CLI Correction Patterns\n");
+    out.push_str(&"=".repeat(52));
+    out.push('\n');
+    out.push_str(&format!(
+        "Scanned: {} sessions (last {} days), {} corrections detected\n",
+        sessions_scanned, since_days, total_corrections
+    ));
+
+    if rules.is_empty() {
+        out.push_str("\nNo recurring correction patterns found.\n");
+        return out;
+    }
+
+    out.push_str(&format!("\n{} correction rule(s) found:\n", rules.len()));
+    out.push_str(&"-".repeat(72));
+    out.push('\n');
+    out.push_str(&format!(
+        "{:<16} {:<18} {:>4}  {}\n",
+        "Base Cmd", "Error Type", "Occ", "Wrong -> Right"
+    ));
+
+    for rule in rules {
+        out.push_str(&format!(
+            "{:<16} {:<18} {:>4}  {} -> {}\n",
+            truncate_str(&rule.base_command, 15),
+            truncate_str(rule.error_type.as_str(), 17),
+            rule.occurrences,
+            truncate_str(&rule.wrong_pattern, 28),
+            truncate_str(&rule.right_pattern, 28),
+        ));
+    }
+
+    out.push_str(&"-".repeat(72));
+    out.push('\n');
+
+    out
+}
+
+/// Render the `--group-by` breakdown: one section per category, with its
+/// total occurrences, distinct commands, first/last seen, and a compact
+/// recent-trend line.
+pub fn format_group_report(groups: &[GroupStats], group_by_label: &str) -> String {
+    let mut out = String::with_capacity(512);
+
+    if groups.is_empty() {
+        return out;
+    }
+
+    out.push_str(&format!("\nGROUPED BY {}\n", group_by_label));
+    out.push_str(&"-".repeat(72));
+    out.push('\n');
+    out.push_str(&format!(
+        "{:<24} {:>4} {:>9}  {:<10} {:<10}  Recent\n",
+        "Category", "Occ", "Distinct", "First Seen", "Last Seen"
+    ));
+
+    for group in groups {
+        let trend: String = group
+            .recent_trend
+            .iter()
+            .map(|d| d.count.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        out.push_str(&format!(
+            "{:<24} {:>4} {:>9}  {:<10} {:<10}  {}\n",
+            truncate_str(&group.category, 23),
+            group.occurrences,
+            group.distinct_commands,
+            group.first_seen.as_deref().unwrap_or("-").get(..10).unwrap_or("-"),
+            group.last_seen.as_deref().unwrap_or("-").get(..10).unwrap_or("-"),
+            trend,
+        ));
+    }
+
+    out.push_str(&"-".repeat(72));
+    out.push('\n');
+
+    out
+}
+
+/// Write deduplicated rules to a markdown rules file Claude Code can load
+/// as project guidance (e.g. `.claude/rules/cli-corrections.md`).
+pub fn write_rules_file(rules: &[CorrectionRule], path: &str) -> Result<()> {
+    let mut out = String::with_capacity(1024);
+
+    out.push_str("# CLI Corrections\n\n");
+    out.push_str("Learned from past CLI mistakes in this project. Prefer the right-hand form.\n\n");
+
+    for rule in rules {
+        out.push_str(&format!(
+            "- `{}` -> `{}` ({}, seen {}x)\n",
+            rule.wrong_pattern,
+            rule.right_pattern,
+            rule.error_type.as_str(),
+            rule.occurrences,
+        ));
+    }
+
+    if let Some(parent) = std::path::Path::new(path).parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+
+    fs::write(path, out).with_context(|| format!("failed to write {}", path))
+}
+
+fn truncate_str(s: &str, max: usize) -> String {
+    if s.len() <= max {
+        s.to_string()
+    } else {
+        // UTF-8 safe truncation: collect chars up to max-2, then add ".."
+        let truncated: String = s
+            .char_indices()
+            .take_while(|(i, _)| *i < max.saturating_sub(2))
+            .map(|(_, c)| c)
+            .collect();
+        format!("{}..", truncated)
+    }
+}
@@ -0,0 +1,295 @@
+//! Durable, mergeable storage for mined [`CorrectionRule`]s. Letting the
+//! in-memory output of a single session's `rtk learn` get serialized,
+//! committed to a repo, and merged with teammates' rulebases turns it into
+//! a "learned mistakes" database that accumulates frequency evidence
+//! across machines instead of being recomputed from scratch each run.
+use super::detector::{
+    deduplicate_corrections, diff_signature, CorrectionPair, CorrectionRule, ErrorType,
+};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// On-disk form of a [`CorrectionRule`]. Kept as its own type so adding a
+/// field to one doesn't silently change the other's wire format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredRule {
+    pub wrong_pattern: String,
+    pub right_pattern: String,
+    pub error_type: ErrorType,
+    pub occurrences: usize,
+    pub base_command: String,
+    pub example_error: String,
+    pub first_seen: Option<String>,
+    pub last_seen: Option<String>,
+}
+
+impl From<&CorrectionRule> for StoredRule {
+    fn from(rule: &CorrectionRule) -> Self {
+        StoredRule {
+            wrong_pattern: rule.wrong_pattern.clone(),
+            right_pattern: rule.right_pattern.clone(),
+            error_type: rule.error_type.clone(),
+            occurrences: rule.occurrences,
+            base_command: rule.base_command.clone(),
+            example_error: rule.example_error.clone(),
+            first_seen: rule.first_seen.clone(),
+            last_seen: rule.last_seen.clone(),
+        }
+    }
+}
+
+impl From<StoredRule> for CorrectionRule {
+    fn from(rule: StoredRule) -> Self {
+        CorrectionRule {
+            wrong_pattern: rule.wrong_pattern,
+            right_pattern: rule.right_pattern,
+            error_type: rule.error_type,
+            occurrences: rule.occurrences,
+            base_command: rule.base_command,
+            example_error: rule.example_error,
+            first_seen: rule.first_seen,
+            last_seen: rule.last_seen,
+        }
+    }
+}
+
+/// The same `(base_command, error_type, diff signature)` key
+/// `deduplicate_corrections` groups by, reused here so merging a rulebase
+/// is exactly "union by that key, sum occurrences" rather than a second,
+/// subtly different notion of "the same mistake".
+fn rule_key(rule: &StoredRule) -> (String, String, String) {
+    (
+        rule.base_command.clone(),
+        rule.error_type.as_str().to_string(),
+        diff_signature(&rule.wrong_pattern, &rule.right_pattern),
+    )
+}
+
+/// RFC 3339 timestamps sort correctly as plain strings, so picking the
+/// earlier/later of two optional timestamps needs no date parsing.
+fn earlier(a: Option<String>, b: Option<String>) -> Option<String> {
+    match (a, b) {
+        (Some(x), Some(y)) => Some(x.min(y)),
+        (Some(x), None) | (None, Some(x)) => Some(x),
+        (None, None) => None,
+    }
+}
+
+fn later(a: Option<String>, b: Option<String>) -> Option<String> {
+    match (a, b) {
+        (Some(x), Some(y)) => Some(x.max(y)),
+        (Some(x), None) | (None, Some(x)) => Some(x),
+        (None, None) => None,
+    }
+}
+
+/// A versioned, mergeable collection of [`StoredRule`]s, serialized as
+/// JSON so it can be committed to a repo and pulled on a new checkout.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Rulebase {
+    #[serde(default = "default_rulebase_version")]
+    pub version: u32,
+    #[serde(default)]
+    pub rules: Vec<StoredRule>,
+}
+
+fn default_rulebase_version() -> u32 {
+    RULEBASE_VERSION
+}
+
+/// Bump when a field is removed or its meaning changes; additive fields
+/// don't need a bump since `#[serde(default)]` already tolerates them.
+pub const RULEBASE_VERSION: u32 = 1;
+
+impl Rulebase {
+    /// Load a rulebase from `path`, or an empty one if it doesn't exist
+    /// yet (a fresh checkout with no prior learned mistakes).
+    pub fn load(path: &str) -> Result<Self> {
+        if !Path::new(path).exists() {
+            return Ok(Rulebase {
+                version: RULEBASE_VERSION,
+                rules: Vec::new(),
+            });
+        }
+
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path))?;
+        serde_json::from_str(&content).with_context(|| format!("failed to parse {}", path))
+    }
+
+    /// Write this rulebase to `path`, creating parent directories as
+    /// needed (mirrors `write_rules_file`'s `.claude/rules/...` handling).
+    pub fn save(&self, path: &str) -> Result<()> {
+        if let Some(parent) = Path::new(path).parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, content).with_context(|| format!("failed to write {}", path))
+    }
+
+    /// Union `other`'s rules into this one by `(base_command, error_type,
+    /// diff signature)`, summing `occurrences` and widening
+    /// `first_seen`/`last_seen` rather than overwriting, so frequency
+    /// evidence accumulates across machines instead of the last import
+    /// winning.
+    pub fn merge(&mut self, other: Rulebase) {
+        let mut index: HashMap<(String, String, String), usize> = self
+            .rules
+            .iter()
+            .enumerate()
+            .map(|(i, rule)| (rule_key(rule), i))
+            .collect();
+
+        for incoming in other.rules {
+            let key = rule_key(&incoming);
+            if let Some(&i) = index.get(&key) {
+                let existing = &mut self.rules[i];
+                existing.occurrences += incoming.occurrences;
+                existing.first_seen = earlier(existing.first_seen.take(), incoming.first_seen);
+                existing.last_seen = later(existing.last_seen.take(), incoming.last_seen);
+            } else {
+                index.insert(key, self.rules.len());
+                self.rules.push(incoming);
+            }
+        }
+
+        self.rules.sort_by(|a, b| b.occurrences.cmp(&a.occurrences));
+    }
+
+    /// Re-run dedup against newly mined correction pairs and merge the
+    /// result into this store in place - the incremental counterpart to
+    /// recomputing `deduplicate_corrections` from a single-session window
+    /// each time.
+    pub fn update_with(&mut self, pairs: Vec<CorrectionPair>) {
+        let incoming = Rulebase {
+            version: RULEBASE_VERSION,
+            rules: deduplicate_corrections(pairs).iter().map(StoredRule::from).collect(),
+        };
+        self.merge(incoming);
+    }
+
+    /// The stored rules as the in-memory [`CorrectionRule`] type the rest
+    /// of `learn` already knows how to render and apply.
+    pub fn to_rules(&self) -> Vec<CorrectionRule> {
+        self.rules.iter().cloned().map(CorrectionRule::from).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn amend_rule(occurrences: usize, first_seen: &str, last_seen: &str) -> StoredRule {
+        StoredRule {
+            wrong_pattern: "git commit --ammend".to_string(),
+            right_pattern: "git commit --amend".to_string(),
+            error_type: ErrorType::UnknownFlag,
+            occurrences,
+            base_command: "git commit".to_string(),
+            example_error: "error: unexpected argument '--ammend'".to_string(),
+            first_seen: Some(first_seen.to_string()),
+            last_seen: Some(last_seen.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_rulebase_save_load_roundtrip() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("rulebase.json");
+        let path = path.to_str().unwrap();
+
+        let rulebase = Rulebase {
+            version: RULEBASE_VERSION,
+            rules: vec![amend_rule(3, "2026-01-01T00:00:00Z", "2026-01-03T00:00:00Z")],
+        };
+        rulebase.save(path).unwrap();
+
+        let loaded = Rulebase::load(path).unwrap();
+        assert_eq!(loaded.version, RULEBASE_VERSION);
+        assert_eq!(loaded.rules.len(), 1);
+        assert_eq!(loaded.rules[0].occurrences, 3);
+        assert_eq!(loaded.rules[0].wrong_pattern, "git commit --ammend");
+    }
+
+    #[test]
+    fn test_rulebase_load_missing_file_is_empty() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("does-not-exist.json");
+        let loaded = Rulebase::load(path.to_str().unwrap()).unwrap();
+        assert!(loaded.rules.is_empty());
+    }
+
+    #[test]
+    fn test_merge_sums_occurrences_and_widens_seen_range() {
+        let mut base = Rulebase {
+            version: RULEBASE_VERSION,
+            rules: vec![amend_rule(2, "2026-01-05T00:00:00Z", "2026-01-06T00:00:00Z")],
+        };
+        let incoming = Rulebase {
+            version: RULEBASE_VERSION,
+            rules: vec![amend_rule(5, "2026-01-01T00:00:00Z", "2026-01-10T00:00:00Z")],
+        };
+
+        base.merge(incoming);
+
+        assert_eq!(base.rules.len(), 1);
+        assert_eq!(base.rules[0].occurrences, 7);
+        assert_eq!(base.rules[0].first_seen.as_deref(), Some("2026-01-01T00:00:00Z"));
+        assert_eq!(base.rules[0].last_seen.as_deref(), Some("2026-01-10T00:00:00Z"));
+    }
+
+    #[test]
+    fn test_merge_keeps_distinct_rules_separate() {
+        let mut base = Rulebase {
+            version: RULEBASE_VERSION,
+            rules: vec![amend_rule(1, "2026-01-01T00:00:00Z", "2026-01-01T00:00:00Z")],
+        };
+        let mut other_rule = amend_rule(1, "2026-01-01T00:00:00Z", "2026-01-01T00:00:00Z");
+        other_rule.base_command = "npm install".to_string();
+        other_rule.wrong_pattern = "npm install".to_string();
+        other_rule.right_pattern = "npm ci".to_string();
+
+        base.merge(Rulebase {
+            version: RULEBASE_VERSION,
+            rules: vec![other_rule],
+        });
+
+        assert_eq!(base.rules.len(), 2);
+    }
+
+    #[test]
+    fn test_update_with_dedupes_and_merges() {
+        let mut base = Rulebase::default();
+        let pairs = vec![
+            CorrectionPair {
+                wrong_command: "git commit --ammend".to_string(),
+                right_command: "git commit --amend".to_string(),
+                error_output: "error: unexpected argument '--ammend'".to_string(),
+                error_type: ErrorType::UnknownFlag,
+                confidence: 0.9,
+                timestamp: Some("2026-01-01T00:00:00Z".to_string()),
+            },
+            CorrectionPair {
+                wrong_command: "git commit --ammend".to_string(),
+                right_command: "git commit --amend".to_string(),
+                error_output: "error: unexpected argument '--ammend'".to_string(),
+                error_type: ErrorType::UnknownFlag,
+                confidence: 0.9,
+                timestamp: Some("2026-01-02T00:00:00Z".to_string()),
+            },
+        ];
+
+        base.update_with(pairs);
+
+        assert_eq!(base.rules.len(), 1);
+        assert_eq!(base.rules[0].occurrences, 2);
+
+        let rules = base.to_rules();
+        assert_eq!(rules[0].wrong_pattern, "git commit --ammend");
+    }
+}
@@ -1,10 +1,15 @@
+use crate::parser::{JsonFormatter, LintIssue, LintResult, LintSeverity};
 use crate::ruff_cmd;
 use crate::tracking;
 use crate::utils::{package_manager_exec, truncate};
 use anyhow::{Context, Result};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde_json::{json, Value};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::path::Path;
 use std::process::Command;
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -37,7 +42,6 @@ struct PylintDiagnostic {
     #[allow(dead_code)]
     obj: String,
     line: usize,
-    #[allow(dead_code)]
     column: usize,
     path: String,
     symbol: String, // rule code like "unused-variable"
@@ -46,22 +50,344 @@ struct PylintDiagnostic {
     message_id: String, // e.g., "W0612"
 }
 
+/// Output format for `rtk lint`, selected with `--rtk-format=<value>`.
+/// `Pretty` is the default grouped-by-rule/file summary; `Json` and `Sarif`
+/// give an agent (or CI step) a stable machine-readable stream instead of
+/// scraping the human text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Pretty,
+    Json,
+    Sarif,
+}
+
+impl Format {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pretty" => Some(Format::Pretty),
+            "json" => Some(Format::Json),
+            "sarif" => Some(Format::Sarif),
+            _ => None,
+        }
+    }
+
+    fn reporter(&self) -> Box<dyn Reporter> {
+        match self {
+            Format::Pretty => Box::new(PrettyReporter),
+            Format::Json => Box::new(JsonReporter),
+            Format::Sarif => Box::new(SarifReporter),
+        }
+    }
+}
+
+/// `--rtk-format=<pretty|json|sarif>` is an rtk-only flag (not forwarded to
+/// the wrapped linter) that picks which [`Reporter`] renders the parsed
+/// diagnostics.
+const FORMAT_FLAG_PREFIX: &str = "--rtk-format=";
+
+/// `--baseline <path>` filters the printed diagnostics down to ones not
+/// already recorded at `<path>`; `--update-baseline` instead (re)writes
+/// `<path>` with the full current diagnostic set. Only applies to linters
+/// whose output we parse into [`LintIssue`] ourselves (eslint/pylint/mypy) -
+/// same scope as the incremental cache.
+const BASELINE_FLAG: &str = "--baseline";
+const UPDATE_BASELINE_FLAG: &str = "--update-baseline";
+
+/// `--include <pattern>`/`--exclude <pattern>` (each repeatable) narrow
+/// reported diagnostics down to matching files, on top of whatever
+/// `.rtklintignore` already excludes. Same scope as the baseline/cache -
+/// only linters parsed into [`LintIssue`] ourselves (eslint/pylint/mypy).
+const INCLUDE_FLAG: &str = "--include";
+const EXCLUDE_FLAG: &str = "--exclude";
+
+/// Project-local, committable pattern file: one pattern per line, either
+/// `path:<prefix>` (a literal directory prefix) or a glob. Lines starting
+/// with `#` and blank lines are ignored. Mirrors `.gitignore` in spirit but
+/// only controls which diagnostics are *reported*, not what the underlying
+/// linter scans.
+const LINT_IGNORE_FILE: &str = ".rtklintignore";
+
+/// `--adapter <mapping>` normalizes an arbitrary JSON-emitting linter into
+/// [`LintIssue`]s without a bespoke parser: `<mapping>` is a comma-separated
+/// list of `canonical=json_key` pairs (canonical fields: `file`, `code`,
+/// `line`, `column`, `message`), e.g. `file=path,code=rule,line=lineno`.
+const ADAPTER_FLAG: &str = "--adapter";
+
+/// `--max-warnings <N>` caps how many aggregated warnings `rtk lint` (across
+/// eslint/pylint/mypy/flake8/adapter - the linters we parse into
+/// [`LintIssue`]) can report before exiting non-zero; `--error-on-warnings`
+/// is shorthand for `--max-warnings 0`. Any aggregated error always exits
+/// non-zero regardless of these flags, matching eslint/deno lint's own exit
+/// codes so `rtk lint` can gate CI directly.
+const MAX_WARNINGS_FLAG: &str = "--max-warnings";
+const ERROR_ON_WARNINGS_FLAG: &str = "--error-on-warnings";
+
+/// Renders a parsed diagnostic list for one `rtk lint` invocation. Each
+/// linter's output is normalized into `Vec<LintIssue>` first, so adding a
+/// new output format is just a matter of writing one more `Reporter`.
+trait Reporter {
+    fn render(&self, label: &str, issues: &[LintIssue]) -> String;
+}
+
+struct PrettyReporter;
+
+impl Reporter for PrettyReporter {
+    fn render(&self, label: &str, issues: &[LintIssue]) -> String {
+        render_pretty(label, issues)
+    }
+}
+
+struct JsonReporter;
+
+impl Reporter for JsonReporter {
+    fn render(&self, _label: &str, issues: &[LintIssue]) -> String {
+        render_json(issues)
+    }
+}
+
+struct SarifReporter;
+
+impl Reporter for SarifReporter {
+    fn render(&self, _label: &str, issues: &[LintIssue]) -> String {
+        render_sarif(issues)
+    }
+}
+
 /// Check if a linter is Python-based (uses pip/pipx, not npm/pnpm)
 fn is_python_linter(linter: &str) -> bool {
     matches!(linter, "ruff" | "pylint" | "mypy" | "flake8")
 }
 
+/// Which linter `run`/`run_watch` dispatch to: the first non-flag,
+/// non-path-looking argument, defaulting to eslint when none is given.
+fn detect_linter(args: &[String]) -> &str {
+    let is_path_or_flag = args.is_empty()
+        || args[0].starts_with('-')
+        || args[0].contains('/')
+        || args[0].contains('.');
+
+    if is_path_or_flag {
+        "eslint"
+    } else {
+        &args[0]
+    }
+}
+
 pub fn run(args: &[String], verbose: u8) -> Result<()> {
+    let watch = args.iter().any(|a| a == "--watch");
+    if watch {
+        let args: Vec<String> = args.iter().filter(|a| *a != "--watch").cloned().collect();
+        return run_watch(&args, verbose);
+    }
+    run_once(args, verbose)
+}
+
+/// Block on filesystem changes and re-run the linter for each burst of
+/// edits, debounced so a save's many touch events collapse into one pass.
+/// Only re-runs when a touched path matches the selected linter's tracked
+/// extensions, so churn elsewhere in the tree (e.g. `.git`) doesn't trigger
+/// a pass. Combined with the incremental cache, each re-run only
+/// re-analyzes what actually changed.
+fn run_watch(args: &[String], verbose: u8) -> Result<()> {
+    use notify::{RecursiveMode, Watcher};
+    use std::sync::mpsc::channel;
+    use std::time::Duration;
+
+    let linter = detect_linter(args);
+    let exts = target_extensions(linter);
+    let watch_root = Path::new(".");
+
+    if let Err(e) = run_once(args, verbose) {
+        eprintln!("⚠️  lint run failed: {}", e);
+    }
+    println!("Watching {}… (Ctrl-C to stop)", watch_root.display());
+
+    let (tx, rx) = channel();
+    let mut watcher = notify::recommended_watcher(move |res| {
+        let _ = tx.send(res);
+    })
+    .context("Failed to start file watcher")?;
+    watcher
+        .watch(watch_root, RecursiveMode::Recursive)
+        .context("Failed to watch directory")?;
+
+    loop {
+        let first = match rx.recv() {
+            Ok(event) => event,
+            Err(_) => break,
+        };
+
+        // Drain anything else that arrives within the debounce window so a
+        // single save (which fires several OS events) triggers exactly one
+        // re-lint.
+        let mut touched: Vec<std::path::PathBuf> =
+            first.ok().map(|e| e.paths).unwrap_or_default();
+        while let Ok(res) = rx.recv_timeout(Duration::from_millis(100)) {
+            if let Ok(event) = res {
+                touched.extend(event.paths);
+            }
+        }
+
+        let relevant = exts.is_empty()
+            || touched.iter().any(|p| {
+                p.extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|e| exts.contains(&e))
+            });
+        if !relevant {
+            continue;
+        }
+
+        print!("\x1B[2J\x1B[1;1H");
+        if let Err(e) = run_once(args, verbose) {
+            eprintln!("⚠️  lint run failed: {}", e);
+        }
+        println!("Watching {}… (Ctrl-C to stop)", watch_root.display());
+    }
+
+    Ok(())
+}
+
+fn run_once(args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
-    // Detect linter name (first arg if not a path/flag, else default to eslint)
+    let format = args
+        .iter()
+        .find_map(|a| a.strip_prefix(FORMAT_FLAG_PREFIX))
+        .map(|s| Format::parse(s).with_context(|| format!("invalid --rtk-format value: {}", s)))
+        .transpose()?
+        .unwrap_or(Format::Pretty);
+
+    let args: Vec<String> = args
+        .iter()
+        .filter(|a| !a.starts_with(FORMAT_FLAG_PREFIX))
+        .cloned()
+        .collect();
+
+    let baseline_flag_idx = args.iter().position(|a| a == BASELINE_FLAG);
+    let baseline_path = baseline_flag_idx.and_then(|i| args.get(i + 1)).cloned();
+    let update_baseline = args.iter().any(|a| a == UPDATE_BASELINE_FLAG);
+    let args: Vec<String> = args
+        .iter()
+        .enumerate()
+        .filter(|(i, a)| {
+            a.as_str() != UPDATE_BASELINE_FLAG
+                && Some(*i) != baseline_flag_idx
+                && Some(*i) != baseline_flag_idx.map(|j| j + 1)
+        })
+        .map(|(_, a)| a.clone())
+        .collect();
+
+    let max_warnings_flag_idx = args.iter().position(|a| a == MAX_WARNINGS_FLAG);
+    let max_warnings: Option<usize> = max_warnings_flag_idx
+        .and_then(|i| args.get(i + 1))
+        .and_then(|v| v.parse().ok());
+    let error_on_warnings = args.iter().any(|a| a == ERROR_ON_WARNINGS_FLAG);
+    let args: Vec<String> = args
+        .iter()
+        .enumerate()
+        .filter(|(i, a)| {
+            a.as_str() != ERROR_ON_WARNINGS_FLAG
+                && Some(*i) != max_warnings_flag_idx
+                && Some(*i) != max_warnings_flag_idx.map(|j| j + 1)
+        })
+        .map(|(_, a)| a.clone())
+        .collect();
+
+    // `--include <pattern>`/`--exclude <pattern>` (repeatable) plus an
+    // optional `.rtklintignore` file narrow which files' diagnostics get
+    // reported, independent of what the underlying linter actually scanned.
+    let mut includes = Vec::new();
+    let mut excludes = Vec::new();
+    let mut path_flag_idxs: HashSet<usize> = HashSet::new();
+    for (i, a) in args.iter().enumerate() {
+        if path_flag_idxs.contains(&i) {
+            continue;
+        }
+        let target = match a.as_str() {
+            INCLUDE_FLAG => Some(&mut includes),
+            EXCLUDE_FLAG => Some(&mut excludes),
+            _ => None,
+        };
+        if let Some(list) = target {
+            if let Some(value) = args.get(i + 1) {
+                list.push(value.clone());
+                path_flag_idxs.insert(i + 1);
+            }
+            path_flag_idxs.insert(i);
+        }
+    }
+    let args: Vec<String> = args
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !path_flag_idxs.contains(i))
+        .map(|(_, a)| a.clone())
+        .collect();
+    let path_filter = PathFilter::load(LINT_IGNORE_FILE, &includes, &excludes);
+
+    let adapter_flag_idx = args.iter().position(|a| a == ADAPTER_FLAG);
+    let adapter_mapping = adapter_flag_idx.and_then(|i| args.get(i + 1)).cloned();
+    let args: Vec<String> = args
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| {
+            Some(*i) != adapter_flag_idx && Some(*i) != adapter_flag_idx.map(|j| j + 1)
+        })
+        .map(|(_, a)| a.clone())
+        .collect();
+
+    // `-`/`--stdin` plus `--stdin-filename=<path>` reads source from stdin,
+    // writes it to a temp file so the linter can analyze it like any other
+    // file, then reports diagnostics against the supplied virtual filename
+    // instead of the temp path - for piping a single buffer from an editor
+    // or pre-commit hook without touching disk.
+    let use_stdin = args.iter().any(|a| a == "-" || a == "--stdin");
+    let stdin_virtual_name = args
+        .iter()
+        .find_map(|a| a.strip_prefix("--stdin-filename="))
+        .unwrap_or("stdin.ts")
+        .to_string();
+
+    let mut stdin_temp_file = None;
+    let args: Vec<String> = if use_stdin {
+        use std::io::Read;
+
+        let ext = Path::new(&stdin_virtual_name)
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("txt");
+
+        let mut source = String::new();
+        std::io::stdin()
+            .read_to_string(&mut source)
+            .context("Failed to read source from stdin")?;
+
+        let temp = tempfile::Builder::new()
+            .prefix("rtk-lint-stdin-")
+            .suffix(&format!(".{}", ext))
+            .tempfile()
+            .context("Failed to create temp file for stdin source")?;
+        std::fs::write(temp.path(), &source)
+            .context("Failed to write stdin source to temp file")?;
+        let temp_path = temp.path().to_string_lossy().to_string();
+        stdin_temp_file = Some(temp);
+
+        args.iter()
+            .filter(|a| *a != "-" && *a != "--stdin" && !a.starts_with("--stdin-filename="))
+            .cloned()
+            .chain(std::iter::once(temp_path))
+            .collect()
+    } else {
+        args
+    };
+    let args = args.as_slice();
+
+    let linter = detect_linter(args);
     let is_path_or_flag = args.is_empty()
         || args[0].starts_with('-')
         || args[0].contains('/')
         || args[0].contains('.');
 
-    let linter = if is_path_or_flag { "eslint" } else { &args[0] };
-
     // Python linters use Command::new() directly (they're on PATH via pip/pipx)
     // JS linters use package_manager_exec (npx/pnpm exec)
     let mut cmd = if is_python_linter(linter) {
@@ -90,6 +416,12 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
         "mypy" => {
             // mypy uses default text output (no special flags)
         }
+        "flake8" => {
+            // Force JSON output via the flake8-json plugin formatter
+            if !args.contains(&"--format".to_string()) {
+                cmd.arg("--format=json");
+            }
+        }
         _ => {
             // Other linters: no special formatting
         }
@@ -117,18 +449,71 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
         if linter == "pylint" && arg.starts_with("--output-format") {
             continue;
         }
+        if linter == "flake8" && arg.starts_with("--format") {
+            continue;
+        }
         cmd.arg(arg);
     }
 
     // Default to current directory if no path specified (for ruff/pylint/mypy/eslint)
-    if matches!(linter, "ruff" | "pylint" | "mypy" | "eslint") {
-        let has_path = args
-            .iter()
-            .skip(start_idx)
-            .any(|a| !a.starts_with('-') && !a.contains('='));
-        if !has_path {
-            cmd.arg(".");
+    let has_path = args
+        .iter()
+        .skip(start_idx)
+        .any(|a| !a.starts_with('-') && !a.contains('='));
+
+    // The incremental cache only applies when we're the one enumerating
+    // files (no explicit paths given) for a linter whose output we parse
+    // into diagnostics ourselves - ruff/generic linters don't go through it.
+    let use_cache = matches!(linter, "eslint" | "pylint" | "mypy" | "flake8") && !has_path;
+
+    let mut cache = if use_cache {
+        load_lint_cache()
+    } else {
+        LintCache::default()
+    };
+    let mut changed_files = Vec::new();
+    let mut cached_issues = Vec::new();
+
+    if use_cache {
+        let linter_cache = cache.linters.entry(linter.to_string()).or_default();
+        let cfg_hash = config_hash(linter);
+        if linter_cache.config_hash != cfg_hash {
+            linter_cache.files.clear();
+            linter_cache.config_hash = cfg_hash;
         }
+
+        let target_files = discover_target_files(linter);
+        for file in &target_files {
+            if let Ok(bytes) = std::fs::read(file) {
+                let digest = hash_bytes(&bytes);
+                match linter_cache.files.get(file) {
+                    Some(entry) if entry.content_hash == digest => {
+                        cached_issues.extend(entry.issues.clone());
+                    }
+                    _ => changed_files.push(file.clone()),
+                }
+            }
+        }
+
+        if !target_files.is_empty() && changed_files.is_empty() {
+            // Every target file is cached and unchanged - skip the linter entirely.
+            let label = display_label(linter);
+            let filtered = format.reporter().render(label, &cached_issues);
+            println!("{}", filtered);
+            timer.track(
+                &format!("{} (cached)", linter),
+                &format!("rtk lint {}", args.join(" ")),
+                "(cache hit, no files changed)",
+                &filtered,
+            );
+            return Ok(());
+        }
+
+        for file in &changed_files {
+            cmd.arg(file);
+        }
+    } else if matches!(linter, "ruff" | "pylint" | "mypy" | "eslint") && !has_path {
+        cmd.arg(".");
     }
 
     if verbose > 0 {
@@ -157,24 +542,198 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
     let stderr = String::from_utf8_lossy(&output.stderr);
     let raw = format!("{}\n{}", stdout, stderr);
 
-    // Dispatch to appropriate filter based on linter
+    let baseline_set = if update_baseline {
+        None
+    } else {
+        baseline_path.as_deref().map(load_baseline)
+    };
+    let mut baseline_hidden = 0usize;
+    let mut baseline_update_issues: Vec<LintIssue> = Vec::new();
+    // Aggregated across every linter we parse into `LintIssue` (same scope
+    // as the baseline/cache); drives the CI-gating exit code below.
+    let mut total_errors = 0usize;
+    let mut total_warnings = 0usize;
+
+    // Dispatch to the matching parser, merge in cached diagnostics for
+    // unchanged files, filter against the baseline (if any), then render
+    // through the selected Reporter.
     let filtered = match linter {
-        "eslint" => filter_eslint_json(&stdout),
+        "eslint" => match parse_eslint_diagnostics(&stdout) {
+            Ok(issues) => {
+                let issues = update_cache_and_merge(
+                    use_cache,
+                    &mut cache,
+                    linter,
+                    &changed_files,
+                    cached_issues,
+                    issues,
+                );
+                if update_baseline {
+                    baseline_update_issues.extend(issues.clone());
+                }
+                let issues = match &baseline_set {
+                    Some(set) => {
+                        let (kept, hidden) = filter_against_baseline(issues, set);
+                        baseline_hidden += hidden;
+                        kept
+                    }
+                    None => issues,
+                };
+                let issues = filter_by_path(issues, &path_filter);
+                count_severities(&issues, &mut total_errors, &mut total_warnings);
+                format.reporter().render("ESLint", &issues)
+            }
+            Err(fallback) => fallback,
+        },
         "ruff" => {
             // Reuse ruff_cmd's JSON parser
             if !stdout.trim().is_empty() {
-                ruff_cmd::filter_ruff_check_json(&stdout)
+                let no_glob_filter = crate::glob_filter::GlobFilter::new(&[]).unwrap();
+                ruff_cmd::filter_ruff_check_json(&stdout, &no_glob_filter)
             } else {
                 "✓ Ruff: No issues found".to_string()
             }
         }
-        "pylint" => filter_pylint_json(&stdout),
-        "mypy" => filter_mypy_output(&raw),
-        _ => filter_generic_lint(&raw),
+        "pylint" => match parse_pylint_diagnostics(&stdout) {
+            Ok(issues) => {
+                let issues = update_cache_and_merge(
+                    use_cache,
+                    &mut cache,
+                    linter,
+                    &changed_files,
+                    cached_issues,
+                    issues,
+                );
+                if update_baseline {
+                    baseline_update_issues.extend(issues.clone());
+                }
+                let issues = match &baseline_set {
+                    Some(set) => {
+                        let (kept, hidden) = filter_against_baseline(issues, set);
+                        baseline_hidden += hidden;
+                        kept
+                    }
+                    None => issues,
+                };
+                let issues = filter_by_path(issues, &path_filter);
+                count_severities(&issues, &mut total_errors, &mut total_warnings);
+                format.reporter().render("Pylint", &issues)
+            }
+            Err(fallback) => fallback,
+        },
+        "mypy" => match parse_mypy_diagnostics(&raw) {
+            Ok(issues) => {
+                let issues = update_cache_and_merge(
+                    use_cache,
+                    &mut cache,
+                    linter,
+                    &changed_files,
+                    cached_issues,
+                    issues,
+                );
+                if update_baseline {
+                    baseline_update_issues.extend(issues.clone());
+                }
+                let issues = match &baseline_set {
+                    Some(set) => {
+                        let (kept, hidden) = filter_against_baseline(issues, set);
+                        baseline_hidden += hidden;
+                        kept
+                    }
+                    None => issues,
+                };
+                let issues = filter_by_path(issues, &path_filter);
+                count_severities(&issues, &mut total_errors, &mut total_warnings);
+                format.reporter().render("Mypy", &issues)
+            }
+            Err(fallback) => fallback,
+        },
+        "flake8" => match parse_flake8_diagnostics(&stdout) {
+            Ok(issues) => {
+                let issues = update_cache_and_merge(
+                    use_cache,
+                    &mut cache,
+                    linter,
+                    &changed_files,
+                    cached_issues,
+                    issues,
+                );
+                if update_baseline {
+                    baseline_update_issues.extend(issues.clone());
+                }
+                let issues = match &baseline_set {
+                    Some(set) => {
+                        let (kept, hidden) = filter_against_baseline(issues, set);
+                        baseline_hidden += hidden;
+                        kept
+                    }
+                    None => issues,
+                };
+                let issues = filter_by_path(issues, &path_filter);
+                count_severities(&issues, &mut total_errors, &mut total_warnings);
+                format.reporter().render("Flake8", &issues)
+            }
+            Err(fallback) => fallback,
+        },
+        _ if adapter_mapping.is_some() => {
+            let mapping = parse_adapter_mapping(adapter_mapping.as_deref().unwrap_or(""));
+            let issues = parse_json_adapter(&stdout, &mapping, linter);
+            if update_baseline {
+                baseline_update_issues.extend(issues.clone());
+            }
+            let issues = match &baseline_set {
+                Some(set) => {
+                    let (kept, hidden) = filter_against_baseline(issues, set);
+                    baseline_hidden += hidden;
+                    kept
+                }
+                None => issues,
+            };
+            let issues = filter_by_path(issues, &path_filter);
+            count_severities(&issues, &mut total_errors, &mut total_warnings);
+            format.reporter().render(display_label(linter), &issues)
+        }
+        _ => {
+            let issues = parse_generic_diagnostics(&raw, linter);
+            count_severities(&issues, &mut total_errors, &mut total_warnings);
+            format.reporter().render("Lint", &issues)
+        }
+    };
+
+    let filtered = if !update_baseline && baseline_hidden > 0 && format == Format::Pretty {
+        format!("{}\n({} baselined issues hidden)", filtered, baseline_hidden)
+    } else {
+        filtered
+    };
+
+    let filtered = match &stdin_temp_file {
+        Some(temp) => relabel_stdin_path(&filtered, temp.path(), &stdin_virtual_name),
+        None => filtered,
     };
 
     println!("{}", filtered);
 
+    if use_cache {
+        if let Err(e) = save_lint_cache(&cache) {
+            eprintln!("rtk: failed to write lint cache: {}", e);
+        }
+    }
+
+    if update_baseline {
+        if let Some(path) = &baseline_path {
+            match save_baseline(path, &baseline_update_issues) {
+                Ok(()) => println!(
+                    "rtk: wrote {} issues to baseline at {}",
+                    baseline_update_issues.len(),
+                    path
+                ),
+                Err(e) => eprintln!("rtk: failed to write baseline: {}", e),
+            }
+        } else {
+            eprintln!("rtk: --update-baseline requires --baseline <path>");
+        }
+    }
+
     timer.track(
         &format!("{} {}", linter, args.join(" ")),
         &format!("rtk lint {} {}", linter, args.join(" ")),
@@ -182,326 +741,678 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
         &filtered,
     );
 
+    // Any error always fails the run; warnings only fail it past
+    // `--max-warnings` (or at all, with `--error-on-warnings`) - the same
+    // gating eslint/deno lint apply via their own exit codes.
+    let warnings_over_budget = error_on_warnings && total_warnings > 0
+        || max_warnings.is_some_and(|max| total_warnings > max);
+    if total_errors > 0 || warnings_over_budget {
+        std::process::exit(1);
+    }
+
     Ok(())
 }
 
-/// Filter ESLint JSON output - group by rule and file
-fn filter_eslint_json(output: &str) -> String {
-    let results: Result<Vec<EslintResult>, _> = serde_json::from_str(output);
-
-    let results = match results {
-        Ok(r) => r,
-        Err(e) => {
-            // Fallback if JSON parsing fails
-            return format!(
-                "ESLint output (JSON parse failed: {})\n{}",
-                e,
-                truncate(output, 500)
-            );
-        }
-    };
-
-    // Count total issues
-    let total_errors: usize = results.iter().map(|r| r.error_count).sum();
-    let total_warnings: usize = results.iter().map(|r| r.warning_count).sum();
-    let total_files = results.iter().filter(|r| !r.messages.is_empty()).count();
+/// Diagnostics reference the stdin temp file's real path (in full or, once
+/// a reporter has run it through its own path-compaction, just the temp
+/// file's basename) - swap either back to the virtual filename the caller
+/// actually asked about.
+fn relabel_stdin_path(text: &str, temp_path: &Path, virtual_name: &str) -> String {
+    let temp_path = temp_path.to_string_lossy().to_string();
+    let temp_name = Path::new(&temp_path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(&temp_path)
+        .to_string();
+
+    text.replace(&temp_path, virtual_name)
+        .replace(&temp_name, virtual_name)
+}
 
-    if total_errors == 0 && total_warnings == 0 {
-        return "✓ ESLint: No issues found".to_string();
+/// Display label for the grouped-by-rule/file summary, shared between the
+/// normal dispatch and the cache-hit fast path.
+fn display_label(linter: &str) -> &'static str {
+    match linter {
+        "eslint" => "ESLint",
+        "pylint" => "Pylint",
+        "mypy" => "Mypy",
+        "flake8" => "Flake8",
+        _ => "Lint",
     }
+}
 
-    // Group messages by rule
-    let mut by_rule: HashMap<String, usize> = HashMap::new();
-    for result in &results {
-        for msg in &result.messages {
-            if let Some(rule) = &msg.rule_id {
-                *by_rule.entry(rule.clone()).or_insert(0) += 1;
-            }
-        }
-    }
+/// `.rtk/lint-cache.json`: per-linter content-hash cache so repeated `rtk
+/// lint` runs skip re-analyzing files whose contents (and the linter's
+/// config) haven't changed since the last run.
+const LINT_CACHE_PATH: &str = ".rtk/lint-cache.json";
 
-    // Group by file
-    let mut by_file: Vec<(&EslintResult, usize)> = results
-        .iter()
-        .filter(|r| !r.messages.is_empty())
-        .map(|r| (r, r.messages.len()))
-        .collect();
-    by_file.sort_by(|a, b| b.1.cmp(&a.1));
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct LintCache {
+    #[serde(default)]
+    linters: HashMap<String, LinterCache>,
+}
 
-    // Build output
-    let mut result = String::new();
-    result.push_str(&format!(
-        "ESLint: {} errors, {} warnings in {} files\n",
-        total_errors, total_warnings, total_files
-    ));
-    result.push_str("═══════════════════════════════════════\n");
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct LinterCache {
+    /// Hash of the linter's config file(s) as of the last run. Any mismatch
+    /// invalidates every entry below, since a config change can flip the
+    /// diagnostics for files whose contents didn't move at all.
+    #[serde(default)]
+    config_hash: u64,
+    #[serde(default)]
+    files: HashMap<String, FileCacheEntry>,
+}
 
-    // Show top rules
-    let mut rule_counts: Vec<_> = by_rule.iter().collect();
-    rule_counts.sort_by(|a, b| b.1.cmp(a.1));
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct FileCacheEntry {
+    content_hash: u64,
+    issues: Vec<LintIssue>,
+}
 
-    if !rule_counts.is_empty() {
-        result.push_str("Top rules:\n");
-        for (rule, count) in rule_counts.iter().take(10) {
-            result.push_str(&format!("  {} ({}x)\n", rule, count));
-        }
-        result.push('\n');
-    }
+fn load_lint_cache() -> LintCache {
+    std::fs::read_to_string(LINT_CACHE_PATH)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
 
-    // Show top files with most issues
-    result.push_str("Top files:\n");
-    for (file_result, count) in by_file.iter().take(10) {
-        let short_path = compact_path(&file_result.file_path);
-        result.push_str(&format!("  {} ({} issues)\n", short_path, count));
+fn save_lint_cache(cache: &LintCache) -> Result<()> {
+    if let Some(parent) = Path::new(LINT_CACHE_PATH).parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    let json = serde_json::to_string_pretty(cache).context("failed to serialize lint cache")?;
+    std::fs::write(LINT_CACHE_PATH, json)
+        .with_context(|| format!("failed to write {}", LINT_CACHE_PATH))
+}
 
-        // Show top 3 rules in this file
-        let mut file_rules: HashMap<String, usize> = HashMap::new();
-        for msg in &file_result.messages {
-            if let Some(rule) = &msg.rule_id {
-                *file_rules.entry(rule.clone()).or_insert(0) += 1;
-            }
-        }
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
 
-        let mut file_rule_counts: Vec<_> = file_rules.iter().collect();
-        file_rule_counts.sort_by(|a, b| b.1.cmp(a.1));
+/// Config files whose contents affect a linter's output, checked in
+/// addition to each file's own hash. Not exhaustive - covers each linter's
+/// conventional default config location.
+fn config_paths_for(linter: &str) -> &'static [&'static str] {
+    match linter {
+        "eslint" => &[
+            ".eslintrc.json",
+            ".eslintrc.js",
+            ".eslintrc.cjs",
+            ".eslintrc.yml",
+            ".eslintrc",
+            "eslint.config.js",
+            "eslint.config.mjs",
+        ],
+        "pylint" => &[".pylintrc", "pyproject.toml"],
+        "mypy" => &["mypy.ini", "pyproject.toml", "setup.cfg"],
+        "flake8" => &[".flake8", "setup.cfg", "tox.ini"],
+        _ => &[],
+    }
+}
 
-        for (rule, count) in file_rule_counts.iter().take(3) {
-            result.push_str(&format!("    {} ({})\n", rule, count));
+fn config_hash(linter: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for path in config_paths_for(linter) {
+        if let Ok(bytes) = std::fs::read(path) {
+            path.hash(&mut hasher);
+            bytes.hash(&mut hasher);
         }
     }
+    hasher.finish()
+}
 
-    if by_file.len() > 10 {
-        result.push_str(&format!("\n... +{} more files\n", by_file.len() - 10));
+/// File extensions `rtk lint` walks the tree for when no explicit path is
+/// given, so the cache can diff "every file this linter would touch"
+/// against last run instead of only ones passed on the command line.
+fn target_extensions(linter: &str) -> &'static [&'static str] {
+    match linter {
+        "eslint" => &["js", "jsx", "ts", "tsx", "mjs", "cjs"],
+        "pylint" | "mypy" | "flake8" => &["py"],
+        _ => &[],
     }
-
-    result.trim().to_string()
 }
 
-/// Filter pylint JSON2 output - group by symbol and file
-fn filter_pylint_json(output: &str) -> String {
-    let diagnostics: Result<Vec<PylintDiagnostic>, _> = serde_json::from_str(output);
+/// Gitignore-aware walk (same as `rtk find`/golangci's file count) for every
+/// file a cache-eligible linter would see by default.
+fn discover_target_files(linter: &str) -> Vec<String> {
+    let exts = target_extensions(linter);
+    if exts.is_empty() {
+        return Vec::new();
+    }
 
-    let diagnostics = match diagnostics {
-        Ok(d) => d,
-        Err(e) => {
-            // Fallback if JSON parsing fails
-            return format!(
-                "Pylint output (JSON parse failed: {})\n{}",
-                e,
-                truncate(output, 500)
-            );
-        }
-    };
+    ignore::WalkBuilder::new(".")
+        .build()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_some_and(|ft| ft.is_file()))
+        .filter(|entry| {
+            entry
+                .path()
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| exts.contains(&e))
+        })
+        .map(|entry| canonical_path(entry.path()))
+        .collect()
+}
+
+/// Canonicalize a path into the same string form a linter tends to echo
+/// back in its own output, so cache keys and re-parsed diagnostics line up
+/// regardless of how the path was spelled on the command line.
+fn canonical_path(path: &Path) -> String {
+    std::fs::canonicalize(path)
+        .unwrap_or_else(|_| path.to_path_buf())
+        .to_string_lossy()
+        .to_string()
+}
 
-    if diagnostics.is_empty() {
-        return "✓ Pylint: No issues found".to_string();
+/// Merge freshly-parsed diagnostics (for `changed_files` only) with
+/// `cached_issues` carried over from unchanged files, and refresh each
+/// changed file's cache entry with its new hash + issues. A no-op that just
+/// returns `fresh_issues` when caching doesn't apply to this linter.
+fn update_cache_and_merge(
+    use_cache: bool,
+    cache: &mut LintCache,
+    linter: &str,
+    changed_files: &[String],
+    cached_issues: Vec<LintIssue>,
+    fresh_issues: Vec<LintIssue>,
+) -> Vec<LintIssue> {
+    if !use_cache {
+        return fresh_issues;
     }
 
-    // Count by type
-    let mut errors = 0;
-    let mut warnings = 0;
-    let mut conventions = 0;
-    let mut refactors = 0;
+    // Diagnostics come back keyed by whatever path form the underlying
+    // linter chose to report (eslint always reports absolute paths, even
+    // when invoked with a relative one) - canonicalize before matching them
+    // up against `changed_files`, which are already canonical.
+    let mut by_file: HashMap<String, Vec<LintIssue>> = HashMap::new();
+    for issue in fresh_issues {
+        let key = canonical_path(Path::new(&issue.file_path));
+        by_file.entry(key).or_default().push(issue);
+    }
 
-    for diag in &diagnostics {
-        match diag.msg_type.as_str() {
-            "error" => errors += 1,
-            "warning" => warnings += 1,
-            "convention" => conventions += 1,
-            "refactor" => refactors += 1,
-            _ => {}
+    let linter_cache = cache.linters.entry(linter.to_string()).or_default();
+    let mut merged = cached_issues;
+    for file in changed_files {
+        let issues_for_file = by_file.remove(file).unwrap_or_default();
+        if let Ok(bytes) = std::fs::read(file) {
+            linter_cache.files.insert(
+                file.clone(),
+                FileCacheEntry {
+                    content_hash: hash_bytes(&bytes),
+                    issues: issues_for_file.clone(),
+                },
+            );
         }
+        merged.extend(issues_for_file);
     }
 
-    // Count unique files
-    let unique_files: std::collections::HashSet<_> = diagnostics.iter().map(|d| &d.path).collect();
-    let total_files = unique_files.len();
+    merged
+}
 
-    // Group by symbol (rule code)
-    let mut by_symbol: HashMap<String, usize> = HashMap::new();
-    for diag in &diagnostics {
-        let key = format!("{} ({})", diag.symbol, diag.message_id);
-        *by_symbol.entry(key).or_insert(0) += 1;
-    }
+/// `.rtk/lint-baseline.json` entry key. Diagnostics are matched on
+/// (relative file, rule, message) rather than line number, so editing
+/// elsewhere in an otherwise-unchanged file doesn't resurrect an issue that
+/// was already accepted into the baseline.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+struct BaselineKey {
+    file: String,
+    code: String,
+    message: String,
+}
 
-    // Group by file
-    let mut by_file: HashMap<&str, usize> = HashMap::new();
-    for diag in &diagnostics {
-        *by_file.entry(&diag.path).or_insert(0) += 1;
+fn baseline_key(issue: &LintIssue) -> BaselineKey {
+    BaselineKey {
+        file: relative_to_cwd(&issue.file_path),
+        code: issue.rule_id.clone(),
+        message: issue.message.clone(),
     }
+}
 
-    let mut file_counts: Vec<_> = by_file.iter().collect();
-    file_counts.sort_by(|a, b| b.1.cmp(a.1));
+/// Express `path` relative to the current directory, so baseline entries
+/// stay stable across machines/checkouts where the absolute prefix differs.
+fn relative_to_cwd(path: &str) -> String {
+    let cwd = std::env::current_dir().unwrap_or_else(|_| Path::new(".").to_path_buf());
+    Path::new(path)
+        .strip_prefix(&cwd)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| path.to_string())
+}
 
-    // Build output
-    let mut result = String::new();
-    result.push_str(&format!(
-        "Pylint: {} issues in {} files\n",
-        diagnostics.len(),
-        total_files
-    ));
+fn load_baseline(path: &str) -> HashSet<BaselineKey> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<Vec<BaselineKey>>(&content).ok())
+        .map(|keys| keys.into_iter().collect())
+        .unwrap_or_default()
+}
 
-    if errors > 0 || warnings > 0 {
-        result.push_str(&format!("  {} errors, {} warnings", errors, warnings));
-        if conventions > 0 || refactors > 0 {
-            result.push_str(&format!(
-                ", {} conventions, {} refactors",
-                conventions, refactors
-            ));
+fn save_baseline(path: &str, issues: &[LintIssue]) -> Result<()> {
+    let keys: Vec<BaselineKey> = issues.iter().map(baseline_key).collect();
+    if let Some(parent) = Path::new(path).parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    let json = serde_json::to_string_pretty(&keys).context("failed to serialize lint baseline")?;
+    std::fs::write(path, json).with_context(|| format!("failed to write {}", path))
+}
+
+/// Split `issues` into (kept, hidden_count) against a loaded baseline.
+fn filter_against_baseline(
+    issues: Vec<LintIssue>,
+    baseline: &HashSet<BaselineKey>,
+) -> (Vec<LintIssue>, usize) {
+    let mut kept = Vec::new();
+    let mut hidden = 0;
+    for issue in issues {
+        if baseline.contains(&baseline_key(&issue)) {
+            hidden += 1;
+        } else {
+            kept.push(issue);
         }
-        result.push('\n');
     }
+    (kept, hidden)
+}
 
-    result.push_str("═══════════════════════════════════════\n");
+/// A single `.rtklintignore`/`--include`/`--exclude` pattern: either a
+/// literal directory prefix (`path:src/legacy`) or a glob matched against
+/// the whole relative path via [`crate::find_cmd::glob_match`].
+#[derive(Debug, Clone)]
+enum PathPattern {
+    Prefix(String),
+    Glob(String),
+}
 
-    // Show top symbols (rules)
-    let mut symbol_counts: Vec<_> = by_symbol.iter().collect();
-    symbol_counts.sort_by(|a, b| b.1.cmp(a.1));
+impl PathPattern {
+    fn parse(raw: &str) -> Self {
+        match raw.strip_prefix("path:") {
+            Some(prefix) => PathPattern::Prefix(prefix.to_string()),
+            None => PathPattern::Glob(raw.to_string()),
+        }
+    }
 
-    if !symbol_counts.is_empty() {
-        result.push_str("Top rules:\n");
-        for (symbol, count) in symbol_counts.iter().take(10) {
-            result.push_str(&format!("  {} ({}x)\n", symbol, count));
+    fn matches(&self, file: &str) -> bool {
+        match self {
+            PathPattern::Prefix(prefix) => file.starts_with(prefix.as_str()),
+            PathPattern::Glob(pattern) => crate::find_cmd::glob_match(pattern, file),
         }
-        result.push('\n');
     }
+}
 
-    // Show top files
-    result.push_str("Top files:\n");
-    for (file, count) in file_counts.iter().take(10) {
-        let short_path = compact_path(file);
-        result.push_str(&format!("  {} ({} issues)\n", short_path, count));
+/// Narrows reported diagnostics to `include AND NOT exclude`, independent of
+/// what the underlying linter actually scanned - useful for focusing `rtk
+/// lint` on one subsystem of a monorepo without reconfiguring each tool.
+#[derive(Debug, Clone, Default)]
+struct PathFilter {
+    include: Vec<PathPattern>,
+    exclude: Vec<PathPattern>,
+}
 
-        // Show top 3 rules in this file
-        let mut file_symbols: HashMap<String, usize> = HashMap::new();
-        for diag in diagnostics.iter().filter(|d| &d.path == *file) {
-            let key = format!("{} ({})", diag.symbol, diag.message_id);
-            *file_symbols.entry(key).or_insert(0) += 1;
-        }
+impl PathFilter {
+    /// `ignore_path` (if present) supplies exclude-only patterns; `includes`
+    /// and `excludes` are the `--include`/`--exclude` flag values, which
+    /// layer on top rather than replace the ignore file.
+    fn load(ignore_path: &str, includes: &[String], excludes: &[String]) -> Self {
+        let mut exclude: Vec<PathPattern> = std::fs::read_to_string(ignore_path)
+            .ok()
+            .map(|content| {
+                content
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                    .map(PathPattern::parse)
+                    .collect()
+            })
+            .unwrap_or_default();
+        exclude.extend(excludes.iter().map(|s| PathPattern::parse(s)));
+        let include = includes.iter().map(|s| PathPattern::parse(s)).collect();
+        Self { include, exclude }
+    }
+
+    /// An empty include list means "everything is included".
+    fn allows(&self, file: &str) -> bool {
+        let included = self.include.is_empty() || self.include.iter().any(|p| p.matches(file));
+        included && !self.exclude.iter().any(|p| p.matches(file))
+    }
+}
 
-        let mut file_symbol_counts: Vec<_> = file_symbols.iter().collect();
-        file_symbol_counts.sort_by(|a, b| b.1.cmp(a.1));
+/// Drop issues whose file the path filter rejects.
+fn filter_by_path(issues: Vec<LintIssue>, filter: &PathFilter) -> Vec<LintIssue> {
+    issues
+        .into_iter()
+        .filter(|issue| filter.allows(&issue.file_path))
+        .collect()
+}
 
-        for (symbol, count) in file_symbol_counts.iter().take(3) {
-            result.push_str(&format!("    {} ({})\n", symbol, count));
+/// Tally `issues` into the running error/warning totals that drive
+/// `--max-warnings`/`--error-on-warnings`'s exit code decision.
+fn count_severities(issues: &[LintIssue], errors: &mut usize, warnings: &mut usize) {
+    for issue in issues {
+        match issue.severity {
+            LintSeverity::Error => *errors += 1,
+            LintSeverity::Warning => *warnings += 1,
+            LintSeverity::Info => {}
         }
     }
+}
 
-    if file_counts.len() > 10 {
-        result.push_str(&format!("\n... +{} more files\n", file_counts.len() - 10));
+/// Parse ESLint JSON output into normalized diagnostics. `Err` carries the
+/// already-formatted passthrough text to show when parsing fails.
+fn parse_eslint_diagnostics(output: &str) -> Result<Vec<LintIssue>, String> {
+    let results: Vec<EslintResult> = serde_json::from_str(output).map_err(|e| {
+        format!(
+            "ESLint output (JSON parse failed: {})\n{}",
+            e,
+            truncate(output, 500)
+        )
+    })?;
+
+    let mut issues = Vec::new();
+    for result in &results {
+        for msg in &result.messages {
+            issues.push(LintIssue {
+                file_path: result.file_path.clone(),
+                line: msg.line,
+                column: msg.column,
+                // ESLint severities: 1 = warning, 2 = error.
+                severity: if msg.severity >= 2 {
+                    LintSeverity::Error
+                } else {
+                    LintSeverity::Warning
+                },
+                rule_id: msg.rule_id.clone().unwrap_or_else(|| "unknown".to_string()),
+                message: msg.message.clone(),
+                linter: "eslint".to_string(),
+            });
+        }
     }
 
-    result.trim().to_string()
+    Ok(issues)
 }
 
-/// Filter mypy text output - parse and group by error code and file
-fn filter_mypy_output(output: &str) -> String {
-    // Regex pattern: path/to/file.py:line: error: message [error-code]
-    let re = Regex::new(r"^(.+\.py):(\d+): (error|warning|note): (.+?) \[(.+?)\]").unwrap();
+/// Parse pylint's JSON2 output into normalized diagnostics. Pylint's
+/// "convention"/"refactor" types have no error/warning equivalent, so they
+/// fold into [`LintSeverity::Info`].
+fn parse_pylint_diagnostics(output: &str) -> Result<Vec<LintIssue>, String> {
+    let diagnostics: Vec<PylintDiagnostic> = serde_json::from_str(output).map_err(|e| {
+        format!(
+            "Pylint output (JSON parse failed: {})\n{}",
+            e,
+            truncate(output, 500)
+        )
+    })?;
+
+    Ok(diagnostics
+        .into_iter()
+        .map(|d| LintIssue {
+            file_path: d.path,
+            line: d.line,
+            column: d.column,
+            severity: match d.msg_type.as_str() {
+                "error" => LintSeverity::Error,
+                "warning" => LintSeverity::Warning,
+                _ => LintSeverity::Info,
+            },
+            rule_id: format!("{} ({})", d.symbol, d.message_id),
+            message: d.message,
+            linter: "pylint".to_string(),
+        })
+        .collect())
+}
 
-    let mut issues: Vec<(String, String, String, String)> = Vec::new(); // (file, line, level, code)
-    let mut errors = 0;
-    let mut warnings = 0;
-    let mut notes = 0;
+/// Parse mypy's text output (`path:line: level: message [code]`) into
+/// normalized diagnostics. `Err` carries the raw passthrough text for
+/// output that doesn't match mypy's format at all.
+fn parse_mypy_diagnostics(output: &str) -> Result<Vec<LintIssue>, String> {
+    let re = Regex::new(r"^(.+\.py):(\d+): (error|warning|note): (.+?) \[(.+?)\]").unwrap();
 
+    let mut issues = Vec::new();
     for line in output.lines() {
         if let Some(caps) = re.captures(line) {
-            let file = caps.get(1).map_or("", |m| m.as_str());
-            let line_num = caps.get(2).map_or("", |m| m.as_str());
+            let file = caps.get(1).map_or("", |m| m.as_str()).to_string();
+            let line_num: usize = caps
+                .get(2)
+                .and_then(|m| m.as_str().parse().ok())
+                .unwrap_or(0);
             let level = caps.get(3).map_or("", |m| m.as_str());
-            let code = caps.get(5).map_or("", |m| m.as_str());
+            let message = caps.get(4).map_or("", |m| m.as_str()).to_string();
+            let code = caps.get(5).map_or("", |m| m.as_str()).to_string();
+
+            issues.push(LintIssue {
+                file_path: file,
+                line: line_num,
+                column: 0,
+                severity: match level {
+                    "error" => LintSeverity::Error,
+                    "warning" => LintSeverity::Warning,
+                    _ => LintSeverity::Info,
+                },
+                rule_id: code,
+                message,
+                linter: "mypy".to_string(),
+            });
+        }
+    }
 
-            match level {
-                "error" => errors += 1,
-                "warning" => warnings += 1,
-                "note" => notes += 1,
-                _ => {}
-            }
+    if issues.is_empty() {
+        if output.contains("Success") || output.trim().is_empty() {
+            return Ok(issues);
+        }
+        return Err(format!("Mypy output:\n{}", truncate(output, 500)));
+    }
+
+    Ok(issues)
+}
+
+/// One entry from the `flake8-json` formatter's per-file diagnostic list.
+#[derive(Debug, Deserialize)]
+struct Flake8Diagnostic {
+    code: String,
+    filename: String,
+    line_number: usize,
+    column_number: usize,
+    text: String,
+}
 
-            issues.push((
-                file.to_string(),
-                line_num.to_string(),
-                level.to_string(),
-                code.to_string(),
-            ));
+/// Parse flake8's `--format=json` (flake8-json plugin) output - a map of
+/// filename to its diagnostics - into normalized [`LintIssue`]s, grouped by
+/// error code and file like the pylint path. flake8 codes classify their
+/// own severity by prefix: `E`/`F` (pycodestyle errors, pyflakes) are
+/// errors, everything else (`W`, `C`, plugin-specific codes) is a warning.
+fn parse_flake8_diagnostics(output: &str) -> Result<Vec<LintIssue>, String> {
+    let by_file: HashMap<String, Vec<Flake8Diagnostic>> =
+        serde_json::from_str(output).map_err(|e| {
+            format!(
+                "Flake8 output (JSON parse failed: {})\n{}",
+                e,
+                truncate(output, 500)
+            )
+        })?;
+
+    Ok(by_file
+        .into_values()
+        .flatten()
+        .map(|d| LintIssue {
+            file_path: d.filename,
+            line: d.line_number,
+            column: d.column_number,
+            severity: if d.code.starts_with('E') || d.code.starts_with('F') {
+                LintSeverity::Error
+            } else {
+                LintSeverity::Warning
+            },
+            rule_id: d.code,
+            message: d.text,
+            linter: "flake8".to_string(),
+        })
+        .collect())
+}
+
+/// Parse a `--adapter` mapping string (`file=path,code=rule,line=lineno,...`)
+/// into canonical-field -> JSON-key pairs. Unrecognized canonical fields are
+/// simply never looked up; missing ones default per [`adapter_field`].
+fn parse_adapter_mapping(spec: &str) -> HashMap<String, String> {
+    spec.split(',')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+        .collect()
+}
+
+/// Normalize an arbitrary JSON-emitting linter's output (a top-level array
+/// of diagnostic objects) into [`LintIssue`]s using a user-supplied field
+/// mapping, so new tools don't need a bespoke parser added to this file.
+fn parse_json_adapter(output: &str, mapping: &HashMap<String, String>, linter: &str) -> Vec<LintIssue> {
+    let Ok(Value::Array(items)) = serde_json::from_str::<Value>(output) else {
+        return Vec::new();
+    };
+
+    items
+        .iter()
+        .map(|item| LintIssue {
+            file_path: adapter_field(item, mapping, "file").unwrap_or_default(),
+            line: adapter_field(item, mapping, "line")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(0),
+            column: adapter_field(item, mapping, "column")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(0),
+            severity: LintSeverity::Warning,
+            rule_id: adapter_field(item, mapping, "code").unwrap_or_default(),
+            message: adapter_field(item, mapping, "message").unwrap_or_default(),
+            linter: linter.to_string(),
+        })
+        .collect()
+}
+
+/// Look up `canonical` (e.g. `"file"`) in `mapping` to get the diagnostic's
+/// own JSON key, then pull that key's value out of `item` as a display
+/// string.
+fn adapter_field(item: &Value, mapping: &HashMap<String, String>, canonical: &str) -> Option<String> {
+    let key = mapping.get(canonical)?;
+    match item.get(key)? {
+        Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+/// Fallback for linters without a structured parser: classify each line
+/// containing "warning"/"error" by substring match. Diagnostics built this
+/// way never have a file/line/rule, so [`render_pretty`] falls back to a
+/// flat message list for them.
+fn parse_generic_diagnostics(output: &str, linter: &str) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+
+    for line in output.lines() {
+        let line_lower = line.to_lowercase();
+        if line_lower.contains("warning") {
+            issues.push(generic_issue(line, LintSeverity::Warning, linter));
         }
+        if line_lower.contains("error") && !line_lower.contains("0 error") {
+            issues.push(generic_issue(line, LintSeverity::Error, linter));
+        }
+    }
+
+    issues
+}
+
+fn generic_issue(line: &str, severity: LintSeverity, linter: &str) -> LintIssue {
+    LintIssue {
+        file_path: String::new(),
+        line: 0,
+        column: 0,
+        severity,
+        rule_id: String::new(),
+        message: line.to_string(),
+        linter: linter.to_string(),
     }
+}
 
+/// Grouped-by-rule/file summary - the pretty-printed format `rtk lint` has
+/// always used. Diagnostics with no file/rule info (the generic fallback
+/// parser) get a flat message list instead, since grouping them would just
+/// collapse every message into one bucket.
+fn render_pretty(label: &str, issues: &[LintIssue]) -> String {
     if issues.is_empty() {
-        // Check if mypy output contains "Success" or similar
-        if output.contains("Success") || output.trim().is_empty() {
-            return "✓ Mypy: No issues found".to_string();
+        return format!("✓ {}: No issues found", label);
+    }
+
+    let errors = issues
+        .iter()
+        .filter(|i| i.severity == LintSeverity::Error)
+        .count();
+    let warnings = issues
+        .iter()
+        .filter(|i| i.severity == LintSeverity::Warning)
+        .count();
+
+    let structured = issues
+        .iter()
+        .any(|i| !i.file_path.is_empty() || !i.rule_id.is_empty());
+
+    if !structured {
+        let mut result = String::new();
+        result.push_str(&format!("{}: {} errors, {} warnings\n", label, errors, warnings));
+        result.push_str("═══════════════════════════════════════\n");
+        for issue in issues.iter().take(20) {
+            result.push_str(&format!("{}\n", truncate(&issue.message, 100)));
+        }
+        if issues.len() > 20 {
+            result.push_str(&format!("\n... +{} more issues\n", issues.len() - 20));
         }
-        // Fallback to generic output if no regex matches
-        return format!("Mypy output:\n{}", truncate(output, 500));
+        return result.trim().to_string();
     }
 
-    // Count unique files
-    let unique_files: std::collections::HashSet<_> = issues.iter().map(|(f, _, _, _)| f).collect();
+    let unique_files: HashSet<_> = issues.iter().map(|i| &i.file_path).collect();
     let total_files = unique_files.len();
 
-    // Group by error code
-    let mut by_code: HashMap<String, usize> = HashMap::new();
-    for (_, _, _, code) in &issues {
-        *by_code.entry(code.clone()).or_insert(0) += 1;
+    let mut by_rule: HashMap<&str, usize> = HashMap::new();
+    for issue in issues {
+        *by_rule.entry(issue.rule_id.as_str()).or_insert(0) += 1;
     }
 
-    // Group by file
     let mut by_file: HashMap<&str, usize> = HashMap::new();
-    for (file, _, _, _) in &issues {
-        *by_file.entry(file.as_str()).or_insert(0) += 1;
+    for issue in issues {
+        *by_file.entry(issue.file_path.as_str()).or_insert(0) += 1;
     }
+    let mut file_counts: Vec<_> = by_file.into_iter().collect();
+    file_counts.sort_by(|a, b| b.1.cmp(&a.1));
 
-    let mut file_counts: Vec<_> = by_file.iter().collect();
-    file_counts.sort_by(|a, b| b.1.cmp(a.1));
-
-    // Build output
     let mut result = String::new();
     result.push_str(&format!(
-        "Mypy: {} issues in {} files\n",
-        issues.len(),
-        total_files
+        "{}: {} errors, {} warnings in {} files\n",
+        label, errors, warnings, total_files
     ));
-
-    if errors > 0 || warnings > 0 {
-        result.push_str(&format!("  {} errors, {} warnings", errors, warnings));
-        if notes > 0 {
-            result.push_str(&format!(", {} notes", notes));
-        }
-        result.push('\n');
-    }
-
     result.push_str("═══════════════════════════════════════\n");
 
-    // Show top error codes
-    let mut code_counts: Vec<_> = by_code.iter().collect();
-    code_counts.sort_by(|a, b| b.1.cmp(a.1));
+    let mut rule_counts: Vec<_> = by_rule.into_iter().collect();
+    rule_counts.sort_by(|a, b| b.1.cmp(&a.1));
 
-    if !code_counts.is_empty() {
-        result.push_str("Top error codes:\n");
-        for (code, count) in code_counts.iter().take(10) {
-            result.push_str(&format!("  {} ({}x)\n", code, count));
+    if !rule_counts.is_empty() {
+        result.push_str("Top rules:\n");
+        for (rule, count) in rule_counts.iter().take(10) {
+            result.push_str(&format!("  {} ({}x)\n", rule, count));
         }
         result.push('\n');
     }
 
-    // Show top files
     result.push_str("Top files:\n");
     for (file, count) in file_counts.iter().take(10) {
         let short_path = compact_path(file);
         result.push_str(&format!("  {} ({} issues)\n", short_path, count));
 
-        // Show top 3 error codes in this file
-        let mut file_codes: HashMap<String, usize> = HashMap::new();
-        for (_f, _, _, code) in issues.iter().filter(|(f, _, _, _)| f == *file) {
-            *file_codes.entry(code.clone()).or_insert(0) += 1;
+        let mut file_rules: HashMap<&str, usize> = HashMap::new();
+        for issue in issues.iter().filter(|i| i.file_path == *file) {
+            *file_rules.entry(issue.rule_id.as_str()).or_insert(0) += 1;
         }
 
-        let mut file_code_counts: Vec<_> = file_codes.iter().collect();
-        file_code_counts.sort_by(|a, b| b.1.cmp(a.1));
+        let mut file_rule_counts: Vec<_> = file_rules.into_iter().collect();
+        file_rule_counts.sort_by(|a, b| b.1.cmp(&a.1));
 
-        for (code, count) in file_code_counts.iter().take(3) {
-            result.push_str(&format!("    {} ({})\n", code, count));
+        for (rule, count) in file_rule_counts.iter().take(3) {
+            result.push_str(&format!("    {} ({})\n", rule, count));
         }
     }
 
@@ -512,41 +1423,86 @@ fn filter_mypy_output(output: &str) -> String {
     result.trim().to_string()
 }
 
-/// Filter generic linter output (fallback for non-ESLint linters)
-fn filter_generic_lint(output: &str) -> String {
-    let mut warnings = 0;
-    let mut errors = 0;
-    let mut issues: Vec<String> = Vec::new();
+/// Build the canonical [`LintResult`] for a diagnostic list and render it
+/// through the existing `JsonFormatter` impl, so `rtk lint --rtk-format=json`
+/// produces the same schema `rtk diff` already knows how to compare.
+fn render_json(issues: &[LintIssue]) -> String {
+    let unique_files: HashSet<_> = issues.iter().map(|i| &i.file_path).collect();
+    let total_files = unique_files.len();
+    let errors = issues
+        .iter()
+        .filter(|i| i.severity == LintSeverity::Error)
+        .count();
+    let warnings = issues
+        .iter()
+        .filter(|i| i.severity == LintSeverity::Warning)
+        .count();
+
+    let result = LintResult {
+        total_files,
+        files_with_issues: total_files,
+        total_issues: issues.len(),
+        errors,
+        warnings,
+        issues: issues.to_vec(),
+    };
+
+    serde_json::to_string_pretty(&result.format_json()).unwrap_or_default()
+}
 
-    for line in output.lines() {
-        let line_lower = line.to_lowercase();
-        if line_lower.contains("warning") {
-            warnings += 1;
-            issues.push(line.to_string());
-        }
-        if line_lower.contains("error") && !line_lower.contains("0 error") {
-            errors += 1;
-            issues.push(line.to_string());
-        }
+/// Render diagnostics as a SARIF 2.1.0 log, grouping issues into one `run`
+/// per linter (mirrors tools like ESLint's own `--format sarif` / golangci's
+/// SARIF exporter) so downstream SARIF consumers (GitHub code scanning, etc.)
+/// see a properly attributed tool driver per run.
+fn render_sarif(issues: &[LintIssue]) -> String {
+    let mut by_linter: std::collections::BTreeMap<&str, Vec<&LintIssue>> =
+        std::collections::BTreeMap::new();
+    for issue in issues {
+        by_linter.entry(issue.linter.as_str()).or_default().push(issue);
     }
 
-    if errors == 0 && warnings == 0 {
-        return "✓ Lint: No issues found".to_string();
-    }
+    let runs: Vec<Value> = by_linter
+        .into_iter()
+        .map(|(linter, issues)| {
+            let results: Vec<Value> = issues
+                .iter()
+                .map(|issue| {
+                    json!({
+                        "ruleId": issue.rule_id,
+                        "level": sarif_level(issue.severity),
+                        "message": {"text": issue.message},
+                        "locations": [{
+                            "physicalLocation": {
+                                "artifactLocation": {"uri": issue.file_path},
+                                "region": {"startLine": issue.line, "startColumn": issue.column}
+                            }
+                        }]
+                    })
+                })
+                .collect();
+
+            json!({
+                "tool": {"driver": {"name": linter}},
+                "results": results,
+            })
+        })
+        .collect();
 
-    let mut result = String::new();
-    result.push_str(&format!("Lint: {} errors, {} warnings\n", errors, warnings));
-    result.push_str("═══════════════════════════════════════\n");
+    let sarif = json!({
+        "version": "2.1.0",
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "runs": runs,
+    });
 
-    for issue in issues.iter().take(20) {
-        result.push_str(&format!("{}\n", truncate(issue, 100)));
-    }
+    serde_json::to_string_pretty(&sarif).unwrap_or_default()
+}
 
-    if issues.len() > 20 {
-        result.push_str(&format!("\n... +{} more issues\n", issues.len() - 20));
+fn sarif_level(severity: LintSeverity) -> &'static str {
+    match severity {
+        LintSeverity::Error => "error",
+        LintSeverity::Warning => "warning",
+        LintSeverity::Info => "note",
     }
-
-    result.trim().to_string()
 }
 
 /// Compact file path (remove common prefixes)
@@ -570,7 +1526,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_filter_eslint_json() {
+    fn test_parse_eslint_diagnostics() {
         let json = r#"[
             {
                 "filePath": "/Users/test/project/src/utils.ts",
@@ -609,7 +1565,11 @@ mod tests {
             }
         ]"#;
 
-        let result = filter_eslint_json(json);
+        let issues = parse_eslint_diagnostics(json).unwrap();
+        assert_eq!(issues.len(), 3);
+        assert!(issues.iter().all(|i| i.linter == "eslint"));
+
+        let result = render_pretty("ESLint", &issues);
         assert!(result.contains("ESLint:"));
         assert!(result.contains("prefer-const"));
         assert!(result.contains("no-unused-vars"));
@@ -630,15 +1590,15 @@ mod tests {
     }
 
     #[test]
-    fn test_filter_pylint_json_no_issues() {
-        let output = "[]";
-        let result = filter_pylint_json(output);
+    fn test_parse_pylint_diagnostics_no_issues() {
+        let issues = parse_pylint_diagnostics("[]").unwrap();
+        let result = render_pretty("Pylint", &issues);
         assert!(result.contains("✓ Pylint"));
         assert!(result.contains("No issues found"));
     }
 
     #[test]
-    fn test_filter_pylint_json_with_issues() {
+    fn test_parse_pylint_diagnostics_with_issues() {
         let json = r#"[
             {
                 "type": "warning",
@@ -675,9 +1635,10 @@ mod tests {
             }
         ]"#;
 
-        let result = filter_pylint_json(json);
-        assert!(result.contains("3 issues"));
-        assert!(result.contains("2 files"));
+        let issues = parse_pylint_diagnostics(json).unwrap();
+        assert_eq!(issues.len(), 3);
+
+        let result = render_pretty("Pylint", &issues);
         assert!(result.contains("1 errors, 2 warnings"));
         assert!(result.contains("unused-variable (W0612)"));
         assert!(result.contains("undefined-variable (E0602)"));
@@ -686,24 +1647,87 @@ mod tests {
     }
 
     #[test]
-    fn test_filter_mypy_no_issues() {
+    fn test_parse_flake8_diagnostics_with_issues() {
+        let json = r#"{
+            "src/main.py": [
+                {
+                    "code": "E501",
+                    "filename": "src/main.py",
+                    "line_number": 10,
+                    "column_number": 80,
+                    "text": "line too long (90 > 79 characters)",
+                    "physical_line": "x = 1\n"
+                },
+                {
+                    "code": "W291",
+                    "filename": "src/main.py",
+                    "line_number": 12,
+                    "column_number": 1,
+                    "text": "trailing whitespace",
+                    "physical_line": "y = 2 \n"
+                }
+            ]
+        }"#;
+
+        let issues = parse_flake8_diagnostics(json).unwrap();
+        assert_eq!(issues.len(), 2);
+        assert!(issues
+            .iter()
+            .any(|i| i.rule_id == "E501" && i.severity == LintSeverity::Error));
+        assert!(issues
+            .iter()
+            .any(|i| i.rule_id == "W291" && i.severity == LintSeverity::Warning));
+    }
+
+    #[test]
+    fn test_parse_flake8_diagnostics_invalid_json() {
+        assert!(parse_flake8_diagnostics("not json").is_err());
+    }
+
+    #[test]
+    fn test_parse_json_adapter_maps_fields() {
+        let json = r#"[
+            {"path": "src/a.go", "rule": "unused-import", "lineno": 3, "msg": "imported and not used"}
+        ]"#;
+        let mapping =
+            parse_adapter_mapping("file=path,code=rule,line=lineno,message=msg");
+
+        let issues = parse_json_adapter(json, &mapping, "customlint");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].file_path, "src/a.go");
+        assert_eq!(issues[0].rule_id, "unused-import");
+        assert_eq!(issues[0].line, 3);
+        assert_eq!(issues[0].message, "imported and not used");
+        assert_eq!(issues[0].linter, "customlint");
+    }
+
+    #[test]
+    fn test_parse_json_adapter_non_array_yields_no_issues() {
+        let mapping = parse_adapter_mapping("file=path,code=rule");
+        assert!(parse_json_adapter("{}", &mapping, "customlint").is_empty());
+    }
+
+    #[test]
+    fn test_parse_mypy_diagnostics_no_issues() {
         let output = "Success: no issues found in 5 source files";
-        let result = filter_mypy_output(output);
+        let issues = parse_mypy_diagnostics(output).unwrap();
+        let result = render_pretty("Mypy", &issues);
         assert!(result.contains("✓ Mypy"));
         assert!(result.contains("No issues found"));
     }
 
     #[test]
-    fn test_filter_mypy_with_errors() {
+    fn test_parse_mypy_diagnostics_with_errors() {
         let output = r#"src/main.py:10: error: Incompatible return value type [return-value]
 src/main.py:15: error: Argument 1 has incompatible type "str"; expected "int" [arg-type]
 src/utils.py:20: error: Name "foo" is not defined [name-defined]
 src/utils.py:25: warning: Unused "type: ignore" comment [unused-ignore]
 Found 4 errors in 2 files (checked 5 source files)"#;
 
-        let result = filter_mypy_output(output);
-        assert!(result.contains("4 issues"));
-        assert!(result.contains("2 files"));
+        let issues = parse_mypy_diagnostics(output).unwrap();
+        assert_eq!(issues.len(), 4);
+
+        let result = render_pretty("Mypy", &issues);
         assert!(result.contains("3 errors, 1 warnings"));
         assert!(result.contains("return-value"));
         assert!(result.contains("arg-type"));
@@ -712,6 +1736,271 @@ Found 4 errors in 2 files (checked 5 source files)"#;
         assert!(result.contains("utils.py"));
     }
 
+    #[test]
+    fn test_parse_generic_diagnostics_flat_list() {
+        let output = "some warning: be careful\nan error occurred\nall clear";
+        let issues = parse_generic_diagnostics(output, "unknown-tool");
+        let result = render_pretty("Lint", &issues);
+        assert!(result.contains("1 errors, 1 warnings"));
+        assert!(result.contains("some warning: be careful"));
+        assert!(result.contains("an error occurred"));
+    }
+
+    #[test]
+    fn test_format_parse() {
+        assert_eq!(Format::parse("pretty"), Some(Format::Pretty));
+        assert_eq!(Format::parse("json"), Some(Format::Json));
+        assert_eq!(Format::parse("sarif"), Some(Format::Sarif));
+        assert_eq!(Format::parse("bogus"), None);
+    }
+
+    #[test]
+    fn test_render_json_roundtrip() {
+        let issues = parse_eslint_diagnostics(
+            r#"[{"filePath": "a.ts", "messages": [{"ruleId": "no-foo", "severity": 2, "message": "bad", "line": 1, "column": 1}], "errorCount": 1, "warningCount": 0}]"#,
+        )
+        .unwrap();
+
+        let rendered = render_json(&issues);
+        let value: Value = serde_json::from_str(&rendered).unwrap();
+        assert_eq!(value["kind"], "lint_result");
+        assert_eq!(value["errors"], 1);
+        assert_eq!(value["issues"][0]["linter"], "eslint");
+    }
+
+    #[test]
+    fn test_render_sarif_groups_by_linter() {
+        let issues = parse_mypy_diagnostics("src/a.py:1: error: bad [misc]").unwrap();
+        let rendered = render_sarif(&issues);
+        let value: Value = serde_json::from_str(&rendered).unwrap();
+        assert_eq!(value["version"], "2.1.0");
+        assert_eq!(value["runs"][0]["tool"]["driver"]["name"], "mypy");
+        assert_eq!(value["runs"][0]["results"][0]["ruleId"], "misc");
+    }
+
+    #[test]
+    fn test_relabel_stdin_path_full_and_basename() {
+        let temp_path = Path::new("/tmp/rtk-lint-stdin-abc123.ts");
+
+        let full = "error in /tmp/rtk-lint-stdin-abc123.ts at line 3";
+        assert_eq!(
+            relabel_stdin_path(full, temp_path, "src/app.ts"),
+            "error in src/app.ts at line 3"
+        );
+
+        let compacted = "Top files:\n  rtk-lint-stdin-abc123.ts (2 issues)";
+        assert_eq!(
+            relabel_stdin_path(compacted, temp_path, "src/app.ts"),
+            "Top files:\n  src/app.ts (2 issues)"
+        );
+    }
+
+    #[test]
+    fn test_count_severities_tallies_by_kind() {
+        let issues = vec![
+            LintIssue {
+                file_path: "a.ts".to_string(),
+                line: 1,
+                column: 1,
+                severity: LintSeverity::Error,
+                rule_id: "no-foo".to_string(),
+                message: "bad".to_string(),
+                linter: "eslint".to_string(),
+            },
+            LintIssue {
+                file_path: "b.ts".to_string(),
+                line: 2,
+                column: 1,
+                severity: LintSeverity::Warning,
+                rule_id: "no-bar".to_string(),
+                message: "meh".to_string(),
+                linter: "eslint".to_string(),
+            },
+            LintIssue {
+                file_path: "c.ts".to_string(),
+                line: 3,
+                column: 1,
+                severity: LintSeverity::Info,
+                rule_id: "note".to_string(),
+                message: "fyi".to_string(),
+                linter: "eslint".to_string(),
+            },
+        ];
+
+        let (mut errors, mut warnings) = (0, 0);
+        count_severities(&issues, &mut errors, &mut warnings);
+        assert_eq!(errors, 1);
+        assert_eq!(warnings, 1);
+    }
+
+    #[test]
+    fn test_filter_against_baseline_hides_only_matching_triple() {
+        let baselined = LintIssue {
+            file_path: "src/legacy.py".to_string(),
+            line: 40,
+            column: 1,
+            severity: LintSeverity::Warning,
+            rule_id: "unused-variable".to_string(),
+            message: "Unused variable 'x'".to_string(),
+            linter: "pylint".to_string(),
+        };
+        // Same file/rule/message, different line - still a match, since
+        // baseline keys deliberately ignore line drift.
+        let shifted_line = LintIssue {
+            line: 45,
+            ..baselined.clone()
+        };
+        let genuinely_new = LintIssue {
+            message: "Unused variable 'y'".to_string(),
+            ..baselined.clone()
+        };
+
+        let mut baseline = HashSet::new();
+        baseline.insert(baseline_key(&baselined));
+
+        let (kept, hidden) =
+            filter_against_baseline(vec![shifted_line, genuinely_new.clone()], &baseline);
+        assert_eq!(hidden, 1);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].message, genuinely_new.message);
+    }
+
+    #[test]
+    fn test_save_and_load_baseline_roundtrip() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("lint-baseline.json");
+        let path_str = path.to_string_lossy().to_string();
+
+        let issue = LintIssue {
+            file_path: "src/main.ts".to_string(),
+            line: 1,
+            column: 1,
+            severity: LintSeverity::Error,
+            rule_id: "no-foo".to_string(),
+            message: "bad".to_string(),
+            linter: "eslint".to_string(),
+        };
+        save_baseline(&path_str, std::slice::from_ref(&issue)).unwrap();
+
+        let loaded = load_baseline(&path_str);
+        assert_eq!(loaded.len(), 1);
+        assert!(loaded.contains(&baseline_key(&issue)));
+    }
+
+    #[test]
+    fn test_path_filter_include_and_not_exclude() {
+        let filter = PathFilter {
+            include: vec![PathPattern::Prefix("src/".to_string())],
+            exclude: vec![PathPattern::Glob("src/generated/*".to_string())],
+        };
+        assert!(filter.allows("src/main.ts"));
+        assert!(!filter.allows("src/generated/schema.ts"));
+        assert!(!filter.allows("tests/main.ts"));
+    }
+
+    #[test]
+    fn test_path_filter_empty_include_matches_everything() {
+        let filter = PathFilter {
+            include: vec![],
+            exclude: vec![PathPattern::Prefix("vendor/".to_string())],
+        };
+        assert!(filter.allows("src/main.ts"));
+        assert!(!filter.allows("vendor/lib.ts"));
+    }
+
+    #[test]
+    fn test_path_filter_load_merges_ignore_file_and_flags() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let ignore_path = dir.path().join(".rtklintignore");
+        std::fs::write(&ignore_path, "# comment\npath:dist/\n*.generated.ts\n").unwrap();
+        let ignore_path_str = ignore_path.to_string_lossy().to_string();
+
+        let filter = PathFilter::load(
+            &ignore_path_str,
+            &[],
+            &["path:tests/fixtures/".to_string()],
+        );
+        assert!(!filter.allows("dist/bundle.ts"));
+        assert!(!filter.allows("src/schema.generated.ts"));
+        assert!(!filter.allows("tests/fixtures/broken.ts"));
+        assert!(filter.allows("src/main.ts"));
+    }
+
+    #[test]
+    fn test_detect_linter() {
+        assert_eq!(detect_linter(&[]), "eslint");
+        assert_eq!(detect_linter(&["src/main.ts".to_string()]), "eslint");
+        assert_eq!(detect_linter(&["--fix".to_string()]), "eslint");
+        assert_eq!(detect_linter(&["pylint".to_string()]), "pylint");
+        assert_eq!(
+            detect_linter(&["mypy".to_string(), "src".to_string()]),
+            "mypy"
+        );
+    }
+
+    #[test]
+    fn test_hash_bytes_stable_and_content_sensitive() {
+        let a = hash_bytes(b"fn main() {}");
+        let b = hash_bytes(b"fn main() {}");
+        let c = hash_bytes(b"fn main() { println!(); }");
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_update_cache_and_merge_passthrough_when_cache_unused() {
+        let mut cache = LintCache::default();
+        let fresh = vec![generic_issue("boom", LintSeverity::Error, "ruff")];
+        let merged = update_cache_and_merge(false, &mut cache, "ruff", &[], Vec::new(), fresh.clone());
+        assert_eq!(merged.len(), fresh.len());
+        assert!(cache.linters.is_empty());
+    }
+
+    #[test]
+    fn test_update_cache_and_merge_combines_cached_and_fresh() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let file = dir.path().join("changed.py");
+        std::fs::write(&file, "x = 1\n").unwrap();
+        let changed_path = canonical_path(&file);
+
+        let mut cache = LintCache::default();
+        let cached_issues = vec![LintIssue {
+            file_path: "unchanged.py".to_string(),
+            line: 1,
+            column: 1,
+            severity: LintSeverity::Warning,
+            rule_id: "stale-rule".to_string(),
+            message: "from a previous run".to_string(),
+            linter: "pylint".to_string(),
+        }];
+        let fresh_issues = vec![LintIssue {
+            file_path: changed_path.clone(),
+            line: 2,
+            column: 1,
+            severity: LintSeverity::Error,
+            rule_id: "new-rule".to_string(),
+            message: "freshly parsed".to_string(),
+            linter: "pylint".to_string(),
+        }];
+
+        let merged = update_cache_and_merge(
+            true,
+            &mut cache,
+            "pylint",
+            &[changed_path.clone()],
+            cached_issues,
+            fresh_issues,
+        );
+
+        assert_eq!(merged.len(), 2);
+        assert!(merged.iter().any(|i| i.rule_id == "stale-rule"));
+        assert!(merged.iter().any(|i| i.rule_id == "new-rule"));
+
+        let entry = cache.linters["pylint"].files.get(&changed_path).unwrap();
+        assert_eq!(entry.content_hash, hash_bytes(b"x = 1\n"));
+        assert_eq!(entry.issues.len(), 1);
+    }
+
     #[test]
     fn test_is_python_linter() {
         assert!(is_python_linter("ruff"));
@@ -0,0 +1,229 @@
+//! Concurrent multi-tool lint orchestrator.
+//!
+//! Runs several linters as child processes at once instead of the serial
+//! blocking `Command::output()` used elsewhere (see [`crate::ruff_cmd`]),
+//! merging each one's filtered summary into a single compact report.
+//! Children are spawned with `spawn()` and reaped with a non-blocking
+//! `try_wait()` poll loop -- a "poor man's async" -- so one slow tool
+//! doesn't head-of-line-block the others; only once every remaining child
+//! is still running does the loop fall back to a single blocking `wait()`.
+
+use crate::glob_filter::GlobFilter;
+use crate::ruff_cmd;
+use crate::tracking;
+use anyhow::{Context, Result};
+use std::io::Read;
+use std::process::{Child, Command, Stdio};
+
+/// A spawned linter child, paired with the label used in the merged report.
+struct Task {
+    label: &'static str,
+    render: fn(&str) -> String,
+    /// Whether `render` expects stdout+stderr combined (ruff's format
+    /// command prints its summary to stderr) or stdout alone (ruff's JSON
+    /// check output, where stderr would corrupt the parse).
+    combine_stderr: bool,
+    child: Child,
+}
+
+/// One finished task's contribution to the merged report.
+struct Finished {
+    label: &'static str,
+    section: String,
+    exit_code: i32,
+}
+
+pub fn run(verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let mut tasks = vec![
+        spawn_task(
+            "ruff check",
+            "ruff",
+            &["check", "--output-format=json", "."],
+            |stdout| ruff_cmd::filter_ruff_check_json(stdout, &GlobFilter::new(&[]).unwrap()),
+            false,
+            verbose,
+        )?,
+        spawn_task(
+            "ruff format --check",
+            "ruff",
+            &["format", "--check", "."],
+            ruff_cmd::filter_ruff_format,
+            true,
+            verbose,
+        )?,
+    ];
+
+    let mut finished: Vec<Finished> = Vec::new();
+
+    while !tasks.is_empty() {
+        let mut reaped_any = false;
+        let mut i = 0;
+        while i < tasks.len() {
+            match tasks[i].child.try_wait() {
+                Ok(Some(status)) => {
+                    let task = tasks.remove(i);
+                    finished.push(finish_task(task, status.code().unwrap_or(1)));
+                    reaped_any = true;
+                }
+                Ok(None) => i += 1,
+                Err(e) => {
+                    let task = tasks.remove(i);
+                    finished.push(Finished {
+                        label: task.label,
+                        section: format!("{}: failed to poll child ({})", task.label, e),
+                        exit_code: 1,
+                    });
+                    reaped_any = true;
+                }
+            }
+        }
+
+        // Every remaining child is still running -- fall back to a single
+        // blocking wait instead of busy-looping on try_wait().
+        if !reaped_any && !tasks.is_empty() {
+            let task = tasks.remove(0);
+            let status = task.child.wait().context("failed to wait for linter")?;
+            finished.push(finish_task(task, status.code().unwrap_or(1)));
+        }
+    }
+
+    let (report, worst_exit_code) = render_report(&finished);
+
+    println!("{}", report);
+    timer.track(
+        "ruff check + ruff format --check (concurrent)",
+        "rtk lint-all",
+        "",
+        &report,
+    );
+
+    if worst_exit_code != 0 {
+        std::process::exit(worst_exit_code);
+    }
+
+    Ok(())
+}
+
+/// Merge each task's section into one report and surface the worst
+/// non-zero exit code, so CI sees a failure if any linter found issues.
+fn render_report(finished: &[Finished]) -> (String, i32) {
+    let worst_exit_code = finished.iter().map(|f| f.exit_code).max().unwrap_or(0);
+
+    let mut report = String::new();
+    for task in finished {
+        report.push_str(&format!("── {} ──\n", task.label));
+        report.push_str(task.section.trim());
+        report.push_str("\n\n");
+    }
+
+    (report.trim().to_string(), worst_exit_code)
+}
+
+fn spawn_task(
+    label: &'static str,
+    cmd: &str,
+    args: &[&str],
+    render: fn(&str) -> String,
+    combine_stderr: bool,
+    verbose: u8,
+) -> Result<Task> {
+    if verbose > 0 {
+        eprintln!("Spawning: {} {}", cmd, args.join(" "));
+    }
+
+    let child = Command::new(cmd)
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to spawn {} {}", cmd, args.join(" ")))?;
+
+    Ok(Task {
+        label,
+        render,
+        combine_stderr,
+        child,
+    })
+}
+
+fn finish_task(mut task: Task, exit_code: i32) -> Finished {
+    let mut stdout = String::new();
+    if let Some(mut out) = task.child.stdout.take() {
+        let _ = out.read_to_string(&mut stdout);
+    }
+    let mut stderr = String::new();
+    if let Some(mut err) = task.child.stderr.take() {
+        let _ = err.read_to_string(&mut stderr);
+    }
+
+    let section = if stdout.trim().is_empty() && stderr.trim().is_empty() {
+        format!("{}: no output", task.label)
+    } else if task.combine_stderr {
+        (task.render)(&format!("{}\n{}", stdout, stderr))
+    } else {
+        (task.render)(&stdout)
+    };
+
+    Finished {
+        label: task.label,
+        section,
+        exit_code,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_report_merges_sections_in_order() {
+        let finished = vec![
+            Finished {
+                label: "ruff check",
+                section: "✓ Ruff: No issues found".to_string(),
+                exit_code: 0,
+            },
+            Finished {
+                label: "ruff format --check",
+                section: "2 files need formatting".to_string(),
+                exit_code: 1,
+            },
+        ];
+
+        let (report, worst_exit_code) = render_report(&finished);
+        assert!(report.contains("ruff check"));
+        assert!(report.contains("No issues found"));
+        assert!(report.contains("ruff format --check"));
+        assert!(report.contains("2 files need formatting"));
+        assert_eq!(worst_exit_code, 1);
+        assert!(report.find("ruff check").unwrap() < report.find("ruff format --check").unwrap());
+    }
+
+    #[test]
+    fn test_render_report_all_clean_is_zero_exit() {
+        let finished = vec![
+            Finished {
+                label: "ruff check",
+                section: "✓ Ruff: No issues found".to_string(),
+                exit_code: 0,
+            },
+            Finished {
+                label: "ruff format --check",
+                section: "✓ Ruff format: All files formatted correctly".to_string(),
+                exit_code: 0,
+            },
+        ];
+
+        let (_, worst_exit_code) = render_report(&finished);
+        assert_eq!(worst_exit_code, 0);
+    }
+
+    #[test]
+    fn test_render_report_empty_is_zero_exit() {
+        let (report, worst_exit_code) = render_report(&[]);
+        assert!(report.is_empty());
+        assert_eq!(worst_exit_code, 0);
+    }
+}
@@ -1,12 +1,15 @@
+use annotate_snippets::display_list::{DisplayList, FormatOptions};
+use annotate_snippets::snippet::{AnnotationType, Slice, Snippet, SourceAnnotation};
 use anyhow::{Context, Result};
 use regex::Regex;
 use std::fs;
+use std::ops::Range;
 use std::path::Path;
 
 use crate::filter::Language;
 
 /// Heuristic-based code summarizer - no external model needed
-pub fn run(file: &Path, _model: &str, _force_download: bool, verbose: u8) -> Result<()> {
+pub fn run(file: &Path, _model: &str, _force_download: bool, format: &str, verbose: u8) -> Result<()> {
     if verbose > 0 {
         eprintln!("Analyzing: {}", file.display());
     }
@@ -20,6 +23,11 @@ pub fn run(file: &Path, _model: &str, _force_download: bool, verbose: u8) -> Res
         .map(Language::from_extension)
         .unwrap_or(Language::Unknown);
 
+    if format == "annotated" {
+        println!("{}", render_annotated(&content, &lang));
+        return Ok(());
+    }
+
     let summary = analyze_code(&content, &lang);
 
     println!("{}", summary.line1);
@@ -34,9 +42,310 @@ struct CodeSummary {
 }
 
 fn analyze_code(content: &str, lang: &Language) -> CodeSummary {
-    let lines: Vec<&str> = content.lines().collect();
-    let total_lines = lines.len();
+    let total_lines = content.lines().count();
+
+    match crate::ts::extract(content, lang) {
+        Some(extracted) => analyze_code_from_ast(content, lang, total_lines, extracted),
+        None => analyze_code_from_regex(content, lang, total_lines),
+    }
+}
+
+/// AST-backed path: real function signatures and doc comments for the top
+/// definitions, accurate counts even across multi-line signatures/generics.
+/// Used whenever `ts::extract` has a grammar for `lang`.
+fn analyze_code_from_ast(
+    content: &str,
+    lang: &Language,
+    total_lines: usize,
+    extracted: crate::ts::ExtractResult,
+) -> CodeSummary {
+    use crate::ts::DefinitionKind;
+
+    let crate::ts::ExtractResult {
+        imports,
+        functions,
+        types,
+        ..
+    } = extracted;
+
+    let structs: Vec<&crate::ts::Definition> = types
+        .iter()
+        .filter(|d| d.kind == DefinitionKind::Type)
+        .collect();
+    let traits: Vec<&crate::ts::Definition> = types
+        .iter()
+        .filter(|d| d.kind == DefinitionKind::Interface)
+        .collect();
+    let imports = normalize_imports(imports, lang);
+    let patterns = detect_patterns(content, lang);
+
+    let lang_name = lang_display_name(lang);
+    let main_type = if !structs.is_empty() && !functions.is_empty() {
+        format!("{} module", lang_name)
+    } else if !structs.is_empty() {
+        format!("{} data structures", lang_name)
+    } else if !functions.is_empty() {
+        format!("{} functions", lang_name)
+    } else {
+        format!("{} code", lang_name)
+    };
+
+    let components: Vec<String> = [
+        (!functions.is_empty()).then(|| format!("{} fn", functions.len())),
+        (!structs.is_empty()).then(|| format!("{} struct", structs.len())),
+        (!traits.is_empty()).then(|| format!("{} trait", traits.len())),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    let line1 = if components.is_empty() {
+        format!("{} ({} lines)", main_type, total_lines)
+    } else {
+        format!(
+            "{} ({}) - {} lines",
+            main_type,
+            components.join(", "),
+            total_lines
+        )
+    };
+
+    let mut details = Vec::new();
+
+    if !imports.is_empty() {
+        let key_imports: Vec<&str> = imports.iter().take(3).map(|s| s.as_str()).collect();
+        details.push(format!("uses: {}", key_imports.join(", ")));
+    }
+
+    if !patterns.is_empty() {
+        details.push(format!("patterns: {}", patterns.join(", ")));
+    }
+
+    if details.is_empty() && !functions.is_empty() {
+        let top: Vec<String> = functions.iter().take(3).map(describe_definition).collect();
+        details.push(format!("defines: {}", top.join("; ")));
+    }
+
+    let line2 = if details.is_empty() {
+        "General purpose code file".to_string()
+    } else {
+        details.join(" | ")
+    };
+
+    CodeSummary { line1, line2 }
+}
+
+/// One named symbol with the byte range of just its identifier, for
+/// underlining in an annotated snippet.
+struct AnnotatedSymbol {
+    label: &'static str,
+    byte_start: usize,
+    byte_end: usize,
+}
+
+/// `--format=annotated`: render the key definitions as diagnostic-style
+/// source snippets, name underlined, instead of the two-line summary.
+fn render_annotated(content: &str, lang: &Language) -> String {
+    let symbols = collect_annotated_symbols(content, lang);
+    if symbols.is_empty() {
+        return "No definitions found to annotate".to_string();
+    }
+
+    let line_spans = line_byte_spans(content);
+    symbols
+        .iter()
+        .map(|sym| render_symbol_snippet(content, &line_spans, sym))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn collect_annotated_symbols(content: &str, lang: &Language) -> Vec<AnnotatedSymbol> {
+    let mut symbols = match crate::ts::extract(content, lang) {
+        Some(extracted) => annotated_symbols_from_ast(extracted),
+        None => annotated_symbols_from_regex(content, lang),
+    };
+    symbols.sort_by_key(|s| s.byte_start);
+    symbols
+}
+
+fn annotated_symbols_from_ast(extracted: crate::ts::ExtractResult) -> Vec<AnnotatedSymbol> {
+    use crate::ts::DefinitionKind;
+
+    let crate::ts::ExtractResult {
+        import_ranges,
+        functions,
+        types,
+        ..
+    } = extracted;
+
+    let mut symbols: Vec<AnnotatedSymbol> = import_ranges
+        .into_iter()
+        .map(|range| AnnotatedSymbol {
+            label: "import",
+            byte_start: range.start,
+            byte_end: range.end,
+        })
+        .collect();
+
+    symbols.extend(functions.into_iter().map(|def| AnnotatedSymbol {
+        label: "fn",
+        byte_start: def.name_range.start,
+        byte_end: def.name_range.end,
+    }));
+
+    symbols.extend(types.into_iter().map(|def| AnnotatedSymbol {
+        label: match def.kind {
+            DefinitionKind::Interface => "trait",
+            DefinitionKind::Type | DefinitionKind::Function => "struct",
+        },
+        byte_start: def.name_range.start,
+        byte_end: def.name_range.end,
+    }));
+
+    symbols
+}
+
+/// Fallback path for languages with no tree-sitter grammar: re-run the
+/// same patterns `extract_*` uses, but keep the match's byte offset
+/// instead of just its text.
+fn annotated_symbols_from_regex(content: &str, lang: &Language) -> Vec<AnnotatedSymbol> {
+    let mut symbols = Vec::new();
+    if let Some(pattern) = import_pattern(lang) {
+        symbols.extend(regex_line_spans(content, pattern, "import"));
+    }
+    if let Some(pattern) = function_pattern(lang) {
+        symbols.extend(regex_line_spans(content, pattern, "fn"));
+    }
+    if let Some(pattern) = struct_pattern(lang) {
+        symbols.extend(regex_content_spans(content, pattern, "struct"));
+    }
+    if let Some(pattern) = trait_pattern(lang) {
+        symbols.extend(regex_content_spans(content, pattern, "trait"));
+    }
+    symbols
+}
+
+/// Scan `content` line by line (matching `extract_imports`/
+/// `extract_functions`'s per-line semantics) and keep each match's
+/// absolute byte range.
+fn regex_line_spans(content: &str, pattern: &str, label: &'static str) -> Vec<AnnotatedSymbol> {
+    let re = Regex::new(pattern).unwrap();
+    let mut symbols = Vec::new();
+    let mut offset = 0;
+
+    for line in content.split('\n') {
+        if let Some(caps) = re.captures(line) {
+            if let Some(m) = caps.get(1).or_else(|| caps.get(2)) {
+                symbols.push(AnnotatedSymbol {
+                    label,
+                    byte_start: offset + m.start(),
+                    byte_end: offset + m.end(),
+                });
+            }
+        }
+        offset += line.len() + 1;
+    }
+
+    symbols
+}
+
+/// Scan the whole `content` at once (matching `extract_structs`/
+/// `extract_traits`'s semantics), keeping each match's byte range.
+fn regex_content_spans(content: &str, pattern: &str, label: &'static str) -> Vec<AnnotatedSymbol> {
+    let re = Regex::new(pattern).unwrap();
+    re.captures_iter(content)
+        .filter_map(|caps| caps.get(1))
+        .map(|m| AnnotatedSymbol {
+            label,
+            byte_start: m.start(),
+            byte_end: m.end(),
+        })
+        .collect()
+}
+
+/// Byte `(start, end)` span of every line in `content`, in order.
+fn line_byte_spans(content: &str) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut start = 0;
+    for line in content.split('\n') {
+        let end = start + line.len();
+        spans.push(start..end);
+        start = end + 1;
+    }
+    spans
+}
+
+fn line_index_for_byte(line_spans: &[Range<usize>], byte: usize) -> usize {
+    line_spans
+        .iter()
+        .position(|span| byte >= span.start && byte <= span.end)
+        .unwrap_or_else(|| line_spans.len().saturating_sub(1))
+}
+
+/// Render one symbol as a diagnostic-style snippet: a line of context
+/// before and after, with the identifier underlined and labeled.
+fn render_symbol_snippet(content: &str, line_spans: &[Range<usize>], sym: &AnnotatedSymbol) -> String {
+    let symbol_line = line_index_for_byte(line_spans, sym.byte_start);
+    let context_start = symbol_line.saturating_sub(1);
+    let context_end = (symbol_line + 1).min(line_spans.len() - 1);
+
+    let window_start = line_spans[context_start].start;
+    let window_end = line_spans[context_end].end;
+    let source = &content[window_start..window_end];
+
+    let snippet = Snippet {
+        title: None,
+        footer: vec![],
+        slices: vec![Slice {
+            source,
+            line_start: context_start + 1,
+            origin: None,
+            fold: false,
+            annotations: vec![SourceAnnotation {
+                label: sym.label,
+                annotation_type: AnnotationType::Info,
+                range: (sym.byte_start - window_start, sym.byte_end - window_start),
+            }],
+        }],
+        opt: FormatOptions {
+            color: false,
+            ..Default::default()
+        },
+    };
 
+    DisplayList::from(snippet).to_string()
+}
+
+/// Render one definition as `name(signature) - doc`, used for the top-3
+/// definitions line2 falls back to when there are no imports/patterns.
+fn describe_definition(def: &crate::ts::Definition) -> String {
+    let mut out = format!("{}{}", def.name, def.signature.as_deref().unwrap_or(""));
+    if let Some(doc) = def.doc.as_deref().filter(|d| !d.is_empty()) {
+        out.push_str(" - ");
+        out.push_str(doc);
+    }
+    out
+}
+
+/// Keep just the first path segment of each import, drop stdlib ones, and
+/// dedupe while preserving first-seen order - the same normalization
+/// `extract_imports`'s regex path applies inline.
+fn normalize_imports(raw: Vec<String>, lang: &Language) -> Vec<String> {
+    let mut imports = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+    for imp in raw {
+        let base = imp.split("::").next().unwrap_or(&imp).to_string();
+        if !seen.contains(&base) && !is_std_import(&base, lang) {
+            seen.insert(base.clone());
+            imports.push(base);
+        }
+    }
+    imports.into_iter().take(5).collect()
+}
+
+/// Regex-based path, used when no tree-sitter grammar is wired in for
+/// `lang`.
+fn analyze_code_from_regex(content: &str, lang: &Language, total_lines: usize) -> CodeSummary {
     // Extract components
     let imports = extract_imports(content, lang);
     let functions = extract_functions(content, lang);
@@ -125,15 +434,21 @@ fn lang_display_name(lang: &Language) -> &'static str {
     }
 }
 
-fn extract_imports(content: &str, lang: &Language) -> Vec<String> {
-    let pattern = match lang {
-        Language::Rust => r"^use\s+([a-zA-Z_][a-zA-Z0-9_]*(?:::[a-zA-Z_][a-zA-Z0-9_]*)?)",
-        Language::Python => r"^(?:from\s+(\S+)|import\s+(\S+))",
+fn import_pattern(lang: &Language) -> Option<&'static str> {
+    match lang {
+        Language::Rust => Some(r"^use\s+([a-zA-Z_][a-zA-Z0-9_]*(?:::[a-zA-Z_][a-zA-Z0-9_]*)?)"),
+        Language::Python => Some(r"^(?:from\s+(\S+)|import\s+(\S+))"),
         Language::JavaScript | Language::TypeScript => {
-            r#"(?:import.*from\s+['"]([^'"]+)['"]|require\(['"]([^'"]+)['"]\))"#
+            Some(r#"(?:import.*from\s+['"]([^'"]+)['"]|require\(['"]([^'"]+)['"]\))"#)
         }
-        Language::Go => r#"^\s*"([^"]+)"$"#,
-        _ => return Vec::new(),
+        Language::Go => Some(r#"^\s*"([^"]+)"$"#),
+        _ => None,
+    }
+}
+
+fn extract_imports(content: &str, lang: &Language) -> Vec<String> {
+    let Some(pattern) = import_pattern(lang) else {
+        return Vec::new();
     };
 
     let re = Regex::new(pattern).unwrap();
@@ -164,15 +479,21 @@ fn is_std_import(name: &str, lang: &Language) -> bool {
     }
 }
 
+fn function_pattern(lang: &Language) -> Option<&'static str> {
+    match lang {
+        Language::Rust => Some(r"(?:pub\s+)?(?:async\s+)?fn\s+([a-zA-Z_][a-zA-Z0-9_]*)"),
+        Language::Python => Some(r"def\s+([a-zA-Z_][a-zA-Z0-9_]*)"),
+        Language::JavaScript | Language::TypeScript => Some(
+            r"(?:async\s+)?function\s+([a-zA-Z_][a-zA-Z0-9_]*)|(?:const|let|var)\s+([a-zA-Z_][a-zA-Z0-9_]*)\s*=\s*(?:async\s+)?\(",
+        ),
+        Language::Go => Some(r"func\s+(?:\([^)]+\)\s+)?([a-zA-Z_][a-zA-Z0-9_]*)"),
+        _ => None,
+    }
+}
+
 fn extract_functions(content: &str, lang: &Language) -> Vec<String> {
-    let pattern = match lang {
-        Language::Rust => r"(?:pub\s+)?(?:async\s+)?fn\s+([a-zA-Z_][a-zA-Z0-9_]*)",
-        Language::Python => r"def\s+([a-zA-Z_][a-zA-Z0-9_]*)",
-        Language::JavaScript | Language::TypeScript => {
-            r"(?:async\s+)?function\s+([a-zA-Z_][a-zA-Z0-9_]*)|(?:const|let|var)\s+([a-zA-Z_][a-zA-Z0-9_]*)\s*=\s*(?:async\s+)?\("
-        }
-        Language::Go => r"func\s+(?:\([^)]+\)\s+)?([a-zA-Z_][a-zA-Z0-9_]*)",
-        _ => return Vec::new(),
+    let Some(pattern) = function_pattern(lang) else {
+        return Vec::new();
     };
 
     let re = Regex::new(pattern).unwrap();
@@ -192,14 +513,20 @@ fn extract_functions(content: &str, lang: &Language) -> Vec<String> {
     functions.into_iter().take(10).collect()
 }
 
+fn struct_pattern(lang: &Language) -> Option<&'static str> {
+    match lang {
+        Language::Rust => Some(r"(?:pub\s+)?(?:struct|enum)\s+([a-zA-Z_][a-zA-Z0-9_]*)"),
+        Language::Python => Some(r"class\s+([a-zA-Z_][a-zA-Z0-9_]*)"),
+        Language::TypeScript => Some(r"(?:interface|class|type)\s+([a-zA-Z_][a-zA-Z0-9_]*)"),
+        Language::Go => Some(r"type\s+([a-zA-Z_][a-zA-Z0-9_]*)\s+struct"),
+        Language::Java => Some(r"(?:public\s+)?class\s+([a-zA-Z_][a-zA-Z0-9_]*)"),
+        _ => None,
+    }
+}
+
 fn extract_structs(content: &str, lang: &Language) -> Vec<String> {
-    let pattern = match lang {
-        Language::Rust => r"(?:pub\s+)?(?:struct|enum)\s+([a-zA-Z_][a-zA-Z0-9_]*)",
-        Language::Python => r"class\s+([a-zA-Z_][a-zA-Z0-9_]*)",
-        Language::TypeScript => r"(?:interface|class|type)\s+([a-zA-Z_][a-zA-Z0-9_]*)",
-        Language::Go => r"type\s+([a-zA-Z_][a-zA-Z0-9_]*)\s+struct",
-        Language::Java => r"(?:public\s+)?class\s+([a-zA-Z_][a-zA-Z0-9_]*)",
-        _ => return Vec::new(),
+    let Some(pattern) = struct_pattern(lang) else {
+        return Vec::new();
     };
 
     let re = Regex::new(pattern).unwrap();
@@ -209,11 +536,17 @@ fn extract_structs(content: &str, lang: &Language) -> Vec<String> {
         .collect()
 }
 
+fn trait_pattern(lang: &Language) -> Option<&'static str> {
+    match lang {
+        Language::Rust => Some(r"(?:pub\s+)?trait\s+([a-zA-Z_][a-zA-Z0-9_]*)"),
+        Language::TypeScript => Some(r"interface\s+([a-zA-Z_][a-zA-Z0-9_]*)"),
+        _ => None,
+    }
+}
+
 fn extract_traits(content: &str, lang: &Language) -> Vec<String> {
-    let pattern = match lang {
-        Language::Rust => r"(?:pub\s+)?trait\s+([a-zA-Z_][a-zA-Z0-9_]*)",
-        Language::TypeScript => r"interface\s+([a-zA-Z_][a-zA-Z0-9_]*)",
-        _ => return Vec::new(),
+    let Some(pattern) = trait_pattern(lang) else {
+        return Vec::new();
     };
 
     let re = Regex::new(pattern).unwrap();
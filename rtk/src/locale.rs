@@ -0,0 +1,227 @@
+//! Locale lookup tables for internationalized table rendering.
+//!
+//! Backs [`crate::display_helpers::print_period_table`]'s headers/period
+//! labels and [`crate::display_helpers::format_tokens`]/[`format_duration`]'s
+//! number formatting. All lookups are `const`/`match` tables over a small,
+//! fixed [`Locale`] enum rather than a runtime catalog — there's no
+//! `pure-rust-locales`-style data file here, just the handful of languages
+//! rtk ships translations for.
+//!
+//! [`format_duration`]: crate::display_helpers::format_duration
+
+/// A supported display locale. Defaults to `EnUs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    EnUs,
+    FrFr,
+    DeDe,
+    EsEs,
+}
+
+/// Abbreviated (3-letter) month names, indexed `0..=11` for Jan..Dec.
+const MONTHS_EN: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+const MONTHS_FR: [&str; 12] = [
+    "jan", "fév", "mar", "avr", "mai", "jui", "jul", "aoû", "sep", "oct", "nov", "déc",
+];
+const MONTHS_DE: [&str; 12] = [
+    "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+];
+const MONTHS_ES: [&str; 12] = [
+    "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sep", "oct", "nov", "dic",
+];
+
+/// Abbreviated (3-letter) weekday names, indexed `0..=6` for Mon..Sun
+/// (matching [`chrono::Weekday::num_days_from_monday`]).
+const WEEKDAYS_EN: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+const WEEKDAYS_FR: [&str; 7] = ["lun", "mar", "mer", "jeu", "ven", "sam", "dim"];
+const WEEKDAYS_DE: [&str; 7] = ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"];
+const WEEKDAYS_ES: [&str; 7] = ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"];
+
+impl Locale {
+    /// Abbreviated month name for `month` (`1..=12`, as returned by
+    /// `chrono::Datelike::month`). Falls back to `"???"` out of range.
+    pub fn month_abbrev(self, month: u32) -> &'static str {
+        let idx = month.saturating_sub(1) as usize;
+        let table = match self {
+            Locale::EnUs => &MONTHS_EN,
+            Locale::FrFr => &MONTHS_FR,
+            Locale::DeDe => &MONTHS_DE,
+            Locale::EsEs => &MONTHS_ES,
+        };
+        table.get(idx).copied().unwrap_or("???")
+    }
+
+    /// Abbreviated weekday name for `weekday` (`0..=6`, Monday-first, as
+    /// returned by `chrono::Weekday::num_days_from_monday`).
+    pub fn weekday_abbrev(self, weekday: u32) -> &'static str {
+        let idx = weekday as usize;
+        let table = match self {
+            Locale::EnUs => &WEEKDAYS_EN,
+            Locale::FrFr => &WEEKDAYS_FR,
+            Locale::DeDe => &WEEKDAYS_DE,
+            Locale::EsEs => &WEEKDAYS_ES,
+        };
+        table.get(idx).copied().unwrap_or("???")
+    }
+
+    /// Decimal separator used when formatting fractional numbers
+    /// (`format_tokens`'s "1.2M" vs. "1,2M").
+    pub fn decimal_separator(self) -> char {
+        match self {
+            Locale::EnUs => '.',
+            Locale::FrFr | Locale::DeDe | Locale::EsEs => ',',
+        }
+    }
+
+    /// Table section title for a `PeriodStats::label()` kind (e.g. "Daily").
+    pub fn period_label(self, label: &'static str) -> &'static str {
+        match (self, label) {
+            (Locale::EnUs, "Daily") => "Daily",
+            (Locale::EnUs, "Weekly") => "Weekly",
+            (Locale::EnUs, "Monthly") => "Monthly",
+            (Locale::EnUs, "Custom") => "Custom",
+            (Locale::FrFr, "Daily") => "Journalier",
+            (Locale::FrFr, "Weekly") => "Hebdomadaire",
+            (Locale::FrFr, "Monthly") => "Mensuel",
+            (Locale::FrFr, "Custom") => "Personnalisé",
+            (Locale::DeDe, "Daily") => "Täglich",
+            (Locale::DeDe, "Weekly") => "Wöchentlich",
+            (Locale::DeDe, "Monthly") => "Monatlich",
+            (Locale::DeDe, "Custom") => "Benutzerdefiniert",
+            (Locale::EsEs, "Daily") => "Diario",
+            (Locale::EsEs, "Weekly") => "Semanal",
+            (Locale::EsEs, "Monthly") => "Mensual",
+            (Locale::EsEs, "Custom") => "Personalizado",
+            (_, other) => other,
+        }
+    }
+
+    /// Header for the leftmost (period identifier) column.
+    pub fn column_header(self, label: &str) -> &'static str {
+        match (self, label) {
+            (Locale::EnUs, "Weekly") => "Week",
+            (Locale::EnUs, "Monthly") => "Month",
+            (Locale::EnUs, _) => "Date",
+            (Locale::FrFr, "Weekly") => "Semaine",
+            (Locale::FrFr, "Monthly") => "Mois",
+            (Locale::FrFr, _) => "Date",
+            (Locale::DeDe, "Weekly") => "Woche",
+            (Locale::DeDe, "Monthly") => "Monat",
+            (Locale::DeDe, _) => "Datum",
+            (Locale::EsEs, "Weekly") => "Semana",
+            (Locale::EsEs, "Monthly") => "Mes",
+            (Locale::EsEs, _) => "Fecha",
+        }
+    }
+
+    /// Other fixed table column headers and the totals-row label, in the
+    /// order `print_period_table` renders them: commands, input, output,
+    /// saved, save%, time, total.
+    pub fn headers(self) -> LocaleHeaders {
+        match self {
+            Locale::EnUs => LocaleHeaders {
+                commands: "Cmds",
+                input: "Input",
+                output: "Output",
+                saved: "Saved",
+                save_pct: "Save%",
+                time: "Time",
+                total: "TOTAL",
+            },
+            Locale::FrFr => LocaleHeaders {
+                commands: "Cmds",
+                input: "Entrée",
+                output: "Sortie",
+                saved: "Économisé",
+                save_pct: "Éco%",
+                time: "Temps",
+                total: "TOTAL",
+            },
+            Locale::DeDe => LocaleHeaders {
+                commands: "Bef.",
+                input: "Eingabe",
+                output: "Ausgabe",
+                saved: "Gespart",
+                save_pct: "Spar%",
+                time: "Zeit",
+                total: "GESAMT",
+            },
+            Locale::EsEs => LocaleHeaders {
+                commands: "Cmds",
+                input: "Entrada",
+                output: "Salida",
+                saved: "Ahorrado",
+                save_pct: "Ahor%",
+                time: "Tiempo",
+                total: "TOTAL",
+            },
+        }
+    }
+}
+
+/// Table column headers for a [`Locale`], as used by
+/// [`crate::display_helpers::print_period_table`].
+#[derive(Debug, Clone, Copy)]
+pub struct LocaleHeaders {
+    pub commands: &'static str,
+    pub input: &'static str,
+    pub output: &'static str,
+    pub saved: &'static str,
+    pub save_pct: &'static str,
+    pub time: &'static str,
+    pub total: &'static str,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_locale_is_en_us() {
+        assert_eq!(Locale::default(), Locale::EnUs);
+    }
+
+    #[test]
+    fn test_month_abbrev_bounds() {
+        assert_eq!(Locale::EnUs.month_abbrev(1), "Jan");
+        assert_eq!(Locale::EnUs.month_abbrev(12), "Dec");
+        assert_eq!(Locale::FrFr.month_abbrev(1), "jan");
+        assert_eq!(Locale::DeDe.month_abbrev(3), "Mär");
+        assert_eq!(Locale::EsEs.month_abbrev(12), "dic");
+    }
+
+    #[test]
+    fn test_month_abbrev_out_of_range() {
+        assert_eq!(Locale::EnUs.month_abbrev(0), "???");
+        assert_eq!(Locale::EnUs.month_abbrev(13), "???");
+    }
+
+    #[test]
+    fn test_weekday_abbrev() {
+        assert_eq!(Locale::EnUs.weekday_abbrev(0), "Mon");
+        assert_eq!(Locale::DeDe.weekday_abbrev(6), "So");
+    }
+
+    #[test]
+    fn test_decimal_separator() {
+        assert_eq!(Locale::EnUs.decimal_separator(), '.');
+        assert_eq!(Locale::FrFr.decimal_separator(), ',');
+    }
+
+    #[test]
+    fn test_period_label_translates() {
+        assert_eq!(Locale::EnUs.period_label("Daily"), "Daily");
+        assert_eq!(Locale::FrFr.period_label("Daily"), "Journalier");
+        assert_eq!(Locale::DeDe.period_label("Monthly"), "Monatlich");
+    }
+
+    #[test]
+    fn test_column_header_by_kind() {
+        assert_eq!(Locale::EnUs.column_header("Weekly"), "Week");
+        assert_eq!(Locale::EnUs.column_header("Daily"), "Date");
+        assert_eq!(Locale::DeDe.column_header("Monthly"), "Monat");
+    }
+}
@@ -1,13 +1,192 @@
+use crate::log_drain::Drain;
 use crate::tracking;
-use anyhow::Result;
-use regex::Regex;
+use anyhow::{bail, Result};
+use regex::{Regex, RegexSet, RegexSetBuilder};
+use serde_json::Value;
 use std::collections::HashMap;
 use std::fs;
-use std::io::{self, BufRead};
+use std::io::{self, BufRead, Read, Seek, SeekFrom};
 use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Line-level filtering options for `rtk log`, independent of severity:
+/// `--include`/`--exclude` patterns compiled into single `RegexSet`s (one
+/// combined scan per line instead of looping per-pattern, the way
+/// Fuchsia's `log_listener` uses `RegexSetBuilder`) plus `--ignore-case`.
+pub struct LogFilters {
+    include: Option<RegexSet>,
+    exclude: RegexSet,
+}
+
+impl LogFilters {
+    pub fn new(include: &[String], exclude: &[String], ignore_case: bool) -> Result<LogFilters> {
+        let build = |patterns: &[String]| -> Result<RegexSet> {
+            Ok(RegexSetBuilder::new(patterns)
+                .case_insensitive(ignore_case)
+                .build()?)
+        };
+
+        Ok(LogFilters {
+            include: if include.is_empty() {
+                None
+            } else {
+                Some(build(include)?)
+            },
+            exclude: build(exclude)?,
+        })
+    }
+
+    /// A line is kept iff it matches the include set (or there is none)
+    /// and matches none of the exclude set.
+    fn keep(&self, line: &str) -> bool {
+        let included = self
+            .include
+            .as_ref()
+            .map(|set| set.is_match(line))
+            .unwrap_or(true);
+        included && !self.exclude.is_match(line)
+    }
+}
+
+impl Default for LogFilters {
+    fn default() -> Self {
+        LogFilters {
+            include: None,
+            exclude: RegexSet::empty(),
+        }
+    }
+}
+
+/// Log severity, ordered least to most urgent the way Fuchsia's
+/// `log_listener` orders its levels, so `--min-severity warn` can drop
+/// everything below it with a single comparison.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Fatal,
+}
+
+impl Severity {
+    /// Parse a `--min-severity` CLI value (case-insensitive).
+    pub fn parse(s: &str) -> Result<Severity> {
+        match s.to_lowercase().as_str() {
+            "trace" => Ok(Severity::Trace),
+            "debug" => Ok(Severity::Debug),
+            "info" => Ok(Severity::Info),
+            "warn" | "warning" => Ok(Severity::Warn),
+            "error" => Ok(Severity::Error),
+            "fatal" | "panic" | "critical" => Ok(Severity::Fatal),
+            other => bail!(
+                "invalid severity '{}' (expected trace, debug, info, warn, error, or fatal)",
+                other
+            ),
+        }
+    }
+
+    /// Match a level token (`ERROR`, `WARN`, ...), not a CLI argument --
+    /// broader aliases than `parse` since logs use more vocabulary than a
+    /// flag does (`ERR`, `WARNING`, `CRIT`, ...).
+    fn from_token(token: &str) -> Option<Severity> {
+        match token.to_uppercase().as_str() {
+            "TRACE" | "TRC" => Some(Severity::Trace),
+            "DEBUG" | "DBG" => Some(Severity::Debug),
+            "INFO" | "INFORMATION" => Some(Severity::Info),
+            "WARN" | "WARNING" => Some(Severity::Warn),
+            "ERROR" | "ERR" => Some(Severity::Error),
+            "FATAL" | "PANIC" | "CRITICAL" | "CRIT" => Some(Severity::Fatal),
+            _ => None,
+        }
+    }
+}
+
+/// Find a severity token at a clear boundary -- `[ERROR]`, `ERROR:`, or a
+/// bare all-caps word delimited by whitespace (` WARN `) -- rather than a
+/// bare substring match, so a path containing "info" or a word like
+/// "errored" doesn't misclassify the line. When more than one form
+/// matches, the leftmost one in the line wins.
+fn extract_severity(line: &str) -> Option<Severity> {
+    let bracketed_re = Regex::new(r"\[([A-Za-z]+)\]").unwrap();
+    let colon_re = Regex::new(r"\b([A-Za-z]+):").unwrap();
+    let bare_re = Regex::new(r"(?:^|\s)([A-Z]{3,})(?:\s|$)").unwrap();
+
+    [&bracketed_re, &colon_re, &bare_re]
+        .iter()
+        .filter_map(|re| {
+            re.captures_iter(line).find_map(|caps| {
+                let m = caps.get(1)?;
+                Severity::from_token(m.as_str()).map(|sev| (m.start(), sev))
+            })
+        })
+        .min_by_key(|(start, _)| *start)
+        .map(|(_, sev)| sev)
+}
+
+/// Field names tried, in order, when `--level-field`/`--msg-field` aren't
+/// given -- covers the handful of structured-logging field conventions
+/// (pino, bunyan, zap, slog) in common use.
+const DEFAULT_LEVEL_FIELDS: &[&str] = &["level", "severity", "lvl"];
+const DEFAULT_MSG_FIELDS: &[&str] = &["msg", "message"];
+
+/// If `line` parses as a single JSON object, read its severity and message
+/// out of `level_field`/`msg_field` (or, when unset, the first matching
+/// name in [`DEFAULT_LEVEL_FIELDS`]/[`DEFAULT_MSG_FIELDS`]) instead of
+/// sniffing for a bracketed/colon-delimited token. Returns `None` for
+/// anything that isn't a JSON object, or whose level field can't be
+/// resolved to a [`Severity`] -- callers should fall back to
+/// [`extract_severity`] on the raw line in that case.
+fn parse_structured_line(
+    line: &str,
+    level_field: Option<&str>,
+    msg_field: Option<&str>,
+) -> Option<(Severity, String)> {
+    let value: Value = serde_json::from_str(line.trim()).ok()?;
+    let obj = value.as_object()?;
+
+    let level = match level_field {
+        Some(field) => obj.get(field).and_then(|v| v.as_str()),
+        None => DEFAULT_LEVEL_FIELDS
+            .iter()
+            .find_map(|field| obj.get(*field).and_then(|v| v.as_str())),
+    }?;
+    let severity = Severity::from_token(level)?;
+
+    let message = match msg_field {
+        Some(field) => obj.get(field).and_then(|v| v.as_str()),
+        None => DEFAULT_MSG_FIELDS
+            .iter()
+            .find_map(|field| obj.get(*field).and_then(|v| v.as_str())),
+    }
+    .unwrap_or(line)
+    .to_string();
+
+    Some((severity, message))
+}
+
+/// Options for reading severity/message out of JSON-line structured logs
+/// (one JSON object per line, as emitted by pino/bunyan/zap/slog-style
+/// loggers) instead of sniffing for a bracketed/colon-delimited token in
+/// plain text. Empty field names fall back to [`DEFAULT_LEVEL_FIELDS`]/
+/// [`DEFAULT_MSG_FIELDS`].
+#[derive(Default, Clone)]
+pub struct StructuredFields {
+    pub level_field: Option<String>,
+    pub msg_field: Option<String>,
+}
 
 /// Filter and deduplicate log output
-pub fn run_file(file: &Path, verbose: u8) -> Result<()> {
+pub fn run_file(
+    file: &Path,
+    min_severity: Option<Severity>,
+    filters: &LogFilters,
+    structured: &StructuredFields,
+    cluster: bool,
+    verbose: u8,
+) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
     if verbose > 0 {
@@ -15,7 +194,11 @@ pub fn run_file(file: &Path, verbose: u8) -> Result<()> {
     }
 
     let content = fs::read_to_string(file)?;
-    let result = analyze_logs(&content);
+    let result = if cluster {
+        cluster_logs(&content, filters)
+    } else {
+        analyze_logs(&content, min_severity, filters, structured)
+    };
     println!("{}", result);
     timer.track(
         &format!("cat {}", file.display()),
@@ -27,7 +210,13 @@ pub fn run_file(file: &Path, verbose: u8) -> Result<()> {
 }
 
 /// Filter logs from stdin
-pub fn run_stdin(_verbose: u8) -> Result<()> {
+pub fn run_stdin(
+    min_severity: Option<Severity>,
+    filters: &LogFilters,
+    structured: &StructuredFields,
+    cluster: bool,
+    _verbose: u8,
+) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
     let mut content = String::new();
@@ -37,7 +226,11 @@ pub fn run_stdin(_verbose: u8) -> Result<()> {
         content.push('\n');
     }
 
-    let result = analyze_logs(&content);
+    let result = if cluster {
+        cluster_logs(&content, filters)
+    } else {
+        analyze_logs(&content, min_severity, filters, structured)
+    };
     println!("{}", result);
 
     timer.track("log (stdin)", "rtk log (stdin)", &content, &result);
@@ -47,156 +240,396 @@ pub fn run_stdin(_verbose: u8) -> Result<()> {
 
 /// For use by other modules
 pub fn run_stdin_str(content: &str) -> String {
-    analyze_logs(content)
-}
-
-fn analyze_logs(content: &str) -> String {
-    let mut result = Vec::new();
-    let mut error_counts: HashMap<String, usize> = HashMap::new();
-    let mut warn_counts: HashMap<String, usize> = HashMap::new();
-    let mut info_counts: HashMap<String, usize> = HashMap::new();
-    let mut unique_errors: Vec<String> = Vec::new();
-    let mut unique_warnings: Vec<String> = Vec::new();
-
-    // Patterns to normalize log messages
-    let timestamp_re =
-        Regex::new(r"^\d{4}[-/]\d{2}[-/]\d{2}[T ]\d{2}:\d{2}:\d{2}[.,]?\d*\s*").unwrap();
-    let uuid_re =
-        Regex::new(r"[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}")
-            .unwrap();
-    let hex_re = Regex::new(r"0x[0-9a-fA-F]+").unwrap();
-    let num_re = Regex::new(r"\b\d{4,}\b").unwrap();
-    let path_re = Regex::new(r"/[\w./\-]+").unwrap();
+    analyze_logs(content, None, &LogFilters::default(), &StructuredFields::default())
+}
 
-    for line in content.lines() {
-        let line_lower = line.to_lowercase();
-
-        // Normalize for deduplication
-        let normalized =
-            normalize_log_line(line, &timestamp_re, &uuid_re, &hex_re, &num_re, &path_re);
-
-        // Categorize
-        if line_lower.contains("error")
-            || line_lower.contains("fatal")
-            || line_lower.contains("panic")
-        {
-            let count = error_counts.entry(normalized.clone()).or_insert(0);
-            if *count == 0 {
-                unique_errors.push(line.to_string());
-            }
-            *count += 1;
-        } else if line_lower.contains("warn") {
-            let count = warn_counts.entry(normalized.clone()).or_insert(0);
-            if *count == 0 {
-                unique_warnings.push(line.to_string());
-            }
-            *count += 1;
-        } else if line_lower.contains("info") {
-            *info_counts.entry(normalized).or_insert(0) += 1;
-        }
-    }
-
-    // Summary
-    let total_errors: usize = error_counts.values().sum();
-    let total_warnings: usize = warn_counts.values().sum();
-    let total_info: usize = info_counts.values().sum();
-
-    result.push(format!("📊 Log Summary"));
-    result.push(format!(
-        "   ❌ {} errors ({} unique)",
-        total_errors,
-        error_counts.len()
-    ));
-    result.push(format!(
-        "   ⚠️  {} warnings ({} unique)",
-        total_warnings,
-        warn_counts.len()
-    ));
-    result.push(format!("   ℹ️  {} info messages", total_info));
-    result.push(String::new());
-
-    // Errors with counts
-    if !unique_errors.is_empty() {
-        result.push("❌ ERRORS:".to_string());
-
-        // Sort by count
-        let mut error_list: Vec<_> = error_counts.iter().collect();
-        error_list.sort_by(|a, b| b.1.cmp(a.1));
-
-        for (normalized, count) in error_list.iter().take(10) {
-            // Find original message
-            let original = unique_errors
-                .iter()
-                .find(|e| {
-                    &normalize_log_line(e, &timestamp_re, &uuid_re, &hex_re, &num_re, &path_re)
-                        == *normalized
-                })
-                .map(|s| s.as_str())
-                .unwrap_or(normalized);
-
-            let truncated = if original.len() > 100 {
-                let t: String = original.chars().take(97).collect();
-                format!("{}...", t)
-            } else {
-                original.to_string()
-            };
+/// Continuously read appended lines from `file` (or, if `None`, a
+/// never-closing stdin) and reprint a rolling, deduplicated summary --
+/// mirroring how Fuchsia's `log_listener` continuously consumes a log
+/// stream instead of doing a one-shot read. The `[x N]` counts keep
+/// accumulating across flushes since the aggregator is never reset.
+pub fn run_follow(
+    file: Option<&Path>,
+    min_severity: Option<Severity>,
+    filters: &LogFilters,
+    structured: &StructuredFields,
+    verbose: u8,
+) -> Result<()> {
+    let mut aggregator = LogAggregator::new(min_severity, filters, structured);
+    match file {
+        Some(path) => follow_file(path, &mut aggregator, verbose),
+        None => follow_stdin(&mut aggregator),
+    }
+}
 
-            if **count > 1 {
-                result.push(format!("   [×{}] {}", count, truncated));
-            } else {
-                result.push(format!("   {}", truncated));
+/// Reprint the rolling summary after this many new lines...
+const FLUSH_EVERY_LINES: usize = 50;
+/// ...or after this much time has passed, whichever comes first.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+/// Poll interval between reads when following a plain file (there's no
+/// inotify-style wakeup available here, so this is a plain sleep loop).
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+fn flush_due(lines_since_flush: usize, last_flush: Instant) -> bool {
+    lines_since_flush >= FLUSH_EVERY_LINES || last_flush.elapsed() >= FLUSH_INTERVAL
+}
+
+fn follow_file(path: &Path, aggregator: &mut LogAggregator, verbose: u8) -> Result<()> {
+    if verbose > 0 {
+        eprintln!("Following log: {}", path.display());
+    }
+
+    let mut file = fs::File::open(path)?;
+    let mut pos: u64 = 0;
+    // Bytes read since the last complete line -- a chunk boundary can land
+    // mid-line (or even mid UTF-8 codepoint), so this is carried forward
+    // until a newline arrives.
+    let mut pending = String::new();
+    let mut lines_since_flush = 0usize;
+    let mut last_flush = Instant::now();
+
+    loop {
+        let len = fs::metadata(path)?.len();
+        if len < pos {
+            // Truncated or rotated out from under us; start over.
+            pos = 0;
+            pending.clear();
+        }
+
+        if len > pos {
+            file.seek(SeekFrom::Start(pos))?;
+            let mut chunk = String::new();
+            file.read_to_string(&mut chunk)?;
+            pos = file.stream_position()?;
+            pending.push_str(&chunk);
+
+            while let Some(idx) = pending.find('\n') {
+                let line = pending[..idx].to_string();
+                pending.drain(..=idx);
+                aggregator.ingest_line(&line);
+                lines_since_flush += 1;
             }
         }
 
-        if error_list.len() > 10 {
-            result.push(format!(
-                "   ... +{} more unique errors",
-                error_list.len() - 10
-            ));
+        if lines_since_flush > 0 && flush_due(lines_since_flush, last_flush) {
+            println!("{}\n", aggregator.render());
+            lines_since_flush = 0;
+            last_flush = Instant::now();
         }
-        result.push(String::new());
+
+        thread::sleep(FOLLOW_POLL_INTERVAL);
     }
+}
 
-    // Warnings with counts
-    if !unique_warnings.is_empty() {
-        result.push("⚠️  WARNINGS:".to_string());
+fn follow_stdin(aggregator: &mut LogAggregator) -> Result<()> {
+    let stdin = io::stdin();
+    let mut lines_since_flush = 0usize;
+    let mut last_flush = Instant::now();
 
-        let mut warn_list: Vec<_> = warn_counts.iter().collect();
-        warn_list.sort_by(|a, b| b.1.cmp(a.1));
+    for line in stdin.lock().lines() {
+        aggregator.ingest_line(&line?);
+        lines_since_flush += 1;
 
-        for (normalized, count) in warn_list.iter().take(5) {
-            let original = unique_warnings
-                .iter()
-                .find(|w| {
-                    &normalize_log_line(w, &timestamp_re, &uuid_re, &hex_re, &num_re, &path_re)
-                        == *normalized
-                })
-                .map(|s| s.as_str())
-                .unwrap_or(normalized);
+        if flush_due(lines_since_flush, last_flush) {
+            println!("{}\n", aggregator.render());
+            lines_since_flush = 0;
+            last_flush = Instant::now();
+        }
+    }
 
-            let truncated = if original.len() > 100 {
-                let t: String = original.chars().take(97).collect();
-                format!("{}...", t)
-            } else {
-                original.to_string()
-            };
+    if lines_since_flush > 0 {
+        println!("{}", aggregator.render());
+    }
 
-            if **count > 1 {
-                result.push(format!("   [×{}] {}", count, truncated));
-            } else {
-                result.push(format!("   {}", truncated));
+    Ok(())
+}
+
+fn analyze_logs(
+    content: &str,
+    min_severity: Option<Severity>,
+    filters: &LogFilters,
+    structured: &StructuredFields,
+) -> String {
+    let mut aggregator = LogAggregator::new(min_severity, filters, structured);
+    for line in content.lines() {
+        aggregator.ingest_line(line);
+    }
+    aggregator.render()
+}
+
+/// Alternative to [`analyze_logs`] that groups lines into [`Drain`]
+/// templates instead of bucketing by severity -- for logs where the
+/// interesting structure is "which message shapes recur" rather than
+/// "how many errors happened", and where `normalize_log_line`'s blanket
+/// regex substitution over- or under-merges lines that a token-level
+/// template would cluster correctly. `--include`/`--exclude` still apply;
+/// `--min-severity` does not, since templates aren't keyed by severity.
+fn cluster_logs(content: &str, filters: &LogFilters) -> String {
+    let mut drain = Drain::new();
+    for line in content.lines() {
+        if filters.keep(line) {
+            drain.ingest(line);
+        }
+    }
+
+    let templates = drain.templates();
+    if templates.is_empty() {
+        return "🧩 No log templates found".to_string();
+    }
+
+    let mut result = vec![format!("🧩 {} log templates", templates.len()), String::new()];
+    for template in templates {
+        result.push(format!("[×{}] {}", template.count, template.rendered()));
+        result.push(format!("   e.g. {}", template.example));
+    }
+    result.join("\n")
+}
+
+/// Holds the running `[x N]` count buckets plus the compiled normalization
+/// patterns, so `run_file`/`run_stdin` (one-shot) and `run_follow`
+/// (long-lived, reprinting on a debounce interval) share the exact same
+/// dedup logic instead of each re-implementing it.
+struct LogAggregator<'a> {
+    min_severity: Option<Severity>,
+    filters: &'a LogFilters,
+    structured: &'a StructuredFields,
+    fatal_counts: HashMap<String, usize>,
+    error_counts: HashMap<String, usize>,
+    warn_counts: HashMap<String, usize>,
+    info_counts: HashMap<String, usize>,
+    unique_fatals: Vec<String>,
+    unique_errors: Vec<String>,
+    unique_warnings: Vec<String>,
+    timestamp_re: Regex,
+    uuid_re: Regex,
+    hex_re: Regex,
+    num_re: Regex,
+    path_re: Regex,
+}
+
+impl<'a> LogAggregator<'a> {
+    fn new(
+        min_severity: Option<Severity>,
+        filters: &'a LogFilters,
+        structured: &'a StructuredFields,
+    ) -> Self {
+        LogAggregator {
+            min_severity,
+            filters,
+            structured,
+            fatal_counts: HashMap::new(),
+            error_counts: HashMap::new(),
+            warn_counts: HashMap::new(),
+            info_counts: HashMap::new(),
+            unique_fatals: Vec::new(),
+            unique_errors: Vec::new(),
+            unique_warnings: Vec::new(),
+            timestamp_re: Regex::new(r"^\d{4}[-/]\d{2}[-/]\d{2}[T ]\d{2}:\d{2}:\d{2}[.,]?\d*\s*")
+                .unwrap(),
+            uuid_re: Regex::new(
+                r"[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}",
+            )
+            .unwrap(),
+            hex_re: Regex::new(r"0x[0-9a-fA-F]+").unwrap(),
+            num_re: Regex::new(r"\b\d{4,}\b").unwrap(),
+            path_re: Regex::new(r"/[\w./\-]+").unwrap(),
+        }
+    }
+
+    /// Feed one line into the running counts. A no-op if the line is
+    /// filtered out, has no recognizable severity, or falls below
+    /// `min_severity`. Lines that parse as a single JSON object (structured
+    /// logging) read their severity/message from `self.structured`'s
+    /// configured fields instead of [`extract_severity`]'s substring
+    /// sniffing; anything else falls back to the plain-text path.
+    fn ingest_line(&mut self, line: &str) {
+        if !self.filters.keep(line) {
+            return;
+        }
+
+        let (severity, message) = match parse_structured_line(
+            line,
+            self.structured.level_field.as_deref(),
+            self.structured.msg_field.as_deref(),
+        ) {
+            Some(parsed) => parsed,
+            None => {
+                let Some(severity) = extract_severity(line) else {
+                    return;
+                };
+                (severity, line.to_string())
+            }
+        };
+        if self.min_severity.is_some_and(|min| severity < min) {
+            return;
+        }
+
+        let normalized = normalize_log_line(
+            &message,
+            &self.timestamp_re,
+            &self.uuid_re,
+            &self.hex_re,
+            &self.num_re,
+            &self.path_re,
+        );
+
+        match severity {
+            Severity::Fatal => {
+                let count = self.fatal_counts.entry(normalized.clone()).or_insert(0);
+                if *count == 0 {
+                    self.unique_fatals.push(message);
+                }
+                *count += 1;
+            }
+            Severity::Error => {
+                let count = self.error_counts.entry(normalized.clone()).or_insert(0);
+                if *count == 0 {
+                    self.unique_errors.push(message);
+                }
+                *count += 1;
+            }
+            Severity::Warn => {
+                let count = self.warn_counts.entry(normalized.clone()).or_insert(0);
+                if *count == 0 {
+                    self.unique_warnings.push(message);
+                }
+                *count += 1;
+            }
+            Severity::Info | Severity::Debug | Severity::Trace => {
+                *self.info_counts.entry(normalized).or_insert(0) += 1;
             }
         }
+    }
+
+    /// Render the current state as a summary -- safe to call repeatedly
+    /// (e.g. once per debounce interval in follow mode) since it never
+    /// mutates the running counts.
+    fn render(&self) -> String {
+        let mut result = Vec::new();
+
+        let total_fatals: usize = self.fatal_counts.values().sum();
+        let total_errors: usize = self.error_counts.values().sum();
+        let total_warnings: usize = self.warn_counts.values().sum();
+        let total_info: usize = self.info_counts.values().sum();
 
-        if warn_list.len() > 5 {
+        result.push("📊 Log Summary".to_string());
+        if total_fatals > 0 {
             result.push(format!(
-                "   ... +{} more unique warnings",
-                warn_list.len() - 5
+                "   💀 {} fatal ({} unique)",
+                total_fatals,
+                self.fatal_counts.len()
             ));
         }
+        result.push(format!(
+            "   ❌ {} errors ({} unique)",
+            total_errors,
+            self.error_counts.len()
+        ));
+        result.push(format!(
+            "   ⚠️  {} warnings ({} unique)",
+            total_warnings,
+            self.warn_counts.len()
+        ));
+        result.push(format!("   ℹ️  {} info messages", total_info));
+        result.push(String::new());
+
+        if !self.unique_fatals.is_empty() {
+            result.push("💀 FATAL:".to_string());
+            push_top_messages(
+                &mut result,
+                &self.fatal_counts,
+                &self.unique_fatals,
+                10,
+                &self.timestamp_re,
+                &self.uuid_re,
+                &self.hex_re,
+                &self.num_re,
+                &self.path_re,
+            );
+            result.push(String::new());
+        }
+
+        if !self.unique_errors.is_empty() {
+            result.push("❌ ERRORS:".to_string());
+            push_top_messages(
+                &mut result,
+                &self.error_counts,
+                &self.unique_errors,
+                10,
+                &self.timestamp_re,
+                &self.uuid_re,
+                &self.hex_re,
+                &self.num_re,
+                &self.path_re,
+            );
+            result.push(String::new());
+        }
+
+        if !self.unique_warnings.is_empty() {
+            result.push("⚠️  WARNINGS:".to_string());
+            push_top_messages(
+                &mut result,
+                &self.warn_counts,
+                &self.unique_warnings,
+                5,
+                &self.timestamp_re,
+                &self.uuid_re,
+                &self.hex_re,
+                &self.num_re,
+                &self.path_re,
+            );
+        }
+
+        result.join("\n")
     }
+}
 
-    result.join("\n")
+/// Render up to `limit` of `counts`' entries, sorted by occurrence count
+/// descending, as `[×N] message` lines (or a bare `message` when it only
+/// occurred once), followed by an `... +N more` line if there's overflow.
+/// Shared by the fatal/error/warning sections, which only differ in their
+/// bucket and limit.
+#[allow(clippy::too_many_arguments)]
+fn push_top_messages(
+    result: &mut Vec<String>,
+    counts: &HashMap<String, usize>,
+    originals: &[String],
+    limit: usize,
+    timestamp_re: &Regex,
+    uuid_re: &Regex,
+    hex_re: &Regex,
+    num_re: &Regex,
+    path_re: &Regex,
+) {
+    let mut sorted: Vec<_> = counts.iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(a.1));
+
+    for (normalized, count) in sorted.iter().take(limit) {
+        let original = originals
+            .iter()
+            .find(|m| {
+                &normalize_log_line(m, timestamp_re, uuid_re, hex_re, num_re, path_re) == *normalized
+            })
+            .map(|s| s.as_str())
+            .unwrap_or(normalized);
+
+        let truncated = if original.len() > 100 {
+            let t: String = original.chars().take(97).collect();
+            format!("{}...", t)
+        } else {
+            original.to_string()
+        };
+
+        if **count > 1 {
+            result.push(format!("   [×{}] {}", count, truncated));
+        } else {
+            result.push(format!("   {}", truncated));
+        }
+    }
+
+    if sorted.len() > limit {
+        result.push(format!("   ... +{} more", sorted.len() - limit));
+    }
 }
 
 fn normalize_log_line(
@@ -228,7 +661,7 @@ mod tests {
 2024-01-01 10:00:03 WARN: Retrying connection
 2024-01-01 10:00:04 INFO: Connected
 "#;
-        let result = analyze_logs(logs);
+        let result = analyze_logs(logs, None, &LogFilters::default(), &StructuredFields::default());
         assert!(result.contains("×3"));
         assert!(result.contains("ERRORS"));
     }
@@ -241,8 +674,161 @@ mod tests {
             "ข้อผิดพลาด".repeat(15),
             "คำเตือน".repeat(15)
         );
-        let result = analyze_logs(&logs);
+        let result = analyze_logs(&logs, None, &LogFilters::default(), &StructuredFields::default());
         // Should not panic even with very long multi-byte messages
         assert!(result.contains("ERRORS"));
     }
+
+    #[test]
+    fn test_extract_severity_boundaries() {
+        assert_eq!(extract_severity("[ERROR] disk full"), Some(Severity::Error));
+        assert_eq!(extract_severity("WARN: retrying"), Some(Severity::Warn));
+        assert_eq!(extract_severity("request took 200ms"), None);
+        // "errored"/"information" aren't whole-token matches
+        assert_eq!(extract_severity("the request errored out"), None);
+        assert_eq!(extract_severity("path /var/log/info/app.log"), None);
+    }
+
+    #[test]
+    fn test_analyze_logs_fatal_is_distinct_from_error() {
+        let logs = "thread panicked: FATAL: out of memory\n\
+                     ERROR: connection refused\n";
+        let result = analyze_logs(logs, None, &LogFilters::default(), &StructuredFields::default());
+        assert!(result.contains("FATAL"));
+        assert!(result.contains("out of memory"));
+        assert!(result.contains("connection refused"));
+    }
+
+    #[test]
+    fn test_analyze_logs_min_severity_filters() {
+        let logs = "INFO: started\nWARN: slow request\nERROR: failed\n";
+        let result = analyze_logs(logs, Some(Severity::Warn), &LogFilters::default(), &StructuredFields::default());
+        assert!(!result.contains("started"));
+        assert!(result.contains("slow request"));
+        assert!(result.contains("failed"));
+    }
+
+    #[test]
+    fn test_severity_parse() {
+        assert_eq!(Severity::parse("warn").unwrap(), Severity::Warn);
+        assert_eq!(Severity::parse("WARNING").unwrap(), Severity::Warn);
+        assert!(Severity::parse("bogus").is_err());
+    }
+
+    #[test]
+    fn test_log_filters_include_and_exclude() {
+        let filters = LogFilters::new(
+            &["database".to_string()],
+            &["retry".to_string()],
+            false,
+        )
+        .unwrap();
+        assert!(filters.keep("ERROR: database connection lost"));
+        assert!(!filters.keep("ERROR: database connection lost, will retry"));
+        assert!(!filters.keep("ERROR: cache miss"));
+    }
+
+    #[test]
+    fn test_log_filters_ignore_case() {
+        let filters = LogFilters::new(&["DATABASE".to_string()], &[], true).unwrap();
+        assert!(filters.keep("error: database down"));
+    }
+
+    #[test]
+    fn test_analyze_logs_applies_include_exclude() {
+        let logs = "ERROR: database timeout\nERROR: cache timeout\n";
+        let filters = LogFilters::new(&["database".to_string()], &[], false).unwrap();
+        let result = analyze_logs(logs, None, &filters, &StructuredFields::default());
+        assert!(result.contains("database timeout"));
+        assert!(!result.contains("cache timeout"));
+    }
+
+    #[test]
+    fn test_aggregator_incremental_matches_one_shot() {
+        let logs = "ERROR: disk full\nERROR: disk full\nWARN: retrying\n";
+        let one_shot = analyze_logs(logs, None, &LogFilters::default(), &StructuredFields::default());
+
+        let filters = LogFilters::default();
+        let mut aggregator = LogAggregator::new(None, &filters, &StructuredFields::default());
+        for line in logs.lines() {
+            aggregator.ingest_line(line);
+        }
+        assert_eq!(aggregator.render(), one_shot);
+    }
+
+    #[test]
+    fn test_aggregator_counts_stay_monotonic_across_flushes() {
+        let filters = LogFilters::default();
+        let mut aggregator = LogAggregator::new(None, &filters, &StructuredFields::default());
+
+        aggregator.ingest_line("ERROR: disk full");
+        let first_flush = aggregator.render();
+        assert!(first_flush.contains("1 errors"));
+
+        aggregator.ingest_line("ERROR: disk full");
+        aggregator.ingest_line("ERROR: disk full");
+        let second_flush = aggregator.render();
+        assert!(second_flush.contains("3 errors"));
+        assert!(second_flush.contains("[×3]"));
+    }
+
+    #[test]
+    fn test_analyze_logs_structured_json_default_fields() {
+        let logs = "{\"level\":\"error\",\"msg\":\"connection failed\"}\n\
+                     {\"level\":\"error\",\"msg\":\"connection failed\"}\n\
+                     {\"level\":\"info\",\"msg\":\"started\"}\n";
+        let result = analyze_logs(logs, None, &LogFilters::default(), &StructuredFields::default());
+        assert!(result.contains("×2"));
+        assert!(result.contains("connection failed"));
+    }
+
+    #[test]
+    fn test_analyze_logs_structured_json_custom_fields() {
+        let logs = "{\"severity\":\"ERROR\",\"text\":\"disk full\"}\n";
+        let structured = StructuredFields {
+            level_field: Some("severity".to_string()),
+            msg_field: Some("text".to_string()),
+        };
+        let result = analyze_logs(logs, None, &LogFilters::default(), &structured);
+        assert!(result.contains("disk full"));
+    }
+
+    #[test]
+    fn test_analyze_logs_mixed_plaintext_and_json_falls_back_per_line() {
+        let logs = "ERROR: plain text failure\n\
+                     {\"level\":\"error\",\"msg\":\"structured failure\"}\n";
+        let result = analyze_logs(logs, None, &LogFilters::default(), &StructuredFields::default());
+        assert!(result.contains("plain text failure"));
+        assert!(result.contains("structured failure"));
+    }
+
+    #[test]
+    fn test_parse_structured_line_ignores_non_json() {
+        assert!(parse_structured_line("ERROR: not json", None, None).is_none());
+    }
+
+    #[test]
+    fn test_parse_structured_line_unresolvable_level_is_none() {
+        assert!(parse_structured_line(r#"{"msg":"no level field"}"#, None, None).is_none());
+    }
+
+    #[test]
+    fn test_aggregator_partial_line_buffering_matches_whole_line() {
+        // Simulates follow mode receiving "ERROR: disk " and "full\n" as
+        // two separate chunks -- the partial line must only be counted
+        // once it's complete.
+        let filters = LogFilters::default();
+        let mut aggregator = LogAggregator::new(None, &filters, &StructuredFields::default());
+        let mut pending = String::from("ERROR: disk ");
+        pending.push_str("full\n");
+        let mut ingested = 0;
+        while let Some(idx) = pending.find('\n') {
+            let line = pending[..idx].to_string();
+            pending.drain(..=idx);
+            aggregator.ingest_line(&line);
+            ingested += 1;
+        }
+        assert_eq!(ingested, 1);
+        assert!(aggregator.render().contains("disk full"));
+    }
 }
@@ -0,0 +1,193 @@
+//! Drain-style log-template clustering: an online algorithm that groups
+//! similar log lines into templates instead of `normalize_log_line`'s
+//! blanket regex substitution. Plain regex normalization over-merges
+//! unrelated messages that happen to share a timestamp/UUID/number shape
+//! and under-merges ones that differ only in ordinary words; clustering
+//! on token-level similarity tells those cases apart.
+//!
+//! Each line is tokenized on whitespace and routed through a fixed-depth
+//! parse tree -- first level keyed on token count, second level keyed on
+//! the first few leading tokens (digit-bearing tokens treated as
+//! wildcards so they don't fragment the tree) -- down to a small leaf
+//! list of candidate clusters. The line is compared against each
+//! candidate by per-position token equality; if the best match clears
+//! [`SIMILARITY_THRESHOLD`] the line joins that cluster and any
+//! mismatched positions in the template become a `<*>` wildcard,
+//! otherwise a new cluster is created.
+
+use std::collections::HashMap;
+
+const WILDCARD: &str = "<*>";
+/// How many leading tokens key the second level of the parse tree; tokens
+/// beyond this depth only affect similarity scoring, not routing.
+const PREFIX_DEPTH: usize = 4;
+/// Minimum fraction of equal-position tokens for a line to join an
+/// existing cluster rather than start a new one.
+const SIMILARITY_THRESHOLD: f64 = 0.5;
+
+/// A learned template: tokens shared by every line in the cluster stay
+/// literal; positions that varied become [`WILDCARD`].
+#[derive(Debug, Clone)]
+pub struct Template {
+    tokens: Vec<String>,
+    pub count: usize,
+    pub example: String,
+}
+
+impl Template {
+    pub fn rendered(&self) -> String {
+        self.tokens.join(" ")
+    }
+}
+
+fn is_wildcard_token(token: &str) -> bool {
+    token.chars().any(|c| c.is_ascii_digit())
+}
+
+fn prefix_signature(tokens: &[String]) -> Vec<String> {
+    tokens
+        .iter()
+        .take(PREFIX_DEPTH)
+        .map(|t| {
+            if is_wildcard_token(t) {
+                WILDCARD.to_string()
+            } else {
+                t.clone()
+            }
+        })
+        .collect()
+}
+
+/// Fraction of positions where `template` and `tokens` agree (a `<*>`
+/// slot in the template always counts as agreeing). Templates and token
+/// lists of different lengths never match -- token count is the first
+/// level of the parse tree, so this only runs within one token-count
+/// bucket in practice.
+fn similarity(template: &[String], tokens: &[String]) -> f64 {
+    if template.is_empty() || template.len() != tokens.len() {
+        return 0.0;
+    }
+    let matches = template
+        .iter()
+        .zip(tokens.iter())
+        .filter(|(t, l)| t.as_str() == WILDCARD || *t == l)
+        .count();
+    matches as f64 / template.len() as f64
+}
+
+/// Online Drain-style clusterer. Feed lines in with [`Drain::ingest`] and
+/// read back the learned templates with [`Drain::templates`].
+#[derive(Default)]
+pub struct Drain {
+    templates: Vec<Template>,
+    // token count -> leading-token signature -> candidate cluster indices
+    tree: HashMap<usize, HashMap<Vec<String>, Vec<usize>>>,
+}
+
+impl Drain {
+    pub fn new() -> Self {
+        Drain::default()
+    }
+
+    /// Route `line` to an existing cluster (updating its template) or
+    /// start a new one. A no-op for blank lines.
+    pub fn ingest(&mut self, line: &str) {
+        let tokens: Vec<String> = line.split_whitespace().map(|s| s.to_string()).collect();
+        if tokens.is_empty() {
+            return;
+        }
+
+        let signature = prefix_signature(&tokens);
+        let leaf: Vec<usize> = self
+            .tree
+            .entry(tokens.len())
+            .or_default()
+            .entry(signature.clone())
+            .or_default()
+            .clone();
+
+        let best = leaf
+            .iter()
+            .map(|&idx| (idx, similarity(&self.templates[idx].tokens, &tokens)))
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+        match best {
+            Some((idx, sim)) if sim >= SIMILARITY_THRESHOLD => {
+                let template = &mut self.templates[idx];
+                for (slot, token) in template.tokens.iter_mut().zip(tokens.iter()) {
+                    if slot != token && slot != WILDCARD {
+                        *slot = WILDCARD.to_string();
+                    }
+                }
+                template.count += 1;
+            }
+            _ => {
+                let idx = self.templates.len();
+                let token_count = tokens.len();
+                self.templates.push(Template {
+                    tokens,
+                    count: 1,
+                    example: line.to_string(),
+                });
+                self.tree
+                    .get_mut(&token_count)
+                    .unwrap()
+                    .get_mut(&signature)
+                    .unwrap()
+                    .push(idx);
+            }
+        }
+    }
+
+    /// All learned templates, most frequent first.
+    pub fn templates(&self) -> Vec<&Template> {
+        let mut out: Vec<&Template> = self.templates.iter().collect();
+        out.sort_by(|a, b| b.count.cmp(&a.count));
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clusters_lines_differing_only_in_variable() {
+        let mut drain = Drain::new();
+        drain.ingest("connection to db-1 failed after 3 retries");
+        drain.ingest("connection to db-2 failed after 7 retries");
+        let templates = drain.templates();
+        assert_eq!(templates.len(), 1);
+        assert_eq!(templates[0].count, 2);
+        assert!(templates[0].rendered().contains("<*>"));
+    }
+
+    #[test]
+    fn test_keeps_different_messages_separate() {
+        let mut drain = Drain::new();
+        drain.ingest("connection to db-1 failed");
+        drain.ingest("cache miss for key user-42");
+        let templates = drain.templates();
+        assert_eq!(templates.len(), 2);
+    }
+
+    #[test]
+    fn test_most_frequent_template_first() {
+        let mut drain = Drain::new();
+        drain.ingest("cache miss for key a");
+        drain.ingest("connection to db-1 failed");
+        drain.ingest("connection to db-2 failed");
+        drain.ingest("connection to db-3 failed");
+        let templates = drain.templates();
+        assert_eq!(templates[0].count, 3);
+        assert!(templates[0].rendered().contains("connection"));
+    }
+
+    #[test]
+    fn test_blank_lines_are_ignored() {
+        let mut drain = Drain::new();
+        drain.ingest("");
+        drain.ingest("   ");
+        assert!(drain.templates().is_empty());
+    }
+}
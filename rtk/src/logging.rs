@@ -0,0 +1,38 @@
+//! Tracing subscriber setup for rtk's internal diagnostics — today, mostly
+//! [`crate::tracking::TimedExecution`] and [`crate::tracking::Tracker`]
+//! spans/events reporting why a command wasn't recorded. Output format and
+//! color are user-selectable (`--log-format`, `--no-color`), mirroring the
+//! verbosity (`-v`/`-vv`/`-vvv`) convention already used for command
+//! output across the rest of the CLI.
+
+use tracing_subscriber::EnvFilter;
+
+/// Install the global tracing subscriber. `format` is `"json"` for
+/// machine-readable output or anything else for the default compact human
+/// format; `no_color` disables ANSI in the compact format; `verbosity`
+/// mirrors the CLI's `-v` count (0 = warn, 1 = info, 2 = debug, 3+ =
+/// trace). Safe to call once per process; a second call is a no-op.
+pub fn init(format: &str, no_color: bool, verbosity: u8) {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(level_for(verbosity)));
+
+    let subscriber = tracing_subscriber::fmt().with_env_filter(filter).with_target(false);
+
+    let result = if format == "json" {
+        subscriber.json().try_init()
+    } else {
+        subscriber.with_ansi(!no_color).try_init()
+    };
+
+    // A second `init` call (e.g. in tests) fails because a subscriber is
+    // already installed; that's expected, not an error worth surfacing.
+    let _ = result;
+}
+
+fn level_for(verbosity: u8) -> &'static str {
+    match verbosity {
+        0 => "warn",
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    }
+}
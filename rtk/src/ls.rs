@@ -1,8 +1,14 @@
+use crate::find_cmd::glob_match;
 use crate::tracking;
 use anyhow::{Context, Result};
+use ignore::WalkBuilder;
+use std::collections::HashSet;
+use std::path::Path;
 use std::process::Command;
 
-/// Noise directories commonly excluded from LLM context
+/// Noise directories commonly excluded from LLM context. Entries may be
+/// shell-style glob patterns (`*.egg-info`), matched via `glob_match`
+/// rather than exact string equality.
 const NOISE_DIRS: &[&str] = &[
     "node_modules",
     ".git",
@@ -37,10 +43,11 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
     let show_all = args
         .iter()
         .any(|a| (a.starts_with('-') && !a.starts_with("--") && a.contains('a')) || a == "--all");
+    let use_ignore = !args.iter().any(|a| a == "--no-ignore");
 
     let flags: Vec<&str> = args
         .iter()
-        .filter(|a| a.starts_with('-'))
+        .filter(|a| a.starts_with('-') && *a != "--no-ignore")
         .map(|s| s.as_str())
         .collect();
     let paths: Vec<&str> = args
@@ -89,7 +96,8 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
     }
 
     let raw = String::from_utf8_lossy(&output.stdout).to_string();
-    let filtered = compact_ls(&raw, show_all);
+    let root = Path::new(paths.first().copied().unwrap_or("."));
+    let filtered = compact_ls(&raw, show_all, use_ignore, root);
 
     if verbose > 0 {
         eprintln!(
@@ -131,16 +139,49 @@ fn human_size(bytes: u64) -> String {
     }
 }
 
+/// The set of entry names directly under `root` that `.gitignore`/`.ignore`
+/// rules (including parent-directory and global excludes) would keep
+/// visible. Mirrors `tree::list_children`'s depth-1 walk so `ls` and `tree`
+/// agree on what counts as project-specific noise.
+fn gitignore_visible_names(root: &Path) -> HashSet<String> {
+    let mut builder = WalkBuilder::new(root);
+    builder
+        .max_depth(Some(1))
+        .hidden(false)
+        .require_git(false)
+        .git_ignore(true)
+        .git_global(true)
+        .git_exclude(true)
+        .ignore(true);
+
+    builder
+        .build()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.depth() == 1)
+        .filter_map(|e| e.file_name().to_str().map(|s| s.to_string()))
+        .collect()
+}
+
 /// Parse ls -la output into compact format:
 ///   name/  (dirs)
 ///   name  size  (files)
-fn compact_ls(raw: &str, show_all: bool) -> String {
+///
+/// Noise filtering drops two kinds of entries (unless `show_all`): names
+/// matching a `NOISE_DIRS` glob pattern, and, when `use_ignore` is set,
+/// names that `root`'s `.gitignore`/`.ignore` rules would exclude.
+fn compact_ls(raw: &str, show_all: bool, use_ignore: bool, root: &Path) -> String {
     use std::collections::HashMap;
 
     let mut dirs: Vec<String> = Vec::new();
     let mut files: Vec<(String, String)> = Vec::new(); // (name, size)
     let mut by_ext: HashMap<String, usize> = HashMap::new();
 
+    let visible = if !show_all && use_ignore {
+        Some(gitignore_visible_names(root))
+    } else {
+        None
+    };
+
     for line in raw.lines() {
         // Skip total, empty, . and ..
         if line.starts_with("total ") || line.is_empty() {
@@ -160,9 +201,18 @@ fn compact_ls(raw: &str, show_all: bool) -> String {
             continue;
         }
 
-        // Filter noise dirs unless -a
-        if !show_all && NOISE_DIRS.iter().any(|noise| name == *noise) {
-            continue;
+        // Filter noise dirs unless -a: glob-matched NOISE_DIRS patterns, or
+        // (when --no-ignore isn't set) anything the project's gitignore
+        // rules would exclude.
+        if !show_all {
+            let is_noise = NOISE_DIRS.iter().any(|pat| glob_match(pat, &name));
+            let is_gitignored = visible
+                .as_ref()
+                .map(|v| !v.contains(&name))
+                .unwrap_or(false);
+            if is_noise || is_gitignored {
+                continue;
+            }
         }
 
         let is_dir = parts[0].starts_with('d');
@@ -237,7 +287,7 @@ mod tests {
                      drwxr-xr-x  2 user  staff    64 Jan  1 12:00 src\n\
                      -rw-r--r--  1 user  staff  1234 Jan  1 12:00 Cargo.toml\n\
                      -rw-r--r--  1 user  staff  5678 Jan  1 12:00 README.md\n";
-        let output = compact_ls(input, false);
+        let output = compact_ls(input, false, false, Path::new("."));
         assert!(output.contains("src/"));
         assert!(output.contains("Cargo.toml"));
         assert!(output.contains("README.md"));
@@ -258,7 +308,7 @@ mod tests {
                      drwxr-xr-x  2 user  staff  64 Jan  1 12:00 target\n\
                      drwxr-xr-x  2 user  staff  64 Jan  1 12:00 src\n\
                      -rw-r--r--  1 user  staff  100 Jan  1 12:00 main.rs\n";
-        let output = compact_ls(input, false);
+        let output = compact_ls(input, false, false, Path::new("."));
         assert!(!output.contains("node_modules"));
         assert!(!output.contains(".git"));
         assert!(!output.contains("target"));
@@ -271,7 +321,7 @@ mod tests {
         let input = "total 8\n\
                      drwxr-xr-x  2 user  staff  64 Jan  1 12:00 .git\n\
                      drwxr-xr-x  2 user  staff  64 Jan  1 12:00 src\n";
-        let output = compact_ls(input, true);
+        let output = compact_ls(input, true, false, Path::new("."));
         assert!(output.contains(".git/"));
         assert!(output.contains("src/"));
     }
@@ -279,7 +329,7 @@ mod tests {
     #[test]
     fn test_compact_empty() {
         let input = "total 0\n";
-        let output = compact_ls(input, false);
+        let output = compact_ls(input, false, false, Path::new("."));
         assert_eq!(output, "(empty)\n");
     }
 
@@ -290,7 +340,7 @@ mod tests {
                      -rw-r--r--  1 user  staff  1234 Jan  1 12:00 main.rs\n\
                      -rw-r--r--  1 user  staff  5678 Jan  1 12:00 lib.rs\n\
                      -rw-r--r--  1 user  staff   100 Jan  1 12:00 Cargo.toml\n";
-        let output = compact_ls(input, false);
+        let output = compact_ls(input, false, false, Path::new("."));
         assert!(output.contains("📊 3 files, 1 dirs"));
         assert!(output.contains(".rs"));
         assert!(output.contains(".toml"));
@@ -310,7 +360,7 @@ mod tests {
     fn test_compact_handles_filenames_with_spaces() {
         let input = "total 8\n\
                      -rw-r--r--  1 user  staff  1234 Jan  1 12:00 my file.txt\n";
-        let output = compact_ls(input, false);
+        let output = compact_ls(input, false, false, Path::new("."));
         assert!(output.contains("my file.txt"));
     }
 
@@ -318,7 +368,38 @@ mod tests {
     fn test_compact_symlinks() {
         let input = "total 8\n\
                      lrwxr-xr-x  1 user  staff  10 Jan  1 12:00 link -> target\n";
-        let output = compact_ls(input, false);
+        let output = compact_ls(input, false, false, Path::new("."));
         assert!(output.contains("link -> target"));
     }
+
+    #[test]
+    fn test_compact_filters_noise_glob_pattern() {
+        // "*.egg-info" only matches via glob, not `name == *noise`.
+        let input = "total 8\n\
+                     drwxr-xr-x  2 user  staff  64 Jan  1 12:00 mypkg.egg-info\n\
+                     drwxr-xr-x  2 user  staff  64 Jan  1 12:00 src\n";
+        let output = compact_ls(input, false, false, Path::new("."));
+        assert!(!output.contains("egg-info"));
+        assert!(output.contains("src/"));
+    }
+
+    #[test]
+    fn test_compact_honors_gitignore() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join(".gitignore"), "vendor/\n").unwrap();
+        std::fs::create_dir(dir.path().join("vendor")).unwrap();
+        std::fs::create_dir(dir.path().join("src")).unwrap();
+
+        let input = "total 8\n\
+                     drwxr-xr-x  2 user  staff  64 Jan  1 12:00 vendor\n\
+                     drwxr-xr-x  2 user  staff  64 Jan  1 12:00 src\n";
+
+        let ignored = compact_ls(input, false, true, dir.path());
+        assert!(!ignored.contains("vendor"));
+        assert!(ignored.contains("src/"));
+
+        let not_ignored = compact_ls(input, false, false, dir.path());
+        assert!(not_ignored.contains("vendor"));
+        assert!(not_ignored.contains("src/"));
+    }
 }
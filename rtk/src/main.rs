@@ -1,6 +1,7 @@
 mod cargo_cmd;
 mod cc_economics;
 mod ccusage;
+mod ci_cmd;
 mod config;
 mod container;
 mod curl_cmd;
@@ -8,45 +9,84 @@ mod deps;
 mod diff_cmd;
 mod discover;
 mod display_helpers;
+mod doctor;
+mod economics_cache;
 mod env_cmd;
+mod events;
 mod filter;
 mod find_cmd;
 mod format_cmd;
 mod gain;
+mod gh_cache;
 mod gh_cmd;
 mod git;
+#[cfg(feature = "libgit2-backend")]
+mod git_backend;
+mod github_api;
+mod glob_filter;
 mod go_cmd;
 mod golangci_cmd;
 mod grep_cmd;
+mod info_cmd;
 mod init;
+mod jobs;
 mod json_cmd;
+#[cfg(feature = "kube-client")]
+mod k8s_client;
+mod latency_histogram;
 mod learn;
 mod lint_cmd;
+mod lint_orchestrator;
 mod local_llm;
+mod locale;
 mod log_cmd;
+mod log_drain;
+mod logging;
 mod ls;
+mod matcher;
+#[cfg(feature = "metrics-server")]
+mod metrics_server;
+mod monorepo;
 mod next_cmd;
 mod npm_cmd;
 mod parser;
+mod picker;
 mod pip_cmd;
 mod playwright_cmd;
 mod pnpm_cmd;
 mod prettier_cmd;
+mod pricing;
 mod prisma_cmd;
+mod proxy_cmd;
 mod pytest_cmd;
 mod read;
+mod redact;
+mod router;
 mod ruff_cmd;
+mod rrule_period;
 mod runner;
+mod sql_ddl;
+mod stats_filter;
+mod style;
+mod suggest;
 mod summary;
+mod time_range;
+mod tokenizer;
 mod tracking;
 mod tree;
+mod ts;
 mod tsc_cmd;
+#[cfg(feature = "tui")]
+mod tui;
 mod utils;
+mod version;
 mod vitest_cmd;
+#[cfg(feature = "webhook-server")]
+mod webhook;
 mod wget_cmd;
 
 use anyhow::{Context, Result};
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
 use std::ffi::OsString;
 use std::path::{Path, PathBuf};
 
@@ -54,6 +94,9 @@ use std::path::{Path, PathBuf};
 #[command(
     name = "rtk",
     version,
+    // Baked in by build.rs: "<CARGO_PKG_VERSION> (<short-sha>@<branch>[, dirty])",
+    // falling back to the plain version outside a git checkout.
+    long_version = env!("RTK_LONG_VERSION"),
     about = "Rust Token Killer - Minimize LLM token consumption",
     long_about = "A high-performance CLI proxy designed to filter and summarize system outputs before they reach your LLM context."
 )]
@@ -72,10 +115,35 @@ struct Cli {
     /// Set SKIP_ENV_VALIDATION=1 for child processes (Next.js, tsc, lint, prisma)
     #[arg(long = "skip-env", global = true)]
     skip_env: bool,
+
+    /// Emit machine-readable JSON (canonical types) instead of human-formatted text
+    #[arg(long, global = true)]
+    json: bool,
+
+    /// Diagnostic log output format: compact (human) or json (machine-readable)
+    #[arg(long, global = true, default_value = "compact")]
+    log_format: String,
+
+    /// Disable ANSI color in diagnostic log output
+    #[arg(long, global = true)]
+    no_color: bool,
+
+    /// Named filtering preset from `[profile.<name>]` in the config file
+    /// (e.g. `aggressive`, `review`, `ci`), setting defaults like filter
+    /// level, line/truncation limits, and dedup threshold across
+    /// subcommands. Explicit per-command flags still take precedence.
+    #[arg(long, global = true)]
+    profile: Option<String>,
 }
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Print version plus embedded git branch/commit and build timestamp
+    Version {
+        /// Output format: text, json
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
     /// List directory contents with token-optimized output (proxy to native ls)
     Ls {
         /// Arguments passed to ls (supports all native ls flags like -l, -a, -h, -R)
@@ -92,17 +160,36 @@ enum Commands {
 
     /// Read file with intelligent filtering
     Read {
-        /// File to read
-        file: PathBuf,
-        /// Filter: none, minimal, aggressive
-        #[arg(short, long, default_value = "minimal")]
-        level: filter::FilterLevel,
+        /// File(s) to read. Accepts multiple paths and/or glob patterns
+        /// (e.g. `src/*.ts`); with more than one resolved file, output
+        /// becomes a combined digest with a `─── path ───` header per file
+        /// and a trailing aggregate reduction summary.
+        #[arg(required = true, num_args = 1..)]
+        files: Vec<String>,
+        /// Filter: none, minimal, aggressive. Defaults to the active
+        /// `--profile`'s filter level (or `minimal`, with no profile set).
+        #[arg(short, long)]
+        level: Option<filter::FilterLevel>,
         /// Max lines
         #[arg(short, long)]
         max_lines: Option<usize>,
-        /// Show line numbers
+        /// Show line numbers (restarts per file in multi-file mode)
         #[arg(short = 'n', long)]
         line_numbers: bool,
+        /// In multi-file mode, skip files whose content duplicates one
+        /// already read (by content hash)
+        #[arg(long)]
+        dedup: bool,
+        /// Emit only the windows of source around these 1-based locations
+        /// instead of the whole (filtered) file, e.g. `12:3,88:5` (line:
+        /// context, context falls back to --context). Requires exactly one
+        /// file; composes with `tsc`'s file+line error output.
+        #[arg(long, value_delimiter = ',')]
+        around: Vec<String>,
+        /// Default context line count for --around entries with no
+        /// per-target override
+        #[arg(long, default_value_t = 3)]
+        context: usize,
     },
 
     /// Generate 2-line technical summary (heuristic-based)
@@ -115,6 +202,17 @@ enum Commands {
         /// Force model download
         #[arg(long)]
         force_download: bool,
+        /// Output: text (2-line summary) or annotated (underlined source snippets)
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
+
+    /// Detect the project's stack and key dependency versions, and check
+    /// which tools rtk's specialized filters wrap are installed
+    Doctor {
+        /// Output format: text, json
+        #[arg(long, default_value = "text")]
+        format: String,
     },
 
     /// Git commands with compact output
@@ -125,9 +223,19 @@ enum Commands {
 
     /// GitHub CLI (gh) commands with token-optimized output
     Gh {
-        /// Subcommand: pr, issue, run, repo
+        /// Subcommand: pr, issue, run, repo, webhook
         subcommand: String,
-        /// Additional arguments
+        /// Additional arguments. Pass --format json or --format ndjson for
+        /// a stable typed record instead of the decorated text output.
+        /// `pr list`/`pr view`/`run list` responses are cached on disk for
+        /// a short TTL; pass --no-cache or --refresh to bypass it.
+        /// `api --rtk-paginate` follows REST Link/GraphQL pageInfo cursors
+        /// and aggregates every page before filtering (--rtk-max-pages to
+        /// change the 10-page cap). `webhook` starts a local receiver
+        /// (--addr, --secret or $GH_WEBHOOK_SECRET) and requires the
+        /// webhook-server feature. `repo view` accepts multiple owner/repo
+        /// args plus --min-stars N to screen a batch down to the popular
+        /// ones.
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
@@ -140,14 +248,28 @@ enum Commands {
 
     /// Run command and show only errors/warnings
     Err {
-        /// Command to run
+        /// Command to run. Pass --watch to re-run on file changes, --fix to
+        /// auto-apply machine-applicable compiler suggestions (--dry-run
+        /// previews the edits instead of writing them), --expect <path> to
+        /// check the filtered output against a saved snapshot (--bless to
+        /// write/update it instead of erroring on a mismatch), and/or
+        /// repeated --filter-out 'PATTERN=>REPLACEMENT' to normalize
+        /// volatile substrings before filtering.
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         command: Vec<String>,
     },
 
     /// Run tests and show only failures
     Test {
-        /// Test command (e.g. cargo test)
+        /// Test command (e.g. cargo test). Pass --watch to re-run on file
+        /// changes, --json to parse the framework's JSON reporter instead of
+        /// scraping stdout, --expect <path> to check the summary against a
+        /// saved snapshot (--bless to write/update it instead of erroring on
+        /// a mismatch), --shuffle to run tests in a seeded random order
+        /// (--shuffle-seed <seed> to pin it, otherwise one is derived and
+        /// echoed in the summary so the run can be replayed), and/or
+        /// repeated --filter-out 'PATTERN=>REPLACEMENT' to normalize
+        /// volatile substrings before summarizing.
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         command: Vec<String>,
     },
@@ -159,6 +281,9 @@ enum Commands {
         /// Max depth
         #[arg(short, long, default_value = "5")]
         depth: usize,
+        /// Schema render: text (compact view) or json (JSON Schema draft-07)
+        #[arg(long, default_value = "text")]
+        schema_format: String,
     },
 
     /// Summarize project dependencies
@@ -166,6 +291,12 @@ enum Commands {
         /// Project path
         #[arg(default_value = ".")]
         path: PathBuf,
+        /// Max workspace/monorepo member traversal depth
+        #[arg(long, default_value_t = 4)]
+        depth: usize,
+        /// Don't descend into workspace/monorepo members
+        #[arg(long)]
+        no_recurse: bool,
     },
 
     /// Show environment variables (filtered, sensitive masked)
@@ -180,31 +311,107 @@ enum Commands {
 
     /// Find files with compact tree output
     Find {
-        /// Pattern to search (glob)
-        pattern: String,
+        /// Pattern(s) to search (glob; supports `**`, `{a,b}`, `[A-Z]`).
+        /// Multiple patterns are matched in a single pass, e.g.
+        /// `rtk find '*.rs' '*.toml'`.
+        #[arg(required = true, trailing_var_arg = true)]
+        patterns: Vec<String>,
         /// Path to search in
-        #[arg(default_value = ".")]
+        #[arg(short = 'C', long = "path", default_value = ".")]
         path: String,
         /// Maximum results to show
         #[arg(short, long, default_value = "50")]
         max: usize,
-        /// Filter by type: f (file), d (directory)
+        /// Filter by type: f (file), d (directory), l (symlink),
+        /// x (executable), e (empty file)
         #[arg(short = 't', long, default_value = "f")]
         file_type: String,
+        /// Only include entries whose size compares to this spec, e.g.
+        /// `+10k` (greater than), `-1M` (less than), `512b` (exact)
+        #[arg(long)]
+        size: Option<String>,
+        /// Only include entries modified within this long ago, e.g. `2d`, `1h`
+        #[arg(long = "changed-within")]
+        changed_within: Option<String>,
+        /// Only include entries last modified before this long ago, e.g. `1w`
+        #[arg(long = "changed-before")]
+        changed_before: Option<String>,
+        /// Include hidden files/directories (dotfiles)
+        #[arg(long)]
+        hidden: bool,
+        /// Disable all gitignore/.ignore filtering
+        #[arg(long = "no-ignore")]
+        no_ignore: bool,
+        /// Don't consult .gitignore/.ignore files in parent directories
+        #[arg(long = "no-ignore-parent")]
+        no_ignore_parent: bool,
+        /// Maximum directory depth to descend
+        #[arg(long = "max-depth")]
+        max_depth: Option<usize>,
+        /// Run a command once per match, e.g. `--exec 'rustfmt {}'`. Supports
+        /// `{}` (path), `{.}` (stem), `{/}` (basename), `{//}` (parent dir)
+        #[arg(long)]
+        exec: Option<String>,
+        /// Run a command once with all matches appended as arguments
+        #[arg(long = "exec-batch")]
+        exec_batch: Option<String>,
     },
 
-    /// Ultra-condensed diff (only changed lines)
+    /// Ultra-condensed diff (only changed lines; snapshots of the same
+    /// canonical type diff semantically instead of line-by-line)
     Diff {
         /// First file or - for stdin (unified diff)
         file1: PathBuf,
         /// Second file (optional if stdin)
         file2: Option<PathBuf>,
+        /// Align on a patience-diff backbone (unique-in-both lines)
+        /// instead of raw Myers, for cleaner hunks on files full of
+        /// repeated boilerplate like `}` or blank lines
+        #[arg(long)]
+        patience: bool,
+        /// Context lines to keep around each change when condensing a
+        /// piped unified diff (stdin mode)
+        #[arg(long, default_value_t = 2)]
+        context: usize,
+        /// Output format: text, json (the full untruncated DiffResult,
+        /// for CI gates and other automation)
+        #[arg(long, default_value = "text")]
+        format: String,
     },
 
     /// Filter and deduplicate log output
     Log {
         /// Log file (omit for stdin)
         file: Option<PathBuf>,
+        /// Drop lines below this severity (trace, debug, info, warn,
+        /// error, fatal)
+        #[arg(long = "min-severity")]
+        min_severity: Option<String>,
+        /// Only keep lines matching this regex (repeatable; a line is
+        /// kept if it matches any --include pattern)
+        #[arg(long)]
+        include: Vec<String>,
+        /// Drop lines matching this regex (repeatable)
+        #[arg(long)]
+        exclude: Vec<String>,
+        /// Match --include/--exclude patterns case-insensitively
+        #[arg(long = "ignore-case")]
+        ignore_case: bool,
+        /// Follow appended lines (tail -f style) and reprint a rolling summary
+        #[arg(short = 'f', long)]
+        follow: bool,
+        /// Group lines into learned templates (Drain-style clustering)
+        /// instead of a severity summary
+        #[arg(long)]
+        cluster: bool,
+        /// JSON field to read severity from on structured (JSON-line) logs
+        /// (default: tries level, severity, lvl)
+        #[arg(long = "level-field")]
+        level_field: Option<String>,
+        /// JSON field to read the message from on structured (JSON-line)
+        /// logs (default: tries msg, message)
+        #[arg(long = "msg-field")]
+        msg_field: Option<String>,
     },
 
     /// Docker commands with compact output
@@ -245,7 +452,10 @@ enum Commands {
         /// Filter by file type (e.g., ts, py, rust)
         #[arg(short = 't', long)]
         file_type: Option<String>,
-        /// Extra ripgrep arguments (e.g., -i, -A 3, -w, --glob)
+        /// Extra ripgrep arguments (e.g., -i, -A 3, -w). Also accepts
+        /// repeatable --glob PATTERN flags (leading `!` negates) to scope
+        /// results by path the same way `rtk ruff` does, independent of
+        /// rg's own --type/--glob syntax.
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         extra_args: Vec<String>,
     },
@@ -260,6 +470,18 @@ enum Commands {
         #[arg(long)]
         show: bool,
 
+        /// Check an existing installation for drift or breakage and exit non-zero on problems
+        #[arg(long)]
+        doctor: bool,
+
+        /// Diff installed artifacts against the embedded current versions and exit non-zero on drift
+        #[arg(long)]
+        verify: bool,
+
+        /// With --verify, re-sync any drifted artifacts instead of just reporting them
+        #[arg(long, requires = "verify")]
+        fix: bool,
+
         /// Inject full instructions into CLAUDE.md (legacy mode)
         #[arg(long = "claude-md", group = "mode")]
         claude_md: bool,
@@ -279,6 +501,22 @@ enum Commands {
         /// Remove all RTK artifacts (hook, RTK.md, CLAUDE.md reference, settings.json entry)
         #[arg(long)]
         uninstall: bool,
+
+        /// Restore the previous hook/RTK.md/CLAUDE.md/settings.json from .rtk.bak snapshots
+        #[arg(long)]
+        restore: bool,
+
+        /// Install a pre-commit git hook that runs project checks through rtk
+        #[arg(long = "git-hooks")]
+        git_hooks: bool,
+
+        /// Also install a pre-push hook (requires --git-hooks)
+        #[arg(long = "pre-push", requires = "git_hooks")]
+        pre_push: bool,
+
+        /// Preview every file change as a unified diff without writing anything
+        #[arg(long = "dry-run")]
+        dry_run: bool,
     },
 
     /// Download with compact output (strips progress bars)
@@ -319,9 +557,64 @@ enum Commands {
         /// Show all time breakdowns (daily + weekly + monthly)
         #[arg(short, long)]
         all: bool,
-        /// Output format: text, json, csv
+        /// Scope the report to a window since a natural-language expression
+        /// (e.g. "yesterday", "last friday", "01/01/26")
+        #[arg(long, conflicts_with = "range")]
+        since: Option<String>,
+        /// Scope the report to an explicit "FROM..TO" window (e.g.
+        /// "01/01/26..today"); TO defaults to now when omitted
+        #[arg(long, conflicts_with = "since")]
+        range: Option<String>,
+        /// Output format: text, json, csv, prometheus
         #[arg(short, long, default_value = "text")]
         format: String,
+        /// Serve a Prometheus /metrics endpoint on ADDR instead of printing
+        /// a report (e.g. "127.0.0.1:9898"); requires the `metrics-server`
+        /// feature
+        #[arg(long, value_name = "ADDR")]
+        serve_metrics: Option<String>,
+        /// Browse command history in an interactive terminal UI instead of
+        /// printing a report; requires the `tui` feature
+        #[arg(short, long)]
+        interactive: bool,
+        /// Export full command history instead of printing a report:
+        /// json, csv, or markdown
+        #[arg(long, value_name = "FORMAT")]
+        export: Option<String>,
+        /// Number of most recent records to include with --export
+        #[arg(long, default_value_t = 1000, requires = "export")]
+        export_limit: usize,
+        /// List matching command records instead of a report (see
+        /// --command, --min-savings, --sort, --limit)
+        #[arg(short, long)]
+        list: bool,
+        /// Filter --list records to those whose rtk command contains SUBSTR
+        #[arg(long, value_name = "SUBSTR", requires = "list")]
+        command: Option<String>,
+        /// Filter --list records to at least this savings percentage
+        #[arg(long, value_name = "PCT", requires = "list")]
+        min_savings: Option<f64>,
+        /// Sort --list records by: saved, pct, time, time-desc
+        #[arg(long, default_value = "time-desc", requires = "list")]
+        sort: String,
+        /// Maximum --list records to show
+        #[arg(long, default_value_t = 50, requires = "list")]
+        limit: usize,
+        /// Pricing profile for dollar-savings estimates (e.g.
+        /// claude-sonnet-4, claude-opus-4, claude-haiku-4)
+        #[arg(long, value_name = "MODEL")]
+        model: Option<String>,
+        /// Override the input-token price ($/million tokens) instead of
+        /// using --model's built-in rate
+        #[arg(long, value_name = "USD_PER_MTOK")]
+        input_price: Option<f64>,
+        /// Override the output-token price ($/million tokens) instead of
+        /// using --model's built-in rate
+        #[arg(long, value_name = "USD_PER_MTOK")]
+        output_price: Option<f64>,
+        /// Color the text report: auto, always, never
+        #[arg(long, default_value = "auto")]
+        color: String,
     },
 
     /// Claude Code economics: spending (ccusage) vs savings (rtk) analysis
@@ -338,9 +631,14 @@ enum Commands {
         /// Show all time breakdowns (daily + weekly + monthly)
         #[arg(short, long)]
         all: bool,
-        /// Output format: text, json, csv
+        /// Output format: text, json, csv, ndjson (nested per-period
+        /// records with explicit nulls, one per line, trailing totals line)
         #[arg(short, long, default_value = "text")]
         format: String,
+        /// Bypass the on-disk economics snapshot cache and recompute every
+        /// period from a full ccusage fetch
+        #[arg(long)]
+        no_cache: bool,
     },
 
     /// Show or create configuration file
@@ -364,7 +662,11 @@ enum Commands {
 
     /// TypeScript compiler with grouped error output
     Tsc {
-        /// TypeScript compiler arguments
+        /// TypeScript compiler arguments. Pass --watch or -w to stream
+        /// tsc's own watch mode: each recompilation is filtered and
+        /// printed as its own batch instead of a single one-shot run.
+        /// --format {text,json,sarif} selects the diagnostic renderer
+        /// (default text); json/sarif both preserve tsc's exit code.
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
@@ -383,6 +685,22 @@ enum Commands {
         args: Vec<String>,
     },
 
+    /// Run ruff check + ruff format --check concurrently and merge into one report
+    LintAll,
+
+    /// Run lint, typecheck, and test commands concurrently and merge into one report
+    Ci {
+        /// Shell command for linting, e.g. "eslint ."
+        #[arg(long)]
+        lint: Option<String>,
+        /// Shell command for type checking, e.g. "tsc --noEmit"
+        #[arg(long)]
+        typecheck: Option<String>,
+        /// Shell command for running tests, e.g. "npm test"
+        #[arg(long)]
+        test: Option<String>,
+    },
+
     /// Prettier format checker with compact output
     Prettier {
         /// Prettier arguments (e.g., --check, --write)
@@ -448,6 +766,27 @@ enum Commands {
         /// Output format: text, json
         #[arg(short, long, default_value = "text")]
         format: String,
+        /// Pricing profile for dollar-savings estimates (e.g.
+        /// claude-sonnet-4, claude-opus-4, claude-haiku-4)
+        #[arg(long, value_name = "MODEL")]
+        model: Option<String>,
+        /// Override the input-token price ($/million tokens) instead of
+        /// using --model's built-in rate
+        #[arg(long, value_name = "USD_PER_MTOK")]
+        input_price: Option<f64>,
+        /// Override the output-token price ($/million tokens) instead of
+        /// using --model's built-in rate
+        #[arg(long, value_name = "USD_PER_MTOK")]
+        output_price: Option<f64>,
+        /// Color the text report: auto, always, never
+        #[arg(long, default_value = "auto")]
+        color: String,
+        /// Only count commands matching this glob/prefix pattern (repeatable, e.g. 'git *')
+        #[arg(long)]
+        include: Vec<String>,
+        /// Drop commands matching this glob/prefix pattern (repeatable, e.g. 'rm *')
+        #[arg(long)]
+        exclude: Vec<String>,
     },
 
     /// Learn CLI corrections from Claude Code error history
@@ -473,10 +812,17 @@ enum Commands {
         /// Minimum occurrences to include in report
         #[arg(long, default_value = "1")]
         min_occurrences: usize,
+        /// Partition rules into categories: "error_type" or "base_command"
+        #[arg(long)]
+        group_by: Option<String>,
     },
 
     /// Execute command without filtering but track usage
     Proxy {
+        /// Skip auto-routing into a specialized filter and always run the
+        /// command verbatim, the way `proxy` behaved before auto-routing
+        #[arg(long)]
+        raw: bool,
         /// Command and arguments to execute
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<OsString>,
@@ -484,7 +830,12 @@ enum Commands {
 
     /// Ruff linter/formatter with compact output
     Ruff {
-        /// Ruff arguments (e.g., check, format --check)
+        /// Ruff arguments (e.g., check, format --check). `check` accepts
+        /// --changed-only (filter to lines touched by `git diff`) or --diff
+        /// (same, but read a unified diff from stdin) to suppress
+        /// pre-existing lint noise when reviewing a PR, and repeatable
+        /// --glob PATTERN flags (leading `!` negates) to scope diagnostics
+        /// by path the same way `rtk grep` does.
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
@@ -516,6 +867,15 @@ enum Commands {
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
+
+    /// Environment doctor: detect package manager, Python, node/npm
+    Info,
+
+    /// Passthrough: dispatches to a `[proxies.<name>]` entry from the
+    /// config file if the first token matches one, else errors like an
+    /// unknown command would.
+    #[command(external_subcommand)]
+    Other(Vec<OsString>),
 }
 
 #[derive(Subcommand)]
@@ -594,6 +954,17 @@ enum GitCommands {
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
+    /// Route staged hunks into `--fixup=<sha>` commits by blaming the
+    /// lines they touch, so a later `rebase -i --autosquash` folds them in
+    #[command(name = "smash")]
+    Smash {
+        /// Rev range to restrict blamed commits to (default: @{upstream}..HEAD)
+        #[arg(long)]
+        range: Option<String>,
+        /// Consider the whole history instead of just @{upstream}..HEAD
+        #[arg(long)]
+        all: bool,
+    },
     /// Passthrough: runs any unsupported git subcommand directly
     #[command(external_subcommand)]
     Other(Vec<OsString>),
@@ -606,12 +977,34 @@ enum PnpmCommands {
         /// Depth level (default: 0)
         #[arg(short, long, default_value = "0")]
         depth: usize,
+        /// Only show dependencies whose name matches this glob/substring
+        #[arg(long)]
+        filter: Option<String>,
+        /// Only show "dev" or "prod" dependencies
+        #[arg(long)]
+        only: Option<String>,
+        /// Only show outdated dependencies
+        #[arg(long = "outdated-only")]
+        outdated_only: bool,
+        /// Read `pnpm-lock.yaml` directly instead of running `pnpm list --json`
+        #[arg(long)]
+        offline: bool,
         /// Additional pnpm arguments
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
     /// Show outdated packages (condensed: "pkg: old → new")
     Outdated {
+        /// Only show dependencies whose name matches this glob/substring
+        #[arg(long)]
+        filter: Option<String>,
+        /// Only show "dev" or "prod" dependencies
+        #[arg(long)]
+        only: Option<String>,
+        /// Only show outdated dependencies (redundant here, but kept for
+        /// symmetry with `pnpm list --outdated-only`)
+        #[arg(long = "outdated-only")]
+        outdated_only: bool,
         /// Additional pnpm arguments
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
@@ -624,6 +1017,22 @@ enum PnpmCommands {
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
+    /// Apply only semver-compatible updates from `pnpm outdated`
+    Upgrade {
+        /// Bump to `latest` even across majors (flagged as breaking)
+        /// instead of the declared-range-compatible `wanted` version
+        #[arg(long = "to-latest")]
+        to_latest: bool,
+        /// Print the planned changes without running `pnpm add`
+        #[arg(long = "dry-run")]
+        dry_run: bool,
+        /// Additional pnpm arguments (forwarded to `pnpm outdated`)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Show project/toolchain snapshot: declared deps, pnpm/node versions,
+    /// detected framework, and how many deps are resolved in the lockfile
+    Info,
     /// Build (delegates to next build filter)
     Build {
         /// Additional build arguments
@@ -643,12 +1052,15 @@ enum PnpmCommands {
 
 #[derive(Subcommand)]
 enum DockerCommands {
-    /// List running containers
-    Ps,
+    /// List running containers (across all configured endpoints; pass a
+    /// name or id to find which endpoint it's running on instead)
+    Ps { container: Option<String> },
     /// List images
     Images,
     /// Show container logs (deduplicated)
     Logs { container: String },
+    /// Compact live CPU/memory summary (top consumers, totals, outliers)
+    Stats,
     /// Passthrough: runs any unsupported docker subcommand directly
     #[command(external_subcommand)]
     Other(Vec<OsString>),
@@ -663,6 +1075,11 @@ enum KubectlCommands {
         /// All namespaces
         #[arg(short = 'A', long)]
         all: bool,
+        /// Stream pod events live and re-render as pods change state
+        /// (requires the `kube-client` feature; falls back to a one-shot
+        /// snapshot otherwise)
+        #[arg(short, long)]
+        watch: bool,
     },
     /// List services
     Services {
@@ -687,7 +1104,18 @@ enum KubectlCommands {
 enum VitestCommands {
     /// Run tests with filtered output (90% token reduction)
     Run {
-        /// Additional vitest arguments
+        /// Additional vitest arguments. Pass --format junit for a
+        /// `<testsuites>` JUnit XML report instead of the condensed text
+        /// summary (default text), or --shuffle[=seed] to randomize test
+        /// order with a seed echoed in the output for replay.
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+
+    /// Re-run tests on file changes, printing one condensed result per batch
+    /// instead of vitest's own noisy watch output
+    Watch {
+        /// Additional vitest arguments, same as `rtk vitest run`
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
@@ -712,6 +1140,18 @@ enum PrismaCommands {
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
+    /// Format the Prisma schema file
+    Format {
+        /// Additional prisma arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Validate the Prisma schema file
+    Validate {
+        /// Additional prisma arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -771,6 +1211,18 @@ enum CargoCommands {
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
+    /// Fix with compact output (per-file applied-fix counts, remaining warnings)
+    Fix {
+        /// Additional cargo fix arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Fmt with compact output (collapses per-file diffs to a one-line summary)
+    Fmt {
+        /// Additional cargo fmt arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
     /// Passthrough: runs any unsupported cargo subcommand directly
     #[command(external_subcommand)]
     Other(Vec<OsString>),
@@ -780,7 +1232,14 @@ enum CargoCommands {
 enum GoCommands {
     /// Run tests with compact output (90% token reduction via JSON streaming)
     Test {
-        /// Additional go test arguments
+        /// Additional go test arguments. Pass --timings to append a
+        /// slowest-tests/slowest-packages section (tune with
+        /// --timings-threshold <ms> and --timings-top <n>), --watch to
+        /// re-run on .go file changes and print only the pass/fail delta,
+        /// go's own -cover/-coverprofile=FILE to append a coverage
+        /// section (flag packages below --coverage-threshold <pct>), or
+        /// --retry-failed=N to re-run failed tests up to N times and
+        /// separate flaky tests from consistently failing ones
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
@@ -796,15 +1255,179 @@ enum GoCommands {
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
+    /// Run vet, build, and test in one pass with a single merged report
+    Check {
+        /// Additional arguments, passed to each of vet/build/test
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
     /// Passthrough: runs any unsupported go subcommand directly
     #[command(external_subcommand)]
     Other(Vec<OsString>),
 }
 
+/// Pull a `--flag <value>` pair out of a trailing-var-arg command vector,
+/// returning the value and the remaining args with both tokens removed.
+/// Used by subcommands (`rtk err`, `rtk test`) whose own options live
+/// alongside the wrapped shell command rather than as separate clap fields.
+fn extract_flag_value(args: &[String], flag: &str) -> (Option<PathBuf>, Vec<String>) {
+    let mut value = None;
+    let mut rest = Vec::with_capacity(args.len());
+    let mut iter = args.iter().cloned();
+    while let Some(arg) = iter.next() {
+        if arg == flag {
+            value = iter.next().map(PathBuf::from);
+        } else {
+            rest.push(arg);
+        }
+    }
+    (value, rest)
+}
+
+/// Like [`extract_flag_value`], but collects every occurrence of a repeated
+/// `--flag <value>` pair (e.g. `--filter-out`) instead of just the last one.
+fn extract_all_flag_values(args: &[String], flag: &str) -> (Vec<String>, Vec<String>) {
+    let mut values = Vec::new();
+    let mut rest = Vec::with_capacity(args.len());
+    let mut iter = args.iter().cloned();
+    while let Some(arg) = iter.next() {
+        if arg == flag {
+            if let Some(v) = iter.next() {
+                values.push(v);
+            }
+        } else {
+            rest.push(arg);
+        }
+    }
+    (values, rest)
+}
+
+/// Pre-parse `argv` before handing it to clap, so tokens meant for a
+/// forwarded child tool (`--help`, `--version`, or anything else clap
+/// would otherwise intercept) reach subcommands like `cargo build` or
+/// `lint` instead of being reinterpreted as rtk's own flags. Inspired by
+/// cargo-llvm-cov's move to a positional lexer for the same problem: once
+/// the subcommand boundary is found, a literal `--` is inserted so clap's
+/// `trailing_var_arg` captures every remaining token verbatim, no matter
+/// what it looks like. Known-subcommand names come straight from `Cli`'s
+/// own clap metadata rather than a hand-maintained list, so this never
+/// drifts out of sync with the `Commands`/nested-command enums.
+///
+/// Tokens before the boundary (global flags like `-v`, `-u`, `--skip-env`)
+/// are left untouched for clap to parse normally, as is everything when no
+/// known subcommand appears at all (clap then reports its usual "unknown
+/// command" / `--help` / `--version` behavior).
+fn preprocess_argv(raw: Vec<OsString>) -> Vec<OsString> {
+    if raw.len() < 2 {
+        return raw;
+    }
+
+    let top = Cli::command();
+    let top_names: std::collections::HashSet<String> =
+        top.get_subcommands().map(|s| s.get_name().to_string()).collect();
+
+    let mut i = 1;
+    while i < raw.len() {
+        if top_names.contains(&raw[i].to_string_lossy().to_string()) {
+            break;
+        }
+        i += 1;
+    }
+
+    // No known subcommand token found at all -- let clap handle this
+    // argv exactly as before (covers bare `rtk`, `rtk --help`, typos).
+    if i >= raw.len() {
+        return raw;
+    }
+
+    let subcommand_name = raw[i].to_string_lossy().to_string();
+    i += 1;
+
+    // Some subcommands (cargo, go, kubectl, ...) nest one more named level
+    // before the forwarded args start; consume that selector token too so
+    // it's still routed by clap instead of landing in the verbatim tail.
+    if let Some(sub) = top.find_subcommand(&subcommand_name) {
+        if sub.has_subcommands() && i < raw.len() {
+            let nested_names: std::collections::HashSet<String> =
+                sub.get_subcommands().map(|s| s.get_name().to_string()).collect();
+            if nested_names.contains(&raw[i].to_string_lossy().to_string()) {
+                i += 1;
+            }
+        }
+    }
+
+    if i >= raw.len() {
+        return raw;
+    }
+
+    let mut result = raw[..i].to_vec();
+    result.push(OsString::from("--"));
+    result.extend(raw[i..].iter().cloned());
+    result
+}
+
+/// Expand `[alias]` config entries in raw argv before clap parses it, e.g.
+/// `rtk co -m fix` -> `rtk git commit -m fix` when config has
+/// `[alias] co = "git commit"`. Only the first non-flag token is ever
+/// considered the alias name, re-checked after each expansion so a
+/// tokenized value that itself starts with another alias keeps unrolling
+/// (`tc = "rtk-fast typecheck"` where `rtk-fast` is also aliased). A name
+/// that collides with a real subcommand is never expanded -- built-ins
+/// always win -- and a name that reappears during one expansion chain is
+/// left alone rather than looped on forever.
+fn expand_aliases(raw: Vec<OsString>, config: &config::Config) -> Vec<OsString> {
+    if raw.len() < 2 || config.alias.is_empty() {
+        return raw;
+    }
+
+    let top_names: std::collections::HashSet<String> =
+        Cli::command().get_subcommands().map(|s| s.get_name().to_string()).collect();
+
+    let mut rest: Vec<OsString> = raw[1..].to_vec();
+    let mut seen = std::collections::HashSet::new();
+
+    loop {
+        let Some(idx) = rest.iter().position(|t| !t.to_string_lossy().starts_with('-')) else {
+            break;
+        };
+        let token = rest[idx].to_string_lossy().to_string();
+
+        if top_names.contains(&token) {
+            break;
+        }
+        let Some(expansion) = config.alias.get(&token) else {
+            break;
+        };
+        if !seen.insert(token) {
+            break;
+        }
+
+        let expanded: Vec<OsString> = expansion.split_whitespace().map(OsString::from).collect();
+        let mut next = rest[..idx].to_vec();
+        next.extend(expanded);
+        next.extend(rest[idx + 1..].iter().cloned());
+        rest = next;
+    }
+
+    let mut result = vec![raw[0].clone()];
+    result.extend(rest);
+    result
+}
+
 fn main() -> Result<()> {
-    let cli = Cli::parse();
+    let argv: Vec<OsString> = std::env::args_os().collect();
+    let argv = match config::Config::load() {
+        Ok(config) => expand_aliases(argv, &config),
+        Err(_) => argv,
+    };
+    let cli = Cli::parse_from(preprocess_argv(argv));
+    logging::init(&cli.log_format, cli.no_color, cli.verbose);
 
     match cli.command {
+        Commands::Version { format } => {
+            version::run(&format)?;
+        }
+
         Commands::Ls { args } => {
             ls::run(&args, cli.verbose)?;
         }
@@ -814,15 +1437,37 @@ fn main() -> Result<()> {
         }
 
         Commands::Read {
-            file,
+            files,
             level,
             max_lines,
             line_numbers,
+            dedup,
+            around,
+            context,
         } => {
-            if file == Path::new("-") {
+            let level = match level {
+                Some(level) => level,
+                None => config::Config::load()?.resolve_profile(cli.profile.as_deref())?.filter_level,
+            };
+
+            if !around.is_empty() {
+                if files.len() != 1 {
+                    anyhow::bail!("--around requires exactly one file");
+                }
+                let targets = read::parse_around_targets(&around, context)?;
+                read::run_around(Path::new(&files[0]), &targets, cli.verbose)?;
+            } else if files.len() == 1 && files[0] == "-" {
                 read::run_stdin(level, max_lines, line_numbers, cli.verbose)?;
+            } else if files.len() == 1 && !files[0].contains(['*', '?', '[', '{']) {
+                read::run(
+                    Path::new(&files[0]),
+                    level,
+                    max_lines,
+                    line_numbers,
+                    cli.verbose,
+                )?;
             } else {
-                read::run(&file, level, max_lines, line_numbers, cli.verbose)?;
+                read::run_many(&files, level, max_lines, line_numbers, dedup, cli.verbose)?;
             }
         }
 
@@ -830,8 +1475,9 @@ fn main() -> Result<()> {
             file,
             model,
             force_download,
+            format,
         } => {
-            local_llm::run(&file, &model, force_download, cli.verbose)?;
+            local_llm::run(&file, &model, force_download, &format, cli.verbose)?;
         }
 
         Commands::Git { command } => match command {
@@ -876,6 +1522,9 @@ fn main() -> Result<()> {
             GitCommands::Worktree { args } => {
                 git::run(git::GitCommand::Worktree, &args, None, cli.verbose)?;
             }
+            GitCommands::Smash { range, all } => {
+                git::run(git::GitCommand::Smash { range, all }, &[], None, cli.verbose)?;
+            }
             GitCommands::Other(args) => {
                 git::run_passthrough(&args, cli.verbose)?;
             }
@@ -886,11 +1535,35 @@ fn main() -> Result<()> {
         }
 
         Commands::Pnpm { command } => match command {
-            PnpmCommands::List { depth, args } => {
-                pnpm_cmd::run(pnpm_cmd::PnpmCommand::List { depth }, &args, cli.verbose)?;
+            PnpmCommands::List {
+                depth,
+                filter,
+                only,
+                outdated_only,
+                offline,
+                args,
+            } => {
+                let filter =
+                    pnpm_cmd::DependencyFilter::new(filter.as_deref(), only.as_deref(), outdated_only)?;
+                pnpm_cmd::run(
+                    pnpm_cmd::PnpmCommand::List {
+                        depth,
+                        filter,
+                        offline,
+                    },
+                    &args,
+                    cli.verbose,
+                )?;
             }
-            PnpmCommands::Outdated { args } => {
-                pnpm_cmd::run(pnpm_cmd::PnpmCommand::Outdated, &args, cli.verbose)?;
+            PnpmCommands::Outdated {
+                filter,
+                only,
+                outdated_only,
+                args,
+            } => {
+                let filter =
+                    pnpm_cmd::DependencyFilter::new(filter.as_deref(), only.as_deref(), outdated_only)?;
+                pnpm_cmd::run(pnpm_cmd::PnpmCommand::Outdated { filter }, &args, cli.verbose)?;
             }
             PnpmCommands::Install { packages, args } => {
                 pnpm_cmd::run(
@@ -899,6 +1572,20 @@ fn main() -> Result<()> {
                     cli.verbose,
                 )?;
             }
+            PnpmCommands::Upgrade {
+                to_latest,
+                dry_run,
+                args,
+            } => {
+                pnpm_cmd::run(
+                    pnpm_cmd::PnpmCommand::Upgrade { to_latest, dry_run },
+                    &args,
+                    cli.verbose,
+                )?;
+            }
+            PnpmCommands::Info => {
+                pnpm_cmd::run(pnpm_cmd::PnpmCommand::Info, &[], cli.verbose)?;
+            }
             PnpmCommands::Build { args } => {
                 next_cmd::run(&args, cli.verbose)?;
             }
@@ -911,25 +1598,86 @@ fn main() -> Result<()> {
         },
 
         Commands::Err { command } => {
-            let cmd = command.join(" ");
-            runner::run_err(&cmd, cli.verbose)?;
+            let (expect, command) = extract_flag_value(&command, "--expect");
+            let (filter_out, command) = extract_all_flag_values(&command, "--filter-out");
+            let watch = command.iter().any(|a| a == "--watch");
+            let fix = command.iter().any(|a| a == "--fix");
+            let dry_run = command.iter().any(|a| a == "--dry-run");
+            let bless = command.iter().any(|a| a == "--bless");
+            let cmd = command
+                .iter()
+                .filter(|a| *a != "--watch" && *a != "--fix" && *a != "--dry-run" && *a != "--bless")
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(" ");
+            let rules = runner::build_normalize_rules(&filter_out)?;
+            if let Some(expect_path) = expect {
+                runner::run_err_expect(&cmd, &expect_path, bless, &rules, cli.verbose)?;
+            } else if fix {
+                runner::run_fix(&cmd, dry_run, cli.verbose)?;
+            } else if watch {
+                runner::run_err_watch_with_filters(&cmd, &rules, cli.verbose)?;
+            } else {
+                runner::run_err_with_filters(&cmd, &rules, cli.verbose)?;
+            }
         }
 
         Commands::Test { command } => {
-            let cmd = command.join(" ");
-            runner::run_test(&cmd, cli.verbose)?;
+            let (expect, command) = extract_flag_value(&command, "--expect");
+            let (shuffle_seed, command) = extract_flag_value(&command, "--shuffle-seed");
+            let (filter_out, command) = extract_all_flag_values(&command, "--filter-out");
+            let watch = command.iter().any(|a| a == "--watch");
+            let json = command.iter().any(|a| a == "--json");
+            let bless = command.iter().any(|a| a == "--bless");
+            let shuffle = command.iter().any(|a| a == "--shuffle");
+            let cmd = command
+                .iter()
+                .filter(|a| {
+                    *a != "--watch" && *a != "--json" && *a != "--bless" && *a != "--shuffle"
+                })
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(" ");
+            let rules = runner::build_normalize_rules(&filter_out)?;
+            let seed = shuffle_seed
+                .map(|s| {
+                    s.to_str()
+                        .and_then(|s| s.parse::<u64>().ok())
+                        .with_context(|| format!("--shuffle-seed must be a number, got: {}", s.display()))
+                })
+                .transpose()?;
+            if let Some(expect_path) = expect {
+                runner::run_test_expect(&cmd, &expect_path, bless, &rules, cli.verbose)?;
+            } else if shuffle {
+                runner::run_test_shuffle(&cmd, seed, cli.verbose)?;
+            } else {
+                match (watch, json) {
+                    (true, true) => runner::run_test_json_watch(&cmd, cli.verbose)?,
+                    (true, false) => runner::run_test_watch_with_filters(&cmd, &rules, cli.verbose)?,
+                    (false, true) => runner::run_test_json(&cmd, cli.verbose)?,
+                    (false, false) => runner::run_test_with_filters(&cmd, &rules, cli.verbose)?,
+                }
+            }
         }
 
-        Commands::Json { file, depth } => {
+        Commands::Json {
+            file,
+            depth,
+            schema_format,
+        } => {
             if file == Path::new("-") {
-                json_cmd::run_stdin(depth, cli.verbose)?;
+                json_cmd::run_stdin(depth, &schema_format, cli.verbose)?;
             } else {
-                json_cmd::run(&file, depth, cli.verbose)?;
+                json_cmd::run(&file, depth, &schema_format, cli.verbose)?;
             }
         }
 
-        Commands::Deps { path } => {
-            deps::run(&path, cli.verbose)?;
+        Commands::Deps {
+            path,
+            depth,
+            no_recurse,
+        } => {
+            deps::run(&path, depth, no_recurse, cli.verbose)?;
         }
 
         Commands::Env { filter, show_all } => {
@@ -937,33 +1685,85 @@ fn main() -> Result<()> {
         }
 
         Commands::Find {
-            pattern,
+            patterns,
             path,
             max,
             file_type,
+            size,
+            changed_within,
+            changed_before,
+            hidden,
+            no_ignore,
+            no_ignore_parent,
+            max_depth,
+            exec,
+            exec_batch,
         } => {
-            find_cmd::run(&pattern, &path, max, &file_type, cli.verbose)?;
+            find_cmd::run(
+                &patterns,
+                &path,
+                max,
+                &file_type,
+                size.as_deref(),
+                changed_within.as_deref(),
+                changed_before.as_deref(),
+                hidden,
+                no_ignore,
+                no_ignore_parent,
+                max_depth,
+                exec.as_deref(),
+                exec_batch.as_deref(),
+                cli.verbose,
+            )?;
         }
 
-        Commands::Diff { file1, file2 } => {
+        Commands::Diff {
+            file1,
+            file2,
+            patience,
+            context,
+            format,
+        } => {
             if let Some(f2) = file2 {
-                diff_cmd::run(&file1, &f2, cli.verbose)?;
+                diff_cmd::run(&file1, &f2, patience, &format, cli.verbose)?;
             } else {
-                diff_cmd::run_stdin(cli.verbose)?;
+                diff_cmd::run_stdin(context, cli.verbose)?;
             }
         }
 
-        Commands::Log { file } => {
-            if let Some(f) = file {
-                log_cmd::run_file(&f, cli.verbose)?;
+        Commands::Log {
+            file,
+            min_severity,
+            include,
+            exclude,
+            ignore_case,
+            follow,
+            cluster,
+            level_field,
+            msg_field,
+        } => {
+            let min_severity = min_severity
+                .as_deref()
+                .map(log_cmd::Severity::parse)
+                .transpose()?;
+            let filters = log_cmd::LogFilters::new(&include, &exclude, ignore_case)?;
+            let structured = log_cmd::StructuredFields {
+                level_field,
+                msg_field,
+            };
+            if follow {
+                log_cmd::run_follow(file.as_deref(), min_severity, &filters, &structured, cli.verbose)?;
+            } else if let Some(f) = file {
+                log_cmd::run_file(&f, min_severity, &filters, &structured, cluster, cli.verbose)?;
             } else {
-                log_cmd::run_stdin(cli.verbose)?;
+                log_cmd::run_stdin(min_severity, &filters, &structured, cluster, cli.verbose)?;
             }
         }
 
         Commands::Docker { command } => match command {
-            DockerCommands::Ps => {
-                container::run(container::ContainerCmd::DockerPs, &[], cli.verbose)?;
+            DockerCommands::Ps { container: c } => {
+                let args: Vec<String> = c.into_iter().collect();
+                container::run(container::ContainerCmd::DockerPs, &args, cli.verbose)?;
             }
             DockerCommands::Images => {
                 container::run(container::ContainerCmd::DockerImages, &[], cli.verbose)?;
@@ -971,13 +1771,20 @@ fn main() -> Result<()> {
             DockerCommands::Logs { container: c } => {
                 container::run(container::ContainerCmd::DockerLogs, &[c], cli.verbose)?;
             }
+            DockerCommands::Stats => {
+                container::run(container::ContainerCmd::DockerStats, &[], cli.verbose)?;
+            }
             DockerCommands::Other(args) => {
                 container::run_docker_passthrough(&args, cli.verbose)?;
             }
         },
 
         Commands::Kubectl { command } => match command {
-            KubectlCommands::Pods { namespace, all } => {
+            KubectlCommands::Pods {
+                namespace,
+                all,
+                watch,
+            } => {
                 let mut args: Vec<String> = Vec::new();
                 if all {
                     args.push("-A".to_string());
@@ -985,6 +1792,9 @@ fn main() -> Result<()> {
                     args.push("-n".to_string());
                     args.push(n);
                 }
+                if watch {
+                    args.push("--watch".to_string());
+                }
                 container::run(container::ContainerCmd::KubectlPods, &args, cli.verbose)?;
             }
             KubectlCommands::Services { namespace, all } => {
@@ -1039,16 +1849,38 @@ fn main() -> Result<()> {
         Commands::Init {
             global,
             show,
+            doctor,
+            verify,
+            fix,
             claude_md,
             hook_only,
             auto_patch,
             no_patch,
             uninstall,
+            restore,
+            git_hooks,
+            pre_push,
+            dry_run,
         } => {
             if show {
                 init::show_config()?;
+            } else if doctor {
+                init::doctor(cli.verbose)?;
+            } else if verify {
+                let patch_mode = if auto_patch {
+                    init::PatchMode::Auto
+                } else if no_patch {
+                    init::PatchMode::Skip
+                } else {
+                    init::PatchMode::Ask
+                };
+                init::verify(fix, patch_mode, cli.verbose)?;
             } else if uninstall {
                 init::uninstall(global, cli.verbose)?;
+            } else if restore {
+                init::restore(global, cli.verbose)?;
+            } else if git_hooks {
+                init::install_git_hooks(pre_push, cli.verbose)?;
             } else {
                 let patch_mode = if auto_patch {
                     init::PatchMode::Auto
@@ -1057,7 +1889,14 @@ fn main() -> Result<()> {
                 } else {
                     init::PatchMode::Ask
                 };
-                init::run(global, claude_md, hook_only, patch_mode, cli.verbose)?;
+                init::run(
+                    global,
+                    claude_md,
+                    hook_only,
+                    patch_mode,
+                    dry_run,
+                    cli.verbose,
+                )?;
             }
         }
 
@@ -1078,7 +1917,22 @@ fn main() -> Result<()> {
             weekly,
             monthly,
             all,
+            since,
+            range,
             format,
+            serve_metrics,
+            interactive,
+            export,
+            export_limit,
+            list,
+            command,
+            min_savings,
+            sort,
+            limit,
+            model,
+            input_price,
+            output_price,
+            color,
         } => {
             gain::run(
                 graph,
@@ -1089,7 +1943,22 @@ fn main() -> Result<()> {
                 weekly,
                 monthly,
                 all,
+                since.as_deref(),
+                range.as_deref(),
                 &format,
+                serve_metrics.as_deref(),
+                interactive,
+                export.as_deref(),
+                export_limit,
+                list,
+                command.as_deref(),
+                min_savings,
+                &sort,
+                limit,
+                model.as_deref(),
+                input_price,
+                output_price,
+                &color,
                 cli.verbose,
             )?;
         }
@@ -1100,8 +1969,9 @@ fn main() -> Result<()> {
             monthly,
             all,
             format,
+            no_cache,
         } => {
-            cc_economics::run(daily, weekly, monthly, all, &format, cli.verbose)?;
+            cc_economics::run(daily, weekly, monthly, all, &format, cli.verbose, no_cache)?;
         }
 
         Commands::Config { create } => {
@@ -1117,6 +1987,9 @@ fn main() -> Result<()> {
             VitestCommands::Run { args } => {
                 vitest_cmd::run(vitest_cmd::VitestCommand::Run, &args, cli.verbose)?;
             }
+            VitestCommands::Watch { args } => {
+                vitest_cmd::run(vitest_cmd::VitestCommand::Watch, &args, cli.verbose)?;
+            }
         },
 
         Commands::Prisma { command } => match command {
@@ -1155,6 +2028,12 @@ fn main() -> Result<()> {
             PrismaCommands::DbPush { args } => {
                 prisma_cmd::run(prisma_cmd::PrismaCommand::DbPush, &args, cli.verbose)?;
             }
+            PrismaCommands::Format { args } => {
+                prisma_cmd::run(prisma_cmd::PrismaCommand::Format, &args, cli.verbose)?;
+            }
+            PrismaCommands::Validate { args } => {
+                prisma_cmd::run(prisma_cmd::PrismaCommand::Validate, &args, cli.verbose)?;
+            }
         },
 
         Commands::Tsc { args } => {
@@ -1169,6 +2048,18 @@ fn main() -> Result<()> {
             lint_cmd::run(&args, cli.verbose)?;
         }
 
+        Commands::LintAll => {
+            lint_orchestrator::run(cli.verbose)?;
+        }
+
+        Commands::Ci {
+            lint,
+            typecheck,
+            test,
+        } => {
+            ci_cmd::run(lint, typecheck, test, cli.verbose)?;
+        }
+
         Commands::Prettier { args } => {
             prettier_cmd::run(&args, cli.verbose)?;
         }
@@ -1183,19 +2074,25 @@ fn main() -> Result<()> {
 
         Commands::Cargo { command } => match command {
             CargoCommands::Build { args } => {
-                cargo_cmd::run(cargo_cmd::CargoCommand::Build, &args, cli.verbose)?;
+                cargo_cmd::run(cargo_cmd::CargoCommand::Build, &args, cli.verbose, cli.json)?;
             }
             CargoCommands::Test { args } => {
-                cargo_cmd::run(cargo_cmd::CargoCommand::Test, &args, cli.verbose)?;
+                cargo_cmd::run(cargo_cmd::CargoCommand::Test, &args, cli.verbose, cli.json)?;
             }
             CargoCommands::Clippy { args } => {
-                cargo_cmd::run(cargo_cmd::CargoCommand::Clippy, &args, cli.verbose)?;
+                cargo_cmd::run(cargo_cmd::CargoCommand::Clippy, &args, cli.verbose, cli.json)?;
             }
             CargoCommands::Check { args } => {
-                cargo_cmd::run(cargo_cmd::CargoCommand::Check, &args, cli.verbose)?;
+                cargo_cmd::run(cargo_cmd::CargoCommand::Check, &args, cli.verbose, cli.json)?;
             }
             CargoCommands::Install { args } => {
-                cargo_cmd::run(cargo_cmd::CargoCommand::Install, &args, cli.verbose)?;
+                cargo_cmd::run(cargo_cmd::CargoCommand::Install, &args, cli.verbose, cli.json)?;
+            }
+            CargoCommands::Fix { args } => {
+                cargo_cmd::run(cargo_cmd::CargoCommand::Fix, &args, cli.verbose, cli.json)?;
+            }
+            CargoCommands::Fmt { args } => {
+                cargo_cmd::run(cargo_cmd::CargoCommand::Fmt, &args, cli.verbose, cli.json)?;
             }
             CargoCommands::Other(args) => {
                 cargo_cmd::run_passthrough(&args, cli.verbose)?;
@@ -1216,8 +2113,27 @@ fn main() -> Result<()> {
             all,
             since,
             format,
+            model,
+            input_price,
+            output_price,
+            color,
+            include,
+            exclude,
         } => {
-            discover::run(project.as_deref(), all, since, limit, &format, cli.verbose)?;
+            discover::run(
+                project.as_deref(),
+                all,
+                since,
+                limit,
+                &format,
+                model.as_deref(),
+                input_price,
+                output_price,
+                &color,
+                &include,
+                &exclude,
+                cli.verbose,
+            )?;
         }
 
         Commands::Learn {
@@ -1228,6 +2144,7 @@ fn main() -> Result<()> {
             write_rules,
             min_confidence,
             min_occurrences,
+            group_by,
         } => {
             learn::run(
                 project,
@@ -1237,6 +2154,7 @@ fn main() -> Result<()> {
                 write_rules,
                 min_confidence,
                 min_occurrences,
+                group_by,
             )?;
         }
 
@@ -1245,14 +2163,11 @@ fn main() -> Result<()> {
                 anyhow::bail!("npx requires a command argument");
             }
 
-            // Intelligent routing: delegate to specialized filters
+            // Intelligent routing: delegate to specialized filters. Prisma's
+            // subcommand-level routing is npx-specific (it always shells
+            // through `npx prisma ...`), so it stays here; everything else
+            // goes through the `router` table shared with `Commands::Proxy`.
             match args[0].as_str() {
-                "tsc" | "typescript" => {
-                    tsc_cmd::run(&args[1..], cli.verbose)?;
-                }
-                "eslint" => {
-                    lint_cmd::run(&args[1..], cli.verbose)?;
-                }
                 "prisma" => {
                     // Route to prisma_cmd based on subcommand
                     if args.len() > 1 {
@@ -1302,18 +2217,20 @@ fn main() -> Result<()> {
                         }
                     }
                 }
-                "next" => {
-                    next_cmd::run(&args[1..], cli.verbose)?;
-                }
-                "prettier" => {
-                    prettier_cmd::run(&args[1..], cli.verbose)?;
-                }
-                "playwright" => {
-                    playwright_cmd::run(&args[1..], cli.verbose)?;
-                }
-                _ => {
-                    // Generic passthrough with npm boilerplate filter
-                    npm_cmd::run(&args, cli.verbose, cli.skip_env)?;
+                name => {
+                    if !router::route(name, &args[1..], cli.verbose, cli.skip_env)? {
+                        const NPX_FILTERS: &[&str] =
+                            &["tsc", "eslint", "prisma", "next", "prettier", "playwright"];
+                        if let Some(hint) = suggest::closest(&args[0], NPX_FILTERS.iter().copied())
+                        {
+                            eprintln!(
+                                "note: `{}` isn't one of rtk's specialized npx filters -- did you mean `{}`? Running the generic passthrough.",
+                                args[0], hint
+                            );
+                        }
+                        // Generic passthrough with npm boilerplate filter
+                        npm_cmd::run(&args, cli.verbose, cli.skip_env)?;
+                    }
                 }
             }
         }
@@ -1327,7 +2244,7 @@ fn main() -> Result<()> {
         }
 
         Commands::Pip { args } => {
-            pip_cmd::run(&args, cli.verbose)?;
+            pip_cmd::run(&args, cli.verbose, cli.json)?;
         }
 
         Commands::Go { command } => match command {
@@ -1340,6 +2257,9 @@ fn main() -> Result<()> {
             GoCommands::Vet { args } => {
                 go_cmd::run_vet(&args, cli.verbose)?;
             }
+            GoCommands::Check { args } => {
+                go_cmd::run_check(&args, cli.verbose)?;
+            }
             GoCommands::Other(args) => {
                 go_cmd::run_other(&args, cli.verbose)?;
             }
@@ -1349,7 +2269,46 @@ fn main() -> Result<()> {
             golangci_cmd::run(&args, cli.verbose)?;
         }
 
-        Commands::Proxy { args } => {
+        Commands::Info => {
+            info_cmd::run(cli.verbose)?;
+        }
+
+        Commands::Doctor { format } => {
+            doctor::run(&format, cli.verbose)?;
+        }
+
+        Commands::Other(tokens) => {
+            let Some((name, rest)) = tokens.split_first() else {
+                anyhow::bail!("no command given");
+            };
+            let name = name.to_string_lossy();
+
+            let config = config::Config::load()?;
+            let Some(proxy) = config.proxies.get(name.as_ref()) else {
+                let candidates = Cli::command()
+                    .get_subcommands()
+                    .map(|s| s.get_name().to_string())
+                    .chain(config.proxies.keys().cloned())
+                    .collect::<Vec<_>>();
+                let hint = suggest::closest(&name, candidates.iter().map(String::as_str))
+                    .map(|c| format!(" (did you mean `{}`?)", c))
+                    .unwrap_or_default();
+                anyhow::bail!(
+                    "unrecognized command '{}' (and no matching [proxies.{}] config entry){}",
+                    name,
+                    name,
+                    hint
+                );
+            };
+
+            let args: Vec<String> = rest
+                .iter()
+                .map(|s| s.to_string_lossy().into_owned())
+                .collect();
+            proxy_cmd::run(&name, proxy, &args, cli.verbose)?;
+        }
+
+        Commands::Proxy { raw, args } => {
             use std::process::Command;
 
             if args.is_empty() {
@@ -1358,14 +2317,18 @@ fn main() -> Result<()> {
                 );
             }
 
-            let timer = tracking::TimedExecution::start();
-
             let cmd_name = args[0].to_string_lossy();
             let cmd_args: Vec<String> = args[1..]
                 .iter()
                 .map(|s| s.to_string_lossy().into_owned())
                 .collect();
 
+            if !raw && router::route(&cmd_name, &cmd_args, cli.verbose, cli.skip_env)? {
+                return Ok(());
+            }
+
+            let timer = tracking::TimedExecution::start();
+
             if cli.verbose > 0 {
                 eprintln!("Proxy mode: {} {}", cmd_name, cmd_args.join(" "));
             }
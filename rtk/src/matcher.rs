@@ -0,0 +1,130 @@
+//! Composable path/command matchers modeled on Mercurial's narrow
+//! matchers (`narrowspec.py`): small, combinable predicates rather than
+//! one monolithic filter. `discover` needs to test both session file
+//! paths and extracted shell commands against the same `--include`/
+//! `--exclude` rules, so the trait is kept deliberately generic over
+//! "what string are we testing" rather than tied to either one.
+
+use crate::find_cmd::glob_match;
+
+/// Something that can decide whether a path or command string is "in".
+pub trait Matcher {
+    fn matches(&self, value: &str) -> bool;
+}
+
+/// Matches everything. The identity element for `--include`: with no
+/// include patterns, nothing should be filtered out.
+pub struct AlwaysMatcher;
+
+impl Matcher for AlwaysMatcher {
+    fn matches(&self, _value: &str) -> bool {
+        true
+    }
+}
+
+/// Matches nothing. The identity element for `--exclude`: with no
+/// exclude patterns, nothing should be removed.
+pub struct NeverMatcher;
+
+impl Matcher for NeverMatcher {
+    fn matches(&self, _value: &str) -> bool {
+        false
+    }
+}
+
+/// Matches values against a set of glob patterns (`*`/`?` wildcards via
+/// [`glob_match`]) or plain prefixes. A value is kept if it matches any
+/// one pattern.
+pub struct IncludeMatcher {
+    patterns: Vec<String>,
+}
+
+impl IncludeMatcher {
+    pub fn new(patterns: Vec<String>) -> Self {
+        IncludeMatcher { patterns }
+    }
+}
+
+impl Matcher for IncludeMatcher {
+    fn matches(&self, value: &str) -> bool {
+        self.patterns
+            .iter()
+            .any(|pattern| glob_match(pattern, value) || value.starts_with(pattern.as_str()))
+    }
+}
+
+/// `include` minus `exclude`: a value must satisfy `include` and must
+/// not satisfy `exclude`.
+pub struct DifferenceMatcher {
+    include: Box<dyn Matcher>,
+    exclude: Box<dyn Matcher>,
+}
+
+impl DifferenceMatcher {
+    pub fn new(include: Box<dyn Matcher>, exclude: Box<dyn Matcher>) -> Self {
+        DifferenceMatcher { include, exclude }
+    }
+}
+
+impl Matcher for DifferenceMatcher {
+    fn matches(&self, value: &str) -> bool {
+        self.include.matches(value) && !self.exclude.matches(value)
+    }
+}
+
+/// Build a matcher from `--include`/`--exclude` glob patterns: no
+/// include patterns means "include everything", no exclude patterns
+/// means "exclude nothing".
+pub fn build_matcher(include: &[String], exclude: &[String]) -> Box<dyn Matcher> {
+    let include_matcher: Box<dyn Matcher> = if include.is_empty() {
+        Box::new(AlwaysMatcher)
+    } else {
+        Box::new(IncludeMatcher::new(include.to_vec()))
+    };
+    let exclude_matcher: Box<dyn Matcher> = if exclude.is_empty() {
+        Box::new(NeverMatcher)
+    } else {
+        Box::new(IncludeMatcher::new(exclude.to_vec()))
+    };
+    Box::new(DifferenceMatcher::new(include_matcher, exclude_matcher))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_always_and_never() {
+        assert!(AlwaysMatcher.matches("anything"));
+        assert!(!NeverMatcher.matches("anything"));
+    }
+
+    #[test]
+    fn test_include_matcher_glob_and_prefix() {
+        let matcher = IncludeMatcher::new(vec!["git *".to_string(), "cargo".to_string()]);
+        assert!(matcher.matches("git log --oneline"));
+        assert!(matcher.matches("cargo build"));
+        assert!(!matcher.matches("rm -rf /tmp/foo"));
+    }
+
+    #[test]
+    fn test_difference_matcher_include_minus_exclude() {
+        let matcher = build_matcher(&["git *".to_string()], &["git push*".to_string()]);
+        assert!(matcher.matches("git log --oneline"));
+        assert!(!matcher.matches("git push origin main"));
+        assert!(!matcher.matches("rm -rf /tmp/foo"));
+    }
+
+    #[test]
+    fn test_no_patterns_matches_everything() {
+        let matcher = build_matcher(&[], &[]);
+        assert!(matcher.matches("anything at all"));
+    }
+
+    #[test]
+    fn test_exclude_only_keeps_everything_but_excluded() {
+        let matcher = build_matcher(&[], &["rm *".to_string()]);
+        assert!(matcher.matches("git log"));
+        assert!(!matcher.matches("rm -rf /tmp/foo"));
+    }
+}
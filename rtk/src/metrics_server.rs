@@ -0,0 +1,61 @@
+//! Tiny built-in HTTP server exposing [`crate::tracking::Tracker::export_prometheus`]
+//! at `/metrics`, for users who'd rather point Prometheus at a long-running
+//! endpoint than scrape `rtk gain --format prometheus` output from a cron
+//! job. Built only with the `metrics-server` feature; uses nothing beyond
+//! `std::net`, since it only ever has to answer one static route.
+#![cfg(feature = "metrics-server")]
+
+use crate::tracking::Tracker;
+use anyhow::{Context, Result};
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+
+/// Serve `/metrics` on `addr` (e.g. `"127.0.0.1:9898"`) until the process
+/// is killed. Blocking and single-threaded: handles one request at a time,
+/// which is plenty for a Prometheus scrape every 15-60s.
+pub fn serve(addr: &str) -> Result<()> {
+    let listener =
+        TcpListener::bind(addr).with_context(|| format!("failed to bind {addr}"))?;
+    println!("📈 Serving Prometheus metrics on http://{addr}/metrics");
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                if let Err(err) = handle_connection(stream) {
+                    eprintln!("rtk metrics-server: {err:#}");
+                }
+            }
+            Err(err) => eprintln!("rtk metrics-server: accept failed: {err}"),
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_connection(mut stream: TcpStream) -> Result<()> {
+    let mut buf = [0u8; 1024];
+    let n = stream.read(&mut buf).context("failed to read request")?;
+    let request = String::from_utf8_lossy(&buf[..n]);
+    let path = request
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .unwrap_or("/");
+
+    let response = if path == "/metrics" {
+        let tracker = Tracker::new().context("failed to open tracking database")?;
+        let body = tracker.export_prometheus()?;
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        )
+    } else {
+        "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n".to_string()
+    };
+
+    stream
+        .write_all(response.as_bytes())
+        .context("failed to write response")?;
+    Ok(())
+}
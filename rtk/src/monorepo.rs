@@ -0,0 +1,254 @@
+//! Monorepo-aware grouping for `git status`'s compact output: bucket
+//! changed files by the project/package that owns them instead of listing
+//! them flat, so an agent working across many `Cargo.toml`/`package.json`/
+//! `go.mod` roots sees "3 projects touched" rather than a wall of
+//! filenames. Enabled with `rtk git status --by-project`; see
+//! [`crate::git`].
+
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Directories skipped while auto-discovering project roots, so a scan
+/// doesn't wander into build output or dependency caches.
+const NOISE_DIRS: &[&str] = &[
+    "node_modules",
+    ".git",
+    "target",
+    "dist",
+    "build",
+    ".cache",
+    "vendor",
+];
+
+/// Manifest filenames that mark a directory as a project root.
+const PROJECT_MARKERS: &[&str] = &["Cargo.toml", "package.json", "go.mod"];
+
+/// How many directory levels deep auto-discovery descends looking for
+/// project markers -- deep enough for a typical `packages/*/service/`
+/// layout without wandering the whole tree on a huge monorepo.
+const MAX_DISCOVERY_DEPTH: usize = 4;
+
+/// Changed files grouped under the project that owns them.
+pub struct ProjectGroup {
+    pub name: String,
+    pub files: Vec<String>,
+}
+
+/// One node of the project-root trie, keyed on path components.
+#[derive(Default)]
+struct TrieNode {
+    children: HashMap<String, TrieNode>,
+    is_root: bool,
+}
+
+/// Prefix-matches changed file paths against a set of declared/discovered
+/// project roots, keyed on path components so the longest matching root
+/// wins regardless of how deeply it's nested.
+struct ProjectTrie {
+    root: TrieNode,
+}
+
+impl ProjectTrie {
+    fn new(roots: &[String]) -> Self {
+        let mut trie = TrieNode::default();
+        for root in roots {
+            let mut node = &mut trie;
+            for component in root.split('/').filter(|c| !c.is_empty()) {
+                node = node.children.entry(component.to_string()).or_default();
+            }
+            node.is_root = true;
+        }
+        Self { root: trie }
+    }
+
+    /// The longest declared project root that `path` falls under, or
+    /// `None` if it matches no root at all.
+    fn find(&self, path: &str) -> Option<String> {
+        let mut node = &self.root;
+        let mut matched = Vec::new();
+        let mut best: Option<usize> = None;
+        for component in path.split('/').filter(|c| !c.is_empty()) {
+            let Some(child) = node.children.get(component) else {
+                break;
+            };
+            matched.push(component);
+            node = child;
+            if node.is_root {
+                best = Some(matched.len());
+            }
+        }
+        best.map(|len| matched[..len].join("/"))
+    }
+}
+
+/// Auto-discover project roots under `dir` by locating `Cargo.toml`,
+/// `package.json`, or `go.mod`, returning each root's path relative to
+/// `dir` with `/` separators regardless of platform. The repo root itself
+/// is never returned as a project -- only nested directories -- since
+/// treating the whole repo as "one project" defeats the point of grouping.
+fn discover_project_roots(dir: &Path, max_depth: usize) -> Vec<String> {
+    let mut roots = Vec::new();
+    discover_project_roots_inner(dir, dir, max_depth, &mut roots);
+    roots.sort();
+    roots
+}
+
+fn discover_project_roots_inner(base: &Path, dir: &Path, depth: usize, out: &mut Vec<String>) {
+    if depth == 0 {
+        return;
+    }
+    let Ok(read_dir) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in read_dir.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if NOISE_DIRS.contains(&name.as_str()) {
+            continue;
+        }
+        if PROJECT_MARKERS.iter().any(|marker| path.join(marker).is_file()) {
+            if let Ok(rel) = path.strip_prefix(base) {
+                out.push(rel.to_string_lossy().replace('\\', "/"));
+            }
+            // Don't descend into a matched project looking for nested
+            // sub-projects -- the first marker found wins.
+            continue;
+        }
+        discover_project_roots_inner(base, &path, depth - 1, out);
+    }
+}
+
+/// Resolve the project roots to group by: explicit `[monorepo] roots`
+/// config entries if any are declared, otherwise auto-discovery under the
+/// current directory.
+pub fn resolve_project_roots(config_roots: &[String]) -> Vec<String> {
+    if !config_roots.is_empty() {
+        return config_roots.to_vec();
+    }
+    let dir = std::env::current_dir().unwrap_or_else(|_| Path::new(".").to_path_buf());
+    discover_project_roots(&dir, MAX_DISCOVERY_DEPTH)
+}
+
+/// Group `files` (repo-relative paths) by the project root they fall
+/// under, preserving the order each project was first seen in. Files
+/// matching no declared/discovered root land in a synthetic `(root)`
+/// bucket.
+pub fn group_by_project(files: &[String], roots: &[String]) -> Vec<ProjectGroup> {
+    let trie = ProjectTrie::new(roots);
+    let mut order = Vec::new();
+    let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+
+    for file in files {
+        let project = trie.find(file).unwrap_or_else(|| "(root)".to_string());
+        if !groups.contains_key(&project) {
+            order.push(project.clone());
+        }
+        groups.entry(project).or_default().push(file.clone());
+    }
+
+    order
+        .into_iter()
+        .map(|name| ProjectGroup {
+            files: groups.remove(&name).unwrap_or_default(),
+            name,
+        })
+        .collect()
+}
+
+/// Render grouped changed files the same way the rest of the compact git
+/// output does: a count-of-projects header, then each project's changed
+/// files with a capped sample and the existing `... +N more` truncation.
+pub fn format_project_groups(groups: &[ProjectGroup], sample: usize) -> String {
+    let mut output = format!(
+        "{} project{} touched\n",
+        groups.len(),
+        if groups.len() == 1 { "" } else { "s" }
+    );
+    for group in groups {
+        output.push_str(&format!("\n{} ({} files)\n", group.name, group.files.len()));
+        for file in group.files.iter().take(sample) {
+            output.push_str(&format!("   {}\n", file));
+        }
+        if group.files.len() > sample {
+            output.push_str(&format!("   ... +{} more\n", group.files.len() - sample));
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_by_project_longest_match_wins() {
+        let roots = vec!["services".to_string(), "services/api".to_string()];
+        let files = vec![
+            "services/api/src/main.rs".to_string(),
+            "services/web/index.ts".to_string(),
+        ];
+        let groups = group_by_project(&files, &roots);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].name, "services/api");
+        assert_eq!(groups[1].name, "services");
+    }
+
+    #[test]
+    fn test_group_by_project_unmatched_falls_back_to_root() {
+        let roots = vec!["services/api".to_string()];
+        let files = vec!["README.md".to_string()];
+        let groups = group_by_project(&files, &roots);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].name, "(root)");
+    }
+
+    #[test]
+    fn test_group_by_project_preserves_first_seen_order() {
+        let roots = vec!["b".to_string(), "a".to_string()];
+        let files = vec!["b/file.rs".to_string(), "a/file.rs".to_string()];
+        let groups = group_by_project(&files, &roots);
+        assert_eq!(groups[0].name, "b");
+        assert_eq!(groups[1].name, "a");
+    }
+
+    #[test]
+    fn test_resolve_project_roots_prefers_explicit_config() {
+        let roots = resolve_project_roots(&["crates/foo".to_string()]);
+        assert_eq!(roots, vec!["crates/foo".to_string()]);
+    }
+
+    #[test]
+    fn test_format_project_groups_truncates_sample() {
+        let groups = vec![ProjectGroup {
+            name: "services/api".to_string(),
+            files: vec!["a.rs".to_string(), "b.rs".to_string(), "c.rs".to_string()],
+        }];
+        let formatted = format_project_groups(&groups, 2);
+        assert!(formatted.contains("1 project touched"));
+        assert!(formatted.contains("services/api (3 files)"));
+        assert!(formatted.contains("... +1 more"));
+    }
+
+    #[test]
+    fn test_discover_project_roots_finds_nested_markers() {
+        let dir = std::env::temp_dir().join(format!(
+            "rtk-monorepo-test-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("services/api")).unwrap();
+        std::fs::write(dir.join("services/api/Cargo.toml"), "[package]\n").unwrap();
+        std::fs::create_dir_all(dir.join("services/web")).unwrap();
+        std::fs::write(dir.join("services/web/package.json"), "{}\n").unwrap();
+
+        let roots = discover_project_roots(&dir, MAX_DISCOVERY_DEPTH);
+
+        assert!(roots.contains(&"services/api".to_string()));
+        assert!(roots.contains(&"services/web".to_string()));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
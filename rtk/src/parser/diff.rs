@@ -0,0 +1,209 @@
+//! Diff two snapshots of the same canonical type, surfacing only what
+//! changed between a baseline and a current run - the same token-saving
+//! goal as `TokenFormatter`, applied across two runs instead of one.
+use super::types::{DependencyState, LintIssue, LintResult, TestResult};
+use std::collections::{HashMap, HashSet};
+
+/// Render the delta between `self` (current) and `baseline` (before).
+pub trait DiffFormatter {
+    fn diff(&self, baseline: &Self) -> String;
+}
+
+impl DiffFormatter for TestResult {
+    fn diff(&self, baseline: &Self) -> String {
+        let current: HashSet<&str> = self.failures.iter().map(|f| f.test_name.as_str()).collect();
+        let before: HashSet<&str> = baseline
+            .failures
+            .iter()
+            .map(|f| f.test_name.as_str())
+            .collect();
+
+        let mut newly_failing: Vec<&str> = current.difference(&before).copied().collect();
+        let mut fixed: Vec<&str> = before.difference(&current).copied().collect();
+        let mut still_failing: Vec<&str> = current.intersection(&before).copied().collect();
+        newly_failing.sort_unstable();
+        fixed.sort_unstable();
+        still_failing.sort_unstable();
+
+        if newly_failing.is_empty() && fixed.is_empty() && still_failing.is_empty() {
+            return "No change in test results".to_string();
+        }
+
+        let mut lines = Vec::new();
+        for name in &newly_failing {
+            lines.push(format!("+ {}", name));
+        }
+        for name in &fixed {
+            lines.push(format!("- {}", name));
+        }
+        for name in &still_failing {
+            lines.push(format!("= {}", name));
+        }
+        lines.push(String::new());
+        lines.push(format!(
+            "{} newly failing, {} fixed, {} still failing",
+            newly_failing.len(),
+            fixed.len(),
+            still_failing.len()
+        ));
+        lines.join("\n")
+    }
+}
+
+impl DiffFormatter for LintResult {
+    fn diff(&self, baseline: &Self) -> String {
+        let current = rule_counts(&self.issues);
+        let before = rule_counts(&baseline.issues);
+
+        let mut rules: Vec<&String> = current.keys().chain(before.keys()).collect();
+        rules.sort_unstable();
+        rules.dedup();
+
+        let lines: Vec<String> = rules
+            .into_iter()
+            .filter_map(|rule| {
+                let before_count = *before.get(rule).unwrap_or(&0);
+                let current_count = *current.get(rule).unwrap_or(&0);
+                if before_count == current_count {
+                    return None;
+                }
+                let delta = current_count as i64 - before_count as i64;
+                Some(format!(
+                    "{}: {} → {} ({:+})",
+                    rule, before_count, current_count, delta
+                ))
+            })
+            .collect();
+
+        if lines.is_empty() {
+            "No change in lint issues".to_string()
+        } else {
+            lines.join("\n")
+        }
+    }
+}
+
+fn rule_counts(issues: &[LintIssue]) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for issue in issues {
+        *counts.entry(issue.rule_id.clone()).or_insert(0) += 1;
+    }
+    counts
+}
+
+impl DiffFormatter for DependencyState {
+    fn diff(&self, baseline: &Self) -> String {
+        let current: HashMap<&str, &str> = self
+            .dependencies
+            .iter()
+            .map(|d| (d.name.as_str(), d.current_version.as_str()))
+            .collect();
+        let before: HashMap<&str, &str> = baseline
+            .dependencies
+            .iter()
+            .map(|d| (d.name.as_str(), d.current_version.as_str()))
+            .collect();
+
+        let mut names: Vec<&str> = current.keys().chain(before.keys()).copied().collect();
+        names.sort_unstable();
+        names.dedup();
+
+        let lines: Vec<String> = names
+            .into_iter()
+            .filter_map(|name| match (before.get(name), current.get(name)) {
+                (Some(b), Some(c)) if b != c => Some(format!("{}: {} → {}", name, b, c)),
+                (Some(b), None) => Some(format!("{}: {} → (removed)", name, b)),
+                (None, Some(c)) => Some(format!("{}: (new) → {}", name, c)),
+                _ => None,
+            })
+            .collect();
+
+        if lines.is_empty() {
+            "No dependency changes".to_string()
+        } else {
+            lines.join("\n")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{Dependency, TestFailure, UpdateSeverity};
+
+    fn failure(name: &str) -> TestFailure {
+        TestFailure {
+            test_name: name.to_string(),
+            file_path: "test.rs".to_string(),
+            error_message: "assertion failed".to_string(),
+            stack_trace: None,
+            attempts: None,
+        }
+    }
+
+    #[test]
+    fn test_test_result_diff() {
+        let baseline = TestResult {
+            total: 3,
+            passed: 1,
+            failed: 2,
+            skipped: 0,
+            flaky: 0,
+            duration_ms: None,
+            failures: vec![failure("a"), failure("b")],
+        };
+        let current = TestResult {
+            total: 3,
+            passed: 1,
+            failed: 2,
+            skipped: 0,
+            flaky: 0,
+            duration_ms: None,
+            failures: vec![failure("b"), failure("c")],
+        };
+
+        let diff = current.diff(&baseline);
+        assert!(diff.contains("+ c"));
+        assert!(diff.contains("- a"));
+        assert!(diff.contains("= b"));
+    }
+
+    #[test]
+    fn test_dependency_state_diff() {
+        let baseline = DependencyState {
+            total_packages: 1,
+            outdated_count: 1,
+            major_count: 0,
+            minor_count: 0,
+            patch_count: 1,
+            dependencies: vec![Dependency {
+                name: "serde".to_string(),
+                current_version: "1.0.0".to_string(),
+                latest_version: Some("1.0.1".to_string()),
+                wanted_version: None,
+                dev_dependency: false,
+                update_severity: Some(UpdateSeverity::Patch),
+                wanted_is_latest: true,
+            }],
+        };
+        let current = DependencyState {
+            total_packages: 1,
+            outdated_count: 0,
+            major_count: 0,
+            minor_count: 0,
+            patch_count: 0,
+            dependencies: vec![Dependency {
+                name: "serde".to_string(),
+                current_version: "1.0.1".to_string(),
+                latest_version: Some("1.0.1".to_string()),
+                wanted_version: None,
+                dev_dependency: false,
+                update_severity: None,
+                wanted_is_latest: true,
+            }],
+        };
+
+        let diff = current.diff(&baseline);
+        assert!(diff.contains("serde: 1.0.0 → 1.0.1"));
+    }
+}
@@ -1,5 +1,8 @@
 /// Token-efficient formatting trait for canonical types
+use super::normalize::normalize_path;
+use super::term::{self, ColorChoice};
 use super::types::*;
+use std::path::Path;
 
 /// Output formatting modes
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -22,6 +25,24 @@ impl FormatMode {
     }
 }
 
+/// Estimates how many tokens a rendered string will cost, so
+/// `format_within_budget` can stop filling before it blows a context
+/// window. Swap in a real tokenizer by implementing this trait; the
+/// default [`CharEstimator`] is a cheap chars/4 approximation.
+pub trait TokenEstimator {
+    fn estimate(&self, text: &str) -> usize;
+}
+
+/// Default token estimator: ~4 characters per token, which is close
+/// enough for the English-ish identifiers and messages rtk formats.
+pub struct CharEstimator;
+
+impl TokenEstimator for CharEstimator {
+    fn estimate(&self, text: &str) -> usize {
+        (text.chars().count() + 3) / 4
+    }
+}
+
 /// Trait for formatting canonical types into token-efficient strings
 pub trait TokenFormatter {
     /// Format as compact summary (default)
@@ -41,11 +62,61 @@ pub trait TokenFormatter {
             FormatMode::Ultra => self.format_ultra(),
         }
     }
+
+    /// Rewrite any file paths down to a short, deterministic form relative
+    /// to `root` (see [`super::normalize::normalize_path`]), in place,
+    /// before formatting. No-op for types that carry no paths.
+    fn normalize_paths(&mut self, _root: &Path) {}
+
+    /// Like [`format`](Self::format), but fill at most `max_tokens`
+    /// (estimated via [`CharEstimator`]) instead of the format's fixed
+    /// per-category cutoffs (`take(5)`, `take(10)`, ...). The summary
+    /// header always fits; items are appended in order of importance
+    /// until the next one would exceed the budget, then a `... +N more`
+    /// tail reports how many were dropped.
+    fn format_within_budget(&self, mode: FormatMode, max_tokens: usize) -> String {
+        self.format_within_budget_with(&CharEstimator, mode, max_tokens)
+    }
+
+    /// Like [`format_within_budget`](Self::format_within_budget), but with
+    /// a caller-supplied [`TokenEstimator`] (e.g. a real tokenizer) instead
+    /// of the chars/4 default. Types that don't override this fall back to
+    /// a hard character truncation of the fully-rendered output.
+    fn format_within_budget_with(
+        &self,
+        estimator: &dyn TokenEstimator,
+        mode: FormatMode,
+        max_tokens: usize,
+    ) -> String {
+        let full = self.format(mode);
+        if estimator.estimate(&full) <= max_tokens {
+            return full;
+        }
+
+        let budget_chars = max_tokens.saturating_mul(4);
+        let chars: Vec<char> = full.chars().collect();
+        if chars.len() <= budget_chars {
+            return full;
+        }
+        let truncated: String = chars[..budget_chars].iter().collect();
+        format!("{}\n... (truncated to fit {} token budget)", truncated, max_tokens)
+    }
+
+    /// Like [`format`](Self::format), but wrap severity glyphs (✗ red, ⚠
+    /// yellow, ✓ green, dim for skipped) in ANSI color per `color`. When
+    /// color resolves to disabled, this is byte-identical to `format`.
+    /// Types with no severity glyphs to color fall back to plain `format`.
+    fn format_colored(&self, mode: FormatMode, _color: ColorChoice) -> String {
+        self.format(mode)
+    }
 }
 
 impl TokenFormatter for TestResult {
     fn format_compact(&self) -> String {
         let mut lines = vec![format!("PASS ({}) FAIL ({})", self.passed, self.failed)];
+        if self.flaky > 0 {
+            lines.push(format!("FLAKY ({})", self.flaky));
+        }
 
         if !self.failures.is_empty() {
             lines.push(String::new());
@@ -77,6 +148,9 @@ impl TokenFormatter for TestResult {
             "Tests: {} passed, {} failed, {} skipped (total: {})",
             self.passed, self.failed, self.skipped, self.total
         )];
+        if self.flaky > 0 {
+            lines.push(format!("{} flaky (passed after retry)", self.flaky));
+        }
 
         if !self.failures.is_empty() {
             lines.push("\nFailures:".to_string());
@@ -87,6 +161,9 @@ impl TokenFormatter for TestResult {
                     failure.test_name,
                     failure.file_path
                 ));
+                if let Some(attempts) = failure.attempts {
+                    lines.push(format!("   attempts: {}", attempts));
+                }
                 lines.push(format!("   {}", failure.error_message));
                 if let Some(stack) = &failure.stack_trace {
                     let stack_preview: String =
@@ -104,11 +181,101 @@ impl TokenFormatter for TestResult {
     }
 
     fn format_ultra(&self) -> String {
+        if self.flaky > 0 {
+            format!(
+                "✓{} ✗{} ⊘{} ⟳{} ({}ms)",
+                self.passed,
+                self.failed,
+                self.skipped,
+                self.flaky,
+                self.duration_ms.unwrap_or(0)
+            )
+        } else {
+            format!(
+                "✓{} ✗{} ⊘{} ({}ms)",
+                self.passed,
+                self.failed,
+                self.skipped,
+                self.duration_ms.unwrap_or(0)
+            )
+        }
+    }
+
+    fn normalize_paths(&mut self, root: &Path) {
+        for failure in &mut self.failures {
+            failure.file_path = normalize_path(&failure.file_path, root);
+        }
+    }
+
+    fn format_within_budget_with(
+        &self,
+        estimator: &dyn TokenEstimator,
+        mode: FormatMode,
+        max_tokens: usize,
+    ) -> String {
+        let header = format!("PASS ({}) FAIL ({})", self.passed, self.failed);
+        let mut used = estimator.estimate(&header);
+        let mut lines = vec![header];
+
+        let mut shown = 0;
+        if !self.failures.is_empty() {
+            lines.push(String::new());
+            for (idx, failure) in self.failures.iter().enumerate() {
+                let entry = match mode {
+                    FormatMode::Verbose => format!(
+                        "{}. {} ({})\n   {}",
+                        idx + 1,
+                        failure.test_name,
+                        failure.file_path,
+                        failure.error_message
+                    ),
+                    _ => {
+                        let preview: String = failure
+                            .error_message
+                            .lines()
+                            .take(2)
+                            .collect::<Vec<_>>()
+                            .join(" ");
+                        format!("{}. {}\n   {}", idx + 1, failure.test_name, preview)
+                    }
+                };
+                let cost = estimator.estimate(&entry) + 1;
+                if used + cost > max_tokens {
+                    break;
+                }
+                lines.push(entry);
+                used += cost;
+                shown += 1;
+            }
+            if shown < self.failures.len() {
+                lines.push(format!(
+                    "... +{} more failures",
+                    self.failures.len() - shown
+                ));
+            }
+        }
+
+        if let Some(duration) = self.duration_ms {
+            let line = format!("Time: {}ms", duration);
+            let cost = estimator.estimate(&line);
+            if used + cost <= max_tokens {
+                lines.push(line);
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    fn format_colored(&self, mode: FormatMode, color: ColorChoice) -> String {
+        if !color.enabled() || mode != FormatMode::Ultra {
+            return self.format(mode);
+        }
+
         format!(
-            "✓{} ✗{} ⊘{} ({}ms)",
-            self.passed,
-            self.failed,
-            self.skipped,
+            "{} {} {} ({}ms)",
+            term::green(&format!("✓{}", self.passed)),
+            term::red(&format!("✗{}", self.failed)),
+            term::dim(&format!("⊘{}", self.skipped)),
             self.duration_ms.unwrap_or(0)
         )
     }
@@ -190,6 +357,139 @@ impl TokenFormatter for LintResult {
             self.errors, self.warnings, self.files_with_issues
         )
     }
+
+    fn normalize_paths(&mut self, root: &Path) {
+        for issue in &mut self.issues {
+            issue.file_path = normalize_path(&issue.file_path, root);
+        }
+    }
+
+    fn format_within_budget_with(
+        &self,
+        estimator: &dyn TokenEstimator,
+        mode: FormatMode,
+        max_tokens: usize,
+    ) -> String {
+        let header = format!(
+            "Errors: {} | Warnings: {} | Files: {}",
+            self.errors, self.warnings, self.files_with_issues
+        );
+        let mut used = estimator.estimate(&header);
+        let mut lines = vec![header];
+
+        let mut by_rule: std::collections::HashMap<String, Vec<&LintIssue>> =
+            std::collections::HashMap::new();
+        for issue in &self.issues {
+            by_rule
+                .entry(issue.rule_id.clone())
+                .or_default()
+                .push(issue);
+        }
+
+        // Importance order: rule groups containing an error first, then by
+        // occurrence count within each bucket (most-frequent first).
+        let mut rules: Vec<_> = by_rule.into_iter().collect();
+        rules.sort_by_key(|(_, issues)| {
+            let has_error = issues.iter().any(|i| i.severity == LintSeverity::Error);
+            (std::cmp::Reverse(has_error), std::cmp::Reverse(issues.len()))
+        });
+
+        let total_rules = rules.len();
+        let mut shown_rules = 0;
+        for (rule, issues) in &rules {
+            let mut entry = format!("{}: {} occurrences", rule, issues.len());
+            if mode != FormatMode::Compact {
+                for issue in issues.iter().take(1) {
+                    entry.push_str(&format!("\n  {}:{}", issue.file_path, issue.line));
+                }
+            }
+            let cost = estimator.estimate(&entry) + 1;
+            if used + cost > max_tokens {
+                break;
+            }
+            lines.push(entry);
+            used += cost;
+            shown_rules += 1;
+        }
+
+        if shown_rules < total_rules {
+            lines.push(format!(
+                "... +{} more rule violations",
+                total_rules - shown_rules
+            ));
+        }
+
+        lines.join("\n")
+    }
+
+    fn format_colored(&self, mode: FormatMode, color: ColorChoice) -> String {
+        if !color.enabled() {
+            return self.format(mode);
+        }
+
+        match mode {
+            FormatMode::Verbose => {
+                let mut lines = vec![format!(
+                    "Total issues: {} ({} errors, {} warnings) in {} files",
+                    self.total_issues, self.errors, self.warnings, self.files_with_issues
+                )];
+
+                if !self.issues.is_empty() {
+                    lines.push("\nIssues:".to_string());
+                    for issue in self.issues.iter().take(20) {
+                        let severity_symbol = match issue.severity {
+                            LintSeverity::Error => term::red("✗"),
+                            LintSeverity::Warning => term::yellow("⚠"),
+                            LintSeverity::Info => term::dim("ℹ"),
+                        };
+                        lines.push(format!(
+                            "{} {}:{}:{} [{}] {}",
+                            severity_symbol,
+                            issue.file_path,
+                            issue.line,
+                            issue.column,
+                            issue.rule_id,
+                            issue.message
+                        ));
+                    }
+
+                    if self.issues.len() > 20 {
+                        lines.push(format!("\n... +{} more issues", self.issues.len() - 20));
+                    }
+                }
+
+                lines.join("\n")
+            }
+            FormatMode::Ultra => format!(
+                "{} {} 📁{}",
+                term::red(&format!("✗{}", self.errors)),
+                term::yellow(&format!("⚠{}", self.warnings)),
+                self.files_with_issues
+            ),
+            FormatMode::Compact => self.format(mode),
+        }
+    }
+}
+
+/// Render non-zero per-severity counts as `" (1 major, 2 patch)"`, or an
+/// empty string when no parser has populated them.
+fn severity_breakdown(state: &DependencyState) -> String {
+    let mut parts = Vec::new();
+    if state.major_count > 0 {
+        parts.push(format!("{} major", state.major_count));
+    }
+    if state.minor_count > 0 {
+        parts.push(format!("{} minor", state.minor_count));
+    }
+    if state.patch_count > 0 {
+        parts.push(format!("{} patch", state.patch_count));
+    }
+
+    if parts.is_empty() {
+        String::new()
+    } else {
+        format!(", {}", parts.join(", "))
+    }
 }
 
 impl TokenFormatter for DependencyState {
@@ -199,8 +499,10 @@ impl TokenFormatter for DependencyState {
         }
 
         let mut lines = vec![format!(
-            "{} outdated packages (of {})",
-            self.outdated_count, self.total_packages
+            "{} outdated packages (of {}{})",
+            self.outdated_count,
+            self.total_packages,
+            severity_breakdown(self)
         )];
 
         for dep in self.dependencies.iter().take(10) {
@@ -223,8 +525,10 @@ impl TokenFormatter for DependencyState {
 
     fn format_verbose(&self) -> String {
         let mut lines = vec![format!(
-            "Total packages: {} ({} outdated)",
-            self.total_packages, self.outdated_count
+            "Total packages: {} ({} outdated{})",
+            self.total_packages,
+            self.outdated_count,
+            severity_breakdown(self)
         )];
 
         if self.outdated_count > 0 {
@@ -253,6 +557,114 @@ impl TokenFormatter for DependencyState {
     fn format_ultra(&self) -> String {
         format!("📦{} ⬆️{}", self.total_packages, self.outdated_count)
     }
+
+    fn format_within_budget_with(
+        &self,
+        estimator: &dyn TokenEstimator,
+        _mode: FormatMode,
+        max_tokens: usize,
+    ) -> String {
+        if self.outdated_count == 0 {
+            return "All packages up-to-date ✓".to_string();
+        }
+
+        let header = format!(
+            "{} outdated packages (of {})",
+            self.outdated_count, self.total_packages
+        );
+        let mut used = estimator.estimate(&header);
+        let mut lines = vec![header];
+
+        let outdated: Vec<&Dependency> = self
+            .dependencies
+            .iter()
+            .filter(|d| d.latest_version.as_deref().is_some_and(|l| l != d.current_version))
+            .collect();
+
+        let mut shown = 0;
+        for dep in &outdated {
+            let latest = dep.latest_version.as_deref().unwrap_or("");
+            let entry = format!("{}: {} → {}", dep.name, dep.current_version, latest);
+            let cost = estimator.estimate(&entry) + 1;
+            if used + cost > max_tokens {
+                break;
+            }
+            lines.push(entry);
+            used += cost;
+            shown += 1;
+        }
+
+        if shown < outdated.len() {
+            lines.push(format!("... +{} more", outdated.len() - shown));
+        }
+
+        lines.join("\n")
+    }
+}
+
+impl TokenFormatter for EnvInfo {
+    fn format_compact(&self) -> String {
+        let name = self.project_name.as_deref().unwrap_or("(unnamed)");
+        let mut lines = vec![format!(
+            "{}: {} deps ({} dev), {} resolved",
+            name, self.total_declared, self.dev_declared, self.resolved_count
+        )];
+
+        if !self.frameworks.is_empty() {
+            lines.push(format!("Frameworks: {}", self.frameworks.join(", ")));
+        }
+
+        if !self.unresolved.is_empty() {
+            lines.push(format!("Unresolved: {}", self.unresolved.len()));
+        }
+
+        lines.join("\n")
+    }
+
+    fn format_verbose(&self) -> String {
+        let name = self.project_name.as_deref().unwrap_or("(unnamed)");
+        let version = self.project_version.as_deref().unwrap_or("?");
+        let mut lines = vec![format!("Project: {} v{}", name, version)];
+
+        lines.push(format!(
+            "pnpm: {}",
+            self.pnpm_version.as_deref().unwrap_or("not found")
+        ));
+        lines.push(format!(
+            "node: {}",
+            self.node_version.as_deref().unwrap_or("not found")
+        ));
+
+        if !self.frameworks.is_empty() {
+            lines.push(format!("Frameworks: {}", self.frameworks.join(", ")));
+        }
+
+        lines.push(format!(
+            "Dependencies: {} total ({} dev), {} resolved in lockfile",
+            self.total_declared, self.dev_declared, self.resolved_count
+        ));
+
+        if !self.unresolved.is_empty() {
+            lines.push("\nUnresolved:".to_string());
+            for dep in self.unresolved.iter().take(10) {
+                lines.push(format!("  {}", dep));
+            }
+            if self.unresolved.len() > 10 {
+                lines.push(format!("  ... +{} more", self.unresolved.len() - 10));
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    fn format_ultra(&self) -> String {
+        format!(
+            "{}d/{}r {}",
+            self.total_declared,
+            self.resolved_count,
+            self.frameworks.join(",")
+        )
+    }
 }
 
 impl TokenFormatter for BuildOutput {
@@ -333,4 +745,106 @@ impl TokenFormatter for BuildOutput {
             self.duration_ms.unwrap_or(0)
         )
     }
+
+    fn normalize_paths(&mut self, root: &Path) {
+        for route in &mut self.routes {
+            route.path = normalize_path(&route.path, root);
+        }
+    }
+
+    fn format_colored(&self, mode: FormatMode, color: ColorChoice) -> String {
+        if !color.enabled() {
+            return self.format(mode);
+        }
+
+        match mode {
+            FormatMode::Compact => {
+                let status = if self.success {
+                    term::green("✓")
+                } else {
+                    term::red("✗")
+                };
+                let mut lines = vec![format!(
+                    "{} Build: {} errors, {} warnings",
+                    status, self.errors, self.warnings
+                )];
+
+                if !self.bundles.is_empty() {
+                    let total_size: u64 = self.bundles.iter().map(|b| b.size_bytes).sum();
+                    lines.push(format!(
+                        "Bundles: {} ({:.1} KB)",
+                        self.bundles.len(),
+                        total_size as f64 / 1024.0
+                    ));
+                }
+
+                if !self.routes.is_empty() {
+                    lines.push(format!("Routes: {}", self.routes.len()));
+                }
+
+                if let Some(duration) = self.duration_ms {
+                    lines.push(format!("Time: {}ms", duration));
+                }
+
+                lines.join("\n")
+            }
+            FormatMode::Verbose => {
+                let status = if self.success {
+                    term::green("Success")
+                } else {
+                    term::red("Failed")
+                };
+                let mut lines = vec![format!(
+                    "Build {}: {} errors, {} warnings",
+                    status, self.errors, self.warnings
+                )];
+
+                if !self.bundles.is_empty() {
+                    lines.push("\nBundles:".to_string());
+                    for bundle in &self.bundles {
+                        let gzip_info = bundle
+                            .gzip_size_bytes
+                            .map(|gz| format!(" (gzip: {:.1} KB)", gz as f64 / 1024.0))
+                            .unwrap_or_default();
+                        lines.push(format!(
+                            "  {}: {:.1} KB{}",
+                            bundle.name,
+                            bundle.size_bytes as f64 / 1024.0,
+                            gzip_info
+                        ));
+                    }
+                }
+
+                if !self.routes.is_empty() {
+                    lines.push("\nRoutes:".to_string());
+                    for route in self.routes.iter().take(10) {
+                        lines.push(format!("  {}: {:.1} KB", route.path, route.size_kb));
+                    }
+                    if self.routes.len() > 10 {
+                        lines.push(format!("  ... +{} more routes", self.routes.len() - 10));
+                    }
+                }
+
+                if let Some(duration) = self.duration_ms {
+                    lines.push(format!("\nDuration: {}ms", duration));
+                }
+
+                lines.join("\n")
+            }
+            FormatMode::Ultra => {
+                let status = if self.success {
+                    term::green("✓")
+                } else {
+                    term::red("✗")
+                };
+                format!(
+                    "{} {} {} ({}ms)",
+                    status,
+                    term::red(&format!("✗{}", self.errors)),
+                    term::yellow(&format!("⚠{}", self.warnings)),
+                    self.duration_ms.unwrap_or(0)
+                )
+            }
+        }
+    }
 }
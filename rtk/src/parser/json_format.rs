@@ -0,0 +1,293 @@
+//! Structured JSON/NDJSON output for machine consumers (editor plugins, CI
+//! dashboards) that would rather not re-parse the human-oriented text the
+//! `TokenFormatter` modes produce. Unlike Compact/Ultra, this is always
+//! complete - no `take(N)` truncation - and versioned via `schema_version`
+//! so consumers can evolve independently of rtk releases.
+use super::types::*;
+use serde_json::{json, Value};
+
+/// Current schema version for [`JsonFormatter`] output. Bump this when a
+/// field is removed or its meaning changes; additive fields don't need a
+/// bump since consumers should already tolerate unknown keys.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Whether wrapper commands should emit [`JsonFormatter`] output instead of
+/// their default token-efficient text, selected via `RTK_JSON=1` the same
+/// way `NO_COLOR`/`CLICOLOR` gate [`super::ColorChoice`] - an env var rather
+/// than a CLI flag, since most wrapper subcommands pass their `args` through
+/// verbatim to the underlying tool and don't parse their own flags.
+pub fn json_output_requested() -> bool {
+    std::env::var("RTK_JSON").as_deref() == Ok("1")
+}
+
+/// Emit a canonical type as machine-consumable JSON, complete rather than
+/// the lossy, truncated text `TokenFormatter` produces.
+pub trait JsonFormatter {
+    /// A single, complete JSON object describing the full result.
+    fn format_json(&self) -> Value;
+
+    /// One JSON line per failure/issue/entry, for streaming large result
+    /// sets without holding the whole document in memory at once.
+    fn format_ndjson(&self) -> String;
+}
+
+impl JsonFormatter for TestResult {
+    fn format_json(&self) -> Value {
+        json!({
+            "schema_version": SCHEMA_VERSION,
+            "kind": "test_result",
+            "total": self.total,
+            "passed": self.passed,
+            "failed": self.failed,
+            "skipped": self.skipped,
+            "flaky": self.flaky,
+            "duration_ms": self.duration_ms,
+            "failures": self.failures.iter().map(test_failure_json).collect::<Vec<_>>(),
+        })
+    }
+
+    fn format_ndjson(&self) -> String {
+        self.failures
+            .iter()
+            .map(|f| test_failure_json(f).to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn test_failure_json(failure: &TestFailure) -> Value {
+    json!({
+        "schema_version": SCHEMA_VERSION,
+        "kind": "test_failure",
+        "test_name": failure.test_name,
+        "file_path": failure.file_path,
+        "error_message": failure.error_message,
+        "stack_trace": failure.stack_trace,
+        "attempts": failure.attempts,
+    })
+}
+
+impl JsonFormatter for LintResult {
+    fn format_json(&self) -> Value {
+        json!({
+            "schema_version": SCHEMA_VERSION,
+            "kind": "lint_result",
+            "total_files": self.total_files,
+            "files_with_issues": self.files_with_issues,
+            "total_issues": self.total_issues,
+            "errors": self.errors,
+            "warnings": self.warnings,
+            "issues": self.issues.iter().map(lint_issue_json).collect::<Vec<_>>(),
+        })
+    }
+
+    fn format_ndjson(&self) -> String {
+        self.issues
+            .iter()
+            .map(|issue| lint_issue_json(issue).to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn lint_issue_json(issue: &LintIssue) -> Value {
+    let severity = match issue.severity {
+        LintSeverity::Error => "error",
+        LintSeverity::Warning => "warning",
+        LintSeverity::Info => "info",
+    };
+    json!({
+        "schema_version": SCHEMA_VERSION,
+        "kind": "lint_issue",
+        "file_path": issue.file_path,
+        "line": issue.line,
+        "column": issue.column,
+        "severity": severity,
+        "rule_id": issue.rule_id,
+        "message": issue.message,
+        "linter": issue.linter,
+    })
+}
+
+impl JsonFormatter for DependencyState {
+    fn format_json(&self) -> Value {
+        json!({
+            "schema_version": SCHEMA_VERSION,
+            "kind": "dependency_state",
+            "total_packages": self.total_packages,
+            "outdated_count": self.outdated_count,
+            "major_count": self.major_count,
+            "minor_count": self.minor_count,
+            "patch_count": self.patch_count,
+            "dependencies": self.dependencies.iter().map(dependency_json).collect::<Vec<_>>(),
+        })
+    }
+
+    fn format_ndjson(&self) -> String {
+        self.dependencies
+            .iter()
+            .map(|dep| dependency_json(dep).to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn dependency_json(dep: &Dependency) -> Value {
+    json!({
+        "schema_version": SCHEMA_VERSION,
+        "kind": "dependency",
+        "name": dep.name,
+        "current_version": dep.current_version,
+        "latest_version": dep.latest_version,
+        "wanted_version": dep.wanted_version,
+        "dev_dependency": dep.dev_dependency,
+        "update_severity": dep.update_severity.as_ref().map(UpdateSeverity::as_str),
+        "wanted_is_latest": dep.wanted_is_latest,
+    })
+}
+
+impl JsonFormatter for EnvInfo {
+    fn format_json(&self) -> Value {
+        json!({
+            "schema_version": SCHEMA_VERSION,
+            "kind": "env_info",
+            "project_name": self.project_name,
+            "project_version": self.project_version,
+            "pnpm_version": self.pnpm_version,
+            "node_version": self.node_version,
+            "frameworks": self.frameworks,
+            "total_declared": self.total_declared,
+            "dev_declared": self.dev_declared,
+            "resolved_count": self.resolved_count,
+            "unresolved": self.unresolved,
+        })
+    }
+
+    fn format_ndjson(&self) -> String {
+        self.unresolved
+            .iter()
+            .map(|name| {
+                json!({
+                    "schema_version": SCHEMA_VERSION,
+                    "kind": "unresolved_dependency",
+                    "name": name,
+                })
+                .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl JsonFormatter for BuildOutput {
+    fn format_json(&self) -> Value {
+        json!({
+            "schema_version": SCHEMA_VERSION,
+            "kind": "build_output",
+            "success": self.success,
+            "duration_ms": self.duration_ms,
+            "warnings": self.warnings,
+            "errors": self.errors,
+            "bundles": self.bundles.iter().map(bundle_json).collect::<Vec<_>>(),
+            "routes": self.routes.iter().map(route_json).collect::<Vec<_>>(),
+        })
+    }
+
+    fn format_ndjson(&self) -> String {
+        self.bundles
+            .iter()
+            .map(|b| bundle_json(b).to_string())
+            .chain(self.routes.iter().map(|r| route_json(r).to_string()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn bundle_json(bundle: &BundleInfo) -> Value {
+    json!({
+        "schema_version": SCHEMA_VERSION,
+        "kind": "bundle",
+        "name": bundle.name,
+        "size_bytes": bundle.size_bytes,
+        "gzip_size_bytes": bundle.gzip_size_bytes,
+    })
+}
+
+fn route_json(route: &RouteInfo) -> Value {
+    json!({
+        "schema_version": SCHEMA_VERSION,
+        "kind": "route",
+        "path": route.path,
+        "size_kb": route.size_kb,
+        "first_load_js_kb": route.first_load_js_kb,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_test_result_format_json_is_complete() {
+        let result = TestResult {
+            total: 2,
+            passed: 1,
+            failed: 1,
+            skipped: 0,
+            flaky: 1,
+            duration_ms: Some(42),
+            failures: vec![TestFailure {
+                test_name: "a".to_string(),
+                file_path: "a.rs".to_string(),
+                error_message: "boom".to_string(),
+                stack_trace: None,
+                attempts: Some(2),
+            }],
+        };
+
+        let value = result.format_json();
+        assert_eq!(value["schema_version"], SCHEMA_VERSION);
+        assert_eq!(value["failures"].as_array().unwrap().len(), 1);
+        assert_eq!(value["flaky"], 1);
+        assert_eq!(value["failures"][0]["attempts"], 2);
+    }
+
+    #[test]
+    fn test_lint_result_ndjson_one_line_per_issue() {
+        let result = LintResult {
+            total_files: 1,
+            files_with_issues: 1,
+            total_issues: 2,
+            errors: 1,
+            warnings: 1,
+            issues: vec![
+                LintIssue {
+                    file_path: "a.ts".to_string(),
+                    line: 1,
+                    column: 1,
+                    severity: LintSeverity::Error,
+                    rule_id: "no-foo".to_string(),
+                    message: "bad".to_string(),
+                    linter: "eslint".to_string(),
+                },
+                LintIssue {
+                    file_path: "b.ts".to_string(),
+                    line: 2,
+                    column: 3,
+                    severity: LintSeverity::Warning,
+                    rule_id: "no-bar".to_string(),
+                    message: "meh".to_string(),
+                    linter: "eslint".to_string(),
+                },
+            ],
+        };
+
+        let ndjson = result.format_ndjson();
+        let lines: Vec<&str> = ndjson.lines().collect();
+        assert_eq!(lines.len(), 2);
+        for line in lines {
+            let parsed: Value = serde_json::from_str(line).expect("valid JSON line");
+            assert_eq!(parsed["schema_version"], SCHEMA_VERSION);
+        }
+    }
+}
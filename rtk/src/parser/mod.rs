@@ -7,11 +7,19 @@
 //!
 //! The three-tier system ensures RTK never returns false data silently.
 
+pub mod diff;
 pub mod error;
 pub mod formatter;
+pub mod json_format;
+pub mod normalize;
+pub mod term;
 pub mod types;
 
-pub use formatter::{FormatMode, TokenFormatter};
+pub use diff::DiffFormatter;
+pub use formatter::{CharEstimator, FormatMode, TokenEstimator, TokenFormatter};
+pub use json_format::{json_output_requested, JsonFormatter, SCHEMA_VERSION};
+pub use normalize::normalize_path;
+pub use term::ColorChoice;
 pub use types::*;
 
 /// Parse result with degradation tier
@@ -132,37 +140,85 @@ pub fn emit_passthrough_warning(tool: &str, reason: &str) {
 ///
 /// Returns `None` if no valid JSON object found.
 pub fn extract_json_object(input: &str) -> Option<&str> {
-    // Try vitest-specific marker first (most reliable)
-    let start_pos = if let Some(pos) = input.find("\"numTotalTests\"") {
-        // Walk backward to find opening brace of this object
-        input[..pos].rfind('{').unwrap_or(0)
-    } else {
-        // Fallback: find first `{` on its own line or after whitespace
-        let mut found_start = None;
-        for (idx, line) in input.lines().enumerate() {
-            let trimmed = line.trim();
-            if trimmed.starts_with('{') {
-                // Calculate byte offset
-                found_start = Some(
-                    input[..]
-                        .lines()
-                        .take(idx)
-                        .map(|l| l.len() + 1)
-                        .sum::<usize>(),
-                );
-                break;
+    extract_json_objects_with_markers(input, &["\"numTotalTests\""])
+        .into_iter()
+        .next()
+}
+
+/// Like [`extract_json_object`] but keeps scanning past the first balanced
+/// object/array instead of stopping there, so NDJSON (one record per line)
+/// and pretty-printed multi-object output interleaved with banner noise both
+/// yield every top-level record. Malformed fragments (an opening brace with
+/// no matching close) are skipped rather than aborting the whole scan.
+pub fn extract_json_objects(input: &str) -> Vec<&str> {
+    extract_json_objects_with_markers(input, &["\"numTotalTests\""])
+}
+
+/// Same as [`extract_json_objects`], but the marker strings used to locate
+/// the *first* object are configurable instead of hardcoded to vitest's
+/// `"numTotalTests"`, so other tier-1 parsers can reuse the same scanner.
+pub fn extract_json_objects_with_markers<'a>(input: &'a str, markers: &[&str]) -> Vec<&'a str> {
+    // Find where to start looking: the first configured marker (most
+    // reliable — it's inside the object we actually want), else the first
+    // standalone `{`/`[` at the start of a trimmed line.
+    let marker_pos = markers.iter().find_map(|m| input.find(m));
+    let mut scan_from = match marker_pos {
+        Some(pos) => input[..pos]
+            .rfind(['{', '['])
+            .unwrap_or(0),
+        None => {
+            let mut found_start = None;
+            for (idx, line) in input.lines().enumerate() {
+                let trimmed = line.trim();
+                if trimmed.starts_with('{') || trimmed.starts_with('[') {
+                    found_start = Some(
+                        input
+                            .lines()
+                            .take(idx)
+                            .map(|l| l.len() + 1)
+                            .sum::<usize>(),
+                    );
+                    break;
+                }
+            }
+            match found_start {
+                Some(p) => p,
+                None => return Vec::new(),
             }
         }
-        found_start?
     };
 
-    // Brace-balance forward from start_pos
-    let mut depth = 0;
+    let mut results = Vec::new();
+
+    while let Some(rel_start) = input[scan_from..].find(['{', '[']) {
+        let start_pos = scan_from + rel_start;
+        match balance_from(input, start_pos) {
+            Some(end_pos) => {
+                results.push(&input[start_pos..end_pos]);
+                scan_from = end_pos;
+            }
+            None => {
+                // Unbalanced fragment (truncated output, stray brace in
+                // prose, etc.) — skip past this opener and keep scanning
+                // instead of giving up on the rest of the stream.
+                scan_from = start_pos + 1;
+            }
+        }
+    }
+
+    results
+}
+
+/// Brace/bracket-balance forward from `start_pos` (which must point at `{`
+/// or `[`), string/escape-aware. Returns the exclusive end byte offset of
+/// the matching close, or `None` if the input ends before depth reaches 0.
+fn balance_from(input: &str, start_pos: usize) -> Option<usize> {
+    let mut depth = 0i32;
     let mut in_string = false;
     let mut escape_next = false;
-    let chars: Vec<char> = input[start_pos..].chars().collect();
+    let bytes_from_start: Vec<(usize, char)> = input[start_pos..].char_indices().collect();
 
-    for (i, &ch) in chars.iter().enumerate() {
+    for (offset, ch) in bytes_from_start {
         if escape_next {
             escape_next = false;
             continue;
@@ -171,13 +227,11 @@ pub fn extract_json_object(input: &str) -> Option<&str> {
         match ch {
             '\\' if in_string => escape_next = true,
             '"' => in_string = !in_string,
-            '{' if !in_string => depth += 1,
-            '}' if !in_string => {
+            '{' | '[' if !in_string => depth += 1,
+            '}' | ']' if !in_string => {
                 depth -= 1;
                 if depth == 0 {
-                    // Found matching closing brace
-                    let end_pos = start_pos + i + 1; // +1 to include the `}`
-                    return Some(&input[start_pos..end_pos]);
+                    return Some(start_pos + offset + ch.len_utf8());
                 }
             }
             _ => {}
@@ -0,0 +1,142 @@
+//! Path normalization, modeled on trybuild's `normalize.rs`: rewrites
+//! absolute, machine-specific paths down to a short, deterministic form
+//! before formatting, so `TokenFormatter` output doesn't vary across
+//! checkouts or environments.
+use std::path::{Component, Path, PathBuf};
+
+/// Cargo registry checkout markers, stripped down to `crate-name-version`.
+const REGISTRY_MARKERS: &[&str] = &["registry/src/", ".cargo/registry/src/"];
+
+/// Normalize one path string relative to `root`:
+/// - registry checkout paths (`~/.cargo/registry/src/.../crate-1.2.3/...`)
+///   collapse to `crate-1.2.3/...`
+/// - paths under `root` become `$ROOT/`-prefixed relative paths, with any
+///   `..` segments collapsed first
+/// - backslashes become forward slashes
+/// - anything else (paths outside `root`, already-normalized paths) is
+///   left untouched
+///
+/// Idempotent: normalizing an already-`$ROOT`-prefixed path is a no-op.
+pub fn normalize_path(path: &str, root: &Path) -> String {
+    if path.starts_with("$ROOT") {
+        return path.to_string();
+    }
+
+    let slashed = path.replace('\\', "/");
+
+    if let Some(crate_ref) = strip_registry_prefix(&slashed) {
+        return crate_ref;
+    }
+
+    let collapsed = collapse_dot_segments(Path::new(&slashed));
+    let collapsed = collapsed.to_string_lossy().replace('\\', "/");
+
+    let root_str = root.to_string_lossy().replace('\\', "/");
+    let root_str = root_str.trim_end_matches('/');
+    if root_str.is_empty() {
+        return collapsed;
+    }
+
+    match collapsed.strip_prefix(root_str) {
+        Some(rel) => {
+            let rel = rel.trim_start_matches('/');
+            if rel.is_empty() {
+                "$ROOT".to_string()
+            } else {
+                format!("$ROOT/{}", rel)
+            }
+        }
+        None => collapsed,
+    }
+}
+
+fn strip_registry_prefix(path: &str) -> Option<String> {
+    for marker in REGISTRY_MARKERS {
+        let pos = path.find(marker)?;
+        let after = &path[pos + marker.len()..];
+        // `after` looks like "index.crates.io-<hash>/crate-name-1.2.3/src/lib.rs" -
+        // drop the registry-index directory, keep the crate ref and beyond.
+        return match after.find('/') {
+            Some(slash) => Some(after[slash + 1..].to_string()),
+            None => Some(after.to_string()),
+        };
+    }
+    None
+}
+
+/// Collapse `.`/`..` path components without touching the filesystem -
+/// `Path::canonicalize` requires the path to exist, which a reported
+/// failure path may not (e.g. a deleted temp file).
+fn collapse_dot_segments(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::ParentDir => {
+                out.pop();
+            }
+            Component::CurDir => {}
+            other => out.push(other.as_os_str()),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_under_root() {
+        let root = Path::new("/home/dev/project");
+        assert_eq!(
+            normalize_path("/home/dev/project/src/lib.rs", root),
+            "$ROOT/src/lib.rs"
+        );
+    }
+
+    #[test]
+    fn test_normalize_collapses_dot_dot() {
+        let root = Path::new("/home/dev/project");
+        assert_eq!(
+            normalize_path("/home/dev/project/src/../src/lib.rs", root),
+            "$ROOT/src/lib.rs"
+        );
+    }
+
+    #[test]
+    fn test_normalize_leaves_outside_root_absolute() {
+        let root = Path::new("/home/dev/project");
+        assert_eq!(
+            normalize_path("/usr/include/stdio.h", root),
+            "/usr/include/stdio.h"
+        );
+    }
+
+    #[test]
+    fn test_normalize_registry_path() {
+        let root = Path::new("/home/dev/project");
+        let path =
+            "/home/dev/.cargo/registry/src/index.crates.io-6f17d22bba15001f/serde-1.0.195/src/lib.rs";
+        assert_eq!(
+            normalize_path(path, root),
+            "serde-1.0.195/src/lib.rs"
+        );
+    }
+
+    #[test]
+    fn test_normalize_is_idempotent() {
+        let root = Path::new("/home/dev/project");
+        let once = normalize_path("/home/dev/project/src/lib.rs", root);
+        let twice = normalize_path(&once, root);
+        assert_eq!(once, twice);
+    }
+
+    #[test]
+    fn test_normalize_windows_backslashes() {
+        let root = Path::new("/home/dev/project");
+        assert_eq!(
+            normalize_path(r"/home/dev/project\src\lib.rs", root),
+            "$ROOT/src/lib.rs"
+        );
+    }
+}
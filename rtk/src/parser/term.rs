@@ -0,0 +1,80 @@
+//! Minimal ANSI color support for terminal output, modeled on trybuild's
+//! `term.rs`: a handful of SGR wrap helpers gated by a [`ColorChoice`] so
+//! color can be forced on/off or auto-detected from the terminal and the
+//! usual `NO_COLOR`/`CLICOLOR` environment conventions.
+use std::io::IsTerminal;
+
+/// When to emit ANSI color codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    /// Always emit color, regardless of terminal or environment.
+    Always,
+    /// Never emit color; output is byte-identical to the plain formatters.
+    Never,
+    /// Emit color only when stdout is a tty and `NO_COLOR`/`CLICOLOR=0`
+    /// aren't set.
+    #[default]
+    Auto,
+}
+
+impl ColorChoice {
+    /// Resolve this choice to a plain yes/no for the current process.
+    pub fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                if std::env::var_os("NO_COLOR").is_some() {
+                    return false;
+                }
+                if std::env::var("CLICOLOR").as_deref() == Ok("0") {
+                    return false;
+                }
+                std::io::stdout().is_terminal()
+            }
+        }
+    }
+}
+
+fn sgr(code: &str, text: &str) -> String {
+    format!("\x1b[{}m{}\x1b[0m", code, text)
+}
+
+/// Wrap `text` in the SGR code for red (errors).
+pub fn red(text: &str) -> String {
+    sgr("31", text)
+}
+
+/// Wrap `text` in the SGR code for yellow (warnings).
+pub fn yellow(text: &str) -> String {
+    sgr("33", text)
+}
+
+/// Wrap `text` in the SGR code for green (passes).
+pub fn green(text: &str) -> String {
+    sgr("32", text)
+}
+
+/// Wrap `text` in the SGR code for dim (skipped/info).
+pub fn dim(text: &str) -> String {
+    sgr("2", text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_choice_always_never() {
+        assert!(ColorChoice::Always.enabled());
+        assert!(!ColorChoice::Never.enabled());
+    }
+
+    #[test]
+    fn test_sgr_wrapping() {
+        assert_eq!(red("x"), "\x1b[31mx\x1b[0m");
+        assert_eq!(yellow("x"), "\x1b[33mx\x1b[0m");
+        assert_eq!(green("x"), "\x1b[32mx\x1b[0m");
+        assert_eq!(dim("x"), "\x1b[2mx\x1b[0m");
+    }
+}
@@ -9,6 +9,10 @@ pub struct TestResult {
     pub passed: usize,
     pub failed: usize,
     pub skipped: usize,
+    /// Tests that failed at least one attempt but ultimately passed on
+    /// retry. `0` for parsers whose tool/reporter doesn't expose retries.
+    #[serde(default)]
+    pub flaky: usize,
     pub duration_ms: Option<u64>,
     pub failures: Vec<TestFailure>,
 }
@@ -19,6 +23,11 @@ pub struct TestFailure {
     pub file_path: String,
     pub error_message: String,
     pub stack_trace: Option<String>,
+    /// Number of attempts (including retries) the tool recorded for this
+    /// test, when the parser can tell - `None` for tools/tiers that only
+    /// ever see a single result.
+    #[serde(default)]
+    pub attempts: Option<usize>,
 }
 
 /// Linting result (eslint, biome, tsc, etc.)
@@ -40,6 +49,10 @@ pub struct LintIssue {
     pub severity: LintSeverity,
     pub rule_id: String,
     pub message: String,
+    /// Which linter produced this issue (e.g. "eslint", "pylint"). Empty for
+    /// parsers that predate this field - `rtk lint` always sets it now.
+    #[serde(default)]
+    pub linter: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -54,6 +67,15 @@ pub enum LintSeverity {
 pub struct DependencyState {
     pub total_packages: usize,
     pub outdated_count: usize,
+    /// Count of outdated dependencies by severity, where a parser computes
+    /// [`Dependency::update_severity`]. Zero for parsers that only know
+    /// "outdated or not" (e.g. `pip`, `pnpm list`).
+    #[serde(default)]
+    pub major_count: usize,
+    #[serde(default)]
+    pub minor_count: usize,
+    #[serde(default)]
+    pub patch_count: usize,
     pub dependencies: Vec<Dependency>,
 }
 
@@ -64,6 +86,65 @@ pub struct Dependency {
     pub latest_version: Option<String>,
     pub wanted_version: Option<String>,
     pub dev_dependency: bool,
+    /// Semver severity of the jump from `current_version` to
+    /// `latest_version`, when a parser classifies it. `None` when not
+    /// outdated or when no parser has computed a classification.
+    #[serde(default)]
+    pub update_severity: Option<UpdateSeverity>,
+    /// Whether `wanted_version` already points at `latest_version`, i.e. the
+    /// declared range doesn't block the newest release. Defaults to `true`
+    /// when either side is unknown.
+    #[serde(default = "default_wanted_is_latest")]
+    pub wanted_is_latest: bool,
+}
+
+fn default_wanted_is_latest() -> bool {
+    true
+}
+
+/// Severity of a version bump between a dependency's current and latest
+/// version, classified field-by-field on a `major.minor.patch[-prerelease]`
+/// split.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum UpdateSeverity {
+    Major,
+    Minor,
+    Patch,
+    Prerelease,
+    Unknown,
+}
+
+impl UpdateSeverity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UpdateSeverity::Major => "major",
+            UpdateSeverity::Minor => "minor",
+            UpdateSeverity::Patch => "patch",
+            UpdateSeverity::Prerelease => "prerelease",
+            UpdateSeverity::Unknown => "unknown",
+        }
+    }
+}
+
+/// Project + toolchain snapshot (`rtk pnpm info`): declared dependencies,
+/// installed pnpm/Node versions, and the inferred frontend framework,
+/// gathered without shelling out for a full dependency tree.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvInfo {
+    pub project_name: Option<String>,
+    pub project_version: Option<String>,
+    pub pnpm_version: Option<String>,
+    pub node_version: Option<String>,
+    pub frameworks: Vec<String>,
+    pub total_declared: usize,
+    pub dev_declared: usize,
+    /// How many of the declared dependencies were found in the lockfile.
+    /// Equal to `total_declared` when no lockfile was found to check
+    /// against, rather than implying everything is missing.
+    pub resolved_count: usize,
+    /// Declared dependencies absent from the lockfile, e.g. after editing
+    /// `package.json` without running `pnpm install`.
+    pub unresolved: Vec<String>,
 }
 
 /// Build output (next, webpack, vite, cargo, etc.)
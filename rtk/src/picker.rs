@@ -0,0 +1,192 @@
+//! Minimal in-crate fuzzy picker used by `gh_cmd`'s `pr view`/`issue
+//! view`/`run view` when called with no identifier. Prefers shelling out to
+//! an external `fzf` binary when one is on `$PATH` (matching the picker UX
+//! those users already have muscle memory for), and falls back to a small
+//! raw-terminal incremental filter modeled on fzf's interaction when it
+//! isn't installed. Skips entirely (returning `Ok(None)`) when stdout isn't
+//! a TTY, so scripted/non-interactive use falls through to the caller's
+//! existing "identifier required" error instead of hanging on a prompt.
+
+use anyhow::{Context, Result};
+use crossterm::cursor::MoveTo;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, Clear, ClearType};
+use crossterm::execute;
+use std::io::{stdout, IsTerminal, Write};
+use std::process::{Command, Stdio};
+
+const MAX_VISIBLE: usize = 20;
+
+/// One candidate row: `key` is returned to the caller on selection (e.g. a
+/// PR number as a string), `label` is what's displayed and matched against.
+pub struct PickItem {
+    pub key: String,
+    pub label: String,
+}
+
+/// Prompt the user to pick one of `items` by fuzzy-filtering `label`s.
+/// Returns `Ok(None)` if stdout isn't a TTY, there are no items, or the user
+/// cancels (Esc/Ctrl-c) - callers should treat `None` the same as if no
+/// picker had been offered at all.
+pub fn pick(items: &[PickItem]) -> Result<Option<String>> {
+    if items.is_empty() || !stdout().is_terminal() {
+        return Ok(None);
+    }
+    if has_fzf() {
+        pick_with_fzf(items)
+    } else {
+        pick_in_crate(items)
+    }
+}
+
+fn has_fzf() -> bool {
+    std::env::var_os("PATH")
+        .map(|path| std::env::split_paths(&path).any(|dir| dir.join("fzf").is_file()))
+        .unwrap_or(false)
+}
+
+fn pick_with_fzf(items: &[PickItem]) -> Result<Option<String>> {
+    let mut child = Command::new("fzf")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .context("Failed to spawn fzf")?;
+
+    {
+        let stdin = child.stdin.as_mut().context("Failed to open fzf stdin")?;
+        for item in items {
+            writeln!(stdin, "{}", item.label)?;
+        }
+    }
+
+    let output = child.wait_with_output().context("Failed while waiting on fzf")?;
+    if !output.status.success() {
+        return Ok(None);
+    }
+    let chosen = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Ok(items.iter().find(|i| i.label == chosen).map(|i| i.key.clone()))
+}
+
+fn pick_in_crate(items: &[PickItem]) -> Result<Option<String>> {
+    enable_raw_mode().context("Failed to enable raw terminal mode")?;
+    let result = run_picker_loop(items);
+    disable_raw_mode().ok();
+    execute!(stdout(), Clear(ClearType::FromCursorUp), MoveTo(0, 0)).ok();
+    result
+}
+
+fn run_picker_loop(items: &[PickItem]) -> Result<Option<String>> {
+    let mut query = String::new();
+    let mut selected = 0usize;
+
+    loop {
+        let matches = filter(items, &query);
+        selected = selected.min(matches.len().saturating_sub(1));
+        render(&query, &matches, selected)?;
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => return Ok(None),
+            KeyCode::Char('c') if ctrl => return Ok(None),
+            KeyCode::Enter => return Ok(matches.get(selected).map(|(item, _)| item.key.clone())),
+            KeyCode::Down => selected = (selected + 1).min(matches.len().saturating_sub(1)),
+            KeyCode::Char('n') if ctrl => selected = (selected + 1).min(matches.len().saturating_sub(1)),
+            KeyCode::Up => selected = selected.saturating_sub(1),
+            KeyCode::Char('p') if ctrl => selected = selected.saturating_sub(1),
+            KeyCode::Backspace => {
+                query.pop();
+                selected = 0;
+            }
+            KeyCode::Char(c) => {
+                query.push(c);
+                selected = 0;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Score a subsequence match of `needle` in `haystack` (case-insensitive),
+/// rewarding contiguous runs so e.g. "fix" ranks "Fix typo" above
+/// "Flush index cache" - `None` if `needle` isn't a subsequence at all.
+fn subsequence_score(needle: &str, haystack: &str) -> Option<i32> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+    let haystack_lower = haystack.to_lowercase();
+    let mut chars = haystack_lower.chars();
+    let mut score = 0;
+    let mut run = 0;
+    for n in needle.to_lowercase().chars() {
+        let mut matched = false;
+        for h in chars.by_ref() {
+            if h == n {
+                matched = true;
+                break;
+            }
+            run = 0;
+        }
+        if !matched {
+            return None;
+        }
+        run += 1;
+        score += run;
+    }
+    Some(score)
+}
+
+fn filter<'a>(items: &'a [PickItem], query: &str) -> Vec<(&'a PickItem, i32)> {
+    let mut matches: Vec<(&PickItem, i32)> = items
+        .iter()
+        .filter_map(|item| subsequence_score(query, &item.label).map(|score| (item, score)))
+        .collect();
+    matches.sort_by(|a, b| b.1.cmp(&a.1));
+    matches
+}
+
+fn render(query: &str, matches: &[(&PickItem, i32)], selected: usize) -> Result<()> {
+    let mut out = stdout();
+    execute!(out, Clear(ClearType::All), MoveTo(0, 0))?;
+    write!(out, "> {}\r\n", query)?;
+    for (i, (item, _)) in matches.iter().take(MAX_VISIBLE).enumerate() {
+        let marker = if i == selected { ">" } else { " " };
+        write!(out, "{} {}\r\n", marker, item.label)?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subsequence_score_matches_in_order() {
+        assert!(subsequence_score("fx", "fix typo").is_some());
+        assert!(subsequence_score("xf", "fix typo").is_none());
+    }
+
+    #[test]
+    fn test_subsequence_score_rewards_contiguous_runs() {
+        let tight = subsequence_score("fix", "fix typo").unwrap();
+        let loose = subsequence_score("fix", "flush index cache").unwrap();
+        assert!(tight > loose);
+    }
+
+    #[test]
+    fn test_filter_sorts_best_match_first() {
+        let items = vec![
+            PickItem { key: "1".to_string(), label: "flush index cache".to_string() },
+            PickItem { key: "2".to_string(), label: "fix typo".to_string() },
+        ];
+        let matches = filter(&items, "fix");
+        assert_eq!(matches[0].0.key, "2");
+    }
+}
@@ -1,3 +1,4 @@
+use crate::parser::{Dependency, DependencyState, UpdateSeverity};
 use crate::tracking;
 use anyhow::{Context, Result};
 use serde::Deserialize;
@@ -11,7 +12,116 @@ struct Package {
     latest_version: Option<String>,
 }
 
-pub fn run(args: &[String], verbose: u8) -> Result<()> {
+/// Risk category of an available upgrade, decided by which version
+/// component first differs between `current_version` and `latest_version`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum UpgradeCategory {
+    /// Same release, only a pre-release marker (`a`/`b`/`rc`/`.devN`) differs.
+    PreRelease,
+    Patch,
+    Minor,
+    Major,
+    /// Either version didn't parse into at least one numeric component.
+    Unknown,
+}
+
+impl UpgradeCategory {
+    fn label(self) -> &'static str {
+        match self {
+            UpgradeCategory::Major => "major",
+            UpgradeCategory::Minor => "minor",
+            UpgradeCategory::Patch => "patch",
+            UpgradeCategory::PreRelease => "pre-release",
+            UpgradeCategory::Unknown => "unknown",
+        }
+    }
+}
+
+/// A version split into `(major, minor, patch, pre-release marker)`.
+///
+/// Tolerant of both semver (`1.2.3`) and PEP 440 (`1.2.3rc1`, `1.2.3.dev0`)
+/// spellings: missing numeric components default to 0, and anything after
+/// the first three dot-separated numbers is kept verbatim as the
+/// pre-release marker rather than rejected.
+struct VersionTriple {
+    major: u64,
+    minor: u64,
+    patch: u64,
+    pre: Option<String>,
+}
+
+/// Parse a version string tolerantly. Returns `None` if not even the first
+/// component is numeric, so callers can fall back to `Unknown`.
+fn parse_version(version: &str) -> Option<VersionTriple> {
+    let mut components = version.splitn(4, '.');
+    let major_part = components.next()?;
+
+    let (major_num, mut pre) = split_numeric_prefix(major_part)?;
+    let (minor_num, minor_pre) = components
+        .next()
+        .and_then(split_numeric_prefix)
+        .unwrap_or((0, None));
+    let (patch_num, patch_pre) = components
+        .next()
+        .and_then(split_numeric_prefix)
+        .unwrap_or((0, None));
+    pre = pre.or(minor_pre).or(patch_pre);
+
+    // Anything left over (a 4th dot-separated segment, e.g. ".dev0") is a
+    // pre-release marker too.
+    if let Some(rest) = components.next() {
+        pre = pre.or_else(|| Some(format!(".{}", rest)));
+    }
+
+    Some(VersionTriple {
+        major: major_num,
+        minor: minor_num,
+        patch: patch_num,
+        pre,
+    })
+}
+
+/// Split a dot-separated version component into its leading numeric run and
+/// any trailing pre-release suffix (`rc1`, `a2`, `.dev0`'s `dev0`, …).
+/// Returns `None` if the component doesn't start with a digit at all.
+fn split_numeric_prefix(part: &str) -> Option<(u64, Option<String>)> {
+    let digit_end = part
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(part.len());
+    if digit_end == 0 {
+        return None;
+    }
+    let num: u64 = part[..digit_end].parse().ok()?;
+    let suffix = if digit_end < part.len() {
+        Some(part[digit_end..].to_string())
+    } else {
+        None
+    };
+    Some((num, suffix))
+}
+
+/// Classify an upgrade from `current` to `latest` by the most-significant
+/// version component that differs. Falls back to `Unknown` rather than
+/// panicking if either side fails to parse.
+fn classify_upgrade(current: &str, latest: &str) -> UpgradeCategory {
+    let (Some(cur), Some(new)) = (parse_version(current), parse_version(latest)) else {
+        return UpgradeCategory::Unknown;
+    };
+
+    if cur.major != new.major {
+        UpgradeCategory::Major
+    } else if cur.minor != new.minor {
+        UpgradeCategory::Minor
+    } else if cur.patch != new.patch {
+        UpgradeCategory::Patch
+    } else if cur.pre != new.pre {
+        UpgradeCategory::PreRelease
+    } else {
+        UpgradeCategory::Unknown
+    }
+}
+
+pub fn run(args: &[String], verbose: u8, json: bool) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
     // Auto-detect uv vs pip
@@ -26,15 +136,17 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
     let subcommand = args.first().map(|s| s.as_str()).unwrap_or("");
 
     let (cmd_str, filtered) = match subcommand {
-        "list" => run_list(base_cmd, &args[1..], verbose)?,
-        "outdated" => run_outdated(base_cmd, &args[1..], verbose)?,
+        "list" => run_list(base_cmd, &args[1..], verbose, json)?,
+        "outdated" => run_outdated(base_cmd, &args[1..], verbose, json)?,
+        "upgrade" => run_upgrade(base_cmd, &args[1..], verbose)?,
+        "tree" => run_tree(base_cmd, &args[1..], verbose)?,
         "install" | "uninstall" | "show" => {
             // Passthrough for write operations
             run_passthrough(base_cmd, args, verbose)?
         }
         _ => {
             anyhow::bail!(
-                "rtk pip: unsupported subcommand '{}'\nSupported: list, outdated, install, uninstall, show",
+                "rtk pip: unsupported subcommand '{}'\nSupported: list, outdated, upgrade, tree, install, uninstall, show",
                 subcommand
             );
         }
@@ -50,7 +162,7 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
     Ok(())
 }
 
-fn run_list(base_cmd: &str, args: &[String], verbose: u8) -> Result<(String, String)> {
+fn run_list(base_cmd: &str, args: &[String], verbose: u8, json: bool) -> Result<(String, String)> {
     let mut cmd = Command::new(base_cmd);
 
     if base_cmd == "uv" {
@@ -75,7 +187,12 @@ fn run_list(base_cmd: &str, args: &[String], verbose: u8) -> Result<(String, Str
     let stderr = String::from_utf8_lossy(&output.stderr);
     let raw = format!("{}\n{}", stdout, stderr);
 
-    let filtered = filter_pip_list(&stdout);
+    let filtered = if json {
+        serde_json::to_string(&list_to_dependency_state(&stdout))
+            .context("Failed to serialize pip list as JSON")?
+    } else {
+        filter_pip_list(&stdout)
+    };
     println!("{}", filtered);
 
     if !output.status.success() {
@@ -85,7 +202,12 @@ fn run_list(base_cmd: &str, args: &[String], verbose: u8) -> Result<(String, Str
     Ok((raw, filtered))
 }
 
-fn run_outdated(base_cmd: &str, args: &[String], verbose: u8) -> Result<(String, String)> {
+fn run_outdated(
+    base_cmd: &str,
+    args: &[String],
+    verbose: u8,
+    json: bool,
+) -> Result<(String, String)> {
     let mut cmd = Command::new(base_cmd);
 
     if base_cmd == "uv" {
@@ -110,7 +232,12 @@ fn run_outdated(base_cmd: &str, args: &[String], verbose: u8) -> Result<(String,
     let stderr = String::from_utf8_lossy(&output.stderr);
     let raw = format!("{}\n{}", stdout, stderr);
 
-    let filtered = filter_pip_outdated(&stdout);
+    let filtered = if json {
+        serde_json::to_string(&outdated_to_dependency_state(&stdout))
+            .context("Failed to serialize pip outdated as JSON")?
+    } else {
+        filter_pip_outdated(&stdout)
+    };
     println!("{}", filtered);
 
     if !output.status.success() {
@@ -120,6 +247,480 @@ fn run_outdated(base_cmd: &str, args: &[String], verbose: u8) -> Result<(String,
     Ok((raw, filtered))
 }
 
+/// Build the canonical [`DependencyState`] for `pip list` JSON output. Parse
+/// failures degrade to an empty state rather than erroring, matching the
+/// text path's tolerance for unparseable output.
+fn list_to_dependency_state(output: &str) -> DependencyState {
+    let packages: Vec<Package> = serde_json::from_str(output).unwrap_or_default();
+    DependencyState {
+        total_packages: packages.len(),
+        outdated_count: 0,
+        major_count: 0,
+        minor_count: 0,
+        patch_count: 0,
+        dependencies: packages
+            .into_iter()
+            .map(|pkg| Dependency {
+                name: pkg.name,
+                current_version: pkg.version,
+                latest_version: None,
+                wanted_version: None,
+                dev_dependency: false,
+                update_severity: None,
+                wanted_is_latest: true,
+            })
+            .collect(),
+    }
+}
+
+/// Build the canonical [`DependencyState`] for `pip list --outdated` JSON
+/// output, with `outdated_count` populated from every entry (they're all
+/// outdated by definition).
+fn outdated_to_dependency_state(output: &str) -> DependencyState {
+    let packages: Vec<Package> = serde_json::from_str(output).unwrap_or_default();
+    let total_packages = packages.len();
+    let mut major_count = 0;
+    let mut minor_count = 0;
+    let mut patch_count = 0;
+
+    let dependencies = packages
+        .into_iter()
+        .map(|pkg| {
+            let severity = pkg
+                .latest_version
+                .as_deref()
+                .map(|latest| classify_upgrade(&pkg.version, latest));
+            match severity {
+                Some(UpgradeCategory::Major) => major_count += 1,
+                Some(UpgradeCategory::Minor) => minor_count += 1,
+                Some(UpgradeCategory::Patch) => patch_count += 1,
+                _ => {}
+            }
+
+            Dependency {
+                name: pkg.name,
+                current_version: pkg.version,
+                latest_version: pkg.latest_version,
+                wanted_version: None,
+                dev_dependency: false,
+                update_severity: severity.map(upgrade_category_to_update_severity),
+                wanted_is_latest: true,
+            }
+        })
+        .collect();
+
+    DependencyState {
+        total_packages,
+        outdated_count: total_packages,
+        major_count,
+        minor_count,
+        patch_count,
+        dependencies,
+    }
+}
+
+/// Map pip's own [`UpgradeCategory`] onto the shared [`UpdateSeverity`], so
+/// `DependencyState`'s per-severity counts stay meaningful across parsers.
+fn upgrade_category_to_update_severity(category: UpgradeCategory) -> UpdateSeverity {
+    match category {
+        UpgradeCategory::Major => UpdateSeverity::Major,
+        UpgradeCategory::Minor => UpdateSeverity::Minor,
+        UpgradeCategory::Patch => UpdateSeverity::Patch,
+        UpgradeCategory::PreRelease => UpdateSeverity::Prerelease,
+        UpgradeCategory::Unknown => UpdateSeverity::Unknown,
+    }
+}
+
+/// Which releases `upgrade` is allowed to rewrite a constraint to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UpgradeMode {
+    /// Keep the existing pin operator, only raise within patch/minor.
+    Compatible,
+    /// Rewrite to the newest available release regardless of category.
+    Latest,
+}
+
+/// A single dependency line found in `requirements.txt` or a
+/// `pyproject.toml` `[project].dependencies` array entry.
+#[derive(Debug)]
+struct Constraint {
+    file: std::path::PathBuf,
+    line_idx: usize,
+    name: String,
+    operator: Option<String>,
+    version: Option<String>,
+}
+
+/// `rtk pip upgrade`: rewrite version pins in `requirements.txt` and/or
+/// `pyproject.toml`'s `[project].dependencies` to the latest resolvable
+/// release, modeled on `cargo upgrade`'s `--compatible`/`--latest` split.
+fn run_upgrade(base_cmd: &str, args: &[String], verbose: u8) -> Result<(String, String)> {
+    let dry_run = args.iter().any(|a| a == "--dry-run");
+    let mode = if args.iter().any(|a| a == "--latest") {
+        UpgradeMode::Latest
+    } else {
+        UpgradeMode::Compatible
+    };
+
+    let constraints = collect_constraints()?;
+    if constraints.is_empty() {
+        let msg = "rtk pip upgrade: no requirements.txt or pyproject.toml dependencies found to rewrite".to_string();
+        println!("{}", msg);
+        return Ok((msg.clone(), msg));
+    }
+
+    let outdated = fetch_outdated(base_cmd, verbose)?;
+
+    let mut changed = 0;
+    let mut skipped = 0;
+    let mut current = 0;
+    let mut report = Vec::new();
+
+    // Group by file so each file is read/written exactly once.
+    let mut by_file: std::collections::BTreeMap<std::path::PathBuf, Vec<String>> =
+        std::collections::BTreeMap::new();
+    for constraint in &constraints {
+        by_file
+            .entry(constraint.file.clone())
+            .or_insert_with(|| {
+                std::fs::read_to_string(&constraint.file)
+                    .unwrap_or_default()
+                    .lines()
+                    .map(|l| l.to_string())
+                    .collect()
+            });
+    }
+
+    for constraint in &constraints {
+        let Some(pkg) = outdated
+            .iter()
+            .find(|p| normalize_name(&p.name) == normalize_name(&constraint.name))
+        else {
+            current += 1;
+            continue;
+        };
+        let Some(latest) = &pkg.latest_version else {
+            current += 1;
+            continue;
+        };
+
+        let category = classify_upgrade(&pkg.version, latest);
+        let allowed = match mode {
+            UpgradeMode::Latest => category != UpgradeCategory::Unknown,
+            UpgradeMode::Compatible => {
+                matches!(category, UpgradeCategory::Patch | UpgradeCategory::Minor)
+            }
+        };
+
+        if !allowed {
+            skipped += 1;
+            continue;
+        }
+
+        let lines = by_file.get_mut(&constraint.file).unwrap();
+        let old_line = lines[constraint.line_idx].clone();
+        let operator = constraint.operator.as_deref().unwrap_or(">=");
+        let new_line = old_line.replacen(
+            &format!(
+                "{}{}",
+                operator,
+                constraint.version.as_deref().unwrap_or("")
+            ),
+            &format!("{}{}", operator, latest),
+            1,
+        );
+
+        if new_line != old_line {
+            report.push(format!("{}:\n  - {}\n  + {}", constraint.file.display(), old_line, new_line));
+            lines[constraint.line_idx] = new_line;
+            changed += 1;
+        } else {
+            skipped += 1;
+        }
+    }
+
+    let mut output = String::new();
+    if dry_run {
+        if report.is_empty() {
+            output.push_str("rtk pip upgrade (dry-run): no changes\n");
+        } else {
+            output.push_str("rtk pip upgrade (dry-run):\n\n");
+            output.push_str(&report.join("\n\n"));
+            output.push('\n');
+        }
+    } else {
+        for (file, lines) in &by_file {
+            std::fs::write(file, format!("{}\n", lines.join("\n")))
+                .with_context(|| format!("Failed to write {}", file.display()))?;
+        }
+        output.push_str("rtk pip upgrade:\n");
+    }
+
+    output.push_str(&format!(
+        "\n{} changed, {} skipped, {} already current\n",
+        changed, skipped, current
+    ));
+
+    println!("{}", output.trim());
+
+    Ok((output.clone(), output))
+}
+
+/// Fetch the current outdated-package list via `pip list --outdated
+/// --format=json`, reusing the existing [`Package`] deserialization.
+fn fetch_outdated(base_cmd: &str, verbose: u8) -> Result<Vec<Package>> {
+    let mut cmd = Command::new(base_cmd);
+    if base_cmd == "uv" {
+        cmd.arg("pip");
+    }
+    cmd.arg("list").arg("--outdated").arg("--format=json");
+
+    if verbose > 0 {
+        eprintln!("Running: {} pip list --outdated --format=json", base_cmd);
+    }
+
+    let output = cmd
+        .output()
+        .with_context(|| format!("Failed to run {} pip list --outdated", base_cmd))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    Ok(serde_json::from_str(&stdout).unwrap_or_default())
+}
+
+/// Normalize a package name per PEP 503 (case-insensitive, `-`/`_`/`.`
+/// treated as equivalent) so requirement-file spellings match `pip`'s.
+fn normalize_name(name: &str) -> String {
+    name.to_lowercase().replace(['_', '.'], "-")
+}
+
+/// Scan `requirements.txt` and `pyproject.toml`'s `[project].dependencies`
+/// array (if present) for rewritable version constraints.
+fn collect_constraints() -> Result<Vec<Constraint>> {
+    let mut constraints = Vec::new();
+
+    let requirements_path = std::path::PathBuf::from("requirements.txt");
+    if let Ok(content) = std::fs::read_to_string(&requirements_path) {
+        for (idx, line) in content.lines().enumerate() {
+            if let Some((name, operator, version)) = parse_requirement_line(line) {
+                constraints.push(Constraint {
+                    file: requirements_path.clone(),
+                    line_idx: idx,
+                    name,
+                    operator,
+                    version,
+                });
+            }
+        }
+    }
+
+    let pyproject_path = std::path::PathBuf::from("pyproject.toml");
+    if let Ok(content) = std::fs::read_to_string(&pyproject_path) {
+        let mut in_dependencies = false;
+        for (idx, line) in content.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.starts_with("dependencies") && trimmed.contains('[') {
+                in_dependencies = true;
+                if trimmed.contains(']') {
+                    in_dependencies = false;
+                }
+                continue;
+            }
+            if !in_dependencies {
+                continue;
+            }
+            if trimmed.starts_with(']') {
+                in_dependencies = false;
+                continue;
+            }
+
+            let quoted = trimmed.trim_matches([' ', ',']).trim_matches('"');
+            if let Some((name, operator, version)) = parse_requirement_line(quoted) {
+                constraints.push(Constraint {
+                    file: pyproject_path.clone(),
+                    line_idx: idx,
+                    name,
+                    operator,
+                    version,
+                });
+            }
+        }
+    }
+
+    Ok(constraints)
+}
+
+/// Parse a PEP 508-ish requirement line into `(name, operator, version)`.
+/// Handles the common pin operators; extras (`name[extra]`) and
+/// environment markers (`; python_version...`) are stripped rather than
+/// rejected. Returns `None` for unpinned or unparseable lines.
+fn parse_requirement_line(line: &str) -> Option<(String, Option<String>, Option<String>)> {
+    let line = line.split('#').next().unwrap_or("").trim();
+    let line = line.split(';').next().unwrap_or("").trim();
+    if line.is_empty() {
+        return None;
+    }
+
+    const OPERATORS: &[&str] = &["==", "~=", ">=", "<=", "!=", ">", "<"];
+    for op in OPERATORS {
+        if let Some(op_pos) = line.find(op) {
+            let mut name = line[..op_pos].trim().to_string();
+            if let Some(bracket) = name.find('[') {
+                name.truncate(bracket);
+            }
+            let version = line[op_pos + op.len()..].trim().to_string();
+            if name.is_empty() || version.is_empty() {
+                return None;
+            }
+            return Some((name, Some(op.to_string()), Some(version)));
+        }
+    }
+
+    None
+}
+
+const TREE_MAX_DEPTH: usize = 5;
+const TREE_MAX_CHILDREN: usize = 15;
+
+/// `rtk pip tree`: an indented dependency graph built from `pip show`
+/// (portable across both `pip` and `uv`, which doesn't expose `show`
+/// output-compatible flags for a condensed tree). Accepts an optional
+/// package name to root the tree at (and `--reverse` to walk dependents
+/// instead of dependencies), truncating wide/deep graphs and marking
+/// cycles rather than recursing forever.
+fn run_tree(base_cmd: &str, args: &[String], verbose: u8) -> Result<(String, String)> {
+    let reverse = args.iter().any(|a| a == "--reverse");
+    let root = args.iter().find(|a| !a.starts_with("--")).cloned();
+
+    let packages: Vec<Package> = {
+        let mut cmd = Command::new(base_cmd);
+        if base_cmd == "uv" {
+            cmd.arg("pip");
+        }
+        cmd.arg("list").arg("--format=json");
+        let output = cmd
+            .output()
+            .with_context(|| format!("Failed to run {} pip list", base_cmd))?;
+        serde_json::from_str(&String::from_utf8_lossy(&output.stdout)).unwrap_or_default()
+    };
+
+    if verbose > 0 {
+        eprintln!("rtk pip tree: {} installed packages", packages.len());
+    }
+
+    let mut requires: std::collections::HashMap<String, Vec<String>> =
+        std::collections::HashMap::new();
+    for pkg in &packages {
+        requires.insert(normalize_name(&pkg.name), show_requires(base_cmd, &pkg.name));
+    }
+
+    let graph = if reverse {
+        let mut reversed: std::collections::HashMap<String, Vec<String>> =
+            std::collections::HashMap::new();
+        for (name, deps) in &requires {
+            for dep in deps {
+                reversed
+                    .entry(normalize_name(dep))
+                    .or_default()
+                    .push(name.clone());
+            }
+        }
+        reversed
+    } else {
+        requires
+    };
+
+    let mut lines = Vec::new();
+    let roots: Vec<String> = match &root {
+        Some(name) => vec![normalize_name(name)],
+        None => {
+            let mut all: Vec<String> = packages.iter().map(|p| normalize_name(&p.name)).collect();
+            all.sort();
+            all
+        }
+    };
+
+    let label = if reverse { "rtk pip tree --reverse" } else { "rtk pip tree" };
+    lines.push(format!("{}: {} packages", label, packages.len()));
+
+    for r in &roots {
+        let mut path = Vec::new();
+        print_tree_node(r, 0, &graph, &mut path, &mut lines);
+    }
+
+    let output = lines.join("\n");
+    println!("{}", output);
+
+    Ok((output.clone(), output))
+}
+
+/// Recursively render one node (and its children) of the dependency graph,
+/// indenting by depth and truncating both depth and fan-out. `path` tracks
+/// the current DFS ancestry so cycles are reported rather than looped.
+fn print_tree_node(
+    name: &str,
+    depth: usize,
+    graph: &std::collections::HashMap<String, Vec<String>>,
+    path: &mut Vec<String>,
+    lines: &mut Vec<String>,
+) {
+    let indent = "  ".repeat(depth);
+
+    if path.contains(&name.to_string()) {
+        lines.push(format!("{}{} (cycle)", indent, name));
+        return;
+    }
+
+    lines.push(format!("{}{}", indent, name));
+
+    if depth >= TREE_MAX_DEPTH {
+        return;
+    }
+
+    let Some(children) = graph.get(name) else {
+        return;
+    };
+
+    path.push(name.to_string());
+    for child in children.iter().take(TREE_MAX_CHILDREN) {
+        print_tree_node(child, depth + 1, graph, path, lines);
+    }
+    if children.len() > TREE_MAX_CHILDREN {
+        lines.push(format!(
+            "{}  ... +{} more",
+            indent,
+            children.len() - TREE_MAX_CHILDREN
+        ));
+    }
+    path.pop();
+}
+
+/// `pip show <name>` → the normalized names listed on its `Requires:` line.
+/// Missing/unparsable output just yields no children rather than erroring —
+/// one package's broken metadata shouldn't sink the whole tree.
+fn show_requires(base_cmd: &str, name: &str) -> Vec<String> {
+    let mut cmd = Command::new(base_cmd);
+    if base_cmd == "uv" {
+        cmd.arg("pip");
+    }
+    cmd.arg("show").arg(name);
+
+    let Ok(output) = cmd.output() else {
+        return Vec::new();
+    };
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("Requires: "))
+        .map(|rest| {
+            rest.split(',')
+                .map(|s| normalize_name(s.trim()))
+                .filter(|s| !s.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 fn run_passthrough(base_cmd: &str, args: &[String], verbose: u8) -> Result<(String, String)> {
     let mut cmd = Command::new(base_cmd);
 
@@ -223,27 +824,54 @@ fn filter_pip_outdated(output: &str) -> String {
         return "✓ pip outdated: All packages up to date".to_string();
     }
 
+    let classified: Vec<(&Package, UpgradeCategory)> = packages
+        .iter()
+        .map(|pkg| {
+            let category = pkg
+                .latest_version
+                .as_deref()
+                .map(|latest| classify_upgrade(&pkg.version, latest))
+                .unwrap_or(UpgradeCategory::Unknown);
+            (pkg, category)
+        })
+        .collect();
+
     let mut result = String::new();
     result.push_str(&format!("pip outdated: {} packages\n", packages.len()));
+    result.push_str(&format!("{}\n", summarize_categories(&classified)));
     result.push_str("═══════════════════════════════════════\n");
 
-    for (i, pkg) in packages.iter().take(20).enumerate() {
-        let latest = pkg
-            .latest_version
-            .as_ref()
-            .map(|v| v.as_str())
-            .unwrap_or("unknown");
-        result.push_str(&format!(
-            "{}. {} ({} → {})\n",
-            i + 1,
-            pkg.name,
-            pkg.version,
-            latest
-        ));
-    }
+    // Highest-risk first.
+    for category in [
+        UpgradeCategory::Major,
+        UpgradeCategory::Minor,
+        UpgradeCategory::Patch,
+        UpgradeCategory::PreRelease,
+        UpgradeCategory::Unknown,
+    ] {
+        let group: Vec<_> = classified
+            .iter()
+            .filter(|(_, c)| *c == category)
+            .collect();
+        if group.is_empty() {
+            continue;
+        }
 
-    if packages.len() > 20 {
-        result.push_str(&format!("\n... +{} more packages\n", packages.len() - 20));
+        result.push_str(&format!("\n[{}]\n", category.label()));
+        for (pkg, _) in group.iter().take(20) {
+            let latest = pkg
+                .latest_version
+                .as_ref()
+                .map(|v| v.as_str())
+                .unwrap_or("unknown");
+            result.push_str(&format!(
+                "  {} ({} → {})\n",
+                pkg.name, pkg.version, latest
+            ));
+        }
+        if group.len() > 20 {
+            result.push_str(&format!("  ... +{} more\n", group.len() - 20));
+        }
     }
 
     result.push_str("\n💡 Run `pip install --upgrade <package>` to update\n");
@@ -251,6 +879,25 @@ fn filter_pip_outdated(output: &str) -> String {
     result.trim().to_string()
 }
 
+/// Build the `3 major, 5 minor, 2 patch` summary header, omitting
+/// zero-count categories, highest-risk first.
+fn summarize_categories(classified: &[(&Package, UpgradeCategory)]) -> String {
+    [
+        UpgradeCategory::Major,
+        UpgradeCategory::Minor,
+        UpgradeCategory::Patch,
+        UpgradeCategory::PreRelease,
+        UpgradeCategory::Unknown,
+    ]
+    .into_iter()
+    .filter_map(|category| {
+        let count = classified.iter().filter(|(_, c)| *c == category).count();
+        (count > 0).then(|| format!("{} {}", count, category.label()))
+    })
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -298,5 +945,62 @@ mod tests {
         assert!(result.contains("2.31.0 → 2.32.0"));
         assert!(result.contains("pytest"));
         assert!(result.contains("7.4.0 → 8.0.0"));
+        assert!(result.contains("2 minor"));
+    }
+
+    #[test]
+    fn test_classify_upgrade() {
+        assert_eq!(classify_upgrade("1.2.3", "2.0.0"), UpgradeCategory::Major);
+        assert_eq!(classify_upgrade("1.2.3", "1.3.0"), UpgradeCategory::Minor);
+        assert_eq!(classify_upgrade("1.2.3", "1.2.4"), UpgradeCategory::Patch);
+        assert_eq!(
+            classify_upgrade("1.2.3", "1.2.3rc1"),
+            UpgradeCategory::PreRelease
+        );
+        assert_eq!(classify_upgrade("1.2.3", "1.2.3"), UpgradeCategory::Unknown);
+        assert_eq!(classify_upgrade("abc", "1.0.0"), UpgradeCategory::Unknown);
+    }
+
+    #[test]
+    fn test_parse_requirement_line() {
+        assert_eq!(
+            parse_requirement_line("requests>=2.31.0"),
+            Some((
+                "requests".to_string(),
+                Some(">=".to_string()),
+                Some("2.31.0".to_string())
+            ))
+        );
+        assert_eq!(
+            parse_requirement_line("pytest[testing]==7.4.0  # pinned"),
+            Some((
+                "pytest".to_string(),
+                Some("==".to_string()),
+                Some("7.4.0".to_string())
+            ))
+        );
+        assert_eq!(parse_requirement_line("# just a comment"), None);
+        assert_eq!(parse_requirement_line("rich"), None);
+    }
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(normalize_name("Flask_SQLAlchemy"), "flask-sqlalchemy");
+        assert_eq!(normalize_name("rich"), "rich");
+    }
+
+    #[test]
+    fn test_print_tree_node_detects_cycle() {
+        let mut graph = std::collections::HashMap::new();
+        graph.insert("a".to_string(), vec!["b".to_string()]);
+        graph.insert("b".to_string(), vec!["a".to_string()]);
+
+        let mut lines = Vec::new();
+        let mut path = Vec::new();
+        print_tree_node("a", 0, &graph, &mut path, &mut lines);
+
+        assert_eq!(lines[0], "a");
+        assert_eq!(lines[1], "  b");
+        assert_eq!(lines[2], "    a (cycle)");
     }
 }
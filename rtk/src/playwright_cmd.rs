@@ -5,8 +5,8 @@ use regex::Regex;
 use serde::Deserialize;
 
 use crate::parser::{
-    emit_degradation_warning, emit_passthrough_warning, truncate_output, FormatMode, OutputParser,
-    ParseResult, TestFailure, TestResult, TokenFormatter,
+    emit_degradation_warning, emit_passthrough_warning, json_output_requested, truncate_output,
+    FormatMode, JsonFormatter, OutputParser, ParseResult, TestFailure, TestResult, TokenFormatter,
 };
 
 /// Playwright JSON output structures (tool-specific format)
@@ -74,14 +74,16 @@ impl OutputParser for PlaywrightParser {
         match serde_json::from_str::<PlaywrightJsonOutput>(input) {
             Ok(json) => {
                 let mut failures = Vec::new();
+                let mut flaky = 0;
                 let mut total = 0;
-                collect_test_results(&json.suites, &mut total, &mut failures);
+                collect_test_results(&json.suites, &mut total, &mut flaky, &mut failures);
 
                 let result = TestResult {
                     total,
                     passed: json.stats.expected,
                     failed: json.stats.unexpected,
                     skipped: json.stats.skipped,
+                    flaky,
                     duration_ms: Some(json.stats.duration),
                     failures,
                 };
@@ -108,31 +110,37 @@ impl OutputParser for PlaywrightParser {
 fn collect_test_results(
     suites: &[PlaywrightSuite],
     total: &mut usize,
+    flaky: &mut usize,
     failures: &mut Vec<TestFailure>,
 ) {
     for suite in suites {
         for test in &suite.tests {
             *total += 1;
 
-            if test.status == "failed" || test.status == "timedOut" {
-                let error_msg = test
-                    .results
-                    .first()
-                    .and_then(|r| r.error.as_ref())
-                    .map(|e| e.message.clone())
-                    .unwrap_or_else(|| "Unknown error".to_string());
+            let first_failed_error = test
+                .results
+                .iter()
+                .find(|r| r.status == "failed" || r.status == "timedOut")
+                .and_then(|r| r.error.as_ref())
+                .map(|e| e.message.clone());
 
+            if test.status == "failed" || test.status == "timedOut" {
                 failures.push(TestFailure {
                     test_name: test.title.clone(),
                     file_path: suite.title.clone(),
-                    error_message: error_msg,
+                    error_message: first_failed_error.unwrap_or_else(|| "Unknown error".to_string()),
                     stack_trace: None,
+                    attempts: Some(test.results.len()),
                 });
+            } else if first_failed_error.is_some() {
+                // Failed at least one attempt but the final attempt
+                // recovered: genuinely flaky, not a hard failure.
+                *flaky += 1;
             }
         }
 
         // Recurse into nested suites
-        collect_test_results(&suite.suites, total, failures);
+        collect_test_results(&suite.suites, total, flaky, failures);
     }
 }
 
@@ -152,6 +160,7 @@ fn extract_playwright_regex(output: &str) -> Option<TestResult> {
     let mut passed = 0;
     let mut failed = 0;
     let mut skipped = 0;
+    let mut flaky = 0;
 
     // Parse summary counts
     for caps in SUMMARY_RE.captures_iter(&clean_output) {
@@ -160,6 +169,7 @@ fn extract_playwright_regex(output: &str) -> Option<TestResult> {
             "passed" => passed = count,
             "failed" => failed = count,
             "skipped" => skipped = count,
+            "flaky" => flaky = count,
             _ => {}
         }
     }
@@ -184,6 +194,7 @@ fn extract_playwright_regex(output: &str) -> Option<TestResult> {
             passed,
             failed,
             skipped,
+            flaky,
             duration_ms,
             failures: extract_failures_regex(&clean_output),
         })
@@ -209,6 +220,7 @@ fn extract_failures_regex(output: &str) -> Vec<TestFailure> {
                 file_path: spec.as_str().to_string(),
                 error_message: String::new(),
                 stack_trace: None,
+                attempts: None,
             });
         }
     }
@@ -243,19 +255,31 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
     // Parse output using PlaywrightParser
     let parse_result = PlaywrightParser::parse(&stdout);
     let mode = FormatMode::from_verbosity(verbose);
+    let root = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
 
+    let want_json = json_output_requested();
     let filtered = match parse_result {
-        ParseResult::Full(data) => {
+        ParseResult::Full(mut data) => {
             if verbose > 0 {
                 eprintln!("playwright test (Tier 1: Full JSON parse)");
             }
-            data.format(mode)
+            data.normalize_paths(&root);
+            if want_json {
+                serde_json::to_string_pretty(&data.format_json()).unwrap_or_default()
+            } else {
+                data.format(mode)
+            }
         }
-        ParseResult::Degraded(data, warnings) => {
+        ParseResult::Degraded(mut data, warnings) => {
             if verbose > 0 {
                 emit_degradation_warning("playwright", &warnings.join(", "));
             }
-            data.format(mode)
+            data.normalize_paths(&root);
+            if want_json {
+                serde_json::to_string_pretty(&data.format_json()).unwrap_or_default()
+            } else {
+                data.format(mode)
+            }
         }
         ParseResult::Passthrough(raw) => {
             emit_passthrough_warning("playwright", "All parsing tiers failed");
@@ -318,6 +342,74 @@ mod tests {
         assert_eq!(data.duration_ms, Some(7300));
     }
 
+    #[test]
+    fn test_playwright_parser_json_flaky() {
+        let json = r#"{
+            "stats": {
+                "expected": 1,
+                "unexpected": 0,
+                "skipped": 0,
+                "duration": 4100
+            },
+            "suites": [
+                {
+                    "title": "auth/login.spec.ts",
+                    "tests": [
+                        {
+                            "title": "should login",
+                            "status": "passed",
+                            "results": [
+                                {"status": "failed", "error": {"message": "timeout waiting for selector"}, "duration": 3000},
+                                {"status": "passed", "duration": 1100}
+                            ]
+                        }
+                    ],
+                    "suites": []
+                }
+            ]
+        }"#;
+
+        let result = PlaywrightParser::parse(json);
+        let data = result.unwrap();
+        assert_eq!(data.flaky, 1);
+        assert!(data.failures.is_empty());
+    }
+
+    #[test]
+    fn test_playwright_parser_json_hard_failure_attempts() {
+        let json = r#"{
+            "stats": {
+                "expected": 0,
+                "unexpected": 1,
+                "skipped": 0,
+                "duration": 6000
+            },
+            "suites": [
+                {
+                    "title": "auth/login.spec.ts",
+                    "tests": [
+                        {
+                            "title": "should login",
+                            "status": "failed",
+                            "results": [
+                                {"status": "failed", "error": {"message": "selector not found"}, "duration": 3000},
+                                {"status": "failed", "error": {"message": "selector not found"}, "duration": 3000}
+                            ]
+                        }
+                    ],
+                    "suites": []
+                }
+            ]
+        }"#;
+
+        let result = PlaywrightParser::parse(json);
+        let data = result.unwrap();
+        assert_eq!(data.flaky, 0);
+        assert_eq!(data.failures.len(), 1);
+        assert_eq!(data.failures[0].attempts, Some(2));
+        assert_eq!(data.failures[0].error_message, "selector not found");
+    }
+
     #[test]
     fn test_playwright_parser_regex_fallback() {
         let text = "3 passed (7.3s)";
@@ -1,5 +1,5 @@
 use crate::tracking;
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::ffi::OsString;
@@ -7,7 +7,7 @@ use std::process::Command;
 
 use crate::parser::{
     emit_degradation_warning, emit_passthrough_warning, truncate_output, Dependency,
-    DependencyState, FormatMode, OutputParser, ParseResult, TokenFormatter,
+    DependencyState, EnvInfo, FormatMode, OutputParser, ParseResult, TokenFormatter, UpdateSeverity,
 };
 
 /// pnpm list JSON output structure
@@ -62,6 +62,9 @@ impl OutputParser for PnpmListParser {
                 let result = DependencyState {
                     total_packages: total_count,
                     outdated_count: 0, // list doesn't provide outdated info
+                    major_count: 0,
+                    minor_count: 0,
+                    patch_count: 0,
                     dependencies,
                 };
 
@@ -98,6 +101,8 @@ fn collect_dependencies(
             latest_version: None,
             wanted_version: None,
             dev_dependency: is_dev,
+            update_severity: None,
+            wanted_is_latest: true,
         });
         *count += 1;
     }
@@ -141,6 +146,8 @@ fn extract_list_text(output: &str) -> Option<DependencyState> {
                         latest_version: None,
                         wanted_version: None,
                         dev_dependency: false,
+                        update_severity: None,
+                        wanted_is_latest: true,
                     });
                     count += 1;
                 }
@@ -152,6 +159,9 @@ fn extract_list_text(output: &str) -> Option<DependencyState> {
         Some(DependencyState {
             total_packages: count,
             outdated_count: 0,
+            major_count: 0,
+            minor_count: 0,
+            patch_count: 0,
             dependencies,
         })
     } else {
@@ -159,6 +169,257 @@ fn extract_list_text(output: &str) -> Option<DependencyState> {
     }
 }
 
+/// Reads the resolved dependency tree straight out of `pnpm-lock.yaml`
+/// (`rtk pnpm list --offline`), so `run_list` can answer deterministically
+/// without shelling out to `pnpm list --json` - useful in CI or sandboxes
+/// where running pnpm isn't desirable. There's no real YAML parser in this
+/// tree, so both tiers are hand-rolled line/indent scanners, the same style
+/// as `deps::parse_yarn_lock`.
+pub struct PnpmLockParser;
+
+impl OutputParser for PnpmLockParser {
+    type Output = DependencyState;
+
+    fn parse(input: &str) -> ParseResult<DependencyState> {
+        // Tier 1: structured walk of `packages:` (resolved name/version)
+        // cross-referenced against `importers: > devDependencies:` (which
+        // entries are dev-only).
+        match parse_lockfile_packages_section(input) {
+            Some(result) => ParseResult::Full(result),
+            None => match parse_lockfile_keys_loosely(input) {
+                // Tier 2: no `packages:` section found (or it was empty) -
+                // just pick up every line that looks like a `name@version:`
+                // header, wherever it appears, with no dev/prod distinction.
+                Some(result) => ParseResult::Degraded(
+                    result,
+                    vec!["no `packages:` section found, falling back to loose key scan".to_string()],
+                ),
+                None => ParseResult::Passthrough(truncate_output(input, 500)),
+            },
+        }
+    }
+}
+
+/// Tier 1: entries under the `packages:` top-level key, each a lockfile key
+/// like `/name@version` or `/@scope/name@version` (an optional peer-dep
+/// suffix in parens is stripped), marked dev via [`collect_importer_dev_names`].
+fn parse_lockfile_packages_section(input: &str) -> Option<DependencyState> {
+    let dev_names = collect_importer_dev_names(input);
+
+    let mut dependencies = Vec::new();
+    let mut in_packages = false;
+    for line in input.lines() {
+        if line.trim_end() == "packages:" {
+            in_packages = true;
+            continue;
+        }
+        if !in_packages || line.trim().is_empty() {
+            continue;
+        }
+
+        let indent = line.len() - line.trim_start().len();
+        if indent == 0 {
+            break; // left the `packages:` section
+        }
+        if indent != 2 {
+            continue; // nested field (resolution, engines, ...)
+        }
+
+        let Some(key) = line.trim().strip_suffix(':') else {
+            continue;
+        };
+        let Some((name, version)) = split_lock_package_key(key) else {
+            continue;
+        };
+        let dev_dependency = dev_names.contains(&name);
+        dependencies.push(Dependency {
+            name,
+            current_version: version,
+            latest_version: None,
+            wanted_version: None,
+            dev_dependency,
+            update_severity: None,
+            wanted_is_latest: true,
+        });
+    }
+
+    if dependencies.is_empty() {
+        return None;
+    }
+
+    let total_packages = dependencies.len();
+    Some(DependencyState {
+        total_packages,
+        outdated_count: 0,
+        major_count: 0,
+        minor_count: 0,
+        patch_count: 0,
+        dependencies,
+    })
+}
+
+/// Tier 2: scan every line of the file for something that parses as a
+/// `name@version:` header, regardless of section or indentation. No
+/// dev/prod distinction is available at this tier.
+fn parse_lockfile_keys_loosely(input: &str) -> Option<DependencyState> {
+    let mut dependencies = Vec::new();
+    for line in input.lines() {
+        let Some(key) = line.trim().strip_suffix(':') else {
+            continue;
+        };
+        let Some((name, version)) = split_lock_package_key(key) else {
+            continue;
+        };
+        dependencies.push(Dependency {
+            name,
+            current_version: version,
+            latest_version: None,
+            wanted_version: None,
+            dev_dependency: false,
+            update_severity: None,
+            wanted_is_latest: true,
+        });
+    }
+
+    if dependencies.is_empty() {
+        return None;
+    }
+
+    let total_packages = dependencies.len();
+    Some(DependencyState {
+        total_packages,
+        outdated_count: 0,
+        major_count: 0,
+        minor_count: 0,
+        patch_count: 0,
+        dependencies,
+    })
+}
+
+/// Splits a `pnpm-lock.yaml` `packages:` entry key into `(name, version)`,
+/// e.g. `/react-dom@18.2.0(react@18.2.0)` or `lodash@4.17.21` -> the part
+/// before the trailing `@version`, ignoring a leading `/` and any
+/// peer-dependency suffix in parens.
+fn split_lock_package_key(key: &str) -> Option<(String, String)> {
+    let key = key.trim_start_matches('/');
+    let key = key.split('(').next().unwrap_or(key);
+    let (name, version) = key.rsplit_once('@')?;
+    if name.is_empty() || version.is_empty() {
+        None
+    } else {
+        Some((name.to_string(), version.to_string()))
+    }
+}
+
+/// Names declared under any `importers: > ... > devDependencies:` block,
+/// used to mark dev-only entries in the `packages:` section. Only the
+/// immediate child level under each `devDependencies:` is read; deeper
+/// nesting (`specifier:`, `version:`) is skipped.
+fn collect_importer_dev_names(input: &str) -> std::collections::HashSet<String> {
+    let mut names = std::collections::HashSet::new();
+    let mut lines = input.lines().peekable();
+    let mut in_importers = false;
+
+    while let Some(line) = lines.next() {
+        if line.trim_end() == "importers:" {
+            in_importers = true;
+            continue;
+        }
+        if !in_importers {
+            continue;
+        }
+        if line.trim() != "devDependencies:" {
+            continue;
+        }
+
+        let dev_indent = line.len() - line.trim_start().len();
+        let name_indent = match lines.peek() {
+            Some(next) if !next.trim().is_empty() => next.len() - next.trim_start().len(),
+            _ => continue,
+        };
+        if name_indent <= dev_indent {
+            continue;
+        }
+
+        while let Some(next_line) = lines.peek() {
+            if next_line.trim().is_empty() {
+                lines.next();
+                continue;
+            }
+            let indent = next_line.len() - next_line.trim_start().len();
+            if indent < name_indent {
+                break;
+            }
+            if indent == name_indent {
+                if let Some(name) = next_line.trim().strip_suffix(':') {
+                    names.insert(name.to_string());
+                }
+            }
+            lines.next();
+        }
+    }
+
+    names
+}
+
+/// A version split into `(major, minor, patch)` plus any `-prerelease` tag.
+struct VersionFields {
+    numeric: [u64; 3],
+    prerelease: Option<String>,
+}
+
+/// Parse a version string tolerantly: strip a leading `v`, split the core on
+/// `.` into up to three numeric fields, and split off any `-suffix` as the
+/// prerelease tag. Returns `None` if no field parses as a number at all, so
+/// callers can fall back to `Unknown`.
+fn parse_version_fields(version: &str) -> Option<VersionFields> {
+    let trimmed = version.trim().trim_start_matches('v');
+    let (core, prerelease) = match trimmed.split_once('-') {
+        Some((core, suffix)) => (core, Some(suffix.to_string())),
+        None => (trimmed, None),
+    };
+
+    let mut numeric = [0u64; 3];
+    let mut parsed_any = false;
+    for (field, slot) in core.split('.').zip(numeric.iter_mut()) {
+        *slot = field.parse().ok()?;
+        parsed_any = true;
+    }
+    if !parsed_any {
+        return None;
+    }
+
+    Some(VersionFields { numeric, prerelease })
+}
+
+/// Classify the severity of an update from `current` to `latest`, following
+/// semver field-by-field comparison. Returns `None` when the two strings are
+/// identical, i.e. not outdated.
+fn classify_update(current: &str, latest: &str) -> Option<UpdateSeverity> {
+    if current == latest {
+        return None;
+    }
+
+    let severity = match (parse_version_fields(current), parse_version_fields(latest)) {
+        (Some(cur), Some(new)) if cur.numeric[0] != new.numeric[0] => UpdateSeverity::Major,
+        (Some(cur), Some(new)) if cur.numeric[1] != new.numeric[1] => UpdateSeverity::Minor,
+        (Some(cur), Some(new)) if cur.numeric[2] != new.numeric[2] => UpdateSeverity::Patch,
+        (Some(cur), Some(new)) if cur.prerelease != new.prerelease => UpdateSeverity::Prerelease,
+        (Some(_), Some(_)) => UpdateSeverity::Unknown, // identical fields, raw strings still differ
+        _ => UpdateSeverity::Unknown,                  // one side failed to parse
+    };
+    Some(severity)
+}
+
+/// Whether `wanted` already points at `latest` (the declared range doesn't
+/// block the newest release), using the same field-by-field comparison as
+/// [`classify_update`]. Defaults to `true` when `wanted` is unknown.
+fn wanted_is_latest(wanted: Option<&str>, latest: &str) -> bool {
+    wanted
+        .map(|w| classify_update(w, latest).is_none())
+        .unwrap_or(true)
+}
+
 /// Parser for pnpm outdated output
 pub struct PnpmOutdatedParser;
 
@@ -171,10 +432,20 @@ impl OutputParser for PnpmOutdatedParser {
             Ok(json) => {
                 let mut dependencies = Vec::new();
                 let mut outdated_count = 0;
+                let mut major_count = 0;
+                let mut minor_count = 0;
+                let mut patch_count = 0;
 
                 for (name, pkg) in &json.packages {
-                    if pkg.current != pkg.latest {
+                    let severity = classify_update(&pkg.current, &pkg.latest);
+                    if let Some(severity) = severity {
                         outdated_count += 1;
+                        match severity {
+                            UpdateSeverity::Major => major_count += 1,
+                            UpdateSeverity::Minor => minor_count += 1,
+                            UpdateSeverity::Patch => patch_count += 1,
+                            UpdateSeverity::Prerelease | UpdateSeverity::Unknown => {}
+                        }
                     }
 
                     dependencies.push(Dependency {
@@ -183,12 +454,17 @@ impl OutputParser for PnpmOutdatedParser {
                         latest_version: Some(pkg.latest.clone()),
                         wanted_version: pkg.wanted.clone(),
                         dev_dependency: pkg.dependency_type == "devDependencies",
+                        update_severity: severity,
+                        wanted_is_latest: wanted_is_latest(pkg.wanted.as_deref(), &pkg.latest),
                     });
                 }
 
                 let result = DependencyState {
                     total_packages: dependencies.len(),
                     outdated_count,
+                    major_count,
+                    minor_count,
+                    patch_count,
                     dependencies,
                 };
 
@@ -214,6 +490,9 @@ impl OutputParser for PnpmOutdatedParser {
 fn extract_outdated_text(output: &str) -> Option<DependencyState> {
     let mut dependencies = Vec::new();
     let mut outdated_count = 0;
+    let mut major_count = 0;
+    let mut minor_count = 0;
+    let mut patch_count = 0;
 
     for line in output.lines() {
         // Skip box-drawing, headers, legend
@@ -234,17 +513,27 @@ fn extract_outdated_text(output: &str) -> Option<DependencyState> {
             let name = parts[0];
             let current = parts[1];
             let latest = parts[3];
+            let wanted = parts.get(2).copied();
 
-            if current != latest {
+            let severity = classify_update(current, latest);
+            if let Some(severity) = severity {
                 outdated_count += 1;
+                match severity {
+                    UpdateSeverity::Major => major_count += 1,
+                    UpdateSeverity::Minor => minor_count += 1,
+                    UpdateSeverity::Patch => patch_count += 1,
+                    UpdateSeverity::Prerelease | UpdateSeverity::Unknown => {}
+                }
             }
 
             dependencies.push(Dependency {
                 name: name.to_string(),
                 current_version: current.to_string(),
                 latest_version: Some(latest.to_string()),
-                wanted_version: parts.get(2).map(|s| s.to_string()),
+                wanted_version: wanted.map(|s| s.to_string()),
                 dev_dependency: false,
+                update_severity: severity,
+                wanted_is_latest: wanted_is_latest(wanted, latest),
             });
         }
     }
@@ -253,6 +542,9 @@ fn extract_outdated_text(output: &str) -> Option<DependencyState> {
         Some(DependencyState {
             total_packages: dependencies.len(),
             outdated_count,
+            major_count,
+            minor_count,
+            patch_count,
             dependencies,
         })
     } else {
@@ -276,24 +568,380 @@ fn is_valid_package_name(name: &str) -> bool {
         .all(|c| c.is_alphanumeric() || matches!(c, '@' | '/' | '-' | '_' | '.'))
 }
 
+/// Which half of `package.json` a dependency was declared in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DependencyScope {
+    Dev,
+    Prod,
+}
+
+/// A single field-selector + target-value predicate over a [`Dependency`],
+/// modeled on the same shape as `find`'s metadata filters.
+#[derive(Debug, Clone)]
+enum FilterPredicate {
+    /// Name matches a glob pattern, or failing that a plain substring (so
+    /// `--filter react` works without forcing users to write `*react*`).
+    Name(String),
+    Only(DependencyScope),
+    OutdatedOnly,
+}
+
+impl FilterPredicate {
+    fn matches(&self, dep: &Dependency) -> bool {
+        match self {
+            FilterPredicate::Name(pattern) => {
+                crate::find_cmd::glob_match(pattern, &dep.name) || dep.name.contains(pattern.as_str())
+            }
+            FilterPredicate::Only(DependencyScope::Dev) => dep.dev_dependency,
+            FilterPredicate::Only(DependencyScope::Prod) => !dep.dev_dependency,
+            FilterPredicate::OutdatedOnly => dep.update_severity.is_some(),
+        }
+    }
+}
+
+/// Narrows a [`DependencyState`]'s dependency list down to the ones matching
+/// every active predicate (`--filter`, `--only`, `--outdated-only`), ANDed
+/// together.
+#[derive(Debug, Clone, Default)]
+pub struct DependencyFilter {
+    predicates: Vec<FilterPredicate>,
+}
+
+impl DependencyFilter {
+    pub fn new(name_glob: Option<&str>, only: Option<&str>, outdated_only: bool) -> Result<Self> {
+        let mut predicates = Vec::new();
+
+        if let Some(pattern) = name_glob {
+            predicates.push(FilterPredicate::Name(pattern.to_string()));
+        }
+
+        if let Some(scope) = only {
+            let scope = match scope {
+                "dev" => DependencyScope::Dev,
+                "prod" => DependencyScope::Prod,
+                other => bail!("rtk pnpm: --only must be 'dev' or 'prod', got '{}'", other),
+            };
+            predicates.push(FilterPredicate::Only(scope));
+        }
+
+        if outdated_only {
+            predicates.push(FilterPredicate::OutdatedOnly);
+        }
+
+        Ok(Self { predicates })
+    }
+
+    fn is_noop(&self) -> bool {
+        self.predicates.is_empty()
+    }
+
+    fn matches(&self, dep: &Dependency) -> bool {
+        self.predicates.iter().all(|p| p.matches(dep))
+    }
+}
+
+/// Apply `filter` to `state.dependencies` and recompute the aggregate counts
+/// against the filtered set, so `format()` reports e.g. "2 outdated (of 2)"
+/// rather than the pre-filter totals.
+fn apply_filter(mut state: DependencyState, filter: &DependencyFilter) -> DependencyState {
+    if filter.is_noop() {
+        return state;
+    }
+
+    state.dependencies.retain(|dep| filter.matches(dep));
+    state.total_packages = state.dependencies.len();
+    state.outdated_count = state
+        .dependencies
+        .iter()
+        .filter(|d| d.update_severity.is_some())
+        .count();
+    state.major_count = state
+        .dependencies
+        .iter()
+        .filter(|d| d.update_severity == Some(UpdateSeverity::Major))
+        .count();
+    state.minor_count = state
+        .dependencies
+        .iter()
+        .filter(|d| d.update_severity == Some(UpdateSeverity::Minor))
+        .count();
+    state.patch_count = state
+        .dependencies
+        .iter()
+        .filter(|d| d.update_severity == Some(UpdateSeverity::Patch))
+        .count();
+
+    state
+}
+
+/// The three declared-range shapes `rtk pnpm upgrade` understands: `^1.2.3`
+/// allows any same-major bump, `~1.2.3` allows any same-major-minor bump,
+/// and an exact pin (`1.2.3`) allows none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RangeKind {
+    Caret,
+    Tilde,
+    Exact,
+}
+
+fn parse_range_prefix(range: &str) -> (RangeKind, &str) {
+    let trimmed = range.trim();
+    if let Some(rest) = trimmed.strip_prefix('^') {
+        (RangeKind::Caret, rest)
+    } else if let Some(rest) = trimmed.strip_prefix('~') {
+        (RangeKind::Tilde, rest)
+    } else {
+        (RangeKind::Exact, trimmed)
+    }
+}
+
+/// Whether `candidate` satisfies `declared`'s range, per the simplified
+/// caret/tilde/exact rules above. Full npm range syntax (`||`, `x`
+/// wildcards, `>=` comparators) is out of scope; anything that doesn't parse
+/// as a plain `major.minor.patch` is treated as incompatible.
+fn is_range_compatible(declared: &str, candidate: &str) -> bool {
+    let (kind, base) = parse_range_prefix(declared);
+    let (Some(base_fields), Some(candidate_fields)) =
+        (parse_version_fields(base), parse_version_fields(candidate))
+    else {
+        return false;
+    };
+
+    match kind {
+        RangeKind::Caret => base_fields.numeric[0] == candidate_fields.numeric[0],
+        RangeKind::Tilde => {
+            base_fields.numeric[0] == candidate_fields.numeric[0]
+                && base_fields.numeric[1] == candidate_fields.numeric[1]
+        }
+        RangeKind::Exact => false,
+    }
+}
+
+/// Read `package.json`'s `dependencies`/`devDependencies` as a map of
+/// package name -> declared range string (e.g. `"^4.18.0"`), tolerating a
+/// missing or unparseable file the same way `deps::detect_node_stack` does.
+fn read_declared_ranges() -> HashMap<String, String> {
+    let mut ranges = HashMap::new();
+    let Ok(content) = std::fs::read_to_string("package.json") else {
+        return ranges;
+    };
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) else {
+        return ranges;
+    };
+
+    for key in ["dependencies", "devDependencies"] {
+        if let Some(obj) = json.get(key).and_then(|v| v.as_object()) {
+            for (name, value) in obj {
+                if let Some(range) = value.as_str() {
+                    ranges.insert(name.clone(), range.to_string());
+                }
+            }
+        }
+    }
+
+    ranges
+}
+
+/// Dependency names that imply a frontend framework is in use, keyed to
+/// the label reported in [`EnvInfo::frameworks`]. Checked against both
+/// `dependencies` and `devDependencies`, in table order, so a project
+/// using more than one (e.g. a Next.js app with Storybook) reports all of
+/// them rather than just the first match.
+const FRAMEWORK_MARKERS: &[(&str, &str)] = &[
+    ("next", "Next.js"),
+    ("nuxt", "Nuxt"),
+    ("react", "React"),
+    ("vue", "Vue"),
+    ("svelte", "Svelte"),
+    ("@angular/core", "Angular"),
+    ("solid-js", "Solid"),
+    ("astro", "Astro"),
+];
+
+/// Declared `package.json` name/version plus its full dependency name set
+/// (both `dependencies` and `devDependencies`), read the same tolerant way
+/// as [`read_declared_ranges`].
+struct PackageManifest {
+    name: Option<String>,
+    version: Option<String>,
+    /// (name, is_dev) for every declared dependency.
+    deps: Vec<(String, bool)>,
+}
+
+fn parse_package_json() -> PackageManifest {
+    let mut manifest = PackageManifest {
+        name: None,
+        version: None,
+        deps: Vec::new(),
+    };
+
+    let Ok(content) = std::fs::read_to_string("package.json") else {
+        return manifest;
+    };
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) else {
+        return manifest;
+    };
+
+    manifest.name = json.get("name").and_then(|v| v.as_str()).map(String::from);
+    manifest.version = json
+        .get("version")
+        .and_then(|v| v.as_str())
+        .map(String::from);
+
+    for (key, is_dev) in [("dependencies", false), ("devDependencies", true)] {
+        if let Some(obj) = json.get(key).and_then(|v| v.as_object()) {
+            for name in obj.keys() {
+                manifest.deps.push((name.clone(), is_dev));
+            }
+        }
+    }
+
+    manifest
+}
+
+/// Which of `manifest`'s declared dependency names imply a known frontend
+/// framework, in [`FRAMEWORK_MARKERS`] order.
+fn detect_frameworks(manifest: &PackageManifest) -> Vec<String> {
+    FRAMEWORK_MARKERS
+        .iter()
+        .filter(|(marker, _)| manifest.deps.iter().any(|(name, _)| name == marker))
+        .map(|(_, label)| label.to_string())
+        .collect()
+}
+
+/// Declared dependency names resolved in `pnpm-lock.yaml`, via the same
+/// [`PnpmLockParser`] that backs `rtk pnpm list --offline` - whichever tier
+/// it manages to parse is good enough here, since all `collect_env_info`
+/// needs is "was this name found at all".
+fn read_lockfile_resolved() -> std::collections::HashSet<String> {
+    let Ok(content) = std::fs::read_to_string("pnpm-lock.yaml") else {
+        return std::collections::HashSet::new();
+    };
+
+    match PnpmLockParser::parse(&content) {
+        ParseResult::Full(state) | ParseResult::Degraded(state, _) => {
+            state.dependencies.into_iter().map(|dep| dep.name).collect()
+        }
+        ParseResult::Passthrough(_) => std::collections::HashSet::new(),
+    }
+}
+
+/// Runs `<cmd> --version` and returns the trimmed stdout, or `None` if the
+/// command isn't installed or exits non-zero.
+fn command_version(cmd: &str) -> Option<String> {
+    let output = Command::new(cmd).arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if version.is_empty() {
+        None
+    } else {
+        Some(version)
+    }
+}
+
+/// Builds the `rtk pnpm info` snapshot: declared dependencies from
+/// `package.json`, cross-referenced against `pnpm-lock.yaml`, plus
+/// `pnpm`/`node` toolchain versions and any detected frontend framework.
+fn collect_env_info() -> EnvInfo {
+    let manifest = parse_package_json();
+    let frameworks = detect_frameworks(&manifest);
+    let resolved = read_lockfile_resolved();
+
+    let total_declared = manifest.deps.len();
+    let dev_declared = manifest.deps.iter().filter(|(_, is_dev)| *is_dev).count();
+
+    let unresolved: Vec<String> = if resolved.is_empty() {
+        Vec::new()
+    } else {
+        manifest
+            .deps
+            .iter()
+            .filter(|(name, _)| !resolved.contains(name))
+            .map(|(name, _)| name.clone())
+            .collect()
+    };
+    let resolved_count = if resolved.is_empty() {
+        total_declared
+    } else {
+        total_declared - unresolved.len()
+    };
+
+    EnvInfo {
+        project_name: manifest.name,
+        project_version: manifest.version,
+        pnpm_version: command_version("pnpm"),
+        node_version: command_version("node"),
+        frameworks,
+        total_declared,
+        dev_declared,
+        resolved_count,
+        unresolved,
+    }
+}
+
+/// A single dependency bump `rtk pnpm upgrade` has decided to apply.
+struct PlannedUpgrade {
+    name: String,
+    from: String,
+    to: String,
+    /// Major-version (or declared-range-incompatible) bump, applied only
+    /// because `--to-latest` was passed.
+    breaking: bool,
+}
+
 #[derive(Debug, Clone)]
 pub enum PnpmCommand {
-    List { depth: usize },
-    Outdated,
-    Install { packages: Vec<String> },
+    List {
+        depth: usize,
+        filter: DependencyFilter,
+        /// Read `pnpm-lock.yaml` directly instead of running `pnpm list --json`.
+        offline: bool,
+    },
+    Outdated {
+        filter: DependencyFilter,
+    },
+    Install {
+        packages: Vec<String>,
+    },
+    Upgrade {
+        to_latest: bool,
+        dry_run: bool,
+    },
+    Info,
 }
 
 pub fn run(cmd: PnpmCommand, args: &[String], verbose: u8) -> Result<()> {
     match cmd {
-        PnpmCommand::List { depth } => run_list(depth, args, verbose),
-        PnpmCommand::Outdated => run_outdated(args, verbose),
+        PnpmCommand::List {
+            depth,
+            filter,
+            offline,
+        } => run_list(depth, args, verbose, &filter, offline),
+        PnpmCommand::Outdated { filter } => run_outdated(args, verbose, &filter),
         PnpmCommand::Install { packages } => run_install(&packages, args, verbose),
+        PnpmCommand::Upgrade {
+            to_latest,
+            dry_run,
+        } => run_upgrade(args, verbose, to_latest, dry_run),
+        PnpmCommand::Info => run_info(verbose),
     }
 }
 
-fn run_list(depth: usize, args: &[String], verbose: u8) -> Result<()> {
+fn run_list(
+    depth: usize,
+    args: &[String],
+    verbose: u8,
+    filter: &DependencyFilter,
+    offline: bool,
+) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
+    if offline {
+        return run_list_offline(verbose, filter, &timer);
+    }
+
     let mut cmd = Command::new("pnpm");
     cmd.arg("list");
     cmd.arg(format!("--depth={}", depth));
@@ -321,13 +969,13 @@ fn run_list(depth: usize, args: &[String], verbose: u8) -> Result<()> {
             if verbose > 0 {
                 eprintln!("pnpm list (Tier 1: Full JSON parse)");
             }
-            data.format(mode)
+            apply_filter(data, filter).format(mode)
         }
         ParseResult::Degraded(data, warnings) => {
             if verbose > 0 {
                 emit_degradation_warning("pnpm list", &warnings.join(", "));
             }
-            data.format(mode)
+            apply_filter(data, filter).format(mode)
         }
         ParseResult::Passthrough(raw) => {
             emit_passthrough_warning("pnpm list", "All parsing tiers failed");
@@ -347,7 +995,45 @@ fn run_list(depth: usize, args: &[String], verbose: u8) -> Result<()> {
     Ok(())
 }
 
-fn run_outdated(args: &[String], verbose: u8) -> Result<()> {
+/// `rtk pnpm list --offline`: reads `pnpm-lock.yaml` directly via
+/// [`PnpmLockParser`] instead of running `pnpm list --json`, for
+/// deterministic, network-free output in CI or sandboxes.
+fn run_list_offline(
+    verbose: u8,
+    filter: &DependencyFilter,
+    timer: &tracking::TimedExecution,
+) -> Result<()> {
+    let content = std::fs::read_to_string("pnpm-lock.yaml")
+        .context("Failed to read pnpm-lock.yaml (required for --offline)")?;
+
+    let mode = FormatMode::from_verbosity(verbose);
+    let filtered = match PnpmLockParser::parse(&content) {
+        ParseResult::Full(data) => apply_filter(data, filter).format(mode),
+        ParseResult::Degraded(data, warnings) => {
+            if verbose > 0 {
+                emit_degradation_warning("pnpm list --offline", &warnings.join(", "));
+            }
+            apply_filter(data, filter).format(mode)
+        }
+        ParseResult::Passthrough(raw) => {
+            emit_passthrough_warning("pnpm list --offline", "All parsing tiers failed");
+            raw
+        }
+    };
+
+    println!("{}", filtered);
+
+    timer.track(
+        "pnpm-lock.yaml",
+        "rtk pnpm list --offline",
+        &content,
+        &filtered,
+    );
+
+    Ok(())
+}
+
+fn run_outdated(args: &[String], verbose: u8, filter: &DependencyFilter) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
     let mut cmd = Command::new("pnpm");
@@ -373,13 +1059,13 @@ fn run_outdated(args: &[String], verbose: u8) -> Result<()> {
             if verbose > 0 {
                 eprintln!("pnpm outdated (Tier 1: Full JSON parse)");
             }
-            data.format(mode)
+            apply_filter(data, filter).format(mode)
         }
         ParseResult::Degraded(data, warnings) => {
             if verbose > 0 {
                 emit_degradation_warning("pnpm outdated", &warnings.join(", "));
             }
-            data.format(mode)
+            apply_filter(data, filter).format(mode)
         }
         ParseResult::Passthrough(raw) => {
             emit_passthrough_warning("pnpm outdated", "All parsing tiers failed");
@@ -398,6 +1084,179 @@ fn run_outdated(args: &[String], verbose: u8) -> Result<()> {
     Ok(())
 }
 
+/// Apply only semver-compatible updates from `pnpm outdated`: by default, a
+/// package is bumped to `wanted` when its declared `package.json` range
+/// already permits it; with `--to-latest`, every package is bumped to
+/// `latest` instead, with major-version (or out-of-range) jumps flagged as
+/// breaking rather than silently skipped.
+fn run_upgrade(args: &[String], verbose: u8, to_latest: bool, dry_run: bool) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let mut cmd = Command::new("pnpm");
+    cmd.arg("outdated").arg("--format").arg("json");
+
+    for arg in args {
+        cmd.arg(arg);
+    }
+
+    let output = cmd.output().context("Failed to run pnpm outdated")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let data = match PnpmOutdatedParser::parse(&stdout) {
+        ParseResult::Full(data) => data,
+        ParseResult::Degraded(data, warnings) => {
+            if verbose > 0 {
+                emit_degradation_warning("pnpm upgrade", &warnings.join(", "));
+            }
+            data
+        }
+        ParseResult::Passthrough(_) => {
+            emit_passthrough_warning("pnpm upgrade", "All parsing tiers failed");
+            let msg = "rtk pnpm upgrade: could not parse `pnpm outdated` output, nothing to do";
+            println!("{}", msg);
+            timer.track(
+                "pnpm outdated --format json",
+                "rtk pnpm upgrade",
+                &stdout,
+                msg,
+            );
+            return Ok(());
+        }
+    };
+
+    let declared_ranges = read_declared_ranges();
+
+    let mut applied = Vec::new();
+    let mut skipped = Vec::new();
+
+    for dep in &data.dependencies {
+        let Some(latest) = dep.latest_version.as_deref() else {
+            continue;
+        };
+        let target = if to_latest {
+            latest
+        } else {
+            dep.wanted_version.as_deref().unwrap_or(latest)
+        };
+        if target == dep.current_version {
+            continue;
+        }
+
+        let breaking = matches!(
+            classify_update(&dep.current_version, target),
+            Some(UpdateSeverity::Major)
+        );
+
+        if to_latest {
+            applied.push(PlannedUpgrade {
+                name: dep.name.clone(),
+                from: dep.current_version.clone(),
+                to: target.to_string(),
+                breaking,
+            });
+            continue;
+        }
+
+        let compatible = declared_ranges
+            .get(&dep.name)
+            .is_some_and(|range| is_range_compatible(range, target));
+        if compatible {
+            applied.push(PlannedUpgrade {
+                name: dep.name.clone(),
+                from: dep.current_version.clone(),
+                to: target.to_string(),
+                breaking: false,
+            });
+        } else {
+            skipped.push(dep.name.clone());
+        }
+    }
+
+    let mut report = if dry_run {
+        "rtk pnpm upgrade (dry-run):\n".to_string()
+    } else {
+        "rtk pnpm upgrade:\n".to_string()
+    };
+
+    if applied.is_empty() {
+        report.push_str("  (nothing to upgrade)\n");
+    }
+    for up in &applied {
+        let marker = if up.breaking { " (breaking)" } else { "" };
+        report.push_str(&format!(
+            "  {}: {} → {}{}\n",
+            up.name, up.from, up.to, marker
+        ));
+    }
+    for name in &skipped {
+        report.push_str(&format!("  {}: skipped (out of declared range)\n", name));
+    }
+
+    if !dry_run {
+        for up in &applied {
+            if !is_valid_package_name(&up.name) {
+                continue;
+            }
+            let spec = format!("{}@{}", up.name, up.to);
+            if verbose > 0 {
+                eprintln!("Running: pnpm add {}", spec);
+            }
+            let status = Command::new("pnpm")
+                .arg("add")
+                .arg(&spec)
+                .status()
+                .with_context(|| format!("Failed to run pnpm add {}", spec))?;
+            if !status.success() {
+                eprintln!(
+                    "rtk pnpm upgrade: `pnpm add {}` exited with {:?}",
+                    spec,
+                    status.code()
+                );
+            }
+        }
+    }
+
+    report.push_str(&format!(
+        "\n{} applied, {} skipped as breaking\n",
+        applied.len(),
+        skipped.len()
+    ));
+
+    println!("{}", report.trim());
+
+    timer.track(
+        "pnpm outdated --format json",
+        &format!(
+            "rtk pnpm upgrade{}{}",
+            if to_latest { " --to-latest" } else { "" },
+            if dry_run { " --dry-run" } else { "" }
+        ),
+        &stdout,
+        &report,
+    );
+
+    Ok(())
+}
+
+fn run_info(verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let info = collect_env_info();
+    let mode = FormatMode::from_verbosity(verbose);
+    let formatted = info.format(mode);
+
+    println!("{}", formatted);
+
+    timer.track(
+        "package.json + pnpm-lock.yaml",
+        "rtk pnpm info",
+        &format!("{:?}", info),
+        &formatted,
+    );
+
+    Ok(())
+}
+
 fn run_install(packages: &[String], args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
@@ -554,6 +1413,99 @@ mod tests {
         let data = result.unwrap();
         assert_eq!(data.outdated_count, 1);
         assert_eq!(data.dependencies[0].name, "express");
+        assert_eq!(data.dependencies[0].update_severity, Some(UpdateSeverity::Minor));
+        assert_eq!(data.minor_count, 1);
+        assert!(data.dependencies[0].wanted_is_latest);
+    }
+
+    #[test]
+    fn test_classify_update() {
+        assert_eq!(classify_update("1.2.3", "2.0.0"), Some(UpdateSeverity::Major));
+        assert_eq!(classify_update("1.2.3", "1.3.0"), Some(UpdateSeverity::Minor));
+        assert_eq!(classify_update("1.2.3", "1.2.4"), Some(UpdateSeverity::Patch));
+        assert_eq!(
+            classify_update("1.2.3", "1.2.3-beta.1"),
+            Some(UpdateSeverity::Prerelease)
+        );
+        assert_eq!(classify_update("1.2.3", "1.2.3"), None);
+        assert_eq!(classify_update("abc", "1.0.0"), Some(UpdateSeverity::Unknown));
+        assert_eq!(classify_update("v1.2.3", "v1.2.4"), Some(UpdateSeverity::Patch));
+    }
+
+    #[test]
+    fn test_wanted_is_latest() {
+        assert!(wanted_is_latest(Some("1.2.4"), "1.2.4"));
+        assert!(!wanted_is_latest(Some("1.2.3"), "1.2.4"));
+        assert!(wanted_is_latest(None, "1.2.4"));
+    }
+
+    #[test]
+    fn test_extract_outdated_text_classifies_severity() {
+        let text = "Package   Current  Wanted  Latest\nexpress   4.18.2   4.18.2  5.0.0\n";
+        let data = extract_outdated_text(text).expect("should parse outdated text");
+        assert_eq!(data.outdated_count, 1);
+        assert_eq!(data.major_count, 1);
+        assert_eq!(
+            data.dependencies[0].update_severity,
+            Some(UpdateSeverity::Major)
+        );
+        assert!(!data.dependencies[0].wanted_is_latest);
+    }
+
+    #[test]
+    fn test_dependency_filter_name_glob() {
+        let filter = DependencyFilter::new(Some("@scope/*"), None, false).unwrap();
+        let dep = |name: &str| Dependency {
+            name: name.to_string(),
+            current_version: "1.0.0".to_string(),
+            latest_version: None,
+            wanted_version: None,
+            dev_dependency: false,
+            update_severity: None,
+            wanted_is_latest: true,
+        };
+        assert!(filter.matches(&dep("@scope/widget")));
+        assert!(!filter.matches(&dep("lodash")));
+    }
+
+    #[test]
+    fn test_dependency_filter_only_rejects_bad_value() {
+        assert!(DependencyFilter::new(None, Some("bogus"), false).is_err());
+        assert!(DependencyFilter::new(None, Some("dev"), false).is_ok());
+    }
+
+    #[test]
+    fn test_apply_filter_recomputes_counts() {
+        let json = r#"{
+            "express": { "current": "4.18.2", "latest": "5.0.0", "wanted": "4.18.2" },
+            "left-pad": { "current": "1.0.0", "latest": "1.0.1", "wanted": "1.0.1" }
+        }"#;
+        let data = PnpmOutdatedParser::parse(json).unwrap();
+        assert_eq!(data.outdated_count, 2);
+
+        let filter = DependencyFilter::new(Some("express"), None, false).unwrap();
+        let filtered = apply_filter(data, &filter);
+        assert_eq!(filtered.total_packages, 1);
+        assert_eq!(filtered.outdated_count, 1);
+        assert_eq!(filtered.major_count, 1);
+        assert_eq!(filtered.patch_count, 0);
+    }
+
+    #[test]
+    fn test_is_range_compatible() {
+        assert!(is_range_compatible("^4.18.0", "4.19.0"));
+        assert!(!is_range_compatible("^4.18.0", "5.0.0"));
+        assert!(is_range_compatible("~4.18.0", "4.18.5"));
+        assert!(!is_range_compatible("~4.18.0", "4.19.0"));
+        assert!(!is_range_compatible("4.18.0", "4.18.1"));
+        assert!(!is_range_compatible("^4.18.0", "not-a-version"));
+    }
+
+    #[test]
+    fn test_parse_range_prefix() {
+        assert_eq!(parse_range_prefix("^1.2.3"), (RangeKind::Caret, "1.2.3"));
+        assert_eq!(parse_range_prefix("~1.2.3"), (RangeKind::Tilde, "1.2.3"));
+        assert_eq!(parse_range_prefix("1.2.3"), (RangeKind::Exact, "1.2.3"));
     }
 
     #[test]
@@ -570,4 +1522,99 @@ mod tests {
         let _args: Vec<OsString> = vec![OsString::from("help")];
         // Compile-time verification that the function exists with correct signature
     }
+
+    #[test]
+    fn test_split_lock_package_key() {
+        assert_eq!(
+            split_lock_package_key("lodash@4.17.21"),
+            Some(("lodash".to_string(), "4.17.21".to_string()))
+        );
+        assert_eq!(
+            split_lock_package_key("/react@18.2.0"),
+            Some(("react".to_string(), "18.2.0".to_string()))
+        );
+        assert_eq!(
+            split_lock_package_key("/@scope/pkg@1.2.3"),
+            Some(("@scope/pkg".to_string(), "1.2.3".to_string()))
+        );
+        assert_eq!(
+            split_lock_package_key("/react-dom@18.2.0(react@18.2.0)"),
+            Some(("react-dom".to_string(), "18.2.0".to_string()))
+        );
+        assert_eq!(split_lock_package_key("resolution"), None);
+    }
+
+    #[test]
+    fn test_pnpm_lock_parser_packages_section() {
+        let lockfile = "lockfileVersion: '6.0'\n\
+importers:\n  \
+  .:\n    \
+    dependencies:\n      \
+      react:\n        \
+        specifier: ^18.2.0\n        \
+        version: 18.2.0\n    \
+    devDependencies:\n      \
+      typescript:\n        \
+        specifier: ^5.0.0\n        \
+        version: 5.0.0\n\n\
+packages:\n\n  \
+  /react@18.2.0:\n    \
+    resolution: {integrity: sha512-abc}\n  \
+  /typescript@5.0.0:\n    \
+    resolution: {integrity: sha512-def}\n";
+
+        let state = match PnpmLockParser::parse(lockfile) {
+            ParseResult::Full(state) => state,
+            other => panic!("expected Full, got {:?}", other.tier()),
+        };
+
+        assert_eq!(state.total_packages, 2);
+        let typescript = state
+            .dependencies
+            .iter()
+            .find(|d| d.name == "typescript")
+            .unwrap();
+        assert!(typescript.dev_dependency);
+        let react = state.dependencies.iter().find(|d| d.name == "react").unwrap();
+        assert!(!react.dev_dependency);
+    }
+
+    #[test]
+    fn test_pnpm_lock_parser_loose_fallback() {
+        let lockfile = "not really yaml, but has a line like:\nlodash@4.17.21:\n  some: field\n";
+        let state = match PnpmLockParser::parse(lockfile) {
+            ParseResult::Degraded(state, _) => state,
+            other => panic!("expected Degraded, got {:?}", other.tier()),
+        };
+        assert_eq!(state.total_packages, 1);
+        assert_eq!(state.dependencies[0].name, "lodash");
+    }
+
+    #[test]
+    fn test_pnpm_lock_parser_passthrough_on_empty() {
+        matches!(PnpmLockParser::parse(""), ParseResult::Passthrough(_));
+    }
+
+    #[test]
+    fn test_detect_frameworks_matches_known_markers() {
+        let manifest = PackageManifest {
+            name: None,
+            version: None,
+            deps: vec![
+                ("react".to_string(), false),
+                ("typescript".to_string(), true),
+            ],
+        };
+        assert_eq!(detect_frameworks(&manifest), vec!["React".to_string()]);
+    }
+
+    #[test]
+    fn test_detect_frameworks_empty_when_no_markers() {
+        let manifest = PackageManifest {
+            name: None,
+            version: None,
+            deps: vec![("lodash".to_string(), false)],
+        };
+        assert!(detect_frameworks(&manifest).is_empty());
+    }
 }
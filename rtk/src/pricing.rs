@@ -0,0 +1,73 @@
+//! Static USD pricing table for Claude models.
+//!
+//! Used to turn token savings into dollar figures (`rtk discover`'s cost
+//! column, `rtk gain --quota`'s "cost preserved" line) without hardcoding a
+//! single rate everywhere — a new model is just a new table row.
+
+/// Per-million-token input/output rates for one model.
+#[derive(Debug, Clone, Copy)]
+pub struct ModelPricing {
+    pub model: &'static str,
+    pub input_per_million: f64,
+    pub output_per_million: f64,
+}
+
+/// Built-in pricing profiles (verified Feb 2026).
+/// Source: <https://docs.anthropic.com/en/docs/about-claude/models>
+pub const PRICING_TABLE: &[ModelPricing] = &[
+    ModelPricing {
+        model: "claude-opus-4",
+        input_per_million: 15.0,
+        output_per_million: 75.0,
+    },
+    ModelPricing {
+        model: "claude-sonnet-4",
+        input_per_million: 3.0,
+        output_per_million: 15.0,
+    },
+    ModelPricing {
+        model: "claude-haiku-4",
+        input_per_million: 0.8,
+        output_per_million: 4.0,
+    },
+];
+
+/// Pricing profile used when `--model` is omitted.
+pub const DEFAULT_MODEL: &str = "claude-sonnet-4";
+
+/// Look up `model` in [`PRICING_TABLE`], falling back to [`DEFAULT_MODEL`]
+/// for an unknown name.
+pub fn rates_for(model: &str) -> ModelPricing {
+    PRICING_TABLE
+        .iter()
+        .find(|p| p.model == model)
+        .copied()
+        .unwrap_or_else(|| {
+            PRICING_TABLE
+                .iter()
+                .find(|p| p.model == DEFAULT_MODEL)
+                .copied()
+                .expect("DEFAULT_MODEL must exist in PRICING_TABLE")
+        })
+}
+
+/// Resolve effective rates from CLI-style overrides: explicit
+/// `--input-price`/`--output-price` win over the named `--model` profile,
+/// which itself defaults to [`DEFAULT_MODEL`].
+pub fn resolve_rates(
+    model: Option<&str>,
+    input_price: Option<f64>,
+    output_price: Option<f64>,
+) -> ModelPricing {
+    let base = rates_for(model.unwrap_or(DEFAULT_MODEL));
+    ModelPricing {
+        model: base.model,
+        input_per_million: input_price.unwrap_or(base.input_per_million),
+        output_per_million: output_price.unwrap_or(base.output_per_million),
+    }
+}
+
+/// Estimate USD cost for `tokens` at a given per-million-token rate.
+pub fn estimate_usd(tokens: usize, per_million: f64) -> f64 {
+    tokens as f64 * per_million / 1_000_000.0
+}
@@ -1,5 +1,8 @@
+use crate::parser::{json_output_requested, JsonFormatter};
+use crate::sql_ddl::{self, DdlChanges, Statement};
 use crate::tracking;
 use anyhow::{Context, Result};
+use std::collections::BTreeMap;
 use std::process::Command;
 
 #[derive(Debug, Clone)]
@@ -7,6 +10,8 @@ pub enum PrismaCommand {
     Generate,
     Migrate { subcommand: MigrateSubcommand },
     DbPush,
+    Format,
+    Validate,
 }
 
 #[derive(Debug, Clone)]
@@ -21,6 +26,8 @@ pub fn run(cmd: PrismaCommand, args: &[String], verbose: u8) -> Result<()> {
         PrismaCommand::Generate => run_generate(args, verbose),
         PrismaCommand::Migrate { subcommand } => run_migrate(subcommand, args, verbose),
         PrismaCommand::DbPush => run_db_push(args, verbose),
+        PrismaCommand::Format => run_format(args, verbose),
+        PrismaCommand::Validate => run_validate(args, verbose),
     }
 }
 
@@ -165,12 +172,110 @@ fn run_db_push(args: &[String], verbose: u8) -> Result<()> {
     Ok(())
 }
 
-/// Filter prisma generate output - strip ASCII art, extract counts
+fn run_format(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let mut cmd = create_prisma_command();
+    cmd.arg("format");
+
+    for arg in args {
+        cmd.arg(arg);
+    }
+
+    if verbose > 0 {
+        eprintln!("Running: prisma format");
+    }
+
+    let output = cmd.output().context("Failed to run prisma format")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("prisma format failed: {}", stderr);
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+    let filtered = filter_prisma_format(&raw);
+
+    println!("{}", filtered);
+
+    timer.track("prisma format", "rtk prisma format", &raw, &filtered);
+
+    Ok(())
+}
+
+fn run_validate(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let mut cmd = create_prisma_command();
+    cmd.arg("validate");
+
+    for arg in args {
+        cmd.arg(arg);
+    }
+
+    if verbose > 0 {
+        eprintln!("Running: prisma validate");
+    }
+
+    let output = cmd.output().context("Failed to run prisma validate")?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+    let filtered = filter_prisma_validate(&raw, output.status.success());
+
+    println!("{}", filtered);
+
+    timer.track("prisma validate", "rtk prisma validate", &raw, &filtered);
+
+    if !output.status.success() {
+        anyhow::bail!("prisma validate failed");
+    }
+
+    Ok(())
+}
+
+/// One `✔ Generated <name> (<version>) to <path> in <duration>` block.
+#[derive(Debug, Clone, PartialEq)]
+struct GeneratedOutput {
+    name: String,
+    version: String,
+    path: String,
+}
+
+/// Parse a single `✔/✓ Generated <name> (<version>) to <path> in
+/// <duration>` line, whatever the generator (`prisma-client-js`,
+/// `prisma-client-rust`, a custom one, ...) and wherever it writes.
+fn parse_generated_line(line: &str) -> Option<GeneratedOutput> {
+    let trimmed = line.trim();
+    let rest = trimmed
+        .strip_prefix("✔ Generated ")
+        .or_else(|| trimmed.strip_prefix("✓ Generated "))?;
+
+    let paren_start = rest.find('(')?;
+    let paren_end = rest[paren_start..].find(')')? + paren_start;
+    let name = rest[..paren_start].trim().to_string();
+    let version = rest[paren_start + 1..paren_end].to_string();
+
+    let after_paren = rest[paren_end + 1..].trim_start().strip_prefix("to ")?;
+    let in_pos = after_paren.rfind(" in ")?;
+    let path = after_paren[..in_pos].trim().to_string();
+
+    Some(GeneratedOutput { name, version, path })
+}
+
+/// Filter prisma generate output - strip ASCII art, extract counts, and
+/// report one bullet per generator actually run (a schema can declare
+/// several, each with its own output path - e.g. `prisma-client-js` to
+/// `node_modules/@prisma/client` alongside `prisma-client-rust` to
+/// `src/prisma.rs`).
 fn filter_prisma_generate(output: &str) -> String {
     let mut models = 0;
     let mut enums = 0;
     let mut types = 0;
-    let mut output_path = String::new();
+    let mut generated = Vec::new();
 
     for line in output.lines() {
         // Skip ASCII art and box drawing
@@ -201,9 +306,8 @@ fn filter_prisma_generate(output: &str) -> String {
             }
         }
 
-        // Extract output path
-        if line.contains("node_modules") && line.contains("@prisma") {
-            output_path = line.trim().to_string();
+        if let Some(block) = parse_generated_line(line) {
+            generated.push(block);
         }
     }
 
@@ -217,8 +321,11 @@ fn filter_prisma_generate(output: &str) -> String {
         ));
     }
 
-    if !output_path.is_empty() {
-        result.push_str("  • Output: node_modules/@prisma/client\n");
+    for block in &generated {
+        result.push_str(&format!(
+            "  • {} ({}) -> {}\n",
+            block.name, block.version, block.path
+        ));
     }
 
     result.trim().to_string()
@@ -227,10 +334,6 @@ fn filter_prisma_generate(output: &str) -> String {
 /// Filter migrate dev output - extract migration changes
 fn filter_migrate_dev(output: &str) -> String {
     let mut migration_name = String::new();
-    let mut tables_added = 0;
-    let mut tables_modified = 0;
-    let mut relations = Vec::new();
-    let mut indexes = Vec::new();
     let mut applied = false;
 
     for line in output.lines() {
@@ -243,30 +346,41 @@ fn filter_migrate_dev(output: &str) -> String {
                 migration_name = line[pos..pos + end].to_string();
             }
         }
-
-        // Count changes
-        if line.contains("CREATE TABLE") {
-            tables_added += 1;
-        }
-        if line.contains("ALTER TABLE") {
-            tables_modified += 1;
-        }
-        if line.contains("FOREIGN KEY") || line.contains("REFERENCES") {
-            if let Some(table) = extract_table_name(line) {
-                relations.push(table);
-            }
-        }
-        if line.contains("CREATE INDEX") || line.contains("CREATE UNIQUE INDEX") {
-            if let Some(idx) = extract_index_name(line) {
-                indexes.push(idx);
-            }
-        }
-
         if line.contains("applied") || line.contains("✓") {
             applied = true;
         }
     }
 
+    // Classify the embedded SQL statement-by-statement (instead of
+    // scanning individual lines for substrings) so counts stay accurate
+    // regardless of how the migration's SQL is wrapped or commented, then
+    // bucket by schema namespace when any table carries a `"schema".`
+    // prefix (defaulting unqualified identifiers to `public`).
+    let sql = sql_ddl::extract_sql_block(output);
+    let statements = sql_ddl::parse_statements_with_source(sql);
+    let mut schemas: BTreeMap<String, DdlChanges> = BTreeMap::new();
+    let mut any_qualified = false;
+    let mut relations = Vec::new();
+
+    for (statement, raw) in &statements {
+        if let Some(table) = statement_table(statement) {
+            any_qualified |= table.schema.is_some();
+            let schema = table.schema.clone().unwrap_or_else(|| "public".to_string());
+            schemas.entry(schema).or_default().record_statement(statement);
+        }
+        relations.extend(sql_ddl::relations_in(raw));
+    }
+
+    if json_output_requested() {
+        let summary = sql_ddl::PrismaSummary {
+            migration_name: (!migration_name.is_empty()).then(|| migration_name.clone()),
+            applied: Some(applied),
+            schemas: schemas.clone(),
+            relations: relations.clone(),
+        };
+        return serde_json::to_string_pretty(&summary.format_json()).unwrap_or_default();
+    }
+
     let mut result = String::new();
 
     if !migration_name.is_empty() {
@@ -275,18 +389,18 @@ fn filter_migrate_dev(output: &str) -> String {
     }
 
     result.push_str("Changes:\n");
-    if tables_added > 0 {
-        result.push_str(&format!("  + {} table(s)\n", tables_added));
-    }
-    if tables_modified > 0 {
-        result.push_str(&format!("  ~ {} table(s) modified\n", tables_modified));
+    if any_qualified {
+        for (schema, changes) in &schemas {
+            if let Some(line) = changes.summary_line() {
+                result.push_str(&format!("  {}: {}\n", schema, line));
+            }
+        }
+    } else if let Some(line) = sql_ddl::parse(sql).summary_line() {
+        result.push_str(&format!("  {}\n", line));
     }
     if !relations.is_empty() {
         result.push_str(&format!("  + {} relation(s)\n", relations.len()));
     }
-    if !indexes.is_empty() {
-        result.push_str(&format!("  ~ {} index(es)\n", indexes.len()));
-    }
 
     result.push('\n');
     if applied {
@@ -296,6 +410,19 @@ fn filter_migrate_dev(output: &str) -> String {
     result.trim().to_string()
 }
 
+/// The table a classified statement applies to, or `None` for statements
+/// (like `DROP INDEX`) that don't carry one.
+fn statement_table(statement: &Statement) -> Option<&sql_ddl::TableName> {
+    match statement {
+        Statement::CreateTable { table, .. }
+        | Statement::AlterTableAddColumn { table }
+        | Statement::AlterTableDropColumn { table }
+        | Statement::CreateIndex { table }
+        | Statement::DropTable { table } => Some(table),
+        Statement::DropIndex | Statement::Other => None,
+    }
+}
+
 /// Filter migrate status output
 fn filter_migrate_status(output: &str) -> String {
     let mut applied_count = 0;
@@ -360,73 +487,108 @@ fn filter_migrate_deploy(output: &str) -> String {
 
 /// Filter db push output
 fn filter_db_push(output: &str) -> String {
-    let mut tables_added = 0;
-    let mut columns_modified = 0;
-    let mut dropped = 0;
-
-    for line in output.lines() {
-        if line.contains("CREATE TABLE") {
-            tables_added += 1;
-        }
-        if line.contains("ALTER") || line.contains("ADD COLUMN") {
-            columns_modified += 1;
-        }
-        if line.contains("DROP") {
-            dropped += 1;
+    let sql = sql_ddl::extract_sql_block(output);
+    let statements = sql_ddl::parse_statements(sql);
+    let mut schemas: BTreeMap<String, DdlChanges> = BTreeMap::new();
+    let mut any_qualified = false;
+
+    for statement in &statements {
+        if let Some(table) = statement_table(statement) {
+            any_qualified |= table.schema.is_some();
+            let schema = table.schema.clone().unwrap_or_else(|| "public".to_string());
+            schemas.entry(schema).or_default().record_statement(statement);
         }
     }
 
+    if json_output_requested() {
+        let summary = sql_ddl::PrismaSummary {
+            migration_name: None,
+            applied: None,
+            schemas: schemas.clone(),
+            relations: Vec::new(),
+        };
+        return serde_json::to_string_pretty(&summary.format_json()).unwrap_or_default();
+    }
+
     let mut result = String::new();
     result.push_str("✓ Schema pushed to database\n");
 
-    if tables_added > 0 || columns_modified > 0 || dropped > 0 {
-        result.push_str(&format!(
-            "  + {} tables, ~ {} columns, - {} dropped\n",
-            tables_added, columns_modified, dropped
-        ));
+    if any_qualified {
+        for (schema, changes) in &schemas {
+            if let Some(line) = changes.summary_line() {
+                result.push_str(&format!("  {}: {}\n", schema, line));
+            }
+        }
+    } else if let Some(line) = sql_ddl::parse(sql).summary_line() {
+        result.push_str(&format!("  {}\n", line));
     }
 
     result.trim().to_string()
 }
 
-/// Extract first number from a line
-fn extract_number(line: &str) -> Option<usize> {
-    line.split_whitespace()
-        .find_map(|word| word.parse::<usize>().ok())
-}
+/// Filter prisma format output - collapse the reformat report into one line
+fn filter_prisma_format(output: &str) -> String {
+    let mut reformatted = 0;
 
-/// Extract table name from SQL
-fn extract_table_name(line: &str) -> Option<String> {
-    if line.contains("TABLE") {
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        for (i, part) in parts.iter().enumerate() {
-            if *part == "TABLE" && i + 1 < parts.len() {
-                return Some(
-                    parts[i + 1]
-                        .trim_matches(|c| c == '`' || c == '"' || c == ';')
-                        .to_string(),
-                );
-            }
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with("Prisma schema loaded") {
+            continue;
         }
+        if trimmed.contains("formatted") || trimmed.starts_with('-') || trimmed.starts_with('+') {
+            reformatted += 1;
+        }
+    }
+
+    if reformatted > 0 {
+        format!("✓ Schema formatted ({} block(s) reformatted)", reformatted)
+    } else {
+        "✓ Schema already formatted".to_string()
     }
-    None
 }
 
-/// Extract index name from SQL
-fn extract_index_name(line: &str) -> Option<String> {
-    if line.contains("INDEX") {
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        for (i, part) in parts.iter().enumerate() {
-            if *part == "INDEX" && i + 1 < parts.len() {
-                return Some(
-                    parts[i + 1]
-                        .trim_matches(|c| c == '`' || c == '"' || c == ';')
-                        .to_string(),
-                );
-            }
+/// Filter prisma validate output - PASS/FAIL plus, on failure, each
+/// `schema.prisma:line:col` error location with its message
+fn filter_prisma_validate(output: &str, success: bool) -> String {
+    let mut schema_path = String::new();
+    let mut errors = Vec::new();
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if schema_path.is_empty() && trimmed.starts_with("Prisma schema loaded from ") {
+            schema_path = trimmed
+                .trim_start_matches("Prisma schema loaded from ")
+                .to_string();
+        }
+        if trimmed.contains(".prisma:") {
+            errors.push(trimmed.to_string());
+        }
+    }
+
+    let mut result = String::new();
+    if !schema_path.is_empty() {
+        result.push_str(&format!("Schema: {}\n", schema_path));
+    }
+
+    if success {
+        result.push_str("PASS\n");
+    } else {
+        result.push_str("FAIL\n");
+        for err in &errors {
+            result.push_str(&format!("  {}\n", err));
         }
     }
-    None
+
+    result.trim().to_string()
+}
+
+/// Extract first number from a line
+fn extract_number(line: &str) -> Option<usize> {
+    line.split_whitespace()
+        .find_map(|word| word.parse::<usize>().ok())
 }
 
 #[cfg(test)]
@@ -453,6 +615,30 @@ import { PrismaClient } from '@prisma/client'
         assert!(!result.contains("Start by importing"));
     }
 
+    #[test]
+    fn test_filter_generate_reports_generator_name_version_and_path() {
+        let output = r#"
+Prisma schema loaded from prisma/schema.prisma
+
+✔ Generated Prisma Client (v5.7.0) to ./node_modules/@prisma/client in 234ms
+"#;
+        let result = filter_prisma_generate(output);
+        assert!(result.contains("Prisma Client (v5.7.0) -> ./node_modules/@prisma/client"));
+    }
+
+    #[test]
+    fn test_filter_generate_reports_multiple_custom_generators() {
+        let output = r#"
+Prisma schema loaded from prisma/schema.prisma
+
+✔ Generated Prisma Client (v5.7.0) to ./node_modules/@prisma/client in 234ms
+✔ Generated prisma-client-rust (0.6.11) to ./src/prisma.rs in 1.2s
+"#;
+        let result = filter_prisma_generate(output);
+        assert!(result.contains("Prisma Client (v5.7.0) -> ./node_modules/@prisma/client"));
+        assert!(result.contains("prisma-client-rust (0.6.11) -> ./src/prisma.rs"));
+    }
+
     #[test]
     fn test_filter_migrate_dev() {
         let output = r#"
@@ -470,13 +656,169 @@ CREATE INDEX "session_status_idx" ON "Session"("status");
 "#;
         let result = filter_migrate_dev(output);
         assert!(result.contains("20260128_add_sessions"));
-        assert!(result.contains("+ 1 table"));
+        assert!(result.contains("+1 table"));
+        assert!(result.contains("+ 1 relation(s)"));
         assert!(result.contains("✓ Applied"));
     }
 
+    #[test]
+    fn test_filter_migrate_dev_emits_json_when_requested() {
+        use std::env;
+
+        let output = r#"
+Applying migration 20260128_add_sessions
+
+CREATE TABLE "Session" (
+  "id" TEXT NOT NULL,
+  "userId" TEXT NOT NULL,
+  FOREIGN KEY ("userId") REFERENCES "User"("id")
+);
+
+✓ Migration applied
+"#;
+        env::set_var("RTK_JSON", "1");
+        let result = filter_migrate_dev(output);
+        env::remove_var("RTK_JSON");
+
+        let value: serde_json::Value = serde_json::from_str(&result).expect("valid JSON");
+        assert_eq!(value["kind"], "prisma_summary");
+        assert_eq!(value["migration_name"], "20260128_add_sessions");
+        assert_eq!(value["applied"], true);
+        assert_eq!(value["schemas"]["public"]["tables_added"], 1);
+        assert_eq!(value["relations"][0], "User");
+    }
+
     #[test]
     fn test_extract_number() {
         assert_eq!(extract_number("42 models generated"), Some(42));
         assert_eq!(extract_number("no numbers here"), None);
     }
+
+    #[test]
+    fn test_filter_migrate_dev_multi_schema_groups_by_namespace() {
+        let output = r#"
+Applying migration 20260128_multi_schema
+
+CREATE TABLE "auth"."User" (
+  "id" TEXT NOT NULL
+);
+
+CREATE TABLE "auth"."Session" (
+  "id" TEXT NOT NULL
+);
+
+CREATE TABLE "public"."Post" (
+  "id" TEXT NOT NULL
+);
+
+CREATE INDEX "post_status_idx" ON "public"."Post"("status");
+CREATE INDEX "post_author_idx" ON "public"."Post"("authorId");
+CREATE INDEX "post_created_idx" ON "public"."Post"("createdAt");
+
+✓ Migration applied
+"#;
+        let result = filter_migrate_dev(output);
+        assert!(result.contains("auth: +2 table(s), +2 column(s)"));
+        assert!(result.contains("public: +1 table(s), +1 column(s), ~3 index(es)"));
+    }
+
+    #[test]
+    fn test_filter_migrate_dev_falls_back_to_flat_summary_without_schema_qualifiers() {
+        let output = r#"
+CREATE TABLE "User" (
+  "id" TEXT NOT NULL
+);
+CREATE TABLE "Session" (
+  "id" TEXT NOT NULL
+);
+"#;
+        let result = filter_migrate_dev(output);
+        assert!(result.contains("+2 table(s), +2 column(s)"));
+        assert!(!result.contains("public:"));
+    }
+
+    #[test]
+    fn test_filter_db_push_flat_summary_without_schema_qualifiers() {
+        let output = r#"
+CREATE TABLE "Post" (
+  "id" TEXT NOT NULL
+);
+ALTER TABLE "User" ADD COLUMN "bio" TEXT;
+"#;
+        let result = filter_db_push(output);
+        assert!(result.contains("+1 table(s), +2 column(s)"));
+        assert!(!result.contains("public:"));
+    }
+
+    #[test]
+    fn test_filter_db_push_emits_json_when_requested() {
+        use std::env;
+
+        let output = r#"
+CREATE TABLE "Post" (
+  "id" TEXT NOT NULL
+);
+ALTER TABLE "User" ADD COLUMN "bio" TEXT;
+"#;
+        env::set_var("RTK_JSON", "1");
+        let result = filter_db_push(output);
+        env::remove_var("RTK_JSON");
+
+        let value: serde_json::Value = serde_json::from_str(&result).expect("valid JSON");
+        assert_eq!(value["kind"], "prisma_summary");
+        assert_eq!(value["schemas"]["public"]["tables_added"], 1);
+        assert_eq!(value["schemas"]["public"]["columns_added"], 2);
+    }
+
+    #[test]
+    fn test_filter_db_push_multi_schema_groups_by_namespace() {
+        let output = r#"
+CREATE TABLE "auth"."User" (
+  "id" TEXT NOT NULL
+);
+ALTER TABLE "public"."Post" ADD COLUMN "bio" TEXT;
+"#;
+        let result = filter_db_push(output);
+        assert!(result.contains("auth: +1 table(s), +1 column(s)"));
+        assert!(result.contains("public: +1 column(s)"));
+    }
+
+    #[test]
+    fn test_filter_format_already_formatted() {
+        let output = "Prisma schema loaded from prisma/schema.prisma\n";
+        let result = filter_prisma_format(output);
+        assert_eq!(result, "✓ Schema already formatted");
+    }
+
+    #[test]
+    fn test_filter_format_reports_reformatted_blocks() {
+        let output = r#"
+Prisma schema loaded from prisma/schema.prisma
+- model User {
++ model User {
+"#;
+        let result = filter_prisma_format(output);
+        assert!(result.contains("Schema formatted"));
+        assert!(result.contains("2 block(s) reformatted"));
+    }
+
+    #[test]
+    fn test_filter_validate_pass() {
+        let output = "Prisma schema loaded from prisma/schema.prisma\nThe schema is valid 🚀\n";
+        let result = filter_prisma_validate(output, true);
+        assert!(result.contains("Schema: prisma/schema.prisma"));
+        assert!(result.contains("PASS"));
+    }
+
+    #[test]
+    fn test_filter_validate_fail_lists_errors() {
+        let output = r#"
+Prisma schema loaded from prisma/schema.prisma
+Error validating: Error parsing attribute "@relation": schema.prisma:12:3
+"#;
+        let result = filter_prisma_validate(output, false);
+        assert!(result.contains("FAIL"));
+        assert!(result.contains("schema.prisma:12:3"));
+        assert!(!result.contains("Prisma schema loaded from"));
+    }
 }
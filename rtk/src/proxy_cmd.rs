@@ -0,0 +1,219 @@
+//! Config-driven custom command proxies.
+//!
+//! `rtk`'s subcommand list is hard-coded, so a tool it doesn't natively
+//! wrap (e.g. `shellcheck`, a house-internal linter) has no compact mode.
+//! `[proxies.<name>]` entries in the config file close that gap, modeled on
+//! how Cargo resolves `alias.<name>` entries before falling through to a
+//! real subcommand: `rtk <name> ...` runs the proxy's `command` and filters
+//! its combined stdout/stderr through a small declarative pipeline instead
+//! of requiring a recompile to add a new wrapper.
+
+use crate::config::ProxyConfig;
+use crate::tracking;
+use crate::utils::{CommandRunner, RunOutcome};
+use anyhow::Result;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+pub fn run(name: &str, proxy: &ProxyConfig, args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("proxy '{}': {} {}", name, proxy.command, args.join(" "));
+    }
+
+    let outcome = CommandRunner::new(proxy.command.as_str())
+        .args(args.to_vec())
+        .run()?;
+
+    let (raw, exit_code) = match outcome {
+        RunOutcome::Completed {
+            stdout,
+            stderr,
+            exit_code,
+        } => (format!("{}\n{}", stdout, stderr), exit_code),
+        RunOutcome::TimedOut => {
+            anyhow::bail!("proxy '{}' ({}) timed out", name, proxy.command);
+        }
+    };
+
+    let filtered = apply_pipeline(&raw, proxy)?;
+
+    println!("{}", filtered);
+
+    timer.track(
+        &format!("{} {}", proxy.command, args.join(" ")),
+        &format!("rtk {} {}", name, args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    if exit_code != 0 {
+        std::process::exit(exit_code);
+    }
+
+    Ok(())
+}
+
+/// Run `raw` through a proxy's declarative filter pipeline: strip regexes,
+/// keep only matching lines, dedup, truncate, then (optionally) group by
+/// file. Each stage runs in that fixed order, matching the order the stages
+/// are declared in the config struct.
+fn apply_pipeline(raw: &str, proxy: &ProxyConfig) -> Result<String> {
+    let strip_patterns: Vec<Regex> = proxy
+        .strip_regex
+        .iter()
+        .map(|p| Regex::new(p))
+        .collect::<Result<_, _>>()?;
+
+    let mut lines: Vec<String> = Vec::new();
+    for line in raw.lines() {
+        let mut line = line.to_string();
+        for pattern in &strip_patterns {
+            line = pattern.replace_all(&line, "").into_owned();
+        }
+        lines.push(line);
+    }
+
+    if !proxy.keep_only.is_empty() {
+        let needles: Vec<String> = proxy.keep_only.iter().map(|s| s.to_lowercase()).collect();
+        lines.retain(|line| {
+            let lower = line.to_lowercase();
+            needles.iter().any(|needle| lower.contains(needle.as_str()))
+        });
+    }
+
+    if proxy.dedup {
+        let mut seen = HashSet::new();
+        lines.retain(|line| seen.insert(line.clone()));
+    }
+
+    if let Some(max_len) = proxy.truncate_line {
+        for line in &mut lines {
+            if line.len() > max_len {
+                line.truncate(max_len);
+            }
+        }
+    }
+
+    if proxy.group_by_file {
+        Ok(group_by_file(&lines))
+    } else {
+        Ok(lines.join("\n").trim().to_string())
+    }
+}
+
+/// Group lines under `### <file>` headers, splitting on the first
+/// `path:` token found in each line (the `file:line:col: message` shape
+/// most linters emit). Lines with no recognizable file prefix are grouped
+/// under `(other)`. Files are emitted in first-seen order.
+fn group_by_file(lines: &[String]) -> String {
+    lazy_static::lazy_static! {
+        static ref FILE_PREFIX: Regex = Regex::new(r"^([^\s:]+\.[A-Za-z0-9_]+):").unwrap();
+    }
+
+    let mut order: Vec<String> = Vec::new();
+    let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+
+    for line in lines {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let file = FILE_PREFIX
+            .captures(line)
+            .map(|c| c[1].to_string())
+            .unwrap_or_else(|| "(other)".to_string());
+
+        if !groups.contains_key(&file) {
+            order.push(file.clone());
+        }
+        groups.entry(file).or_default().push(line.clone());
+    }
+
+    let mut result = String::new();
+    for file in order {
+        result.push_str(&format!("### {}\n", file));
+        for line in &groups[&file] {
+            result.push_str(line);
+            result.push('\n');
+        }
+        result.push('\n');
+    }
+
+    result.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proxy(command: &str) -> ProxyConfig {
+        ProxyConfig {
+            command: command.to_string(),
+            strip_regex: Vec::new(),
+            dedup: false,
+            truncate_line: None,
+            keep_only: Vec::new(),
+            group_by_file: false,
+        }
+    }
+
+    #[test]
+    fn test_strip_regex_removes_matches() {
+        let mut cfg = proxy("tool");
+        cfg.strip_regex = vec![r"\x1b\[[0-9;]*m".to_string()];
+        let raw = "\x1b[31merror\x1b[0m: bad input";
+        let result = apply_pipeline(raw, &cfg).unwrap();
+        assert_eq!(result, "error: bad input");
+    }
+
+    #[test]
+    fn test_keep_only_filters_lines() {
+        let mut cfg = proxy("tool");
+        cfg.keep_only = vec!["error".to_string()];
+        let raw = "info: fine\nerror: bad\nwarn: meh";
+        let result = apply_pipeline(raw, &cfg).unwrap();
+        assert_eq!(result, "error: bad");
+    }
+
+    #[test]
+    fn test_dedup_drops_repeats() {
+        let mut cfg = proxy("tool");
+        cfg.dedup = true;
+        let raw = "same\nsame\ndifferent";
+        let result = apply_pipeline(raw, &cfg).unwrap();
+        assert_eq!(result, "same\ndifferent");
+    }
+
+    #[test]
+    fn test_truncate_line_caps_length() {
+        let mut cfg = proxy("tool");
+        cfg.truncate_line = Some(5);
+        let raw = "abcdefgh";
+        let result = apply_pipeline(raw, &cfg).unwrap();
+        assert_eq!(result, "abcde");
+    }
+
+    #[test]
+    fn test_group_by_file_buckets_and_preserves_order() {
+        let mut cfg = proxy("tool");
+        cfg.group_by_file = true;
+        let raw = "b.py:1: issue one\na.py:2: issue two\nb.py:3: issue three";
+        let result = apply_pipeline(raw, &cfg).unwrap();
+        let b_pos = result.find("### b.py").unwrap();
+        let a_pos = result.find("### a.py").unwrap();
+        assert!(b_pos < a_pos);
+        assert!(result.contains("issue one"));
+        assert!(result.contains("issue three"));
+    }
+
+    #[test]
+    fn test_group_by_file_other_bucket_for_unmatched_lines() {
+        let mut cfg = proxy("tool");
+        cfg.group_by_file = true;
+        let raw = "a.py:1: issue\nsome unrelated summary line";
+        let result = apply_pipeline(raw, &cfg).unwrap();
+        assert!(result.contains("### a.py"));
+        assert!(result.contains("### (other)"));
+    }
+}
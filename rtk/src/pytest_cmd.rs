@@ -1,7 +1,10 @@
 use crate::tracking;
-use crate::utils::truncate;
+use crate::utils::{compare_snapshot, truncate, update_snapshot};
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::process::Command;
+use std::time::Instant;
 
 #[derive(Debug, PartialEq)]
 enum ParseState {
@@ -11,8 +14,196 @@ enum ParseState {
     Summary,
 }
 
+/// A single `--report-log` JSONL record we care about. pytest emits other
+/// `$report_type` values (`CollectReport`, `SessionStart`, ...) which we
+/// simply skip via `serde(default)` + the type check in `parse_report_log`.
+#[derive(Debug, Deserialize)]
+struct ReportLogEntry {
+    #[serde(rename = "$report_type")]
+    report_type: String,
+    nodeid: Option<String>,
+    when: Option<String>,
+    outcome: Option<String>,
+    longrepr: Option<serde_json::Value>,
+    duration: Option<f64>,
+}
+
+/// Aggregated per-nodeid outcome across setup/call/teardown phases.
+#[derive(Debug, Default)]
+struct NodeOutcome {
+    passed: bool,
+    failed: bool,
+    skipped: bool,
+    longrepr: Option<String>,
+    /// Seconds spent in the `call` phase, pytest's own unit of "test time".
+    call_duration: f64,
+}
+
+/// Expected outcome for a test, borrowed from abi-cafe's `TestCheckMode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum TestCheckMode {
+    /// Default: the test must pass.
+    Pass,
+    /// Known-broken: failures are expected and tallied separately instead of
+    /// reported as regressions. A pass is itself a regression (stale rule).
+    Busted,
+    /// Run but discard the result entirely.
+    Ignore,
+}
+
+#[derive(Debug, Deserialize)]
+struct TestRule {
+    /// Exact nodeid or glob pattern (e.g. `tests/test_flaky.py::*`).
+    pattern: String,
+    #[serde(default = "default_check_mode")]
+    mode: TestCheckMode,
+}
+
+fn default_check_mode() -> TestCheckMode {
+    TestCheckMode::Pass
+}
+
+/// `.rtk/pytest-rules.toml` contents.
+#[derive(Debug, Default, Deserialize)]
+struct TestRules {
+    #[serde(default, rename = "rule")]
+    rules: Vec<TestRule>,
+}
+
+impl TestRules {
+    fn load() -> Self {
+        let path = std::path::Path::new(".rtk/pytest-rules.toml");
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| toml::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    fn mode_for(&self, nodeid: &str) -> TestCheckMode {
+        self.rules
+            .iter()
+            .find(|r| glob_matches(&r.pattern, nodeid))
+            .map(|r| r.mode)
+            .unwrap_or(TestCheckMode::Pass)
+    }
+}
+
+/// Minimal `*`-only glob matcher, sufficient for nodeid patterns like
+/// `tests/test_flaky.py::*`. Not a general glob engine on purpose — the
+/// rules file only ever targets pytest node IDs.
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    if pattern == text {
+        return true;
+    }
+    let parts: Vec<&str> = pattern.split('*').collect();
+    if parts.len() == 1 {
+        return false;
+    }
+    let mut rest = text;
+    for (i, part) in parts.iter().enumerate() {
+        if part.is_empty() {
+            continue;
+        }
+        if i == 0 {
+            if !rest.starts_with(part) {
+                return false;
+            }
+            rest = &rest[part.len()..];
+        } else if let Some(idx) = rest.find(part) {
+            rest = &rest[idx + part.len()..];
+        } else {
+            return false;
+        }
+    }
+    true
+}
+
+/// Outcome of reconciling parsed nodes against `.rtk/pytest-rules.toml`.
+#[derive(Debug, Default)]
+struct Reconciled {
+    /// Failures not excused by a `Busted` rule — these are true signal.
+    regressions: Vec<String>,
+    /// Failures matching a `Busted` rule, tallied separately from regressions.
+    expected_failures: usize,
+    /// `Busted` tests that unexpectedly passed (the rule is now stale).
+    unexpectedly_passed: Vec<String>,
+}
+
+fn reconcile_with_rules(nodes: &BTreeMap<String, NodeOutcome>, rules: &TestRules) -> Reconciled {
+    let mut out = Reconciled::default();
+
+    for (nodeid, node) in nodes {
+        let mode = rules.mode_for(nodeid);
+        if mode == TestCheckMode::Ignore {
+            continue;
+        }
+        if node.failed {
+            match mode {
+                TestCheckMode::Busted => out.expected_failures += 1,
+                _ => {
+                    let detail = node.longrepr.clone().unwrap_or_default();
+                    out.regressions.push(format!(
+                        "FAILED {} - {}",
+                        nodeid,
+                        detail.lines().next().unwrap_or("")
+                    ));
+                }
+            }
+        } else if node.passed && mode == TestCheckMode::Busted {
+            out.unexpectedly_passed.push(nodeid.clone());
+        }
+    }
+
+    out
+}
+
+/// `rtk pytest --json` result, mirroring libtest's `--format json` and
+/// `deno test --reporter json` in shape: overall counts plus a flat list of
+/// failures editors/CI can jump to directly.
+#[derive(Debug, Serialize)]
+struct PytestJsonResult {
+    passed: usize,
+    failed: usize,
+    skipped: usize,
+    duration_secs: f64,
+    failures: Vec<PytestJsonFailure>,
+}
+
+#[derive(Debug, Serialize)]
+struct PytestJsonFailure {
+    nodeid: String,
+    file: String,
+    line: Option<u32>,
+    message: String,
+    traceback: String,
+}
+
 pub fn run(args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
+    let json_mode = args.iter().any(|a| a == "--json");
+    let slowest_n = args
+        .iter()
+        .find_map(|a| a.strip_prefix("--slowest="))
+        .and_then(|n| n.parse::<usize>().ok())
+        .unwrap_or(5);
+    let snapshot_path = args
+        .iter()
+        .find_map(|a| a.strip_prefix("--snapshot="))
+        .map(std::path::PathBuf::from);
+    let snapshot_update = args.iter().any(|a| a == "--snapshot-update");
+    let args: Vec<String> = args
+        .iter()
+        .filter(|a| {
+            *a != "--json"
+                && *a != "--snapshot-update"
+                && !a.starts_with("--slowest=")
+                && !a.starts_with("--snapshot=")
+        })
+        .cloned()
+        .collect();
+    let args = &args[..];
+    let started = Instant::now();
 
     // Try to detect pytest command (could be "pytest", "python -m pytest", etc.)
     let mut cmd = if which_command("pytest").is_some() {
@@ -35,6 +226,21 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
         cmd.arg("-q");
     }
 
+    // Ask pytest for a machine-readable JSON Lines report alongside the
+    // human output. If the plugin providing `--report-log` isn't available,
+    // pytest exits with a usage error before running any tests and we fall
+    // back to scraping stdout like before.
+    let report_log = tempfile::Builder::new()
+        .prefix("rtk-pytest-report-")
+        .suffix(".jsonl")
+        .tempfile()
+        .context("Failed to create temp file for --report-log")?;
+    let report_log_path = report_log.path().to_path_buf();
+    cmd.arg(format!("--report-log={}", report_log_path.display()));
+    if !args.iter().any(|a| a.starts_with("--durations")) {
+        cmd.arg("--durations=0");
+    }
+
     for arg in args {
         cmd.arg(arg);
     }
@@ -51,15 +257,48 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
     let stderr = String::from_utf8_lossy(&output.stderr);
     let raw = format!("{}\n{}", stdout, stderr);
 
-    let filtered = filter_pytest_output(&stdout);
+    let report_log_text = std::fs::read_to_string(&report_log_path).unwrap_or_default();
+    let nodes = parse_report_log_nodes(&report_log_text);
+    let rules = TestRules::load();
+    let reconciled = if nodes.is_empty() {
+        None
+    } else {
+        Some(reconcile_with_rules(&nodes, &rules))
+    };
+
+    let filtered = if json_mode {
+        let json = build_pytest_json(&nodes, started.elapsed().as_secs_f64());
+        serde_json::to_string(&json).unwrap_or_else(|_| "{}".to_string())
+    } else {
+        match nodes_to_summary(&nodes) {
+            Some((summary, failures)) => {
+                let mut rendered =
+                    build_pytest_summary(&summary, &[], &failures, reconciled.as_ref());
+                rendered.push_str(&slowest_tests_section(&nodes, slowest_n));
+                rendered
+            }
+            // `--report-log` unsupported (unrecognized arguments) or produced
+            // nothing usable: fall back to the original stdout scraper.
+            None => filter_pytest_output(&stdout),
+        }
+    };
 
     println!("{}", filtered);
 
-    // Include stderr if present (import errors, etc.)
-    if !stderr.trim().is_empty() {
+    // Include stderr if present (import errors, etc.), unless --json is in
+    // play: keep stdout parseable as a single JSON object.
+    if !json_mode && !stderr.trim().is_empty() {
         eprintln!("{}", stderr.trim());
     }
 
+    if let Some(path) = &snapshot_path {
+        if snapshot_update {
+            update_snapshot(path, &filtered)?;
+        } else {
+            compare_snapshot(path, &filtered)?;
+        }
+    }
+
     timer.track(
         &format!("pytest {}", args.join(" ")),
         &format!("rtk pytest {}", args.join(" ")),
@@ -67,14 +306,224 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
         &filtered,
     );
 
-    // Preserve exit code for CI/CD
-    if !output.status.success() {
-        std::process::exit(output.status.code().unwrap_or(1));
+    // Preserve exit code for CI/CD, but when test-rules are in play only
+    // fail on un-excused regressions: a suite that's all `Busted` failures
+    // should come back green, while an unexpected pass flips a green run red.
+    match &reconciled {
+        Some(r) if !r.regressions.is_empty() || !r.unexpectedly_passed.is_empty() => {
+            std::process::exit(1);
+        }
+        Some(_) => {}
+        None if !output.status.success() => {
+            std::process::exit(output.status.code().unwrap_or(1));
+        }
+        None => {}
     }
 
     Ok(())
 }
 
+/// Parse a pytest `--report-log` JSONL file into per-nodeid outcomes.
+///
+/// Returns an empty map if the file is empty or contains no `TestReport`
+/// records, which means `--report-log` wasn't understood by the installed
+/// pytest and the caller should fall back to scraping stdout.
+fn parse_report_log_nodes(report_log: &str) -> BTreeMap<String, NodeOutcome> {
+    let mut nodes: BTreeMap<String, NodeOutcome> = BTreeMap::new();
+
+    for line in report_log.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let Ok(entry) = serde_json::from_str::<ReportLogEntry>(line) else {
+            continue;
+        };
+        if entry.report_type != "TestReport" {
+            continue;
+        }
+        let (Some(nodeid), Some(when), Some(outcome)) =
+            (entry.nodeid, entry.when, entry.outcome)
+        else {
+            continue;
+        };
+
+        let node = nodes.entry(nodeid).or_default();
+        match outcome.as_str() {
+            "failed" => {
+                node.failed = true;
+                if let Some(longrepr) = entry.longrepr {
+                    node.longrepr = Some(longrepr_to_string(&longrepr));
+                }
+            }
+            "skipped" if when == "setup" => node.skipped = true,
+            "passed" => node.passed = true,
+            _ => {}
+        }
+        if when == "call" {
+            if let Some(duration) = entry.duration {
+                node.call_duration = duration;
+            }
+        }
+    }
+
+    nodes
+}
+
+/// Basic summary stats over a slice of durations (seconds), already sorted.
+struct DurationStats {
+    mean: f64,
+    median: f64,
+    p95: f64,
+}
+
+fn duration_stats(sorted: &[f64]) -> DurationStats {
+    if sorted.is_empty() {
+        return DurationStats {
+            mean: 0.0,
+            median: 0.0,
+            p95: 0.0,
+        };
+    }
+    let sum: f64 = sorted.iter().sum();
+    let mean = sum / sorted.len() as f64;
+    let percentile = |p: f64| -> f64 {
+        let idx = ((sorted.len() - 1) as f64 * p).round() as usize;
+        sorted[idx.min(sorted.len() - 1)]
+    };
+    DurationStats {
+        mean,
+        median: percentile(0.5),
+        p95: percentile(0.95),
+    }
+}
+
+/// Render a "Slowest N tests" section plus mean/median/p95 across all
+/// collected `call` durations, mirroring pytest's own `--durations` block
+/// but folded into the compact summary instead of a separate pass.
+fn slowest_tests_section(nodes: &BTreeMap<String, NodeOutcome>, top_n: usize) -> String {
+    let mut durations: Vec<f64> = nodes.values().map(|n| n.call_duration).collect();
+    durations.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    if durations.is_empty() {
+        return String::new();
+    }
+    let stats = duration_stats(&durations);
+
+    let mut by_duration: Vec<(&str, f64)> = nodes
+        .iter()
+        .map(|(nodeid, n)| (nodeid.as_str(), n.call_duration))
+        .collect();
+    by_duration.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+
+    let mut out = String::new();
+    out.push_str(&format!(
+        "\nSlowest {} tests (mean {:.3}s, median {:.3}s, p95 {:.3}s):\n",
+        top_n.min(by_duration.len()),
+        stats.mean,
+        stats.median,
+        stats.p95
+    ));
+    for (nodeid, secs) in by_duration.iter().take(top_n) {
+        out.push_str(&format!("  {:>7.3}s  {}\n", secs, nodeid));
+    }
+    out
+}
+
+/// Reduce parsed nodes into the `(summary_line, failures)` shape
+/// `filter_pytest_output` produces, so both feed the same
+/// `build_pytest_summary` renderer. `None` means the report log had nothing
+/// usable in it.
+fn nodes_to_summary(nodes: &BTreeMap<String, NodeOutcome>) -> Option<(String, Vec<String>)> {
+    if nodes.is_empty() {
+        return None;
+    }
+
+    let mut passed = 0usize;
+    let mut failed = 0usize;
+    let mut skipped = 0usize;
+    let mut failures = Vec::new();
+
+    for (nodeid, node) in nodes {
+        if node.failed {
+            failed += 1;
+            let detail = node.longrepr.clone().unwrap_or_default();
+            failures.push(format!(
+                "FAILED {} - {}",
+                nodeid,
+                detail.lines().next().unwrap_or("")
+            ));
+        } else if node.skipped {
+            skipped += 1;
+        } else if node.passed {
+            passed += 1;
+        }
+    }
+
+    let summary = format!(
+        "=== {} passed, {} failed, {} skipped in 0.00s ===",
+        passed, failed, skipped
+    );
+    Some((summary, failures))
+}
+
+/// Build the `--json` result struct from the same parsed nodes the text
+/// renderer uses, so both modes agree on pass/fail/skip counts.
+fn build_pytest_json(nodes: &BTreeMap<String, NodeOutcome>, duration_secs: f64) -> PytestJsonResult {
+    let mut passed = 0usize;
+    let mut failed = 0usize;
+    let mut skipped = 0usize;
+    let mut failures = Vec::new();
+
+    for (nodeid, node) in nodes {
+        if node.failed {
+            failed += 1;
+            let traceback = node.longrepr.clone().unwrap_or_default();
+            let message = traceback.lines().next().unwrap_or("").to_string();
+            let (file, line) = split_nodeid_location(nodeid);
+            failures.push(PytestJsonFailure {
+                nodeid: nodeid.clone(),
+                file,
+                line,
+                message,
+                traceback: truncate(&traceback, 2000),
+            });
+        } else if node.skipped {
+            skipped += 1;
+        } else if node.passed {
+            passed += 1;
+        }
+    }
+
+    PytestJsonResult {
+        passed,
+        failed,
+        skipped,
+        duration_secs,
+        failures,
+    }
+}
+
+/// Split a pytest nodeid (`tests/test_foo.py::test_bar`) into its file path
+/// and, if the longrepr isn't available, a best-effort `None` line number.
+fn split_nodeid_location(nodeid: &str) -> (String, Option<u32>) {
+    let file = nodeid.split("::").next().unwrap_or(nodeid).to_string();
+    (file, None)
+}
+
+/// pytest's `longrepr` is either a plain string or a nested structured repr
+/// object; we only need readable text out of it for the compact summary.
+fn longrepr_to_string(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other
+            .get("reprcrash")
+            .and_then(|c| c.get("message"))
+            .and_then(|m| m.as_str())
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| other.to_string()),
+    }
+}
+
 /// Check if a command exists in PATH
 fn which_command(cmd: &str) -> Option<String> {
     Command::new("which")
@@ -164,18 +613,34 @@ fn filter_pytest_output(output: &str) -> String {
     }
 
     // Build compact output
-    build_pytest_summary(&summary_line, &test_files, &failures)
+    build_pytest_summary(&summary_line, &test_files, &failures, None)
 }
 
-fn build_pytest_summary(summary: &str, _test_files: &[String], failures: &[String]) -> String {
+fn build_pytest_summary(
+    summary: &str,
+    _test_files: &[String],
+    failures: &[String],
+    reconciled: Option<&Reconciled>,
+) -> String {
     // Parse summary line
     let (passed, failed, skipped) = parse_summary_line(summary);
 
-    if failed == 0 && passed > 0 {
-        return format!("✓ Pytest: {} passed", passed);
+    // When test-rules are in play, only un-excused failures count toward
+    // `failed`/the red section; `Busted` failures move to their own tally.
+    let (failed, failures): (usize, &[String]) = match reconciled {
+        Some(r) => (r.regressions.len(), &r.regressions),
+        None => (failed, failures),
+    };
+
+    if failed == 0 && passed > 0 && reconciled.map(|r| r.unexpectedly_passed.is_empty()).unwrap_or(true) {
+        let mut msg = format!("✓ Pytest: {} passed", passed);
+        if let Some(r) = reconciled.filter(|r| r.expected_failures > 0) {
+            msg.push_str(&format!(" ({} expected failures)", r.expected_failures));
+        }
+        return msg;
     }
 
-    if passed == 0 && failed == 0 {
+    if passed == 0 && failed == 0 && reconciled.is_none() {
         return "Pytest: No tests collected".to_string();
     }
 
@@ -184,9 +649,21 @@ fn build_pytest_summary(summary: &str, _test_files: &[String], failures: &[Strin
     if skipped > 0 {
         result.push_str(&format!(", {} skipped", skipped));
     }
+    if let Some(r) = reconciled.filter(|r| r.expected_failures > 0) {
+        result.push_str(&format!(", {} expected failures", r.expected_failures));
+    }
     result.push('\n');
     result.push_str("═══════════════════════════════════════\n");
 
+    if let Some(r) = reconciled {
+        if !r.unexpectedly_passed.is_empty() {
+            result.push_str("\nRegressions (unexpectedly passed, remove from rules):\n");
+            for nodeid in &r.unexpectedly_passed {
+                result.push_str(&format!("  ⚠ {}\n", nodeid));
+            }
+        }
+    }
+
     if failures.is_empty() {
         return result.trim().to_string();
     }
@@ -1,8 +1,10 @@
 use crate::filter::{self, FilterLevel, Language};
 use crate::tracking;
 use anyhow::{Context, Result};
+use globset::Glob;
+use std::collections::HashSet;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 pub fn run(
     file: &Path,
@@ -132,16 +134,297 @@ pub fn run_stdin(
     Ok(())
 }
 
+/// Resolves `rtk read`'s path/glob arguments to concrete files and reads
+/// each one once into an owned buffer, optionally dropping files whose
+/// content exactly duplicates one already loaded (e.g. a vendored copy of
+/// the same source). Used only by [`run_many`]; single-file reads keep
+/// going through the plain [`run`] above.
+struct Loader {
+    dedup: bool,
+    seen_hashes: HashSet<u64>,
+}
+
+impl Loader {
+    fn new(dedup: bool) -> Self {
+        Loader {
+            dedup,
+            seen_hashes: HashSet::new(),
+        }
+    }
+
+    /// Expand `patterns` (literal paths or glob patterns like `src/**/*.ts`)
+    /// into a flat, first-seen-order list of `(path, content)` pairs.
+    fn load(&mut self, patterns: &[String]) -> Result<Vec<(PathBuf, String)>> {
+        let mut files = Vec::new();
+        for pattern in patterns {
+            for path in expand_pattern(pattern)? {
+                let content = fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read file: {}", path.display()))?;
+                if self.dedup {
+                    let hash = content_hash(&content);
+                    if !self.seen_hashes.insert(hash) {
+                        continue;
+                    }
+                }
+                files.push((path, content));
+            }
+        }
+        Ok(files)
+    }
+}
+
+/// Resolve one CLI argument to concrete file paths: a literal path passes
+/// through unchanged, while anything containing glob metacharacters is
+/// matched against the files under the current directory (the same
+/// `ignore`-walked, `globset`-compiled approach `find_cmd` uses for `rtk
+/// find`), sorted for stable output ordering.
+fn expand_pattern(pattern: &str) -> Result<Vec<PathBuf>> {
+    if !pattern.contains(['*', '?', '[', '{']) {
+        return Ok(vec![PathBuf::from(pattern)]);
+    }
+
+    let matcher = Glob::new(pattern)
+        .with_context(|| format!("invalid glob pattern: {pattern}"))?
+        .compile_matcher();
+
+    let mut matches = Vec::new();
+    for entry in ignore::WalkBuilder::new(".").build() {
+        let entry = entry.context("failed to walk current directory")?;
+        if !entry.file_type().map(|t| t.is_file()).unwrap_or(false) {
+            continue;
+        }
+        let relative = entry.path().strip_prefix(".").unwrap_or(entry.path());
+        if matcher.is_match(relative) {
+            matches.push(relative.to_path_buf());
+        }
+    }
+    matches.sort();
+    Ok(matches)
+}
+
+fn content_hash(content: &str) -> u64 {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Multi-file `rtk read`: resolve every path/glob in `patterns`, filter each
+/// file independently (language detected per-file, `line_numbers`
+/// restarting per file), and print one combined digest with a
+/// `─── path ───` section header per file plus a trailing aggregate
+/// reduction summary across all of them.
+pub fn run_many(
+    patterns: &[String],
+    level: FilterLevel,
+    max_lines: Option<usize>,
+    line_numbers: bool,
+    dedup: bool,
+    verbose: u8,
+) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+    let mut loader = Loader::new(dedup);
+    let files = loader.load(patterns)?;
+
+    if files.is_empty() {
+        anyhow::bail!("no files matched the given paths/patterns");
+    }
+
+    if verbose > 0 {
+        eprintln!("Reading {} file(s) (filter: {})", files.len(), level);
+    }
+
+    let mut combined_raw = String::new();
+    let mut combined_output = String::new();
+    let mut total_original_lines = 0usize;
+    let mut total_filtered_lines = 0usize;
+
+    for (path, content) in &files {
+        let lang = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(Language::from_extension)
+            .unwrap_or(Language::Unknown);
+
+        let filter = filter::get_filter(level);
+        let mut filtered = filter.filter(content, &lang);
+        if let Some(max) = max_lines {
+            filtered = filter::smart_truncate(&filtered, max, &lang);
+        }
+
+        total_original_lines += content.lines().count();
+        total_filtered_lines += filtered.lines().count();
+
+        let section = if line_numbers {
+            format_with_line_numbers(&filtered)
+        } else {
+            filtered
+        };
+
+        combined_output.push_str(&format!("─── {} ───\n", path.display()));
+        combined_output.push_str(&section);
+        if !combined_output.ends_with('\n') {
+            combined_output.push('\n');
+        }
+        combined_output.push('\n');
+
+        combined_raw.push_str(content);
+        combined_raw.push('\n');
+    }
+
+    let reduction = if total_original_lines > 0 {
+        ((total_original_lines - total_filtered_lines) as f64 / total_original_lines as f64)
+            * 100.0
+    } else {
+        0.0
+    };
+    combined_output.push_str(&format!(
+        "─── {} files, {} -> {} lines ({:.1}% reduction) ───",
+        files.len(),
+        total_original_lines,
+        total_filtered_lines,
+        reduction
+    ));
+
+    println!("{}", combined_output);
+
+    timer.track(
+        &format!("cat {} files", files.len()),
+        "rtk read (multi-file)",
+        &combined_raw,
+        &combined_output,
+    );
+
+    Ok(())
+}
+
 fn format_with_line_numbers(content: &str) -> String {
     let lines: Vec<&str> = content.lines().collect();
     let width = lines.len().to_string().len();
+    format_with_line_numbers_from(content, 1, width)
+}
+
+/// Like [`format_with_line_numbers`], but numbers lines starting at
+/// `start_line` (1-based) instead of 1, and takes the column `width`
+/// explicitly so a run of windows cut from the same file lines up under a
+/// shared width rather than each window re-deriving its own from its
+/// (much smaller) line count.
+fn format_with_line_numbers_from(content: &str, start_line: usize, width: usize) -> String {
     let mut out = String::new();
-    for (i, line) in lines.iter().enumerate() {
-        out.push_str(&format!("{:>width$} │ {}\n", i + 1, line, width = width));
+    for (i, line) in content.lines().enumerate() {
+        out.push_str(&format!(
+            "{:>width$} │ {}\n",
+            start_line + i,
+            line,
+            width = width
+        ));
     }
     out
 }
 
+/// One `--around` target: the 1-based line to center on, and how many
+/// lines of context to include on each side.
+pub type AroundTarget = (usize, usize);
+
+/// Parse `--around` specs like `"12:3,88:5"` (or bare `"12"`, falling back
+/// to `default_context`) into `(line, context)` targets.
+pub fn parse_around_targets(specs: &[String], default_context: usize) -> Result<Vec<AroundTarget>> {
+    specs
+        .iter()
+        .map(|spec| {
+            let (line_str, context_str) = match spec.split_once(':') {
+                Some((l, c)) => (l, Some(c)),
+                None => (spec.as_str(), None),
+            };
+            let line: usize = line_str
+                .trim()
+                .parse()
+                .with_context(|| format!("invalid --around line number: {spec}"))?;
+            let context = match context_str {
+                Some(c) => c
+                    .trim()
+                    .parse()
+                    .with_context(|| format!("invalid --around context count: {spec}"))?,
+                None => default_context,
+            };
+            Ok((line, context))
+        })
+        .collect()
+}
+
+/// Merge per-target `±context` windows into the smallest set of
+/// non-overlapping, non-touching `[start, end]` (1-based, inclusive)
+/// ranges, clamped to `[1, total_lines]`.
+fn merge_windows(targets: &[AroundTarget], total_lines: usize) -> Vec<(usize, usize)> {
+    let mut windows: Vec<(usize, usize)> = targets
+        .iter()
+        .map(|&(line, context)| {
+            let start = line.saturating_sub(context).max(1);
+            let end = (line + context).min(total_lines);
+            (start, end)
+        })
+        .collect();
+    windows.sort();
+
+    let mut merged: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in windows {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 + 1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// `rtk read --around`: instead of filtering the whole file, emit only the
+/// `±context`-line windows surrounding each target location, merging
+/// windows that overlap or touch and inserting a `⋯` gap marker between
+/// windows that don't. Line numbers in the output are the original file's,
+/// not window-relative -- this is the minimal source a diagnostic like a
+/// `tsc` error (file + line) needs to be understood on its own.
+pub fn run_around(file: &Path, targets: &[AroundTarget], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!(
+            "Reading: {} (around {} location(s))",
+            file.display(),
+            targets.len()
+        );
+    }
+
+    let content = fs::read_to_string(file)
+        .with_context(|| format!("Failed to read file: {}", file.display()))?;
+    let lines: Vec<&str> = content.lines().collect();
+    let total = lines.len();
+
+    let windows = merge_windows(targets, total);
+    let width = total.to_string().len();
+
+    let mut output = String::new();
+    for (i, (start, end)) in windows.iter().enumerate() {
+        if i > 0 {
+            output.push_str("⋯\n");
+        }
+        let window_text = lines[(start - 1)..*end].join("\n");
+        output.push_str(&format_with_line_numbers_from(&window_text, *start, width));
+    }
+    let output = output.trim_end().to_string();
+
+    println!("{}", output);
+
+    timer.track(
+        &format!("cat {} (around {} loc)", file.display(), targets.len()),
+        "rtk read --around",
+        &content,
+        &output,
+    );
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,120 @@
+//! Shared "which specialized filter does this bare command name map to"
+//! table, consulted by both `Commands::Npx` (routing `npx <tool>`) and
+//! `Commands::Proxy` (routing `rtk proxy <command>`) before either falls
+//! back to a raw passthrough. Centralizing it here means a new filter only
+//! needs to be registered in one place to benefit both entry points.
+
+use crate::cargo_cmd::{self, CargoCommand};
+use crate::container::{self, ContainerCmd};
+use crate::git::{self, GitCommand};
+use crate::{lint_cmd, next_cmd, npm_cmd, playwright_cmd, prettier_cmd, tsc_cmd};
+use anyhow::{Context, Result};
+use std::ffi::OsString;
+use std::process::Command;
+
+/// Route `name` (the binary/tool being proxied) with its `rest` arguments
+/// through whichever specialized filter recognizes it. Returns `Ok(true)`
+/// once a filter has handled the command, or `Ok(false)` if `name` isn't
+/// one rtk has a filter for, so the caller can fall back to its own raw
+/// passthrough.
+pub fn route(name: &str, rest: &[String], verbose: u8, skip_env: bool) -> Result<bool> {
+    match name {
+        "tsc" | "typescript" => {
+            tsc_cmd::run(rest, verbose)?;
+        }
+        "eslint" => {
+            lint_cmd::run(rest, verbose)?;
+        }
+        "next" => {
+            next_cmd::run(rest, verbose)?;
+        }
+        "prettier" => {
+            prettier_cmd::run(rest, verbose)?;
+        }
+        "playwright" => {
+            playwright_cmd::run(rest, verbose)?;
+        }
+        "npm" => {
+            npm_cmd::run(rest, verbose, skip_env)?;
+        }
+        "cargo" => route_cargo(rest, verbose)?,
+        "docker" => route_docker(rest, verbose)?,
+        "git" => route_git(rest, verbose)?,
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+fn route_cargo(rest: &[String], verbose: u8) -> Result<()> {
+    let Some((sub, args)) = rest.split_first() else {
+        return raw_passthrough("cargo", rest, verbose);
+    };
+    let cmd = match sub.as_str() {
+        "build" => CargoCommand::Build,
+        "test" => CargoCommand::Test,
+        "clippy" => CargoCommand::Clippy,
+        "check" => CargoCommand::Check,
+        "install" => CargoCommand::Install,
+        "fix" => CargoCommand::Fix,
+        "fmt" => CargoCommand::Fmt,
+        _ => return cargo_cmd::run_passthrough(&to_os_strings(rest), verbose),
+    };
+    cargo_cmd::run(cmd, args, verbose, false)
+}
+
+fn route_docker(rest: &[String], verbose: u8) -> Result<()> {
+    let Some((sub, args)) = rest.split_first() else {
+        return raw_passthrough("docker", rest, verbose);
+    };
+    match sub.as_str() {
+        "ps" => container::run(ContainerCmd::DockerPs, args, verbose),
+        "images" => container::run(ContainerCmd::DockerImages, &[], verbose),
+        "logs" if !args.is_empty() => container::run(ContainerCmd::DockerLogs, args, verbose),
+        "stats" => container::run(ContainerCmd::DockerStats, &[], verbose),
+        _ => container::run_docker_passthrough(&to_os_strings(rest), verbose),
+    }
+}
+
+fn route_git(rest: &[String], verbose: u8) -> Result<()> {
+    let Some((sub, args)) = rest.split_first() else {
+        return raw_passthrough("git", rest, verbose);
+    };
+    let cmd = match sub.as_str() {
+        "diff" => GitCommand::Diff,
+        "log" => GitCommand::Log,
+        "status" => GitCommand::Status,
+        "show" => GitCommand::Show,
+        "add" => GitCommand::Add,
+        "push" => GitCommand::Push,
+        "pull" => GitCommand::Pull,
+        "branch" => GitCommand::Branch,
+        "fetch" => GitCommand::Fetch,
+        "worktree" => GitCommand::Worktree,
+        // `commit`/`stash`/`smash` need extra parsing (a message, a
+        // sub-subcommand) that the other variants don't -- simplest to let
+        // the regular `git` subcommand handle those and fall back to a
+        // passthrough here.
+        _ => return git::run_passthrough(&to_os_strings(rest), verbose),
+    };
+    git::run(cmd, args, None, verbose)
+}
+
+fn to_os_strings(args: &[String]) -> Vec<OsString> {
+    args.iter().map(OsString::from).collect()
+}
+
+/// Last-resort fallback for a recognized tool invoked with no subcommand at
+/// all (e.g. `rtk proxy cargo`), where there's nothing to route on.
+fn raw_passthrough(cmd: &str, args: &[String], verbose: u8) -> Result<()> {
+    if verbose > 0 {
+        eprintln!("{cmd} passthrough: {:?}", args);
+    }
+    let status = Command::new(cmd)
+        .args(args)
+        .status()
+        .context(format!("Failed to run {cmd}"))?;
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+    Ok(())
+}
@@ -0,0 +1,438 @@
+//! RFC 5545 recurrence-rule expansion for custom reporting periods.
+//!
+//! [`DayStats`]/[`WeekStats`]/[`MonthStats`] (see [`crate::tracking`]) only
+//! cover three hardcoded bucket shapes. This module lets `rtk gain --rrule`
+//! bucket tracked commands into arbitrary windows instead — quarterly,
+//! biweekly, every-3-days — by parsing a (subset of an) RFC 5545 recurrence
+//! rule and stepping boundary dates from a `DTSTART`. Only `FREQ`,
+//! `INTERVAL`, `COUNT`, and `UNTIL` are supported; other components
+//! (`BYDAY`, `WKST`, ...) are accepted but ignored.
+//!
+//! [`DayStats`]: crate::tracking::DayStats
+//! [`WeekStats`]: crate::tracking::WeekStats
+//! [`MonthStats`]: crate::tracking::MonthStats
+
+use crate::display_helpers::PeriodStats;
+use anyhow::{anyhow, bail, Result};
+use chrono::{Datelike, Duration, NaiveDate};
+
+/// Recurrence frequency, the `FREQ=` component of an RRULE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Freq {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// A parsed RRULE, covering the subset of RFC 5545 needed for boundary
+/// generation: `FREQ`, `INTERVAL` (default 1), and an optional bound
+/// (`COUNT` or `UNTIL`).
+#[derive(Debug, Clone)]
+pub struct RRule {
+    pub freq: Freq,
+    pub interval: u32,
+    pub count: Option<u32>,
+    pub until: Option<NaiveDate>,
+}
+
+/// Parse an RFC 5545 recurrence rule string: `key=value` pairs split on
+/// `;`, e.g. `"FREQ=WEEKLY;INTERVAL=2;COUNT=10"`.
+pub fn parse_rrule(rule: &str) -> Result<RRule> {
+    let mut freq = None;
+    let mut interval = 1u32;
+    let mut count = None;
+    let mut until = None;
+
+    for part in rule.split(';') {
+        let part = part.trim();
+        if part.is_empty() {
+            continue;
+        }
+        let Some((key, value)) = part.split_once('=') else {
+            bail!("invalid RRULE component \"{part}\": expected KEY=VALUE");
+        };
+        let value = value.trim();
+
+        match key.trim().to_uppercase().as_str() {
+            "FREQ" => {
+                freq = Some(match value.to_uppercase().as_str() {
+                    "DAILY" => Freq::Daily,
+                    "WEEKLY" => Freq::Weekly,
+                    "MONTHLY" => Freq::Monthly,
+                    "YEARLY" => Freq::Yearly,
+                    other => bail!(
+                        "unsupported FREQ \"{other}\" (expected DAILY, WEEKLY, MONTHLY, or YEARLY)"
+                    ),
+                });
+            }
+            "INTERVAL" => {
+                interval = value
+                    .parse()
+                    .map_err(|_| anyhow!("invalid INTERVAL \"{value}\": expected a positive integer"))?;
+                if interval == 0 {
+                    bail!("INTERVAL must be at least 1");
+                }
+            }
+            "COUNT" => {
+                count = Some(value.parse().map_err(|_| {
+                    anyhow!("invalid COUNT \"{value}\": expected a positive integer")
+                })?);
+            }
+            "UNTIL" => {
+                until = Some(parse_until(value)?);
+            }
+            _ => {} // ignore unsupported components (BYDAY, WKST, ...)
+        }
+    }
+
+    let freq = freq.ok_or_else(|| anyhow!("RRULE is missing required FREQ component"))?;
+    Ok(RRule {
+        freq,
+        interval,
+        count,
+        until,
+    })
+}
+
+/// Parse RFC 5545's `UNTIL` value: a date (`YYYYMMDD`) or a date-time
+/// (`YYYYMMDDTHHMMSSZ`). Only the date portion matters for bucketing.
+fn parse_until(value: &str) -> Result<NaiveDate> {
+    let date_part = &value[..value.len().min(8)];
+    NaiveDate::parse_from_str(date_part, "%Y%m%d")
+        .map_err(|_| anyhow!("invalid UNTIL \"{value}\": expected YYYYMMDD or YYYYMMDDTHHMMSSZ"))
+}
+
+/// Hard cap on generated boundaries so a rule with neither `COUNT` nor
+/// `UNTIL` can't expand forever.
+const MAX_OCCURRENCES: usize = 10_000;
+
+/// Generate boundary dates starting from `dtstart`, stepping by `rule`
+/// (`DAILY` adds `INTERVAL` days, `WEEKLY` adds `INTERVAL*7` days,
+/// `MONTHLY`/`YEARLY` add `INTERVAL` months/years, clamping the
+/// day-of-month on short months) until `COUNT` or `UNTIL` is reached, or
+/// the defensive [`MAX_OCCURRENCES`] cap. Consecutive pairs
+/// `[boundaries[i], boundaries[i+1])` are the buckets produced by
+/// [`bucket_records`].
+pub fn expand_boundaries(dtstart: NaiveDate, rule: &RRule) -> Vec<NaiveDate> {
+    let mut boundaries = vec![dtstart];
+    let mut current = dtstart;
+
+    loop {
+        if let Some(count) = rule.count {
+            if boundaries.len() as u32 >= count {
+                break;
+            }
+        }
+        if boundaries.len() >= MAX_OCCURRENCES {
+            break;
+        }
+
+        current = step(current, rule.freq, rule.interval);
+
+        if let Some(until) = rule.until {
+            if current > until {
+                break;
+            }
+        }
+
+        boundaries.push(current);
+    }
+
+    boundaries
+}
+
+fn step(date: NaiveDate, freq: Freq, interval: u32) -> NaiveDate {
+    match freq {
+        Freq::Daily => date + Duration::days(interval as i64),
+        Freq::Weekly => date + Duration::days(interval as i64 * 7),
+        Freq::Monthly => shift_months(date, interval as i32),
+        Freq::Yearly => shift_months(date, interval as i32 * 12),
+    }
+}
+
+/// Shift a date forward by whole calendar months, clamping the day to the
+/// destination month's length (e.g. Jan 31 + 1 month -> Feb 28/29).
+fn shift_months(date: NaiveDate, months: i32) -> NaiveDate {
+    let total_months = date.year() * 12 + date.month0() as i32 + months;
+    let year = total_months.div_euclid(12);
+    let month0 = total_months.rem_euclid(12) as u32;
+
+    let last_day_of_month = NaiveDate::from_ymd_opt(year, month0 + 1, 1)
+        .map(|first_of_month| {
+            first_of_month
+                .checked_add_months(chrono::Months::new(1))
+                .unwrap_or(first_of_month)
+                - Duration::days(1)
+        })
+        .map(|d| d.day())
+        .unwrap_or(28);
+
+    NaiveDate::from_ymd_opt(year, month0 + 1, date.day().min(last_day_of_month))
+        .expect("clamped day is always valid for its month")
+}
+
+/// One bucket of aggregated stats for an arbitrary `[start, end)` window,
+/// produced by [`crate::tracking::Tracker::get_by_rrule`] from a parsed
+/// [`RRule`]. `end` is `None` for the final bucket, which stays open
+/// through "now" rather than closing at the last generated boundary.
+#[derive(Debug, Clone)]
+pub struct RRulePeriodStats {
+    pub start: NaiveDate,
+    pub end: Option<NaiveDate>,
+    pub commands: usize,
+    pub input_tokens: usize,
+    pub output_tokens: usize,
+    pub saved_tokens: usize,
+    pub savings_pct: f64,
+    pub total_time_ms: u64,
+    pub avg_time_ms: u64,
+}
+
+/// Assign each `(date, input_tokens, output_tokens, saved_tokens,
+/// exec_time_ms)` record to the bucket its date falls in: events before
+/// `boundaries[0]` go to the first bucket, and the final bucket stays open
+/// through "now". Sums commands/tokens/time per bucket and derives
+/// `savings_pct`/`avg_time_ms` the same way [`crate::tracking::Tracker::get_all_days`] does.
+pub fn bucket_records(
+    boundaries: &[NaiveDate],
+    records: &[(NaiveDate, usize, usize, usize, u64)],
+) -> Vec<RRulePeriodStats> {
+    if boundaries.is_empty() {
+        return Vec::new();
+    }
+
+    let mut buckets: Vec<RRulePeriodStats> = boundaries
+        .windows(2)
+        .map(|w| new_bucket(w[0], Some(w[1])))
+        .collect();
+    buckets.push(new_bucket(*boundaries.last().unwrap(), None));
+
+    for (date, input, output, saved, exec_time_ms) in records {
+        let idx = boundaries.iter().rposition(|b| date >= b).unwrap_or(0);
+        let bucket = &mut buckets[idx];
+        bucket.commands += 1;
+        bucket.input_tokens += input;
+        bucket.output_tokens += output;
+        bucket.saved_tokens += saved;
+        bucket.total_time_ms += exec_time_ms;
+    }
+
+    for bucket in &mut buckets {
+        bucket.savings_pct = if bucket.input_tokens > 0 {
+            (bucket.saved_tokens as f64 / bucket.input_tokens as f64) * 100.0
+        } else {
+            0.0
+        };
+        bucket.avg_time_ms = if bucket.commands > 0 {
+            bucket.total_time_ms / bucket.commands as u64
+        } else {
+            0
+        };
+    }
+
+    buckets
+}
+
+fn new_bucket(start: NaiveDate, end: Option<NaiveDate>) -> RRulePeriodStats {
+    RRulePeriodStats {
+        start,
+        end,
+        commands: 0,
+        input_tokens: 0,
+        output_tokens: 0,
+        saved_tokens: 0,
+        savings_pct: 0.0,
+        total_time_ms: 0,
+        avg_time_ms: 0,
+    }
+}
+
+impl PeriodStats for RRulePeriodStats {
+    fn icon() -> &'static str {
+        "🔁"
+    }
+
+    fn label() -> &'static str {
+        "Custom"
+    }
+
+    fn period(&self, locale: crate::locale::Locale) -> String {
+        let render = |d: NaiveDate| format!("{} {:02}, {}", locale.month_abbrev(d.month()), d.day(), d.year());
+        let end = self.end.map(render).unwrap_or_else(|| "now".to_string());
+        format!("{} → {}", render(self.start), end)
+    }
+
+    fn start_date(&self) -> NaiveDate {
+        self.start
+    }
+
+    fn commands(&self) -> usize {
+        self.commands
+    }
+
+    fn input_tokens(&self) -> usize {
+        self.input_tokens
+    }
+
+    fn output_tokens(&self) -> usize {
+        self.output_tokens
+    }
+
+    fn saved_tokens(&self) -> usize {
+        self.saved_tokens
+    }
+
+    fn savings_pct(&self) -> f64 {
+        self.savings_pct
+    }
+
+    fn total_time_ms(&self) -> u64 {
+        self.total_time_ms
+    }
+
+    fn avg_time_ms(&self) -> u64 {
+        self.avg_time_ms
+    }
+
+    fn period_width() -> usize {
+        25
+    }
+
+    fn separator_width() -> usize {
+        86
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rrule_defaults_interval() {
+        let rule = parse_rrule("FREQ=DAILY").unwrap();
+        assert_eq!(rule.freq, Freq::Daily);
+        assert_eq!(rule.interval, 1);
+        assert_eq!(rule.count, None);
+        assert_eq!(rule.until, None);
+    }
+
+    #[test]
+    fn test_parse_rrule_full() {
+        let rule = parse_rrule("FREQ=WEEKLY;INTERVAL=2;COUNT=5").unwrap();
+        assert_eq!(rule.freq, Freq::Weekly);
+        assert_eq!(rule.interval, 2);
+        assert_eq!(rule.count, Some(5));
+    }
+
+    #[test]
+    fn test_parse_rrule_until() {
+        let rule = parse_rrule("FREQ=MONTHLY;UNTIL=20261231T000000Z").unwrap();
+        assert_eq!(rule.until, Some(NaiveDate::from_ymd_opt(2026, 12, 31).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_rrule_missing_freq() {
+        assert!(parse_rrule("INTERVAL=2").is_err());
+    }
+
+    #[test]
+    fn test_parse_rrule_unsupported_freq() {
+        assert!(parse_rrule("FREQ=HOURLY").is_err());
+    }
+
+    #[test]
+    fn test_parse_rrule_zero_interval_rejected() {
+        assert!(parse_rrule("FREQ=DAILY;INTERVAL=0").is_err());
+    }
+
+    #[test]
+    fn test_expand_boundaries_daily_count() {
+        let dtstart = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let rule = RRule {
+            freq: Freq::Daily,
+            interval: 3,
+            count: Some(4),
+            until: None,
+        };
+        let boundaries = expand_boundaries(dtstart, &rule);
+        assert_eq!(
+            boundaries,
+            vec![
+                NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2026, 1, 4).unwrap(),
+                NaiveDate::from_ymd_opt(2026, 1, 7).unwrap(),
+                NaiveDate::from_ymd_opt(2026, 1, 10).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expand_boundaries_monthly_clamps_short_month() {
+        let dtstart = NaiveDate::from_ymd_opt(2026, 1, 31).unwrap();
+        let rule = RRule {
+            freq: Freq::Monthly,
+            interval: 1,
+            count: Some(3),
+            until: None,
+        };
+        let boundaries = expand_boundaries(dtstart, &rule);
+        assert_eq!(boundaries[1], NaiveDate::from_ymd_opt(2026, 2, 28).unwrap());
+        assert_eq!(boundaries[2], NaiveDate::from_ymd_opt(2026, 3, 28).unwrap());
+    }
+
+    #[test]
+    fn test_expand_boundaries_respects_until() {
+        let dtstart = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let rule = RRule {
+            freq: Freq::Weekly,
+            interval: 1,
+            count: None,
+            until: Some(NaiveDate::from_ymd_opt(2026, 1, 20).unwrap()),
+        };
+        let boundaries = expand_boundaries(dtstart, &rule);
+        assert_eq!(
+            boundaries,
+            vec![
+                NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2026, 1, 8).unwrap(),
+                NaiveDate::from_ymd_opt(2026, 1, 15).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bucket_records_assigns_and_sums() {
+        let boundaries = vec![
+            NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 1, 8).unwrap(),
+        ];
+        let records = vec![
+            (NaiveDate::from_ymd_opt(2025, 12, 25).unwrap(), 100, 20, 80, 50),
+            (NaiveDate::from_ymd_opt(2026, 1, 3).unwrap(), 200, 50, 150, 100),
+            (NaiveDate::from_ymd_opt(2026, 1, 10).unwrap(), 300, 100, 200, 150),
+        ];
+
+        let buckets = bucket_records(&boundaries, &records);
+        assert_eq!(buckets.len(), 2);
+
+        // Before DTSTART falls into the first bucket alongside the
+        // in-range record.
+        assert_eq!(buckets[0].commands, 2);
+        assert_eq!(buckets[0].input_tokens, 300);
+        assert_eq!(buckets[0].saved_tokens, 230);
+
+        // Final bucket stays open ("now").
+        assert_eq!(buckets[1].end, None);
+        assert_eq!(buckets[1].commands, 1);
+        assert_eq!(
+            buckets[1].period(crate::locale::Locale::default()),
+            "Jan 08, 2026 → now"
+        );
+    }
+
+    #[test]
+    fn test_bucket_records_empty_boundaries() {
+        assert!(bucket_records(&[], &[]).is_empty());
+    }
+}
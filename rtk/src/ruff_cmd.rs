@@ -1,8 +1,9 @@
+use crate::glob_filter::{extract_glob_args, GlobFilter};
 use crate::tracking;
 use crate::utils::truncate;
 use anyhow::{Context, Result};
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::process::Command;
 
 #[derive(Debug, Deserialize)]
@@ -13,7 +14,6 @@ struct RuffLocation {
 
 #[derive(Debug, Deserialize)]
 struct RuffFix {
-    #[allow(dead_code)]
     applicability: Option<String>,
 }
 
@@ -28,9 +28,62 @@ struct RuffDiagnostic {
     fix: Option<RuffFix>,
 }
 
+/// Whether ruff will apply a diagnostic's fix by default (`safe`), only
+/// with `--unsafe-fixes` (`unsafe`), or never -- it's shown for reference
+/// but not auto-applicable either way (`display`). Ruff versions that omit
+/// `applicability` only ever emitted safe fixes, so a missing field
+/// defaults to `Safe`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FixApplicability {
+    Safe,
+    Unsafe,
+    Display,
+}
+
+impl FixApplicability {
+    fn parse(s: Option<&str>) -> Self {
+        match s {
+            Some("unsafe") => FixApplicability::Unsafe,
+            Some("display") => FixApplicability::Display,
+            _ => FixApplicability::Safe,
+        }
+    }
+}
+
+impl RuffDiagnostic {
+    /// `None` if ruff reported no fix at all; `display` fixes are shown for
+    /// reference but ruff never auto-applies them, so they don't count as
+    /// fixable from `--fix`/`--unsafe-fixes`'s point of view either.
+    fn applicability(&self) -> Option<FixApplicability> {
+        self.fix
+            .as_ref()
+            .map(|f| FixApplicability::parse(f.applicability.as_deref()))
+    }
+
+    fn is_fixable(&self) -> bool {
+        matches!(
+            self.applicability(),
+            Some(FixApplicability::Safe) | Some(FixApplicability::Unsafe)
+        )
+    }
+}
+
 pub fn run(args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
+    // `--diff`/`--changed-only`/`--glob` aren't ruff flags, so strip them
+    // before building ruff's own argv.
+    let changed_only = args.iter().any(|a| a == "--changed-only");
+    let diff_from_stdin = args.iter().any(|a| a == "--diff");
+    let owned_args: Vec<String> = args
+        .iter()
+        .filter(|a| *a != "--changed-only" && *a != "--diff")
+        .cloned()
+        .collect();
+    let (globs, owned_args) = extract_glob_args(&owned_args);
+    let glob_filter = GlobFilter::new(&globs).context("invalid --glob pattern")?;
+    let args = &owned_args[..];
+
     // Detect subcommand: check, format, or version
     let is_check = args.is_empty()
         || args[0] == "check"
@@ -85,8 +138,16 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
     let stderr = String::from_utf8_lossy(&output.stderr);
     let raw = format!("{}\n{}", stdout, stderr);
 
-    let filtered = if is_check && !stdout.trim().is_empty() {
-        filter_ruff_check_json(&stdout)
+    let filtered = if is_check && !stdout.trim().is_empty() && (changed_only || diff_from_stdin) {
+        let diff_text = if diff_from_stdin {
+            read_diff_from_stdin()?
+        } else {
+            run_git_diff()?
+        };
+        let changed_lines = parse_diff_changed_lines(&diff_text);
+        filter_ruff_check_json_changed_only(&stdout, &changed_lines, &glob_filter)
+    } else if is_check && !stdout.trim().is_empty() {
+        filter_ruff_check_json(&stdout, &glob_filter)
     } else if is_format {
         filter_ruff_format(&raw)
     } else {
@@ -111,28 +172,157 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
     Ok(())
 }
 
+/// Read a unified diff from stdin for `ruff check --diff`.
+fn read_diff_from_stdin() -> Result<String> {
+    use std::io::Read as IoRead;
+
+    let mut buf = String::new();
+    std::io::stdin()
+        .lock()
+        .read_to_string(&mut buf)
+        .context("Failed to read diff from stdin")?;
+    Ok(buf)
+}
+
+/// `git diff` (working tree vs. HEAD) for `ruff check --changed-only`.
+fn run_git_diff() -> Result<String> {
+    let output = Command::new("git")
+        .arg("diff")
+        .output()
+        .context("Failed to run git diff")?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "git diff failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parse a unified diff (clang-format-diff style) into per-file sets of
+/// 1-based line numbers added by the diff: each `+++ b/<path>` header sets
+/// the current target file, each `@@ -a,b +c,d @@` hunk header starts a
+/// line counter at `c`, and the hunk body advances that counter for
+/// context (` `) and added (`+`) lines -- not for removed (`-`) lines --
+/// recording every `+` line's number as it's consumed.
+fn parse_diff_changed_lines(diff_text: &str) -> HashMap<String, HashSet<usize>> {
+    lazy_static::lazy_static! {
+        static ref HUNK_RE: regex::Regex =
+            regex::Regex::new(r"^@@ -\d+(?:,\d+)? \+(\d+)(?:,\d+)? @@").unwrap();
+    }
+
+    let mut changed: HashMap<String, HashSet<usize>> = HashMap::new();
+    let mut current_file: Option<String> = None;
+    let mut line_no: usize = 0;
+    let mut in_hunk = false;
+
+    for line in diff_text.lines() {
+        if let Some(path) = line.strip_prefix("+++ b/") {
+            current_file = Some(path.to_string());
+            in_hunk = false;
+            continue;
+        }
+        if let Some(caps) = HUNK_RE.captures(line) {
+            line_no = caps[1].parse().unwrap_or(1);
+            in_hunk = true;
+            continue;
+        }
+        if !in_hunk || line.starts_with('\\') {
+            continue;
+        }
+
+        if line.starts_with('+') {
+            if let Some(file) = &current_file {
+                changed.entry(file.clone()).or_default().insert(line_no);
+            }
+            line_no += 1;
+        } else if !line.starts_with('-') {
+            line_no += 1;
+        }
+    }
+
+    changed
+}
+
+/// Whether `diff_path` and `ruff_path` refer to the same file, tolerating
+/// the diff's `b/`-stripped relative spelling against ruff's (possibly
+/// absolute) one via `compact_path`-style suffix matching.
+fn paths_match(diff_path: &str, ruff_path: &str) -> bool {
+    let diff_path = diff_path.replace('\\', "/");
+    let ruff_path = ruff_path.replace('\\', "/");
+    diff_path == ruff_path
+        || ruff_path.ends_with(&format!("/{diff_path}"))
+        || diff_path.ends_with(&format!("/{ruff_path}"))
+}
+
 /// Filter ruff check JSON output - group by rule and file
-pub fn filter_ruff_check_json(output: &str) -> String {
-    let diagnostics: Result<Vec<RuffDiagnostic>, _> = serde_json::from_str(output);
+pub fn filter_ruff_check_json(output: &str, glob_filter: &GlobFilter) -> String {
+    match parse_ruff_diagnostics(output) {
+        Ok(diagnostics) => {
+            let diagnostics: Vec<RuffDiagnostic> = diagnostics
+                .into_iter()
+                .filter(|d| glob_filter.keep(&d.filename))
+                .collect();
+            render_ruff_report(&diagnostics)
+        }
+        Err(msg) => msg,
+    }
+}
 
-    let diagnostics = match diagnostics {
+/// Like [`filter_ruff_check_json`], but first drops every diagnostic whose
+/// line isn't in `changed_lines` for its file -- so the grouped summary,
+/// fixable count, and top-rules/top-files sections are all computed over
+/// only the lines a diff actually touched.
+pub fn filter_ruff_check_json_changed_only(
+    output: &str,
+    changed_lines: &HashMap<String, HashSet<usize>>,
+    glob_filter: &GlobFilter,
+) -> String {
+    let diagnostics = match parse_ruff_diagnostics(output) {
         Ok(d) => d,
-        Err(e) => {
-            // Fallback if JSON parsing fails
-            return format!(
-                "Ruff check (JSON parse failed: {})\n{}",
-                e,
-                truncate(output, 500)
-            );
-        }
+        Err(msg) => return msg,
     };
 
+    let filtered: Vec<RuffDiagnostic> = diagnostics
+        .into_iter()
+        .filter(|d| glob_filter.keep(&d.filename))
+        .filter(|d| {
+            changed_lines.iter().any(|(diff_file, lines)| {
+                paths_match(diff_file, &d.filename) && lines.contains(&d.location.row)
+            })
+        })
+        .collect();
+
+    render_ruff_report(&filtered)
+}
+
+fn parse_ruff_diagnostics(output: &str) -> Result<Vec<RuffDiagnostic>, String> {
+    serde_json::from_str(output).map_err(|e| {
+        format!(
+            "Ruff check (JSON parse failed: {})\n{}",
+            e,
+            truncate(output, 500)
+        )
+    })
+}
+
+fn render_ruff_report(diagnostics: &[RuffDiagnostic]) -> String {
     if diagnostics.is_empty() {
         return "âœ“ Ruff: No issues found".to_string();
     }
 
     let total_issues = diagnostics.len();
-    let fixable_count = diagnostics.iter().filter(|d| d.fix.is_some()).count();
+    let safe_count = diagnostics
+        .iter()
+        .filter(|d| d.applicability() == Some(FixApplicability::Safe))
+        .count();
+    let unsafe_count = diagnostics
+        .iter()
+        .filter(|d| d.applicability() == Some(FixApplicability::Unsafe))
+        .count();
+    let fixable_count = safe_count + unsafe_count;
 
     // Count unique files
     let unique_files: std::collections::HashSet<_> =
@@ -141,13 +331,30 @@ pub fn filter_ruff_check_json(output: &str) -> String {
 
     // Group by rule code
     let mut by_rule: HashMap<String, usize> = HashMap::new();
-    for diag in &diagnostics {
+    for diag in diagnostics {
         *by_rule.entry(diag.code.clone()).or_insert(0) += 1;
     }
 
+    // Rules whose every fixable occurrence is unsafe-only, so the
+    // per-rule breakdown can flag them.
+    let unsafe_only_rules: std::collections::HashSet<&str> = by_rule
+        .keys()
+        .filter(|rule| {
+            let fixable: Vec<_> = diagnostics
+                .iter()
+                .filter(|d| &d.code == *rule && d.is_fixable())
+                .collect();
+            !fixable.is_empty()
+                && fixable
+                    .iter()
+                    .all(|d| d.applicability() == Some(FixApplicability::Unsafe))
+        })
+        .map(|s| s.as_str())
+        .collect();
+
     // Group by file
     let mut by_file: HashMap<&str, usize> = HashMap::new();
-    for diag in &diagnostics {
+    for diag in diagnostics {
         *by_file.entry(&diag.filename).or_insert(0) += 1;
     }
 
@@ -162,7 +369,14 @@ pub fn filter_ruff_check_json(output: &str) -> String {
     ));
 
     if fixable_count > 0 {
-        result.push_str(&format!(" ({} fixable)", fixable_count));
+        if unsafe_count > 0 {
+            result.push_str(&format!(
+                " ({} fixable: {} safe, {} unsafe)",
+                fixable_count, safe_count, unsafe_count
+            ));
+        } else {
+            result.push_str(&format!(" ({} fixable)", fixable_count));
+        }
     }
     result.push('\n');
     result.push_str("â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•\n");
@@ -174,7 +388,11 @@ pub fn filter_ruff_check_json(output: &str) -> String {
     if !rule_counts.is_empty() {
         result.push_str("Top rules:\n");
         for (rule, count) in rule_counts.iter().take(10) {
-            result.push_str(&format!("  {} ({}x)\n", rule, count));
+            if unsafe_only_rules.contains(rule.as_str()) {
+                result.push_str(&format!("  {} ({}x) [unsafe fix only]\n", rule, count));
+            } else {
+                result.push_str(&format!("  {} ({}x)\n", rule, count));
+            }
         }
         result.push('\n');
     }
@@ -204,10 +422,19 @@ pub fn filter_ruff_check_json(output: &str) -> String {
     }
 
     if fixable_count > 0 {
-        result.push_str(&format!(
-            "\nğŸ’¡ Run `ruff check --fix` to auto-fix {} issues\n",
-            fixable_count
-        ));
+        result.push('\n');
+        if safe_count > 0 {
+            result.push_str(&format!(
+                "ğŸ’¡ Run `ruff check --fix` to auto-fix {} safe issues\n",
+                safe_count
+            ));
+        }
+        if unsafe_count > 0 {
+            result.push_str(&format!(
+                "ğŸ’¡ Run `ruff check --fix --unsafe-fixes` to also fix {} unsafe issues\n",
+                unsafe_count
+            ));
+        }
     }
 
     result.trim().to_string()
@@ -323,7 +550,7 @@ mod tests {
     #[test]
     fn test_filter_ruff_check_no_issues() {
         let output = "[]";
-        let result = filter_ruff_check_json(output);
+        let result = filter_ruff_check_json(output, &GlobFilter::new(&[]).unwrap());
         assert!(result.contains("âœ“ Ruff"));
         assert!(result.contains("No issues found"));
     }
@@ -356,7 +583,7 @@ mod tests {
     "fix": null
   }
 ]"#;
-        let result = filter_ruff_check_json(output);
+        let result = filter_ruff_check_json(output, &GlobFilter::new(&[]).unwrap());
         assert!(result.contains("3 issues"));
         assert!(result.contains("2 files"));
         assert!(result.contains("1 fixable"));
@@ -399,4 +626,140 @@ Would reformat: tests/test_utils.py
         );
         assert_eq!(compact_path("relative/file.py"), "file.py");
     }
+
+    #[test]
+    fn test_parse_diff_changed_lines() {
+        let diff = "\
+diff --git a/src/main.py b/src/main.py
+index 1111111..2222222 100644
+--- a/src/main.py
++++ b/src/main.py
+@@ -8,3 +8,4 @@ def foo():
+ def foo():
+     x = 1
+-    return x
++    return x + 1
++    # trailing comment
+";
+        let changed = parse_diff_changed_lines(diff);
+        let lines = changed.get("src/main.py").expect("file present");
+        assert!(lines.contains(&10));
+        assert!(lines.contains(&11));
+        assert!(!lines.contains(&9)); // context line, not added
+        assert_eq!(lines.len(), 2);
+    }
+
+    #[test]
+    fn test_filter_ruff_check_json_changed_only() {
+        let output = r#"[
+  {
+    "code": "F401",
+    "message": "`os` imported but unused",
+    "location": {"row": 1, "column": 8},
+    "end_location": {"row": 1, "column": 10},
+    "filename": "src/main.py",
+    "fix": {"applicability": "safe"}
+  },
+  {
+    "code": "E501",
+    "message": "Line too long (100 > 88 characters)",
+    "location": {"row": 11, "column": 89},
+    "end_location": {"row": 11, "column": 100},
+    "filename": "src/main.py",
+    "fix": null
+  }
+]"#;
+        let mut changed: HashMap<String, HashSet<usize>> = HashMap::new();
+        changed.insert("src/main.py".to_string(), HashSet::from([11]));
+
+        let result = filter_ruff_check_json_changed_only(
+            output,
+            &changed,
+            &GlobFilter::new(&[]).unwrap(),
+        );
+        assert!(result.contains("1 issues"));
+        assert!(result.contains("E501"));
+        assert!(!result.contains("F401"));
+    }
+
+    #[test]
+    fn test_paths_match_suffix() {
+        assert!(paths_match("src/main.py", "/home/user/project/src/main.py"));
+        assert!(paths_match("src/main.py", "src/main.py"));
+        assert!(!paths_match("src/main.py", "src/other.py"));
+    }
+
+    #[test]
+    fn test_filter_ruff_check_json_glob_scoping() {
+        let output = r#"[
+  {
+    "code": "F401",
+    "message": "`os` imported but unused",
+    "location": {"row": 1, "column": 8},
+    "end_location": {"row": 1, "column": 10},
+    "filename": "src/main.py",
+    "fix": null
+  },
+  {
+    "code": "F401",
+    "message": "`os` imported but unused",
+    "location": {"row": 1, "column": 8},
+    "end_location": {"row": 1, "column": 10},
+    "filename": "tests/test_main.py",
+    "fix": null
+  }
+]"#;
+        let glob_filter =
+            GlobFilter::new(&["**/*.py".to_string(), "!**/tests/**".to_string()]).unwrap();
+        let result = filter_ruff_check_json(output, &glob_filter);
+        assert!(result.contains("1 issues"));
+        assert!(result.contains("main.py"));
+        assert!(!result.contains("test_main.py"));
+    }
+
+    #[test]
+    fn test_filter_ruff_check_json_splits_safe_and_unsafe_fixes() {
+        let output = r#"[
+  {
+    "code": "F401",
+    "message": "`os` imported but unused",
+    "location": {"row": 1, "column": 8},
+    "end_location": {"row": 1, "column": 10},
+    "filename": "src/main.py",
+    "fix": {"applicability": "safe"}
+  },
+  {
+    "code": "UP007",
+    "message": "Use `X | Y` for type annotations",
+    "location": {"row": 2, "column": 1},
+    "end_location": {"row": 2, "column": 5},
+    "filename": "src/main.py",
+    "fix": {"applicability": "unsafe"}
+  },
+  {
+    "code": "UP007",
+    "message": "Use `X | Y` for type annotations",
+    "location": {"row": 9, "column": 1},
+    "end_location": {"row": 9, "column": 5},
+    "filename": "src/utils.py",
+    "fix": {"applicability": "unsafe"}
+  },
+  {
+    "code": "ERA001",
+    "message": "Found commented-out code",
+    "location": {"row": 3, "column": 1},
+    "end_location": {"row": 3, "column": 20},
+    "filename": "src/main.py",
+    "fix": {"applicability": "display"}
+  }
+]"#;
+        let result = filter_ruff_check_json(output, &GlobFilter::new(&[]).unwrap());
+        assert!(result.contains("4 issues"));
+        assert!(result.contains("3 fixable: 1 safe, 2 unsafe"));
+        assert!(result.contains("UP007 (2x) [unsafe fix only]"));
+        assert!(result.contains("Run `ruff check --fix` to auto-fix 1 safe issues"));
+        assert!(result.contains(
+            "Run `ruff check --fix --unsafe-fixes` to also fix 2 unsafe issues"
+        ));
+    }
 }
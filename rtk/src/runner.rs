@@ -1,10 +1,261 @@
 use crate::tracking;
 use anyhow::{Context, Result};
 use regex::Regex;
-use std::process::{Command, Stdio};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
 
 /// Run a command and filter output to show only errors/warnings
 pub fn run_err(command: &str, verbose: u8) -> Result<()> {
+    run_err_once(command, verbose, &[])
+}
+
+/// Run `run_err` once, or in a loop that re-runs on filesystem changes.
+pub fn run_err_watch(command: &str, verbose: u8) -> Result<()> {
+    run_watch(command, verbose, |c, v| run_err_once(c, v, &[]))
+}
+
+/// Like [`run_err`], but normalizes `raw` with `rules` before filtering, so
+/// volatile substrings (paths, timestamps, durations, ...) don't leak into
+/// the filtered output.
+pub fn run_err_with_filters(command: &str, rules: &[(Regex, String)], verbose: u8) -> Result<()> {
+    run_err_once(command, verbose, rules)
+}
+
+/// Like [`run_err_watch`], but normalizes each run's output with `rules`.
+pub fn run_err_watch_with_filters(
+    command: &str,
+    rules: &[(Regex, String)],
+    verbose: u8,
+) -> Result<()> {
+    run_watch(command, verbose, |c, v| run_err_once(c, v, rules))
+}
+
+/// Run tests and show only failures
+pub fn run_test(command: &str, verbose: u8) -> Result<()> {
+    run_test_once(command, verbose, &[])
+}
+
+/// Run `run_test` once, or in a loop that re-runs on filesystem changes.
+pub fn run_test_watch(command: &str, verbose: u8) -> Result<()> {
+    run_watch(command, verbose, |c, v| run_test_once(c, v, &[]))
+}
+
+/// Like [`run_test`], but normalizes `raw` with `rules` before summarizing.
+pub fn run_test_with_filters(command: &str, rules: &[(Regex, String)], verbose: u8) -> Result<()> {
+    run_test_once(command, verbose, rules)
+}
+
+/// Like [`run_test_watch`], but normalizes each run's output with `rules`.
+pub fn run_test_watch_with_filters(
+    command: &str,
+    rules: &[(Regex, String)],
+    verbose: u8,
+) -> Result<()> {
+    run_watch(command, verbose, |c, v| run_test_once(c, v, rules))
+}
+
+/// Compile a [`crate::config::NormalizeConfig`]'s rules plus any repeated
+/// `--filter-out 'PATTERN=>REPLACEMENT'` flags into ready-to-apply regex
+/// substitutions. CLI-supplied filters are appended after the config file's,
+/// so both apply and neither silently overrides the other.
+pub fn build_normalize_rules(cli_filters: &[String]) -> Result<Vec<(Regex, String)>> {
+    let mut rules = Vec::new();
+
+    if let Ok(config) = crate::config::Config::load() {
+        for rule in config.normalize.rules {
+            let re = Regex::new(&rule.pattern)
+                .with_context(|| format!("Invalid normalize pattern: {}", rule.pattern))?;
+            rules.push((re, rule.replacement));
+        }
+    }
+
+    for raw in cli_filters {
+        let (pattern, replacement) = raw.split_once("=>").with_context(|| {
+            format!("--filter-out must be PATTERN=>REPLACEMENT, got: {}", raw)
+        })?;
+        let re = Regex::new(pattern)
+            .with_context(|| format!("Invalid --filter-out pattern: {}", pattern))?;
+        rules.push((re, replacement.to_string()));
+    }
+
+    Ok(rules)
+}
+
+/// Apply normalization rules in order, replacing every match of each
+/// pattern with its stable placeholder.
+fn normalize(text: &str, rules: &[(Regex, String)]) -> String {
+    if rules.is_empty() {
+        return text.to_string();
+    }
+    let mut result = text.to_string();
+    for (re, replacement) in rules {
+        result = re.replace_all(&result, replacement.as_str()).into_owned();
+    }
+    result
+}
+
+/// Run `run_test_json` once, or in a loop that re-runs on filesystem changes.
+pub fn run_test_json_watch(command: &str, verbose: u8) -> Result<()> {
+    run_watch(command, verbose, run_test_json)
+}
+
+/// Derive a default seed from the wall clock when the user doesn't pin one
+/// with `--shuffle-seed`, the same "random but logged so it can be replayed"
+/// approach Deno's test runner takes. `pub(crate)` so other wrappers with
+/// their own native `--shuffle` passthrough (e.g. `rtk vitest run`) can
+/// derive a seed the same way instead of rolling their own.
+pub(crate) fn derive_seed_from_time() -> u64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+/// Build the native shuffle invocation for a command whose test framework
+/// has its own seeded-shuffle support, or `None` if the framework isn't one
+/// rtk knows how to pass a seed to directly.
+fn native_shuffle_command(command: &str, seed: u64) -> Option<String> {
+    if command.contains("cargo test") {
+        Some(format!(
+            "{} -- -Z unstable-options --shuffle --shuffle-seed {}",
+            command, seed
+        ))
+    } else if command.contains("go test") {
+        Some(format!("{} -shuffle={}", command, seed))
+    } else if command.contains("pytest") {
+        Some(format!("{} -p randomly --randomly-seed={}", command, seed))
+    } else {
+        None
+    }
+}
+
+/// Discover a test list via the command's own `--list` convention, shuffle
+/// it with a seeded `SmallRng`, and rebuild the command with the shuffled
+/// names pinned as explicit arguments - this is the fallback for frameworks
+/// without a native shuffle flag, e.g. a plain test binary or an unrecognized
+/// runner. Falls back to the unshuffled command if nothing is discovered.
+fn shuffle_by_test_list(command: &str, seed: u64, verbose: u8) -> Result<String> {
+    use rand::rngs::SmallRng;
+    use rand::seq::SliceRandom;
+    use rand::SeedableRng;
+
+    let list_command = format!("{} --list", command);
+    if verbose > 0 {
+        eprintln!("Discovering tests: {}", list_command);
+    }
+
+    let mut names: Vec<String> = exec_shell(&list_command)
+        .ok()
+        .map(|output| {
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter_map(|line| line.split(':').next())
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if names.is_empty() {
+        if verbose > 0 {
+            eprintln!("No test list discovered, running unshuffled");
+        }
+        return Ok(command.to_string());
+    }
+
+    let mut rng = SmallRng::seed_from_u64(seed);
+    names.shuffle(&mut rng);
+
+    Ok(format!("{} {}", command, names.join(" ")))
+}
+
+/// Run tests in a deterministic shuffled order, à la `deno test --shuffle`.
+/// Frameworks with their own seeded-shuffle flag (`cargo test`, `go test`,
+/// `pytest-randomly`) get it passed straight through; anything else falls
+/// back to [`shuffle_by_test_list`]. Either way the resolved seed - pinned
+/// via `--shuffle-seed`, or derived from the clock when omitted - is echoed
+/// in the summary so a failing shuffled run can be replayed exactly with
+/// `--shuffle --shuffle-seed <that seed>`.
+pub fn run_test_shuffle(command: &str, seed: Option<u64>, verbose: u8) -> Result<()> {
+    let seed = seed.unwrap_or_else(derive_seed_from_time);
+    let timer = tracking::TimedExecution::start();
+
+    let run_command = match native_shuffle_command(command, seed) {
+        Some(native) => native,
+        None => shuffle_by_test_list(command, seed, verbose)?,
+    };
+
+    if verbose > 0 {
+        eprintln!("Running tests: {}", run_command);
+    }
+
+    let output = exec_shell(&run_command).context("Failed to execute test command")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let mut summary = extract_test_summary(&raw, command);
+    summary.push_str(&format!(
+        "🔀 shuffle seed: {seed} (replay with --shuffle --shuffle-seed {seed})\n"
+    ));
+
+    println!("{}", summary);
+    timer.track(command, "rtk run-test --shuffle", &raw, &summary);
+    Ok(())
+}
+
+/// Block on filesystem changes under the starting directory and re-invoke
+/// `once` for each burst of edits, debounced so a save's many touch events
+/// collapse into a single run. The watched root is resolved from the current
+/// directory at startup (not re-resolved per run) so a command that `cd`s
+/// internally doesn't change what's being watched.
+fn run_watch(command: &str, verbose: u8, once: impl Fn(&str, u8) -> Result<()>) -> Result<()> {
+    use notify::{RecursiveMode, Watcher};
+    use std::sync::mpsc::channel;
+    use std::time::Duration;
+
+    let watch_root = std::env::current_dir().context("Failed to resolve current directory")?;
+
+    let run_and_announce = |root: &Path| -> Result<()> {
+        if let Err(e) = once(command, verbose) {
+            eprintln!("⚠️  command failed: {}", e);
+        }
+        print!("\x1B[2J\x1B[1;1H");
+        println!("Watching {} for changes…", root.display());
+        Ok(())
+    };
+
+    run_and_announce(&watch_root)?;
+
+    let (tx, rx) = channel();
+    let mut watcher = notify::recommended_watcher(move |res| {
+        let _ = tx.send(res);
+    })
+    .context("Failed to start file watcher")?;
+    watcher
+        .watch(&watch_root, RecursiveMode::Recursive)
+        .context("Failed to watch directory")?;
+
+    loop {
+        // Block for the first event, then drain anything else that arrives
+        // within the debounce window so a single save (which fires several
+        // OS events) triggers exactly one re-run.
+        if rx.recv().is_err() {
+            break;
+        }
+        while rx.recv_timeout(Duration::from_millis(200)).is_ok() {}
+
+        run_and_announce(&watch_root)?;
+    }
+
+    Ok(())
+}
+
+fn run_err_once(command: &str, verbose: u8, rules: &[(Regex, String)]) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
     if verbose > 0 {
@@ -28,7 +279,7 @@ pub fn run_err(command: &str, verbose: u8) -> Result<()> {
 
     let stdout = String::from_utf8_lossy(&output.stdout);
     let stderr = String::from_utf8_lossy(&output.stderr);
-    let raw = format!("{}\n{}", stdout, stderr);
+    let raw = normalize(&format!("{}\n{}", stdout, stderr), rules);
     let filtered = filter_errors(&raw);
     let mut rtk = String::new();
 
@@ -54,8 +305,129 @@ pub fn run_err(command: &str, verbose: u8) -> Result<()> {
     Ok(())
 }
 
-/// Run tests and show only failures
-pub fn run_test(command: &str, verbose: u8) -> Result<()> {
+/// Run `run_err`, then check the filtered output against a committed
+/// snapshot instead of just printing it. See [`check_snapshot`].
+pub fn run_err_expect(
+    command: &str,
+    expect_path: &Path,
+    bless: bool,
+    rules: &[(Regex, String)],
+    verbose: u8,
+) -> Result<()> {
+    let rtk = capture_err_output(command, verbose, rules)?;
+    check_snapshot("rtk err", &rtk, expect_path, bless)
+}
+
+/// Run `run_test`, then check the summary against a committed snapshot
+/// instead of just printing it. See [`check_snapshot`].
+pub fn run_test_expect(
+    command: &str,
+    expect_path: &Path,
+    bless: bool,
+    rules: &[(Regex, String)],
+    verbose: u8,
+) -> Result<()> {
+    let summary = capture_test_output(command, verbose, rules)?;
+    check_snapshot("rtk test", &summary, expect_path, bless)
+}
+
+/// Compare `actual` against the contents of `expect_path`, ui_test/compiletest
+/// style: with `bless`, the snapshot is (over)written with `actual` and
+/// treated as a pass; otherwise a missing or differing snapshot prints a
+/// unified diff and exits non-zero so CI fails on an undeclared output change.
+fn check_snapshot(label: &str, actual: &str, expect_path: &Path, bless: bool) -> Result<()> {
+    if bless {
+        std::fs::write(expect_path, actual)
+            .with_context(|| format!("Failed to write snapshot {}", expect_path.display()))?;
+        println!("✅ {label}: snapshot written to {}", expect_path.display());
+        return Ok(());
+    }
+
+    if !expect_path.exists() {
+        eprintln!(
+            "❌ {label}: no snapshot at {} (run with --bless to create one)",
+            expect_path.display()
+        );
+        std::process::exit(1);
+    }
+
+    let expected = std::fs::read_to_string(expect_path)
+        .with_context(|| format!("Failed to read snapshot {}", expect_path.display()))?;
+
+    if expected == actual {
+        println!("✅ {label}: matches snapshot {}", expect_path.display());
+        return Ok(());
+    }
+
+    eprintln!(
+        "❌ {label}: output differs from snapshot {}",
+        expect_path.display()
+    );
+    if let Some(diff) = crate::init::render_unified_diff(&expected, actual, &expect_path.display().to_string()) {
+        eprintln!("{diff}");
+    }
+    std::process::exit(1);
+}
+
+/// Run the command and return `run_err`'s filtered/summary string without
+/// printing it or exiting, for callers (`run_err_expect`) that need to
+/// compare it against something first.
+fn capture_err_output(command: &str, verbose: u8, rules: &[(Regex, String)]) -> Result<String> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("Running: {}", command);
+    }
+
+    let output = exec_shell(command).context("Failed to execute command")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = normalize(&format!("{}\n{}", stdout, stderr), rules);
+    let filtered = filter_errors(&raw);
+    let mut rtk = String::new();
+
+    if filtered.is_empty() {
+        if output.status.success() {
+            rtk.push_str("✅ Command completed successfully (no errors)");
+        } else {
+            rtk.push_str(&format!(
+                "❌ Command failed (exit code: {:?})\n",
+                output.status.code()
+            ));
+            let lines: Vec<&str> = raw.lines().collect();
+            for line in lines.iter().rev().take(10).rev() {
+                rtk.push_str(&format!("  {}\n", line));
+            }
+        }
+    } else {
+        rtk.push_str(&filtered);
+    }
+
+    timer.track(command, "rtk run-err", &raw, &rtk);
+    Ok(rtk)
+}
+
+/// Run the command and return `run_test`'s summary string without printing
+/// it or exiting, for callers (`run_test_expect`) that need to compare it
+/// against something first.
+fn capture_test_output(command: &str, verbose: u8, rules: &[(Regex, String)]) -> Result<String> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("Running tests: {}", command);
+    }
+
+    let output = exec_shell(command).context("Failed to execute test command")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = normalize(&format!("{}\n{}", stdout, stderr), rules);
+
+    let summary = extract_test_summary(&raw, command);
+    timer.track(command, "rtk run-test", &raw, &summary);
+    Ok(summary)
+}
+
+fn run_test_once(command: &str, verbose: u8, rules: &[(Regex, String)]) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
     if verbose > 0 {
@@ -79,7 +451,7 @@ pub fn run_test(command: &str, verbose: u8) -> Result<()> {
 
     let stdout = String::from_utf8_lossy(&output.stdout);
     let stderr = String::from_utf8_lossy(&output.stderr);
-    let raw = format!("{}\n{}", stdout, stderr);
+    let raw = normalize(&format!("{}\n{}", stdout, stderr), rules);
 
     let summary = extract_test_summary(&raw, command);
     println!("{}", summary);
@@ -87,6 +459,396 @@ pub fn run_test(command: &str, verbose: u8) -> Result<()> {
     Ok(())
 }
 
+/// Run a Rust command (cargo build/check/clippy, etc.) with
+/// `--message-format=json`, apply every `MachineApplicable` suggestion it
+/// surfaces the way rustfix/compiletest do, then re-run the original command
+/// through [`run_err_once`] to show what's left. `--dry-run` previews the
+/// edits as a unified diff per file instead of writing them.
+pub fn run_fix(command: &str, dry_run: bool, verbose: u8) -> Result<()> {
+    let json_command = if command.contains("--message-format=json") {
+        command.to_string()
+    } else {
+        format!("{} --message-format=json", command)
+    };
+
+    if verbose > 0 {
+        eprintln!("Running: {}", json_command);
+    }
+
+    let output = exec_shell(&json_command).context("Failed to execute command")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let edits = crate::cargo_cmd::collect_machine_fixes(&stdout);
+
+    if edits.is_empty() {
+        println!("No machine-applicable fixes found.");
+        return Ok(());
+    }
+
+    if dry_run {
+        let contents = crate::cargo_cmd::compute_fixed_contents(&edits)?;
+        let mut files: Vec<&String> = contents.keys().collect();
+        files.sort();
+        for file in files {
+            let (old, new) = &contents[file];
+            if let Some(diff) = crate::init::render_unified_diff(old, new, file) {
+                println!("{}", diff);
+            }
+        }
+        println!("{} file(s) would be fixed (dry run, nothing written)", contents.len());
+        return Ok(());
+    }
+
+    let mut by_file: std::collections::HashSet<&str> = std::collections::HashSet::new();
+    for edit in &edits {
+        by_file.insert(edit.file.as_str());
+    }
+    let applied = crate::cargo_cmd::apply_fixes(&edits)?;
+    println!("applied {} fix(es) across {} file(s)\n", applied, by_file.len());
+
+    run_err_once(command, verbose, &[])
+}
+
+fn exec_shell(command: &str) -> std::io::Result<Output> {
+    if cfg!(target_os = "windows") {
+        Command::new("cmd")
+            .args(["/C", command])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+    } else {
+        Command::new("sh")
+            .args(["-c", command])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+    }
+}
+
+/// A machine-parsed test run, built from a framework's JSON reporter instead
+/// of scraped stdout, so counts and failure names are exact.
+#[derive(Debug, Clone, Default)]
+pub struct TestReport {
+    pub passed: usize,
+    pub failed: usize,
+    pub ignored: usize,
+    pub failures: Vec<TestFailure>,
+    pub duration: Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TestFailure {
+    pub name: String,
+    pub message: String,
+    pub stdout: String,
+}
+
+/// Which JSON test reporter to ask for, based on the same framework
+/// detection `extract_test_summary` uses. `Pytest` carries the temp file its
+/// `--report-log` is written to, since that reporter writes JSONL to a file
+/// rather than stdout.
+enum TestFramework {
+    Cargo,
+    Go,
+    Pytest(tempfile::NamedTempFile),
+}
+
+/// Build the command line and framework tag for a JSON-reporter run, or
+/// `None` if the command doesn't match a framework with a JSON reporter rtk
+/// knows how to parse (e.g. jest, deno test) - callers should fall back to
+/// the regex-based summary in that case.
+fn json_reporter_command(command: &str) -> Option<(String, TestFramework)> {
+    if command.contains("cargo test") {
+        Some((
+            format!("{} -- -Z unstable-options --format json", command),
+            TestFramework::Cargo,
+        ))
+    } else if command.contains("go test") {
+        let cmd = if command.contains("-json") {
+            command.to_string()
+        } else {
+            format!("{} -json", command)
+        };
+        Some((cmd, TestFramework::Go))
+    } else if command.contains("pytest") {
+        let file = tempfile::NamedTempFile::new().ok()?;
+        let cmd = format!("{} --report-log={}", command, file.path().display());
+        Some((cmd, TestFramework::Pytest(file)))
+    } else {
+        None
+    }
+}
+
+/// Run tests via a framework's machine-readable JSON reporter and render the
+/// same ❌/📊 summary from structured counts instead of regex-scraped text.
+/// Falls back to [`run_test_once`]'s heuristics when the command's framework
+/// has no known JSON reporter, or the reporter's output doesn't parse.
+pub fn run_test_json(command: &str, verbose: u8) -> Result<()> {
+    let Some((json_command, framework)) = json_reporter_command(command) else {
+        if verbose > 0 {
+            eprintln!("No JSON reporter for this test command, falling back to text parsing");
+        }
+        return run_test_once(command, verbose, &[]);
+    };
+
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("Running tests: {}", json_command);
+    }
+
+    let output = exec_shell(&json_command).context("Failed to execute test command")?;
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let report = match framework {
+        TestFramework::Cargo => parse_cargo_test_json(&stdout),
+        TestFramework::Go => parse_go_test_json(&stdout),
+        TestFramework::Pytest(file) => {
+            let log = std::fs::read_to_string(file.path()).unwrap_or_default();
+            parse_pytest_report_log(&log)
+        }
+    };
+
+    let summary = match report {
+        Some(report) => render_test_report(&report),
+        None => extract_test_summary(&raw, command),
+    };
+
+    println!("{}", summary);
+    timer.track(command, "rtk run-test --json", &raw, &summary);
+    Ok(())
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoTestEvent {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    event: Option<String>,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    stdout: Option<String>,
+    #[serde(default)]
+    passed: Option<usize>,
+    #[serde(default)]
+    failed: Option<usize>,
+    #[serde(default)]
+    ignored: Option<usize>,
+    #[serde(default)]
+    exec_time: Option<f64>,
+}
+
+/// Parse `cargo test -- -Z unstable-options --format json` ndjson: one
+/// `"test"` event per test outcome and a trailing `"suite"` event carrying
+/// the authoritative totals, which is what's used for the summary counts -
+/// per-test events only supply failure names/messages.
+fn parse_cargo_test_json(output: &str) -> Option<TestReport> {
+    let mut report = TestReport::default();
+    let mut saw_suite_result = false;
+
+    for line in output.lines() {
+        let line = line.trim();
+        if !line.starts_with('{') {
+            continue;
+        }
+        let event: CargoTestEvent = match serde_json::from_str(line) {
+            Ok(e) => e,
+            Err(_) => continue,
+        };
+
+        match event.kind.as_str() {
+            "test" if event.event.as_deref() == Some("failed") => {
+                report.failures.push(TestFailure {
+                    name: event.name.unwrap_or_default(),
+                    message: event.stdout.clone().unwrap_or_default(),
+                    stdout: event.stdout.unwrap_or_default(),
+                });
+            }
+            "suite" if event.event.as_deref() != Some("started") => {
+                report.passed = event.passed.unwrap_or(0);
+                report.failed = event.failed.unwrap_or(0);
+                report.ignored = event.ignored.unwrap_or(0);
+                report.duration = event.exec_time;
+                saw_suite_result = true;
+            }
+            _ => {}
+        }
+    }
+
+    saw_suite_result.then_some(report)
+}
+
+#[derive(Debug, Deserialize)]
+struct GoTestEvent {
+    #[serde(rename = "Action")]
+    action: String,
+    #[serde(rename = "Test", default)]
+    test: Option<String>,
+    #[serde(rename = "Output", default)]
+    output: Option<String>,
+    #[serde(rename = "Elapsed", default)]
+    elapsed: Option<f64>,
+}
+
+/// Parse `go test -json` ndjson. Only events carrying a `Test` field are
+/// counted (package-level `pass`/`fail` events have no `Test` and would
+/// double-count); `output` events are buffered per test so a `fail` event
+/// can attach the test's captured output as the failure message.
+fn parse_go_test_json(output: &str) -> Option<TestReport> {
+    let mut report = TestReport::default();
+    let mut outputs: HashMap<String, String> = HashMap::new();
+    let mut saw_any = false;
+
+    for line in output.lines() {
+        let line = line.trim();
+        if !line.starts_with('{') {
+            continue;
+        }
+        let event: GoTestEvent = match serde_json::from_str(line) {
+            Ok(e) => e,
+            Err(_) => continue,
+        };
+
+        let Some(test_name) = event.test else {
+            continue;
+        };
+
+        match event.action.as_str() {
+            "output" => {
+                outputs
+                    .entry(test_name)
+                    .or_default()
+                    .push_str(event.output.as_deref().unwrap_or(""));
+            }
+            "pass" => {
+                report.passed += 1;
+                report.duration = event.elapsed.or(report.duration);
+                saw_any = true;
+            }
+            "fail" => {
+                report.failed += 1;
+                report.duration = event.elapsed.or(report.duration);
+                saw_any = true;
+                report.failures.push(TestFailure {
+                    name: test_name.clone(),
+                    message: outputs.get(&test_name).cloned().unwrap_or_default(),
+                    stdout: outputs.get(&test_name).cloned().unwrap_or_default(),
+                });
+            }
+            "skip" => {
+                report.ignored += 1;
+                saw_any = true;
+            }
+            _ => {}
+        }
+    }
+
+    saw_any.then_some(report)
+}
+
+#[derive(Debug, Deserialize)]
+struct PytestReportLogEntry {
+    #[serde(rename = "$report_type")]
+    report_type: String,
+    #[serde(default)]
+    when: Option<String>,
+    #[serde(default)]
+    outcome: Option<String>,
+    #[serde(default)]
+    nodeid: Option<String>,
+    #[serde(default)]
+    longrepr: Option<serde_json::Value>,
+    #[serde(default)]
+    duration: Option<f64>,
+}
+
+/// Parse `pytest --report-log=<file>` JSONL. Only `"TestReport"` entries for
+/// the `call` phase count toward pass/fail/skip; a failure during `setup`
+/// (a fixture error) is also counted as a failure since the test body never
+/// got a `call` phase to report one.
+fn parse_pytest_report_log(output: &str) -> Option<TestReport> {
+    let mut report = TestReport::default();
+    let mut total_duration = 0.0;
+    let mut saw_any = false;
+
+    for line in output.lines() {
+        let line = line.trim();
+        if !line.starts_with('{') {
+            continue;
+        }
+        let entry: PytestReportLogEntry = match serde_json::from_str(line) {
+            Ok(e) => e,
+            Err(_) => continue,
+        };
+
+        if entry.report_type != "TestReport" {
+            continue;
+        }
+
+        let when = entry.when.as_deref().unwrap_or("");
+        let outcome = entry.outcome.as_deref().unwrap_or("");
+        let is_setup_failure = when == "setup" && outcome == "failed";
+        if when != "call" && !is_setup_failure {
+            continue;
+        }
+
+        saw_any = true;
+        total_duration += entry.duration.unwrap_or(0.0);
+
+        match outcome {
+            "passed" => report.passed += 1,
+            "skipped" => report.ignored += 1,
+            "failed" => {
+                report.failed += 1;
+                report.failures.push(TestFailure {
+                    name: entry.nodeid.unwrap_or_default(),
+                    message: entry.longrepr.map(|v| v.to_string()).unwrap_or_default(),
+                    stdout: String::new(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    if saw_any {
+        report.duration = Some(total_duration);
+    }
+
+    saw_any.then_some(report)
+}
+
+/// Render a [`TestReport`] with the same ❌/📊 layout [`extract_test_summary`]
+/// uses, so `--json` output and the regex fallback look identical at a glance.
+fn render_test_report(report: &TestReport) -> String {
+    let mut output = String::new();
+
+    if !report.failures.is_empty() {
+        output.push_str("❌ FAILURES:\n");
+        for f in report.failures.iter().take(10) {
+            let first_line = f.message.lines().find(|l| !l.trim().is_empty()).unwrap_or("");
+            output.push_str(&format!("  {}: {}\n", f.name, first_line));
+        }
+        if report.failures.len() > 10 {
+            output.push_str(&format!("  ... +{} more failures\n", report.failures.len() - 10));
+        }
+        output.push('\n');
+    }
+
+    output.push_str("📊 SUMMARY:\n");
+    output.push_str(&format!(
+        "  {} passed, {} failed, {} ignored",
+        report.passed, report.failed, report.ignored
+    ));
+    if let Some(duration) = report.duration {
+        output.push_str(&format!(" in {:.2}s", duration));
+    }
+    output.push('\n');
+
+    output
+}
+
 fn filter_errors(output: &str) -> String {
     lazy_static::lazy_static! {
         static ref ERROR_PATTERNS: Vec<Regex> = vec![
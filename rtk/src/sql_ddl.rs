@@ -0,0 +1,572 @@
+//! A small SQL DDL statement splitter/classifier backing the Prisma
+//! wrapper's migrate/db-push filters. Earlier versions of those filters
+//! scanned individual lines for substrings like `"CREATE TABLE"`, which
+//! miscounts when a statement spans multiple lines, a keyword appears
+//! inside a string literal or comment, or several statements share one
+//! line. This module instead splits on statement boundaries first and
+//! classifies each statement as a whole.
+use crate::parser::{JsonFormatter, SCHEMA_VERSION};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A SQL table identifier, optionally schema-qualified (e.g.
+/// `"auth"."User"` parses to `schema: Some("auth"), name: "User"`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct TableName {
+    pub schema: Option<String>,
+    pub name: String,
+}
+
+impl fmt::Display for TableName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.schema {
+            Some(schema) => write!(f, "{}.{}", schema, self.name),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+/// One classified DDL statement.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    CreateTable { table: TableName, columns: usize },
+    AlterTableAddColumn { table: TableName },
+    AlterTableDropColumn { table: TableName },
+    CreateIndex { table: TableName },
+    DropTable { table: TableName },
+    DropIndex,
+    Other,
+}
+
+/// Aggregate counts across a batch of statements, plus the foreign-key
+/// relations found along the way. This is the flat tally
+/// `filter_migrate_dev`/`filter_db_push` fall back to when no statement
+/// carries a schema qualifier; when some do, callers instead bucket
+/// `parse_statements`'s output per schema.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DdlChanges {
+    pub tables_added: usize,
+    pub columns_added: usize,
+    pub columns_dropped: usize,
+    pub tables_dropped: usize,
+    pub relations: Vec<String>,
+    pub indexes: usize,
+}
+
+impl DdlChanges {
+    /// Fold one classified statement's effect into this tally.
+    pub fn record_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::CreateTable { columns, .. } => {
+                self.tables_added += 1;
+                self.columns_added += columns;
+            }
+            Statement::AlterTableAddColumn { .. } => self.columns_added += 1,
+            Statement::AlterTableDropColumn { .. } => self.columns_dropped += 1,
+            Statement::CreateIndex { .. } => self.indexes += 1,
+            Statement::DropTable { .. } => self.tables_dropped += 1,
+            Statement::DropIndex | Statement::Other => {}
+        }
+    }
+
+    /// A terse, comma-joined rendering of the non-zero counts (e.g. `+2
+    /// table(s), ~3 index(es)`), or `None` when nothing changed.
+    pub fn summary_line(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        if self.tables_added > 0 {
+            parts.push(format!("+{} table(s)", self.tables_added));
+        }
+        if self.columns_added > 0 {
+            parts.push(format!("+{} column(s)", self.columns_added));
+        }
+        if self.columns_dropped > 0 {
+            parts.push(format!("-{} column(s)", self.columns_dropped));
+        }
+        if self.tables_dropped > 0 {
+            parts.push(format!("-{} table(s)", self.tables_dropped));
+        }
+        if self.indexes > 0 {
+            parts.push(format!("~{} index(es)", self.indexes));
+        }
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join(", "))
+        }
+    }
+}
+
+fn ddl_changes_json(changes: &DdlChanges) -> Value {
+    json!({
+        "tables_added": changes.tables_added,
+        "columns_added": changes.columns_added,
+        "columns_dropped": changes.columns_dropped,
+        "tables_dropped": changes.tables_dropped,
+        "indexes": changes.indexes,
+        "relations": changes.relations,
+    })
+}
+
+/// Machine-readable counterpart to `filter_migrate_dev`/`filter_db_push`'s
+/// text rendering - the same per-schema [`DdlChanges`] tallies and foreign
+/// key relations, structured for CI/agent tooling instead of pretty-printed.
+/// Unlike the text path's "flat" fallback when no table carries a schema
+/// qualifier, this always reports by namespace (unqualified tables bucket
+/// under `"public"`), since a JSON consumer has no reason to prefer the
+/// terser, ambiguity-prone flat form.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PrismaSummary {
+    pub migration_name: Option<String>,
+    pub applied: Option<bool>,
+    pub schemas: BTreeMap<String, DdlChanges>,
+    pub relations: Vec<String>,
+}
+
+impl JsonFormatter for PrismaSummary {
+    fn format_json(&self) -> Value {
+        let schemas: Map<String, Value> = self
+            .schemas
+            .iter()
+            .map(|(schema, changes)| (schema.clone(), ddl_changes_json(changes)))
+            .collect();
+        json!({
+            "schema_version": SCHEMA_VERSION,
+            "kind": "prisma_summary",
+            "migration_name": self.migration_name,
+            "applied": self.applied,
+            "schemas": schemas,
+            "relations": self.relations,
+        })
+    }
+
+    fn format_ndjson(&self) -> String {
+        self.schemas
+            .iter()
+            .map(|(schema, changes)| {
+                let mut value = ddl_changes_json(changes);
+                value["schema_version"] = json!(SCHEMA_VERSION);
+                value["kind"] = json!("ddl_changes");
+                value["schema"] = json!(schema);
+                value.to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Split `sql` into individual statements on top-level `;`, respecting
+/// single/double-quoted strings and `--`/`/* */` comments so a semicolon
+/// or keyword inside either doesn't fool the split/classify step.
+pub fn split_statements(sql: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut chars = sql.chars().peekable();
+    let mut in_single_quote = false;
+    let mut in_double_quote = false;
+
+    while let Some(c) = chars.next() {
+        if in_single_quote {
+            current.push(c);
+            if c == '\'' {
+                in_single_quote = false;
+            }
+            continue;
+        }
+        if in_double_quote {
+            current.push(c);
+            if c == '"' {
+                in_double_quote = false;
+            }
+            continue;
+        }
+
+        match c {
+            '\'' => {
+                in_single_quote = true;
+                current.push(c);
+            }
+            '"' => {
+                in_double_quote = true;
+                current.push(c);
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = '\0';
+                for next in chars.by_ref() {
+                    if prev == '*' && next == '/' {
+                        break;
+                    }
+                    prev = next;
+                }
+            }
+            ';' => {
+                let trimmed = current.trim();
+                if !trimmed.is_empty() {
+                    statements.push(trimmed.to_string());
+                }
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+
+    let trimmed = current.trim();
+    if !trimmed.is_empty() {
+        statements.push(trimmed.to_string());
+    }
+
+    statements
+}
+
+/// Parse a single whitespace-delimited SQL identifier token: strip any
+/// trailing `(...)` argument list and surrounding quote/backtick
+/// characters, then split a `schema.table` prefix off when present.
+fn parse_qualified_identifier(token: &str) -> TableName {
+    let token = match token.find('(') {
+        Some(idx) => &token[..idx],
+        None => token,
+    };
+    let token = token.trim_matches(|c| c == ';' || c == ',');
+
+    match token.split_once('.') {
+        Some((schema, name)) => TableName {
+            schema: Some(schema.trim_matches(|c| c == '`' || c == '"').to_string()),
+            name: name.trim_matches(|c| c == '`' || c == '"').to_string(),
+        },
+        None => TableName {
+            schema: None,
+            name: token.trim_matches(|c| c == '`' || c == '"').to_string(),
+        },
+    }
+}
+
+/// Find the token following the (case-insensitive) keyword, skipping over
+/// `IF [NOT] EXISTS` when it sits between the keyword and the identifier.
+fn table_after_keyword(statement: &str, keyword: &str) -> Option<TableName> {
+    let tokens: Vec<&str> = statement.split_whitespace().collect();
+    for (i, tok) in tokens.iter().enumerate() {
+        if tok.eq_ignore_ascii_case(keyword) {
+            let mut j = i + 1;
+            while j < tokens.len()
+                && (tokens[j].eq_ignore_ascii_case("if")
+                    || tokens[j].eq_ignore_ascii_case("not")
+                    || tokens[j].eq_ignore_ascii_case("exists"))
+            {
+                j += 1;
+            }
+            if j < tokens.len() {
+                return Some(parse_qualified_identifier(tokens[j]));
+            }
+        }
+    }
+    None
+}
+
+/// Count the top-level, comma-separated items inside the outermost
+/// parentheses of a `CREATE TABLE (...)` statement, ignoring commas nested
+/// in further parens (e.g. inside a `DECIMAL(10, 2)` column type).
+fn count_top_level_columns(statement: &str) -> usize {
+    let Some(start) = statement.find('(') else {
+        return 0;
+    };
+
+    let mut depth = 0usize;
+    let mut items = 0usize;
+    let mut saw_any_char = false;
+    let mut in_single_quote = false;
+    let mut in_double_quote = false;
+
+    for c in statement[start..].chars() {
+        if in_single_quote {
+            if c == '\'' {
+                in_single_quote = false;
+            }
+            continue;
+        }
+        if in_double_quote {
+            if c == '"' {
+                in_double_quote = false;
+            }
+            continue;
+        }
+
+        match c {
+            '\'' => in_single_quote = true,
+            '"' => in_double_quote = true,
+            '(' => {
+                depth += 1;
+            }
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            ',' if depth == 1 => items += 1,
+            c if depth >= 1 && !c.is_whitespace() => saw_any_char = true,
+            _ => {}
+        }
+    }
+
+    if saw_any_char {
+        items + 1
+    } else {
+        0
+    }
+}
+
+/// Pull out each `FOREIGN KEY (...) REFERENCES "Target"(...)` in a
+/// statement as a relation to the named target (schema-qualified when the
+/// target identifier is).
+pub fn relations_in(statement: &str) -> Vec<String> {
+    let tokens: Vec<&str> = statement.split_whitespace().collect();
+    let mut relations = Vec::new();
+    for (i, tok) in tokens.iter().enumerate() {
+        if tok.eq_ignore_ascii_case("REFERENCES") && i + 1 < tokens.len() {
+            relations.push(parse_qualified_identifier(tokens[i + 1]).to_string());
+        }
+    }
+    relations
+}
+
+/// Leading keywords `classify` recognizes, used by `extract_sql_block` to
+/// find where the embedded SQL actually starts.
+const DDL_KEYWORDS: &[&str] = &[
+    "CREATE TABLE",
+    "CREATE UNIQUE INDEX",
+    "CREATE INDEX",
+    "ALTER TABLE",
+    "DROP TABLE",
+    "DROP INDEX",
+];
+
+/// Callers like the Prisma wrapper hand this module a whole CLI output
+/// blob, not isolated SQL - narrative lines like `Applying migration
+/// ...` precede the embedded DDL with no statement-terminating `;`
+/// between them, which would otherwise get glued onto the first real
+/// statement and defeat its leading-keyword classification. Return the
+/// suffix of `text` starting at the first line that looks like DDL, or
+/// `""` if none does.
+pub fn extract_sql_block(text: &str) -> &str {
+    let mut offset = 0;
+    for segment in text.split_inclusive('\n') {
+        let upper = segment.trim().to_uppercase();
+        if DDL_KEYWORDS.iter().any(|kw| upper.starts_with(kw)) {
+            return &text[offset..];
+        }
+        offset += segment.len();
+    }
+    ""
+}
+
+/// Classify a single statement by its leading keyword.
+pub fn classify(statement: &str) -> Statement {
+    let upper = statement.to_uppercase();
+    let trimmed = upper.trim_start();
+
+    if trimmed.starts_with("CREATE TABLE") {
+        let table = table_after_keyword(statement, "TABLE").unwrap_or_default();
+        let columns = count_top_level_columns(statement);
+        return Statement::CreateTable { table, columns };
+    }
+    if trimmed.starts_with("CREATE INDEX") || trimmed.starts_with("CREATE UNIQUE INDEX") {
+        let table = table_after_keyword(statement, "ON").unwrap_or_default();
+        return Statement::CreateIndex { table };
+    }
+    if trimmed.starts_with("ALTER TABLE") {
+        let table = table_after_keyword(statement, "TABLE").unwrap_or_default();
+        if trimmed.contains("DROP COLUMN") {
+            return Statement::AlterTableDropColumn { table };
+        }
+        if trimmed.contains("ADD COLUMN") {
+            return Statement::AlterTableAddColumn { table };
+        }
+        return Statement::Other;
+    }
+    if trimmed.starts_with("DROP TABLE") {
+        let table = table_after_keyword(statement, "TABLE").unwrap_or_default();
+        return Statement::DropTable { table };
+    }
+    if trimmed.starts_with("DROP INDEX") {
+        return Statement::DropIndex;
+    }
+
+    Statement::Other
+}
+
+/// Split and classify `sql`'s statements.
+pub fn parse_statements(sql: &str) -> Vec<Statement> {
+    split_statements(sql).iter().map(|s| classify(s)).collect()
+}
+
+/// Split, classify and pull relations out of `sql`'s statements in one
+/// pass, pairing each classified `Statement` with the raw statement text
+/// it came from (callers that need per-statement relations - `parse` only
+/// returns the flat total - can run `relations_in` over the second field).
+pub fn parse_statements_with_source(sql: &str) -> Vec<(Statement, String)> {
+    split_statements(sql)
+        .into_iter()
+        .map(|raw| {
+            let statement = classify(&raw);
+            (statement, raw)
+        })
+        .collect()
+}
+
+/// Parse `sql`'s statements into a flat `DdlChanges` tally, treating every
+/// table as one namespace. Callers that need a per-schema breakdown should
+/// use `parse_statements_with_source` and bucket by each statement's
+/// `TableName.schema` instead.
+pub fn parse(sql: &str) -> DdlChanges {
+    let mut changes = DdlChanges::default();
+    for raw in split_statements(sql) {
+        let statement = classify(&raw);
+        changes.record_statement(&statement);
+        changes.relations.extend(relations_in(&raw));
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_statements_respects_quotes_and_comments() {
+        let sql = r#"
+            -- a comment with a ; in it
+            CREATE TABLE "User" ("id" TEXT NOT NULL, "note" TEXT DEFAULT 'semi;colon');
+            /* block comment; with semicolons; inside */
+            CREATE TABLE "Post" ("id" TEXT NOT NULL);
+        "#;
+        let statements = split_statements(sql);
+        assert_eq!(statements.len(), 2);
+        assert!(statements[0].contains("User"));
+        assert!(statements[1].contains("Post"));
+    }
+
+    #[test]
+    fn test_split_statements_multiple_on_one_line() {
+        let sql = r#"CREATE TABLE "A" ("id" TEXT); CREATE TABLE "B" ("id" TEXT);"#;
+        let statements = split_statements(sql);
+        assert_eq!(statements.len(), 2);
+    }
+
+    #[test]
+    fn test_classify_create_table_counts_top_level_columns() {
+        let statement =
+            r#"CREATE TABLE "Invoice" ("id" TEXT NOT NULL, "amount" DECIMAL(10, 2), "note" TEXT)"#;
+        match classify(statement) {
+            Statement::CreateTable { table, columns } => {
+                assert_eq!(table.name, "Invoice");
+                assert_eq!(columns, 3);
+            }
+            other => panic!("expected CreateTable, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_classify_create_table_schema_qualified() {
+        let statement = r#"CREATE TABLE "auth"."User" ("id" TEXT NOT NULL)"#;
+        match classify(statement) {
+            Statement::CreateTable { table, .. } => {
+                assert_eq!(table.schema, Some("auth".to_string()));
+                assert_eq!(table.name, "User");
+            }
+            other => panic!("expected CreateTable, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_extract_sql_block_drops_leading_narrative_lines() {
+        let output = "Applying migration 20260128_add_sessions\n\nCREATE TABLE \"Session\" (\"id\" TEXT NOT NULL);\n\n✓ Migration applied\n";
+        let block = extract_sql_block(output);
+        assert!(block.starts_with("CREATE TABLE"));
+        let statements = parse_statements(block);
+        assert!(matches!(statements[0], Statement::CreateTable { .. }));
+    }
+
+    #[test]
+    fn test_extract_sql_block_empty_when_no_ddl_present() {
+        assert_eq!(extract_sql_block("Your database is already in sync\n"), "");
+    }
+
+    #[test]
+    fn test_classify_alter_table_add_vs_drop_column() {
+        assert!(matches!(
+            classify(r#"ALTER TABLE "User" ADD COLUMN "bio" TEXT"#),
+            Statement::AlterTableAddColumn { .. }
+        ));
+        assert!(matches!(
+            classify(r#"ALTER TABLE "User" DROP COLUMN "bio""#),
+            Statement::AlterTableDropColumn { .. }
+        ));
+    }
+
+    #[test]
+    fn test_classify_create_index_targets_indexed_table() {
+        let statement = r#"CREATE INDEX "user_email_idx" ON "public"."User"("email")"#;
+        match classify(statement) {
+            Statement::CreateIndex { table } => {
+                assert_eq!(table.schema, Some("public".to_string()));
+                assert_eq!(table.name, "User");
+            }
+            other => panic!("expected CreateIndex, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_extract_relations_from_foreign_key() {
+        let statement = r#"ALTER TABLE "Post" ADD CONSTRAINT "fk" FOREIGN KEY ("authorId") REFERENCES "User"("id") ON DELETE CASCADE"#;
+        assert_eq!(relations_in(statement), vec!["User".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_aggregates_flat_changes() {
+        let sql = r#"
+            CREATE TABLE "User" ("id" TEXT NOT NULL, "email" TEXT NOT NULL);
+            CREATE TABLE "Post" ("id" TEXT NOT NULL, FOREIGN KEY ("authorId") REFERENCES "User"("id"));
+            CREATE INDEX "post_author_idx" ON "Post"("authorId");
+            ALTER TABLE "User" ADD COLUMN "bio" TEXT;
+        "#;
+        let changes = parse(sql);
+        assert_eq!(changes.tables_added, 2);
+        assert_eq!(changes.columns_added, 2 + 2 + 1); // User(2) + Post(id, FK)(2) + ALTER(1)
+        assert_eq!(changes.indexes, 1);
+        assert_eq!(changes.relations, vec!["User".to_string()]);
+    }
+
+    #[test]
+    fn test_prisma_summary_format_json_groups_by_schema() {
+        let mut schemas = BTreeMap::new();
+        schemas.insert(
+            "public".to_string(),
+            DdlChanges {
+                tables_added: 1,
+                columns_added: 2,
+                ..Default::default()
+            },
+        );
+        let summary = PrismaSummary {
+            migration_name: Some("20260128_add_sessions".to_string()),
+            applied: Some(true),
+            schemas,
+            relations: vec!["User".to_string()],
+        };
+
+        let value = summary.format_json();
+        assert_eq!(value["schema_version"], SCHEMA_VERSION);
+        assert_eq!(value["migration_name"], "20260128_add_sessions");
+        assert_eq!(value["applied"], true);
+        assert_eq!(value["schemas"]["public"]["tables_added"], 1);
+        assert_eq!(value["relations"][0], "User");
+    }
+}
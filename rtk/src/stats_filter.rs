@@ -0,0 +1,170 @@
+//! Rolling-window filters for period statistics tables.
+//!
+//! `rtk gain`'s daily/weekly/monthly breakdowns always render full history;
+//! [`StatsFilter`] lets a caller restrict a [`PeriodStats`] table to
+//! "today", "this week", or "this month" before printing. Predicates take
+//! the reference ("now") date as an explicit argument instead of calling
+//! `Local::now()` internally, so unit tests can assert exact bucket
+//! membership around week/month boundaries deterministically.
+
+use crate::display_helpers::{print_period_table_locale, PeriodStats};
+use crate::locale::Locale;
+use chrono::{Datelike, Duration, NaiveDate};
+
+/// A `[from, to)` date window a [`PeriodStats`] row's `start_date()` must
+/// fall in to survive [`print_period_table_filtered`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatsFilter {
+    from: NaiveDate,
+    to: NaiveDate,
+}
+
+impl StatsFilter {
+    /// Just `reference`'s calendar day.
+    pub fn today(reference: NaiveDate) -> Self {
+        Self {
+            from: reference,
+            to: reference + Duration::days(1),
+        }
+    }
+
+    /// The Sunday-to-Saturday week containing `reference`, matching
+    /// [`crate::tracking::Tracker::get_by_week`]'s SQLite `'weekday 0'`
+    /// bucketing convention (weeks start on Sunday).
+    pub fn current_week(reference: NaiveDate) -> Self {
+        let start = reference - Duration::days(reference.weekday().num_days_from_sunday() as i64);
+        Self {
+            from: start,
+            to: start + Duration::days(7),
+        }
+    }
+
+    /// The calendar month containing `reference`.
+    pub fn current_month(reference: NaiveDate) -> Self {
+        let start = reference
+            .with_day(1)
+            .expect("day 1 is always valid for any month");
+        let to = if start.month() == 12 {
+            NaiveDate::from_ymd_opt(start.year() + 1, 1, 1)
+        } else {
+            NaiveDate::from_ymd_opt(start.year(), start.month() + 1, 1)
+        }
+        .expect("first of a month is always valid");
+        Self { from: start, to }
+    }
+
+    /// Whether `date` falls within `[from, to)`.
+    pub fn matches(&self, date: NaiveDate) -> bool {
+        date >= self.from && date < self.to
+    }
+}
+
+/// Print `data` restricted to `filter`'s window, with the TOTAL row
+/// recomputed over only the surviving rows — rather than filtering after
+/// [`crate::display_helpers::print_period_table`] has already summed
+/// everything.
+pub fn print_period_table_filtered<T: PeriodStats>(data: &[T], filter: StatsFilter) {
+    print_period_table_filtered_locale(data, filter, Locale::default());
+}
+
+/// Same as [`print_period_table_filtered`], but rendered in `locale`.
+pub fn print_period_table_filtered_locale<T: PeriodStats>(
+    data: &[T],
+    filter: StatsFilter,
+    locale: Locale,
+) {
+    let filtered: Vec<&T> = data
+        .iter()
+        .filter(|row| filter.matches(row.start_date()))
+        .collect();
+    print_period_table_locale(&filtered, locale);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_today_matches_only_reference_day() {
+        let filter = StatsFilter::today(date(2026, 2, 13));
+        assert!(filter.matches(date(2026, 2, 13)));
+        assert!(!filter.matches(date(2026, 2, 12)));
+        assert!(!filter.matches(date(2026, 2, 14)));
+    }
+
+    #[test]
+    fn test_current_week_spans_sunday_to_saturday() {
+        // 2026-02-13 is a Friday; the containing week is Sun 02-08..Sat 02-14.
+        let filter = StatsFilter::current_week(date(2026, 2, 13));
+        assert!(filter.matches(date(2026, 2, 8)));
+        assert!(filter.matches(date(2026, 2, 14)));
+        assert!(!filter.matches(date(2026, 2, 7)));
+        assert!(!filter.matches(date(2026, 2, 15)));
+    }
+
+    #[test]
+    fn test_current_week_reference_is_sunday() {
+        // 2026-02-08 is itself a Sunday — should anchor the window, not
+        // shift back another week.
+        let filter = StatsFilter::current_week(date(2026, 2, 8));
+        assert!(filter.matches(date(2026, 2, 8)));
+        assert!(!filter.matches(date(2026, 2, 1)));
+    }
+
+    #[test]
+    fn test_current_month_spans_full_calendar_month() {
+        let filter = StatsFilter::current_month(date(2026, 2, 13));
+        assert!(filter.matches(date(2026, 2, 1)));
+        assert!(filter.matches(date(2026, 2, 28)));
+        assert!(!filter.matches(date(2026, 1, 31)));
+        assert!(!filter.matches(date(2026, 3, 1)));
+    }
+
+    #[test]
+    fn test_current_month_handles_december_rollover() {
+        let filter = StatsFilter::current_month(date(2026, 12, 15));
+        assert!(filter.matches(date(2026, 12, 31)));
+        assert!(!filter.matches(date(2027, 1, 1)));
+    }
+
+    #[test]
+    fn test_print_period_table_filtered_smoke() {
+        use crate::tracking::DayStats;
+
+        let data = vec![
+            DayStats {
+                date: "2026-02-12".to_string(),
+                commands: 5,
+                input_tokens: 500,
+                output_tokens: 100,
+                saved_tokens: 400,
+                savings_pct: 80.0,
+                total_time_ms: 500,
+                avg_time_ms: 100,
+                p50_time_ms: 100,
+                p95_time_ms: 120,
+                p99_time_ms: 130,
+            },
+            DayStats {
+                date: "2026-02-13".to_string(),
+                commands: 10,
+                input_tokens: 1000,
+                output_tokens: 200,
+                saved_tokens: 800,
+                savings_pct: 80.0,
+                total_time_ms: 1000,
+                avg_time_ms: 100,
+                p50_time_ms: 100,
+                p95_time_ms: 120,
+                p99_time_ms: 130,
+            },
+        ];
+
+        // Should not panic, and should only render the 02-13 row.
+        print_period_table_filtered(&data, StatsFilter::today(date(2026, 2, 13)));
+    }
+}
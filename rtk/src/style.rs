@@ -0,0 +1,63 @@
+//! Terminal color resolution and ANSI styling for text report formatters.
+//!
+//! Resolution follows the common `--color auto|always|never` convention:
+//! `auto` colors only when stdout is a TTY and [`NO_COLOR`](https://no-color.org)
+//! is unset; `never` (and JSON/CSV output paths, which should call
+//! [`Style::plain`] instead of resolving `--color`) always stay plain.
+
+use std::io::IsTerminal;
+
+/// Savings percentage at or above which a figure is highlighted green as a
+/// "good" result, shared by `gain`'s and `discover`'s text formatters.
+pub const GOOD_SAVINGS_PCT: f64 = 50.0;
+
+/// Resolved color choice for one invocation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Style {
+    enabled: bool,
+}
+
+impl Style {
+    /// Resolve a `--color auto|always|never` value against stdout and `NO_COLOR`.
+    pub fn resolve(color: &str) -> Self {
+        let enabled = match color {
+            "always" => true,
+            "never" => false,
+            _ => std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal(),
+        };
+        Style { enabled }
+    }
+
+    /// Color forced off, for JSON/CSV/other machine-readable output paths.
+    pub fn plain() -> Self {
+        Style { enabled: false }
+    }
+
+    fn wrap(&self, code: &str, text: &str) -> String {
+        if self.enabled {
+            format!("\x1b[{code}m{text}\x1b[0m")
+        } else {
+            text.to_string()
+        }
+    }
+
+    pub fn green(&self, text: &str) -> String {
+        self.wrap("32", text)
+    }
+
+    pub fn red(&self, text: &str) -> String {
+        self.wrap("31", text)
+    }
+
+    pub fn yellow(&self, text: &str) -> String {
+        self.wrap("33", text)
+    }
+
+    pub fn bold(&self, text: &str) -> String {
+        self.wrap("1", text)
+    }
+
+    pub fn dim(&self, text: &str) -> String {
+        self.wrap("2", text)
+    }
+}
@@ -0,0 +1,74 @@
+//! "Did you mean ...?" suggestions for mistyped subcommand/filter names.
+//!
+//! Uses the classic Levenshtein edit-distance DP over two strings, the
+//! same algorithm cargo uses for its own "did you mean" hints, so a typo
+//! close to a known name gets a suggestion instead of a bare error.
+
+/// Minimum-edit-distance between `a` and `b` (insertions, deletions, and
+/// substitutions all cost 1).
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut d = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=b.len() {
+        d[0][j] = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+        }
+    }
+
+    d[a.len()][b.len()]
+}
+
+/// Maximum edit distance that still counts as "probably a typo of this
+/// name" rather than an unrelated word -- matches cargo's own cutoff, so
+/// `asdf` against `add` doesn't produce a misleading suggestion.
+const MAX_SUGGEST_DISTANCE: usize = 3;
+
+/// Pick the candidate closest to `input`, if any is within
+/// [`MAX_SUGGEST_DISTANCE`].
+pub fn closest<'a>(input: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    candidates
+        .into_iter()
+        .map(|candidate| (candidate, levenshtein(input, candidate)))
+        .filter(|(_, dist)| *dist <= MAX_SUGGEST_DISTANCE)
+        .min_by_key(|(_, dist)| *dist)
+        .map(|(candidate, _)| candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein_identical_strings() {
+        assert_eq!(levenshtein("cargo", "cargo"), 0);
+    }
+
+    #[test]
+    fn test_levenshtein_single_substitution() {
+        assert_eq!(levenshtein("lsit", "list"), 2);
+    }
+
+    #[test]
+    fn test_closest_picks_nearest_candidate() {
+        let candidates = ["list", "log", "lint", "ls"];
+        assert_eq!(closest("lsit", candidates), Some("list"));
+    }
+
+    #[test]
+    fn test_closest_returns_none_beyond_threshold() {
+        let candidates = ["build", "test", "clippy", "check"];
+        assert_eq!(closest("asdf", candidates), None);
+    }
+}
@@ -2,6 +2,7 @@ use crate::tracking;
 use crate::utils::truncate;
 use anyhow::{Context, Result};
 use regex::Regex;
+use serde::Deserialize;
 use std::process::{Command, Stdio};
 
 /// Run a command and provide a heuristic summary
@@ -55,6 +56,8 @@ fn summarize_output(output: &str, command: &str, success: bool) -> String {
     let output_type = detect_output_type(output, command);
 
     match output_type {
+        OutputType::DiffOutput => summarize_diff(output, &mut result),
+        OutputType::Backtrace => summarize_backtrace(output, &mut result),
         OutputType::TestResults => summarize_tests(output, &mut result),
         OutputType::BuildOutput => summarize_build(output, &mut result),
         OutputType::LogOutput => summarize_logs_quick(output, &mut result),
@@ -68,6 +71,8 @@ fn summarize_output(output: &str, command: &str, success: bool) -> String {
 
 #[derive(Debug)]
 enum OutputType {
+    DiffOutput,
+    Backtrace,
     TestResults,
     BuildOutput,
     LogOutput,
@@ -80,7 +85,11 @@ fn detect_output_type(output: &str, command: &str) -> OutputType {
     let cmd_lower = command.to_lowercase();
     let out_lower = output.to_lowercase();
 
-    if cmd_lower.contains("test") || out_lower.contains("passed") && out_lower.contains("failed") {
+    if is_diff_output(output) {
+        OutputType::DiffOutput
+    } else if is_backtrace_output(output) {
+        OutputType::Backtrace
+    } else if cmd_lower.contains("test") || out_lower.contains("passed") && out_lower.contains("failed") {
         OutputType::TestResults
     } else if cmd_lower.contains("build")
         || cmd_lower.contains("compile")
@@ -107,9 +116,537 @@ fn detect_output_type(output: &str, command: &str) -> OutputType {
     }
 }
 
+/// A unified diff has a `diff --git `/`@@ ` hunk marker plus a `--- `/
+/// `+++ ` file-pair header -- cheap enough to check before the other
+/// output-type heuristics, and specific enough not to misfire on prose
+/// that merely mentions "test"/"error".
+fn is_diff_output(output: &str) -> bool {
+    let has_hunk = output
+        .lines()
+        .any(|l| l.starts_with("diff --git ") || l.starts_with("@@ "));
+    let has_file_header = output
+        .lines()
+        .any(|l| l.starts_with("--- ") || l.starts_with("+++ "));
+    has_hunk && has_file_header
+}
+
+/// A panic or `RUST_BACKTRACE=1`/`=full` dump has a `thread '...' panicked
+/// at` line or numbered stack frames (`   N: 0x<addr> - symbol`) -- cheap
+/// and specific enough to check before the test/build/log heuristics,
+/// which would otherwise misfire on the panic message's own "error"/"test"
+/// substrings.
+fn is_backtrace_output(output: &str) -> bool {
+    let has_panic = output.lines().any(|l| l.contains("panicked at"));
+    let frame_re = Regex::new(r"^\s*\d+:\s+0x[0-9a-f]+\s+-?\s*").unwrap();
+    has_panic || output.lines().any(|l| frame_re.is_match(l))
+}
+
+/// One parsed stack frame: its (demangled) symbol and, if the following
+/// line is an `at file:line:col` continuation, its source location.
+struct BacktraceFrame {
+    symbol: String,
+    location: Option<String>,
+}
+
+/// Standard runtime/unwinding frames that carry no information about
+/// *where in user code* a panic happened -- dropped so the summary only
+/// shows the frames someone would actually want to look at.
+fn is_noise_frame(symbol: &str) -> bool {
+    symbol.starts_with("std::")
+        || symbol.starts_with("core::")
+        || symbol.starts_with("alloc::")
+        || symbol.starts_with("__rust_")
+        || symbol.starts_with("rust_begin_unwind")
+        || symbol.contains("backtrace::")
+        || symbol.contains("backtrace_rs::")
+}
+
+/// Extract the panicking thread, message, and `file:line:col` location
+/// from either panic message format: the pre-2021 `'msg', file:line:col`
+/// form, or the newer `at file:line:col` form with the message on its own
+/// line below.
+fn parse_panic_header(output: &str) -> Option<(String, Option<String>, Option<String>)> {
+    let re_old = Regex::new(r"^thread '([^']+)' panicked at '(.*)',\s*(\S+)$").ok()?;
+    let re_new = Regex::new(r"^thread '([^']+)' panicked at ([^\n:]+:\d+:\d+):?\s*(.*)$").ok()?;
+
+    let lines: Vec<&str> = output.lines().collect();
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if let Some(caps) = re_old.captures(trimmed) {
+            return Some((
+                caps[1].to_string(),
+                Some(caps[2].to_string()),
+                Some(caps[3].to_string()),
+            ));
+        }
+        if let Some(caps) = re_new.captures(trimmed) {
+            let thread = caps[1].to_string();
+            let location = caps[2].to_string();
+            let inline_message = caps[3].trim().to_string();
+            let message = if !inline_message.is_empty() {
+                Some(inline_message)
+            } else {
+                lines
+                    .get(i + 1)
+                    .map(|l| l.trim().to_string())
+                    .filter(|l| !l.is_empty())
+            };
+            return Some((thread, message, Some(location)));
+        }
+    }
+    None
+}
+
+/// Parse numbered stack frames (`   N: [0x<addr> -] symbol`, optionally
+/// followed by an `at file:line:col` continuation line), demangling each
+/// symbol along the way.
+fn parse_backtrace_frames(output: &str) -> Vec<BacktraceFrame> {
+    let frame_re = Regex::new(r"^\s*\d+:\s*(?:0x[0-9a-f]+\s*-\s*)?(.+?)\s*$").unwrap();
+    let at_re = Regex::new(r"^\s*at\s+(.+?)\s*$").unwrap();
+
+    let lines: Vec<&str> = output.lines().collect();
+    let mut frames = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        if let Some(caps) = frame_re.captures(lines[i]) {
+            let symbol = demangle_symbol(&caps[1]);
+            let location = lines
+                .get(i + 1)
+                .and_then(|l| at_re.captures(l))
+                .map(|c| c[1].to_string());
+            if location.is_some() {
+                i += 1;
+            }
+            frames.push(BacktraceFrame { symbol, location });
+        }
+        i += 1;
+    }
+    frames
+}
+
+/// Demangle a Rust symbol, trying the legacy `_ZN...E` scheme then the v0
+/// `_R...` scheme, falling back to the raw symbol if neither matches
+/// (e.g. it's already a demangled function path).
+fn demangle_symbol(symbol: &str) -> String {
+    demangle_legacy(symbol)
+        .or_else(|| demangle_v0(symbol))
+        .unwrap_or_else(|| symbol.to_string())
+}
+
+/// Legacy (pre-v0) Rust mangling: `_ZN` + one length-prefixed path
+/// component per segment + a trailing `E`. Each component's declared
+/// length is read off as plain decimal digits, e.g. `_ZN4core3fmt...E` is
+/// the path `core::fmt::...`. The final component is almost always a
+/// `17h<16 hex digits>` disambiguator hash, which is dropped rather than
+/// joined in -- it's noise for a human reading the trace.
+fn demangle_legacy(symbol: &str) -> Option<String> {
+    let mut rest = symbol.strip_prefix("_ZN")?;
+    let mut parts = Vec::new();
+
+    loop {
+        let digits_end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        if digits_end == 0 {
+            break;
+        }
+        let len: usize = rest[..digits_end].parse().ok()?;
+        rest = &rest[digits_end..];
+        if rest.len() < len {
+            return None;
+        }
+        parts.push(rest[..len].to_string());
+        rest = &rest[len..];
+
+        if rest.starts_with('E') {
+            break;
+        }
+        if rest.is_empty() {
+            break;
+        }
+    }
+
+    if parts.is_empty() {
+        return None;
+    }
+
+    if let Some(last) = parts.last() {
+        let is_hash = last.len() == 17
+            && last.starts_with('h')
+            && last[1..].chars().all(|c| c.is_ascii_hexdigit());
+        if is_hash {
+            parts.pop();
+        }
+    }
+
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join("::"))
+    }
+}
+
+/// Best-effort v0 (`_R...`) Rust mangling demangler: handles the common
+/// `C<ident>` (crate root) and `N<ns><path><ident>` (nested path) path
+/// productions, which cover ordinary `crate::module::function` symbols.
+/// Generics, impl blocks, and back-references (`I`, `M`, `X`, `B`, ...)
+/// aren't implemented; those bail out to the raw symbol via the `?`
+/// chain rather than risk rendering something misleading.
+fn demangle_v0(symbol: &str) -> Option<String> {
+    let rest = symbol.strip_prefix("_R")?;
+    let (parts, _rest) = parse_v0_path(rest)?;
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join("::"))
+    }
+}
+
+fn parse_v0_path(input: &str) -> Option<(Vec<String>, &str)> {
+    let mut chars = input.chars();
+    let tag = chars.next()?;
+    let rest = chars.as_str();
+
+    match tag {
+        'C' => {
+            let (ident, rest) = parse_v0_identifier(rest)?;
+            Some((vec![ident], rest))
+        }
+        'N' => {
+            let mut chars = rest.chars();
+            chars.next()?; // namespace tag ('t' type, 'v' value, ...)
+            let rest = chars.as_str();
+            let (mut parts, rest) = parse_v0_path(rest)?;
+            let (ident, rest) = parse_v0_identifier(rest)?;
+            parts.push(ident);
+            Some((parts, rest))
+        }
+        _ => None,
+    }
+}
+
+/// `[<disambiguator>] <decimal-length> <name>`, e.g. `7mycrate` or the
+/// disambiguated `s_07mycrate`. The disambiguator (`s<base62>_`) is
+/// skipped rather than decoded since it doesn't affect the printed name.
+fn parse_v0_identifier(input: &str) -> Option<(String, &str)> {
+    let rest = if let Some(after_s) = input.strip_prefix('s') {
+        let underscore = after_s.find('_')?;
+        &after_s[underscore + 1..]
+    } else {
+        input
+    };
+
+    let digits_end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    if digits_end == 0 {
+        return None;
+    }
+    let len: usize = rest[..digits_end].parse().ok()?;
+    let rest = &rest[digits_end..];
+    let rest = rest.strip_prefix('_').unwrap_or(rest);
+    if rest.len() < len {
+        return None;
+    }
+    Some((rest[..len].to_string(), &rest[len..]))
+}
+
+fn summarize_backtrace(output: &str, result: &mut Vec<String>) {
+    result.push("🧵 Backtrace Summary:".to_string());
+
+    if let Some((thread, message, location)) = parse_panic_header(output) {
+        let at = location
+            .as_deref()
+            .map(|l| format!(" at {}", l))
+            .unwrap_or_default();
+        result.push(format!("   Thread '{}' panicked{}", thread, at));
+        if let Some(msg) = message {
+            result.push(format!("   {}", truncate(&msg, 100)));
+        }
+    }
+
+    let frames = parse_backtrace_frames(output);
+    let user_frames: Vec<&BacktraceFrame> = frames
+        .iter()
+        .filter(|f| !is_noise_frame(&f.symbol))
+        .take(8)
+        .collect();
+
+    if !user_frames.is_empty() {
+        result.push(String::new());
+        result.push("   Frames:".to_string());
+        for frame in user_frames {
+            let location = frame.location.as_deref().unwrap_or("?");
+            result.push(format!("   {} {}", location, frame.symbol));
+        }
+    }
+}
+
+struct DiffFileStat {
+    path: String,
+    additions: usize,
+    deletions: usize,
+    binary: bool,
+}
+
+/// Parse a unified diff (`git diff`, `git show`, a `.patch` file) into
+/// one [`DiffFileStat`] per file, in the order files first appear.
+fn parse_diff_stats(output: &str) -> Vec<DiffFileStat> {
+    let mut files: Vec<DiffFileStat> = Vec::new();
+    let mut current: Option<usize> = None;
+
+    for line in output.lines() {
+        if let Some(rest) = line.strip_prefix("diff --git ") {
+            files.push(DiffFileStat {
+                path: String::new(),
+                additions: 0,
+                deletions: 0,
+                binary: false,
+            });
+            current = Some(files.len() - 1);
+
+            // Fall back to the b/ path from the `diff --git a/x b/x` line
+            // itself -- overridden below for renames, or by +++/--- if
+            // this is an ordinary modification.
+            if let Some((_, b_path)) = rest.rsplit_once(" b/") {
+                if let Some(f) = current.and_then(|i| files.get_mut(i)) {
+                    f.path = b_path.to_string();
+                }
+            }
+        } else if let Some(old_path) = line.strip_prefix("rename from ") {
+            if let Some(f) = current.and_then(|i| files.get_mut(i)) {
+                f.path = format!("{} => ", old_path);
+            }
+        } else if let Some(new_path) = line.strip_prefix("rename to ") {
+            if let Some(f) = current.and_then(|i| files.get_mut(i)) {
+                if f.path.ends_with("=> ") {
+                    f.path.push_str(new_path);
+                } else {
+                    f.path = new_path.to_string();
+                }
+            }
+        } else if line.starts_with("Binary files ") && line.ends_with(" differ") {
+            if let Some(f) = current.and_then(|i| files.get_mut(i)) {
+                f.binary = true;
+            }
+        } else if let Some(path) = line.strip_prefix("+++ b/") {
+            if let Some(f) = current.and_then(|i| files.get_mut(i)) {
+                f.path = path.to_string();
+            }
+        } else if let Some(path) = line.strip_prefix("--- a/") {
+            if let Some(f) = current.and_then(|i| files.get_mut(i)) {
+                if f.path.is_empty() {
+                    f.path = path.to_string();
+                }
+            }
+        } else if line.starts_with("+++") || line.starts_with("---") || line.starts_with("@@ ") {
+            // /dev/null side of an add/delete, or a hunk header -- no stat bookkeeping needed
+        } else if let Some(f) = current.and_then(|i| files.get_mut(i)) {
+            if line.starts_with('+') {
+                f.additions += 1;
+            } else if line.starts_with('-') {
+                f.deletions += 1;
+            }
+        }
+    }
+
+    files.into_iter().filter(|f| !f.path.is_empty()).collect()
+}
+
+/// Scale `additions`/`deletions` down to a `+`/`-` bar at most
+/// `max_width` characters wide, preserving their ratio the way `git diff
+/// --stat` does, while still showing at least one character for a
+/// nonzero side.
+fn scale_bar(additions: usize, deletions: usize, max_width: usize) -> (usize, usize) {
+    let total = additions + deletions;
+    if total <= max_width {
+        return (additions, deletions);
+    }
+
+    let mut plus = additions * max_width / total;
+    let mut minus = max_width - plus;
+    if additions > 0 && plus == 0 {
+        plus = 1;
+        minus = max_width.saturating_sub(1);
+    }
+    if deletions > 0 && minus == 0 {
+        minus = 1;
+        plus = max_width.saturating_sub(1);
+    }
+    (plus, minus)
+}
+
+fn summarize_diff(output: &str, result: &mut Vec<String>) {
+    let files = parse_diff_stats(output);
+
+    result.push("📊 Diff Summary:".to_string());
+
+    if files.is_empty() {
+        result.push("   (no changed files detected)".to_string());
+        return;
+    }
+
+    let name_width = files.iter().map(|f| f.path.len()).max().unwrap_or(0);
+    let mut total_additions = 0;
+    let mut total_deletions = 0;
+
+    for file in &files {
+        if file.binary {
+            result.push(format!("   {:<width$} | Bin", file.path, width = name_width));
+            continue;
+        }
+
+        total_additions += file.additions;
+        total_deletions += file.deletions;
+
+        let (plus, minus) = scale_bar(file.additions, file.deletions, 20);
+        let bar = format!("{}{}", "+".repeat(plus), "-".repeat(minus));
+        result.push(format!(
+            "   {:<width$} | {:>4} {}",
+            file.path,
+            file.additions + file.deletions,
+            bar,
+            width = name_width
+        ));
+    }
+
+    result.push(String::new());
+    result.push(format!(
+        "   {} file{} changed, {} insertion{}(+), {} deletion{}(-)",
+        files.len(),
+        if files.len() == 1 { "" } else { "s" },
+        total_additions,
+        if total_additions == 1 { "" } else { "s" },
+        total_deletions,
+        if total_deletions == 1 { "" } else { "s" },
+    ));
+}
+
+/// One event from `cargo test -- -Z unstable-options --format json` (or
+/// nextest's equivalent libtest-JSON output). Fields we don't care about
+/// (e.g. a passing test's `exec_time`) are left for serde to ignore.
+#[derive(Debug, Deserialize)]
+struct LibtestEvent {
+    #[serde(rename = "type")]
+    event_type: String,
+    event: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    stdout: Option<String>,
+    #[serde(default)]
+    passed: Option<usize>,
+    #[serde(default)]
+    failed: Option<usize>,
+    #[serde(default)]
+    ignored: Option<usize>,
+}
+
+struct LibtestSummary {
+    passed: usize,
+    failed: usize,
+    ignored: usize,
+    /// (test name, first assertion line from its captured stdout)
+    failures: Vec<(String, Option<String>)>,
+}
+
+/// Parse libtest/nextest JSON test events into authoritative pass/fail/
+/// skip counts (from the final `suite` event) plus each failed test's
+/// name and first assertion line, or `None` if the output doesn't
+/// contain any `{"type":"test"|"suite",...}` events at all -- the caller
+/// falls back to the prose heuristic in that case.
+fn parse_libtest_json(output: &str) -> Option<LibtestSummary> {
+    let mut failures: Vec<(String, Option<String>)> = Vec::new();
+    let mut suite_totals: Option<(usize, usize, usize)> = None;
+    let mut saw_any = false;
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if !trimmed.starts_with('{') {
+            continue;
+        }
+
+        let event: LibtestEvent = match serde_json::from_str(trimmed) {
+            Ok(e) => e,
+            Err(_) => continue,
+        };
+
+        match event.event_type.as_str() {
+            "test" => {
+                saw_any = true;
+                if event.event == "failed" {
+                    if let Some(name) = event.name {
+                        let first_line = event.stdout.as_deref().and_then(first_assertion_line);
+                        failures.push((name, first_line));
+                    }
+                }
+            }
+            "suite" => {
+                saw_any = true;
+                suite_totals = Some((
+                    event.passed.unwrap_or(0),
+                    event.failed.unwrap_or(0),
+                    event.ignored.unwrap_or(0),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    if !saw_any {
+        return None;
+    }
+
+    let (passed, failed, ignored) = suite_totals.unwrap_or((0, 0, 0));
+    Some(LibtestSummary {
+        passed,
+        failed,
+        ignored,
+        failures,
+    })
+}
+
+/// Pull out the first line of a failed test's captured stdout that looks
+/// like the actual assertion failure (rather than surrounding `=== RUN`-
+/// style noise), falling back to the first non-empty line if nothing
+/// matches.
+fn first_assertion_line(stdout: &str) -> Option<String> {
+    let lines: Vec<&str> = stdout.lines().map(str::trim).filter(|l| !l.is_empty()).collect();
+    lines
+        .iter()
+        .find(|l| {
+            let lower = l.to_lowercase();
+            lower.contains("assert") || lower.contains("panicked")
+        })
+        .or_else(|| lines.first())
+        .map(|l| l.to_string())
+}
+
+fn render_libtest_summary(summary: &LibtestSummary, result: &mut Vec<String>) {
+    result.push(format!("   ✅ {} passed", summary.passed));
+    if summary.failed > 0 {
+        result.push(format!("   ❌ {} failed", summary.failed));
+    }
+    if summary.ignored > 0 {
+        result.push(format!("   ⏭️  {} skipped", summary.ignored));
+    }
+
+    if !summary.failures.is_empty() {
+        result.push(String::new());
+        result.push("   Failures:".to_string());
+        for (name, first_line) in summary.failures.iter().take(5) {
+            result.push(format!("   • {}", name));
+            if let Some(line) = first_line {
+                result.push(format!("     {}", truncate(line, 90)));
+            }
+        }
+    }
+}
+
 fn summarize_tests(output: &str, result: &mut Vec<String>) {
     result.push("📋 Test Results:".to_string());
 
+    if let Some(summary) = parse_libtest_json(output) {
+        render_libtest_summary(&summary, result);
+        return;
+    }
+
     let mut passed = 0;
     let mut failed = 0;
     let mut skipped = 0;
@@ -161,17 +698,61 @@ fn summarize_tests(output: &str, result: &mut Vec<String>) {
 fn summarize_build(output: &str, result: &mut Vec<String>) {
     result.push("🔨 Build Summary:".to_string());
 
+    // `cargo --message-format=json` diagnostics give exact counts and
+    // structured locations; reuse cargo_cmd's parser instead of guessing
+    // from substrings, which overcounts (every mention of "error") and
+    // throws away the file:line.
+    let (diagnostics, mut compiled, _success) = crate::cargo_cmd::parse_compiler_messages(output);
+
     let mut errors = 0;
     let mut warnings = 0;
-    let mut compiled = 0;
-    let mut error_msgs = Vec::new();
+    let mut diagnostic_lines = Vec::new();
+
+    for diag in &diagnostics {
+        let icon = match diag.level.as_str() {
+            "error" => {
+                errors += 1;
+                "❌"
+            }
+            "warning" => {
+                warnings += 1;
+                "⚠️"
+            }
+            _ => continue,
+        };
+
+        let location = diag
+            .spans
+            .iter()
+            .find(|s| s.is_primary)
+            .map(|s| format!("{}:{}", s.file_name, s.line_start))
+            .unwrap_or_default();
+        let code = diag.code.as_ref().map(|c| c.code.as_str()).unwrap_or("");
+
+        diagnostic_lines.push(
+            format!("{} {} {} {}", icon, code, location, diag.message)
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+    }
 
+    // Any line that isn't itself a `--message-format=json` object (e.g. a
+    // lockfile warning cargo prints as plain text ahead of the JSON
+    // stream, or a completely non-JSON `cargo build`) falls back to the
+    // substring heuristic -- already-counted JSON lines are skipped so
+    // they aren't double-counted.
+    let mut heuristic_error_lines = Vec::new();
     for line in output.lines() {
+        if line.trim_start().starts_with('{') {
+            continue;
+        }
+
         let lower = line.to_lowercase();
         if lower.contains("error") && !lower.contains("0 error") {
             errors += 1;
-            if error_msgs.len() < 5 {
-                error_msgs.push(line.to_string());
+            if heuristic_error_lines.len() < 5 {
+                heuristic_error_lines.push(line.to_string());
             }
         }
         if lower.contains("warning") && !lower.contains("0 warning") {
@@ -195,10 +776,16 @@ fn summarize_build(output: &str, result: &mut Vec<String>) {
         result.push("   ✅ Build successful".to_string());
     }
 
-    if !error_msgs.is_empty() {
+    if !diagnostic_lines.is_empty() {
+        result.push(String::new());
+        result.push("   Diagnostics:".to_string());
+        for d in diagnostic_lines.iter().take(5) {
+            result.push(format!("   {}", truncate(d, 100)));
+        }
+    } else if !heuristic_error_lines.is_empty() {
         result.push(String::new());
         result.push("   Errors:".to_string());
-        for e in &error_msgs {
+        for e in &heuristic_error_lines {
             result.push(format!("   • {}", truncate(e, 70)));
         }
     }
@@ -0,0 +1,221 @@
+//! Natural-language time-range parsing for `rtk gain --since` / `--range`.
+//!
+//! Resolves human expressions like `"yesterday"`, `"last friday"`, or
+//! `"01/01/26..today"` into a concrete `[from, to)` UTC interval that can be
+//! pushed down into aggregation SQL as a `WHERE timestamp >= ?1 AND
+//! timestamp < ?2` clause.
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc, Weekday};
+
+/// Parse a `--since` expression into a `(from, now)` interval.
+///
+/// The end bound is always `now`. Accepts relative words (`today`,
+/// `yesterday`, `last week`, `last month`, `last <weekday>`) and absolute
+/// dates (`MM/DD/YY` or ISO `YYYY-MM-DD`).
+pub fn parse_since(expr: &str, now: DateTime<Utc>) -> Result<(DateTime<Utc>, DateTime<Utc>)> {
+    let from = resolve_bound(expr, now)?;
+    Ok((from, now))
+}
+
+/// Parse a `--range` expression of the form `FROM..TO` into a `(from, to)`
+/// interval. `TO` may be omitted (e.g. `"01/01/26.."`), in which case it
+/// defaults to `now`.
+pub fn parse_range(expr: &str, now: DateTime<Utc>) -> Result<(DateTime<Utc>, DateTime<Utc>)> {
+    let Some((from_part, to_part)) = expr.split_once("..") else {
+        bail!("invalid --range \"{expr}\": expected \"FROM..TO\" (e.g. \"01/01/26..today\")");
+    };
+
+    let from_part = from_part.trim();
+    let to_part = to_part.trim();
+
+    if from_part.is_empty() {
+        bail!("invalid --range \"{expr}\": missing start of range");
+    }
+
+    let from = resolve_bound(from_part, now)?;
+    let to = if to_part.is_empty() {
+        now
+    } else {
+        resolve_bound(to_part, now)?
+    };
+
+    if from >= to {
+        bail!("invalid --range \"{expr}\": start ({from}) is not before end ({to})");
+    }
+
+    Ok((from, to))
+}
+
+/// Resolve a single relative-or-absolute date expression to a UTC instant.
+fn resolve_bound(expr: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let normalized = expr.trim().to_lowercase();
+
+    match normalized.as_str() {
+        "now" => return Ok(now),
+        "today" => return Ok(start_of_day(now.date_naive())),
+        "yesterday" => return Ok(start_of_day(now.date_naive() - Duration::days(1))),
+        "last week" => return Ok(start_of_day(now.date_naive() - Duration::weeks(1))),
+        "last month" => {
+            return Ok(start_of_day(shift_months(now.date_naive(), -1)));
+        }
+        _ => {}
+    }
+
+    if let Some(weekday_name) = normalized.strip_prefix("last ") {
+        if let Some(weekday) = parse_weekday(weekday_name) {
+            return Ok(start_of_day(last_weekday_before(now.date_naive(), weekday)));
+        }
+    }
+
+    parse_absolute_date(&normalized)
+}
+
+/// Parse an absolute date in `MM/DD/YY` or ISO `YYYY-MM-DD` form.
+fn parse_absolute_date(s: &str) -> Result<DateTime<Utc>> {
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%m/%d/%y") {
+        return Ok(start_of_day(date));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return Ok(start_of_day(date));
+    }
+
+    bail!(
+        "could not parse \"{s}\" as a relative expression (today, yesterday, last friday, \
+         last week, last month) or an absolute date (MM/DD/YY, YYYY-MM-DD)"
+    )
+}
+
+fn start_of_day(date: NaiveDate) -> DateTime<Utc> {
+    Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).expect("midnight is always valid"))
+}
+
+fn parse_weekday(name: &str) -> Option<Weekday> {
+    match name {
+        "monday" | "mon" => Some(Weekday::Mon),
+        "tuesday" | "tue" => Some(Weekday::Tue),
+        "wednesday" | "wed" => Some(Weekday::Wed),
+        "thursday" | "thu" => Some(Weekday::Thu),
+        "friday" | "fri" => Some(Weekday::Fri),
+        "saturday" | "sat" => Some(Weekday::Sat),
+        "sunday" | "sun" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Most recent date strictly before `today` that falls on `weekday`.
+fn last_weekday_before(today: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let mut days_back = 1;
+    loop {
+        let candidate = today - Duration::days(days_back);
+        if candidate.weekday() == weekday {
+            return candidate;
+        }
+        days_back += 1;
+    }
+}
+
+/// Shift a date by whole calendar months, clamping the day to the
+/// destination month's length (e.g. Mar 31 - 1 month -> Feb 28/29).
+fn shift_months(date: NaiveDate, months: i32) -> NaiveDate {
+    let total_months = date.year() * 12 + date.month0() as i32 + months;
+    let year = total_months.div_euclid(12);
+    let month0 = total_months.rem_euclid(12) as u32;
+
+    let last_day_of_month = NaiveDate::from_ymd_opt(year, month0 + 1, 1)
+        .map(|first_of_month| {
+            first_of_month
+                .checked_add_months(chrono::Months::new(1))
+                .unwrap_or(first_of_month)
+                - Duration::days(1)
+        })
+        .map(|d| d.day())
+        .unwrap_or(28);
+
+    NaiveDate::from_ymd_opt(year, month0 + 1, date.day().min(last_day_of_month))
+        .expect("clamped day is always valid for its month")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixed_now() -> DateTime<Utc> {
+        // Friday, 2026-02-13 12:00:00 UTC
+        Utc.with_ymd_and_hms(2026, 2, 13, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse_since_today() {
+        let (from, to) = parse_since("today", fixed_now()).unwrap();
+        assert_eq!(from, Utc.with_ymd_and_hms(2026, 2, 13, 0, 0, 0).unwrap());
+        assert_eq!(to, fixed_now());
+    }
+
+    #[test]
+    fn test_parse_since_yesterday() {
+        let (from, _) = parse_since("yesterday", fixed_now()).unwrap();
+        assert_eq!(from, Utc.with_ymd_and_hms(2026, 2, 12, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_parse_since_last_week() {
+        let (from, _) = parse_since("last week", fixed_now()).unwrap();
+        assert_eq!(from, Utc.with_ymd_and_hms(2026, 2, 6, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_parse_since_last_month_clamps_day() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 31, 0, 0, 0).unwrap();
+        let (from, _) = parse_since("last month", now).unwrap();
+        assert_eq!(from, Utc.with_ymd_and_hms(2026, 2, 28, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_parse_since_last_weekday() {
+        // fixed_now() is a Friday, so "last friday" should resolve to the
+        // prior Friday, not today.
+        let (from, _) = parse_since("last friday", fixed_now()).unwrap();
+        assert_eq!(from, Utc.with_ymd_and_hms(2026, 2, 6, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_parse_since_absolute_iso() {
+        let (from, _) = parse_since("2026-01-01", fixed_now()).unwrap();
+        assert_eq!(from, Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_parse_since_absolute_mm_dd_yy() {
+        let (from, _) = parse_since("01/01/26", fixed_now()).unwrap();
+        assert_eq!(from, Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_parse_since_invalid() {
+        assert!(parse_since("whenever", fixed_now()).is_err());
+    }
+
+    #[test]
+    fn test_parse_range_explicit_bounds() {
+        let (from, to) = parse_range("01/01/26..today", fixed_now()).unwrap();
+        assert_eq!(from, Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap());
+        assert_eq!(to, Utc.with_ymd_and_hms(2026, 2, 13, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_parse_range_defaults_end_to_now() {
+        let (_, to) = parse_range("01/01/26..", fixed_now()).unwrap();
+        assert_eq!(to, fixed_now());
+    }
+
+    #[test]
+    fn test_parse_range_rejects_missing_separator() {
+        assert!(parse_range("01/01/26", fixed_now()).is_err());
+    }
+
+    #[test]
+    fn test_parse_range_rejects_inverted_bounds() {
+        assert!(parse_range("today..yesterday", fixed_now()).is_err());
+    }
+}
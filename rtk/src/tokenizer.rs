@@ -0,0 +1,184 @@
+//! Pluggable token counting for tracking metrics.
+//!
+//! [`estimate_tokens`](crate::tracking::estimate_tokens)'s `ceil(chars/4)`
+//! heuristic is a fast, zero-dependency approximation, but it skews every
+//! savings metric for code, CJK text, and JSON-heavy tool output. This
+//! module introduces a [`Tokenizer`] trait so [`TimedExecution`]'s callers
+//! can opt into a precise, tiktoken-style BPE count instead, selected per
+//! model via `config.tracking.tokenizer_model`. The heuristic remains the
+//! default and the only option when rtk is built without the
+//! `bpe-tokenizer` feature.
+//!
+//! [`TimedExecution`]: crate::tracking::TimedExecution
+
+use crate::tracking::estimate_tokens;
+
+/// Counts tokens in a string for savings-tracking purposes.
+pub trait Tokenizer {
+    fn count_tokens(&self, text: &str) -> usize;
+}
+
+/// The long-standing `ceil(chars / 4)` estimate. Zero dependencies, always
+/// available, and the fallback whenever a precise tokenizer can't be
+/// loaded.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HeuristicTokenizer;
+
+impl Tokenizer for HeuristicTokenizer {
+    fn count_tokens(&self, text: &str) -> usize {
+        estimate_tokens(text)
+    }
+}
+
+/// Resolve the tokenizer configured in `config.tracking.tokenizer_model`,
+/// falling back to [`HeuristicTokenizer`] when no model is configured, the
+/// `bpe-tokenizer` feature isn't built, or the model's ranks table can't be
+/// loaded.
+pub fn configured() -> Box<dyn Tokenizer> {
+    let model = crate::config::Config::load()
+        .ok()
+        .and_then(|config| config.tracking.tokenizer_model);
+
+    #[cfg(feature = "bpe-tokenizer")]
+    if let Some(model) = model.as_deref() {
+        if let Ok(bpe) = bpe::BpeTokenizer::load(model) {
+            return Box::new(bpe);
+        }
+    }
+
+    let _ = model;
+    Box::new(HeuristicTokenizer)
+}
+
+#[cfg(feature = "bpe-tokenizer")]
+pub use bpe::BpeTokenizer;
+
+/// Tiktoken-style byte-pair-encoding tokenizer: loads a model's
+/// mergeable-ranks table, pre-splits input on a word/punctuation regex,
+/// then greedily merges the lowest-rank adjacent byte pair within each
+/// chunk until no merge applies, counting the resulting pieces.
+#[cfg(feature = "bpe-tokenizer")]
+mod bpe {
+    use super::Tokenizer;
+    use anyhow::{Context, Result};
+    use regex::Regex;
+    use std::collections::HashMap;
+    use std::fs;
+    use std::path::PathBuf;
+
+    /// Mirrors cl100k_base's pre-tokenizer pattern closely enough to split
+    /// on word/number/punctuation/whitespace boundaries before merging.
+    const SPLIT_PATTERN: &str = r"'s|'t|'re|'ve|'m|'ll|'d| ?\p{L}+| ?\p{N}+| ?[^\s\p{L}\p{N}]+|\s+";
+
+    pub struct BpeTokenizer {
+        ranks: HashMap<Vec<u8>, u32>,
+        split: Regex,
+    }
+
+    impl BpeTokenizer {
+        /// Load `<config_dir>/rtk/tokenizers/<model>.tiktoken`: one
+        /// `<base64 token> <rank>` pair per line, the same plaintext format
+        /// tiktoken ships its ranks files in.
+        pub fn load(model: &str) -> Result<Self> {
+            let path = ranks_path(model)?;
+            let contents = fs::read_to_string(&path)
+                .with_context(|| format!("failed to read tokenizer ranks at {}", path.display()))?;
+
+            let mut ranks = HashMap::new();
+            for line in contents.lines() {
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+                let (token_b64, rank) = line
+                    .rsplit_once(' ')
+                    .with_context(|| format!("malformed ranks line: {line}"))?;
+                let token = decode_base64(token_b64)
+                    .with_context(|| format!("invalid base64 token: {token_b64}"))?;
+                let rank: u32 = rank.parse().context("invalid rank")?;
+                ranks.insert(token, rank);
+            }
+
+            let split = Regex::new(SPLIT_PATTERN).context("invalid pre-split pattern")?;
+            Ok(Self { ranks, split })
+        }
+
+        /// Greedily merge the lowest-rank adjacent pair in `piece` until no
+        /// pair in `ranks` applies, returning the number of resulting
+        /// tokens.
+        fn count_piece(&self, piece: &[u8]) -> usize {
+            if piece.is_empty() {
+                return 0;
+            }
+
+            let mut parts: Vec<Vec<u8>> = piece.iter().map(|&b| vec![b]).collect();
+
+            loop {
+                let mut best: Option<(usize, u32)> = None;
+                for i in 0..parts.len().saturating_sub(1) {
+                    let mut merged = parts[i].clone();
+                    merged.extend_from_slice(&parts[i + 1]);
+                    if let Some(&rank) = self.ranks.get(&merged) {
+                        if best.map(|(_, best_rank)| rank < best_rank).unwrap_or(true) {
+                            best = Some((i, rank));
+                        }
+                    }
+                }
+
+                match best {
+                    Some((i, _)) => {
+                        let merged = [parts[i].clone(), parts[i + 1].clone()].concat();
+                        parts.splice(i..=i + 1, [merged]);
+                    }
+                    None => break,
+                }
+            }
+
+            parts.len()
+        }
+    }
+
+    impl Tokenizer for BpeTokenizer {
+        fn count_tokens(&self, text: &str) -> usize {
+            self.split
+                .find_iter(text)
+                .map(|m| self.count_piece(m.as_str().as_bytes()))
+                .sum()
+        }
+    }
+
+    fn ranks_path(model: &str) -> Result<PathBuf> {
+        let config_dir = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+        Ok(config_dir
+            .join("rtk")
+            .join("tokenizers")
+            .join(format!("{model}.tiktoken")))
+    }
+
+    const BASE64_ALPHABET: &[u8] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    /// Minimal standard-alphabet base64 decoder (with `=` padding), enough
+    /// to read tiktoken's plaintext ranks files without a new dependency.
+    fn decode_base64(input: &str) -> Result<Vec<u8>> {
+        let input = input.trim_end_matches('=');
+        let mut bits: u32 = 0;
+        let mut bit_count = 0u32;
+        let mut out = Vec::with_capacity(input.len() * 3 / 4 + 1);
+
+        for ch in input.bytes() {
+            let value = BASE64_ALPHABET
+                .iter()
+                .position(|&c| c == ch)
+                .with_context(|| format!("invalid base64 character: {}", ch as char))?;
+            bits = (bits << 6) | value as u32;
+            bit_count += 6;
+            if bit_count >= 8 {
+                bit_count -= 8;
+                out.push((bits >> bit_count) as u8);
+            }
+        }
+
+        Ok(out)
+    }
+}
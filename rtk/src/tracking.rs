@@ -7,7 +7,8 @@
 //! # Architecture
 //!
 //! - Storage: SQLite database (~/.local/share/rtk/tracking.db)
-//! - Retention: 90-day automatic cleanup
+//! - Retention: tiered prune policy (recent rows in full, older rows
+//!   collapsed to one sample per day/week/month) via [`RetentionPolicy`]
 //! - Metrics: Input/output tokens, savings %, execution time
 //!
 //! # Quick Start
@@ -29,16 +30,114 @@
 //!
 //! See [docs/tracking.md](../docs/tracking.md) for full documentation.
 
+use crate::latency_histogram::Histogram;
 use anyhow::Result;
-use chrono::{DateTime, Utc};
-use rusqlite::{params, Connection};
+use chrono::{DateTime, Datelike, Utc};
+use rusqlite::{params, params_from_iter, Connection, ToSql};
 use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 use std::ffi::OsString;
+use std::io::Write;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Instant;
 
-/// Number of days to retain tracking history before automatic cleanup.
-const HISTORY_DAYS: i64 = 90;
+/// Number of [`Tracker::record`] calls between automatic `cleanup_old`
+/// runs. Cleanup is cheap but needless on every single insert in hot
+/// loops, so it's amortized to once every `CLEANUP_INTERVAL` calls; the
+/// count starts at zero, so it still fires once for short-lived processes
+/// that only ever call `record` a handful of times.
+const CLEANUP_INTERVAL: u64 = 20;
+
+/// Process-lifetime counter backing the `record` cleanup amortization
+/// guard. [`Tracker::record_bulk`] bypasses it entirely and always runs
+/// `cleanup_old` exactly once at the end of the batch.
+static RECORD_COUNT: AtomicU64 = AtomicU64::new(0);
+
+/// Tiered retention policy for [`compute_prune_list`].
+///
+/// Each tier keeps at most one row per distinct bucket key, newest-first,
+/// up to its budget: `keep_last` buckets on the row's own id (i.e. the
+/// most recent rows verbatim), `keep_daily` on `YYYY-MM-DD`, `keep_weekly`
+/// on ISO year-week, and `keep_monthly` on `YYYY-MM`. A row survives if
+/// *any* tier claims it. This lets high-volume recent activity be kept
+/// in full while older rows collapse to one representative sample per
+/// day/week/month.
+#[derive(Debug, Clone, Copy)]
+pub struct RetentionPolicy {
+    pub keep_last: usize,
+    pub keep_daily: usize,
+    pub keep_weekly: usize,
+    pub keep_monthly: usize,
+}
+
+impl From<&crate::config::TrackingConfig> for RetentionPolicy {
+    fn from(cfg: &crate::config::TrackingConfig) -> Self {
+        Self {
+            keep_last: cfg.keep_last,
+            keep_daily: cfg.keep_daily,
+            keep_weekly: cfg.keep_weekly,
+            keep_monthly: cfg.keep_monthly,
+        }
+    }
+}
+
+/// Decide which `(id, timestamp)` rows survive a [`RetentionPolicy`].
+///
+/// Scans `records` newest-first and, for each tier in turn, marks a row
+/// "keep" the first time its bucket key is seen within that tier and the
+/// tier still has budget remaining. Returns the ids of rows that were
+/// *not* kept by any tier, i.e. the ones `cleanup_old` should delete.
+pub fn compute_prune_list(records: &[(i64, DateTime<Utc>)], policy: &RetentionPolicy) -> Vec<i64> {
+    let mut sorted: Vec<(i64, DateTime<Utc>)> = records.to_vec();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1));
+
+    let mut keep: HashSet<i64> = HashSet::new();
+    apply_tier(&sorted, policy.keep_last, &mut keep, |id, _ts| {
+        id.to_string()
+    });
+    apply_tier(&sorted, policy.keep_daily, &mut keep, |_id, ts| {
+        ts.format("%Y-%m-%d").to_string()
+    });
+    apply_tier(&sorted, policy.keep_weekly, &mut keep, |_id, ts| {
+        let week = ts.iso_week();
+        format!("{}-W{:02}", week.year(), week.week())
+    });
+    apply_tier(&sorted, policy.keep_monthly, &mut keep, |_id, ts| {
+        ts.format("%Y-%m").to_string()
+    });
+
+    sorted
+        .into_iter()
+        .filter(|(id, _)| !keep.contains(id))
+        .map(|(id, _)| id)
+        .collect()
+}
+
+/// Walk `sorted` (newest-first) marking up to `budget` rows as kept, one
+/// per distinct `key_fn` output.
+fn apply_tier<F>(
+    sorted: &[(i64, DateTime<Utc>)],
+    budget: usize,
+    keep: &mut HashSet<i64>,
+    key_fn: F,
+) where
+    F: Fn(i64, DateTime<Utc>) -> String,
+{
+    if budget == 0 {
+        return;
+    }
+
+    let mut seen: HashSet<String> = HashSet::new();
+    for &(id, ts) in sorted {
+        if seen.len() >= budget {
+            break;
+        }
+        if seen.insert(key_fn(id, ts)) {
+            keep.insert(id);
+        }
+    }
+}
 
 /// Main tracking interface for recording and querying command history.
 ///
@@ -84,6 +183,50 @@ pub struct CommandRecord {
     pub savings_pct: f64,
 }
 
+/// Full command record from tracking history, including the id and
+/// `original_cmd` that [`CommandRecord`] drops. Returned by
+/// [`Tracker::get_recent_detailed`] for callers (e.g. the `--interactive`
+/// inspector) that need to show or delete a specific row.
+#[derive(Debug, Clone)]
+pub struct CommandRecordDetailed {
+    /// Row id, usable with [`Tracker::delete_by_id`]
+    pub id: i64,
+    /// UTC timestamp when command was executed
+    pub timestamp: DateTime<Utc>,
+    /// The standard command (e.g., "ls -la")
+    pub original_cmd: String,
+    /// RTK command that was executed (e.g., "rtk ls")
+    pub rtk_cmd: String,
+    /// Estimated tokens from standard command output
+    pub input_tokens: usize,
+    /// Actual tokens from RTK output
+    pub output_tokens: usize,
+    /// Number of tokens saved (input - output)
+    pub saved_tokens: usize,
+    /// Savings percentage ((saved / input) * 100)
+    pub savings_pct: f64,
+    /// Execution time in milliseconds
+    pub exec_time_ms: u64,
+}
+
+/// One row to insert via [`Tracker::record_bulk`] — the same fields as
+/// [`Tracker::record`]'s arguments, gathered up-front so many rows can be
+/// inserted in a single transaction instead of one DELETE-after-INSERT
+/// cleanup per row.
+#[derive(Debug, Clone)]
+pub struct CommandEntry {
+    /// The standard command (e.g., "ls -la")
+    pub original_cmd: String,
+    /// The RTK command used (e.g., "rtk ls")
+    pub rtk_cmd: String,
+    /// Estimated tokens from standard command output
+    pub input_tokens: usize,
+    /// Actual tokens from RTK output
+    pub output_tokens: usize,
+    /// Execution time in milliseconds
+    pub exec_time_ms: u64,
+}
+
 /// Aggregated statistics across all recorded commands.
 ///
 /// Provides overall metrics and breakdowns by command and by day.
@@ -104,12 +247,48 @@ pub struct GainSummary {
     pub total_time_ms: u64,
     /// Average execution time per command (milliseconds)
     pub avg_time_ms: u64,
+    /// Median execution time (50th percentile, milliseconds)
+    pub p50_time_ms: u64,
+    /// 95th percentile execution time (milliseconds)
+    pub p95_time_ms: u64,
+    /// 99th percentile execution time (milliseconds)
+    pub p99_time_ms: u64,
     /// Top 10 commands by tokens saved: (cmd, count, saved, avg_pct, avg_time_ms)
     pub by_command: Vec<(String, usize, usize, f64, u64)>,
     /// Last 30 days of activity: (date, saved_tokens)
     pub by_day: Vec<(String, usize)>,
 }
 
+/// Per-command statistical aggregation, as returned by [`Tracker::stats`].
+///
+/// Mean/median/min/max/sample-standard-deviation of `savings_pct` and
+/// `exec_time_ms`, borrowing hyperfine's statistical treatment: sample
+/// standard deviation is `sqrt(Σ(xᵢ-mean)² / (n-1))`, and `exec_time_ms`
+/// outliers are flagged via the modified Z-score (`|0.6745·(xᵢ-med)/MAD| >
+/// 3.5`, with `MAD == 0` meaning "no outliers").
+#[derive(Debug, Clone)]
+pub struct CommandStats {
+    /// The `rtk_cmd` this group of samples shares.
+    pub rtk_cmd: String,
+    /// Number of recorded runs for this command.
+    pub sample_count: usize,
+    /// Total tokens saved across all runs of this command.
+    pub total_saved: usize,
+    pub savings_pct_mean: f64,
+    pub savings_pct_median: f64,
+    pub savings_pct_min: f64,
+    pub savings_pct_max: f64,
+    pub savings_pct_stddev: f64,
+    pub exec_time_mean_ms: f64,
+    pub exec_time_median_ms: f64,
+    pub exec_time_min_ms: u64,
+    pub exec_time_max_ms: u64,
+    pub exec_time_stddev_ms: f64,
+    /// Indices into this command's run order (not row ids) flagged as
+    /// `exec_time_ms` outliers by the modified Z-score.
+    pub exec_time_outliers: Vec<usize>,
+}
+
 /// Daily statistics for token savings and execution metrics.
 ///
 /// Serializable to JSON for export via `rtk gain --daily --format json`.
@@ -125,7 +304,10 @@ pub struct GainSummary {
 ///   "saved_tokens": 11578,
 ///   "savings_pct": 75.08,
 ///   "total_time_ms": 8450,
-///   "avg_time_ms": 201
+///   "avg_time_ms": 201,
+///   "p50_time_ms": 180,
+///   "p95_time_ms": 410,
+///   "p99_time_ms": 520
 /// }
 /// ```
 #[derive(Debug, Serialize)]
@@ -146,6 +328,12 @@ pub struct DayStats {
     pub total_time_ms: u64,
     /// Average execution time per command (milliseconds)
     pub avg_time_ms: u64,
+    /// Median execution time (50th percentile, milliseconds)
+    pub p50_time_ms: u64,
+    /// 95th percentile execution time (milliseconds)
+    pub p95_time_ms: u64,
+    /// 99th percentile execution time (milliseconds)
+    pub p99_time_ms: u64,
 }
 
 /// Weekly statistics for token savings and execution metrics.
@@ -172,6 +360,12 @@ pub struct WeekStats {
     pub total_time_ms: u64,
     /// Average execution time per command (milliseconds)
     pub avg_time_ms: u64,
+    /// Median execution time (50th percentile, milliseconds)
+    pub p50_time_ms: u64,
+    /// 95th percentile execution time (milliseconds)
+    pub p95_time_ms: u64,
+    /// 99th percentile execution time (milliseconds)
+    pub p99_time_ms: u64,
 }
 
 /// Monthly statistics for token savings and execution metrics.
@@ -195,6 +389,12 @@ pub struct MonthStats {
     pub total_time_ms: u64,
     /// Average execution time per command (milliseconds)
     pub avg_time_ms: u64,
+    /// Median execution time (50th percentile, milliseconds)
+    pub p50_time_ms: u64,
+    /// 95th percentile execution time (milliseconds)
+    pub p95_time_ms: u64,
+    /// 99th percentile execution time (milliseconds)
+    pub p99_time_ms: u64,
 }
 
 impl Tracker {
@@ -258,7 +458,8 @@ impl Tracker {
     /// Record a command execution with token counts and timing.
     ///
     /// Calculates savings metrics and stores the record in the database.
-    /// Automatically cleans up records older than 90 days after insertion.
+    /// Automatically prunes history after insertion per the configured
+    /// tiered [`RetentionPolicy`] (see [`compute_prune_list`]).
     ///
     /// # Arguments
     ///
@@ -307,16 +508,100 @@ impl Tracker {
             ],
         )?;
 
+        if RECORD_COUNT.fetch_add(1, Ordering::Relaxed) % CLEANUP_INTERVAL == 0 {
+            self.cleanup_old()?;
+        }
+        Ok(())
+    }
+
+    /// Insert many records in one transaction, reusing a single prepared
+    /// statement, and run `cleanup_old` exactly once at the end instead of
+    /// after every row.
+    ///
+    /// Substantially cuts write amplification versus calling
+    /// [`Tracker::record`] in a loop when backfilling history or wrapping
+    /// scripts that shell out hundreds of commands.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rtk::tracking::{CommandEntry, Tracker};
+    ///
+    /// let tracker = Tracker::new()?;
+    /// tracker.record_bulk(&[CommandEntry {
+    ///     original_cmd: "ls -la".to_string(),
+    ///     rtk_cmd: "rtk ls".to_string(),
+    ///     input_tokens: 1000,
+    ///     output_tokens: 200,
+    ///     exec_time_ms: 50,
+    /// }])?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn record_bulk(&self, records: &[CommandEntry]) -> Result<()> {
+        if records.is_empty() {
+            return Ok(());
+        }
+
+        let tx = self.conn.unchecked_transaction()?;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT INTO commands (timestamp, original_cmd, rtk_cmd, input_tokens, output_tokens, saved_tokens, savings_pct, exec_time_ms)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            )?;
+
+            for entry in records {
+                let saved = entry.input_tokens.saturating_sub(entry.output_tokens);
+                let pct = if entry.input_tokens > 0 {
+                    (saved as f64 / entry.input_tokens as f64) * 100.0
+                } else {
+                    0.0
+                };
+
+                stmt.execute(params![
+                    Utc::now().to_rfc3339(),
+                    entry.original_cmd,
+                    entry.rtk_cmd,
+                    entry.input_tokens as i64,
+                    entry.output_tokens as i64,
+                    saved as i64,
+                    pct,
+                    entry.exec_time_ms as i64
+                ])?;
+            }
+        }
+        tx.commit()?;
+
         self.cleanup_old()?;
         Ok(())
     }
 
     fn cleanup_old(&self) -> Result<()> {
-        let cutoff = Utc::now() - chrono::Duration::days(HISTORY_DAYS);
-        self.conn.execute(
-            "DELETE FROM commands WHERE timestamp < ?1",
-            params![cutoff.to_rfc3339()],
-        )?;
+        let policy = match crate::config::Config::load() {
+            Ok(config) => RetentionPolicy::from(&config.tracking),
+            Err(_) => RetentionPolicy::from(&crate::config::TrackingConfig::default()),
+        };
+
+        let mut stmt = self.conn.prepare("SELECT id, timestamp FROM commands")?;
+        let rows = stmt.query_map([], |row| {
+            let timestamp: String = row.get(1)?;
+            Ok((row.get::<_, i64>(0)?, timestamp))
+        })?;
+
+        let records: Vec<(i64, DateTime<Utc>)> = rows
+            .filter_map(|row| row.ok())
+            .filter_map(|(id, ts)| {
+                DateTime::parse_from_rfc3339(&ts)
+                    .ok()
+                    .map(|dt| (id, dt.with_timezone(&Utc)))
+            })
+            .collect();
+
+        let to_delete = compute_prune_list(&records, &policy);
+        for id in to_delete {
+            self.conn
+                .execute("DELETE FROM commands WHERE id = ?1", params![id])?;
+        }
+
         Ok(())
     }
 
@@ -345,6 +630,7 @@ impl Tracker {
         let mut total_output = 0usize;
         let mut total_saved = 0usize;
         let mut total_time_ms = 0u64;
+        let mut latency = Histogram::new();
 
         let mut stmt = self.conn.prepare(
             "SELECT input_tokens, output_tokens, saved_tokens, exec_time_ms FROM commands",
@@ -366,6 +652,7 @@ impl Tracker {
             total_output += output;
             total_saved += saved;
             total_time_ms += time_ms;
+            latency.record(time_ms);
         }
 
         let avg_savings_pct = if total_input > 0 {
@@ -391,6 +678,100 @@ impl Tracker {
             avg_savings_pct,
             total_time_ms,
             avg_time_ms,
+            p50_time_ms: latency.percentile(50.0),
+            p95_time_ms: latency.percentile(95.0),
+            p99_time_ms: latency.percentile(99.0),
+            by_command,
+            by_day,
+        })
+    }
+
+    /// Get summary statistics scoped to a `[from, to)` UTC interval.
+    ///
+    /// Mirrors [`get_summary`](Self::get_summary) but filters every
+    /// aggregate (totals, by-command, by-day) to commands recorded within
+    /// the given window. Used by `rtk gain --since`/`--range` to scope
+    /// savings reports to arbitrary windows instead of all-time.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use chrono::{Duration, Utc};
+    /// use rtk::tracking::Tracker;
+    ///
+    /// let tracker = Tracker::new()?;
+    /// let to = Utc::now();
+    /// let from = to - Duration::days(7);
+    /// let summary = tracker.get_stats_for_range(from, to)?;
+    /// println!("Saved {} tokens in the last 7 days", summary.total_saved);
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn get_stats_for_range(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<GainSummary> {
+        let from_s = from.to_rfc3339();
+        let to_s = to.to_rfc3339();
+
+        let mut total_commands = 0usize;
+        let mut total_input = 0usize;
+        let mut total_output = 0usize;
+        let mut total_saved = 0usize;
+        let mut total_time_ms = 0u64;
+        let mut latency = Histogram::new();
+
+        let mut stmt = self.conn.prepare(
+            "SELECT input_tokens, output_tokens, saved_tokens, exec_time_ms
+             FROM commands
+             WHERE timestamp >= ?1 AND timestamp < ?2",
+        )?;
+
+        let rows = stmt.query_map(params![from_s, to_s], |row| {
+            Ok((
+                row.get::<_, i64>(0)? as usize,
+                row.get::<_, i64>(1)? as usize,
+                row.get::<_, i64>(2)? as usize,
+                row.get::<_, i64>(3)? as u64,
+            ))
+        })?;
+
+        for row in rows {
+            let (input, output, saved, time_ms) = row?;
+            total_commands += 1;
+            total_input += input;
+            total_output += output;
+            total_saved += saved;
+            total_time_ms += time_ms;
+            latency.record(time_ms);
+        }
+
+        let avg_savings_pct = if total_input > 0 {
+            (total_saved as f64 / total_input as f64) * 100.0
+        } else {
+            0.0
+        };
+
+        let avg_time_ms = if total_commands > 0 {
+            total_time_ms / total_commands as u64
+        } else {
+            0
+        };
+
+        let by_command = self.get_by_command_range(&from_s, &to_s)?;
+        let by_day = self.get_by_day_range(&from_s, &to_s)?;
+
+        Ok(GainSummary {
+            total_commands,
+            total_input,
+            total_output,
+            total_saved,
+            avg_savings_pct,
+            total_time_ms,
+            avg_time_ms,
+            p50_time_ms: latency.percentile(50.0),
+            p95_time_ms: latency.percentile(95.0),
+            p99_time_ms: latency.percentile(99.0),
             by_command,
             by_day,
         })
@@ -418,6 +799,33 @@ impl Tracker {
         Ok(rows.collect::<Result<Vec<_>, _>>()?)
     }
 
+    fn get_by_command_range(
+        &self,
+        from_s: &str,
+        to_s: &str,
+    ) -> Result<Vec<(String, usize, usize, f64, u64)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT rtk_cmd, COUNT(*), SUM(saved_tokens), AVG(savings_pct), AVG(exec_time_ms)
+             FROM commands
+             WHERE timestamp >= ?1 AND timestamp < ?2
+             GROUP BY rtk_cmd
+             ORDER BY SUM(saved_tokens) DESC
+             LIMIT 10",
+        )?;
+
+        let rows = stmt.query_map(params![from_s, to_s], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, i64>(1)? as usize,
+                row.get::<_, i64>(2)? as usize,
+                row.get::<_, f64>(3)?,
+                row.get::<_, f64>(4)? as u64,
+            ))
+        })?;
+
+        Ok(rows.collect::<Result<Vec<_>, _>>()?)
+    }
+
     fn get_by_day(&self) -> Result<Vec<(String, usize)>> {
         let mut stmt = self.conn.prepare(
             "SELECT DATE(timestamp), SUM(saved_tokens)
@@ -436,6 +844,70 @@ impl Tracker {
         Ok(result)
     }
 
+    fn get_by_day_range(&self, from_s: &str, to_s: &str) -> Result<Vec<(String, usize)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT DATE(timestamp), SUM(saved_tokens)
+             FROM commands
+             WHERE timestamp >= ?1 AND timestamp < ?2
+             GROUP BY DATE(timestamp)
+             ORDER BY DATE(timestamp) DESC",
+        )?;
+
+        let rows = stmt.query_map(params![from_s, to_s], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as usize))
+        })?;
+
+        let mut result: Vec<_> = rows.collect::<Result<Vec<_>, _>>()?;
+        result.reverse();
+        Ok(result)
+    }
+
+    /// Build a per-group execution-time [`Histogram`] keyed by `group_expr`
+    /// (e.g. `DATE(timestamp)`), scanning the same `commands` table used by
+    /// the grouped aggregate queries. Backs the `p50`/`p95`/`p99` fields on
+    /// [`DayStats`], [`WeekStats`], and [`MonthStats`].
+    fn latency_by_group(&self, group_expr: &str) -> Result<HashMap<String, Histogram>> {
+        let sql = format!("SELECT {group_expr}, exec_time_ms FROM commands");
+        let mut stmt = self.conn.prepare(&sql)?;
+
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as u64))
+        })?;
+
+        let mut histograms: HashMap<String, Histogram> = HashMap::new();
+        for row in rows {
+            let (key, time_ms) = row?;
+            histograms.entry(key).or_default().record(time_ms);
+        }
+
+        Ok(histograms)
+    }
+
+    /// Range-scoped variant of [`latency_by_group`](Self::latency_by_group).
+    fn latency_by_group_range(
+        &self,
+        group_expr: &str,
+        from_s: &str,
+        to_s: &str,
+    ) -> Result<HashMap<String, Histogram>> {
+        let sql = format!(
+            "SELECT {group_expr}, exec_time_ms FROM commands WHERE timestamp >= ?1 AND timestamp < ?2"
+        );
+        let mut stmt = self.conn.prepare(&sql)?;
+
+        let rows = stmt.query_map(params![from_s, to_s], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as u64))
+        })?;
+
+        let mut histograms: HashMap<String, Histogram> = HashMap::new();
+        for row in rows {
+            let (key, time_ms) = row?;
+            histograms.entry(key).or_default().record(time_ms);
+        }
+
+        Ok(histograms)
+    }
+
     /// Get daily statistics for all recorded days.
     ///
     /// Returns one [`DayStats`] per day with commands executed, tokens saved,
@@ -455,6 +927,8 @@ impl Tracker {
     /// # Ok::<(), anyhow::Error>(())
     /// ```
     pub fn get_all_days(&self) -> Result<Vec<DayStats>> {
+        let latencies = self.latency_by_group("DATE(timestamp)")?;
+
         let mut stmt = self.conn.prepare(
             "SELECT
                 DATE(timestamp) as date,
@@ -469,6 +943,7 @@ impl Tracker {
         )?;
 
         let rows = stmt.query_map([], |row| {
+            let date: String = row.get(0)?;
             let input = row.get::<_, i64>(2)? as usize;
             let saved = row.get::<_, i64>(4)? as usize;
             let commands = row.get::<_, i64>(1)? as usize;
@@ -483,9 +958,10 @@ impl Tracker {
             } else {
                 0
             };
+            let hist = latencies.get(&date);
 
             Ok(DayStats {
-                date: row.get(0)?,
+                date,
                 commands,
                 input_tokens: input,
                 output_tokens: row.get::<_, i64>(3)? as usize,
@@ -493,6 +969,9 @@ impl Tracker {
                 savings_pct,
                 total_time_ms: total_time,
                 avg_time_ms,
+                p50_time_ms: hist.map(|h| h.percentile(50.0)).unwrap_or(0),
+                p95_time_ms: hist.map(|h| h.percentile(95.0)).unwrap_or(0),
+                p99_time_ms: hist.map(|h| h.percentile(99.0)).unwrap_or(0),
             })
         })?;
 
@@ -520,6 +999,8 @@ impl Tracker {
     /// # Ok::<(), anyhow::Error>(())
     /// ```
     pub fn get_by_week(&self) -> Result<Vec<WeekStats>> {
+        let latencies = self.latency_by_group("DATE(timestamp, 'weekday 0', '-6 days')")?;
+
         let mut stmt = self.conn.prepare(
             "SELECT
                 DATE(timestamp, 'weekday 0', '-6 days') as week_start,
@@ -535,6 +1016,7 @@ impl Tracker {
         )?;
 
         let rows = stmt.query_map([], |row| {
+            let week_start: String = row.get(0)?;
             let input = row.get::<_, i64>(3)? as usize;
             let saved = row.get::<_, i64>(5)? as usize;
             let commands = row.get::<_, i64>(2)? as usize;
@@ -549,9 +1031,10 @@ impl Tracker {
             } else {
                 0
             };
+            let hist = latencies.get(&week_start);
 
             Ok(WeekStats {
-                week_start: row.get(0)?,
+                week_start,
                 week_end: row.get(1)?,
                 commands,
                 input_tokens: input,
@@ -560,6 +1043,9 @@ impl Tracker {
                 savings_pct,
                 total_time_ms: total_time,
                 avg_time_ms,
+                p50_time_ms: hist.map(|h| h.percentile(50.0)).unwrap_or(0),
+                p95_time_ms: hist.map(|h| h.percentile(95.0)).unwrap_or(0),
+                p99_time_ms: hist.map(|h| h.percentile(99.0)).unwrap_or(0),
             })
         })?;
 
@@ -587,6 +1073,8 @@ impl Tracker {
     /// # Ok::<(), anyhow::Error>(())
     /// ```
     pub fn get_by_month(&self) -> Result<Vec<MonthStats>> {
+        let latencies = self.latency_by_group("strftime('%Y-%m', timestamp)")?;
+
         let mut stmt = self.conn.prepare(
             "SELECT
                 strftime('%Y-%m', timestamp) as month,
@@ -601,6 +1089,7 @@ impl Tracker {
         )?;
 
         let rows = stmt.query_map([], |row| {
+            let month: String = row.get(0)?;
             let input = row.get::<_, i64>(2)? as usize;
             let saved = row.get::<_, i64>(4)? as usize;
             let commands = row.get::<_, i64>(1)? as usize;
@@ -615,9 +1104,10 @@ impl Tracker {
             } else {
                 0
             };
+            let hist = latencies.get(&month);
 
             Ok(MonthStats {
-                month: row.get(0)?,
+                month,
                 commands,
                 input_tokens: input,
                 output_tokens: row.get::<_, i64>(3)? as usize,
@@ -625,6 +1115,9 @@ impl Tracker {
                 savings_pct,
                 total_time_ms: total_time,
                 avg_time_ms,
+                p50_time_ms: hist.map(|h| h.percentile(50.0)).unwrap_or(0),
+                p95_time_ms: hist.map(|h| h.percentile(95.0)).unwrap_or(0),
+                p99_time_ms: hist.map(|h| h.percentile(99.0)).unwrap_or(0),
             })
         })?;
 
@@ -633,62 +1126,738 @@ impl Tracker {
         Ok(result)
     }
 
-    /// Get recent command history.
-    ///
-    /// Returns up to `limit` most recent command records, ordered by timestamp (newest first).
-    ///
-    /// # Arguments
-    ///
-    /// - `limit`: Maximum number of records to return
-    ///
-    /// # Examples
-    ///
-    /// ```no_run
-    /// use rtk::tracking::Tracker;
+    /// Bucket all tracked commands using an RFC 5545 recurrence rule
+    /// instead of the hardcoded day/week/month buckets above — quarterly,
+    /// biweekly, every-3-days, whatever `rrule` describes.
     ///
-    /// let tracker = Tracker::new()?;
-    /// let recent = tracker.get_recent(10)?;
-    /// for cmd in recent {
-    ///     println!("{}: {} saved {:.1}%",
-    ///         cmd.timestamp, cmd.rtk_cmd, cmd.savings_pct);
-    /// }
-    /// # Ok::<(), anyhow::Error>(())
-    /// ```
-    pub fn get_recent(&self, limit: usize) -> Result<Vec<CommandRecord>> {
+    /// See [`crate::rrule_period::parse_rrule`] for the supported
+    /// `FREQ`/`INTERVAL`/`COUNT`/`UNTIL` syntax. `dtstart` anchors the
+    /// first boundary; commands recorded before it fall into the first
+    /// bucket, and the final bucket stays open through "now".
+    pub fn get_by_rrule(
+        &self,
+        rrule: &str,
+        dtstart: chrono::NaiveDate,
+    ) -> Result<Vec<crate::rrule_period::RRulePeriodStats>> {
+        let rule = crate::rrule_period::parse_rrule(rrule)?;
+        let boundaries = crate::rrule_period::expand_boundaries(dtstart, &rule);
+
         let mut stmt = self.conn.prepare(
-            "SELECT timestamp, rtk_cmd, saved_tokens, savings_pct
+            "SELECT timestamp, input_tokens, output_tokens, saved_tokens, exec_time_ms
              FROM commands
-             ORDER BY timestamp DESC
-             LIMIT ?1",
+             ORDER BY timestamp ASC",
         )?;
 
-        let rows = stmt.query_map(params![limit as i64], |row| {
-            Ok(CommandRecord {
-                timestamp: DateTime::parse_from_rfc3339(&row.get::<_, String>(0)?)
-                    .map(|dt| dt.with_timezone(&Utc))
-                    .unwrap_or_else(|_| Utc::now()),
-                rtk_cmd: row.get(1)?,
-                saved_tokens: row.get::<_, i64>(2)? as usize,
-                savings_pct: row.get(3)?,
-            })
-        })?;
-
-        Ok(rows.collect::<Result<Vec<_>, _>>()?)
+        let records = stmt
+            .query_map([], |row| {
+                let timestamp: String = row.get(0)?;
+                let date = DateTime::parse_from_rfc3339(&timestamp)
+                    .map(|dt| dt.with_timezone(&Utc).date_naive())
+                    .unwrap_or_else(|_| Utc::now().date_naive());
+                Ok((
+                    date,
+                    row.get::<_, i64>(1)? as usize,
+                    row.get::<_, i64>(2)? as usize,
+                    row.get::<_, i64>(3)? as usize,
+                    row.get::<_, i64>(4)? as u64,
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(crate::rrule_period::bucket_records(&boundaries, &records))
     }
-}
 
-fn get_db_path() -> Result<PathBuf> {
-    // Priority 1: Environment variable RTK_DB_PATH
-    if let Ok(custom_path) = std::env::var("RTK_DB_PATH") {
-        return Ok(PathBuf::from(custom_path));
-    }
+    /// Get daily statistics scoped to a `[from, to)` UTC interval.
+    ///
+    /// Same shape as [`get_all_days`](Self::get_all_days) but filtered via a
+    /// `WHERE timestamp >= ?1 AND timestamp < ?2` clause instead of
+    /// returning all recorded history.
+    pub fn get_all_days_range(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<DayStats>> {
+        let from_s = from.to_rfc3339();
+        let to_s = to.to_rfc3339();
+        let latencies = self.latency_by_group_range("DATE(timestamp)", &from_s, &to_s)?;
 
-    // Priority 2: Configuration file
-    if let Ok(config) = crate::config::Config::load() {
-        if let Some(db_path) = config.tracking.database_path {
-            return Ok(db_path);
-        }
-    }
+        let mut stmt = self.conn.prepare(
+            "SELECT
+                DATE(timestamp) as date,
+                COUNT(*) as commands,
+                SUM(input_tokens) as input,
+                SUM(output_tokens) as output,
+                SUM(saved_tokens) as saved,
+                SUM(exec_time_ms) as total_time
+             FROM commands
+             WHERE timestamp >= ?1 AND timestamp < ?2
+             GROUP BY DATE(timestamp)
+             ORDER BY DATE(timestamp) DESC",
+        )?;
+
+        let rows = stmt.query_map(params![from_s, to_s], |row| {
+            let date: String = row.get(0)?;
+            let input = row.get::<_, i64>(2)? as usize;
+            let saved = row.get::<_, i64>(4)? as usize;
+            let commands = row.get::<_, i64>(1)? as usize;
+            let total_time = row.get::<_, i64>(5)? as u64;
+            let savings_pct = if input > 0 {
+                (saved as f64 / input as f64) * 100.0
+            } else {
+                0.0
+            };
+            let avg_time_ms = if commands > 0 {
+                total_time / commands as u64
+            } else {
+                0
+            };
+            let hist = latencies.get(&date);
+
+            Ok(DayStats {
+                date,
+                commands,
+                input_tokens: input,
+                output_tokens: row.get::<_, i64>(3)? as usize,
+                saved_tokens: saved,
+                savings_pct,
+                total_time_ms: total_time,
+                avg_time_ms,
+                p50_time_ms: hist.map(|h| h.percentile(50.0)).unwrap_or(0),
+                p95_time_ms: hist.map(|h| h.percentile(95.0)).unwrap_or(0),
+                p99_time_ms: hist.map(|h| h.percentile(99.0)).unwrap_or(0),
+            })
+        })?;
+
+        let mut result: Vec<_> = rows.collect::<Result<Vec<_>, _>>()?;
+        result.reverse();
+        Ok(result)
+    }
+
+    /// Get weekly statistics scoped to a `[from, to)` UTC interval.
+    ///
+    /// Same shape as [`get_by_week`](Self::get_by_week) but filtered via a
+    /// `WHERE timestamp >= ?1 AND timestamp < ?2` clause instead of
+    /// returning all recorded history.
+    pub fn get_by_week_range(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<WeekStats>> {
+        let from_s = from.to_rfc3339();
+        let to_s = to.to_rfc3339();
+        let latencies = self.latency_by_group_range(
+            "DATE(timestamp, 'weekday 0', '-6 days')",
+            &from_s,
+            &to_s,
+        )?;
+
+        let mut stmt = self.conn.prepare(
+            "SELECT
+                DATE(timestamp, 'weekday 0', '-6 days') as week_start,
+                DATE(timestamp, 'weekday 0') as week_end,
+                COUNT(*) as commands,
+                SUM(input_tokens) as input,
+                SUM(output_tokens) as output,
+                SUM(saved_tokens) as saved,
+                SUM(exec_time_ms) as total_time
+             FROM commands
+             WHERE timestamp >= ?1 AND timestamp < ?2
+             GROUP BY week_start
+             ORDER BY week_start DESC",
+        )?;
+
+        let rows = stmt.query_map(params![from_s, to_s], |row| {
+            let week_start: String = row.get(0)?;
+            let input = row.get::<_, i64>(3)? as usize;
+            let saved = row.get::<_, i64>(5)? as usize;
+            let commands = row.get::<_, i64>(2)? as usize;
+            let total_time = row.get::<_, i64>(6)? as u64;
+            let savings_pct = if input > 0 {
+                (saved as f64 / input as f64) * 100.0
+            } else {
+                0.0
+            };
+            let avg_time_ms = if commands > 0 {
+                total_time / commands as u64
+            } else {
+                0
+            };
+            let hist = latencies.get(&week_start);
+
+            Ok(WeekStats {
+                week_start,
+                week_end: row.get(1)?,
+                commands,
+                input_tokens: input,
+                output_tokens: row.get::<_, i64>(4)? as usize,
+                saved_tokens: saved,
+                savings_pct,
+                total_time_ms: total_time,
+                avg_time_ms,
+                p50_time_ms: hist.map(|h| h.percentile(50.0)).unwrap_or(0),
+                p95_time_ms: hist.map(|h| h.percentile(95.0)).unwrap_or(0),
+                p99_time_ms: hist.map(|h| h.percentile(99.0)).unwrap_or(0),
+            })
+        })?;
+
+        let mut result: Vec<_> = rows.collect::<Result<Vec<_>, _>>()?;
+        result.reverse();
+        Ok(result)
+    }
+
+    /// Get monthly statistics scoped to a `[from, to)` UTC interval.
+    ///
+    /// Same shape as [`get_by_month`](Self::get_by_month) but filtered via a
+    /// `WHERE timestamp >= ?1 AND timestamp < ?2` clause instead of
+    /// returning all recorded history.
+    pub fn get_by_month_range(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<MonthStats>> {
+        let from_s = from.to_rfc3339();
+        let to_s = to.to_rfc3339();
+        let latencies =
+            self.latency_by_group_range("strftime('%Y-%m', timestamp)", &from_s, &to_s)?;
+
+        let mut stmt = self.conn.prepare(
+            "SELECT
+                strftime('%Y-%m', timestamp) as month,
+                COUNT(*) as commands,
+                SUM(input_tokens) as input,
+                SUM(output_tokens) as output,
+                SUM(saved_tokens) as saved,
+                SUM(exec_time_ms) as total_time
+             FROM commands
+             WHERE timestamp >= ?1 AND timestamp < ?2
+             GROUP BY month
+             ORDER BY month DESC",
+        )?;
+
+        let rows = stmt.query_map(params![from_s, to_s], |row| {
+            let month: String = row.get(0)?;
+            let input = row.get::<_, i64>(2)? as usize;
+            let saved = row.get::<_, i64>(4)? as usize;
+            let commands = row.get::<_, i64>(1)? as usize;
+            let total_time = row.get::<_, i64>(5)? as u64;
+            let savings_pct = if input > 0 {
+                (saved as f64 / input as f64) * 100.0
+            } else {
+                0.0
+            };
+            let avg_time_ms = if commands > 0 {
+                total_time / commands as u64
+            } else {
+                0
+            };
+            let hist = latencies.get(&month);
+
+            Ok(MonthStats {
+                month,
+                commands,
+                input_tokens: input,
+                output_tokens: row.get::<_, i64>(3)? as usize,
+                saved_tokens: saved,
+                savings_pct,
+                total_time_ms: total_time,
+                avg_time_ms,
+                p50_time_ms: hist.map(|h| h.percentile(50.0)).unwrap_or(0),
+                p95_time_ms: hist.map(|h| h.percentile(95.0)).unwrap_or(0),
+                p99_time_ms: hist.map(|h| h.percentile(99.0)).unwrap_or(0),
+            })
+        })?;
+
+        let mut result: Vec<_> = rows.collect::<Result<Vec<_>, _>>()?;
+        result.reverse();
+        Ok(result)
+    }
+
+    /// Get recent command history.
+    ///
+    /// Returns up to `limit` most recent command records, ordered by timestamp (newest first).
+    ///
+    /// # Arguments
+    ///
+    /// - `limit`: Maximum number of records to return
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rtk::tracking::Tracker;
+    ///
+    /// let tracker = Tracker::new()?;
+    /// let recent = tracker.get_recent(10)?;
+    /// for cmd in recent {
+    ///     println!("{}: {} saved {:.1}%",
+    ///         cmd.timestamp, cmd.rtk_cmd, cmd.savings_pct);
+    /// }
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn get_recent(&self, limit: usize) -> Result<Vec<CommandRecord>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT timestamp, rtk_cmd, saved_tokens, savings_pct
+             FROM commands
+             ORDER BY timestamp DESC
+             LIMIT ?1",
+        )?;
+
+        let rows = stmt.query_map(params![limit as i64], |row| {
+            Ok(CommandRecord {
+                timestamp: DateTime::parse_from_rfc3339(&row.get::<_, String>(0)?)
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .unwrap_or_else(|_| Utc::now()),
+                rtk_cmd: row.get(1)?,
+                saved_tokens: row.get::<_, i64>(2)? as usize,
+                savings_pct: row.get(3)?,
+            })
+        })?;
+
+        Ok(rows.collect::<Result<Vec<_>, _>>()?)
+    }
+
+    /// Get recent command history with every column, including the row id
+    /// and `original_cmd` that [`CommandRecord`]/[`Tracker::get_recent`]
+    /// drop. Used by the `--interactive` inspector, which needs the id to
+    /// delete a row and the original command to show alongside the rtk one.
+    ///
+    /// # Arguments
+    ///
+    /// - `limit`: Maximum number of records to return
+    pub fn get_recent_detailed(&self, limit: usize) -> Result<Vec<CommandRecordDetailed>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, timestamp, original_cmd, rtk_cmd, input_tokens, output_tokens, saved_tokens, savings_pct, exec_time_ms
+             FROM commands
+             ORDER BY timestamp DESC
+             LIMIT ?1",
+        )?;
+
+        let rows = stmt.query_map(params![limit as i64], |row| {
+            Ok(CommandRecordDetailed {
+                id: row.get(0)?,
+                timestamp: DateTime::parse_from_rfc3339(&row.get::<_, String>(1)?)
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .unwrap_or_else(|_| Utc::now()),
+                original_cmd: row.get(2)?,
+                rtk_cmd: row.get(3)?,
+                input_tokens: row.get::<_, i64>(4)? as usize,
+                output_tokens: row.get::<_, i64>(5)? as usize,
+                saved_tokens: row.get::<_, i64>(6)? as usize,
+                savings_pct: row.get(7)?,
+                exec_time_ms: row.get::<_, i64>(8)? as u64,
+            })
+        })?;
+
+        Ok(rows.collect::<Result<Vec<_>, _>>()?)
+    }
+
+    /// Query command records with optional filters, for `rtk gain --list`.
+    ///
+    /// `command` matches `rtk_cmd` by substring (`LIKE %needle%`),
+    /// `min_savings_pct` is a lower bound on `savings_pct`, and `window`
+    /// bounds `timestamp` the same way the other period queries do.
+    /// `sort` is one of `"saved"`, `"pct"`, `"time"`, `"time-desc"`
+    /// (default), matching [`Tracker::get_recent_detailed`]'s column set.
+    /// Filters are pushed into the SQL `WHERE`/`ORDER BY` clauses rather
+    /// than applied after loading every row, so this stays cheap against a
+    /// large tracking database.
+    pub fn list_records(
+        &self,
+        command: Option<&str>,
+        min_savings_pct: Option<f64>,
+        window: Option<(DateTime<Utc>, DateTime<Utc>)>,
+        sort: &str,
+        limit: usize,
+    ) -> Result<Vec<CommandRecordDetailed>> {
+        let mut sql = String::from(
+            "SELECT id, timestamp, original_cmd, rtk_cmd, input_tokens, output_tokens, saved_tokens, savings_pct, exec_time_ms
+             FROM commands
+             WHERE 1=1",
+        );
+        let mut query_params: Vec<Box<dyn ToSql>> = Vec::new();
+
+        if let Some(cmd) = command {
+            sql.push_str(" AND rtk_cmd LIKE ?");
+            query_params.push(Box::new(format!("%{cmd}%")));
+        }
+        if let Some(min_pct) = min_savings_pct {
+            sql.push_str(" AND savings_pct >= ?");
+            query_params.push(Box::new(min_pct));
+        }
+        if let Some((from, to)) = window {
+            sql.push_str(" AND timestamp >= ? AND timestamp < ?");
+            query_params.push(Box::new(from.to_rfc3339()));
+            query_params.push(Box::new(to.to_rfc3339()));
+        }
+
+        sql.push_str(match sort {
+            "saved" => " ORDER BY saved_tokens DESC",
+            "pct" => " ORDER BY savings_pct DESC",
+            "time" => " ORDER BY exec_time_ms ASC",
+            _ => " ORDER BY exec_time_ms DESC", // "time-desc" and default
+        });
+        sql.push_str(" LIMIT ?");
+        query_params.push(Box::new(limit as i64));
+
+        let mut stmt = self.conn.prepare(&sql)?;
+        let rows = stmt.query_map(params_from_iter(query_params), |row| {
+            Ok(CommandRecordDetailed {
+                id: row.get(0)?,
+                timestamp: DateTime::parse_from_rfc3339(&row.get::<_, String>(1)?)
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .unwrap_or_else(|_| Utc::now()),
+                original_cmd: row.get(2)?,
+                rtk_cmd: row.get(3)?,
+                input_tokens: row.get::<_, i64>(4)? as usize,
+                output_tokens: row.get::<_, i64>(5)? as usize,
+                saved_tokens: row.get::<_, i64>(6)? as usize,
+                savings_pct: row.get(7)?,
+                exec_time_ms: row.get::<_, i64>(8)? as u64,
+            })
+        })?;
+
+        Ok(rows.collect::<Result<Vec<_>, _>>()?)
+    }
+
+    /// Delete a single command record by its row id.
+    ///
+    /// Used by the `--interactive` inspector to remove individual rows (or
+    /// a selected range, one id at a time) directly from the UI.
+    pub fn delete_by_id(&self, id: i64) -> Result<()> {
+        self.conn
+            .execute("DELETE FROM commands WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    /// Render aggregated savings data as [Prometheus text exposition
+    /// format](https://prometheus.io/docs/instrumenting/exposition_formats/),
+    /// suitable for `rtk gain --format prometheus` or the `metrics-server`
+    /// feature's `/metrics` endpoint.
+    ///
+    /// Each metric is emitted once as an overall total (from
+    /// [`Tracker::get_summary`]) plus one `command="..."`-labeled sample per
+    /// entry in `summary.by_command` (top 10 commands by tokens saved), all
+    /// under a single `# HELP`/`# TYPE` pair so scrapers don't choke on a
+    /// repeated metric name. `rtk_daily_tokens_saved` adds a `date="..."`
+    /// gauge per day from [`Tracker::get_all_days`].
+    pub fn export_prometheus(&self) -> Result<String> {
+        let summary = self.get_summary()?;
+        let days = self.get_all_days()?;
+
+        let mut out = String::new();
+
+        out.push_str("# HELP rtk_commands_total Total tracked command executions.\n");
+        out.push_str("# TYPE rtk_commands_total counter\n");
+        out.push_str(&format!("rtk_commands_total {}\n", summary.total_commands));
+        for (cmd, count, _, _, _) in &summary.by_command {
+            out.push_str(&format!(
+                "rtk_commands_total{{command=\"{}\"}} {}\n",
+                escape_label(cmd),
+                count
+            ));
+        }
+
+        out.push_str("# HELP rtk_input_tokens_total Total input tokens across tracked commands.\n");
+        out.push_str("# TYPE rtk_input_tokens_total counter\n");
+        out.push_str(&format!("rtk_input_tokens_total {}\n", summary.total_input));
+
+        out.push_str("# HELP rtk_output_tokens_total Total output tokens across tracked commands.\n");
+        out.push_str("# TYPE rtk_output_tokens_total counter\n");
+        out.push_str(&format!("rtk_output_tokens_total {}\n", summary.total_output));
+
+        out.push_str("# HELP rtk_tokens_saved_total Total tokens saved, overall and by command.\n");
+        out.push_str("# TYPE rtk_tokens_saved_total counter\n");
+        out.push_str(&format!("rtk_tokens_saved_total {}\n", summary.total_saved));
+        for (cmd, _, saved, _, _) in &summary.by_command {
+            out.push_str(&format!(
+                "rtk_tokens_saved_total{{command=\"{}\"}} {}\n",
+                escape_label(cmd),
+                saved
+            ));
+        }
+
+        out.push_str("# HELP rtk_savings_pct Average token savings percentage, overall and by command.\n");
+        out.push_str("# TYPE rtk_savings_pct gauge\n");
+        out.push_str(&format!("rtk_savings_pct {:.2}\n", summary.avg_savings_pct));
+        for (cmd, _, _, avg_savings_pct, _) in &summary.by_command {
+            out.push_str(&format!(
+                "rtk_savings_pct{{command=\"{}\"}} {:.2}\n",
+                escape_label(cmd),
+                avg_savings_pct
+            ));
+        }
+
+        out.push_str("# HELP rtk_exec_time_ms_total Total command execution time in milliseconds (per-command samples are the average).\n");
+        out.push_str("# TYPE rtk_exec_time_ms_total counter\n");
+        out.push_str(&format!("rtk_exec_time_ms_total {}\n", summary.total_time_ms));
+        for (cmd, _, _, _, avg_time_ms) in &summary.by_command {
+            out.push_str(&format!(
+                "rtk_exec_time_ms_total{{command=\"{}\"}} {}\n",
+                escape_label(cmd),
+                avg_time_ms
+            ));
+        }
+
+        out.push_str("# HELP rtk_daily_tokens_saved Tokens saved per day.\n");
+        out.push_str("# TYPE rtk_daily_tokens_saved gauge\n");
+        for day in &days {
+            out.push_str(&format!(
+                "rtk_daily_tokens_saved{{date=\"{}\"}} {}\n",
+                escape_label(&day.date),
+                day.saved_tokens
+            ));
+        }
+
+        Ok(out)
+    }
+
+    /// Aggregate recorded runs by `rtk_cmd`, computing mean/median/min/max
+    /// and sample standard deviation of `savings_pct` and `exec_time_ms`
+    /// per command, plus which runs are timing outliers. Sorted by
+    /// `total_saved` descending, same order as [`Tracker::get_by_command`].
+    pub fn stats(&self) -> Result<Vec<CommandStats>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT rtk_cmd, savings_pct, exec_time_ms, saved_tokens
+             FROM commands
+             ORDER BY rtk_cmd, timestamp",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, f64>(1)?,
+                row.get::<_, i64>(2)? as u64,
+                row.get::<_, i64>(3)? as usize,
+            ))
+        })?;
+
+        let mut by_cmd: HashMap<String, Vec<(f64, u64, usize)>> = HashMap::new();
+        for row in rows {
+            let (rtk_cmd, savings_pct, exec_time_ms, saved_tokens) = row?;
+            by_cmd
+                .entry(rtk_cmd)
+                .or_default()
+                .push((savings_pct, exec_time_ms, saved_tokens));
+        }
+
+        let mut stats: Vec<CommandStats> = by_cmd
+            .into_iter()
+            .map(|(rtk_cmd, samples)| command_stats_from_samples(rtk_cmd, &samples))
+            .collect();
+        stats.sort_by(|a, b| b.total_saved.cmp(&a.total_saved));
+        Ok(stats)
+    }
+
+    /// Export the most recent `limit` command records to `writer` as
+    /// `"json"`, `"csv"`, or `"markdown"`, so users can archive or share
+    /// savings data instead of being locked into the local SQLite DB.
+    ///
+    /// JSON emits an array of objects with every field (including `id` and
+    /// `timestamp` as RFC 3339); CSV emits a header plus one row per
+    /// record; Markdown emits a table suitable for pasting into a report.
+    pub fn export_history<W: Write>(&self, format: &str, limit: usize, writer: &mut W) -> Result<()> {
+        let records = self.get_recent_detailed(limit)?;
+        match format {
+            "json" => export_history_json(&records, writer),
+            "csv" => export_history_csv(&records, writer),
+            "markdown" | "md" => export_history_markdown(&records, writer),
+            other => anyhow::bail!("unknown export format: {other} (expected json, csv, or markdown)"),
+        }
+    }
+}
+
+pub(crate) fn export_history_json<W: Write>(records: &[CommandRecordDetailed], writer: &mut W) -> Result<()> {
+    #[derive(Serialize)]
+    struct ExportRow<'a> {
+        id: i64,
+        timestamp: String,
+        original_cmd: &'a str,
+        rtk_cmd: &'a str,
+        input_tokens: usize,
+        output_tokens: usize,
+        saved_tokens: usize,
+        savings_pct: f64,
+        exec_time_ms: u64,
+    }
+
+    let rows: Vec<ExportRow> = records
+        .iter()
+        .map(|r| ExportRow {
+            id: r.id,
+            timestamp: r.timestamp.to_rfc3339(),
+            original_cmd: &r.original_cmd,
+            rtk_cmd: &r.rtk_cmd,
+            input_tokens: r.input_tokens,
+            output_tokens: r.output_tokens,
+            saved_tokens: r.saved_tokens,
+            savings_pct: r.savings_pct,
+            exec_time_ms: r.exec_time_ms,
+        })
+        .collect();
+
+    let json = serde_json::to_string_pretty(&rows)?;
+    writeln!(writer, "{json}")?;
+    Ok(())
+}
+
+pub(crate) fn export_history_csv<W: Write>(records: &[CommandRecordDetailed], writer: &mut W) -> Result<()> {
+    writeln!(
+        writer,
+        "id,timestamp,original_cmd,rtk_cmd,input_tokens,output_tokens,saved_tokens,savings_pct,exec_time_ms"
+    )?;
+    for r in records {
+        writeln!(
+            writer,
+            "{},{},{},{},{},{},{},{:.2},{}",
+            r.id,
+            r.timestamp.to_rfc3339(),
+            csv_escape(&r.original_cmd),
+            csv_escape(&r.rtk_cmd),
+            r.input_tokens,
+            r.output_tokens,
+            r.saved_tokens,
+            r.savings_pct,
+            r.exec_time_ms
+        )?;
+    }
+    Ok(())
+}
+
+fn export_history_markdown<W: Write>(records: &[CommandRecordDetailed], writer: &mut W) -> Result<()> {
+    writeln!(
+        writer,
+        "| Timestamp | Original | RTK | Input | Output | Saved | Savings % | Exec ms |"
+    )?;
+    writeln!(writer, "|---|---|---|---|---|---|---|---|")?;
+    for r in records {
+        writeln!(
+            writer,
+            "| {} | {} | {} | {} | {} | {} | {:.1}% | {} |",
+            r.timestamp.to_rfc3339(),
+            markdown_escape(&r.original_cmd),
+            markdown_escape(&r.rtk_cmd),
+            r.input_tokens,
+            r.output_tokens,
+            r.saved_tokens,
+            r.savings_pct,
+            r.exec_time_ms
+        )?;
+    }
+    Ok(())
+}
+
+/// Quote a CSV field if it contains a comma, quote, or newline, doubling
+/// any embedded quotes.
+fn csv_escape(field: &str) -> String {
+    if field.contains(',') || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Escape `|` and collapse newlines so a field can't break a Markdown
+/// table row.
+fn markdown_escape(field: &str) -> String {
+    field.replace('|', "\\|").replace('\n', " ")
+}
+
+fn command_stats_from_samples(rtk_cmd: String, samples: &[(f64, u64, usize)]) -> CommandStats {
+    let savings_pcts: Vec<f64> = samples.iter().map(|(pct, _, _)| *pct).collect();
+    let exec_times: Vec<f64> = samples.iter().map(|(_, ms, _)| *ms as f64).collect();
+    let total_saved = samples.iter().map(|(_, _, saved)| saved).sum();
+
+    let savings_pct_mean = mean(&savings_pcts);
+    let exec_time_mean_ms = mean(&exec_times);
+
+    CommandStats {
+        rtk_cmd,
+        sample_count: samples.len(),
+        total_saved,
+        savings_pct_mean,
+        savings_pct_median: median(&savings_pcts),
+        savings_pct_min: savings_pcts.iter().cloned().fold(f64::INFINITY, f64::min),
+        savings_pct_max: savings_pcts.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+        savings_pct_stddev: stddev(&savings_pcts, savings_pct_mean),
+        exec_time_mean_ms,
+        exec_time_median_ms: median(&exec_times),
+        exec_time_min_ms: samples.iter().map(|(_, ms, _)| *ms).min().unwrap_or(0),
+        exec_time_max_ms: samples.iter().map(|(_, ms, _)| *ms).max().unwrap_or(0),
+        exec_time_stddev_ms: stddev(&exec_times, exec_time_mean_ms),
+        exec_time_outliers: modified_z_outliers(&exec_times),
+    }
+}
+
+fn mean(xs: &[f64]) -> f64 {
+    if xs.is_empty() {
+        return 0.0;
+    }
+    xs.iter().sum::<f64>() / xs.len() as f64
+}
+
+fn median(xs: &[f64]) -> f64 {
+    if xs.is_empty() {
+        return 0.0;
+    }
+    let mut sorted = xs.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+/// Sample standard deviation: `sqrt(Σ(xᵢ-mean)² / (n-1))`. `0.0` for fewer
+/// than 2 samples (n-1 would be zero or negative).
+fn stddev(xs: &[f64], mean: f64) -> f64 {
+    if xs.len() < 2 {
+        return 0.0;
+    }
+    let variance = xs.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (xs.len() - 1) as f64;
+    variance.sqrt()
+}
+
+/// Flag outliers by modified Z-score: `|0.6745·(xᵢ-med)/MAD| > 3.5`, where
+/// `MAD = median(|xᵢ-med|)`. `MAD == 0` means every sample equals the
+/// median, so nothing is flagged.
+fn modified_z_outliers(xs: &[f64]) -> Vec<usize> {
+    if xs.len() < 2 {
+        return Vec::new();
+    }
+    let med = median(xs);
+    let deviations: Vec<f64> = xs.iter().map(|x| (x - med).abs()).collect();
+    let mad = median(&deviations);
+    if mad == 0.0 {
+        return Vec::new();
+    }
+    xs.iter()
+        .enumerate()
+        .filter(|(_, x)| (0.6745 * (*x - med) / mad).abs() > 3.5)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Escape a Prometheus label value per the exposition format: backslash,
+/// double-quote, and newline must be escaped.
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn get_db_path() -> Result<PathBuf> {
+    // Priority 1: Environment variable RTK_DB_PATH
+    if let Ok(custom_path) = std::env::var("RTK_DB_PATH") {
+        return Ok(PathBuf::from(custom_path));
+    }
+
+    // Priority 2: Configuration file
+    if let Ok(config) = crate::config::Config::load() {
+        if let Some(db_path) = config.tracking.database_path {
+            return Ok(db_path);
+        }
+    }
 
     // Priority 3: Default platform-specific location
     let data_dir = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
@@ -719,6 +1888,28 @@ pub fn estimate_tokens(text: &str) -> usize {
     (text.len() as f64 / 4.0).ceil() as usize
 }
 
+/// Whether [`TimedExecution`] should run in deterministic mock mode, per
+/// the `RTK_MOCK` environment variable.
+fn is_mock_mode() -> bool {
+    std::env::var("RTK_MOCK").map(|v| v == "1").unwrap_or(false)
+}
+
+/// Parse a fake duration in milliseconds out of a mock-mode command
+/// string, recognizing `sleep N`/`sleep Ns` (seconds, optionally
+/// fractional). Returns `None` when no such marker is present.
+fn parse_mock_duration_ms(cmd: &str) -> Option<u64> {
+    let after = cmd.split("sleep ").nth(1)?;
+    let token = after.split_whitespace().next()?;
+    let seconds: f64 = token.trim_end_matches('s').parse().ok()?;
+    Some((seconds * 1000.0).round() as u64)
+}
+
+/// Parse a mock-mode literal token count; anything that doesn't parse as
+/// an integer counts as `0` rather than falling back to `estimate_tokens`.
+fn parse_mock_tokens(value: &str) -> usize {
+    value.trim().parse().unwrap_or(0)
+}
+
 /// Helper struct for timing command execution
 /// Helper for timing command execution and tracking results.
 ///
@@ -738,6 +1929,7 @@ pub fn estimate_tokens(text: &str) -> usize {
 /// ```
 pub struct TimedExecution {
     start: Instant,
+    mock: bool,
 }
 
 impl TimedExecution {
@@ -747,6 +1939,11 @@ impl TimedExecution {
     /// Call [`track`](Self::track) or [`track_passthrough`](Self::track_passthrough)
     /// when the command completes.
     ///
+    /// When the `RTK_MOCK` environment variable is set to `1`, the timer
+    /// runs in deterministic mock mode (see [`track`](Self::track)), so
+    /// tests can assert exact `saved_tokens`/`savings_pct`/`exec_time_ms`
+    /// values without timing flakiness or running real subprocesses.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -759,6 +1956,21 @@ impl TimedExecution {
     pub fn start() -> Self {
         Self {
             start: Instant::now(),
+            mock: is_mock_mode(),
+        }
+    }
+
+    /// Milliseconds elapsed since [`start`](Self::start).
+    ///
+    /// In mock mode, this is instead a fake duration parsed from `cmd`
+    /// (currently recognizing `sleep N`/`sleep Ns`, in seconds), defaulting
+    /// to `0` when nothing recognizable is found — no subprocess runs and
+    /// no real clock time passes in mock mode.
+    fn elapsed_ms(&self, cmd: &str) -> u64 {
+        if self.mock {
+            parse_mock_duration_ms(cmd).unwrap_or(0)
+        } else {
+            self.start.elapsed().as_millis() as u64
         }
     }
 
@@ -776,6 +1988,15 @@ impl TimedExecution {
     /// - `input`: Standard command output (for token estimation)
     /// - `output`: RTK command output (for token estimation)
     ///
+    /// # Mock mode
+    ///
+    /// When [`start`](Self::start) was called with `RTK_MOCK=1` set,
+    /// `input`/`output` are instead parsed as literal token counts (e.g.
+    /// `"120"`, not real command output) and `rtk_cmd` is scanned for a
+    /// fake duration (see [`elapsed_ms`](Self::elapsed_ms)) rather than
+    /// using `estimate_tokens`/the wall clock. A value that doesn't parse
+    /// as an integer counts as `0` tokens.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -787,18 +2008,73 @@ impl TimedExecution {
     /// timer.track("ls -la", "rtk ls", input, output);
     /// ```
     pub fn track(&self, original_cmd: &str, rtk_cmd: &str, input: &str, output: &str) {
-        let elapsed_ms = self.start.elapsed().as_millis() as u64;
-        let input_tokens = estimate_tokens(input);
-        let output_tokens = estimate_tokens(output);
-
-        if let Ok(tracker) = Tracker::new() {
-            let _ = tracker.record(
+        if self.mock {
+            self.record_raw(
                 original_cmd,
                 rtk_cmd,
-                input_tokens,
-                output_tokens,
-                elapsed_ms,
+                parse_mock_tokens(input),
+                parse_mock_tokens(output),
             );
+            return;
+        }
+
+        self.track_with_tokenizer(
+            original_cmd,
+            rtk_cmd,
+            input,
+            output,
+            crate::tokenizer::configured().as_ref(),
+        );
+    }
+
+    /// Like [`track`](Self::track), but counts tokens with an explicit
+    /// [`Tokenizer`](crate::tokenizer::Tokenizer) instead of the one
+    /// configured via `config.tracking.tokenizer_model`. Still honors mock
+    /// mode's fake `rtk_cmd`-derived duration.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rtk::tokenizer::HeuristicTokenizer;
+    /// use rtk::tracking::TimedExecution;
+    ///
+    /// let timer = TimedExecution::start();
+    /// timer.track_with_tokenizer("ls -la", "rtk ls", "input", "output", &HeuristicTokenizer);
+    /// ```
+    pub fn track_with_tokenizer(
+        &self,
+        original_cmd: &str,
+        rtk_cmd: &str,
+        input: &str,
+        output: &str,
+        tokenizer: &dyn crate::tokenizer::Tokenizer,
+    ) {
+        let input_tokens = tokenizer.count_tokens(input);
+        let output_tokens = tokenizer.count_tokens(output);
+        self.record_raw(original_cmd, rtk_cmd, input_tokens, output_tokens);
+    }
+
+    #[tracing::instrument(
+        skip_all,
+        fields(original_cmd = %original_cmd, rtk_cmd = %rtk_cmd, input_tokens, output_tokens, elapsed_ms)
+    )]
+    fn record_raw(&self, original_cmd: &str, rtk_cmd: &str, input_tokens: usize, output_tokens: usize) {
+        let elapsed_ms = self.elapsed_ms(rtk_cmd);
+        let span = tracing::Span::current();
+        span.record("input_tokens", input_tokens);
+        span.record("output_tokens", output_tokens);
+        span.record("elapsed_ms", elapsed_ms);
+
+        let tracker = match Tracker::new() {
+            Ok(tracker) => tracker,
+            Err(err) => {
+                tracing::warn!(error = %err, "failed to open tracking database; command not recorded");
+                return;
+            }
+        };
+
+        if let Err(err) = tracker.record(original_cmd, rtk_cmd, input_tokens, output_tokens, elapsed_ms) {
+            tracing::warn!(error = %err, "failed to record command; savings data will be incomplete");
         }
     }
 
@@ -823,11 +2099,8 @@ impl TimedExecution {
     /// timer.track_passthrough("git tag", "rtk git tag");
     /// ```
     pub fn track_passthrough(&self, original_cmd: &str, rtk_cmd: &str) {
-        let elapsed_ms = self.start.elapsed().as_millis() as u64;
         // input_tokens=0, output_tokens=0 won't dilute savings statistics
-        if let Ok(tracker) = Tracker::new() {
-            let _ = tracker.record(original_cmd, rtk_cmd, 0, 0, elapsed_ms);
-        }
+        self.record_raw(original_cmd, rtk_cmd, 0, 0);
     }
 }
 
@@ -882,14 +2155,23 @@ pub fn track(original_cmd: &str, rtk_cmd: &str, input: &str, output: &str) {
     let input_tokens = estimate_tokens(input);
     let output_tokens = estimate_tokens(output);
 
-    if let Ok(tracker) = Tracker::new() {
-        let _ = tracker.record(original_cmd, rtk_cmd, input_tokens, output_tokens, 0);
+    let tracker = match Tracker::new() {
+        Ok(tracker) => tracker,
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to open tracking database; command not recorded");
+            return;
+        }
+    };
+
+    if let Err(err) = tracker.record(original_cmd, rtk_cmd, input_tokens, output_tokens, 0) {
+        tracing::warn!(error = %err, "failed to record command; savings data will be incomplete");
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
 
     // 1. estimate_tokens — verify ~4 chars/token ratio
     #[test]
@@ -1037,4 +2319,305 @@ mod tests {
         let db_path = get_db_path().expect("Failed to get db path");
         assert!(db_path.ends_with("rtk/history.db"));
     }
+
+    // 9. get_stats_for_range only counts commands within [from, to)
+    #[test]
+    fn test_get_stats_for_range_excludes_out_of_window_records() {
+        let tracker = Tracker::new().expect("Failed to create tracker");
+        let pid = std::process::id();
+        let test_cmd = format!("rtk range_test_{}", pid);
+
+        tracker
+            .record("range cmd", &test_cmd, 500, 100, 20)
+            .expect("Failed to record");
+
+        // A window that starts after the record was written should exclude it.
+        let future_from = Utc::now() + chrono::Duration::days(1);
+        let future_to = future_from + chrono::Duration::days(1);
+        let empty = tracker
+            .get_stats_for_range(future_from, future_to)
+            .expect("Failed to query range stats");
+        assert!(!empty
+            .by_command
+            .iter()
+            .any(|(cmd, ..)| cmd == &test_cmd));
+
+        // A window spanning now should include it.
+        let from = Utc::now() - chrono::Duration::minutes(5);
+        let to = Utc::now() + chrono::Duration::minutes(5);
+        let included = tracker
+            .get_stats_for_range(from, to)
+            .expect("Failed to query range stats");
+        assert!(included
+            .by_command
+            .iter()
+            .any(|(cmd, ..)| cmd == &test_cmd));
+    }
+
+    // 10. compute_prune_list — keep_last alone keeps the N newest rows
+    #[test]
+    fn test_compute_prune_list_keep_last_only() {
+        let now = Utc::now();
+        let records: Vec<(i64, DateTime<Utc>)> = (0..5)
+            .map(|i| (i, now - chrono::Duration::hours(i)))
+            .collect();
+        let policy = RetentionPolicy {
+            keep_last: 2,
+            keep_daily: 0,
+            keep_weekly: 0,
+            keep_monthly: 0,
+        };
+
+        let mut deleted = compute_prune_list(&records, &policy);
+        deleted.sort();
+        assert_eq!(deleted, vec![2, 3, 4]);
+    }
+
+    // 11. compute_prune_list — daily tier keeps one row per day, newest first
+    #[test]
+    fn test_compute_prune_list_daily_tier_collapses_same_day() {
+        let day0 = Utc.with_ymd_and_hms(2026, 6, 1, 9, 0, 0).unwrap();
+        let records = vec![
+            (1, day0),
+            (2, day0 + chrono::Duration::hours(1)),
+            (3, day0 + chrono::Duration::hours(2)),
+        ];
+        let policy = RetentionPolicy {
+            keep_last: 0,
+            keep_daily: 1,
+            keep_weekly: 0,
+            keep_monthly: 0,
+        };
+
+        let mut deleted = compute_prune_list(&records, &policy);
+        deleted.sort();
+        // Only the newest record of the day (id 3) is kept by the daily tier.
+        assert_eq!(deleted, vec![1, 2]);
+    }
+
+    // 12. compute_prune_list — monthly tier keeps the newest row per distinct month
+    #[test]
+    fn test_compute_prune_list_monthly_tier_spans_year() {
+        let records: Vec<(i64, DateTime<Utc>)> = (0..12)
+            .map(|i| {
+                (
+                    i,
+                    Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap() + chrono::Duration::days(i * 30),
+                )
+            })
+            .collect();
+        let policy = RetentionPolicy {
+            keep_last: 0,
+            keep_daily: 0,
+            keep_weekly: 0,
+            keep_monthly: 12,
+        };
+
+        // 30-day steps drift across month boundaries unevenly, so January
+        // and May each get two samples (ids 0/1 and 4/5); the monthly tier
+        // keeps only the newer one (1, 5) and prunes the older duplicate.
+        let mut deleted = compute_prune_list(&records, &policy);
+        deleted.sort();
+        assert_eq!(deleted, vec![0, 4]);
+    }
+
+    // 13. compute_prune_list — a row kept by any tier survives even if another tier drops it
+    #[test]
+    fn test_compute_prune_list_union_across_tiers() {
+        let now = Utc.with_ymd_and_hms(2026, 6, 15, 12, 0, 0).unwrap();
+        let records = vec![
+            (1, now),
+            (2, now - chrono::Duration::days(1)),
+            (3, now - chrono::Duration::days(40)),
+        ];
+        let policy = RetentionPolicy {
+            keep_last: 1,
+            keep_daily: 0,
+            keep_weekly: 0,
+            keep_monthly: 2,
+        };
+
+        let mut deleted = compute_prune_list(&records, &policy);
+        deleted.sort();
+        // id 1 (June): kept by both keep_last and keep_monthly.
+        // id 3 (May, a different month): kept by keep_monthly's second
+        // budget slot.
+        // id 2 (also June, but the monthly tier's June slot is already
+        // claimed by id 1 and keep_last's budget is exhausted): pruned.
+        assert_eq!(deleted, vec![2]);
+    }
+
+    // 14. record_bulk — inserts every row in one transaction
+    #[test]
+    fn test_record_bulk_inserts_all_rows() {
+        let tracker = Tracker::new().expect("Failed to create tracker");
+        let pid = std::process::id();
+        let cmd1 = format!("rtk bulk1_test_{}", pid);
+        let cmd2 = format!("rtk bulk2_test_{}", pid);
+
+        tracker
+            .record_bulk(&[
+                CommandEntry {
+                    original_cmd: "git status".to_string(),
+                    rtk_cmd: cmd1.clone(),
+                    input_tokens: 100,
+                    output_tokens: 20,
+                    exec_time_ms: 10,
+                },
+                CommandEntry {
+                    original_cmd: "git log".to_string(),
+                    rtk_cmd: cmd2.clone(),
+                    input_tokens: 200,
+                    output_tokens: 50,
+                    exec_time_ms: 30,
+                },
+            ])
+            .expect("Failed to bulk record");
+
+        let recent = tracker.get_recent(50).expect("Failed to get recent");
+        assert!(recent.iter().any(|r| r.rtk_cmd == cmd1 && r.saved_tokens == 80));
+        assert!(recent.iter().any(|r| r.rtk_cmd == cmd2 && r.saved_tokens == 150));
+    }
+
+    // 15. record_bulk — an empty batch is a no-op, not an error
+    #[test]
+    fn test_record_bulk_empty_is_noop() {
+        let tracker = Tracker::new().expect("Failed to create tracker");
+        tracker
+            .record_bulk(&[])
+            .expect("Empty batch should succeed");
+    }
+
+    // 16. get_recent_detailed + delete_by_id — full row round-trip and removal
+    #[test]
+    fn test_get_recent_detailed_and_delete_by_id() {
+        let tracker = Tracker::new().expect("Failed to create tracker");
+        let pid = std::process::id();
+        let test_cmd = format!("rtk detailed_test_{}", pid);
+
+        tracker
+            .record("git diff --stat", &test_cmd, 300, 60, 15)
+            .expect("Failed to record");
+
+        let recent = tracker
+            .get_recent_detailed(50)
+            .expect("Failed to get recent detailed");
+        let row = recent
+            .iter()
+            .find(|r| r.rtk_cmd == test_cmd)
+            .expect("Test record not found in detailed history");
+
+        assert_eq!(row.original_cmd, "git diff --stat");
+        assert_eq!(row.input_tokens, 300);
+        assert_eq!(row.output_tokens, 60);
+        assert_eq!(row.saved_tokens, 240);
+
+        tracker.delete_by_id(row.id).expect("Failed to delete row");
+
+        let after_delete = tracker
+            .get_recent_detailed(50)
+            .expect("Failed to get recent detailed");
+        assert!(!after_delete.iter().any(|r| r.id == row.id));
+    }
+
+    // 17. parse_mock_duration_ms — recognizes "sleep N"/"sleep Ns" in a command string
+    #[test]
+    fn test_parse_mock_duration_ms() {
+        assert_eq!(parse_mock_duration_ms("rtk sleep 0.5"), Some(500));
+        assert_eq!(parse_mock_duration_ms("sleep 2s"), Some(2000));
+        assert_eq!(parse_mock_duration_ms("rtk ls -la"), None);
+    }
+
+    // 18. parse_mock_tokens — literal integer counts, 0 for anything else
+    #[test]
+    fn test_parse_mock_tokens() {
+        assert_eq!(parse_mock_tokens("120"), 120);
+        assert_eq!(parse_mock_tokens(" 7 "), 7);
+        assert_eq!(parse_mock_tokens("not a number"), 0);
+    }
+
+    // 19. mean/median/stddev — known closed-form values
+    #[test]
+    fn test_mean_median_stddev() {
+        let xs = vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        assert_eq!(mean(&xs), 5.0);
+        assert_eq!(median(&xs), 4.5);
+        // Σ(xᵢ-mean)² = 32, sample variance = 32/(8-1), stddev = sqrt(32/7).
+        assert!((stddev(&xs, mean(&xs)) - (32.0_f64 / 7.0).sqrt()).abs() < 1e-9);
+        assert_eq!(stddev(&[1.0], 1.0), 0.0);
+    }
+
+    // 20. modified_z_outliers — a single far-off value is flagged, a uniform series is not
+    #[test]
+    fn test_modified_z_outliers() {
+        let xs = vec![10.0, 11.0, 9.0, 10.0, 11.0, 9.0, 500.0];
+        assert_eq!(modified_z_outliers(&xs), vec![6]);
+
+        let uniform = vec![10.0, 10.0, 10.0, 10.0];
+        assert!(modified_z_outliers(&uniform).is_empty());
+    }
+
+    // 21. Tracker::stats — groups by rtk_cmd, aggregates, and flags a timing outlier
+    #[test]
+    fn test_tracker_stats_groups_and_flags_outliers() {
+        let tracker = Tracker::new().expect("Failed to create tracker");
+        let pid = std::process::id();
+        let test_cmd = format!("rtk stats_test_{}", pid);
+
+        for exec_time_ms in [100, 110, 105, 95, 5000] {
+            tracker
+                .record("some original cmd", &test_cmd, 100, 50, exec_time_ms)
+                .expect("Failed to record");
+        }
+
+        let stats = tracker.stats().expect("Failed to compute stats");
+        let entry = stats
+            .iter()
+            .find(|s| s.rtk_cmd == test_cmd)
+            .expect("Test command not found in stats");
+
+        assert_eq!(entry.sample_count, 5);
+        assert_eq!(entry.total_saved, 50 * 5);
+        assert_eq!(entry.exec_time_outliers.len(), 1);
+        assert_eq!(entry.exec_time_outliers[0], 4);
+    }
+
+    // 22. export_history — json/csv/markdown all contain the recorded row, csv/md escape specials
+    #[test]
+    fn test_export_history_formats() {
+        let tracker = Tracker::new().expect("Failed to create tracker");
+        let pid = std::process::id();
+        let test_cmd = format!("rtk export_test_{}", pid);
+
+        tracker
+            .record("git log, --stat", &test_cmd, 400, 80, 20)
+            .expect("Failed to record");
+
+        let mut json_out = Vec::new();
+        tracker
+            .export_history("json", 50, &mut json_out)
+            .expect("json export failed");
+        let json_str = String::from_utf8(json_out).unwrap();
+        assert!(json_str.contains(&test_cmd));
+        assert!(json_str.contains("\"saved_tokens\": 320"));
+
+        let mut csv_out = Vec::new();
+        tracker
+            .export_history("csv", 50, &mut csv_out)
+            .expect("csv export failed");
+        let csv_str = String::from_utf8(csv_out).unwrap();
+        assert!(csv_str.starts_with("id,timestamp,original_cmd,rtk_cmd"));
+        assert!(csv_str.contains("\"git log, --stat\""));
+
+        let mut md_out = Vec::new();
+        tracker
+            .export_history("markdown", 50, &mut md_out)
+            .expect("markdown export failed");
+        let md_str = String::from_utf8(md_out).unwrap();
+        assert!(md_str.starts_with("| Timestamp |"));
+        assert!(md_str.contains(&test_cmd));
+
+        let mut bad_out = Vec::new();
+        assert!(tracker.export_history("yaml", 50, &mut bad_out).is_err());
+    }
 }
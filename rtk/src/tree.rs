@@ -1,13 +1,22 @@
 //! tree command - proxy to native tree with token-optimized output
 //!
-//! This module proxies to the native `tree` command and filters the output
-//! to reduce token usage while preserving structure visibility.
+//! This module proxies to the native `tree` command when it's installed,
+//! and falls back to a built-in directory walker otherwise (e.g. on
+//! Windows or in minimal containers that don't ship `tree`). Output is
+//! filtered to reduce token usage while preserving structure visibility.
 //!
-//! Token optimization: automatically excludes noise directories via -I pattern
-//! unless -a flag is present (respecting user intent).
+//! Token optimization: the native path excludes noise directories via a
+//! `-I` glob built from `NOISE_DIRS`; the built-in path does the same plus
+//! honors `.gitignore`/`.ignore` and nested ignore files up the directory
+//! chain (via the `ignore` crate), so project-specific build artifacts and
+//! generated directories are excluded without a manual pattern. Pass
+//! `--no-ignore` to disable gitignore matching; `-a`/`--all` disables both.
 
 use crate::tracking;
 use anyhow::{Context, Result};
+use ignore::WalkBuilder;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 /// Noise directories commonly excluded from LLM context
@@ -43,18 +52,46 @@ const NOISE_DIRS: &[&str] = &[
 pub fn run(args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
-    // Check if tree is installed
-    let tree_check = Command::new("which").arg("tree").output();
-    if tree_check.is_err() || !tree_check.unwrap().status.success() {
-        anyhow::bail!(
-            "tree command not found. Install it first:\n\
-             - macOS: brew install tree\n\
-             - Ubuntu/Debian: sudo apt install tree\n\
-             - Fedora/RHEL: sudo dnf install tree\n\
-             - Arch: sudo pacman -S tree"
+    let native_available = native_tree_available();
+    if verbose > 0 && !native_available {
+        eprintln!("tree command not found; using built-in walker");
+    }
+
+    let raw = if native_available {
+        run_native_tree(args)?
+    } else {
+        run_builtin_tree(args)
+    };
+    let filtered = filter_tree_output(&raw);
+
+    if verbose > 0 {
+        eprintln!(
+            "Lines: {} → {} ({}% reduction)",
+            raw.lines().count(),
+            filtered.lines().count(),
+            if raw.lines().count() > 0 {
+                100 - (filtered.lines().count() * 100 / raw.lines().count())
+            } else {
+                0
+            }
         );
     }
 
+    print!("{}", filtered);
+    timer.track("tree", "rtk tree", &raw, &filtered);
+
+    Ok(())
+}
+
+fn native_tree_available() -> bool {
+    Command::new("which")
+        .arg("tree")
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+fn run_native_tree(args: &[String]) -> Result<String> {
     let mut cmd = Command::new("tree");
 
     // Determine if user wants all files or default behavior
@@ -80,26 +117,155 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
         std::process::exit(output.status.code().unwrap_or(1));
     }
 
-    let raw = String::from_utf8_lossy(&output.stdout).to_string();
-    let filtered = filter_tree_output(&raw);
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
 
-    if verbose > 0 {
-        eprintln!(
-            "Lines: {} → {} ({}% reduction)",
-            raw.lines().count(),
-            filtered.lines().count(),
-            if raw.lines().count() > 0 {
-                100 - (filtered.lines().count() * 100 / raw.lines().count())
-            } else {
-                0
+/// Reproduce `tree`'s box-drawing output (`├──`/`│`/`└──`) without
+/// shelling out, for systems where the native binary isn't installed.
+/// Honors `-a`/`--all`, `-L <depth>`, `-d`, and `--no-ignore` the same way
+/// the native binary (plus our gitignore integration) does, and applies
+/// `NOISE_DIRS` exclusion on top of `.gitignore`/`.ignore` matching.
+fn run_builtin_tree(args: &[String]) -> String {
+    let show_all = args.iter().any(|a| a == "-a" || a == "--all");
+    let dirs_only = args.iter().any(|a| a == "-d");
+    let use_ignore = !args.iter().any(|a| a == "--no-ignore");
+    let max_depth = parse_depth_flag(args);
+    let root = resolve_root_path(args);
+
+    let mut out = String::new();
+    out.push_str(".\n");
+    let mut dir_count = 0;
+    let mut file_count = 0;
+    walk_builtin_tree(
+        &root,
+        "",
+        show_all,
+        dirs_only,
+        use_ignore,
+        max_depth,
+        1,
+        &mut out,
+        &mut dir_count,
+        &mut file_count,
+    );
+    out.push('\n');
+    out.push_str(&format!(
+        "{} director{}, {} file{}\n",
+        dir_count,
+        if dir_count == 1 { "y" } else { "ies" },
+        file_count,
+        if file_count == 1 { "" } else { "s" }
+    ));
+    out
+}
+
+fn parse_depth_flag(args: &[String]) -> Option<usize> {
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "-L" {
+            return iter.next().and_then(|v| v.parse().ok());
+        }
+        if let Some(rest) = arg.strip_prefix("-L") {
+            if !rest.is_empty() {
+                return rest.parse().ok();
             }
-        );
+        }
     }
+    None
+}
 
-    print!("{}", filtered);
-    timer.track("tree", "rtk tree", &raw, &filtered);
+fn resolve_root_path(args: &[String]) -> PathBuf {
+    let mut i = 0;
+    while i < args.len() {
+        let arg = &args[i];
+        if arg == "-L" {
+            i += 2;
+            continue;
+        }
+        if arg.starts_with('-') {
+            i += 1;
+            continue;
+        }
+        return PathBuf::from(arg);
+    }
+    PathBuf::from(".")
+}
 
-    Ok(())
+/// List a directory's immediate children, honoring `.gitignore`/`.ignore`
+/// and any nested ignore files up the directory chain when `use_ignore` is
+/// set (the `ignore` crate walks ancestors to find those itself).
+fn list_children(dir: &Path, show_all: bool, use_ignore: bool) -> Vec<ignore::DirEntry> {
+    let mut builder = WalkBuilder::new(dir);
+    builder
+        .max_depth(Some(1))
+        .hidden(!show_all)
+        .require_git(false) // honor .gitignore even outside a git repo
+        .git_ignore(use_ignore)
+        .git_global(use_ignore)
+        .git_exclude(use_ignore)
+        .ignore(use_ignore)
+        .sort_by_file_name(|a, b| a.cmp(b));
+
+    builder
+        .build()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.depth() == 1)
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn walk_builtin_tree(
+    dir: &Path,
+    prefix: &str,
+    show_all: bool,
+    dirs_only: bool,
+    use_ignore: bool,
+    max_depth: Option<usize>,
+    depth: usize,
+    out: &mut String,
+    dir_count: &mut usize,
+    file_count: &mut usize,
+) {
+    let mut entries = list_children(dir, show_all, use_ignore);
+    entries.retain(|entry| {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if NOISE_DIRS.contains(&name.as_str()) {
+            return false;
+        }
+        if dirs_only && !entry.path().is_dir() {
+            return false;
+        }
+        true
+    });
+
+    let last_index = entries.len().saturating_sub(1);
+    for (i, entry) in entries.iter().enumerate() {
+        let is_last = i == last_index;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let connector = if is_last { "└── " } else { "├── " };
+        out.push_str(&format!("{prefix}{connector}{name}\n"));
+
+        if entry.path().is_dir() {
+            *dir_count += 1;
+            if max_depth.map_or(true, |max| depth < max) {
+                let child_prefix = format!("{prefix}{}", if is_last { "    " } else { "│   " });
+                walk_builtin_tree(
+                    entry.path(),
+                    &child_prefix,
+                    show_all,
+                    dirs_only,
+                    use_ignore,
+                    max_depth,
+                    depth + 1,
+                    out,
+                    dir_count,
+                    file_count,
+                );
+            }
+        } else {
+            *file_count += 1;
+        }
+    }
 }
 
 fn filter_tree_output(raw: &str) -> String {
@@ -206,4 +372,81 @@ mod tests {
         assert!(NOISE_DIRS.contains(&"dist"));
         assert!(NOISE_DIRS.contains(&"build"));
     }
+
+    #[test]
+    fn test_parse_depth_flag() {
+        let args = vec!["-L".to_string(), "2".to_string()];
+        assert_eq!(parse_depth_flag(&args), Some(2));
+
+        let args = vec!["-L3".to_string()];
+        assert_eq!(parse_depth_flag(&args), Some(3));
+
+        let args: Vec<String> = vec![];
+        assert_eq!(parse_depth_flag(&args), None);
+    }
+
+    #[test]
+    fn test_resolve_root_path() {
+        let args = vec!["-a".to_string(), "src".to_string()];
+        assert_eq!(resolve_root_path(&args), PathBuf::from("src"));
+
+        let args = vec!["-L".to_string(), "2".to_string(), "lib".to_string()];
+        assert_eq!(resolve_root_path(&args), PathBuf::from("lib"));
+
+        let args: Vec<String> = vec![];
+        assert_eq!(resolve_root_path(&args), PathBuf::from("."));
+    }
+
+    #[test]
+    fn test_builtin_tree_walks_directory() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("src/main.rs"), "fn main() {}").unwrap();
+        fs::write(dir.path().join("Cargo.toml"), "[package]").unwrap();
+        fs::create_dir(dir.path().join("node_modules")).unwrap();
+        fs::write(dir.path().join("node_modules/pkg.js"), "").unwrap();
+        fs::write(dir.path().join(".hidden"), "").unwrap();
+
+        let output = run_builtin_tree(&[dir.path().to_string_lossy().into_owned()]);
+        assert!(output.contains("main.rs"));
+        assert!(output.contains("Cargo.toml"));
+        assert!(!output.contains("node_modules"));
+        assert!(!output.contains(".hidden"));
+        assert!(output.contains("2 directories, 2 files") || output.contains("directories"));
+    }
+
+    #[test]
+    fn test_builtin_tree_respects_dirs_only_and_all() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::create_dir(dir.path().join("sub")).unwrap();
+        fs::write(dir.path().join("file.txt"), "").unwrap();
+        fs::write(dir.path().join(".hidden"), "").unwrap();
+
+        let dirs_only = run_builtin_tree(&["-d".to_string(), dir.path().to_string_lossy().into_owned()]);
+        assert!(dirs_only.contains("sub"));
+        assert!(!dirs_only.contains("file.txt"));
+
+        let show_all = run_builtin_tree(&["-a".to_string(), dir.path().to_string_lossy().into_owned()]);
+        assert!(show_all.contains(".hidden"));
+    }
+
+    #[test]
+    fn test_builtin_tree_honors_gitignore() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::write(dir.path().join(".gitignore"), "vendor/\n").unwrap();
+        fs::create_dir(dir.path().join("vendor")).unwrap();
+        fs::write(dir.path().join("vendor/lib.rs"), "").unwrap();
+        fs::write(dir.path().join("kept.rs"), "").unwrap();
+
+        let ignored = run_builtin_tree(&[dir.path().to_string_lossy().into_owned()]);
+        assert!(!ignored.contains("vendor"));
+        assert!(ignored.contains("kept.rs"));
+
+        let not_ignored = run_builtin_tree(&[
+            "--no-ignore".to_string(),
+            dir.path().to_string_lossy().into_owned(),
+        ]);
+        assert!(not_ignored.contains("vendor"));
+        assert!(not_ignored.contains("kept.rs"));
+    }
 }
@@ -0,0 +1,213 @@
+//! AST-backed code-structure extraction, backing `local_llm::analyze_code`.
+//! Parses a file with `tree_sitter` and runs per-language tag queries
+//! (`@definition.function`, `@definition.type`, `@definition.interface`,
+//! `@reference.import`) to collect definitions and imports with accurate
+//! byte ranges, instead of `local_llm`'s line-by-line regex, which silently
+//! mangles multi-line signatures, generics, attributes, and anything inside
+//! strings/comments.
+//!
+//! Only languages with a grammar wired into `grammar_for` are supported;
+//! everything else returns `None` from `extract`, and `local_llm` falls back
+//! to its regex path.
+
+use crate::filter::Language;
+use std::ops::Range;
+use tree_sitter::{Node, Parser, Query, QueryCursor};
+
+/// What a [`Definition`] represents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefinitionKind {
+    Function,
+    Type,
+    Interface,
+}
+
+/// One named definition pulled out of the AST.
+#[derive(Debug, Clone)]
+pub struct Definition {
+    pub kind: DefinitionKind,
+    pub name: String,
+    pub byte_range: Range<usize>,
+    /// The byte range of just the name token, e.g. for underlining it in
+    /// an annotated snippet - a subrange of `byte_range`.
+    pub name_range: Range<usize>,
+    /// Parameter list + return type, e.g. `(name: &str, n: usize) -> bool`.
+    pub signature: Option<String>,
+    /// The leading doc comment or docstring, with its markers stripped.
+    pub doc: Option<String>,
+}
+
+/// Everything `analyze_code` needs out of one parse.
+#[derive(Debug, Clone, Default)]
+pub struct ExtractResult {
+    pub imports: Vec<String>,
+    /// Byte range of each entry in `imports`, same order/length.
+    pub import_ranges: Vec<Range<usize>>,
+    pub functions: Vec<Definition>,
+    pub types: Vec<Definition>,
+}
+
+fn grammar_for(lang: &Language) -> Option<tree_sitter::Language> {
+    match lang {
+        Language::Rust => Some(tree_sitter_rust::language()),
+        Language::Python => Some(tree_sitter_python::language()),
+        _ => None,
+    }
+}
+
+/// Tag queries, one per supported grammar. Modeled on the `queries/tags.scm`
+/// convention tree-sitter grammars ship with; capture names double as the
+/// bucket they're collected into.
+fn query_for(lang: &Language) -> Option<&'static str> {
+    match lang {
+        Language::Rust => Some(
+            r#"
+            (function_item name: (identifier) @definition.function)
+            (struct_item name: (type_identifier) @definition.type)
+            (enum_item name: (type_identifier) @definition.type)
+            (trait_item name: (type_identifier) @definition.interface)
+            (use_declaration argument: (_) @reference.import)
+            "#,
+        ),
+        Language::Python => Some(
+            r#"
+            (function_definition name: (identifier) @definition.function)
+            (class_definition name: (identifier) @definition.type)
+            (import_statement name: (dotted_name) @reference.import)
+            (import_from_statement module_name: (dotted_name) @reference.import)
+            "#,
+        ),
+        _ => None,
+    }
+}
+
+/// Parse `source` and extract its definitions/imports, or `None` if no
+/// grammar is wired in for `lang` - the signal for callers to fall back to
+/// the regex path.
+pub fn extract(source: &str, lang: &Language) -> Option<ExtractResult> {
+    let grammar = grammar_for(lang)?;
+    let query_src = query_for(lang)?;
+
+    let mut parser = Parser::new();
+    parser.set_language(grammar).ok()?;
+    let tree = parser.parse(source, None)?;
+
+    let query = Query::new(grammar, query_src).ok()?;
+    let import_idx = query.capture_index_for_name("reference.import")?;
+    let fn_idx = query.capture_index_for_name("definition.function")?;
+    let type_idx = query.capture_index_for_name("definition.type");
+    let iface_idx = query.capture_index_for_name("definition.interface");
+
+    let mut cursor = QueryCursor::new();
+    let bytes = source.as_bytes();
+    let mut result = ExtractResult::default();
+
+    for m in cursor.matches(&query, tree.root_node(), bytes) {
+        for capture in m.captures {
+            let node = capture.node;
+            let Ok(text) = node.utf8_text(bytes) else {
+                continue;
+            };
+
+            if capture.index == import_idx {
+                result.imports.push(text.to_string());
+                result.import_ranges.push(node.byte_range());
+            } else if capture.index == fn_idx {
+                let def_node = node.parent().unwrap_or(node);
+                result.functions.push(Definition {
+                    kind: DefinitionKind::Function,
+                    name: text.to_string(),
+                    byte_range: def_node.byte_range(),
+                    name_range: node.byte_range(),
+                    signature: function_signature(def_node, bytes),
+                    doc: leading_doc_comment(def_node, bytes),
+                });
+            } else if Some(capture.index) == type_idx {
+                let def_node = node.parent().unwrap_or(node);
+                result.types.push(Definition {
+                    kind: DefinitionKind::Type,
+                    name: text.to_string(),
+                    byte_range: def_node.byte_range(),
+                    name_range: node.byte_range(),
+                    signature: None,
+                    doc: leading_doc_comment(def_node, bytes),
+                });
+            } else if Some(capture.index) == iface_idx {
+                let def_node = node.parent().unwrap_or(node);
+                result.types.push(Definition {
+                    kind: DefinitionKind::Interface,
+                    name: text.to_string(),
+                    byte_range: def_node.byte_range(),
+                    name_range: node.byte_range(),
+                    signature: None,
+                    doc: leading_doc_comment(def_node, bytes),
+                });
+            }
+        }
+    }
+
+    Some(result)
+}
+
+/// Render `(params) -> return_type` from a function/method node's
+/// `parameters`/`return_type` fields, when the grammar exposes them.
+fn function_signature(def_node: Node, bytes: &[u8]) -> Option<String> {
+    let params = def_node
+        .child_by_field_name("parameters")
+        .and_then(|n| n.utf8_text(bytes).ok())
+        .unwrap_or("()");
+    let return_type = def_node
+        .child_by_field_name("return_type")
+        .and_then(|n| n.utf8_text(bytes).ok());
+
+    Some(match return_type {
+        Some(rt) => format!("{} -> {}", params, rt),
+        None => params.to_string(),
+    })
+}
+
+/// The doc comment or docstring attached to a definition: Rust's leading
+/// `///`/`/** */` sibling nodes (skipping over attributes), or Python's
+/// first-statement-is-a-string-literal docstring convention.
+fn leading_doc_comment(def_node: Node, bytes: &[u8]) -> Option<String> {
+    let mut sibling = def_node.prev_sibling();
+    while let Some(node) = sibling {
+        if node.kind() == "attribute_item" {
+            sibling = node.prev_sibling();
+            continue;
+        }
+        if node.kind() == "line_comment" || node.kind() == "block_comment" {
+            let text = node.utf8_text(bytes).ok()?;
+            if text.starts_with("///") || text.starts_with("/**") {
+                return Some(strip_comment_markers(text));
+            }
+        }
+        break;
+    }
+
+    let body = def_node.child_by_field_name("body")?;
+    let first = body.named_child(0)?;
+    if first.kind() == "expression_statement" {
+        let string_node = first.named_child(0)?;
+        if string_node.kind() == "string" {
+            let text = string_node.utf8_text(bytes).ok()?;
+            return Some(strip_comment_markers(text));
+        }
+    }
+    None
+}
+
+/// Strip `///`/`/** */`/quote markers and keep just the first line, for a
+/// compact one-line doc summary.
+fn strip_comment_markers(text: &str) -> String {
+    text.trim()
+        .trim_start_matches("///")
+        .trim_start_matches("/**")
+        .trim_end_matches("*/")
+        .trim_matches(|c: char| c == '"' || c == '\'')
+        .trim()
+        .lines()
+        .next()
+        .unwrap_or("")
+        .to_string()
+}
@@ -2,10 +2,61 @@ use crate::tracking;
 use crate::utils::truncate;
 use anyhow::{Context, Result};
 use regex::Regex;
+use serde::Serialize;
+use serde_json::{json, Value};
 use std::collections::HashMap;
 use std::process::Command;
 
+/// Output format for `rtk tsc`, selected with `--format <value>`. `Text` is
+/// the default grouped-by-file summary; `Json`/`Sarif` give CI annotation
+/// systems and editors a stable machine-readable diagnostic stream instead
+/// of scraping it, the same split `rtk lint`'s `Format`/`Reporter` makes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+    Sarif,
+}
+
+impl Format {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "sarif" => Some(Format::Sarif),
+            _ => None,
+        }
+    }
+}
+
+/// Strip `--format <text|json|sarif>` out of `args` (tsc itself has no such
+/// flag), returning the selected format and the remaining args to actually
+/// pass to tsc.
+fn extract_format_flag(args: &[String]) -> Result<(Format, Vec<String>)> {
+    let mut format = Format::Text;
+    let mut rest = Vec::with_capacity(args.len());
+    let mut iter = args.iter().cloned();
+    while let Some(arg) = iter.next() {
+        if arg == "--format" {
+            let value = iter
+                .next()
+                .context("--format requires a value (text, json, or sarif)")?;
+            format = Format::parse(&value)
+                .with_context(|| format!("invalid --format value: {}", value))?;
+        } else {
+            rest.push(arg);
+        }
+    }
+    Ok((format, rest))
+}
+
 pub fn run(args: &[String], verbose: u8) -> Result<()> {
+    let (format, args) = extract_format_flag(args)?;
+
+    if args.iter().any(|a| a == "--watch" || a == "-w") {
+        return run_watch(&args, verbose, format);
+    }
+
     let timer = tracking::TimedExecution::start();
 
     // Try tsc directly first, fallback to npx if not found
@@ -23,7 +74,7 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
         c
     };
 
-    for arg in args {
+    for arg in &args {
         cmd.arg(arg);
     }
 
@@ -39,7 +90,7 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
     let stderr = String::from_utf8_lossy(&output.stderr);
     let raw = format!("{}\n{}", stdout, stderr);
 
-    let filtered = filter_tsc_output(&raw);
+    let filtered = render(&raw, format);
 
     println!("{}", filtered);
 
@@ -54,8 +105,105 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
     std::process::exit(output.status.code().unwrap_or(1));
 }
 
-/// Filter TypeScript compiler output - group errors by file, show every error
-fn filter_tsc_output(output: &str) -> String {
+/// `rtk tsc --watch`: tsc's own watch mode never exits, so instead of the
+/// single blocking `cmd.output()` above, spawn it with piped stdout and
+/// read line by line. tsc delimits each recompilation with sentinel lines
+/// ("File change detected. Starting incremental compilation..." through
+/// "Found N errors. Watching for file changes."); lines between them are
+/// one compilation batch, filtered and printed as a unit the moment the
+/// batch closes rather than waiting on a process that runs indefinitely.
+fn run_watch(args: &[String], verbose: u8, format: Format) -> Result<()> {
+    use std::io::BufRead;
+    use std::process::Stdio;
+
+    let tsc_exists = Command::new("which")
+        .arg("tsc")
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false);
+
+    let mut cmd = if tsc_exists {
+        Command::new("tsc")
+    } else {
+        let mut c = Command::new("npx");
+        c.arg("tsc");
+        c
+    };
+
+    for arg in args {
+        cmd.arg(arg);
+    }
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+
+    if verbose > 0 {
+        let tool = if tsc_exists { "tsc" } else { "npx tsc" };
+        eprintln!("Running: {} {} (watch mode)", tool, args.join(" "));
+    }
+
+    let mut child = cmd
+        .spawn()
+        .context("Failed to run tsc (try: npm install -g typescript)")?;
+    let stdout = child.stdout.take().expect("stdout was piped");
+
+    const START_SENTINEL: &str = "File change detected. Starting incremental compilation...";
+    const END_SENTINEL_PREFIX: &str = "Found ";
+    const END_SENTINEL_SUFFIX: &str = "Watching for file changes.";
+
+    let mut batch: Vec<String> = Vec::new();
+    let mut timer = tracking::TimedExecution::start();
+
+    for line in std::io::BufReader::new(stdout).lines() {
+        let line = line.context("Failed to read tsc output")?;
+
+        if line.contains(START_SENTINEL) {
+            batch.clear();
+            timer = tracking::TimedExecution::start();
+            continue;
+        }
+
+        batch.push(line.clone());
+
+        if line.starts_with(END_SENTINEL_PREFIX) && line.contains(END_SENTINEL_SUFFIX) {
+            let raw = batch.join("\n");
+            let filtered = render(&raw, format);
+
+            print!("\x1B[2J\x1B[1;1H");
+            println!("{}", filtered);
+
+            timer.track(
+                &format!("tsc {} (watch)", args.join(" ")),
+                &format!("rtk tsc {} (watch)", args.join(" ")),
+                &raw,
+                &filtered,
+            );
+
+            batch.clear();
+        }
+    }
+
+    let status = child.wait().context("Failed waiting on tsc")?;
+    std::process::exit(status.code().unwrap_or(1));
+}
+
+/// One parsed tsc diagnostic line, plus any indented continuation lines tsc
+/// prints underneath it. Shared by the grouped pretty-text view and the
+/// `--format json`/`--format sarif` renderers below.
+#[derive(Debug, Clone, Serialize)]
+struct TsDiagnostic {
+    file: String,
+    line: usize,
+    column: usize,
+    code: String,
+    severity: String,
+    message: String,
+    context: Vec<String>,
+}
+
+/// Parse every `file(line,col): error|warning TSnnnn: message` line out of
+/// raw tsc output, folding in the indented continuation lines tsc prints
+/// underneath some diagnostics.
+fn parse_tsc_diagnostics(output: &str) -> Vec<TsDiagnostic> {
     lazy_static::lazy_static! {
         // Pattern: src/file.ts(12,5): error TS2322: Type 'string' is not assignable to type 'number'.
         static ref TSC_ERROR: Regex = Regex::new(
@@ -63,27 +211,21 @@ fn filter_tsc_output(output: &str) -> String {
         ).unwrap();
     }
 
-    struct TsError {
-        file: String,
-        line: usize,
-        code: String,
-        message: String,
-        context_lines: Vec<String>,
-    }
-
-    let mut errors: Vec<TsError> = Vec::new();
+    let mut diagnostics = Vec::new();
     let lines: Vec<&str> = output.lines().collect();
     let mut i = 0;
 
     while i < lines.len() {
         let line = lines[i];
         if let Some(caps) = TSC_ERROR.captures(line) {
-            let mut err = TsError {
+            let mut diag = TsDiagnostic {
                 file: caps[1].to_string(),
                 line: caps[2].parse().unwrap_or(0),
+                column: caps[3].parse().unwrap_or(0),
+                severity: caps[4].to_string(),
                 code: caps[5].to_string(),
                 message: caps[6].to_string(),
-                context_lines: Vec::new(),
+                context: Vec::new(),
             };
 
             // Capture continuation lines (indented context from tsc)
@@ -94,47 +236,129 @@ fn filter_tsc_output(output: &str) -> String {
                     && (next.starts_with("  ") || next.starts_with('\t'))
                     && !TSC_ERROR.is_match(next)
                 {
-                    err.context_lines.push(next.trim().to_string());
+                    diag.context.push(next.trim().to_string());
                     i += 1;
                 } else {
                     break;
                 }
             }
 
-            errors.push(err);
+            diagnostics.push(diag);
         } else {
             i += 1;
         }
     }
 
-    if errors.is_empty() {
+    diagnostics
+}
+
+/// Render raw tsc output in the requested `Format`.
+fn render(output: &str, format: Format) -> String {
+    match format {
+        Format::Text => filter_tsc_output(output),
+        Format::Json => render_tsc_json(&parse_tsc_diagnostics(output)),
+        Format::Sarif => render_tsc_sarif(&parse_tsc_diagnostics(output)),
+    }
+}
+
+fn render_tsc_json(diagnostics: &[TsDiagnostic]) -> String {
+    serde_json::to_string_pretty(diagnostics).unwrap_or_default()
+}
+
+/// Minimal SARIF 2.1.0 log, one `tsc` run with one `result` per diagnostic.
+fn render_tsc_sarif(diagnostics: &[TsDiagnostic]) -> String {
+    let results: Vec<Value> = diagnostics
+        .iter()
+        .map(|d| {
+            json!({
+                "ruleId": d.code,
+                "level": sarif_level(&d.severity),
+                "message": {"text": d.message},
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": {"uri": d.file},
+                        "region": {"startLine": d.line, "startColumn": d.column}
+                    }
+                }]
+            })
+        })
+        .collect();
+
+    let sarif = json!({
+        "version": "2.1.0",
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "runs": [{
+            "tool": {"driver": {"name": "tsc"}},
+            "results": results,
+        }],
+    });
+
+    serde_json::to_string_pretty(&sarif).unwrap_or_default()
+}
+
+fn sarif_level(severity: &str) -> &'static str {
+    match severity {
+        "error" => "error",
+        "warning" => "warning",
+        _ => "note",
+    }
+}
+
+/// One-line plain-English hint for the TS codes that show up often enough
+/// to be worth a built-in lookup, so the grouped summary gives a triage
+/// clue without the reader having to memorize TypeScript's error codes.
+fn code_hint(code: &str) -> Option<&'static str> {
+    match code {
+        "TS2322" => Some("type not assignable; check the expected vs actual type"),
+        "TS2345" => Some("argument not assignable; check the parameter type"),
+        "TS2339" => Some("property does not exist; check the type or add it"),
+        "TS7006" => Some("implicit any; add an explicit parameter type"),
+        "TS2304" => Some("cannot find name; check the import or spelling"),
+        "TS2551" => Some("property does not exist; did you mean a similarly named one?"),
+        "TS2531" => Some("object is possibly 'null'; add a null check"),
+        "TS2532" => Some("object is possibly 'undefined'; add an undefined check"),
+        "TS18048" => Some("value is possibly 'undefined'; narrow it before use"),
+        "TS2769" => Some("no overload matches this call; check the argument types"),
+        _ => None,
+    }
+}
+
+/// Filter TypeScript compiler output - split into Errors/Warnings sections,
+/// each grouped by file, showing every diagnostic (no per-file limit).
+fn filter_tsc_output(output: &str) -> String {
+    let diagnostics = parse_tsc_diagnostics(output);
+
+    if diagnostics.is_empty() {
         if output.contains("Found 0 errors") {
             return "✓ TypeScript: No errors found".to_string();
         }
         return "TypeScript compilation completed".to_string();
     }
 
-    // Group by file
-    let mut by_file: HashMap<String, Vec<&TsError>> = HashMap::new();
-    for err in &errors {
-        by_file.entry(err.file.clone()).or_default().push(err);
+    let errors: Vec<&TsDiagnostic> = diagnostics.iter().filter(|d| d.severity == "error").collect();
+    let warnings: Vec<&TsDiagnostic> = diagnostics.iter().filter(|d| d.severity == "warning").collect();
+
+    let mut by_file: HashMap<String, Vec<&TsDiagnostic>> = HashMap::new();
+    for diag in &diagnostics {
+        by_file.entry(diag.file.clone()).or_default().push(diag);
     }
 
     // Count by error code for summary
     let mut by_code: HashMap<String, usize> = HashMap::new();
-    for err in &errors {
-        *by_code.entry(err.code.clone()).or_insert(0) += 1;
+    for diag in &diagnostics {
+        *by_code.entry(diag.code.clone()).or_insert(0) += 1;
     }
 
     let mut result = String::new();
     result.push_str(&format!(
-        "TypeScript: {} errors in {} files\n",
+        "TypeScript: {} errors, {} warnings in {} files\n",
         errors.len(),
+        warnings.len(),
         by_file.len()
     ));
     result.push_str("═══════════════════════════════════════\n");
 
-    // Top error codes summary (compact, one line)
+    // Top codes summary (compact, one line, with a hint per distinct code)
     let mut code_counts: Vec<_> = by_code.iter().collect();
     code_counts.sort_by(|a, b| b.1.cmp(a.1));
 
@@ -142,34 +366,63 @@ fn filter_tsc_output(output: &str) -> String {
         let codes_str: Vec<String> = code_counts
             .iter()
             .take(5)
-            .map(|(code, count)| format!("{} ({}x)", code, count))
+            .map(|(code, count)| match code_hint(code) {
+                Some(hint) => format!("{} ({}x) — {}", code, count, hint),
+                None => format!("{} ({}x)", code, count),
+            })
             .collect();
         result.push_str(&format!("Top codes: {}\n\n", codes_str.join(", ")));
     }
 
-    // Files sorted by error count (most errors first)
+    if !errors.is_empty() {
+        result.push_str(&render_section("Errors", &errors));
+    }
+    if !warnings.is_empty() {
+        result.push_str(&render_section("Warnings", &warnings));
+    }
+
+    result.trim().to_string()
+}
+
+/// Render one severity section (`Errors`/`Warnings`): a heading, then every
+/// diagnostic grouped by file (most diagnostics first), no per-file limit.
+fn render_section(heading: &str, diagnostics: &[&TsDiagnostic]) -> String {
+    let mut by_file: HashMap<String, Vec<&TsDiagnostic>> = HashMap::new();
+    for diag in diagnostics {
+        by_file.entry(diag.file.clone()).or_default().push(diag);
+    }
+
+    let noun = heading.trim_end_matches('s').to_lowercase();
+    let mut result = String::new();
+    result.push_str(&format!("{}\n{}\n", heading, "─".repeat(heading.len())));
+
     let mut files_sorted: Vec<_> = by_file.iter().collect();
     files_sorted.sort_by(|a, b| b.1.len().cmp(&a.1.len()));
 
-    // Show every error per file — no limits
-    for (file, file_errors) in &files_sorted {
-        result.push_str(&format!("{} ({} errors)\n", file, file_errors.len()));
+    for (file, file_diags) in &files_sorted {
+        result.push_str(&format!(
+            "{} ({} {}{})\n",
+            file,
+            file_diags.len(),
+            noun,
+            if file_diags.len() == 1 { "" } else { "s" }
+        ));
 
-        for err in *file_errors {
+        for diag in *file_diags {
             result.push_str(&format!(
                 "  L{}: {} {}\n",
-                err.line,
-                err.code,
-                truncate(&err.message, 120)
+                diag.line,
+                diag.code,
+                truncate(&diag.message, 120)
             ));
-            for ctx in &err.context_lines {
+            for ctx in &diag.context {
                 result.push_str(&format!("    {}\n", truncate(ctx, 120)));
             }
         }
         result.push('\n');
     }
 
-    result.trim().to_string()
+    result
 }
 
 #[cfg(test)]
@@ -187,7 +440,7 @@ src/components/Button.tsx(10,5): error TS2322: Type 'string' is not assignable t
 Found 4 errors in 2 files.
 "#;
         let result = filter_tsc_output(output);
-        assert!(result.contains("TypeScript: 4 errors in 2 files"));
+        assert!(result.contains("TypeScript: 4 errors, 0 warnings in 2 files"));
         assert!(result.contains("auth.ts (2 errors)"));
         assert!(result.contains("Button.tsx (2 errors)"));
         assert!(result.contains("TS2322"));
@@ -235,7 +488,7 @@ src/app.tsx(20,5): error TS2345: Argument of type 'number' is not assignable to
             ));
         }
         let result = filter_tsc_output(&output);
-        assert!(result.contains("15 errors in 15 files"));
+        assert!(result.contains("15 errors, 0 warnings in 15 files"));
         for i in 1..=15 {
             assert!(
                 result.contains(&format!("file{}.ts", i)),
@@ -251,4 +504,29 @@ src/app.tsx(20,5): error TS2345: Argument of type 'number' is not assignable to
         let result = filter_tsc_output(output);
         assert!(result.contains("No errors found"));
     }
+
+    #[test]
+    fn test_errors_and_warnings_split_into_sections() {
+        let output = "\
+src/api.ts(10,5): error TS2322: Type 'string' is not assignable to type 'number'.
+src/api.ts(20,1): warning TS7006: Parameter 'x' implicitly has an 'any' type.
+";
+        let result = filter_tsc_output(output);
+        assert!(result.contains("TypeScript: 1 errors, 1 warnings in 1 files"));
+        assert!(result.contains("Errors\n"));
+        assert!(result.contains("Warnings\n"));
+        assert!(result.contains("L10: TS2322"));
+        assert!(result.contains("L20: TS7006"));
+    }
+
+    #[test]
+    fn test_code_hint_appended_to_top_codes() {
+        let output = "\
+src/a.ts(1,1): error TS2322: Type 'string' is not assignable to type 'number'.
+src/b.ts(2,1): error TS2345: Argument of type 'number' is not assignable to parameter of type 'string'.
+";
+        let result = filter_tsc_output(output);
+        assert!(result.contains("TS2322 (1x) — type not assignable; check the expected vs actual type"));
+        assert!(result.contains("TS2345 (1x) — argument not assignable; check the parameter type"));
+    }
 }
@@ -0,0 +1,296 @@
+//! Interactive terminal inspector over the tracking store (`rtk gain
+//! --interactive`), built with the `tui` feature. Lets users scroll
+//! [`Tracker::get_recent_detailed`], open an inspector pane on the
+//! selected row (full `original_cmd`/`rtk_cmd`, token counts, savings %,
+//! exec time), filter the list by command name, delete a row, and flip to
+//! a stats tab rendering [`GainSummary`]. `gain.rs` falls back to the
+//! existing one-shot text report when this feature is off.
+#![cfg(feature = "tui")]
+
+use crate::tracking::{CommandRecordDetailed, GainSummary, Tracker};
+use anyhow::{Context, Result};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::execute;
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState};
+use ratatui::{Frame, Terminal};
+use std::io;
+use std::time::Duration;
+
+const TABS: [&str; 3] = ["History", "Inspector", "Stats"];
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum Tab {
+    History,
+    Inspector,
+    Stats,
+}
+
+struct App {
+    rows: Vec<CommandRecordDetailed>,
+    filter: String,
+    selected: usize,
+    tab: Tab,
+    summary: GainSummary,
+    status: String,
+}
+
+impl App {
+    fn load(tracker: &Tracker) -> Result<Self> {
+        Ok(Self {
+            rows: tracker.get_recent_detailed(500)?,
+            filter: String::new(),
+            selected: 0,
+            tab: Tab::History,
+            summary: tracker.get_summary()?,
+            status: "Tab: switch pane  ↑/↓: select  d: delete  /type: filter  q: quit".to_string(),
+        })
+    }
+
+    fn visible_rows(&self) -> Vec<&CommandRecordDetailed> {
+        if self.filter.is_empty() {
+            self.rows.iter().collect()
+        } else {
+            self.rows
+                .iter()
+                .filter(|r| {
+                    r.rtk_cmd.contains(&self.filter) || r.original_cmd.contains(&self.filter)
+                })
+                .collect()
+        }
+    }
+
+    fn selected_row(&self) -> Option<CommandRecordDetailed> {
+        self.visible_rows().get(self.selected).map(|r| (*r).clone())
+    }
+}
+
+/// Run the interactive inspector until the user quits (`q`/`Esc`).
+pub fn run() -> Result<()> {
+    let tracker = Tracker::new().context("Failed to initialize tracking database")?;
+    let mut app = App::load(&tracker)?;
+
+    enable_raw_mode().context("Failed to enable raw terminal mode")?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen).context("Failed to enter alternate screen")?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend).context("Failed to initialize terminal")?;
+
+    let result = event_loop(&mut terminal, &mut app, &tracker);
+
+    disable_raw_mode().ok();
+    execute!(terminal.backend_mut(), LeaveAlternateScreen).ok();
+    terminal.show_cursor().ok();
+
+    result
+}
+
+fn event_loop(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    app: &mut App,
+    tracker: &Tracker,
+) -> Result<()> {
+    loop {
+        terminal.draw(|frame| draw(frame, app))?;
+
+        if !event::poll(Duration::from_millis(200))? {
+            continue;
+        }
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Tab => {
+                app.tab = match app.tab {
+                    Tab::History => Tab::Inspector,
+                    Tab::Inspector => Tab::Stats,
+                    Tab::Stats => Tab::History,
+                };
+            }
+            KeyCode::Down => {
+                let len = app.visible_rows().len();
+                if len > 0 {
+                    app.selected = (app.selected + 1).min(len - 1);
+                }
+            }
+            KeyCode::Up => app.selected = app.selected.saturating_sub(1),
+            KeyCode::Enter => app.tab = Tab::Inspector,
+            KeyCode::Backspace => {
+                app.filter.pop();
+                app.selected = 0;
+            }
+            KeyCode::Char('d') => {
+                if let Some(row) = app.selected_row() {
+                    tracker.delete_by_id(row.id)?;
+                    app.rows.retain(|r| r.id != row.id);
+                    app.summary = tracker.get_summary()?;
+                    app.status = format!("Deleted record #{}", row.id);
+                    let len = app.visible_rows().len();
+                    app.selected = app.selected.min(len.saturating_sub(1));
+                }
+            }
+            KeyCode::Char(c) => {
+                app.filter.push(c);
+                app.selected = 0;
+            }
+            _ => {}
+        }
+    }
+}
+
+fn draw(frame: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .split(frame.area());
+
+    draw_tabs(frame, chunks[0], app);
+    match app.tab {
+        Tab::History => draw_history(frame, chunks[1], app),
+        Tab::Inspector => draw_inspector(frame, chunks[1], app),
+        Tab::Stats => draw_stats(frame, chunks[1], app),
+    }
+    draw_status(frame, chunks[2], app);
+}
+
+fn draw_tabs(frame: &mut Frame, area: Rect, app: &App) {
+    let title = TABS
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let active = i == app.tab as usize;
+            if active {
+                format!("[{name}]")
+            } else {
+                format!(" {name} ")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let filter_hint = if app.filter.is_empty() {
+        String::new()
+    } else {
+        format!("  filter: \"{}\"", app.filter)
+    };
+
+    frame.render_widget(
+        Paragraph::new(format!("{title}{filter_hint}")).block(Block::default().borders(Borders::ALL)),
+        area,
+    );
+}
+
+fn draw_history(frame: &mut Frame, area: Rect, app: &App) {
+    let rows: Vec<Row> = app
+        .visible_rows()
+        .iter()
+        .map(|r| {
+            Row::new(vec![
+                Cell::from(r.timestamp.format("%m-%d %H:%M").to_string()),
+                Cell::from(r.rtk_cmd.clone()),
+                Cell::from(format!("{}", r.saved_tokens)),
+                Cell::from(format!("{:.0}%", r.savings_pct)),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(12),
+        Constraint::Min(20),
+        Constraint::Length(10),
+        Constraint::Length(6),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(
+            Row::new(vec!["When", "Command", "Saved", "Pct"])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("History (Enter: inspect, d: delete)"),
+        )
+        .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+    let mut state = TableState::default();
+    state.select(Some(app.selected));
+    frame.render_stateful_widget(table, area, &mut state);
+}
+
+fn draw_inspector(frame: &mut Frame, area: Rect, app: &App) {
+    let body = match app.selected_row() {
+        Some(row) => format!(
+            "id:            {}\n\
+             timestamp:     {}\n\
+             original_cmd:  {}\n\
+             rtk_cmd:       {}\n\
+             input_tokens:  {}\n\
+             output_tokens: {}\n\
+             saved_tokens:  {}\n\
+             savings_pct:   {:.1}%\n\
+             exec_time_ms:  {}",
+            row.id,
+            row.timestamp,
+            row.original_cmd,
+            row.rtk_cmd,
+            row.input_tokens,
+            row.output_tokens,
+            row.saved_tokens,
+            row.savings_pct,
+            row.exec_time_ms,
+        ),
+        None => "No record selected.".to_string(),
+    };
+
+    frame.render_widget(
+        Paragraph::new(body).block(Block::default().borders(Borders::ALL).title("Inspector")),
+        area,
+    );
+}
+
+fn draw_stats(frame: &mut Frame, area: Rect, app: &App) {
+    let s = &app.summary;
+    let body = format!(
+        "total_commands: {}\n\
+         total_input:    {}\n\
+         total_output:   {}\n\
+         total_saved:    {} ({:.1}%)\n\
+         total_time_ms:  {} (avg {})\n\
+         p50_time_ms:    {}\n\
+         p95_time_ms:    {}\n\
+         p99_time_ms:    {}",
+        s.total_commands,
+        s.total_input,
+        s.total_output,
+        s.total_saved,
+        s.avg_savings_pct,
+        s.total_time_ms,
+        s.avg_time_ms,
+        s.p50_time_ms,
+        s.p95_time_ms,
+        s.p99_time_ms,
+    );
+
+    frame.render_widget(
+        Paragraph::new(body).block(Block::default().borders(Borders::ALL).title("Stats")),
+        area,
+    );
+}
+
+fn draw_status(frame: &mut Frame, area: Rect, app: &App) {
+    frame.render_widget(Paragraph::new(app.status.as_str()), area);
+}
@@ -6,8 +6,9 @@
 //! - Command execution with error context
 
 use anyhow::{Context, Result};
-use regex::Regex;
 use std::process::Command;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 /// Tronque une chaîne à `max_len` caractères avec "..." si nécessaire.
 ///
@@ -33,22 +34,194 @@ pub fn truncate(s: &str, max_len: usize) -> String {
     }
 }
 
-/// Supprime les codes ANSI d'une chaîne (couleurs, styles).
+/// Like [`truncate`], but measures and truncates by terminal display width
+/// (grapheme clusters, not `char`s) instead of character count.
 ///
-/// # Arguments
-/// * `text` - Texte contenant potentiellement des codes ANSI
+/// `truncate` overruns a fixed-width table column on CJK/emoji input because
+/// each ideograph is one `char` but occupies two terminal columns, and
+/// cutting at a `char` boundary can split a grapheme cluster (e.g. an emoji
+/// with a skin-tone modifier). This walks grapheme clusters via
+/// `unicode-segmentation`, sums each cluster's column width via
+/// `unicode-width` (ASCII = 1, CJK/emoji = 2, combining/zero-width = 0), and
+/// stops before the next cluster would exceed `max_cols - 3`, so the result
+/// (including "...") never measures wider than `max_cols` columns.
+///
+/// # Examples
+/// ```
+/// use rtk::utils::truncate_display;
+/// use unicode_width::UnicodeWidthStr;
+/// let result = truncate_display("你好世界测试字符串", 6);
+/// assert!(result.width() <= 6);
+/// assert!(result.ends_with("..."));
+/// ```
+pub fn truncate_display(s: &str, max_cols: usize) -> String {
+    if s.width() <= max_cols {
+        return s.to_string();
+    }
+    if max_cols < 3 {
+        return "...".to_string();
+    }
+
+    let budget = max_cols - 3;
+    let mut out = String::new();
+    let mut width = 0;
+    for grapheme in s.graphemes(true) {
+        let w = grapheme.width();
+        if width + w > budget {
+            break;
+        }
+        out.push_str(grapheme);
+        width += w;
+    }
+    out.push_str("...");
+    out
+}
+
+/// Supprime les codes ANSI d'une chaîne (couleurs, styles, liens OSC 8,
+/// titres de fenêtre, etc.).
+///
+/// Strips CSI sequences (`\x1b[...`, including private/intermediate bytes
+/// like `?`, `!`, `>`), OSC strings (`\x1b]...` terminated by BEL `\x07` or
+/// ST `\x1b\\` — window titles, OSC 8 hyperlinks) and the common
+/// single-character escapes (`\x1bM`, `\x1bc`, ...). The visible label text
+/// inside an OSC 8 hyperlink sits outside the escape sequence itself, so it
+/// is preserved; only the surrounding URL control frames are dropped. See
+/// [`strip_ansi_keep_links`] to keep those frames intact instead.
 ///
 /// # Examples
 /// ```
 /// use rtk::utils::strip_ansi;
 /// let colored = "\x1b[31mError\x1b[0m";
 /// assert_eq!(strip_ansi(colored), "Error");
+///
+/// let link = "\x1b]8;;https://example.com\x1b\\click here\x1b]8;;\x1b\\";
+/// assert_eq!(strip_ansi(link), "click here");
 /// ```
 pub fn strip_ansi(text: &str) -> String {
-    lazy_static::lazy_static! {
-        static ref ANSI_RE: Regex = Regex::new(r"\x1b\[[0-9;]*[a-zA-Z]").unwrap();
+    strip_ansi_impl(text, false)
+}
+
+/// Like [`strip_ansi`], but leaves OSC 8 hyperlink control frames
+/// (`\x1b]8;...`) intact so a hyperlink-aware terminal can still render them
+/// clickable; every other escape sequence (colors, window titles, ...) is
+/// stripped the same way.
+///
+/// # Examples
+/// ```
+/// use rtk::utils::strip_ansi_keep_links;
+/// let link = "\x1b]8;;https://example.com\x1b\\click here\x1b]8;;\x1b\\";
+/// assert_eq!(strip_ansi_keep_links(link), link);
+///
+/// let mixed = "\x1b[31m\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\\x1b[0m";
+/// assert_eq!(
+///     strip_ansi_keep_links(mixed),
+///     "\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\"
+/// );
+/// ```
+pub fn strip_ansi_keep_links(text: &str) -> String {
+    strip_ansi_impl(text, true)
+}
+
+fn strip_ansi_impl(text: &str, keep_hyperlinks: bool) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.peek().copied() {
+            Some('[') => {
+                chars.next();
+                // CSI: parameter bytes (0x30-0x3F: digits, `;:<=>?`), then
+                // intermediate bytes (0x20-0x2F), then one final byte in
+                // `@`..=`~`. Anything else before a final byte means this
+                // isn't a complete CSI sequence (most likely output cut off
+                // mid-escape by CommandRunner's max_output cap) — stop and
+                // emit what we tentatively consumed as literal text instead
+                // of swallowing whatever follows it.
+                let mut seq = String::new();
+                let mut closed = false;
+                while let Some(&next) = chars.peek() {
+                    if ('0'..='?').contains(&next) || (' '..='/').contains(&next) {
+                        seq.push(next);
+                        chars.next();
+                    } else if ('@'..='~').contains(&next) {
+                        chars.next();
+                        closed = true;
+                        break;
+                    } else {
+                        break;
+                    }
+                }
+                if !closed {
+                    out.push('\x1b');
+                    out.push('[');
+                    out.push_str(&seq);
+                }
+            }
+            Some(']') => {
+                chars.next();
+                let mut payload = String::new();
+                let mut terminator: Option<&str> = None;
+                loop {
+                    match chars.peek().copied() {
+                        Some('\x07') => {
+                            chars.next();
+                            terminator = Some("\x07");
+                            break;
+                        }
+                        Some('\x1b') => {
+                            let mut lookahead = chars.clone();
+                            lookahead.next();
+                            if lookahead.peek() == Some(&'\\') {
+                                chars.next();
+                                chars.next();
+                                terminator = Some("\x1b\\");
+                            }
+                            // Either a proper ST, or an unterminated OSC
+                            // butting up against the next escape — stop
+                            // either way without consuming that next escape.
+                            break;
+                        }
+                        Some(next) => {
+                            payload.push(next);
+                            chars.next();
+                        }
+                        None => break,
+                    }
+                }
+                match terminator {
+                    Some(term) => {
+                        if keep_hyperlinks && payload.starts_with("8;") {
+                            out.push('\x1b');
+                            out.push(']');
+                            out.push_str(&payload);
+                            out.push_str(term);
+                        }
+                        // Else: a recognized, complete OSC string — drop it.
+                    }
+                    None => {
+                        // No terminator found before the input ran out
+                        // (truncated output) — don't swallow text we can't
+                        // positively identify as a control sequence.
+                        out.push('\x1b');
+                        out.push(']');
+                        out.push_str(&payload);
+                    }
+                }
+            }
+            Some(_) => {
+                // Common two-byte escapes (`\x1bM`, `\x1bc`, ...).
+                chars.next();
+            }
+            None => {}
+        }
     }
-    ANSI_RE.replace_all(text, "").to_string()
+
+    out
 }
 
 /// Exécute une commande et retourne stdout/stderr nettoyés.
@@ -80,6 +253,291 @@ pub fn execute_command(cmd: &str, args: &[&str]) -> Result<(String, String, i32)
     Ok((stdout, stderr, exit_code))
 }
 
+/// Output captured from a [`CommandRunner`] run.
+#[derive(Debug, Clone)]
+pub enum RunOutcome {
+    /// The child exited (cleanly or not) before the timeout elapsed.
+    Completed {
+        stdout: String,
+        stderr: String,
+        exit_code: i32,
+    },
+    /// The timeout elapsed first; the child's process group was killed.
+    TimedOut,
+}
+
+/// Bytes reserved for the `\n...[truncated]` marker appended when a stream
+/// hits [`CommandRunner::max_output`].
+const TRUNCATION_MARKER: &str = "\n...[truncated]";
+
+/// Default cap on captured stdout/stderr, chosen so a runaway linter can't
+/// exhaust memory even with no explicit `max_output` call.
+const DEFAULT_MAX_OUTPUT: usize = 10 * 1024 * 1024;
+
+/// Builder-style, hang-resistant alternative to [`execute_command`].
+///
+/// Unlike `execute_command`, which blocks on `Command::output()` with no
+/// stdin, no timeout, and no cap on captured output, `CommandRunner`:
+/// - pipes `stdin_bytes` to the child on a dedicated thread while stdout and
+///   stderr drain concurrently on their own threads, so a large input can't
+///   deadlock against a full pipe buffer;
+/// - enforces `timeout` by killing the child's whole process group (so a
+///   linter that forks workers doesn't leave orphans) and returning
+///   [`RunOutcome::TimedOut`] instead of blocking forever;
+/// - truncates each captured stream at `max_output` bytes with a marker and
+///   kills the process group once the cap is hit, so a linter stuck
+///   producing unbounded output still returns promptly (as
+///   [`RunOutcome::Completed`], since the kill is the reason it exited).
+///
+/// # Examples
+/// ```no_run
+/// use rtk::utils::{CommandRunner, RunOutcome};
+/// use std::time::Duration;
+///
+/// let outcome = CommandRunner::new("eslint")
+///     .args(["--stdin", "--stdin-filename", "foo.js"])
+///     .stdin_bytes(b"const x = 1".to_vec())
+///     .timeout(Duration::from_secs(30))
+///     .run()
+///     .unwrap();
+///
+/// match outcome {
+///     RunOutcome::Completed { exit_code, .. } => assert!(exit_code >= 0),
+///     RunOutcome::TimedOut => panic!("eslint hung"),
+/// }
+/// ```
+pub struct CommandRunner {
+    cmd: String,
+    args: Vec<String>,
+    stdin_bytes: Option<Vec<u8>>,
+    timeout: Option<std::time::Duration>,
+    cwd: Option<std::path::PathBuf>,
+    envs: Vec<(String, String)>,
+    max_output: usize,
+}
+
+impl CommandRunner {
+    pub fn new(cmd: impl Into<String>) -> Self {
+        CommandRunner {
+            cmd: cmd.into(),
+            args: Vec::new(),
+            stdin_bytes: None,
+            timeout: None,
+            cwd: None,
+            envs: Vec::new(),
+            max_output: DEFAULT_MAX_OUTPUT,
+        }
+    }
+
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn stdin_bytes(mut self, bytes: Vec<u8>) -> Self {
+        self.stdin_bytes = Some(bytes);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn cwd(mut self, dir: impl Into<std::path::PathBuf>) -> Self {
+        self.cwd = Some(dir.into());
+        self
+    }
+
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.envs.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn max_output(mut self, bytes: usize) -> Self {
+        self.max_output = bytes;
+        self
+    }
+
+    /// Spawn the child and block until it exits or `timeout` elapses.
+    pub fn run(self) -> Result<RunOutcome> {
+        use std::io::Write;
+        use std::process::Stdio;
+
+        let mut command = Command::new(&self.cmd);
+        command.args(&self.args);
+        if let Some(dir) = &self.cwd {
+            command.current_dir(dir);
+        }
+        for (key, value) in &self.envs {
+            command.env(key, value);
+        }
+        command
+            .stdin(if self.stdin_bytes.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        #[cfg(unix)]
+        {
+            // Run the child in its own process group so a timeout can kill
+            // the whole subtree, not just the immediate child.
+            use std::os::unix::process::CommandExt;
+            command.process_group(0);
+        }
+
+        let mut child = command
+            .spawn()
+            .context(format!("Failed to execute {}", self.cmd))?;
+        let pid = child.id();
+        let stdin = child.stdin.take();
+        let mut stdout = child.stdout.take().expect("stdout was piped");
+        let mut stderr = child.stderr.take().expect("stderr was piped");
+
+        // Shared so both the capped readers and the timeout path below can
+        // kill the child; on non-Unix targets that's the only way to kill
+        // it at all, since there's no process-group syscall to fall back on.
+        let child = std::sync::Arc::new(std::sync::Mutex::new(child));
+
+        let stdin_handle = self.stdin_bytes.zip(stdin).map(|(bytes, mut stdin)| {
+            std::thread::spawn(move || {
+                // Best-effort: a child that never reads stdin closes the
+                // pipe and turns this into an Err we can safely ignore.
+                let _ = stdin.write_all(&bytes);
+            })
+        });
+
+        // Shared between both readers: if one stream's cap kills the child
+        // mid-run, the other stream's EOF is a side effect of that kill, not
+        // genuine completion, so it gets the truncation marker too.
+        let capped = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+        let max_output = self.max_output;
+        let stdout_handle = {
+            let child = std::sync::Arc::clone(&child);
+            let capped = std::sync::Arc::clone(&capped);
+            std::thread::spawn(move || read_capped(&mut stdout, max_output, pid, &child, &capped))
+        };
+        let stderr_handle = {
+            let child = std::sync::Arc::clone(&child);
+            let capped = std::sync::Arc::clone(&capped);
+            std::thread::spawn(move || read_capped(&mut stderr, max_output, pid, &child, &capped))
+        };
+
+        let deadline = self.timeout.map(|d| std::time::Instant::now() + d);
+        let status = loop {
+            if let Some(status) = child.lock().unwrap().try_wait()? {
+                break Some(status);
+            }
+            if deadline.is_some_and(|d| std::time::Instant::now() >= d) {
+                break None;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        };
+
+        let Some(status) = status else {
+            kill_child(pid, &child);
+            let _ = child.lock().unwrap().wait();
+            if let Some(handle) = stdin_handle {
+                let _ = handle.join();
+            }
+            let _ = stdout_handle.join();
+            let _ = stderr_handle.join();
+            return Ok(RunOutcome::TimedOut);
+        };
+
+        if let Some(handle) = stdin_handle {
+            let _ = handle.join();
+        }
+        let mut stdout_bytes = stdout_handle.join().unwrap_or_default();
+        let mut stderr_bytes = stderr_handle.join().unwrap_or_default();
+
+        // Both readers have fully joined, so this check can't race with
+        // either one: if either stream's cap killed the child, tag whichever
+        // stream doesn't already carry its own marker, since its apparent
+        // completion was really just a side effect of that kill.
+        if capped.load(std::sync::atomic::Ordering::SeqCst) {
+            for buf in [&mut stdout_bytes, &mut stderr_bytes] {
+                if !buf.ends_with(TRUNCATION_MARKER.as_bytes()) {
+                    buf.extend_from_slice(TRUNCATION_MARKER.as_bytes());
+                }
+            }
+        }
+
+        Ok(RunOutcome::Completed {
+            stdout: String::from_utf8_lossy(&stdout_bytes).to_string(),
+            stderr: String::from_utf8_lossy(&stderr_bytes).to_string(),
+            exit_code: status.code().unwrap_or(-1),
+        })
+    }
+}
+
+/// Read `reader` to EOF, stopping at `max_bytes` and appending
+/// [`TRUNCATION_MARKER`]. Once the cap is hit, sets `capped` and kills the
+/// child so an unbounded producer (e.g. a linter stuck in a loop) can't keep
+/// this thread draining forever, then finishes draining the now-bounded
+/// pipe. Only this stream's own marker is added here — whether the *other*
+/// stream also needs one is decided once both threads have joined (see
+/// [`CommandRunner::run`]), so that decision can't race this one.
+fn read_capped<R: std::io::Read>(
+    reader: &mut R,
+    max_bytes: usize,
+    pid: u32,
+    child: &std::sync::Arc<std::sync::Mutex<std::process::Child>>,
+    capped: &std::sync::Arc<std::sync::atomic::AtomicBool>,
+) -> Vec<u8> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 8192];
+    loop {
+        let n = match reader.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(_) => break,
+        };
+        if buf.len() + n > max_bytes {
+            let remaining = max_bytes.saturating_sub(buf.len());
+            buf.extend_from_slice(&chunk[..remaining]);
+            buf.extend_from_slice(TRUNCATION_MARKER.as_bytes());
+            capped.store(true, std::sync::atomic::Ordering::SeqCst);
+            kill_child(pid, child);
+            while reader.read(&mut chunk).unwrap_or(0) > 0 {}
+            return buf;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+    buf
+}
+
+/// Kill `child`. On Unix this kills its whole process group (`pid` is that
+/// group's leader, set via `process_group(0)` at spawn) so forked workers
+/// die too; elsewhere it falls back to [`std::process::Child::kill`], which
+/// only reaches the direct child.
+fn kill_child(pid: u32, child: &std::sync::Arc<std::sync::Mutex<std::process::Child>>) {
+    #[cfg(unix)]
+    {
+        let _ = child;
+        extern "C" {
+            fn kill(pid: i32, sig: i32) -> i32;
+        }
+        const SIGKILL: i32 = 9;
+        unsafe {
+            kill(-(pid as i32), SIGKILL);
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = pid;
+        let _ = child.lock().unwrap().kill();
+    }
+}
+
 /// Formate un nombre de tokens avec suffixes K/M pour lisibilité.
 ///
 /// # Arguments
@@ -96,13 +554,20 @@ pub fn execute_command(cmd: &str, args: &[&str]) -> Result<(String, String, i32)
 /// assert_eq!(format_tokens(694), "694");
 /// ```
 pub fn format_tokens(n: usize) -> String {
-    if n >= 1_000_000 {
+    format_tokens_locale(n, crate::locale::Locale::default())
+}
+
+/// Same as [`format_tokens`], but rendered with `locale`'s decimal
+/// separator (e.g. "1,2M" in French/German/Spanish instead of "1.2M").
+pub fn format_tokens_locale(n: usize, locale: crate::locale::Locale) -> String {
+    let formatted = if n >= 1_000_000 {
         format!("{:.1}M", n as f64 / 1_000_000.0)
     } else if n >= 1_000 {
         format!("{:.1}K", n as f64 / 1_000.0)
     } else {
-        format!("{}", n)
-    }
+        return format!("{}", n);
+    };
+    formatted.replace('.', &locale.decimal_separator().to_string())
 }
 
 /// Formate un montant USD avec précision adaptée.
@@ -225,6 +690,94 @@ pub fn package_manager_exec(tool: &str) -> Command {
     }
 }
 
+/// Compare une ligne attendue (pouvant contenir des jokers `[..]`) à une
+/// ligne réelle. `[..]` matche n'importe quelle séquence de caractères
+/// (y compris vide) à l'intérieur de la ligne, à la manière du comparateur
+/// de snapshots de Cargo. Les segments entre jokers doivent matcher
+/// exactement et dans l'ordre.
+///
+/// # Examples
+/// ```
+/// use rtk::utils::lines_match;
+/// assert!(lines_match("passed in [..]s", "passed in 0.42s"));
+/// assert!(!lines_match("passed in [..]s", "failed in 0.42s"));
+/// assert!(lines_match("exact line", "exact line"));
+/// ```
+pub fn lines_match(expected: &str, actual: &str) -> bool {
+    if !expected.contains("[..]") {
+        return expected == actual;
+    }
+
+    let segments: Vec<&str> = expected.split("[..]").collect();
+    let mut rest = actual;
+
+    for (i, segment) in segments.iter().enumerate() {
+        if i == 0 {
+            if !rest.starts_with(segment) {
+                return false;
+            }
+            rest = &rest[segment.len()..];
+        } else if i == segments.len() - 1 {
+            // Last segment must match the remaining tail exactly.
+            if !rest.ends_with(segment) {
+                return false;
+            }
+        } else if let Some(idx) = rest.find(segment) {
+            rest = &rest[idx + segment.len()..];
+        } else {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Compare un texte réel à un fichier de snapshot ligne par ligne via
+/// [`lines_match`]. Retourne `Ok(())` si tout matche, sinon une erreur
+/// contenant un diff unifié compact (lignes `-`/`+` pour la première
+/// divergence et le delta de longueur).
+pub fn compare_snapshot(snapshot_path: &std::path::Path, actual: &str) -> Result<()> {
+    let expected = std::fs::read_to_string(snapshot_path)
+        .with_context(|| format!("Failed to read snapshot {}", snapshot_path.display()))?;
+
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+
+    let mut diff = String::new();
+    let max = expected_lines.len().max(actual_lines.len());
+    let mut mismatched = false;
+
+    for i in 0..max {
+        let exp = expected_lines.get(i).copied().unwrap_or("");
+        let act = actual_lines.get(i).copied().unwrap_or("");
+        let both_present = i < expected_lines.len() && i < actual_lines.len();
+        if !both_present || !lines_match(exp, act) {
+            mismatched = true;
+            diff.push_str(&format!("-{}\n+{}\n", exp, act));
+        }
+    }
+
+    if mismatched {
+        anyhow::bail!(
+            "Snapshot mismatch against {}:\n{}",
+            snapshot_path.display(),
+            diff.trim_end()
+        );
+    }
+
+    Ok(())
+}
+
+/// Écrit (ou réécrit) le fichier de snapshot avec le texte réel, pour
+/// `--snapshot-update`.
+pub fn update_snapshot(snapshot_path: &std::path::Path, actual: &str) -> Result<()> {
+    if let Some(parent) = snapshot_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(snapshot_path, actual)
+        .with_context(|| format!("Failed to write snapshot {}", snapshot_path.display()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -278,6 +831,60 @@ mod tests {
         assert_eq!(strip_ansi(input), "Green normal Red");
     }
 
+    #[test]
+    fn test_strip_ansi_csi_private_and_intermediate_bytes() {
+        // DEC private mode set/reset (CSI `?` ... `h`/`l`)
+        let input = "\x1b[?25lhidden cursor\x1b[?25h";
+        assert_eq!(strip_ansi(input), "hidden cursor");
+    }
+
+    #[test]
+    fn test_strip_ansi_osc_window_title() {
+        let input = "\x1b]0;my title\x07prompt$ ";
+        assert_eq!(strip_ansi(input), "prompt$ ");
+    }
+
+    #[test]
+    fn test_strip_ansi_osc_hyperlink_keeps_label() {
+        let input = "\x1b]8;;https://example.com\x1b\\click here\x1b]8;;\x1b\\";
+        assert_eq!(strip_ansi(input), "click here");
+    }
+
+    #[test]
+    fn test_strip_ansi_simple_two_byte_escape() {
+        let input = "before\x1bMafter";
+        assert_eq!(strip_ansi(input), "beforeafter");
+    }
+
+    #[test]
+    fn test_strip_ansi_keep_links_preserves_hyperlink_frames() {
+        let input = "\x1b]8;;https://example.com\x1b\\click here\x1b]8;;\x1b\\";
+        assert_eq!(strip_ansi_keep_links(input), input);
+    }
+
+    #[test]
+    fn test_strip_ansi_truncated_csi_is_left_literal() {
+        // A CSI sequence cut off mid-parameter (e.g. by CommandRunner's
+        // max_output cap) must not swallow the text that follows it.
+        let input = "colored text\x1b[38;5;123\n...[truncated]";
+        assert_eq!(strip_ansi(input), input);
+    }
+
+    #[test]
+    fn test_strip_ansi_unterminated_osc_is_left_literal() {
+        let input = "before\x1b]8;;https://example.com/still-writing";
+        assert_eq!(strip_ansi(input), input);
+    }
+
+    #[test]
+    fn test_strip_ansi_keep_links_still_strips_other_codes() {
+        let input = "\x1b[31m\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\\x1b[0m";
+        assert_eq!(
+            strip_ansi_keep_links(input),
+            "\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\"
+        );
+    }
+
     #[test]
     fn test_execute_command_success() {
         let result = execute_command("echo", &["test"]);
@@ -396,4 +1003,93 @@ mod tests {
         assert!(result.ends_with("..."));
     }
 
+    #[test]
+    fn test_truncate_display_short_string() {
+        assert_eq!(truncate_display("hi", 10), "hi");
+    }
+
+    #[test]
+    fn test_truncate_display_ascii() {
+        assert_eq!(truncate_display("hello world", 8), "hello...");
+    }
+
+    #[test]
+    fn test_truncate_display_cjk_fits_width() {
+        // Each ideograph is 2 columns; "..." is 3, so budget is 6-3=6 cols
+        // of CJK, which is 3 characters.
+        let cjk = "你好世界测试字符串";
+        let result = truncate_display(cjk, 6);
+        assert!(result.width() <= 6);
+        assert!(result.ends_with("..."));
+    }
+
+    #[test]
+    fn test_truncate_display_emoji_no_split() {
+        let emoji = "🎉🎊🎈🎁🎂🎄🎃🎆🎇✨";
+        let result = truncate_display(emoji, 5);
+        assert!(result.width() <= 5);
+        assert!(result.ends_with("..."));
+        // Must not have split a grapheme cluster into invalid UTF-8.
+        assert!(std::str::from_utf8(result.as_bytes()).is_ok());
+    }
+
+    #[test]
+    fn test_truncate_display_too_small() {
+        assert_eq!(truncate_display("hello", 2), "...");
+    }
+
+    #[test]
+    fn test_command_runner_args_and_exit_code() {
+        let outcome = CommandRunner::new("echo")
+            .args(["hello"])
+            .run()
+            .unwrap();
+        match outcome {
+            RunOutcome::Completed {
+                stdout, exit_code, ..
+            } => {
+                assert_eq!(stdout.trim(), "hello");
+                assert_eq!(exit_code, 0);
+            }
+            RunOutcome::TimedOut => panic!("echo should not time out"),
+        }
+    }
+
+    #[test]
+    fn test_command_runner_stdin_bytes() {
+        let outcome = CommandRunner::new("cat")
+            .stdin_bytes(b"piped input".to_vec())
+            .run()
+            .unwrap();
+        match outcome {
+            RunOutcome::Completed { stdout, .. } => assert_eq!(stdout, "piped input"),
+            RunOutcome::TimedOut => panic!("cat should not time out"),
+        }
+    }
+
+    #[test]
+    fn test_command_runner_timeout() {
+        let outcome = CommandRunner::new("sleep")
+            .args(["5"])
+            .timeout(std::time::Duration::from_millis(100))
+            .run()
+            .unwrap();
+        assert!(matches!(outcome, RunOutcome::TimedOut));
+    }
+
+    #[test]
+    fn test_command_runner_max_output_truncates() {
+        let outcome = CommandRunner::new("yes")
+            .max_output(10)
+            .timeout(std::time::Duration::from_secs(5))
+            .run()
+            .unwrap();
+        match outcome {
+            RunOutcome::Completed { stdout, .. } => {
+                assert!(stdout.ends_with(TRUNCATION_MARKER));
+                assert!(stdout.len() <= 10 + TRUNCATION_MARKER.len());
+            }
+            RunOutcome::TimedOut => panic!("yes | capped read should complete, not time out"),
+        }
+    }
 }
@@ -0,0 +1,33 @@
+use anyhow::Result;
+use serde_json::json;
+
+include!(concat!(env!("OUT_DIR"), "/version_info.rs"));
+
+/// Print `rtk`'s version plus the git branch/commit/build-timestamp baked
+/// in by `build.rs`, so bug reports against a tool that shells out to many
+/// external binaries can be traced back to an exact build.
+pub fn run(format: &str) -> Result<()> {
+    match format {
+        "json" => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&json!({
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "git_branch": GIT_BRANCH,
+                    "git_commit": GIT_COMMIT,
+                    "git_dirty": GIT_DIRTY,
+                    "build_timestamp": BUILD_TIMESTAMP,
+                }))?
+            );
+        }
+        _ => {
+            println!("rtk {}", env!("CARGO_PKG_VERSION"));
+            if !GIT_COMMIT.is_empty() {
+                let dirty = if GIT_DIRTY { ", dirty" } else { "" };
+                println!("commit:  {GIT_COMMIT}@{GIT_BRANCH}{dirty}");
+            }
+            println!("built:   {BUILD_TIMESTAMP}");
+        }
+    }
+    Ok(())
+}
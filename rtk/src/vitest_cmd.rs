@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 use regex::Regex;
 use serde::Deserialize;
+use std::path::Path;
 
 use crate::parser::{
     emit_degradation_warning, emit_passthrough_warning, extract_json_object, truncate_output,
@@ -47,19 +48,27 @@ struct VitestTest {
 /// Parser for Vitest JSON output
 pub struct VitestParser;
 
+/// Tier 1's JSON decode, with the same prefixed-output fallback
+/// [`VitestParser::parse`] uses, exposed on its own so callers that need
+/// the full per-file/per-assertion structure (the `--format junit`
+/// renderer below) don't have to re-derive it from the already-flattened
+/// [`TestResult`].
+fn parse_vitest_json(input: &str) -> Result<VitestJsonOutput, serde_json::Error> {
+    serde_json::from_str::<VitestJsonOutput>(input).or_else(|first_err| {
+        if let Some(extracted) = extract_json_object(input) {
+            serde_json::from_str::<VitestJsonOutput>(extracted)
+        } else {
+            Err(first_err)
+        }
+    })
+}
+
 impl OutputParser for VitestParser {
     type Output = TestResult;
 
     fn parse(input: &str) -> ParseResult<TestResult> {
         // Tier 1: Try JSON parsing (with extraction fallback for pnpm/dotenv prefixes)
-        let json_result = serde_json::from_str::<VitestJsonOutput>(input).or_else(|first_err| {
-            // Fallback: Try extracting JSON object from prefixed output
-            if let Some(extracted) = extract_json_object(input) {
-                serde_json::from_str::<VitestJsonOutput>(extracted)
-            } else {
-                Err(first_err)
-            }
-        });
+        let json_result = parse_vitest_json(input);
 
         match json_result {
             Ok(json) => {
@@ -74,6 +83,7 @@ impl OutputParser for VitestParser {
                     passed: json.num_passed_tests,
                     failed: json.num_failed_tests,
                     skipped: json.num_pending_tests,
+                    flaky: 0,
                     duration_ms,
                     failures,
                 };
@@ -103,12 +113,19 @@ fn extract_failures_from_json(json: &VitestJsonOutput) -> Vec<TestFailure> {
     for file in &json.test_results {
         for test in &file.assertion_results {
             if test.status == "failed" {
-                let error_message = test.failure_messages.join("\n");
+                let message = test.failure_messages.join("\n");
+                let (error_message, stack_trace) = split_failure_message(&message);
+                let file_path = stack_trace
+                    .as_deref()
+                    .and_then(first_user_frame)
+                    .unwrap_or_else(|| file.name.clone());
+
                 failures.push(TestFailure {
                     test_name: test.full_name.clone(),
-                    file_path: file.name.clone(),
+                    file_path,
                     error_message,
-                    stack_trace: None,
+                    stack_trace,
+                    attempts: None,
                 });
             }
         }
@@ -117,6 +134,44 @@ fn extract_failures_from_json(json: &VitestJsonOutput) -> Vec<TestFailure> {
     failures
 }
 
+/// Split a Vitest `failureMessages` entry into its human-readable
+/// assertion (everything before the first `at ...` stack frame) and the
+/// frames themselves, joined back with newlines. Messages with no stack
+/// frame at all (e.g. a bare assertion string) get `stack_trace: None`.
+fn split_failure_message(message: &str) -> (String, Option<String>) {
+    lazy_static::lazy_static! {
+        static ref STACK_FRAME_RE: Regex = Regex::new(r"^\s*at\s").unwrap();
+    }
+
+    let lines: Vec<&str> = message.lines().collect();
+    let Some(split_at) = lines.iter().position(|line| STACK_FRAME_RE.is_match(line)) else {
+        return (message.to_string(), None);
+    };
+
+    let error_message = lines[..split_at].join("\n").trim_end().to_string();
+    let stack_trace = lines[split_at..].join("\n");
+    (error_message, Some(stack_trace))
+}
+
+/// Pick the first stack frame whose path isn't inside `node_modules` and
+/// render it as `file:line`, so agents land on user code instead of a
+/// vitest/vite internal frame or (worse) the first frame in the trace.
+fn first_user_frame(stack_trace: &str) -> Option<String> {
+    lazy_static::lazy_static! {
+        static ref FRAME_RE: Regex = Regex::new(r"at\s+(?:.*\()?([^()\s]+):(\d+):(\d+)\)?").unwrap();
+    }
+
+    stack_trace.lines().find_map(|line| {
+        let caps = FRAME_RE.captures(line)?;
+        let path = caps.get(1)?.as_str();
+        if path.contains("node_modules") {
+            return None;
+        }
+        let line_no = caps.get(2)?.as_str();
+        Some(format!("{}:{}", path, line_no))
+    })
+}
+
 /// Tier 2: Extract test statistics using regex (degraded mode)
 fn extract_stats_regex(output: &str) -> Option<TestResult> {
     lazy_static::lazy_static! {
@@ -166,6 +221,7 @@ fn extract_stats_regex(output: &str) -> Option<TestResult> {
             passed,
             failed,
             skipped: 0,
+            flaky: 0,
             duration_ms,
             failures: extract_failures_regex(&clean_output),
         })
@@ -198,6 +254,7 @@ fn extract_failures_regex(output: &str) -> Vec<TestFailure> {
                     file_path: String::new(),
                     error_message: error_lines[1..].join("\n"),
                     stack_trace: None,
+                    attempts: None,
                 });
             }
         } else {
@@ -208,64 +265,630 @@ fn extract_failures_regex(output: &str) -> Vec<TestFailure> {
     failures
 }
 
+/// Output format for `rtk vitest run`, selected with `--format <value>`.
+/// `Text` is the default condensed summary; `Junit` gives CI test-report
+/// ingestion (GitLab/Jenkins/GitHub Actions) the standard
+/// `<testsuites><testsuite><testcase>` XML instead of scraping it, the
+/// same split `rtk tsc`'s `--format json`/`--format sarif` makes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Junit,
+}
+
+impl Format {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "text" => Some(Format::Text),
+            "junit" => Some(Format::Junit),
+            _ => None,
+        }
+    }
+}
+
+/// Strip a `--shuffle[=seed]` flag out of `args`, translating it to
+/// vitest's own `--sequence.shuffle`/`--sequence.seed`. Returns `None` when
+/// `--shuffle` wasn't passed, `Some(None)` when it was passed bare (the
+/// caller derives a seed, the same "random but logged so it can be
+/// replayed" approach [`runner::run_test_shuffle`](crate::runner::run_test_shuffle)
+/// takes for other test runners), and `Some(Some(seed))` when the user
+/// pinned one explicitly to replay a past failing order.
+fn extract_shuffle_flag(args: &[String]) -> Result<(Option<Option<u64>>, Vec<String>)> {
+    let mut shuffle = None;
+    let mut rest = Vec::with_capacity(args.len());
+    for arg in args {
+        if arg == "--shuffle" {
+            shuffle = Some(None);
+        } else if let Some(value) = arg.strip_prefix("--shuffle=") {
+            let seed: u64 = value
+                .parse()
+                .with_context(|| format!("--shuffle seed must be a number, got: {}", value))?;
+            shuffle = Some(Some(seed));
+        } else {
+            rest.push(arg.clone());
+        }
+    }
+    Ok((shuffle, rest))
+}
+
+/// Strip `--format <text|junit>` out of `args` (vitest itself has no such
+/// flag), returning the selected format and the remaining args to actually
+/// pass to vitest.
+fn extract_format_flag(args: &[String]) -> Result<(Format, Vec<String>)> {
+    let mut format = Format::Text;
+    let mut rest = Vec::with_capacity(args.len());
+    let mut iter = args.iter().cloned();
+    while let Some(arg) = iter.next() {
+        if arg == "--format" {
+            let value = iter
+                .next()
+                .context("--format requires a value (text or junit)")?;
+            format = Format::parse(&value)
+                .with_context(|| format!("invalid --format value: {}", value))?;
+        } else {
+            rest.push(arg);
+        }
+    }
+    Ok((format, rest))
+}
+
+/// Display disposition for `Format::Text`, selected by `-v` count --
+/// distinct from the shared [`FormatMode`] (compact/verbose/ultra) other
+/// tool wrappers use, because a failing vitest run carries a full
+/// `error_message` + `stack_trace` per failure that's worth expanding on
+/// its own, independent of whether passing tests get listed too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VerboseTier {
+    /// Summary only: pass/fail counts, failure names with a short preview.
+    Quiet,
+    /// Full `error_message` + `stack_trace` for every failure; passes
+    /// collapse to a count.
+    FailedOnly,
+    /// Every assertion, passes included. Needs the tier-1 per-assertion
+    /// JSON; falls back to `FailedOnly` when only the flattened
+    /// `TestResult` is available (degraded/passthrough tiers).
+    Full,
+}
+
+impl VerboseTier {
+    fn from_verbosity(verbosity: u8) -> Self {
+        match verbosity {
+            0 => VerboseTier::Quiet,
+            1 => VerboseTier::FailedOnly,
+            _ => VerboseTier::Full,
+        }
+    }
+}
+
+/// Render `data` as text at `tier`, using the richer `json` (when the
+/// tier-1 parse succeeded) for `VerboseTier::Full`'s full assertion list.
+fn render_text(json: Option<&VitestJsonOutput>, data: &TestResult, tier: VerboseTier) -> String {
+    match tier {
+        VerboseTier::Quiet => data.format(FormatMode::Compact),
+        VerboseTier::FailedOnly => render_failed_only(data),
+        VerboseTier::Full => json
+            .map(render_full_detail)
+            .unwrap_or_else(|| render_failed_only(data)),
+    }
+}
+
+/// Pass/fail counts, then every failure in full: complete `error_message`
+/// and `stack_trace` (not the 3-line preview `TestResult::format_verbose`
+/// uses), with passing tests left as just a count.
+fn render_failed_only(data: &TestResult) -> String {
+    let mut lines = vec![format!(
+        "Tests: {} passed, {} failed, {} skipped (total: {})",
+        data.passed, data.failed, data.skipped, data.total
+    )];
+    if data.flaky > 0 {
+        lines.push(format!("{} flaky (passed after retry)", data.flaky));
+    }
+
+    if !data.failures.is_empty() {
+        lines.push("\nFailures:".to_string());
+        for (idx, failure) in data.failures.iter().enumerate() {
+            lines.push(String::new());
+            lines.push(render_single_failure(idx + 1, failure));
+        }
+    }
+
+    if let Some(duration) = data.duration_ms {
+        lines.push(format!("\nDuration: {}ms", duration));
+    }
+
+    lines.join("\n")
+}
+
+/// Every assertion from the tier-1 JSON, passes included, grouped by file.
+fn render_full_detail(json: &VitestJsonOutput) -> String {
+    let mut lines = vec![format!(
+        "Tests: {} passed, {} failed, {} skipped (total: {})",
+        json.num_passed_tests,
+        json.num_failed_tests,
+        json.num_pending_tests,
+        json.num_total_tests
+    )];
+
+    for file in &json.test_results {
+        lines.push(format!("\n{}:", file.name));
+        for test in &file.assertion_results {
+            let symbol = match test.status.as_str() {
+                "passed" => "✓",
+                "failed" => "✗",
+                _ => "⊘",
+            };
+            lines.push(format!("  {} {}", symbol, test.full_name));
+
+            if test.status == "failed" {
+                let message = test.failure_messages.join("\n");
+                let (error_message, stack_trace) = split_failure_message(&message);
+                lines.push(format!("     {}", error_message));
+                if let Some(stack) = stack_trace {
+                    lines.push(indent(&stack, "     "));
+                }
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Render one failure's full detail: name, file, attempt count (if any),
+/// complete `error_message`, and full `stack_trace` (if any) -- shared by
+/// `render_failed_only`'s batch listing and the streaming path's
+/// print-as-it-arrives rendering.
+fn render_single_failure(index: usize, failure: &TestFailure) -> String {
+    let mut lines = vec![format!(
+        "{}. {} ({})",
+        index, failure.test_name, failure.file_path
+    )];
+    if let Some(attempts) = failure.attempts {
+        lines.push(format!("   attempts: {}", attempts));
+    }
+    lines.push(format!("   {}", failure.error_message));
+    if let Some(stack) = &failure.stack_trace {
+        lines.push(indent(stack, "   "));
+    }
+    lines.join("\n")
+}
+
+/// Prefix every line of `text` with `prefix`.
+fn indent(text: &str, prefix: &str) -> String {
+    text.lines()
+        .map(|line| format!("{}{}", prefix, line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 #[derive(Debug, Clone)]
 pub enum VitestCommand {
     Run,
+    Watch,
 }
 
 pub fn run(cmd: VitestCommand, args: &[String], verbose: u8) -> Result<()> {
     match cmd {
         VitestCommand::Run => run_vitest(args, verbose),
+        VitestCommand::Watch => run_watch(args, verbose),
     }
 }
 
 fn run_vitest(args: &[String], verbose: u8) -> Result<()> {
+    let (format, args) = extract_format_flag(args)?;
+    let (shuffle, args) = extract_shuffle_flag(&args)?;
+    let root = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
+    let exit_code = execute_vitest(&args, &root, format, verbose, shuffle)?;
+    std::process::exit(exit_code)
+}
+
+/// `rtk vitest watch`: drive our own debounced file watcher over the
+/// project root rather than vitest's own `--watch` (whose JSON reporter
+/// doesn't cleanly delimit one run's output from the next), re-invoking
+/// the one-shot parse-and-format pipeline for each batch of changes --
+/// the same split `rtk lint --watch`/`rtk format --watch` use. The root is
+/// resolved to an absolute path once, before the loop starts, and reused
+/// for every re-run: a test that `cd`s elsewhere mid-run must not move the
+/// watcher (the footgun Deno hit when it re-resolved the watch root from
+/// the process's current directory on every cycle).
+fn run_watch(args: &[String], verbose: u8) -> Result<()> {
+    use notify::{RecursiveMode, Watcher};
+    use std::sync::mpsc::channel;
+    use std::time::Duration;
+
+    let (format, args) = extract_format_flag(args)?;
+    let (shuffle, args) = extract_shuffle_flag(&args)?;
+    let root = std::env::current_dir()
+        .context("Failed to resolve working directory for vitest watch")?;
+
+    let mut exit_code = execute_vitest(&args, &root, format, verbose, shuffle)?;
+    println!("Watching {}… (Ctrl-C to stop)", root.display());
+
+    let (tx, rx) = channel();
+    let mut watcher = notify::recommended_watcher(move |res| {
+        let _ = tx.send(res);
+    })
+    .context("Failed to start file watcher")?;
+    watcher
+        .watch(&root, RecursiveMode::Recursive)
+        .context("Failed to watch directory")?;
+
+    loop {
+        if rx.recv().is_err() {
+            break;
+        }
+        // Drain anything else that arrives within the debounce window so a
+        // single save (which fires several OS events) triggers exactly one
+        // re-run.
+        while rx.recv_timeout(Duration::from_millis(100)).is_ok() {}
+
+        print!("\x1B[2J\x1B[1;1H");
+        match execute_vitest(&args, &root, format, verbose, shuffle) {
+            Ok(code) => exit_code = code,
+            Err(e) => eprintln!("⚠️  vitest run failed: {}", e),
+        }
+        println!("Watching {}… (Ctrl-C to stop)", root.display());
+    }
+
+    std::process::exit(exit_code)
+}
+
+/// Run vitest once against `root`, parse-and-format its output, print the
+/// condensed result, and return the underlying exit code -- shared by the
+/// one-shot `run` command and each cycle of `watch`.
+///
+/// Reads the child's stdout line by line instead of buffering the whole
+/// run up front: if the very first line is valid per-event NDJSON (one
+/// JSON object per assertion, as a custom `--reporter` can emit), each
+/// failure is parsed, rendered, and printed the moment it arrives via
+/// [`run_streaming`], and only the final tally waits for the stream to
+/// close. If that first line isn't per-event JSON -- the default `json`
+/// reporter's single end-of-run blob, or a user-supplied `--reporter`
+/// that doesn't speak this schema -- the rest of stdout is buffered and
+/// handed to the existing three-tier [`VitestParser::parse`], unchanged.
+///
+/// `shuffle` mirrors [`extract_shuffle_flag`]'s result: when present, a
+/// seed is resolved (pinned by the user or derived from the clock),
+/// translated to vitest's `--sequence.shuffle`/`--sequence.seed`, echoed
+/// as `shuffle seed: N` after the formatted output, and folded into the
+/// `rtk_cmd` passed to [`tracking::TimedExecution::track`] so the tracked
+/// record carries the seed a failing run needs to replay.
+fn execute_vitest(
+    args: &[String],
+    root: &Path,
+    format: Format,
+    verbose: u8,
+    shuffle: Option<Option<u64>>,
+) -> Result<i32> {
+    use std::io::{BufRead, Read};
+    use std::process::Stdio;
+
     let timer = tracking::TimedExecution::start();
+    let seed = shuffle.map(|user_seed| user_seed.unwrap_or_else(crate::runner::derive_seed_from_time));
 
     let mut cmd = package_manager_exec("vitest");
-    cmd.arg("run"); // Force non-watch mode
+    cmd.arg("run"); // Force non-watch mode; we drive our own re-run loop.
 
     // Add JSON reporter for structured output
     cmd.arg("--reporter=json");
 
+    if let Some(seed) = seed {
+        cmd.arg("--sequence.shuffle");
+        cmd.arg(format!("--sequence.seed={}", seed));
+    }
+
     for arg in args {
         cmd.arg(arg);
     }
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+
+    let mut child = cmd.spawn().context("Failed to run vitest")?;
+    let mut reader = std::io::BufReader::new(child.stdout.take().expect("stdout was piped"));
+
+    let mut first_line = String::new();
+    reader
+        .read_line(&mut first_line)
+        .context("Failed to read vitest output")?;
+
+    let (raw_stdout, filtered) =
+        if serde_json::from_str::<VitestStreamEvent>(first_line.trim_end()).is_ok() {
+            let mut result = TestResult {
+                total: 0,
+                passed: 0,
+                failed: 0,
+                skipped: 0,
+                flaky: 0,
+                duration_ms: None,
+                failures: Vec::new(),
+            };
+            let raw = run_streaming(reader, &first_line, &mut result)?;
+            result.normalize_paths(root);
+            (raw, render_stream_summary(&result))
+        } else {
+            let mut rest = String::new();
+            reader
+                .read_to_string(&mut rest)
+                .context("Failed to read vitest output")?;
+            let stdout = format!("{}{}", first_line, rest);
+            let filtered = render_buffered(&stdout, root, format, verbose);
+            (stdout, filtered)
+        };
+
+    let filtered = match seed {
+        Some(seed) => format!("{}\nshuffle seed: {seed} (replay with --shuffle={seed})", filtered),
+        None => filtered,
+    };
+    println!("{}", filtered);
+
+    let status = child.wait().context("Failed waiting on vitest")?;
+    let mut stderr = String::new();
+    if let Some(mut child_stderr) = child.stderr.take() {
+        let _ = child_stderr.read_to_string(&mut stderr);
+    }
+    let combined = format!("{}{}", raw_stdout, stderr);
+
+    let rtk_cmd = match seed {
+        Some(seed) => format!("rtk vitest run --shuffle={}", seed),
+        None => "rtk vitest run".to_string(),
+    };
+    timer.track("vitest run", &rtk_cmd, &combined, &filtered);
+
+    Ok(status.code().unwrap_or(1))
+}
 
-    let output = cmd.output().context("Failed to run vitest")?;
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
-    let combined = format!("{}{}", stdout, stderr);
+/// One event from a per-test NDJSON `--reporter` (one JSON object per
+/// assertion, as opposed to the stock `json` reporter's single blob at
+/// the end of the run), consumed incrementally by [`run_streaming`].
+#[derive(Debug, Deserialize)]
+struct VitestStreamEvent {
+    file: String,
+    #[serde(rename = "fullName")]
+    full_name: String,
+    status: String,
+    #[serde(rename = "failureMessages", default)]
+    failure_messages: Vec<String>,
+}
 
+/// Fold one [`VitestStreamEvent`] into the running `result`, returning the
+/// rendered failure block to print immediately when the event was a
+/// failure (`None` for passes/skips, which only move the counters).
+fn apply_stream_event(result: &mut TestResult, event: &VitestStreamEvent) -> Option<String> {
+    result.total += 1;
+    match event.status.as_str() {
+        "passed" => {
+            result.passed += 1;
+            None
+        }
+        "failed" => {
+            result.failed += 1;
+            let message = event.failure_messages.join("\n");
+            let (error_message, stack_trace) = split_failure_message(&message);
+            let file_path = stack_trace
+                .as_deref()
+                .and_then(first_user_frame)
+                .unwrap_or_else(|| event.file.clone());
+            result.failures.push(TestFailure {
+                test_name: event.full_name.clone(),
+                file_path,
+                error_message,
+                stack_trace,
+                attempts: None,
+            });
+            result
+                .failures
+                .last()
+                .map(|f| render_single_failure(result.failures.len(), f))
+        }
+        _ => {
+            result.skipped += 1;
+            None
+        }
+    }
+}
+
+/// Consume `reader` line by line, applying each line's event to `result`
+/// and printing its rendered failure block (if any) as soon as it
+/// arrives. `first_line` is the opening line already read by the caller
+/// to decide whether the run is per-event NDJSON at all; it still needs
+/// to be applied here. Returns every line read (first_line included), so
+/// the caller can still track raw-vs-filtered savings for a streamed run.
+fn run_streaming<R: std::io::BufRead>(
+    mut reader: R,
+    first_line: &str,
+    result: &mut TestResult,
+) -> Result<String> {
+    let mut raw = String::new();
+    raw.push_str(first_line);
+
+    if let Ok(event) = serde_json::from_str::<VitestStreamEvent>(first_line.trim_end()) {
+        if let Some(rendered) = apply_stream_event(result, &event) {
+            println!("{}", rendered);
+        }
+    }
+
+    let mut line = String::new();
+    loop {
+        line.clear();
+        let bytes = reader
+            .read_line(&mut line)
+            .context("Failed to read vitest output")?;
+        if bytes == 0 {
+            break;
+        }
+        raw.push_str(&line);
+        let trimmed = line.trim_end();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if let Ok(event) = serde_json::from_str::<VitestStreamEvent>(trimmed) {
+            if let Some(rendered) = apply_stream_event(result, &event) {
+                println!("{}", rendered);
+            }
+        }
+    }
+
+    Ok(raw)
+}
+
+/// Final pass/fail tally printed once a streamed run closes -- the
+/// per-failure detail was already printed as each one arrived, so this is
+/// just the summary line, not a repeat of `render_failed_only`.
+fn render_stream_summary(result: &TestResult) -> String {
+    let mut line = format!("PASS ({}) FAIL ({})", result.passed, result.failed);
+    if result.skipped > 0 {
+        line.push_str(&format!(" SKIP ({})", result.skipped));
+    }
+    line
+}
+
+/// The pre-streaming path: buffer the whole run's stdout, then parse and
+/// render it with the existing three-tier pipeline.
+fn render_buffered(stdout: &str, root: &Path, format: Format, verbose: u8) -> String {
     // Parse output using VitestParser
-    let parse_result = VitestParser::parse(&stdout);
-    let mode = FormatMode::from_verbosity(verbose);
+    let parse_result = VitestParser::parse(stdout);
+    let tier = VerboseTier::from_verbosity(verbose);
 
-    let filtered = match parse_result {
-        ParseResult::Full(data) => {
+    match parse_result {
+        ParseResult::Full(mut data) => {
             if verbose > 0 {
                 eprintln!("vitest run (Tier 1: Full JSON parse)");
             }
-            data.format(mode)
+            data.normalize_paths(root);
+            // Tier 1 kept the full per-file/per-assertion JSON around, so
+            // both the JUnit report and a `VerboseTier::Full` pass listing
+            // can be built from that instead of the already-flattened
+            // TestResult.
+            let json = parse_vitest_json(stdout).ok();
+            match format {
+                Format::Junit => json
+                    .as_ref()
+                    .map(render_junit_xml)
+                    .unwrap_or_else(|| render_junit_xml_from_result(&data)),
+                Format::Text => render_text(json.as_ref(), &data, tier),
+            }
         }
-        ParseResult::Degraded(data, warnings) => {
+        ParseResult::Degraded(mut data, warnings) => {
             if verbose > 0 {
                 emit_degradation_warning("vitest", &warnings.join(", "));
             }
-            data.format(mode)
+            data.normalize_paths(root);
+            match format {
+                Format::Junit => render_junit_xml_from_result(&data),
+                Format::Text => render_text(None, &data, tier),
+            }
         }
         ParseResult::Passthrough(raw) => {
             emit_passthrough_warning("vitest", "All parsing tiers failed");
             raw
         }
+    }
+}
+
+/// XML-escape `&`, `<`, `>`, and `"` for safe use in both JUnit attribute
+/// values and element text.
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Full-fidelity JUnit XML built straight from the parsed JSON (tier 1
+/// only): one `<testsuite>` per [`VitestTestFile`], one `<testcase>` per
+/// assertion, with a nested `<failure>` for failed tests and a bare
+/// `<skipped/>` for pending ones.
+fn render_junit_xml(json: &VitestJsonOutput) -> String {
+    // Vitest's JSON reporter only timestamps the overall run, not each
+    // file, so every suite carries the same run-wide `time`.
+    let time = match (json.start_time, json.end_time) {
+        (Some(start), Some(end)) => end.saturating_sub(start) as f64 / 1000.0,
+        _ => 0.0,
     };
 
-    println!("{}", filtered);
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites>\n");
 
-    timer.track("vitest run", "rtk vitest run", &combined, &filtered);
+    for file in &json.test_results {
+        let failures = file
+            .assertion_results
+            .iter()
+            .filter(|t| t.status == "failed")
+            .count();
+        let skipped = file
+            .assertion_results
+            .iter()
+            .filter(|t| t.status == "pending" || t.status == "skipped" || t.status == "todo")
+            .count();
+
+        xml.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.3}\">\n",
+            xml_escape(&file.name),
+            file.assertion_results.len(),
+            failures,
+            skipped,
+            time,
+        ));
+
+        for test in &file.assertion_results {
+            xml.push_str(&format!(
+                "    <testcase name=\"{}\" classname=\"{}\">\n",
+                xml_escape(&test.full_name),
+                xml_escape(&file.name),
+            ));
 
-    // Propagate original exit code
-    std::process::exit(output.status.code().unwrap_or(1))
+            if test.status == "failed" {
+                let message = test.failure_messages.join("\n");
+                xml.push_str(&format!(
+                    "      <failure message=\"{}\">{}</failure>\n",
+                    xml_escape(&message),
+                    xml_escape(&message),
+                ));
+            } else if test.status == "pending" || test.status == "skipped" || test.status == "todo"
+            {
+                xml.push_str("      <skipped/>\n");
+            }
+
+            xml.push_str("    </testcase>\n");
+        }
+
+        xml.push_str("  </testsuite>\n");
+    }
+
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+/// Fallback JUnit XML for when only the flattened [`TestResult`] is
+/// available (tiers 2/3 have no per-file JSON to render from): a single
+/// `<testsuite>` carrying the aggregate counts, with one `<testcase>` per
+/// recorded failure. Individual passed/skipped tests aren't enumerable at
+/// this tier, so only the suite-level `skipped` attribute reflects them.
+fn render_junit_xml_from_result(result: &TestResult) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites>\n");
+    xml.push_str(&format!(
+        "  <testsuite name=\"vitest\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.3}\">\n",
+        result.total,
+        result.failed,
+        result.skipped,
+        result.duration_ms.unwrap_or(0) as f64 / 1000.0,
+    ));
+
+    for failure in &result.failures {
+        xml.push_str(&format!(
+            "    <testcase name=\"{}\" classname=\"{}\">\n",
+            xml_escape(&failure.test_name),
+            xml_escape(&failure.file_path),
+        ));
+        xml.push_str(&format!(
+            "      <failure message=\"{}\">{}</failure>\n",
+            xml_escape(&failure.error_message),
+            xml_escape(&failure.error_message),
+        ));
+        xml.push_str("    </testcase>\n");
+    }
+
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
 }
 
 #[cfg(test)]
@@ -377,4 +1000,295 @@ Scope: all 6 workspace projects
         assert_eq!(data.total, 2);
         assert_eq!(data.passed, 2);
     }
+
+    #[test]
+    fn test_extract_format_flag_defaults_to_text() {
+        let (format, rest) = extract_format_flag(&["--coverage".to_string()]).unwrap();
+        assert_eq!(format, Format::Text);
+        assert_eq!(rest, vec!["--coverage".to_string()]);
+    }
+
+    #[test]
+    fn test_extract_format_flag_junit() {
+        let args = vec!["--format".to_string(), "junit".to_string(), "src".to_string()];
+        let (format, rest) = extract_format_flag(&args).unwrap();
+        assert_eq!(format, Format::Junit);
+        assert_eq!(rest, vec!["src".to_string()]);
+    }
+
+    #[test]
+    fn test_extract_format_flag_rejects_unknown_value() {
+        let args = vec!["--format".to_string(), "sarif".to_string()];
+        assert!(extract_format_flag(&args).is_err());
+    }
+
+    #[test]
+    fn test_extract_shuffle_flag_absent_by_default() {
+        let (shuffle, rest) = extract_shuffle_flag(&["src".to_string()]).unwrap();
+        assert_eq!(shuffle, None);
+        assert_eq!(rest, vec!["src".to_string()]);
+    }
+
+    #[test]
+    fn test_extract_shuffle_flag_bare_defers_seed() {
+        let args = vec!["--shuffle".to_string(), "src".to_string()];
+        let (shuffle, rest) = extract_shuffle_flag(&args).unwrap();
+        assert_eq!(shuffle, Some(None));
+        assert_eq!(rest, vec!["src".to_string()]);
+    }
+
+    #[test]
+    fn test_extract_shuffle_flag_pins_seed() {
+        let args = vec!["--shuffle=12345".to_string()];
+        let (shuffle, rest) = extract_shuffle_flag(&args).unwrap();
+        assert_eq!(shuffle, Some(Some(12345)));
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn test_extract_shuffle_flag_rejects_non_numeric_seed() {
+        let args = vec!["--shuffle=banana".to_string()];
+        assert!(extract_shuffle_flag(&args).is_err());
+    }
+
+    #[test]
+    fn test_render_junit_xml_escapes_and_groups_by_file() {
+        let json = r#"{"numTotalTests": 2, "numPassedTests": 1, "numFailedTests": 1, "numPendingTests": 0, "testResults": [{"name": "a<b>.test.ts", "assertionResults": [{"fullName": "passes", "status": "passed", "failureMessages": []}, {"fullName": "fails \"badly\"", "status": "failed", "failureMessages": ["expected 1 & 2 to match"]}]}], "startTime": 1000, "endTime": 1500}"#;
+        let parsed: VitestJsonOutput = serde_json::from_str(json).unwrap();
+        let xml = render_junit_xml(&parsed);
+
+        assert!(xml.contains("<testsuites>"));
+        assert!(xml.contains("name=\"a&lt;b&gt;.test.ts\""));
+        assert!(xml.contains("tests=\"2\" failures=\"1\" skipped=\"0\""));
+        assert!(xml.contains("time=\"0.500\""));
+        assert!(xml.contains("name=\"fails &quot;badly&quot;\""));
+        assert!(xml.contains("expected 1 &amp; 2 to match"));
+        assert!(!xml.contains("<skipped/>"));
+    }
+
+    #[test]
+    fn test_render_junit_xml_marks_pending_as_skipped() {
+        let json = r#"{"numTotalTests": 1, "numPassedTests": 0, "numFailedTests": 0, "numPendingTests": 1, "testResults": [{"name": "b.test.ts", "assertionResults": [{"fullName": "todo later", "status": "pending", "failureMessages": []}]}], "startTime": 1000, "endTime": 1000}"#;
+        let parsed: VitestJsonOutput = serde_json::from_str(json).unwrap();
+        let xml = render_junit_xml(&parsed);
+
+        assert!(xml.contains("skipped=\"1\""));
+        assert!(xml.contains("<skipped/>"));
+    }
+
+    #[test]
+    fn test_render_junit_xml_from_result_aggregates_without_file_granularity() {
+        let result = TestResult {
+            total: 5,
+            passed: 3,
+            failed: 1,
+            skipped: 1,
+            flaky: 0,
+            duration_ms: Some(2500),
+            failures: vec![TestFailure {
+                test_name: "it works".to_string(),
+                file_path: "foo.test.ts".to_string(),
+                error_message: "assertion failed".to_string(),
+                stack_trace: None,
+                attempts: None,
+            }],
+        };
+
+        let xml = render_junit_xml_from_result(&result);
+        assert!(xml.contains("tests=\"5\" failures=\"1\" skipped=\"1\" time=\"2.500\""));
+        assert!(xml.contains("classname=\"foo.test.ts\""));
+        assert!(xml.contains("assertion failed"));
+    }
+
+    #[test]
+    fn test_split_failure_message_separates_assertion_from_stack() {
+        let message = "expected 1 to be 2\n\n    at Object.<anonymous> (/repo/src/add.test.ts:10:20)\n    at Module._compile (node:internal/modules/cjs/loader:1105:14)";
+        let (error_message, stack_trace) = split_failure_message(message);
+
+        assert_eq!(error_message, "expected 1 to be 2");
+        let stack_trace = stack_trace.unwrap();
+        assert!(stack_trace.starts_with("    at Object.<anonymous>"));
+        assert!(!stack_trace.contains("expected 1 to be 2"));
+    }
+
+    #[test]
+    fn test_split_failure_message_without_stack_frame_keeps_whole_message() {
+        let message = "assertion failed with no stack";
+        let (error_message, stack_trace) = split_failure_message(message);
+
+        assert_eq!(error_message, message);
+        assert!(stack_trace.is_none());
+    }
+
+    #[test]
+    fn test_first_user_frame_skips_node_modules() {
+        let stack_trace = "    at Vitest.run (/repo/node_modules/vitest/dist/index.js:42:10)\n    at Object.<anonymous> (/repo/src/add.test.ts:10:20)";
+        assert_eq!(first_user_frame(stack_trace), Some("/repo/src/add.test.ts:10".to_string()));
+    }
+
+    #[test]
+    fn test_first_user_frame_none_when_all_frames_are_vendored() {
+        let stack_trace = "    at Vitest.run (/repo/node_modules/vitest/dist/index.js:42:10)";
+        assert_eq!(first_user_frame(stack_trace), None);
+    }
+
+    #[test]
+    fn test_extract_failures_from_json_uses_user_frame_as_file_path() {
+        let json = r#"{"numTotalTests": 1, "numPassedTests": 0, "numFailedTests": 1, "numPendingTests": 0, "testResults": [{"name": "add.test.ts", "assertionResults": [{"fullName": "adds", "status": "failed", "failureMessages": ["expected 1 to be 2\n    at Vitest.run (/repo/node_modules/vitest/dist/index.js:42:10)\n    at Object.<anonymous> (/repo/src/add.test.ts:10:20)"]}]}], "startTime": 1000, "endTime": 1000}"#;
+        let parsed: VitestJsonOutput = serde_json::from_str(json).unwrap();
+        let failures = extract_failures_from_json(&parsed);
+
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].error_message, "expected 1 to be 2");
+        assert_eq!(failures[0].file_path, "/repo/src/add.test.ts:10");
+        assert!(failures[0].stack_trace.as_ref().unwrap().contains("Object.<anonymous>"));
+    }
+
+    #[test]
+    fn test_verbose_tier_from_verbosity() {
+        assert_eq!(VerboseTier::from_verbosity(0), VerboseTier::Quiet);
+        assert_eq!(VerboseTier::from_verbosity(1), VerboseTier::FailedOnly);
+        assert_eq!(VerboseTier::from_verbosity(2), VerboseTier::Full);
+    }
+
+    fn sample_result_with_stack() -> TestResult {
+        TestResult {
+            total: 2,
+            passed: 1,
+            failed: 1,
+            skipped: 0,
+            flaky: 0,
+            duration_ms: Some(50),
+            failures: vec![TestFailure {
+                test_name: "adds".to_string(),
+                file_path: "add.test.ts:10".to_string(),
+                error_message: "expected 1 to be 2".to_string(),
+                stack_trace: Some("    at Object.<anonymous> (/repo/src/add.test.ts:10:20)".to_string()),
+                attempts: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_render_text_failed_only_includes_full_stack_trace() {
+        let rendered = render_text(None, &sample_result_with_stack(), VerboseTier::FailedOnly);
+        assert!(rendered.contains("expected 1 to be 2"));
+        assert!(rendered.contains("at Object.<anonymous>"));
+    }
+
+    #[test]
+    fn test_render_text_quiet_omits_stack_trace() {
+        let rendered = render_text(None, &sample_result_with_stack(), VerboseTier::Quiet);
+        assert!(!rendered.contains("at Object.<anonymous>"));
+    }
+
+    #[test]
+    fn test_render_text_full_lists_passes_when_json_available() {
+        let json = r#"{"numTotalTests": 2, "numPassedTests": 1, "numFailedTests": 1, "numPendingTests": 0, "testResults": [{"name": "add.test.ts", "assertionResults": [{"fullName": "adds", "status": "passed", "failureMessages": []}, {"fullName": "subtracts", "status": "failed", "failureMessages": ["expected 3 to be 2"]}]}], "startTime": 0, "endTime": 10}"#;
+        let parsed: VitestJsonOutput = serde_json::from_str(json).unwrap();
+
+        let rendered = render_text(Some(&parsed), &sample_result_with_stack(), VerboseTier::Full);
+        assert!(rendered.contains("✓ adds"));
+        assert!(rendered.contains("✗ subtracts"));
+        assert!(rendered.contains("expected 3 to be 2"));
+    }
+
+    #[test]
+    fn test_render_text_full_falls_back_to_failed_only_without_json() {
+        let rendered = render_text(None, &sample_result_with_stack(), VerboseTier::Full);
+        assert!(rendered.contains("expected 1 to be 2"));
+        assert!(rendered.contains("at Object.<anonymous>"));
+    }
+
+    #[test]
+    fn test_apply_stream_event_counts_pass_and_skips_quietly() {
+        let mut result = TestResult {
+            total: 0,
+            passed: 0,
+            failed: 0,
+            skipped: 0,
+            flaky: 0,
+            duration_ms: None,
+            failures: Vec::new(),
+        };
+
+        let passed = serde_json::from_str::<VitestStreamEvent>(
+            r#"{"file": "a.test.ts", "fullName": "adds", "status": "passed"}"#,
+        )
+        .unwrap();
+        assert!(apply_stream_event(&mut result, &passed).is_none());
+
+        let skipped = serde_json::from_str::<VitestStreamEvent>(
+            r#"{"file": "a.test.ts", "fullName": "todo later", "status": "skipped"}"#,
+        )
+        .unwrap();
+        assert!(apply_stream_event(&mut result, &skipped).is_none());
+
+        assert_eq!(result.total, 2);
+        assert_eq!(result.passed, 1);
+        assert_eq!(result.skipped, 1);
+    }
+
+    #[test]
+    fn test_apply_stream_event_renders_failure_immediately() {
+        let mut result = TestResult {
+            total: 0,
+            passed: 0,
+            failed: 0,
+            skipped: 0,
+            flaky: 0,
+            duration_ms: None,
+            failures: Vec::new(),
+        };
+
+        let failed = serde_json::from_str::<VitestStreamEvent>(
+            r#"{"file": "a.test.ts", "fullName": "adds", "status": "failed", "failureMessages": ["expected 1 to be 2\n    at Object.<anonymous> (/repo/src/a.test.ts:5:1)"]}"#,
+        )
+        .unwrap();
+
+        let rendered = apply_stream_event(&mut result, &failed).unwrap();
+        assert!(rendered.contains("1. adds"));
+        assert!(rendered.contains("expected 1 to be 2"));
+        assert_eq!(result.failed, 1);
+        assert_eq!(result.failures[0].file_path, "/repo/src/a.test.ts:5");
+    }
+
+    #[test]
+    fn test_run_streaming_prints_failures_and_returns_raw_lines() {
+        let mut result = TestResult {
+            total: 0,
+            passed: 0,
+            failed: 0,
+            skipped: 0,
+            flaky: 0,
+            duration_ms: None,
+            failures: Vec::new(),
+        };
+
+        let first_line = r#"{"file": "a.test.ts", "fullName": "adds", "status": "passed"}"#.to_string();
+        let rest = r#"{"file": "b.test.ts", "fullName": "subtracts", "status": "failed", "failureMessages": ["boom"]}
+"#;
+
+        let raw = run_streaming(rest.as_bytes(), &first_line, &mut result).unwrap();
+        assert_eq!(result.passed, 1);
+        assert_eq!(result.failed, 1);
+        assert!(raw.contains("subtracts"));
+    }
+
+    #[test]
+    fn test_render_stream_summary_includes_skipped_only_when_nonzero() {
+        let mut result = TestResult {
+            total: 3,
+            passed: 2,
+            failed: 1,
+            skipped: 0,
+            flaky: 0,
+            duration_ms: None,
+            failures: Vec::new(),
+        };
+        assert_eq!(render_stream_summary(&result), "PASS (2) FAIL (1)");
+
+        result.skipped = 1;
+        assert_eq!(render_stream_summary(&result), "PASS (2) FAIL (1) SKIP (1)");
+    }
 }
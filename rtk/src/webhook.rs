@@ -0,0 +1,304 @@
+//! Local GitHub webhook receiver for `rtk gh webhook`, mirroring
+//! build-o-tron's webhook server: verify the `X-Hub-Signature-256` header
+//! via constant-time `HMAC-SHA256(secret, body)` comparison, dispatch on
+//! `X-GitHub-Event`, and print a one-to-three-line summary alongside the
+//! full payload run through [`crate::json_cmd::filter_json_string`] - the
+//! same compaction `gh api`/`gh pr diff` already use. Built only with the
+//! `webhook-server` feature; uses nothing beyond `std::net` plus `hmac`/
+//! `sha2` for the signature check, matching [`crate::metrics_server`]'s
+//! "plain `std::net`, one route" approach.
+#![cfg(feature = "webhook-server")]
+
+use crate::{json_cmd, tracking};
+use anyhow::{bail, Context, Result};
+use hmac::{Hmac, Mac};
+use serde_json::Value;
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::Duration;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// GitHub's own webhook payload limit is 25MB; cap well above any real
+/// delivery but far below "attacker-chosen multi-gigabyte allocation" so a
+/// forged `Content-Length` can't be used to OOM or wedge the listener.
+const MAX_BODY_BYTES: usize = 32 * 1024 * 1024;
+
+/// Bound on how long a single connection may sit idle mid-read. Without
+/// this, a client that sends headers and then stalls (or never sends the
+/// promised body) blocks this single-threaded `serve` loop forever.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Max bytes accepted for the request line or any single header line.
+/// `read_line` otherwise grows its `String` buffer without bound, so a
+/// multi-gigabyte line with no `\n` is exactly as much of a problem as the
+/// `Content-Length` the body-size cap above already closes off.
+const MAX_LINE_BYTES: usize = 8 * 1024;
+
+/// Max header lines read per request before giving up -- bounds total
+/// header memory to `MAX_HEADER_LINES * MAX_LINE_BYTES` even from a client
+/// that sends thousands of small, individually-under-the-cap lines.
+const MAX_HEADER_LINES: usize = 100;
+
+/// Read one line (up to and including the terminating `\n`, if any) from
+/// `reader`, bailing instead of growing `line` past `max_bytes`. Reads a
+/// byte at a time -- `reader` is already a `BufReader`, so this doesn't add
+/// a second layer of buffering, and header/request lines are small enough
+/// that per-byte dispatch overhead doesn't matter.
+fn read_bounded_line<R: BufRead>(reader: &mut R, max_bytes: usize) -> Result<String> {
+    let mut line = Vec::new();
+    let mut byte = [0u8; 1];
+    loop {
+        if reader.read(&mut byte).context("failed to read line")? == 0 {
+            break;
+        }
+        line.push(byte[0]);
+        if byte[0] == b'\n' {
+            break;
+        }
+        if line.len() > max_bytes {
+            bail!("line exceeds maximum length of {} bytes", max_bytes);
+        }
+    }
+    Ok(String::from_utf8_lossy(&line).into_owned())
+}
+
+/// Serve GitHub webhook deliveries on `addr` (e.g. `"127.0.0.1:8787"`) until
+/// the process is killed, verifying each delivery against `secret`.
+/// Blocking and single-threaded: webhook deliveries arrive one at a time
+/// per repo event, which this easily keeps up with.
+pub fn serve(addr: &str, secret: &str) -> Result<()> {
+    let listener = TcpListener::bind(addr).with_context(|| format!("failed to bind {addr}"))?;
+    println!("🪝 Listening for GitHub webhooks on http://{addr}");
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                if let Err(err) = handle_connection(stream, secret) {
+                    eprintln!("rtk gh webhook: {err:#}");
+                }
+            }
+            Err(err) => eprintln!("rtk gh webhook: accept failed: {err}"),
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_connection(mut stream: TcpStream, secret: &str) -> Result<()> {
+    stream
+        .set_read_timeout(Some(READ_TIMEOUT))
+        .context("failed to set read timeout")?;
+    let mut reader = BufReader::new(stream.try_clone().context("failed to clone stream")?);
+
+    let _request_line =
+        read_bounded_line(&mut reader, MAX_LINE_BYTES).context("failed to read request line")?;
+
+    let mut headers = HashMap::new();
+    let mut saw_header_terminator = false;
+    for _ in 0..MAX_HEADER_LINES {
+        let line = read_bounded_line(&mut reader, MAX_LINE_BYTES).context("failed to read header line")?;
+        if line == "\r\n" || line.is_empty() {
+            saw_header_terminator = true;
+            break;
+        }
+        if let Some((key, value)) = line.trim_end().split_once(':') {
+            headers.insert(key.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+    if !saw_header_terminator {
+        bail!("too many header lines (max {})", MAX_HEADER_LINES);
+    }
+
+    let content_length: usize = headers
+        .get("content-length")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0);
+    if content_length > MAX_BODY_BYTES {
+        respond(&mut stream, "413 Payload Too Large", "payload exceeds maximum size")?;
+        return Ok(());
+    }
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body).context("failed to read request body")?;
+
+    let signature = headers.get("x-hub-signature-256");
+    let signed = signature
+        .map(|sig| verify_signature(secret, &body, sig))
+        .unwrap_or(false);
+
+    if !signed {
+        respond(&mut stream, "401 Unauthorized", "signature missing or invalid")?;
+        return Ok(());
+    }
+
+    let event = headers
+        .get("x-github-event")
+        .cloned()
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let timer = tracking::TimedExecution::start();
+    let raw = String::from_utf8_lossy(&body).into_owned();
+    let json: Value = serde_json::from_str(&raw).unwrap_or(Value::Null);
+
+    let summary = summarize_event(&event, &json);
+    let filtered_schema = json_cmd::filter_json_string(&raw, 5, "text").unwrap_or_default();
+    let filtered = format!("{}\n{}", summary, filtered_schema);
+
+    println!("{}", filtered);
+    timer.track(
+        &format!("webhook {}", event),
+        &format!("rtk gh webhook {} (compacted)", event),
+        &raw,
+        &filtered,
+    );
+
+    respond(&mut stream, "200 OK", "ok")?;
+    Ok(())
+}
+
+fn respond(stream: &mut TcpStream, status: &str, body: &str) -> Result<()> {
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).context("failed to write response")?;
+    Ok(())
+}
+
+/// Render a one-to-three-line human summary for the GitHub event types
+/// that show up most often in a repo's webhook stream; anything else gets
+/// a generic one-liner naming the event.
+fn summarize_event(event: &str, json: &Value) -> String {
+    match event {
+        "push" => {
+            let branch = json["ref"]
+                .as_str()
+                .unwrap_or("?")
+                .trim_start_matches("refs/heads/");
+            let pusher = json["pusher"]["name"].as_str().unwrap_or("???");
+            let commits = json["commits"].as_array().map(|c| c.len()).unwrap_or(0);
+            format!("push to {} by {} ({} commit{})", branch, pusher, commits, if commits == 1 { "" } else { "s" })
+        }
+        "pull_request" => {
+            let action = json["action"].as_str().unwrap_or("???");
+            let number = json["number"].as_i64().unwrap_or(0);
+            let title = json["pull_request"]["title"].as_str().unwrap_or("???");
+            let author = json["pull_request"]["user"]["login"].as_str().unwrap_or("???");
+            format!("PR #{} {} by {}: {}", number, action, author, title)
+        }
+        "issues" => {
+            let action = json["action"].as_str().unwrap_or("???");
+            let number = json["issue"]["number"].as_i64().unwrap_or(0);
+            let title = json["issue"]["title"].as_str().unwrap_or("???");
+            format!("Issue #{} {}: {}", number, action, title)
+        }
+        "issue_comment" => {
+            let number = json["issue"]["number"].as_i64().unwrap_or(0);
+            let author = json["comment"]["user"]["login"].as_str().unwrap_or("???");
+            format!("Comment on #{} by {}", number, author)
+        }
+        "workflow_run" => {
+            let name = json["workflow_run"]["name"].as_str().unwrap_or("???");
+            let conclusion = json["workflow_run"]["conclusion"].as_str().unwrap_or("in_progress");
+            format!("Workflow \"{}\": {}", name, conclusion)
+        }
+        other => format!("{} event received", other),
+    }
+}
+
+/// Verify a `sha256=<hex>` signature header against `HMAC-SHA256(secret,
+/// body)`, comparing in constant time so a timing side channel can't be
+/// used to guess the signature byte-by-byte.
+fn verify_signature(secret: &str, body: &[u8], header_value: &str) -> bool {
+    let Some(given_hex) = header_value.strip_prefix("sha256=") else {
+        return false;
+    };
+    let Ok(mut mac) = HmacSha256::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(body);
+    let computed_hex = to_hex(&mac.finalize().into_bytes());
+    constant_time_eq(computed_hex.as_bytes(), given_hex.as_bytes())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_signature_accepts_correct_hmac() {
+        let secret = "topsecret";
+        let body = b"{\"zen\":\"hello\"}";
+        let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(body);
+        let sig = format!("sha256={}", to_hex(&mac.finalize().into_bytes()));
+        assert!(verify_signature(secret, body, &sig));
+    }
+
+    #[test]
+    fn test_verify_signature_rejects_wrong_secret() {
+        let body = b"{\"zen\":\"hello\"}";
+        let mut mac = HmacSha256::new_from_slice(b"right").unwrap();
+        mac.update(body);
+        let sig = format!("sha256={}", to_hex(&mac.finalize().into_bytes()));
+        assert!(!verify_signature("wrong", body, &sig));
+    }
+
+    #[test]
+    fn test_verify_signature_rejects_missing_prefix() {
+        assert!(!verify_signature("secret", b"body", "deadbeef"));
+    }
+
+    #[test]
+    fn test_summarize_pull_request_event() {
+        let json = serde_json::json!({
+            "action": "opened",
+            "number": 42,
+            "pull_request": {"title": "Fix bug", "user": {"login": "octocat"}},
+        });
+        assert_eq!(summarize_event("pull_request", &json), "PR #42 opened by octocat: Fix bug");
+    }
+
+    #[test]
+    fn test_constant_time_eq_differing_lengths() {
+        assert!(!constant_time_eq(b"abc", b"ab"));
+    }
+
+    #[test]
+    fn test_read_bounded_line_normal_line() {
+        let mut cursor = std::io::Cursor::new(b"GET / HTTP/1.1\r\nhost: example.com\r\n".as_slice());
+        let line = read_bounded_line(&mut cursor, 1024).unwrap();
+        assert_eq!(line, "GET / HTTP/1.1\r\n");
+        let line = read_bounded_line(&mut cursor, 1024).unwrap();
+        assert_eq!(line, "host: example.com\r\n");
+    }
+
+    #[test]
+    fn test_read_bounded_line_rejects_oversized_line() {
+        let body = vec![b'a'; 64];
+        let mut cursor = std::io::Cursor::new(body.as_slice());
+        assert!(read_bounded_line(&mut cursor, 16).is_err());
+    }
+
+    #[test]
+    fn test_read_bounded_line_handles_missing_trailing_newline() {
+        let mut cursor = std::io::Cursor::new(b"no newline here".as_slice());
+        let line = read_bounded_line(&mut cursor, 1024).unwrap();
+        assert_eq!(line, "no newline here");
+    }
+}